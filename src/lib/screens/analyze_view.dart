@@ -1905,6 +1905,9 @@ class _AnalyzeViewState extends State<AnalyzeView>
       signatureInvalid: () => 'Digital signature is invalid',
       certificateExpired: () => 'Certificate has expired',
       certificateUntrusted: () => 'Certificate is not trusted',
+      certificateRevoked: () => 'Certificate has been revoked',
+      contentModified: () => 'Content was modified after signing',
+      validationFailed: (codes) => 'Validation failed: ${codes.join(', ')}',
       noManifest: () => 'No C2PA data found',
       error: (msg) => msg.isNotEmpty ? msg : 'Analysis error',
     );
@@ -1916,6 +1919,9 @@ class _AnalyzeViewState extends State<AnalyzeView>
       signatureInvalid: () => true,
       certificateExpired: () => true,
       certificateUntrusted: () => true,
+      certificateRevoked: () => true,
+      contentModified: () => true,
+      validationFailed: (_) => true,
       noManifest: () => false,
       error: (_) => false,
     );
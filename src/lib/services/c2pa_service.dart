@@ -4,11 +4,11 @@ import 'package:flutter/foundation.dart';
 import '../src/rust/frb_generated.dart';
 import '../src/rust/api/c2pa_reader.dart' as rust;
 
+export '../src/rust/api/actions.dart' show ContentAction;
 export '../src/rust/api/c2pa_reader.dart'
     show
         AiInfo,
         C2paAnalysisResult,
-        ContentAction,
         ExifInfo,
         SignerInfo,
         VerificationStatus,
@@ -16,6 +16,9 @@ export '../src/rust/api/c2pa_reader.dart'
         VerificationStatus_SignatureInvalid,
         VerificationStatus_CertificateExpired,
         VerificationStatus_CertificateUntrusted,
+        VerificationStatus_CertificateRevoked,
+        VerificationStatus_ContentModified,
+        VerificationStatus_ValidationFailed,
         VerificationStatus_NoManifest,
         VerificationStatus_Error;
 
@@ -50,7 +53,10 @@ class C2paService {
           status: const rust.VerificationStatus.error(
             message: 'File not found',
           ),
+          validationStatuses: [],
           actions: [],
+          modelIdentities: [],
+          consistency: [],
         );
       }
 
@@ -78,7 +84,10 @@ class C2paService {
       debugPrint('C2PA Error: $e');
       return rust.C2paAnalysisResult(
         status: rust.VerificationStatus.error(message: e.toString()),
+        validationStatuses: [],
         actions: [],
+        modelIdentities: [],
+        consistency: [],
       );
     }
   }
@@ -123,7 +132,10 @@ class C2paService {
       debugPrint('Stack trace: $stackTrace');
       return rust.C2paAnalysisResult(
         status: rust.VerificationStatus.error(message: e.toString()),
+        validationStatuses: [],
         actions: [],
+        modelIdentities: [],
+        consistency: [],
       );
     }
   }
@@ -0,0 +1,162 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'digital_source_type.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'actions.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `action_change`, `actions_from_assertion`, `apply_templates`, `content_action`, `is_actions_label`, `parse_actions`, `region_range`, `software_agent`, `text`, `uri_of`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// A part of the asset an action changed
+class ActionChange {
+  final String? description;
+  final List<RegionRange> regions;
+
+  const ActionChange({this.description, required this.regions});
+
+  @override
+  int get hashCode => description.hashCode ^ regions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ActionChange &&
+          runtimeType == other.runtimeType &&
+          description == other.description &&
+          regions == other.regions;
+}
+
+/// An action parameter not covered by a dedicated field, e.g. `com.adobe.acr`
+class ActionParameter {
+  final String key;
+  /// String values as written, anything else as compact JSON
+  final String value;
+
+  const ActionParameter({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ActionParameter &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// A single action in the content's edit history
+class ContentAction {
+  final String action;
+  /// Display name of the software agent
+  final String? softwareAgent;
+  final SoftwareAgentInfo? softwareAgentInfo;
+  final String? when;
+  final String? description;
+  /// IPTC digital source type the action declares for its output
+  final DigitalSourceType? digitalSourceType;
+  /// Why the action was taken, e.g. "c2pa.PII.present"
+  final String? reason;
+  /// JUMBF URIs or instance IDs of the ingredients the action used
+  final List<String> ingredients;
+  final List<ActionParameter> parameters;
+  final List<ActionChange> changes;
+  /// Actions performed as part of this one
+  final List<ContentAction> related;
+
+  const ContentAction({
+    required this.action,
+    this.softwareAgent,
+    this.softwareAgentInfo,
+    this.when,
+    this.description,
+    this.digitalSourceType,
+    this.reason,
+    required this.ingredients,
+    required this.parameters,
+    required this.changes,
+    required this.related,
+  });
+
+  @override
+  int get hashCode =>
+      action.hashCode ^
+      softwareAgent.hashCode ^
+      softwareAgentInfo.hashCode ^
+      when.hashCode ^
+      description.hashCode ^
+      digitalSourceType.hashCode ^
+      reason.hashCode ^
+      ingredients.hashCode ^
+      parameters.hashCode ^
+      changes.hashCode ^
+      related.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContentAction &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          softwareAgent == other.softwareAgent &&
+          softwareAgentInfo == other.softwareAgentInfo &&
+          when == other.when &&
+          description == other.description &&
+          digitalSourceType == other.digitalSourceType &&
+          reason == other.reason &&
+          ingredients == other.ingredients &&
+          parameters == other.parameters &&
+          changes == other.changes &&
+          related == other.related;
+}
+
+@freezed
+sealed class RegionRange with _$RegionRange {
+  const RegionRange._();
+
+  /// An area of the image; `x`/`y` are the shape's origin
+  const factory RegionRange.spatial({
+    required String shape,
+    String? unit,
+    double? x,
+    double? y,
+    double? width,
+    double? height,
+  }) = RegionRange_Spatial;
+  /// A time span, as NPT or other time strings
+  const factory RegionRange.temporal({String? start, String? end}) =
+      RegionRange_Temporal;
+  /// A span of video frames
+  const factory RegionRange.frame({PlatformInt64? start, PlatformInt64? end}) =
+      RegionRange_Frame;
+  /// Any other range type, as compact JSON
+  const factory RegionRange.other({required String json}) = RegionRange_Other;
+}
+
+/// Software that performed an action
+class SoftwareAgentInfo {
+  final String name;
+  final String? version;
+  /// URI of the agent's icon resource in the manifest
+  final String? icon;
+
+  const SoftwareAgentInfo({required this.name, this.version, this.icon});
+
+  @override
+  int get hashCode => name.hashCode ^ version.hashCode ^ icon.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SoftwareAgentInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          version == other.version &&
+          icon == other.icon;
+}
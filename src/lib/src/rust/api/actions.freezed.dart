@@ -0,0 +1,925 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'actions.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$RegionRange {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )
+    spatial,
+    required TResult Function(String? start, String? end) temporal,
+    required TResult Function(PlatformInt64? start, PlatformInt64? end) frame,
+    required TResult Function(String json) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult? Function(String? start, String? end)? temporal,
+    TResult? Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult? Function(String json)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult Function(String? start, String? end)? temporal,
+    TResult Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult Function(String json)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RegionRange_Spatial value) spatial,
+    required TResult Function(RegionRange_Temporal value) temporal,
+    required TResult Function(RegionRange_Frame value) frame,
+    required TResult Function(RegionRange_Other value) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RegionRange_Spatial value)? spatial,
+    TResult? Function(RegionRange_Temporal value)? temporal,
+    TResult? Function(RegionRange_Frame value)? frame,
+    TResult? Function(RegionRange_Other value)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RegionRange_Spatial value)? spatial,
+    TResult Function(RegionRange_Temporal value)? temporal,
+    TResult Function(RegionRange_Frame value)? frame,
+    TResult Function(RegionRange_Other value)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RegionRangeCopyWith<$Res> {
+  factory $RegionRangeCopyWith(
+    RegionRange value,
+    $Res Function(RegionRange) then,
+  ) = _$RegionRangeCopyWithImpl<$Res, RegionRange>;
+}
+
+/// @nodoc
+class _$RegionRangeCopyWithImpl<$Res, $Val extends RegionRange>
+    implements $RegionRangeCopyWith<$Res> {
+  _$RegionRangeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$RegionRange_SpatialImplCopyWith<$Res> {
+  factory _$$RegionRange_SpatialImplCopyWith(
+    _$RegionRange_SpatialImpl value,
+    $Res Function(_$RegionRange_SpatialImpl) then,
+  ) = __$$RegionRange_SpatialImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({
+    String shape,
+    String? unit,
+    double? x,
+    double? y,
+    double? width,
+    double? height,
+  });
+}
+
+/// @nodoc
+class __$$RegionRange_SpatialImplCopyWithImpl<$Res>
+    extends _$RegionRangeCopyWithImpl<$Res, _$RegionRange_SpatialImpl>
+    implements _$$RegionRange_SpatialImplCopyWith<$Res> {
+  __$$RegionRange_SpatialImplCopyWithImpl(
+    _$RegionRange_SpatialImpl _value,
+    $Res Function(_$RegionRange_SpatialImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? shape = null,
+    Object? unit = freezed,
+    Object? x = freezed,
+    Object? y = freezed,
+    Object? width = freezed,
+    Object? height = freezed,
+  }) {
+    return _then(
+      _$RegionRange_SpatialImpl(
+        shape: null == shape
+            ? _value.shape
+            : shape // ignore: cast_nullable_to_non_nullable
+                  as String,
+        unit: freezed == unit
+            ? _value.unit
+            : unit // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        x: freezed == x
+            ? _value.x
+            : x // ignore: cast_nullable_to_non_nullable
+                  as double?,
+        y: freezed == y
+            ? _value.y
+            : y // ignore: cast_nullable_to_non_nullable
+                  as double?,
+        width: freezed == width
+            ? _value.width
+            : width // ignore: cast_nullable_to_non_nullable
+                  as double?,
+        height: freezed == height
+            ? _value.height
+            : height // ignore: cast_nullable_to_non_nullable
+                  as double?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$RegionRange_SpatialImpl extends RegionRange_Spatial {
+  const _$RegionRange_SpatialImpl({
+    required this.shape,
+    this.unit,
+    this.x,
+    this.y,
+    this.width,
+    this.height,
+  }) : super._();
+
+  @override
+  final String shape;
+  @override
+  final String? unit;
+  @override
+  final double? x;
+  @override
+  final double? y;
+  @override
+  final double? width;
+  @override
+  final double? height;
+
+  @override
+  String toString() {
+    return 'RegionRange.spatial(shape: $shape, unit: $unit, x: $x, y: $y, width: $width, height: $height)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RegionRange_SpatialImpl &&
+            (identical(other.shape, shape) || other.shape == shape) &&
+            (identical(other.unit, unit) || other.unit == unit) &&
+            (identical(other.x, x) || other.x == x) &&
+            (identical(other.y, y) || other.y == y) &&
+            (identical(other.width, width) || other.width == width) &&
+            (identical(other.height, height) || other.height == height));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, shape, unit, x, y, width, height);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RegionRange_SpatialImplCopyWith<_$RegionRange_SpatialImpl> get copyWith =>
+      __$$RegionRange_SpatialImplCopyWithImpl<
+        _$RegionRange_SpatialImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )
+    spatial,
+    required TResult Function(String? start, String? end) temporal,
+    required TResult Function(PlatformInt64? start, PlatformInt64? end) frame,
+    required TResult Function(String json) other,
+  }) {
+    return spatial(shape, unit, x, y, width, height);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult? Function(String? start, String? end)? temporal,
+    TResult? Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult? Function(String json)? other,
+  }) {
+    return spatial?.call(shape, unit, x, y, width, height);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult Function(String? start, String? end)? temporal,
+    TResult Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult Function(String json)? other,
+    required TResult orElse(),
+  }) {
+    if (spatial != null) {
+      return spatial(shape, unit, x, y, width, height);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RegionRange_Spatial value) spatial,
+    required TResult Function(RegionRange_Temporal value) temporal,
+    required TResult Function(RegionRange_Frame value) frame,
+    required TResult Function(RegionRange_Other value) other,
+  }) {
+    return spatial(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RegionRange_Spatial value)? spatial,
+    TResult? Function(RegionRange_Temporal value)? temporal,
+    TResult? Function(RegionRange_Frame value)? frame,
+    TResult? Function(RegionRange_Other value)? other,
+  }) {
+    return spatial?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RegionRange_Spatial value)? spatial,
+    TResult Function(RegionRange_Temporal value)? temporal,
+    TResult Function(RegionRange_Frame value)? frame,
+    TResult Function(RegionRange_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (spatial != null) {
+      return spatial(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RegionRange_Spatial extends RegionRange {
+  const factory RegionRange_Spatial({
+    required final String shape,
+    final String? unit,
+    final double? x,
+    final double? y,
+    final double? width,
+    final double? height,
+  }) = _$RegionRange_SpatialImpl;
+  const RegionRange_Spatial._() : super._();
+
+  String get shape;
+  String? get unit;
+  double? get x;
+  double? get y;
+  double? get width;
+  double? get height;
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RegionRange_SpatialImplCopyWith<_$RegionRange_SpatialImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$RegionRange_TemporalImplCopyWith<$Res> {
+  factory _$$RegionRange_TemporalImplCopyWith(
+    _$RegionRange_TemporalImpl value,
+    $Res Function(_$RegionRange_TemporalImpl) then,
+  ) = __$$RegionRange_TemporalImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? start, String? end});
+}
+
+/// @nodoc
+class __$$RegionRange_TemporalImplCopyWithImpl<$Res>
+    extends _$RegionRangeCopyWithImpl<$Res, _$RegionRange_TemporalImpl>
+    implements _$$RegionRange_TemporalImplCopyWith<$Res> {
+  __$$RegionRange_TemporalImplCopyWithImpl(
+    _$RegionRange_TemporalImpl _value,
+    $Res Function(_$RegionRange_TemporalImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? start = freezed, Object? end = freezed}) {
+    return _then(
+      _$RegionRange_TemporalImpl(
+        start: freezed == start
+            ? _value.start
+            : start // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        end: freezed == end
+            ? _value.end
+            : end // ignore: cast_nullable_to_non_nullable
+                  as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$RegionRange_TemporalImpl extends RegionRange_Temporal {
+  const _$RegionRange_TemporalImpl({this.start, this.end}) : super._();
+
+  @override
+  final String? start;
+  @override
+  final String? end;
+
+  @override
+  String toString() {
+    return 'RegionRange.temporal(start: $start, end: $end)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RegionRange_TemporalImpl &&
+            (identical(other.start, start) || other.start == start) &&
+            (identical(other.end, end) || other.end == end));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, start, end);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RegionRange_TemporalImplCopyWith<_$RegionRange_TemporalImpl>
+  get copyWith =>
+      __$$RegionRange_TemporalImplCopyWithImpl<
+        _$RegionRange_TemporalImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )
+    spatial,
+    required TResult Function(String? start, String? end) temporal,
+    required TResult Function(PlatformInt64? start, PlatformInt64? end) frame,
+    required TResult Function(String json) other,
+  }) {
+    return temporal(start, end);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult? Function(String? start, String? end)? temporal,
+    TResult? Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult? Function(String json)? other,
+  }) {
+    return temporal?.call(start, end);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult Function(String? start, String? end)? temporal,
+    TResult Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult Function(String json)? other,
+    required TResult orElse(),
+  }) {
+    if (temporal != null) {
+      return temporal(start, end);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RegionRange_Spatial value) spatial,
+    required TResult Function(RegionRange_Temporal value) temporal,
+    required TResult Function(RegionRange_Frame value) frame,
+    required TResult Function(RegionRange_Other value) other,
+  }) {
+    return temporal(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RegionRange_Spatial value)? spatial,
+    TResult? Function(RegionRange_Temporal value)? temporal,
+    TResult? Function(RegionRange_Frame value)? frame,
+    TResult? Function(RegionRange_Other value)? other,
+  }) {
+    return temporal?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RegionRange_Spatial value)? spatial,
+    TResult Function(RegionRange_Temporal value)? temporal,
+    TResult Function(RegionRange_Frame value)? frame,
+    TResult Function(RegionRange_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (temporal != null) {
+      return temporal(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RegionRange_Temporal extends RegionRange {
+  const factory RegionRange_Temporal({final String? start, final String? end}) =
+      _$RegionRange_TemporalImpl;
+  const RegionRange_Temporal._() : super._();
+
+  String? get start;
+  String? get end;
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RegionRange_TemporalImplCopyWith<_$RegionRange_TemporalImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$RegionRange_FrameImplCopyWith<$Res> {
+  factory _$$RegionRange_FrameImplCopyWith(
+    _$RegionRange_FrameImpl value,
+    $Res Function(_$RegionRange_FrameImpl) then,
+  ) = __$$RegionRange_FrameImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PlatformInt64? start, PlatformInt64? end});
+}
+
+/// @nodoc
+class __$$RegionRange_FrameImplCopyWithImpl<$Res>
+    extends _$RegionRangeCopyWithImpl<$Res, _$RegionRange_FrameImpl>
+    implements _$$RegionRange_FrameImplCopyWith<$Res> {
+  __$$RegionRange_FrameImplCopyWithImpl(
+    _$RegionRange_FrameImpl _value,
+    $Res Function(_$RegionRange_FrameImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? start = freezed, Object? end = freezed}) {
+    return _then(
+      _$RegionRange_FrameImpl(
+        start: freezed == start
+            ? _value.start
+            : start // ignore: cast_nullable_to_non_nullable
+                  as PlatformInt64?,
+        end: freezed == end
+            ? _value.end
+            : end // ignore: cast_nullable_to_non_nullable
+                  as PlatformInt64?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$RegionRange_FrameImpl extends RegionRange_Frame {
+  const _$RegionRange_FrameImpl({this.start, this.end}) : super._();
+
+  @override
+  final PlatformInt64? start;
+  @override
+  final PlatformInt64? end;
+
+  @override
+  String toString() {
+    return 'RegionRange.frame(start: $start, end: $end)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RegionRange_FrameImpl &&
+            (identical(other.start, start) || other.start == start) &&
+            (identical(other.end, end) || other.end == end));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, start, end);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RegionRange_FrameImplCopyWith<_$RegionRange_FrameImpl> get copyWith =>
+      __$$RegionRange_FrameImplCopyWithImpl<
+        _$RegionRange_FrameImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )
+    spatial,
+    required TResult Function(String? start, String? end) temporal,
+    required TResult Function(PlatformInt64? start, PlatformInt64? end) frame,
+    required TResult Function(String json) other,
+  }) {
+    return frame(start, end);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult? Function(String? start, String? end)? temporal,
+    TResult? Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult? Function(String json)? other,
+  }) {
+    return frame?.call(start, end);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult Function(String? start, String? end)? temporal,
+    TResult Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult Function(String json)? other,
+    required TResult orElse(),
+  }) {
+    if (frame != null) {
+      return frame(start, end);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RegionRange_Spatial value) spatial,
+    required TResult Function(RegionRange_Temporal value) temporal,
+    required TResult Function(RegionRange_Frame value) frame,
+    required TResult Function(RegionRange_Other value) other,
+  }) {
+    return frame(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RegionRange_Spatial value)? spatial,
+    TResult? Function(RegionRange_Temporal value)? temporal,
+    TResult? Function(RegionRange_Frame value)? frame,
+    TResult? Function(RegionRange_Other value)? other,
+  }) {
+    return frame?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RegionRange_Spatial value)? spatial,
+    TResult Function(RegionRange_Temporal value)? temporal,
+    TResult Function(RegionRange_Frame value)? frame,
+    TResult Function(RegionRange_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (frame != null) {
+      return frame(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RegionRange_Frame extends RegionRange {
+  const factory RegionRange_Frame({
+    final PlatformInt64? start,
+    final PlatformInt64? end,
+  }) = _$RegionRange_FrameImpl;
+  const RegionRange_Frame._() : super._();
+
+  PlatformInt64? get start;
+  PlatformInt64? get end;
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RegionRange_FrameImplCopyWith<_$RegionRange_FrameImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$RegionRange_OtherImplCopyWith<$Res> {
+  factory _$$RegionRange_OtherImplCopyWith(
+    _$RegionRange_OtherImpl value,
+    $Res Function(_$RegionRange_OtherImpl) then,
+  ) = __$$RegionRange_OtherImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String json});
+}
+
+/// @nodoc
+class __$$RegionRange_OtherImplCopyWithImpl<$Res>
+    extends _$RegionRangeCopyWithImpl<$Res, _$RegionRange_OtherImpl>
+    implements _$$RegionRange_OtherImplCopyWith<$Res> {
+  __$$RegionRange_OtherImplCopyWithImpl(
+    _$RegionRange_OtherImpl _value,
+    $Res Function(_$RegionRange_OtherImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? json = null}) {
+    return _then(
+      _$RegionRange_OtherImpl(
+        json: null == json
+            ? _value.json
+            : json // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$RegionRange_OtherImpl extends RegionRange_Other {
+  const _$RegionRange_OtherImpl({required this.json}) : super._();
+
+  @override
+  final String json;
+
+  @override
+  String toString() {
+    return 'RegionRange.other(json: $json)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RegionRange_OtherImpl &&
+            (identical(other.json, json) || other.json == json));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, json);
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RegionRange_OtherImplCopyWith<_$RegionRange_OtherImpl> get copyWith =>
+      __$$RegionRange_OtherImplCopyWithImpl<
+        _$RegionRange_OtherImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )
+    spatial,
+    required TResult Function(String? start, String? end) temporal,
+    required TResult Function(PlatformInt64? start, PlatformInt64? end) frame,
+    required TResult Function(String json) other,
+  }) {
+    return other(json);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult? Function(String? start, String? end)? temporal,
+    TResult? Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult? Function(String json)? other,
+  }) {
+    return other?.call(json);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+      String shape,
+      String? unit,
+      double? x,
+      double? y,
+      double? width,
+      double? height,
+    )?
+    spatial,
+    TResult Function(String? start, String? end)? temporal,
+    TResult Function(PlatformInt64? start, PlatformInt64? end)? frame,
+    TResult Function(String json)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(json);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RegionRange_Spatial value) spatial,
+    required TResult Function(RegionRange_Temporal value) temporal,
+    required TResult Function(RegionRange_Frame value) frame,
+    required TResult Function(RegionRange_Other value) other,
+  }) {
+    return other(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RegionRange_Spatial value)? spatial,
+    TResult? Function(RegionRange_Temporal value)? temporal,
+    TResult? Function(RegionRange_Frame value)? frame,
+    TResult? Function(RegionRange_Other value)? other,
+  }) {
+    return other?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RegionRange_Spatial value)? spatial,
+    TResult Function(RegionRange_Temporal value)? temporal,
+    TResult Function(RegionRange_Frame value)? frame,
+    TResult Function(RegionRange_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RegionRange_Other extends RegionRange {
+  const factory RegionRange_Other({required final String json}) =
+      _$RegionRange_OtherImpl;
+  const RegionRange_Other._() : super._();
+
+  String get json;
+
+  /// Create a copy of RegionRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RegionRange_OtherImplCopyWith<_$RegionRange_OtherImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
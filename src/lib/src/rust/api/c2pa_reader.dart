@@ -4,12 +4,29 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'actions.dart';
+import 'certificate.dart';
+import 'consistency.dart';
+import 'error.dart';
+import 'evidence.dart';
+import 'exif_metadata.dart';
+import 'generation_parameters.dart';
+import 'input.dart';
+import 'iptc.dart';
+import 'maker_note.dart';
+import 'mime.dart';
+import 'model_identity.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scoring.dart';
+import 'thumbnail_comparison.dart';
+import 'timestamp.dart';
+import 'validation_status.dart';
+import 'xmp.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'c2pa_reader.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `check_json_for_ai_indicators`, `detect_ai_generation`, `error`, `extract_cert_field`, `extract_generator_from_json`, `extract_model_name`, `no_manifest_with_exif`, `no_manifest`, `parse_exif_from_bytes`, `parse_exif_from_file`, `parse_manifest_reader`, `software_agent_to_string`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `ai_info_from_evidence`, `analyze_input`, `build_provenance_graph`, `c2pa_evidence`, `classify_expired_with_timestamp`, `exif_evidence`, `from_error`, `manifest_intact`, `manifest_node`, `manifest_store_evidence`, `merge_evidence`, `merge_exif`, `merge_generation_parameters`, `merge_xmp`, `no_manifest`, `parse_exif`, `parse_manifest_reader`, `parse_signer_info`, `read_claim_signatures`, `source_type_evidence`, `status_refers_to_manifest`, `text_field_evidence`, `to_ingredient_relationship`, `to_validation_entries`, `unix_now`, `verification_status_from_entries`, `xmp_evidence`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Analyzes a file at the given path for C2PA metadata
 C2paAnalysisResult analyzeC2PaFromPath({required String filePath}) => RustLib
@@ -17,6 +34,11 @@ C2paAnalysisResult analyzeC2PaFromPath({required String filePath}) => RustLib
     .api
     .crateApiC2PaReaderAnalyzeC2PaFromPath(filePath: filePath);
 
+/// Analyzes a file through a memory map instead of buffered reads, for large videos
+C2paAnalysisResult analyzeC2PaFromMappedFile({required String filePath}) => RustLib.instance.api.crateApiC2PaReaderAnalyzeC2PaFromMappedFile(
+  filePath: filePath,
+);
+
 /// Analyzes raw bytes for C2PA metadata
 C2paAnalysisResult analyzeC2PaFromBytes({
   required List<int> data,
@@ -26,6 +48,10 @@ C2paAnalysisResult analyzeC2PaFromBytes({
   mimeType: mimeType,
 );
 
+/// Analyzes an asset streamed from Dart in chunks
+C2paAnalysisResult analyzeC2PaFromChunks({required ChunkedAsset asset}) =>
+    RustLib.instance.api.crateApiC2PaReaderAnalyzeC2PaFromChunks(asset: asset);
+
 /// Returns the C2PA SDK version
 String c2PaSdkVersion() =>
     RustLib.instance.api.crateApiC2PaReaderC2PaSdkVersion();
@@ -37,23 +63,41 @@ bool isC2PaAvailable() =>
 /// AI generation information
 class AiInfo {
   final bool isAiGenerated;
+  /// Combined likelihood of AI involvement, 0–1, from the configured scoring weights
+  final double likelihood;
+  final AiLabel label;
   final String? generatorName;
+  /// Display name of the model, from `model`
   final String? modelName;
+  /// Vendor, product, model and version of the AI tool the evidence names
+  final ModelIdentity? model;
+  /// Metadata the evidence came from: "c2pa", "exif", "png" or "xmp", joined
+  /// with " + " when there is more than one
   final String? detectionSource;
+  /// Every match behind this verdict, strongest first
+  final List<Evidence> evidence;
 
   const AiInfo({
     required this.isAiGenerated,
+    required this.likelihood,
+    required this.label,
     this.generatorName,
     this.modelName,
+    this.model,
     this.detectionSource,
+    required this.evidence,
   });
 
   @override
   int get hashCode =>
       isAiGenerated.hashCode ^
+      likelihood.hashCode ^
+      label.hashCode ^
       generatorName.hashCode ^
       modelName.hashCode ^
-      detectionSource.hashCode;
+      model.hashCode ^
+      detectionSource.hashCode ^
+      evidence.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -61,49 +105,91 @@ class AiInfo {
       other is AiInfo &&
           runtimeType == other.runtimeType &&
           isAiGenerated == other.isAiGenerated &&
+          likelihood == other.likelihood &&
+          label == other.label &&
           generatorName == other.generatorName &&
           modelName == other.modelName &&
-          detectionSource == other.detectionSource;
+          model == other.model &&
+          detectionSource == other.detectionSource &&
+          evidence == other.evidence;
 }
 
 /// The full C2PA analysis result
 class C2paAnalysisResult {
   final VerificationStatus status;
+  /// Why analysis failed, or `ManifestNotFound` when there was nothing to verify
+  final AnalysisError? error;
+  final List<ValidationStatusEntry> validationStatuses;
   final SignerInfo? signer;
   final List<ContentAction> actions;
   final AiInfo? aiInfo;
   final ExifInfo? exifInfo;
+  /// IPTC-IIM newsroom metadata: by-line, credit, source, caption and location
+  final IptcInfo? iptcInfo;
+  /// Provenance properties from the asset's XMP packet
+  final XmpInfo? xmpInfo;
+  /// Generation parameters from PNG text chunks
+  final GenerationParameters? generationParameters;
+  /// Every tool identified from software agents, claim generator info,
+  /// EXIF Software, XMP CreatorTool and generation parameters, AI or not
+  final List<ModelIdentity> modelIdentities;
   final String? claimGenerator;
   final String? title;
   final String? format;
   final String? instanceId;
   final String? rawManifestJson;
+  final ProvenanceGraph? provenance;
+  final MimeCheck? mimeCheck;
+  /// The EXIF thumbnail compared with the image it previews
+  final ThumbnailComparison? thumbnailComparison;
+  /// Contradictions between EXIF, XMP, IPTC and the manifest
+  final List<ConsistencyFinding> consistency;
 
   const C2paAnalysisResult({
     required this.status,
+    this.error,
+    required this.validationStatuses,
     this.signer,
     required this.actions,
     this.aiInfo,
     this.exifInfo,
+    this.iptcInfo,
+    this.xmpInfo,
+    this.generationParameters,
+    required this.modelIdentities,
     this.claimGenerator,
     this.title,
     this.format,
     this.instanceId,
     this.rawManifestJson,
+    this.provenance,
+    this.mimeCheck,
+    this.thumbnailComparison,
+    required this.consistency,
   });
 
   @override
   int get hashCode =>
       status.hashCode ^
+      error.hashCode ^
+      validationStatuses.hashCode ^
       signer.hashCode ^
       actions.hashCode ^
       aiInfo.hashCode ^
       exifInfo.hashCode ^
+      iptcInfo.hashCode ^
+      xmpInfo.hashCode ^
+      generationParameters.hashCode ^
+      modelIdentities.hashCode ^
       claimGenerator.hashCode ^
       title.hashCode ^
       format.hashCode ^
       instanceId.hashCode ^
-      rawManifestJson.hashCode;
+      rawManifestJson.hashCode ^
+      provenance.hashCode ^
+      mimeCheck.hashCode ^
+      thumbnailComparison.hashCode ^
+      consistency.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -111,47 +197,25 @@ class C2paAnalysisResult {
       other is C2paAnalysisResult &&
           runtimeType == other.runtimeType &&
           status == other.status &&
+          error == other.error &&
+          validationStatuses == other.validationStatuses &&
           signer == other.signer &&
           actions == other.actions &&
           aiInfo == other.aiInfo &&
           exifInfo == other.exifInfo &&
+          iptcInfo == other.iptcInfo &&
+          xmpInfo == other.xmpInfo &&
+          generationParameters == other.generationParameters &&
+          modelIdentities == other.modelIdentities &&
           claimGenerator == other.claimGenerator &&
           title == other.title &&
           format == other.format &&
           instanceId == other.instanceId &&
-          rawManifestJson == other.rawManifestJson;
-}
-
-/// A single action in the content's edit history
-class ContentAction {
-  final String action;
-  final String? softwareAgent;
-  final String? when;
-  final String? description;
-
-  const ContentAction({
-    required this.action,
-    this.softwareAgent,
-    this.when,
-    this.description,
-  });
-
-  @override
-  int get hashCode =>
-      action.hashCode ^
-      softwareAgent.hashCode ^
-      when.hashCode ^
-      description.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ContentAction &&
-          runtimeType == other.runtimeType &&
-          action == other.action &&
-          softwareAgent == other.softwareAgent &&
-          when == other.when &&
-          description == other.description;
+          rawManifestJson == other.rawManifestJson &&
+          provenance == other.provenance &&
+          mimeCheck == other.mimeCheck &&
+          thumbnailComparison == other.thumbnailComparison &&
+          consistency == other.consistency;
 }
 
 /// EXIF metadata result
@@ -164,6 +228,30 @@ class ExifInfo {
   final String? userComment;
   final String? imageDescription;
   final String? dateTimeOriginal;
+  /// Last modification, "YYYY-MM-DD HH:MM:SS"
+  final String? dateTime;
+  final String? dateTimeDigitized;
+  /// UTC offsets of the three timestamps, e.g. "+01:00"
+  final String? offsetTime;
+  final String? offsetTimeOriginal;
+  final String? offsetTimeDigitized;
+  /// Fractions of a second of the three timestamps, e.g. "042"
+  final String? subSecTime;
+  final String? subSecTimeOriginal;
+  final String? subSecTimeDigitized;
+  final int? orientation;
+  final int? imageWidth;
+  final int? imageHeight;
+  final String? bodySerialNumber;
+  final String? cameraOwnerName;
+  final String? imageUniqueId;
+  final GpsInfo? gps;
+  final LensInfo? lens;
+  final ExposureInfo? exposure;
+  final ExifThumbnail? thumbnail;
+  final MakerNote? makerNote;
+  /// Every field, including those above, as text
+  final List<ExifTag> tags;
   final bool aiDetected;
   final String? aiGenerator;
 
@@ -176,6 +264,26 @@ class ExifInfo {
     this.userComment,
     this.imageDescription,
     this.dateTimeOriginal,
+    this.dateTime,
+    this.dateTimeDigitized,
+    this.offsetTime,
+    this.offsetTimeOriginal,
+    this.offsetTimeDigitized,
+    this.subSecTime,
+    this.subSecTimeOriginal,
+    this.subSecTimeDigitized,
+    this.orientation,
+    this.imageWidth,
+    this.imageHeight,
+    this.bodySerialNumber,
+    this.cameraOwnerName,
+    this.imageUniqueId,
+    this.gps,
+    this.lens,
+    this.exposure,
+    this.thumbnail,
+    this.makerNote,
+    required this.tags,
     required this.aiDetected,
     this.aiGenerator,
   });
@@ -190,6 +298,26 @@ class ExifInfo {
       userComment.hashCode ^
       imageDescription.hashCode ^
       dateTimeOriginal.hashCode ^
+      dateTime.hashCode ^
+      dateTimeDigitized.hashCode ^
+      offsetTime.hashCode ^
+      offsetTimeOriginal.hashCode ^
+      offsetTimeDigitized.hashCode ^
+      subSecTime.hashCode ^
+      subSecTimeOriginal.hashCode ^
+      subSecTimeDigitized.hashCode ^
+      orientation.hashCode ^
+      imageWidth.hashCode ^
+      imageHeight.hashCode ^
+      bodySerialNumber.hashCode ^
+      cameraOwnerName.hashCode ^
+      imageUniqueId.hashCode ^
+      gps.hashCode ^
+      lens.hashCode ^
+      exposure.hashCode ^
+      thumbnail.hashCode ^
+      makerNote.hashCode ^
+      tags.hashCode ^
       aiDetected.hashCode ^
       aiGenerator.hashCode;
 
@@ -206,22 +334,179 @@ class ExifInfo {
           userComment == other.userComment &&
           imageDescription == other.imageDescription &&
           dateTimeOriginal == other.dateTimeOriginal &&
+          dateTime == other.dateTime &&
+          dateTimeDigitized == other.dateTimeDigitized &&
+          offsetTime == other.offsetTime &&
+          offsetTimeOriginal == other.offsetTimeOriginal &&
+          offsetTimeDigitized == other.offsetTimeDigitized &&
+          subSecTime == other.subSecTime &&
+          subSecTimeOriginal == other.subSecTimeOriginal &&
+          subSecTimeDigitized == other.subSecTimeDigitized &&
+          orientation == other.orientation &&
+          imageWidth == other.imageWidth &&
+          imageHeight == other.imageHeight &&
+          bodySerialNumber == other.bodySerialNumber &&
+          cameraOwnerName == other.cameraOwnerName &&
+          imageUniqueId == other.imageUniqueId &&
+          gps == other.gps &&
+          lens == other.lens &&
+          exposure == other.exposure &&
+          thumbnail == other.thumbnail &&
+          makerNote == other.makerNote &&
+          tags == other.tags &&
           aiDetected == other.aiDetected &&
           aiGenerator == other.aiGenerator;
 }
 
+/// An ingredient link from a manifest to the ingredient it consumed
+class IngredientEdge {
+  final String fromLabel;
+  /// Label of the ingredient's own manifest, if the ingredient carried Content Credentials
+  final String? toLabel;
+  final String ingredientTitle;
+  final String? ingredientFormat;
+  final String? ingredientInstanceId;
+  final IngredientRelationship relationship;
+  /// Validation codes recorded for this ingredient when it was added
+  final List<ValidationStatusEntry> validationStatuses;
+
+  const IngredientEdge({
+    required this.fromLabel,
+    this.toLabel,
+    required this.ingredientTitle,
+    this.ingredientFormat,
+    this.ingredientInstanceId,
+    required this.relationship,
+    required this.validationStatuses,
+  });
+
+  @override
+  int get hashCode =>
+      fromLabel.hashCode ^
+      toLabel.hashCode ^
+      ingredientTitle.hashCode ^
+      ingredientFormat.hashCode ^
+      ingredientInstanceId.hashCode ^
+      relationship.hashCode ^
+      validationStatuses.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IngredientEdge &&
+          runtimeType == other.runtimeType &&
+          fromLabel == other.fromLabel &&
+          toLabel == other.toLabel &&
+          ingredientTitle == other.ingredientTitle &&
+          ingredientFormat == other.ingredientFormat &&
+          ingredientInstanceId == other.ingredientInstanceId &&
+          relationship == other.relationship &&
+          validationStatuses == other.validationStatuses;
+}
+
+/// How an ingredient was used by the manifest that embeds it
+enum IngredientRelationship {
+  parentOf,
+  componentOf,
+  inputTo,
+  ;
+}
+
+/// A single manifest in the provenance graph
+class ManifestNode {
+  final String label;
+  final bool isActive;
+  final String? title;
+  final String? format;
+  final String? instanceId;
+  final String? claimGenerator;
+  final SignerInfo? signer;
+  final List<ContentAction> actions;
+  final List<ValidationStatusEntry> validationStatuses;
+
+  const ManifestNode({
+    required this.label,
+    required this.isActive,
+    this.title,
+    this.format,
+    this.instanceId,
+    this.claimGenerator,
+    this.signer,
+    required this.actions,
+    required this.validationStatuses,
+  });
+
+  @override
+  int get hashCode =>
+      label.hashCode ^
+      isActive.hashCode ^
+      title.hashCode ^
+      format.hashCode ^
+      instanceId.hashCode ^
+      claimGenerator.hashCode ^
+      signer.hashCode ^
+      actions.hashCode ^
+      validationStatuses.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ManifestNode &&
+          runtimeType == other.runtimeType &&
+          label == other.label &&
+          isActive == other.isActive &&
+          title == other.title &&
+          format == other.format &&
+          instanceId == other.instanceId &&
+          claimGenerator == other.claimGenerator &&
+          signer == other.signer &&
+          actions == other.actions &&
+          validationStatuses == other.validationStatuses;
+}
+
+/// Every manifest in the store and the ingredient relationships between them
+class ProvenanceGraph {
+  final String? activeLabel;
+  final List<ManifestNode> nodes;
+  final List<IngredientEdge> edges;
+
+  const ProvenanceGraph({
+    this.activeLabel,
+    required this.nodes,
+    required this.edges,
+  });
+
+  @override
+  int get hashCode => activeLabel.hashCode ^ nodes.hashCode ^ edges.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProvenanceGraph &&
+          runtimeType == other.runtimeType &&
+          activeLabel == other.activeLabel &&
+          nodes == other.nodes &&
+          edges == other.edges;
+}
+
 /// Information about the content signer
 class SignerInfo {
   final String? name;
   final String? organization;
   final String? issuedBy;
   final String? timestamp;
+  /// Every certificate in the COSE signature's chain, leaf first
+  final List<CertificateInfo> certificateChain;
+  /// RFC 3161 time-stamp token from the claim signature, if present
+  final TimestampInfo? timestampToken;
 
   const SignerInfo({
     this.name,
     this.organization,
     this.issuedBy,
     this.timestamp,
+    required this.certificateChain,
+    this.timestampToken,
   });
 
   @override
@@ -229,7 +514,9 @@ class SignerInfo {
       name.hashCode ^
       organization.hashCode ^
       issuedBy.hashCode ^
-      timestamp.hashCode;
+      timestamp.hashCode ^
+      certificateChain.hashCode ^
+      timestampToken.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -239,7 +526,46 @@ class SignerInfo {
           name == other.name &&
           organization == other.organization &&
           issuedBy == other.issuedBy &&
-          timestamp == other.timestamp;
+          timestamp == other.timestamp &&
+          certificateChain == other.certificateChain &&
+          timestampToken == other.timestampToken;
+}
+
+/// A single validation status code reported by the C2PA validator
+class ValidationStatusEntry {
+  final String code;
+  final ValidationStatusCode kind;
+  final ValidationCategory category;
+  /// JUMBF URI of the element the code refers to, e.g. "self#jumbf=/c2pa/<label>/c2pa.assertions/c2pa.hash.data"
+  final String? url;
+  final String? explanation;
+
+  const ValidationStatusEntry({
+    required this.code,
+    required this.kind,
+    required this.category,
+    this.url,
+    this.explanation,
+  });
+
+  @override
+  int get hashCode =>
+      code.hashCode ^
+      kind.hashCode ^
+      category.hashCode ^
+      url.hashCode ^
+      explanation.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ValidationStatusEntry &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          kind == other.kind &&
+          category == other.category &&
+          url == other.url &&
+          explanation == other.explanation;
 }
 
 @freezed
@@ -253,6 +579,15 @@ sealed class VerificationStatus with _$VerificationStatus {
       VerificationStatus_CertificateExpired;
   const factory VerificationStatus.certificateUntrusted() =
       VerificationStatus_CertificateUntrusted;
+  const factory VerificationStatus.certificateRevoked() =
+      VerificationStatus_CertificateRevoked;
+  /// The asset or one of its assertions no longer matches its signed hash
+  const factory VerificationStatus.contentModified() =
+      VerificationStatus_ContentModified;
+  /// Any other failure code reported by the validator
+  const factory VerificationStatus.validationFailed({
+    required List<String> codes,
+  }) = VerificationStatus_ValidationFailed;
   const factory VerificationStatus.noManifest() = VerificationStatus_NoManifest;
   const factory VerificationStatus.error({required String message}) =
       VerificationStatus_Error;
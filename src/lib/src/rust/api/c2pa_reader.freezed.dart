@@ -23,6 +23,9 @@ mixin _$VerificationStatus {
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) => throw _privateConstructorUsedError;
@@ -32,6 +35,9 @@ mixin _$VerificationStatus {
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) => throw _privateConstructorUsedError;
@@ -41,6 +47,9 @@ mixin _$VerificationStatus {
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -54,6 +63,12 @@ mixin _$VerificationStatus {
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) => throw _privateConstructorUsedError;
@@ -66,6 +81,12 @@ mixin _$VerificationStatus {
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) => throw _privateConstructorUsedError;
@@ -78,6 +99,11 @@ mixin _$VerificationStatus {
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -158,6 +184,9 @@ class _$VerificationStatus_VerifiedImpl extends VerificationStatus_Verified {
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) {
@@ -171,6 +200,9 @@ class _$VerificationStatus_VerifiedImpl extends VerificationStatus_Verified {
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) {
@@ -184,6 +216,9 @@ class _$VerificationStatus_VerifiedImpl extends VerificationStatus_Verified {
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -204,6 +239,12 @@ class _$VerificationStatus_VerifiedImpl extends VerificationStatus_Verified {
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) {
@@ -220,6 +261,12 @@ class _$VerificationStatus_VerifiedImpl extends VerificationStatus_Verified {
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) {
@@ -236,6 +283,11 @@ class _$VerificationStatus_VerifiedImpl extends VerificationStatus_Verified {
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -306,6 +358,9 @@ class _$VerificationStatus_SignatureInvalidImpl
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) {
@@ -319,6 +374,9 @@ class _$VerificationStatus_SignatureInvalidImpl
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) {
@@ -332,6 +390,9 @@ class _$VerificationStatus_SignatureInvalidImpl
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -352,6 +413,12 @@ class _$VerificationStatus_SignatureInvalidImpl
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) {
@@ -368,6 +435,12 @@ class _$VerificationStatus_SignatureInvalidImpl
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) {
@@ -384,6 +457,11 @@ class _$VerificationStatus_SignatureInvalidImpl
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -454,6 +532,9 @@ class _$VerificationStatus_CertificateExpiredImpl
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) {
@@ -467,6 +548,9 @@ class _$VerificationStatus_CertificateExpiredImpl
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) {
@@ -480,6 +564,9 @@ class _$VerificationStatus_CertificateExpiredImpl
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -500,6 +587,12 @@ class _$VerificationStatus_CertificateExpiredImpl
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) {
@@ -516,6 +609,12 @@ class _$VerificationStatus_CertificateExpiredImpl
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) {
@@ -532,6 +631,11 @@ class _$VerificationStatus_CertificateExpiredImpl
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -603,6 +707,9 @@ class _$VerificationStatus_CertificateUntrustedImpl
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) {
@@ -616,6 +723,9 @@ class _$VerificationStatus_CertificateUntrustedImpl
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) {
@@ -629,6 +739,9 @@ class _$VerificationStatus_CertificateUntrustedImpl
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -649,6 +762,12 @@ class _$VerificationStatus_CertificateUntrustedImpl
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) {
@@ -665,6 +784,12 @@ class _$VerificationStatus_CertificateUntrustedImpl
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) {
@@ -681,6 +806,11 @@ class _$VerificationStatus_CertificateUntrustedImpl
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -699,6 +829,580 @@ abstract class VerificationStatus_CertificateUntrusted
   const VerificationStatus_CertificateUntrusted._() : super._();
 }
 
+/// @nodoc
+abstract class _$$VerificationStatus_CertificateRevokedImplCopyWith<$Res> {
+  factory _$$VerificationStatus_CertificateRevokedImplCopyWith(
+    _$VerificationStatus_CertificateRevokedImpl value,
+    $Res Function(_$VerificationStatus_CertificateRevokedImpl) then,
+  ) = __$$VerificationStatus_CertificateRevokedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$VerificationStatus_CertificateRevokedImplCopyWithImpl<$Res>
+    extends
+        _$VerificationStatusCopyWithImpl<
+          $Res,
+          _$VerificationStatus_CertificateRevokedImpl
+        >
+    implements _$$VerificationStatus_CertificateRevokedImplCopyWith<$Res> {
+  __$$VerificationStatus_CertificateRevokedImplCopyWithImpl(
+    _$VerificationStatus_CertificateRevokedImpl _value,
+    $Res Function(_$VerificationStatus_CertificateRevokedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of VerificationStatus
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$VerificationStatus_CertificateRevokedImpl
+    extends VerificationStatus_CertificateRevoked {
+  const _$VerificationStatus_CertificateRevokedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'VerificationStatus.certificateRevoked()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VerificationStatus_CertificateRevokedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() verified,
+    required TResult Function() signatureInvalid,
+    required TResult Function() certificateExpired,
+    required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
+    required TResult Function() noManifest,
+    required TResult Function(String message) error,
+  }) {
+    return certificateRevoked();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? verified,
+    TResult? Function()? signatureInvalid,
+    TResult? Function()? certificateExpired,
+    TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
+    TResult? Function()? noManifest,
+    TResult? Function(String message)? error,
+  }) {
+    return certificateRevoked?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? verified,
+    TResult Function()? signatureInvalid,
+    TResult Function()? certificateExpired,
+    TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
+    TResult Function()? noManifest,
+    TResult Function(String message)? error,
+    required TResult orElse(),
+  }) {
+    if (certificateRevoked != null) {
+      return certificateRevoked();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VerificationStatus_Verified value) verified,
+    required TResult Function(VerificationStatus_SignatureInvalid value)
+    signatureInvalid,
+    required TResult Function(VerificationStatus_CertificateExpired value)
+    certificateExpired,
+    required TResult Function(VerificationStatus_CertificateUntrusted value)
+    certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
+    required TResult Function(VerificationStatus_NoManifest value) noManifest,
+    required TResult Function(VerificationStatus_Error value) error,
+  }) {
+    return certificateRevoked(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VerificationStatus_Verified value)? verified,
+    TResult? Function(VerificationStatus_SignatureInvalid value)?
+    signatureInvalid,
+    TResult? Function(VerificationStatus_CertificateExpired value)?
+    certificateExpired,
+    TResult? Function(VerificationStatus_CertificateUntrusted value)?
+    certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
+    TResult? Function(VerificationStatus_NoManifest value)? noManifest,
+    TResult? Function(VerificationStatus_Error value)? error,
+  }) {
+    return certificateRevoked?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VerificationStatus_Verified value)? verified,
+    TResult Function(VerificationStatus_SignatureInvalid value)?
+    signatureInvalid,
+    TResult Function(VerificationStatus_CertificateExpired value)?
+    certificateExpired,
+    TResult Function(VerificationStatus_CertificateUntrusted value)?
+    certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
+    TResult Function(VerificationStatus_NoManifest value)? noManifest,
+    TResult Function(VerificationStatus_Error value)? error,
+    required TResult orElse(),
+  }) {
+    if (certificateRevoked != null) {
+      return certificateRevoked(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VerificationStatus_CertificateRevoked
+    extends VerificationStatus {
+  const factory VerificationStatus_CertificateRevoked() =
+      _$VerificationStatus_CertificateRevokedImpl;
+  const VerificationStatus_CertificateRevoked._() : super._();
+}
+
+/// @nodoc
+abstract class _$$VerificationStatus_ContentModifiedImplCopyWith<$Res> {
+  factory _$$VerificationStatus_ContentModifiedImplCopyWith(
+    _$VerificationStatus_ContentModifiedImpl value,
+    $Res Function(_$VerificationStatus_ContentModifiedImpl) then,
+  ) = __$$VerificationStatus_ContentModifiedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$VerificationStatus_ContentModifiedImplCopyWithImpl<$Res>
+    extends
+        _$VerificationStatusCopyWithImpl<
+          $Res,
+          _$VerificationStatus_ContentModifiedImpl
+        >
+    implements _$$VerificationStatus_ContentModifiedImplCopyWith<$Res> {
+  __$$VerificationStatus_ContentModifiedImplCopyWithImpl(
+    _$VerificationStatus_ContentModifiedImpl _value,
+    $Res Function(_$VerificationStatus_ContentModifiedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of VerificationStatus
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$VerificationStatus_ContentModifiedImpl
+    extends VerificationStatus_ContentModified {
+  const _$VerificationStatus_ContentModifiedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'VerificationStatus.contentModified()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VerificationStatus_ContentModifiedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() verified,
+    required TResult Function() signatureInvalid,
+    required TResult Function() certificateExpired,
+    required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
+    required TResult Function() noManifest,
+    required TResult Function(String message) error,
+  }) {
+    return contentModified();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? verified,
+    TResult? Function()? signatureInvalid,
+    TResult? Function()? certificateExpired,
+    TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
+    TResult? Function()? noManifest,
+    TResult? Function(String message)? error,
+  }) {
+    return contentModified?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? verified,
+    TResult Function()? signatureInvalid,
+    TResult Function()? certificateExpired,
+    TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
+    TResult Function()? noManifest,
+    TResult Function(String message)? error,
+    required TResult orElse(),
+  }) {
+    if (contentModified != null) {
+      return contentModified();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VerificationStatus_Verified value) verified,
+    required TResult Function(VerificationStatus_SignatureInvalid value)
+    signatureInvalid,
+    required TResult Function(VerificationStatus_CertificateExpired value)
+    certificateExpired,
+    required TResult Function(VerificationStatus_CertificateUntrusted value)
+    certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
+    required TResult Function(VerificationStatus_NoManifest value) noManifest,
+    required TResult Function(VerificationStatus_Error value) error,
+  }) {
+    return contentModified(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VerificationStatus_Verified value)? verified,
+    TResult? Function(VerificationStatus_SignatureInvalid value)?
+    signatureInvalid,
+    TResult? Function(VerificationStatus_CertificateExpired value)?
+    certificateExpired,
+    TResult? Function(VerificationStatus_CertificateUntrusted value)?
+    certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
+    TResult? Function(VerificationStatus_NoManifest value)? noManifest,
+    TResult? Function(VerificationStatus_Error value)? error,
+  }) {
+    return contentModified?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VerificationStatus_Verified value)? verified,
+    TResult Function(VerificationStatus_SignatureInvalid value)?
+    signatureInvalid,
+    TResult Function(VerificationStatus_CertificateExpired value)?
+    certificateExpired,
+    TResult Function(VerificationStatus_CertificateUntrusted value)?
+    certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
+    TResult Function(VerificationStatus_NoManifest value)? noManifest,
+    TResult Function(VerificationStatus_Error value)? error,
+    required TResult orElse(),
+  }) {
+    if (contentModified != null) {
+      return contentModified(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VerificationStatus_ContentModified extends VerificationStatus {
+  const factory VerificationStatus_ContentModified() =
+      _$VerificationStatus_ContentModifiedImpl;
+  const VerificationStatus_ContentModified._() : super._();
+}
+
+/// @nodoc
+abstract class _$$VerificationStatus_ValidationFailedImplCopyWith<$Res> {
+  factory _$$VerificationStatus_ValidationFailedImplCopyWith(
+    _$VerificationStatus_ValidationFailedImpl value,
+    $Res Function(_$VerificationStatus_ValidationFailedImpl) then,
+  ) = __$$VerificationStatus_ValidationFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<String> codes});
+}
+
+/// @nodoc
+class __$$VerificationStatus_ValidationFailedImplCopyWithImpl<$Res>
+    extends
+        _$VerificationStatusCopyWithImpl<
+          $Res,
+          _$VerificationStatus_ValidationFailedImpl
+        >
+    implements _$$VerificationStatus_ValidationFailedImplCopyWith<$Res> {
+  __$$VerificationStatus_ValidationFailedImplCopyWithImpl(
+    _$VerificationStatus_ValidationFailedImpl _value,
+    $Res Function(_$VerificationStatus_ValidationFailedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of VerificationStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? codes = null}) {
+    return _then(
+      _$VerificationStatus_ValidationFailedImpl(
+        codes: null == codes
+            ? _value._codes
+            : codes // ignore: cast_nullable_to_non_nullable
+                  as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$VerificationStatus_ValidationFailedImpl
+    extends VerificationStatus_ValidationFailed {
+  const _$VerificationStatus_ValidationFailedImpl({
+    required final List<String> codes,
+  }) : _codes = codes,
+       super._();
+
+  final List<String> _codes;
+  @override
+  List<String> get codes {
+    if (_codes is EqualUnmodifiableListView) return _codes;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_codes);
+  }
+
+  @override
+  String toString() {
+    return 'VerificationStatus.validationFailed(codes: $codes)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VerificationStatus_ValidationFailedImpl &&
+            const DeepCollectionEquality().equals(other._codes, _codes));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_codes));
+
+  /// Create a copy of VerificationStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VerificationStatus_ValidationFailedImplCopyWith<
+    _$VerificationStatus_ValidationFailedImpl
+  >
+  get copyWith =>
+      __$$VerificationStatus_ValidationFailedImplCopyWithImpl<
+        _$VerificationStatus_ValidationFailedImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() verified,
+    required TResult Function() signatureInvalid,
+    required TResult Function() certificateExpired,
+    required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
+    required TResult Function() noManifest,
+    required TResult Function(String message) error,
+  }) {
+    return validationFailed(codes);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? verified,
+    TResult? Function()? signatureInvalid,
+    TResult? Function()? certificateExpired,
+    TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
+    TResult? Function()? noManifest,
+    TResult? Function(String message)? error,
+  }) {
+    return validationFailed?.call(codes);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? verified,
+    TResult Function()? signatureInvalid,
+    TResult Function()? certificateExpired,
+    TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
+    TResult Function()? noManifest,
+    TResult Function(String message)? error,
+    required TResult orElse(),
+  }) {
+    if (validationFailed != null) {
+      return validationFailed(codes);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VerificationStatus_Verified value) verified,
+    required TResult Function(VerificationStatus_SignatureInvalid value)
+    signatureInvalid,
+    required TResult Function(VerificationStatus_CertificateExpired value)
+    certificateExpired,
+    required TResult Function(VerificationStatus_CertificateUntrusted value)
+    certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
+    required TResult Function(VerificationStatus_NoManifest value) noManifest,
+    required TResult Function(VerificationStatus_Error value) error,
+  }) {
+    return validationFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VerificationStatus_Verified value)? verified,
+    TResult? Function(VerificationStatus_SignatureInvalid value)?
+    signatureInvalid,
+    TResult? Function(VerificationStatus_CertificateExpired value)?
+    certificateExpired,
+    TResult? Function(VerificationStatus_CertificateUntrusted value)?
+    certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
+    TResult? Function(VerificationStatus_NoManifest value)? noManifest,
+    TResult? Function(VerificationStatus_Error value)? error,
+  }) {
+    return validationFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VerificationStatus_Verified value)? verified,
+    TResult Function(VerificationStatus_SignatureInvalid value)?
+    signatureInvalid,
+    TResult Function(VerificationStatus_CertificateExpired value)?
+    certificateExpired,
+    TResult Function(VerificationStatus_CertificateUntrusted value)?
+    certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
+    TResult Function(VerificationStatus_NoManifest value)? noManifest,
+    TResult Function(VerificationStatus_Error value)? error,
+    required TResult orElse(),
+  }) {
+    if (validationFailed != null) {
+      return validationFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VerificationStatus_ValidationFailed extends VerificationStatus {
+  const factory VerificationStatus_ValidationFailed({
+    required final List<String> codes,
+  }) = _$VerificationStatus_ValidationFailedImpl;
+  const VerificationStatus_ValidationFailed._() : super._();
+
+  List<String> get codes;
+
+  /// Create a copy of VerificationStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VerificationStatus_ValidationFailedImplCopyWith<
+    _$VerificationStatus_ValidationFailedImpl
+  >
+  get copyWith => throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$VerificationStatus_NoManifestImplCopyWith<$Res> {
   factory _$$VerificationStatus_NoManifestImplCopyWith(
@@ -752,6 +1456,9 @@ class _$VerificationStatus_NoManifestImpl
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) {
@@ -765,6 +1472,9 @@ class _$VerificationStatus_NoManifestImpl
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) {
@@ -778,6 +1488,9 @@ class _$VerificationStatus_NoManifestImpl
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -798,6 +1511,12 @@ class _$VerificationStatus_NoManifestImpl
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) {
@@ -814,6 +1533,12 @@ class _$VerificationStatus_NoManifestImpl
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) {
@@ -830,6 +1555,11 @@ class _$VerificationStatus_NoManifestImpl
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -925,6 +1655,9 @@ class _$VerificationStatus_ErrorImpl extends VerificationStatus_Error {
     required TResult Function() signatureInvalid,
     required TResult Function() certificateExpired,
     required TResult Function() certificateUntrusted,
+    required TResult Function() certificateRevoked,
+    required TResult Function() contentModified,
+    required TResult Function(List<String> codes) validationFailed,
     required TResult Function() noManifest,
     required TResult Function(String message) error,
   }) {
@@ -938,6 +1671,9 @@ class _$VerificationStatus_ErrorImpl extends VerificationStatus_Error {
     TResult? Function()? signatureInvalid,
     TResult? Function()? certificateExpired,
     TResult? Function()? certificateUntrusted,
+    TResult? Function()? certificateRevoked,
+    TResult? Function()? contentModified,
+    TResult? Function(List<String> codes)? validationFailed,
     TResult? Function()? noManifest,
     TResult? Function(String message)? error,
   }) {
@@ -951,6 +1687,9 @@ class _$VerificationStatus_ErrorImpl extends VerificationStatus_Error {
     TResult Function()? signatureInvalid,
     TResult Function()? certificateExpired,
     TResult Function()? certificateUntrusted,
+    TResult Function()? certificateRevoked,
+    TResult Function()? contentModified,
+    TResult Function(List<String> codes)? validationFailed,
     TResult Function()? noManifest,
     TResult Function(String message)? error,
     required TResult orElse(),
@@ -971,6 +1710,12 @@ class _$VerificationStatus_ErrorImpl extends VerificationStatus_Error {
     certificateExpired,
     required TResult Function(VerificationStatus_CertificateUntrusted value)
     certificateUntrusted,
+    required TResult Function(VerificationStatus_CertificateRevoked value)
+    certificateRevoked,
+    required TResult Function(VerificationStatus_ContentModified value)
+    contentModified,
+    required TResult Function(VerificationStatus_ValidationFailed value)
+    validationFailed,
     required TResult Function(VerificationStatus_NoManifest value) noManifest,
     required TResult Function(VerificationStatus_Error value) error,
   }) {
@@ -987,6 +1732,12 @@ class _$VerificationStatus_ErrorImpl extends VerificationStatus_Error {
     certificateExpired,
     TResult? Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult? Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult? Function(VerificationStatus_ContentModified value)?
+    contentModified,
+    TResult? Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult? Function(VerificationStatus_NoManifest value)? noManifest,
     TResult? Function(VerificationStatus_Error value)? error,
   }) {
@@ -1003,6 +1754,11 @@ class _$VerificationStatus_ErrorImpl extends VerificationStatus_Error {
     certificateExpired,
     TResult Function(VerificationStatus_CertificateUntrusted value)?
     certificateUntrusted,
+    TResult Function(VerificationStatus_CertificateRevoked value)?
+    certificateRevoked,
+    TResult Function(VerificationStatus_ContentModified value)? contentModified,
+    TResult Function(VerificationStatus_ValidationFailed value)?
+    validationFailed,
     TResult Function(VerificationStatus_NoManifest value)? noManifest,
     TResult Function(VerificationStatus_Error value)? error,
     required TResult orElse(),
@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'distinguished_name.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'revocation.dart';
+
+// These functions are ignored because they are not marked as `pub`: `attribute_value_to_string`, `chains_to_anchor`, `extended_key_usages`, `hex_colon`, `lookup`, `parse_certificate_chain`, `parse_certificate_der`, `same_certificate`, `to_distinguished_name`, `valid_at`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// A certificate from the signer's COSE certificate chain
+class CertificateInfo {
+  final DistinguishedName subject;
+  final DistinguishedName issuer;
+  /// Colon-separated hex serial number
+  final String serialNumber;
+  /// Start of the validity window, seconds since the Unix epoch
+  final PlatformInt64 notBefore;
+  /// End of the validity window, seconds since the Unix epoch
+  final PlatformInt64 notAfter;
+  /// "RSA", "EC", "Ed25519", ... or the dotted OID when not recognized
+  final String keyAlgorithm;
+  final int? keySizeBits;
+  /// Named curve for EC keys, e.g. "P-256"
+  final String? curve;
+  final String signatureAlgorithm;
+  /// Colon-separated uppercase hex SHA-256 of the DER encoding
+  final String sha256Fingerprint;
+  /// Extended key usage OIDs, e.g. "1.3.6.1.5.5.7.3.4"
+  final List<String> extendedKeyUsages;
+  final bool isCa;
+  final Uint8List der;
+  /// Filled in for signer chains from stapled OCSP, cached OCSP and loaded CRLs
+  final RevocationInfo revocation;
+
+  const CertificateInfo({
+    required this.subject,
+    required this.issuer,
+    required this.serialNumber,
+    required this.notBefore,
+    required this.notAfter,
+    required this.keyAlgorithm,
+    this.keySizeBits,
+    this.curve,
+    required this.signatureAlgorithm,
+    required this.sha256Fingerprint,
+    required this.extendedKeyUsages,
+    required this.isCa,
+    required this.der,
+    required this.revocation,
+  });
+
+  @override
+  int get hashCode =>
+      subject.hashCode ^
+      issuer.hashCode ^
+      serialNumber.hashCode ^
+      notBefore.hashCode ^
+      notAfter.hashCode ^
+      keyAlgorithm.hashCode ^
+      keySizeBits.hashCode ^
+      curve.hashCode ^
+      signatureAlgorithm.hashCode ^
+      sha256Fingerprint.hashCode ^
+      extendedKeyUsages.hashCode ^
+      isCa.hashCode ^
+      der.hashCode ^
+      revocation.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertificateInfo &&
+          runtimeType == other.runtimeType &&
+          subject == other.subject &&
+          issuer == other.issuer &&
+          serialNumber == other.serialNumber &&
+          notBefore == other.notBefore &&
+          notAfter == other.notAfter &&
+          keyAlgorithm == other.keyAlgorithm &&
+          keySizeBits == other.keySizeBits &&
+          curve == other.curve &&
+          signatureAlgorithm == other.signatureAlgorithm &&
+          sha256Fingerprint == other.sha256Fingerprint &&
+          extendedKeyUsages == other.extendedKeyUsages &&
+          isCa == other.isCa &&
+          der == other.der &&
+          revocation == other.revocation;
+}
@@ -0,0 +1,103 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `capture_time`, `check_camera`, `check_consistency`, `check_software`, `check_thumbnail_content`, `check_thumbnail`, `check_times`, `digits`, `has_sensor_data`, `parse_offset`, `parse_time`, `product_words`, `same_producer`, `signing_time`, `value`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+/// A contradiction between metadata sources
+class ConsistencyFinding {
+  final InconsistencyKind kind;
+  final FindingSeverity severity;
+  /// The values that disagree, or for a missing-data finding, the values
+  /// that led to it
+  final List<FindingValue> values;
+  /// Human-readable reason for reviewers
+  final String explanation;
+
+  const ConsistencyFinding({
+    required this.kind,
+    required this.severity,
+    required this.values,
+    required this.explanation,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      severity.hashCode ^
+      values.hashCode ^
+      explanation.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConsistencyFinding &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          severity == other.severity &&
+          values == other.values &&
+          explanation == other.explanation;
+}
+
+/// How much a finding on its own says about the asset
+enum FindingSeverity {
+  /// Worth a look; ordinary workflows often produce it
+  info,
+  /// Unlikely unless the asset or its metadata was altered, or a clock
+  /// was set wrong
+  warning,
+  ;
+}
+
+/// One of the values a finding compares
+class FindingValue {
+  /// "c2pa", "exif", "image", "iptc" or "xmp"
+  final String source;
+  /// The field the value was read from, e.g. "DateTimeOriginal" or
+  /// "claim_generator"
+  final String field;
+  final String value;
+
+  const FindingValue({
+    required this.source,
+    required this.field,
+    required this.value,
+  });
+
+  @override
+  int get hashCode => source.hashCode ^ field.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FindingValue &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          field == other.field &&
+          value == other.value;
+}
+
+/// What a consistency finding is about
+enum InconsistencyKind {
+  /// The recorded capture time is later than the manifest was signed
+  captureAfterSigning,
+  /// A C2PA action is dated before the content was captured
+  actionBeforeCapture,
+  /// EXIF names a camera but has none of the exposure data cameras record
+  cameraWithoutSensorData,
+  /// EXIF Software or XMP CreatorTool names a different tool than the
+  /// manifest's claim generator
+  softwareMismatch,
+  /// The EXIF thumbnail's shape differs from the image's, as when an image
+  /// is cropped without regenerating its thumbnail
+  thumbnailMismatch,
+  /// The EXIF thumbnail shows different content from the image, as when an
+  /// object is removed or the image replaced after capture
+  thumbnailContentMismatch,
+  ;
+}
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'digital_source_type.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ai_involvement`, `from_uri`, `is_capture`, `term`, `uri`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+
+@freezed
+sealed class DigitalSourceType with _$DigitalSourceType {
+  const DigitalSourceType._();
+
+  const factory DigitalSourceType.digitalCapture() =
+      DigitalSourceType_DigitalCapture;
+  const factory DigitalSourceType.computationalCapture() =
+      DigitalSourceType_ComputationalCapture;
+  const factory DigitalSourceType.negativeFilm() =
+      DigitalSourceType_NegativeFilm;
+  const factory DigitalSourceType.positiveFilm() =
+      DigitalSourceType_PositiveFilm;
+  const factory DigitalSourceType.print() = DigitalSourceType_Print;
+  const factory DigitalSourceType.screenCapture() =
+      DigitalSourceType_ScreenCapture;
+  const factory DigitalSourceType.virtualRecording() =
+      DigitalSourceType_VirtualRecording;
+  const factory DigitalSourceType.humanEdits() = DigitalSourceType_HumanEdits;
+  /// Deprecated by IPTC in favour of `HumanEdits`, still common in manifests
+  const factory DigitalSourceType.minorHumanEdits() =
+      DigitalSourceType_MinorHumanEdits;
+  const factory DigitalSourceType.algorithmicallyEnhanced() =
+      DigitalSourceType_AlgorithmicallyEnhanced;
+  /// Also covers the deprecated `digitalArt` and `softwareImage` terms
+  const factory DigitalSourceType.digitalCreation() =
+      DigitalSourceType_DigitalCreation;
+  const factory DigitalSourceType.dataDrivenMedia() =
+      DigitalSourceType_DataDrivenMedia;
+  /// Produced by an algorithm that was not trained on sampled data
+  const factory DigitalSourceType.algorithmicMedia() =
+      DigitalSourceType_AlgorithmicMedia;
+  /// Produced by a model trained on sampled content: generative AI
+  const factory DigitalSourceType.trainedAlgorithmicMedia() =
+      DigitalSourceType_TrainedAlgorithmicMedia;
+  const factory DigitalSourceType.composite() = DigitalSourceType_Composite;
+  const factory DigitalSourceType.compositeCapture() =
+      DigitalSourceType_CompositeCapture;
+  const factory DigitalSourceType.compositeSynthetic() =
+      DigitalSourceType_CompositeSynthetic;
+  const factory DigitalSourceType.compositeWithTrainedAlgorithmicMedia() =
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia;
+  /// A term outside the IPTC vocabulary, kept as written
+  const factory DigitalSourceType.unknown({required String uri}) =
+      DigitalSourceType_Unknown;
+}
@@ -0,0 +1,5065 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'digital_source_type.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$DigitalSourceType {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $DigitalSourceTypeCopyWith<$Res> {
+  factory $DigitalSourceTypeCopyWith(
+    DigitalSourceType value,
+    $Res Function(DigitalSourceType) then,
+  ) = _$DigitalSourceTypeCopyWithImpl<$Res, DigitalSourceType>;
+}
+
+/// @nodoc
+class _$DigitalSourceTypeCopyWithImpl<$Res, $Val extends DigitalSourceType>
+    implements $DigitalSourceTypeCopyWith<$Res> {
+  _$DigitalSourceTypeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_DigitalCaptureImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_DigitalCaptureImplCopyWith(
+    _$DigitalSourceType_DigitalCaptureImpl value,
+    $Res Function(_$DigitalSourceType_DigitalCaptureImpl) then,
+  ) = __$$DigitalSourceType_DigitalCaptureImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_DigitalCaptureImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_DigitalCaptureImpl
+        >
+    implements _$$DigitalSourceType_DigitalCaptureImplCopyWith<$Res> {
+  __$$DigitalSourceType_DigitalCaptureImplCopyWithImpl(
+    _$DigitalSourceType_DigitalCaptureImpl _value,
+    $Res Function(_$DigitalSourceType_DigitalCaptureImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_DigitalCaptureImpl
+    extends DigitalSourceType_DigitalCapture {
+  const _$DigitalSourceType_DigitalCaptureImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.digitalCapture()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_DigitalCaptureImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return digitalCapture();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return digitalCapture?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (digitalCapture != null) {
+      return digitalCapture();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return digitalCapture(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return digitalCapture?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (digitalCapture != null) {
+      return digitalCapture(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_DigitalCapture extends DigitalSourceType {
+  const factory DigitalSourceType_DigitalCapture() =
+      _$DigitalSourceType_DigitalCaptureImpl;
+  const DigitalSourceType_DigitalCapture._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_ComputationalCaptureImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_ComputationalCaptureImplCopyWith(
+    _$DigitalSourceType_ComputationalCaptureImpl value,
+    $Res Function(_$DigitalSourceType_ComputationalCaptureImpl) then,
+  ) = __$$DigitalSourceType_ComputationalCaptureImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_ComputationalCaptureImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_ComputationalCaptureImpl
+        >
+    implements _$$DigitalSourceType_ComputationalCaptureImplCopyWith<$Res> {
+  __$$DigitalSourceType_ComputationalCaptureImplCopyWithImpl(
+    _$DigitalSourceType_ComputationalCaptureImpl _value,
+    $Res Function(_$DigitalSourceType_ComputationalCaptureImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_ComputationalCaptureImpl
+    extends DigitalSourceType_ComputationalCapture {
+  const _$DigitalSourceType_ComputationalCaptureImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.computationalCapture()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_ComputationalCaptureImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return computationalCapture();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return computationalCapture?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (computationalCapture != null) {
+      return computationalCapture();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return computationalCapture(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return computationalCapture?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (computationalCapture != null) {
+      return computationalCapture(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_ComputationalCapture
+    extends DigitalSourceType {
+  const factory DigitalSourceType_ComputationalCapture() =
+      _$DigitalSourceType_ComputationalCaptureImpl;
+  const DigitalSourceType_ComputationalCapture._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_NegativeFilmImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_NegativeFilmImplCopyWith(
+    _$DigitalSourceType_NegativeFilmImpl value,
+    $Res Function(_$DigitalSourceType_NegativeFilmImpl) then,
+  ) = __$$DigitalSourceType_NegativeFilmImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_NegativeFilmImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_NegativeFilmImpl
+        >
+    implements _$$DigitalSourceType_NegativeFilmImplCopyWith<$Res> {
+  __$$DigitalSourceType_NegativeFilmImplCopyWithImpl(
+    _$DigitalSourceType_NegativeFilmImpl _value,
+    $Res Function(_$DigitalSourceType_NegativeFilmImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_NegativeFilmImpl
+    extends DigitalSourceType_NegativeFilm {
+  const _$DigitalSourceType_NegativeFilmImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.negativeFilm()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_NegativeFilmImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return negativeFilm();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return negativeFilm?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (negativeFilm != null) {
+      return negativeFilm();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return negativeFilm(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return negativeFilm?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (negativeFilm != null) {
+      return negativeFilm(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_NegativeFilm extends DigitalSourceType {
+  const factory DigitalSourceType_NegativeFilm() =
+      _$DigitalSourceType_NegativeFilmImpl;
+  const DigitalSourceType_NegativeFilm._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_PositiveFilmImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_PositiveFilmImplCopyWith(
+    _$DigitalSourceType_PositiveFilmImpl value,
+    $Res Function(_$DigitalSourceType_PositiveFilmImpl) then,
+  ) = __$$DigitalSourceType_PositiveFilmImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_PositiveFilmImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_PositiveFilmImpl
+        >
+    implements _$$DigitalSourceType_PositiveFilmImplCopyWith<$Res> {
+  __$$DigitalSourceType_PositiveFilmImplCopyWithImpl(
+    _$DigitalSourceType_PositiveFilmImpl _value,
+    $Res Function(_$DigitalSourceType_PositiveFilmImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_PositiveFilmImpl
+    extends DigitalSourceType_PositiveFilm {
+  const _$DigitalSourceType_PositiveFilmImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.positiveFilm()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_PositiveFilmImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return positiveFilm();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return positiveFilm?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (positiveFilm != null) {
+      return positiveFilm();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return positiveFilm(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return positiveFilm?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (positiveFilm != null) {
+      return positiveFilm(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_PositiveFilm extends DigitalSourceType {
+  const factory DigitalSourceType_PositiveFilm() =
+      _$DigitalSourceType_PositiveFilmImpl;
+  const DigitalSourceType_PositiveFilm._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_PrintImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_PrintImplCopyWith(
+    _$DigitalSourceType_PrintImpl value,
+    $Res Function(_$DigitalSourceType_PrintImpl) then,
+  ) = __$$DigitalSourceType_PrintImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_PrintImplCopyWithImpl<$Res>
+    extends _$DigitalSourceTypeCopyWithImpl<$Res, _$DigitalSourceType_PrintImpl>
+    implements _$$DigitalSourceType_PrintImplCopyWith<$Res> {
+  __$$DigitalSourceType_PrintImplCopyWithImpl(
+    _$DigitalSourceType_PrintImpl _value,
+    $Res Function(_$DigitalSourceType_PrintImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_PrintImpl extends DigitalSourceType_Print {
+  const _$DigitalSourceType_PrintImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.print()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_PrintImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return print();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return print?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (print != null) {
+      return print();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return print(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return print?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (print != null) {
+      return print(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_Print extends DigitalSourceType {
+  const factory DigitalSourceType_Print() = _$DigitalSourceType_PrintImpl;
+  const DigitalSourceType_Print._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_ScreenCaptureImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_ScreenCaptureImplCopyWith(
+    _$DigitalSourceType_ScreenCaptureImpl value,
+    $Res Function(_$DigitalSourceType_ScreenCaptureImpl) then,
+  ) = __$$DigitalSourceType_ScreenCaptureImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_ScreenCaptureImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_ScreenCaptureImpl
+        >
+    implements _$$DigitalSourceType_ScreenCaptureImplCopyWith<$Res> {
+  __$$DigitalSourceType_ScreenCaptureImplCopyWithImpl(
+    _$DigitalSourceType_ScreenCaptureImpl _value,
+    $Res Function(_$DigitalSourceType_ScreenCaptureImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_ScreenCaptureImpl
+    extends DigitalSourceType_ScreenCapture {
+  const _$DigitalSourceType_ScreenCaptureImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.screenCapture()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_ScreenCaptureImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return screenCapture();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return screenCapture?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (screenCapture != null) {
+      return screenCapture();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return screenCapture(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return screenCapture?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (screenCapture != null) {
+      return screenCapture(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_ScreenCapture extends DigitalSourceType {
+  const factory DigitalSourceType_ScreenCapture() =
+      _$DigitalSourceType_ScreenCaptureImpl;
+  const DigitalSourceType_ScreenCapture._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_VirtualRecordingImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_VirtualRecordingImplCopyWith(
+    _$DigitalSourceType_VirtualRecordingImpl value,
+    $Res Function(_$DigitalSourceType_VirtualRecordingImpl) then,
+  ) = __$$DigitalSourceType_VirtualRecordingImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_VirtualRecordingImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_VirtualRecordingImpl
+        >
+    implements _$$DigitalSourceType_VirtualRecordingImplCopyWith<$Res> {
+  __$$DigitalSourceType_VirtualRecordingImplCopyWithImpl(
+    _$DigitalSourceType_VirtualRecordingImpl _value,
+    $Res Function(_$DigitalSourceType_VirtualRecordingImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_VirtualRecordingImpl
+    extends DigitalSourceType_VirtualRecording {
+  const _$DigitalSourceType_VirtualRecordingImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.virtualRecording()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_VirtualRecordingImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return virtualRecording();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return virtualRecording?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (virtualRecording != null) {
+      return virtualRecording();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return virtualRecording(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return virtualRecording?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (virtualRecording != null) {
+      return virtualRecording(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_VirtualRecording extends DigitalSourceType {
+  const factory DigitalSourceType_VirtualRecording() =
+      _$DigitalSourceType_VirtualRecordingImpl;
+  const DigitalSourceType_VirtualRecording._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_HumanEditsImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_HumanEditsImplCopyWith(
+    _$DigitalSourceType_HumanEditsImpl value,
+    $Res Function(_$DigitalSourceType_HumanEditsImpl) then,
+  ) = __$$DigitalSourceType_HumanEditsImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_HumanEditsImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_HumanEditsImpl
+        >
+    implements _$$DigitalSourceType_HumanEditsImplCopyWith<$Res> {
+  __$$DigitalSourceType_HumanEditsImplCopyWithImpl(
+    _$DigitalSourceType_HumanEditsImpl _value,
+    $Res Function(_$DigitalSourceType_HumanEditsImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_HumanEditsImpl extends DigitalSourceType_HumanEdits {
+  const _$DigitalSourceType_HumanEditsImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.humanEdits()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_HumanEditsImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return humanEdits();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return humanEdits?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (humanEdits != null) {
+      return humanEdits();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return humanEdits(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return humanEdits?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (humanEdits != null) {
+      return humanEdits(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_HumanEdits extends DigitalSourceType {
+  const factory DigitalSourceType_HumanEdits() =
+      _$DigitalSourceType_HumanEditsImpl;
+  const DigitalSourceType_HumanEdits._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_MinorHumanEditsImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_MinorHumanEditsImplCopyWith(
+    _$DigitalSourceType_MinorHumanEditsImpl value,
+    $Res Function(_$DigitalSourceType_MinorHumanEditsImpl) then,
+  ) = __$$DigitalSourceType_MinorHumanEditsImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_MinorHumanEditsImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_MinorHumanEditsImpl
+        >
+    implements _$$DigitalSourceType_MinorHumanEditsImplCopyWith<$Res> {
+  __$$DigitalSourceType_MinorHumanEditsImplCopyWithImpl(
+    _$DigitalSourceType_MinorHumanEditsImpl _value,
+    $Res Function(_$DigitalSourceType_MinorHumanEditsImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_MinorHumanEditsImpl
+    extends DigitalSourceType_MinorHumanEdits {
+  const _$DigitalSourceType_MinorHumanEditsImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.minorHumanEdits()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_MinorHumanEditsImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return minorHumanEdits();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return minorHumanEdits?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (minorHumanEdits != null) {
+      return minorHumanEdits();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return minorHumanEdits(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return minorHumanEdits?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (minorHumanEdits != null) {
+      return minorHumanEdits(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_MinorHumanEdits extends DigitalSourceType {
+  const factory DigitalSourceType_MinorHumanEdits() =
+      _$DigitalSourceType_MinorHumanEditsImpl;
+  const DigitalSourceType_MinorHumanEdits._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_AlgorithmicallyEnhancedImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_AlgorithmicallyEnhancedImplCopyWith(
+    _$DigitalSourceType_AlgorithmicallyEnhancedImpl value,
+    $Res Function(_$DigitalSourceType_AlgorithmicallyEnhancedImpl) then,
+  ) = __$$DigitalSourceType_AlgorithmicallyEnhancedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_AlgorithmicallyEnhancedImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_AlgorithmicallyEnhancedImpl
+        >
+    implements _$$DigitalSourceType_AlgorithmicallyEnhancedImplCopyWith<$Res> {
+  __$$DigitalSourceType_AlgorithmicallyEnhancedImplCopyWithImpl(
+    _$DigitalSourceType_AlgorithmicallyEnhancedImpl _value,
+    $Res Function(_$DigitalSourceType_AlgorithmicallyEnhancedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_AlgorithmicallyEnhancedImpl
+    extends DigitalSourceType_AlgorithmicallyEnhanced {
+  const _$DigitalSourceType_AlgorithmicallyEnhancedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.algorithmicallyEnhanced()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_AlgorithmicallyEnhancedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return algorithmicallyEnhanced();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return algorithmicallyEnhanced?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (algorithmicallyEnhanced != null) {
+      return algorithmicallyEnhanced();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return algorithmicallyEnhanced(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return algorithmicallyEnhanced?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (algorithmicallyEnhanced != null) {
+      return algorithmicallyEnhanced(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_AlgorithmicallyEnhanced
+    extends DigitalSourceType {
+  const factory DigitalSourceType_AlgorithmicallyEnhanced() =
+      _$DigitalSourceType_AlgorithmicallyEnhancedImpl;
+  const DigitalSourceType_AlgorithmicallyEnhanced._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_DigitalCreationImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_DigitalCreationImplCopyWith(
+    _$DigitalSourceType_DigitalCreationImpl value,
+    $Res Function(_$DigitalSourceType_DigitalCreationImpl) then,
+  ) = __$$DigitalSourceType_DigitalCreationImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_DigitalCreationImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_DigitalCreationImpl
+        >
+    implements _$$DigitalSourceType_DigitalCreationImplCopyWith<$Res> {
+  __$$DigitalSourceType_DigitalCreationImplCopyWithImpl(
+    _$DigitalSourceType_DigitalCreationImpl _value,
+    $Res Function(_$DigitalSourceType_DigitalCreationImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_DigitalCreationImpl
+    extends DigitalSourceType_DigitalCreation {
+  const _$DigitalSourceType_DigitalCreationImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.digitalCreation()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_DigitalCreationImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return digitalCreation();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return digitalCreation?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (digitalCreation != null) {
+      return digitalCreation();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return digitalCreation(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return digitalCreation?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (digitalCreation != null) {
+      return digitalCreation(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_DigitalCreation extends DigitalSourceType {
+  const factory DigitalSourceType_DigitalCreation() =
+      _$DigitalSourceType_DigitalCreationImpl;
+  const DigitalSourceType_DigitalCreation._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_DataDrivenMediaImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_DataDrivenMediaImplCopyWith(
+    _$DigitalSourceType_DataDrivenMediaImpl value,
+    $Res Function(_$DigitalSourceType_DataDrivenMediaImpl) then,
+  ) = __$$DigitalSourceType_DataDrivenMediaImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_DataDrivenMediaImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_DataDrivenMediaImpl
+        >
+    implements _$$DigitalSourceType_DataDrivenMediaImplCopyWith<$Res> {
+  __$$DigitalSourceType_DataDrivenMediaImplCopyWithImpl(
+    _$DigitalSourceType_DataDrivenMediaImpl _value,
+    $Res Function(_$DigitalSourceType_DataDrivenMediaImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_DataDrivenMediaImpl
+    extends DigitalSourceType_DataDrivenMedia {
+  const _$DigitalSourceType_DataDrivenMediaImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.dataDrivenMedia()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_DataDrivenMediaImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return dataDrivenMedia();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return dataDrivenMedia?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (dataDrivenMedia != null) {
+      return dataDrivenMedia();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return dataDrivenMedia(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return dataDrivenMedia?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (dataDrivenMedia != null) {
+      return dataDrivenMedia(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_DataDrivenMedia extends DigitalSourceType {
+  const factory DigitalSourceType_DataDrivenMedia() =
+      _$DigitalSourceType_DataDrivenMediaImpl;
+  const DigitalSourceType_DataDrivenMedia._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_AlgorithmicMediaImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_AlgorithmicMediaImplCopyWith(
+    _$DigitalSourceType_AlgorithmicMediaImpl value,
+    $Res Function(_$DigitalSourceType_AlgorithmicMediaImpl) then,
+  ) = __$$DigitalSourceType_AlgorithmicMediaImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_AlgorithmicMediaImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_AlgorithmicMediaImpl
+        >
+    implements _$$DigitalSourceType_AlgorithmicMediaImplCopyWith<$Res> {
+  __$$DigitalSourceType_AlgorithmicMediaImplCopyWithImpl(
+    _$DigitalSourceType_AlgorithmicMediaImpl _value,
+    $Res Function(_$DigitalSourceType_AlgorithmicMediaImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_AlgorithmicMediaImpl
+    extends DigitalSourceType_AlgorithmicMedia {
+  const _$DigitalSourceType_AlgorithmicMediaImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.algorithmicMedia()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_AlgorithmicMediaImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return algorithmicMedia();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return algorithmicMedia?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (algorithmicMedia != null) {
+      return algorithmicMedia();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return algorithmicMedia(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return algorithmicMedia?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (algorithmicMedia != null) {
+      return algorithmicMedia(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_AlgorithmicMedia extends DigitalSourceType {
+  const factory DigitalSourceType_AlgorithmicMedia() =
+      _$DigitalSourceType_AlgorithmicMediaImpl;
+  const DigitalSourceType_AlgorithmicMedia._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_TrainedAlgorithmicMediaImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_TrainedAlgorithmicMediaImplCopyWith(
+    _$DigitalSourceType_TrainedAlgorithmicMediaImpl value,
+    $Res Function(_$DigitalSourceType_TrainedAlgorithmicMediaImpl) then,
+  ) = __$$DigitalSourceType_TrainedAlgorithmicMediaImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_TrainedAlgorithmicMediaImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_TrainedAlgorithmicMediaImpl
+        >
+    implements _$$DigitalSourceType_TrainedAlgorithmicMediaImplCopyWith<$Res> {
+  __$$DigitalSourceType_TrainedAlgorithmicMediaImplCopyWithImpl(
+    _$DigitalSourceType_TrainedAlgorithmicMediaImpl _value,
+    $Res Function(_$DigitalSourceType_TrainedAlgorithmicMediaImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_TrainedAlgorithmicMediaImpl
+    extends DigitalSourceType_TrainedAlgorithmicMedia {
+  const _$DigitalSourceType_TrainedAlgorithmicMediaImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.trainedAlgorithmicMedia()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_TrainedAlgorithmicMediaImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return trainedAlgorithmicMedia();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return trainedAlgorithmicMedia?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (trainedAlgorithmicMedia != null) {
+      return trainedAlgorithmicMedia();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return trainedAlgorithmicMedia(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return trainedAlgorithmicMedia?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (trainedAlgorithmicMedia != null) {
+      return trainedAlgorithmicMedia(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_TrainedAlgorithmicMedia
+    extends DigitalSourceType {
+  const factory DigitalSourceType_TrainedAlgorithmicMedia() =
+      _$DigitalSourceType_TrainedAlgorithmicMediaImpl;
+  const DigitalSourceType_TrainedAlgorithmicMedia._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_CompositeImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_CompositeImplCopyWith(
+    _$DigitalSourceType_CompositeImpl value,
+    $Res Function(_$DigitalSourceType_CompositeImpl) then,
+  ) = __$$DigitalSourceType_CompositeImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_CompositeImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<$Res, _$DigitalSourceType_CompositeImpl>
+    implements _$$DigitalSourceType_CompositeImplCopyWith<$Res> {
+  __$$DigitalSourceType_CompositeImplCopyWithImpl(
+    _$DigitalSourceType_CompositeImpl _value,
+    $Res Function(_$DigitalSourceType_CompositeImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_CompositeImpl extends DigitalSourceType_Composite {
+  const _$DigitalSourceType_CompositeImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.composite()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_CompositeImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return composite();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return composite?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (composite != null) {
+      return composite();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return composite(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return composite?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (composite != null) {
+      return composite(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_Composite extends DigitalSourceType {
+  const factory DigitalSourceType_Composite() =
+      _$DigitalSourceType_CompositeImpl;
+  const DigitalSourceType_Composite._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_CompositeCaptureImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_CompositeCaptureImplCopyWith(
+    _$DigitalSourceType_CompositeCaptureImpl value,
+    $Res Function(_$DigitalSourceType_CompositeCaptureImpl) then,
+  ) = __$$DigitalSourceType_CompositeCaptureImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_CompositeCaptureImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_CompositeCaptureImpl
+        >
+    implements _$$DigitalSourceType_CompositeCaptureImplCopyWith<$Res> {
+  __$$DigitalSourceType_CompositeCaptureImplCopyWithImpl(
+    _$DigitalSourceType_CompositeCaptureImpl _value,
+    $Res Function(_$DigitalSourceType_CompositeCaptureImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_CompositeCaptureImpl
+    extends DigitalSourceType_CompositeCapture {
+  const _$DigitalSourceType_CompositeCaptureImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.compositeCapture()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_CompositeCaptureImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return compositeCapture();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return compositeCapture?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (compositeCapture != null) {
+      return compositeCapture();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return compositeCapture(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return compositeCapture?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (compositeCapture != null) {
+      return compositeCapture(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_CompositeCapture extends DigitalSourceType {
+  const factory DigitalSourceType_CompositeCapture() =
+      _$DigitalSourceType_CompositeCaptureImpl;
+  const DigitalSourceType_CompositeCapture._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_CompositeSyntheticImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_CompositeSyntheticImplCopyWith(
+    _$DigitalSourceType_CompositeSyntheticImpl value,
+    $Res Function(_$DigitalSourceType_CompositeSyntheticImpl) then,
+  ) = __$$DigitalSourceType_CompositeSyntheticImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_CompositeSyntheticImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_CompositeSyntheticImpl
+        >
+    implements _$$DigitalSourceType_CompositeSyntheticImplCopyWith<$Res> {
+  __$$DigitalSourceType_CompositeSyntheticImplCopyWithImpl(
+    _$DigitalSourceType_CompositeSyntheticImpl _value,
+    $Res Function(_$DigitalSourceType_CompositeSyntheticImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_CompositeSyntheticImpl
+    extends DigitalSourceType_CompositeSynthetic {
+  const _$DigitalSourceType_CompositeSyntheticImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.compositeSynthetic()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_CompositeSyntheticImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return compositeSynthetic();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return compositeSynthetic?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (compositeSynthetic != null) {
+      return compositeSynthetic();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return compositeSynthetic(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return compositeSynthetic?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (compositeSynthetic != null) {
+      return compositeSynthetic(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_CompositeSynthetic extends DigitalSourceType {
+  const factory DigitalSourceType_CompositeSynthetic() =
+      _$DigitalSourceType_CompositeSyntheticImpl;
+  const DigitalSourceType_CompositeSynthetic._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImplCopyWith(
+    _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl value,
+    $Res Function(_$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl) then,
+  ) = __$$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<
+          $Res,
+          _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl
+        >
+    implements _$$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImplCopyWith<$Res> {
+  __$$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImplCopyWithImpl(
+    _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl _value,
+    $Res Function(_$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl
+    extends DigitalSourceType_CompositeWithTrainedAlgorithmicMedia {
+  const _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.compositeWithTrainedAlgorithmicMedia()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return compositeWithTrainedAlgorithmicMedia();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return compositeWithTrainedAlgorithmicMedia?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (compositeWithTrainedAlgorithmicMedia != null) {
+      return compositeWithTrainedAlgorithmicMedia();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return compositeWithTrainedAlgorithmicMedia(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return compositeWithTrainedAlgorithmicMedia?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (compositeWithTrainedAlgorithmicMedia != null) {
+      return compositeWithTrainedAlgorithmicMedia(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_CompositeWithTrainedAlgorithmicMedia
+    extends DigitalSourceType {
+  const factory DigitalSourceType_CompositeWithTrainedAlgorithmicMedia() =
+      _$DigitalSourceType_CompositeWithTrainedAlgorithmicMediaImpl;
+  const DigitalSourceType_CompositeWithTrainedAlgorithmicMedia._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DigitalSourceType_UnknownImplCopyWith<$Res> {
+  factory _$$DigitalSourceType_UnknownImplCopyWith(
+    _$DigitalSourceType_UnknownImpl value,
+    $Res Function(_$DigitalSourceType_UnknownImpl) then,
+  ) = __$$DigitalSourceType_UnknownImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String uri});
+}
+
+/// @nodoc
+class __$$DigitalSourceType_UnknownImplCopyWithImpl<$Res>
+    extends
+        _$DigitalSourceTypeCopyWithImpl<$Res, _$DigitalSourceType_UnknownImpl>
+    implements _$$DigitalSourceType_UnknownImplCopyWith<$Res> {
+  __$$DigitalSourceType_UnknownImplCopyWithImpl(
+    _$DigitalSourceType_UnknownImpl _value,
+    $Res Function(_$DigitalSourceType_UnknownImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? uri = null}) {
+    return _then(
+      _$DigitalSourceType_UnknownImpl(
+        uri: null == uri
+            ? _value.uri
+            : uri // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$DigitalSourceType_UnknownImpl extends DigitalSourceType_Unknown {
+  const _$DigitalSourceType_UnknownImpl({required this.uri}) : super._();
+
+  @override
+  final String uri;
+
+  @override
+  String toString() {
+    return 'DigitalSourceType.unknown(uri: $uri)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DigitalSourceType_UnknownImpl &&
+            (identical(other.uri, uri) || other.uri == uri));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, uri);
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DigitalSourceType_UnknownImplCopyWith<_$DigitalSourceType_UnknownImpl>
+  get copyWith =>
+      __$$DigitalSourceType_UnknownImplCopyWithImpl<
+        _$DigitalSourceType_UnknownImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() digitalCapture,
+    required TResult Function() computationalCapture,
+    required TResult Function() negativeFilm,
+    required TResult Function() positiveFilm,
+    required TResult Function() print,
+    required TResult Function() screenCapture,
+    required TResult Function() virtualRecording,
+    required TResult Function() humanEdits,
+    required TResult Function() minorHumanEdits,
+    required TResult Function() algorithmicallyEnhanced,
+    required TResult Function() digitalCreation,
+    required TResult Function() dataDrivenMedia,
+    required TResult Function() algorithmicMedia,
+    required TResult Function() trainedAlgorithmicMedia,
+    required TResult Function() composite,
+    required TResult Function() compositeCapture,
+    required TResult Function() compositeSynthetic,
+    required TResult Function() compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(String uri) unknown,
+  }) {
+    return unknown(uri);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? digitalCapture,
+    TResult? Function()? computationalCapture,
+    TResult? Function()? negativeFilm,
+    TResult? Function()? positiveFilm,
+    TResult? Function()? print,
+    TResult? Function()? screenCapture,
+    TResult? Function()? virtualRecording,
+    TResult? Function()? humanEdits,
+    TResult? Function()? minorHumanEdits,
+    TResult? Function()? algorithmicallyEnhanced,
+    TResult? Function()? digitalCreation,
+    TResult? Function()? dataDrivenMedia,
+    TResult? Function()? algorithmicMedia,
+    TResult? Function()? trainedAlgorithmicMedia,
+    TResult? Function()? composite,
+    TResult? Function()? compositeCapture,
+    TResult? Function()? compositeSynthetic,
+    TResult? Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(String uri)? unknown,
+  }) {
+    return unknown?.call(uri);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? digitalCapture,
+    TResult Function()? computationalCapture,
+    TResult Function()? negativeFilm,
+    TResult Function()? positiveFilm,
+    TResult Function()? print,
+    TResult Function()? screenCapture,
+    TResult Function()? virtualRecording,
+    TResult Function()? humanEdits,
+    TResult Function()? minorHumanEdits,
+    TResult Function()? algorithmicallyEnhanced,
+    TResult Function()? digitalCreation,
+    TResult Function()? dataDrivenMedia,
+    TResult Function()? algorithmicMedia,
+    TResult Function()? trainedAlgorithmicMedia,
+    TResult Function()? composite,
+    TResult Function()? compositeCapture,
+    TResult Function()? compositeSynthetic,
+    TResult Function()? compositeWithTrainedAlgorithmicMedia,
+    TResult Function(String uri)? unknown,
+    required TResult orElse(),
+  }) {
+    if (unknown != null) {
+      return unknown(uri);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DigitalSourceType_DigitalCapture value)
+    digitalCapture,
+    required TResult Function(DigitalSourceType_ComputationalCapture value)
+    computationalCapture,
+    required TResult Function(DigitalSourceType_NegativeFilm value)
+    negativeFilm,
+    required TResult Function(DigitalSourceType_PositiveFilm value)
+    positiveFilm,
+    required TResult Function(DigitalSourceType_Print value) print,
+    required TResult Function(DigitalSourceType_ScreenCapture value)
+    screenCapture,
+    required TResult Function(DigitalSourceType_VirtualRecording value)
+    virtualRecording,
+    required TResult Function(DigitalSourceType_HumanEdits value) humanEdits,
+    required TResult Function(DigitalSourceType_MinorHumanEdits value)
+    minorHumanEdits,
+    required TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)
+    algorithmicallyEnhanced,
+    required TResult Function(DigitalSourceType_DigitalCreation value)
+    digitalCreation,
+    required TResult Function(DigitalSourceType_DataDrivenMedia value)
+    dataDrivenMedia,
+    required TResult Function(DigitalSourceType_AlgorithmicMedia value)
+    algorithmicMedia,
+    required TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)
+    trainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Composite value) composite,
+    required TResult Function(DigitalSourceType_CompositeCapture value)
+    compositeCapture,
+    required TResult Function(DigitalSourceType_CompositeSynthetic value)
+    compositeSynthetic,
+    required TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )
+    compositeWithTrainedAlgorithmicMedia,
+    required TResult Function(DigitalSourceType_Unknown value) unknown,
+  }) {
+    return unknown(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult? Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult? Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult? Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult? Function(DigitalSourceType_Print value)? print,
+    TResult? Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult? Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult? Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult? Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult? Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult? Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult? Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult? Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult? Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Composite value)? composite,
+    TResult? Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult? Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult? Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult? Function(DigitalSourceType_Unknown value)? unknown,
+  }) {
+    return unknown?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DigitalSourceType_DigitalCapture value)? digitalCapture,
+    TResult Function(DigitalSourceType_ComputationalCapture value)?
+    computationalCapture,
+    TResult Function(DigitalSourceType_NegativeFilm value)? negativeFilm,
+    TResult Function(DigitalSourceType_PositiveFilm value)? positiveFilm,
+    TResult Function(DigitalSourceType_Print value)? print,
+    TResult Function(DigitalSourceType_ScreenCapture value)? screenCapture,
+    TResult Function(DigitalSourceType_VirtualRecording value)?
+    virtualRecording,
+    TResult Function(DigitalSourceType_HumanEdits value)? humanEdits,
+    TResult Function(DigitalSourceType_MinorHumanEdits value)? minorHumanEdits,
+    TResult Function(DigitalSourceType_AlgorithmicallyEnhanced value)?
+    algorithmicallyEnhanced,
+    TResult Function(DigitalSourceType_DigitalCreation value)? digitalCreation,
+    TResult Function(DigitalSourceType_DataDrivenMedia value)? dataDrivenMedia,
+    TResult Function(DigitalSourceType_AlgorithmicMedia value)?
+    algorithmicMedia,
+    TResult Function(DigitalSourceType_TrainedAlgorithmicMedia value)?
+    trainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Composite value)? composite,
+    TResult Function(DigitalSourceType_CompositeCapture value)?
+    compositeCapture,
+    TResult Function(DigitalSourceType_CompositeSynthetic value)?
+    compositeSynthetic,
+    TResult Function(
+      DigitalSourceType_CompositeWithTrainedAlgorithmicMedia value,
+    )?
+    compositeWithTrainedAlgorithmicMedia,
+    TResult Function(DigitalSourceType_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (unknown != null) {
+      return unknown(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DigitalSourceType_Unknown extends DigitalSourceType {
+  const factory DigitalSourceType_Unknown({required final String uri}) =
+      _$DigitalSourceType_UnknownImpl;
+  const DigitalSourceType_Unknown._() : super._();
+
+  String get uri;
+
+  /// Create a copy of DigitalSourceType
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DigitalSourceType_UnknownImplCopyWith<_$DigitalSourceType_UnknownImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `attribute_name_for_oid`, `attribute_starts_at`, `canonical_attribute_type`, `decode_hex_value`, `escape_rfc4514_value`, `first`, `format_rfc4514`, `from_attributes`, `is_separator_at`, `new`, `parse`, `peek`, `push_char`, `read_escape`, `read_plain_value`, `read_quoted_value`, `read_type`, `read_value`, `skip_spaces`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// Parse a distinguished name string.
+///
+/// Accepts RFC 4514 / RFC 2253 strings ("CN=Name,O=Org,C=US"), the RFC 1779
+/// variants with spaces, semicolons and quoted values, and OpenSSL's
+/// one-line form ("/C=US/O=Org/CN=Name"). Returns `None` if the string is
+/// not a DN at all, e.g. a bare organization name.
+DistinguishedName? parseDistinguishedName({required String dn}) => RustLib
+    .instance
+    .api
+    .crateApiDistinguishedNameParseDistinguishedName(dn: dn);
+
+/// An X.500 distinguished name, with attributes in ASN.1 order (most significant first)
+class DistinguishedName {
+  final List<DnAttribute> attributes;
+  /// RFC 4514 string form, least significant RDN first
+  final String rfc4514;
+
+  const DistinguishedName({required this.attributes, required this.rfc4514});
+
+  @override
+  int get hashCode => attributes.hashCode ^ rfc4514.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DistinguishedName &&
+          runtimeType == other.runtimeType &&
+          attributes == other.attributes &&
+          rfc4514 == other.rfc4514;
+}
+
+/// A single attribute of an X.500 distinguished name
+class DnAttribute {
+  /// Short name such as "CN" or "O", or the dotted OID when the type is not known
+  final String attrType;
+  final String oid;
+  final String value;
+  /// Index of the RDN this attribute belongs to; multi-valued RDNs share an index
+  final int rdnIndex;
+
+  const DnAttribute({
+    required this.attrType,
+    required this.oid,
+    required this.value,
+    required this.rdnIndex,
+  });
+
+  @override
+  int get hashCode =>
+      attrType.hashCode ^ oid.hashCode ^ value.hashCode ^ rdnIndex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DnAttribute &&
+          runtimeType == other.runtimeType &&
+          attrType == other.attrType &&
+          oid == other.oid &&
+          value == other.value &&
+          rdnIndex == other.rdnIndex;
+}
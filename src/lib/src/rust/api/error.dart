@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `from_c2pa`, `io`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`, `fmt`
+
+@freezed
+sealed class AnalysisError with _$AnalysisError {
+  const AnalysisError._();
+
+  /// The asset could not be opened or read
+  const factory AnalysisError.io({required String message}) = AnalysisError_Io;
+  /// The C2PA SDK cannot read manifests from this type of asset
+  const factory AnalysisError.unsupportedFormat({required String mimeType}) =
+      AnalysisError_UnsupportedFormat;
+  /// The asset carries no C2PA manifest
+  const factory AnalysisError.manifestNotFound() =
+      AnalysisError_ManifestNotFound;
+  /// A manifest store is present but its JUMBF or claim structure is broken
+  const factory AnalysisError.manifestCorrupt({required String detail}) =
+      AnalysisError_ManifestCorrupt;
+  /// A claim or assertion is not valid CBOR
+  const factory AnalysisError.cborDecode({required String detail}) =
+      AnalysisError_CborDecode;
+  /// The manifest is stored remotely and could not be fetched; analysis never goes online
+  const factory AnalysisError.remoteManifestUnavailable({required String url}) =
+      AnalysisError_RemoteManifestUnavailable;
+  /// Any other failure reported by the C2PA SDK
+  const factory AnalysisError.other({required String message}) =
+      AnalysisError_Other;
+}
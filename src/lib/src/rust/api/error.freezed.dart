@@ -0,0 +1,1382 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$AnalysisError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AnalysisErrorCopyWith<$Res> {
+  factory $AnalysisErrorCopyWith(
+    AnalysisError value,
+    $Res Function(AnalysisError) then,
+  ) = _$AnalysisErrorCopyWithImpl<$Res, AnalysisError>;
+}
+
+/// @nodoc
+class _$AnalysisErrorCopyWithImpl<$Res, $Val extends AnalysisError>
+    implements $AnalysisErrorCopyWith<$Res> {
+  _$AnalysisErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_IoImplCopyWith<$Res> {
+  factory _$$AnalysisError_IoImplCopyWith(
+    _$AnalysisError_IoImpl value,
+    $Res Function(_$AnalysisError_IoImpl) then,
+  ) = __$$AnalysisError_IoImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$AnalysisError_IoImplCopyWithImpl<$Res>
+    extends _$AnalysisErrorCopyWithImpl<$Res, _$AnalysisError_IoImpl>
+    implements _$$AnalysisError_IoImplCopyWith<$Res> {
+  __$$AnalysisError_IoImplCopyWithImpl(
+    _$AnalysisError_IoImpl _value,
+    $Res Function(_$AnalysisError_IoImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? message = null}) {
+    return _then(
+      _$AnalysisError_IoImpl(
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$AnalysisError_IoImpl extends AnalysisError_Io {
+  const _$AnalysisError_IoImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'AnalysisError.io(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_IoImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AnalysisError_IoImplCopyWith<_$AnalysisError_IoImpl> get copyWith =>
+      __$$AnalysisError_IoImplCopyWithImpl<
+        _$AnalysisError_IoImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return io(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return io?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return io(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return io?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_Io extends AnalysisError {
+  const factory AnalysisError_Io({required final String message}) =
+      _$AnalysisError_IoImpl;
+  const AnalysisError_Io._() : super._();
+
+  String get message;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AnalysisError_IoImplCopyWith<_$AnalysisError_IoImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_UnsupportedFormatImplCopyWith<$Res> {
+  factory _$$AnalysisError_UnsupportedFormatImplCopyWith(
+    _$AnalysisError_UnsupportedFormatImpl value,
+    $Res Function(_$AnalysisError_UnsupportedFormatImpl) then,
+  ) = __$$AnalysisError_UnsupportedFormatImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String mimeType});
+}
+
+/// @nodoc
+class __$$AnalysisError_UnsupportedFormatImplCopyWithImpl<$Res>
+    extends
+        _$AnalysisErrorCopyWithImpl<$Res, _$AnalysisError_UnsupportedFormatImpl>
+    implements _$$AnalysisError_UnsupportedFormatImplCopyWith<$Res> {
+  __$$AnalysisError_UnsupportedFormatImplCopyWithImpl(
+    _$AnalysisError_UnsupportedFormatImpl _value,
+    $Res Function(_$AnalysisError_UnsupportedFormatImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? mimeType = null}) {
+    return _then(
+      _$AnalysisError_UnsupportedFormatImpl(
+        mimeType: null == mimeType
+            ? _value.mimeType
+            : mimeType // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$AnalysisError_UnsupportedFormatImpl
+    extends AnalysisError_UnsupportedFormat {
+  const _$AnalysisError_UnsupportedFormatImpl({
+    required this.mimeType,
+  }) : super._();
+
+  @override
+  final String mimeType;
+
+  @override
+  String toString() {
+    return 'AnalysisError.unsupportedFormat(mimeType: $mimeType)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_UnsupportedFormatImpl &&
+            (identical(other.mimeType, mimeType) ||
+                other.mimeType == mimeType));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, mimeType);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AnalysisError_UnsupportedFormatImplCopyWith<
+    _$AnalysisError_UnsupportedFormatImpl
+  >
+  get copyWith =>
+      __$$AnalysisError_UnsupportedFormatImplCopyWithImpl<
+        _$AnalysisError_UnsupportedFormatImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return unsupportedFormat(mimeType);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return unsupportedFormat?.call(mimeType);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (unsupportedFormat != null) {
+      return unsupportedFormat(mimeType);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return unsupportedFormat(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return unsupportedFormat?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unsupportedFormat != null) {
+      return unsupportedFormat(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_UnsupportedFormat extends AnalysisError {
+  const factory AnalysisError_UnsupportedFormat({
+    required final String mimeType,
+  }) = _$AnalysisError_UnsupportedFormatImpl;
+  const AnalysisError_UnsupportedFormat._() : super._();
+
+  String get mimeType;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AnalysisError_UnsupportedFormatImplCopyWith<
+    _$AnalysisError_UnsupportedFormatImpl
+  >
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_ManifestNotFoundImplCopyWith<$Res> {
+  factory _$$AnalysisError_ManifestNotFoundImplCopyWith(
+    _$AnalysisError_ManifestNotFoundImpl value,
+    $Res Function(_$AnalysisError_ManifestNotFoundImpl) then,
+  ) = __$$AnalysisError_ManifestNotFoundImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$AnalysisError_ManifestNotFoundImplCopyWithImpl<$Res>
+    extends
+        _$AnalysisErrorCopyWithImpl<$Res, _$AnalysisError_ManifestNotFoundImpl>
+    implements _$$AnalysisError_ManifestNotFoundImplCopyWith<$Res> {
+  __$$AnalysisError_ManifestNotFoundImplCopyWithImpl(
+    _$AnalysisError_ManifestNotFoundImpl _value,
+    $Res Function(_$AnalysisError_ManifestNotFoundImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$AnalysisError_ManifestNotFoundImpl
+    extends AnalysisError_ManifestNotFound {
+  const _$AnalysisError_ManifestNotFoundImpl() : super._();
+
+  @override
+  String toString() {
+    return 'AnalysisError.manifestNotFound()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_ManifestNotFoundImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return manifestNotFound();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return manifestNotFound?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (manifestNotFound != null) {
+      return manifestNotFound();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return manifestNotFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return manifestNotFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (manifestNotFound != null) {
+      return manifestNotFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_ManifestNotFound extends AnalysisError {
+  const factory AnalysisError_ManifestNotFound() =
+      _$AnalysisError_ManifestNotFoundImpl;
+  const AnalysisError_ManifestNotFound._() : super._();
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_ManifestCorruptImplCopyWith<$Res> {
+  factory _$$AnalysisError_ManifestCorruptImplCopyWith(
+    _$AnalysisError_ManifestCorruptImpl value,
+    $Res Function(_$AnalysisError_ManifestCorruptImpl) then,
+  ) = __$$AnalysisError_ManifestCorruptImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$AnalysisError_ManifestCorruptImplCopyWithImpl<$Res>
+    extends
+        _$AnalysisErrorCopyWithImpl<$Res, _$AnalysisError_ManifestCorruptImpl>
+    implements _$$AnalysisError_ManifestCorruptImplCopyWith<$Res> {
+  __$$AnalysisError_ManifestCorruptImplCopyWithImpl(
+    _$AnalysisError_ManifestCorruptImpl _value,
+    $Res Function(_$AnalysisError_ManifestCorruptImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? detail = null}) {
+    return _then(
+      _$AnalysisError_ManifestCorruptImpl(
+        detail: null == detail
+            ? _value.detail
+            : detail // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$AnalysisError_ManifestCorruptImpl
+    extends AnalysisError_ManifestCorrupt {
+  const _$AnalysisError_ManifestCorruptImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'AnalysisError.manifestCorrupt(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_ManifestCorruptImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AnalysisError_ManifestCorruptImplCopyWith<
+    _$AnalysisError_ManifestCorruptImpl
+  >
+  get copyWith =>
+      __$$AnalysisError_ManifestCorruptImplCopyWithImpl<
+        _$AnalysisError_ManifestCorruptImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return manifestCorrupt(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return manifestCorrupt?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (manifestCorrupt != null) {
+      return manifestCorrupt(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return manifestCorrupt(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return manifestCorrupt?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (manifestCorrupt != null) {
+      return manifestCorrupt(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_ManifestCorrupt extends AnalysisError {
+  const factory AnalysisError_ManifestCorrupt({required final String detail}) =
+      _$AnalysisError_ManifestCorruptImpl;
+  const AnalysisError_ManifestCorrupt._() : super._();
+
+  String get detail;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AnalysisError_ManifestCorruptImplCopyWith<
+    _$AnalysisError_ManifestCorruptImpl
+  >
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_CborDecodeImplCopyWith<$Res> {
+  factory _$$AnalysisError_CborDecodeImplCopyWith(
+    _$AnalysisError_CborDecodeImpl value,
+    $Res Function(_$AnalysisError_CborDecodeImpl) then,
+  ) = __$$AnalysisError_CborDecodeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$AnalysisError_CborDecodeImplCopyWithImpl<$Res>
+    extends _$AnalysisErrorCopyWithImpl<$Res, _$AnalysisError_CborDecodeImpl>
+    implements _$$AnalysisError_CborDecodeImplCopyWith<$Res> {
+  __$$AnalysisError_CborDecodeImplCopyWithImpl(
+    _$AnalysisError_CborDecodeImpl _value,
+    $Res Function(_$AnalysisError_CborDecodeImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? detail = null}) {
+    return _then(
+      _$AnalysisError_CborDecodeImpl(
+        detail: null == detail
+            ? _value.detail
+            : detail // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$AnalysisError_CborDecodeImpl extends AnalysisError_CborDecode {
+  const _$AnalysisError_CborDecodeImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'AnalysisError.cborDecode(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_CborDecodeImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AnalysisError_CborDecodeImplCopyWith<_$AnalysisError_CborDecodeImpl>
+  get copyWith =>
+      __$$AnalysisError_CborDecodeImplCopyWithImpl<
+        _$AnalysisError_CborDecodeImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return cborDecode(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return cborDecode?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (cborDecode != null) {
+      return cborDecode(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return cborDecode(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return cborDecode?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (cborDecode != null) {
+      return cborDecode(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_CborDecode extends AnalysisError {
+  const factory AnalysisError_CborDecode({required final String detail}) =
+      _$AnalysisError_CborDecodeImpl;
+  const AnalysisError_CborDecode._() : super._();
+
+  String get detail;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AnalysisError_CborDecodeImplCopyWith<_$AnalysisError_CborDecodeImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_RemoteManifestUnavailableImplCopyWith<$Res> {
+  factory _$$AnalysisError_RemoteManifestUnavailableImplCopyWith(
+    _$AnalysisError_RemoteManifestUnavailableImpl value,
+    $Res Function(_$AnalysisError_RemoteManifestUnavailableImpl) then,
+  ) = __$$AnalysisError_RemoteManifestUnavailableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$AnalysisError_RemoteManifestUnavailableImplCopyWithImpl<$Res>
+    extends
+        _$AnalysisErrorCopyWithImpl<
+          $Res,
+          _$AnalysisError_RemoteManifestUnavailableImpl
+        >
+    implements _$$AnalysisError_RemoteManifestUnavailableImplCopyWith<$Res> {
+  __$$AnalysisError_RemoteManifestUnavailableImplCopyWithImpl(
+    _$AnalysisError_RemoteManifestUnavailableImpl _value,
+    $Res Function(_$AnalysisError_RemoteManifestUnavailableImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? url = null}) {
+    return _then(
+      _$AnalysisError_RemoteManifestUnavailableImpl(
+        url: null == url
+            ? _value.url
+            : url // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$AnalysisError_RemoteManifestUnavailableImpl
+    extends AnalysisError_RemoteManifestUnavailable {
+  const _$AnalysisError_RemoteManifestUnavailableImpl({
+    required this.url,
+  }) : super._();
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'AnalysisError.remoteManifestUnavailable(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_RemoteManifestUnavailableImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AnalysisError_RemoteManifestUnavailableImplCopyWith<
+    _$AnalysisError_RemoteManifestUnavailableImpl
+  >
+  get copyWith =>
+      __$$AnalysisError_RemoteManifestUnavailableImplCopyWithImpl<
+        _$AnalysisError_RemoteManifestUnavailableImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return remoteManifestUnavailable(url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return remoteManifestUnavailable?.call(url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (remoteManifestUnavailable != null) {
+      return remoteManifestUnavailable(url);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return remoteManifestUnavailable(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return remoteManifestUnavailable?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (remoteManifestUnavailable != null) {
+      return remoteManifestUnavailable(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_RemoteManifestUnavailable extends AnalysisError {
+  const factory AnalysisError_RemoteManifestUnavailable({
+    required final String url,
+  }) = _$AnalysisError_RemoteManifestUnavailableImpl;
+  const AnalysisError_RemoteManifestUnavailable._() : super._();
+
+  String get url;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AnalysisError_RemoteManifestUnavailableImplCopyWith<
+    _$AnalysisError_RemoteManifestUnavailableImpl
+  >
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AnalysisError_OtherImplCopyWith<$Res> {
+  factory _$$AnalysisError_OtherImplCopyWith(
+    _$AnalysisError_OtherImpl value,
+    $Res Function(_$AnalysisError_OtherImpl) then,
+  ) = __$$AnalysisError_OtherImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$AnalysisError_OtherImplCopyWithImpl<$Res>
+    extends _$AnalysisErrorCopyWithImpl<$Res, _$AnalysisError_OtherImpl>
+    implements _$$AnalysisError_OtherImplCopyWith<$Res> {
+  __$$AnalysisError_OtherImplCopyWithImpl(
+    _$AnalysisError_OtherImpl _value,
+    $Res Function(_$AnalysisError_OtherImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? message = null}) {
+    return _then(
+      _$AnalysisError_OtherImpl(
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$AnalysisError_OtherImpl extends AnalysisError_Other {
+  const _$AnalysisError_OtherImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'AnalysisError.other(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AnalysisError_OtherImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AnalysisError_OtherImplCopyWith<_$AnalysisError_OtherImpl> get copyWith =>
+      __$$AnalysisError_OtherImplCopyWithImpl<
+        _$AnalysisError_OtherImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) io,
+    required TResult Function(String mimeType) unsupportedFormat,
+    required TResult Function() manifestNotFound,
+    required TResult Function(String detail) manifestCorrupt,
+    required TResult Function(String detail) cborDecode,
+    required TResult Function(String url) remoteManifestUnavailable,
+    required TResult Function(String message) other,
+  }) {
+    return other(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? io,
+    TResult? Function(String mimeType)? unsupportedFormat,
+    TResult? Function()? manifestNotFound,
+    TResult? Function(String detail)? manifestCorrupt,
+    TResult? Function(String detail)? cborDecode,
+    TResult? Function(String url)? remoteManifestUnavailable,
+    TResult? Function(String message)? other,
+  }) {
+    return other?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? io,
+    TResult Function(String mimeType)? unsupportedFormat,
+    TResult Function()? manifestNotFound,
+    TResult Function(String detail)? manifestCorrupt,
+    TResult Function(String detail)? cborDecode,
+    TResult Function(String url)? remoteManifestUnavailable,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AnalysisError_Io value) io,
+    required TResult Function(AnalysisError_UnsupportedFormat value)
+    unsupportedFormat,
+    required TResult Function(AnalysisError_ManifestNotFound value)
+    manifestNotFound,
+    required TResult Function(AnalysisError_ManifestCorrupt value)
+    manifestCorrupt,
+    required TResult Function(AnalysisError_CborDecode value) cborDecode,
+    required TResult Function(AnalysisError_RemoteManifestUnavailable value)
+    remoteManifestUnavailable,
+    required TResult Function(AnalysisError_Other value) other,
+  }) {
+    return other(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AnalysisError_Io value)? io,
+    TResult? Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult? Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult? Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult? Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult? Function(AnalysisError_Other value)? other,
+  }) {
+    return other?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AnalysisError_Io value)? io,
+    TResult Function(AnalysisError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(AnalysisError_ManifestNotFound value)? manifestNotFound,
+    TResult Function(AnalysisError_ManifestCorrupt value)? manifestCorrupt,
+    TResult Function(AnalysisError_CborDecode value)? cborDecode,
+    TResult Function(AnalysisError_RemoteManifestUnavailable value)?
+    remoteManifestUnavailable,
+    TResult Function(AnalysisError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AnalysisError_Other extends AnalysisError {
+  const factory AnalysisError_Other({required final String message}) =
+      _$AnalysisError_OtherImpl;
+  const AnalysisError_Other._() : super._();
+
+  String get message;
+
+  /// Create a copy of AnalysisError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AnalysisError_OtherImplCopyWith<_$AnalysisError_OtherImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
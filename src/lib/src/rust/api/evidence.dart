@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `contains_word`, `find_word`, `is_ai`, `manifest_fields`, `push_key`, `push_text`, `source`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+/// One reason an asset was, or could be, flagged as AI-generated, or a
+/// sign that it is a camera original
+class Evidence {
+  final EvidenceDetector detector;
+  final EvidenceKind kind;
+  /// Where the match was found: a JSON path into the manifest store such
+  /// as `$.manifests["urn:uuid:…"].claim_generator`, an EXIF tag name, a
+  /// PNG text chunk keyword or an XMP property such as `xmp:CreatorTool`
+  final String field;
+  /// The text that matched
+  final String matchedText;
+  final EvidenceStrength strength;
+  /// Human-readable reason for reviewers
+  final String explanation;
+  /// The generator this evidence names, when it names one
+  final String? generator;
+
+  const Evidence({
+    required this.detector,
+    required this.kind,
+    required this.field,
+    required this.matchedText,
+    required this.strength,
+    required this.explanation,
+    this.generator,
+  });
+
+  @override
+  int get hashCode =>
+      detector.hashCode ^
+      kind.hashCode ^
+      field.hashCode ^
+      matchedText.hashCode ^
+      strength.hashCode ^
+      explanation.hashCode ^
+      generator.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Evidence &&
+          runtimeType == other.runtimeType &&
+          detector == other.detector &&
+          kind == other.kind &&
+          field == other.field &&
+          matchedText == other.matchedText &&
+          strength == other.strength &&
+          explanation == other.explanation &&
+          generator == other.generator;
+}
+
+/// Which detector produced a piece of evidence
+enum EvidenceDetector {
+  /// A `c2pa.actions` entry: its software agent or description
+  c2paAction,
+  /// The manifest's claim generator
+  c2paClaimGenerator,
+  /// Any other part of the manifest store JSON
+  c2paManifest,
+  /// EXIF text fields
+  exif,
+  /// Generation parameters in PNG text chunks
+  pngText,
+  /// XMP properties
+  xmp,
+  ;
+}
+
+/// What a piece of evidence points towards
+enum EvidenceKind {
+  /// The content was generated by an AI model
+  aiGeneration,
+  /// Existing content was edited or composited with AI
+  aiEditing,
+  /// The content was captured by a camera; counts against AI generation
+  cameraCapture,
+  ;
+}
+
+/// How much a piece of evidence on its own says about AI generation
+enum EvidenceStrength {
+  /// A label or keyword that often, but not always, accompanies AI content
+  weak,
+  /// A known AI tool is named as the producer
+  strong,
+  /// A signed, standardized declaration of AI generation
+  definitive,
+  ;
+}
@@ -0,0 +1,226 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `all_tags`, `coordinate`, `dashed_date`, `display`, `exif_text`, `exposure_info`, `field_text`, `gps_info`, `gps_timestamp`, `image_size`, `lens_info`, `maker_note`, `number_at`, `number`, `thumbnail`, `uint`, `user_comment`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `default`, `default`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// One EXIF field as text
+class ExifTag {
+  /// 0 for the primary image, 1 for the thumbnail
+  final int ifd;
+  /// "Tiff", "Exif", "Gps" or "Interop"
+  final String group;
+  final int id;
+  /// The tag's EXIF name, or its id in hex ("0xc4a5") when unknown
+  final String name;
+  final String value;
+
+  const ExifTag({
+    required this.ifd,
+    required this.group,
+    required this.id,
+    required this.name,
+    required this.value,
+  });
+
+  @override
+  int get hashCode =>
+      ifd.hashCode ^
+      group.hashCode ^
+      id.hashCode ^
+      name.hashCode ^
+      value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExifTag &&
+          runtimeType == other.runtimeType &&
+          ifd == other.ifd &&
+          group == other.group &&
+          id == other.id &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// The JPEG thumbnail in IFD1
+class ExifThumbnail {
+  final String mimeType;
+  /// Read from the JPEG itself, not IFD1's tags, which writers rarely set
+  final int? width;
+  final int? height;
+  final Uint8List data;
+
+  const ExifThumbnail({
+    required this.mimeType,
+    this.width,
+    this.height,
+    required this.data,
+  });
+
+  @override
+  int get hashCode =>
+      mimeType.hashCode ^ width.hashCode ^ height.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExifThumbnail &&
+          runtimeType == other.runtimeType &&
+          mimeType == other.mimeType &&
+          width == other.width &&
+          height == other.height &&
+          data == other.data;
+}
+
+/// Exposure settings, from the EXIF IFD
+class ExposureInfo {
+  /// Seconds
+  final double? exposureTime;
+  final double? fNumber;
+  /// PhotographicSensitivity
+  final int? iso;
+  /// Exposure compensation in EV
+  final double? exposureBias;
+  /// e.g. "manual" or "aperture priority"
+  final String? exposureProgram;
+  final String? meteringMode;
+  /// e.g. "fired, no return light detection function"
+  final String? flash;
+  final String? whiteBalance;
+
+  const ExposureInfo({
+    this.exposureTime,
+    this.fNumber,
+    this.iso,
+    this.exposureBias,
+    this.exposureProgram,
+    this.meteringMode,
+    this.flash,
+    this.whiteBalance,
+  });
+
+  @override
+  int get hashCode =>
+      exposureTime.hashCode ^
+      fNumber.hashCode ^
+      iso.hashCode ^
+      exposureBias.hashCode ^
+      exposureProgram.hashCode ^
+      meteringMode.hashCode ^
+      flash.hashCode ^
+      whiteBalance.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExposureInfo &&
+          runtimeType == other.runtimeType &&
+          exposureTime == other.exposureTime &&
+          fNumber == other.fNumber &&
+          iso == other.iso &&
+          exposureBias == other.exposureBias &&
+          exposureProgram == other.exposureProgram &&
+          meteringMode == other.meteringMode &&
+          flash == other.flash &&
+          whiteBalance == other.whiteBalance;
+}
+
+/// Where and how the camera was pointed, from the GPS IFD
+class GpsInfo {
+  /// Decimal degrees, negative south of the equator
+  final double? latitude;
+  /// Decimal degrees, negative west of Greenwich
+  final double? longitude;
+  /// Metres, negative below sea level
+  final double? altitude;
+  /// GPSDateStamp and GPSTimeStamp, in UTC, e.g. "2024-03-02T13:11:05Z"
+  final String? timestamp;
+  /// Direction the camera faced, in degrees
+  final double? imageDirection;
+  /// "T" for true north, "M" for magnetic
+  final String? imageDirectionRef;
+  /// Geodetic datum, usually "WGS-84"
+  final String? mapDatum;
+
+  const GpsInfo({
+    this.latitude,
+    this.longitude,
+    this.altitude,
+    this.timestamp,
+    this.imageDirection,
+    this.imageDirectionRef,
+    this.mapDatum,
+  });
+
+  @override
+  int get hashCode =>
+      latitude.hashCode ^
+      longitude.hashCode ^
+      altitude.hashCode ^
+      timestamp.hashCode ^
+      imageDirection.hashCode ^
+      imageDirectionRef.hashCode ^
+      mapDatum.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GpsInfo &&
+          runtimeType == other.runtimeType &&
+          latitude == other.latitude &&
+          longitude == other.longitude &&
+          altitude == other.altitude &&
+          timestamp == other.timestamp &&
+          imageDirection == other.imageDirection &&
+          imageDirectionRef == other.imageDirectionRef &&
+          mapDatum == other.mapDatum;
+}
+
+/// The lens, from the EXIF IFD
+class LensInfo {
+  final String? make;
+  final String? model;
+  final String? serialNumber;
+  /// LensSpecification: shortest and longest focal length in mm, then the
+  /// widest aperture at each
+  final Float64List specification;
+  /// Focal length in mm
+  final double? focalLength;
+  final int? focalLength35Mm;
+
+  const LensInfo({
+    this.make,
+    this.model,
+    this.serialNumber,
+    required this.specification,
+    this.focalLength,
+    this.focalLength35Mm,
+  });
+
+  @override
+  int get hashCode =>
+      make.hashCode ^
+      model.hashCode ^
+      serialNumber.hashCode ^
+      specification.hashCode ^
+      focalLength.hashCode ^
+      focalLength35Mm.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LensInfo &&
+          runtimeType == other.runtimeType &&
+          make == other.make &&
+          model == other.model &&
+          serialNumber == other.serialNumber &&
+          specification == other.specification &&
+          focalLength == other.focalLength &&
+          focalLength35Mm == other.focalLength35Mm;
+}
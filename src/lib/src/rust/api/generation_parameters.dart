@@ -0,0 +1,147 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `class_type`, `comfy_text`, `generation_evidence`, `generation_parameters`, `has_settings`, `invoke_model_name`, `json_f64`, `json_text`, `json_u32`, `json_u64`, `name`, `new`, `non_empty`, `parse_a1111`, `parse_comfyui_prompt`, `parse_comfyui_workflow`, `parse_comfyui`, `parse_fooocus`, `parse_invokeai`, `parse_lora`, `parse_novelai`, `parse_size`, `prompt_loras`, `split_settings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Typed generation parameters read from PNG text chunks
+class GenerationParameters {
+  final GenerationTool tool;
+  /// Tool version, e.g. "v1.7.0"
+  final String? toolVersion;
+  final String? prompt;
+  final String? negativePrompt;
+  final int? steps;
+  final String? sampler;
+  final String? scheduler;
+  final double? cfgScale;
+  final BigInt? seed;
+  final int? width;
+  final int? height;
+  /// Checkpoint name, e.g. "sd_xl_base_1.0.safetensors"
+  final String? model;
+  final String? modelHash;
+  final List<LoraReference> loras;
+  /// The ComfyUI or InvokeAI node graph, as JSON
+  final String? workflow;
+  final List<GenerationSetting> otherSettings;
+  /// Keywords of the chunks the parameters were read from
+  final List<String> sourceChunks;
+
+  const GenerationParameters({
+    required this.tool,
+    this.toolVersion,
+    this.prompt,
+    this.negativePrompt,
+    this.steps,
+    this.sampler,
+    this.scheduler,
+    this.cfgScale,
+    this.seed,
+    this.width,
+    this.height,
+    this.model,
+    this.modelHash,
+    required this.loras,
+    this.workflow,
+    required this.otherSettings,
+    required this.sourceChunks,
+  });
+
+  @override
+  int get hashCode =>
+      tool.hashCode ^
+      toolVersion.hashCode ^
+      prompt.hashCode ^
+      negativePrompt.hashCode ^
+      steps.hashCode ^
+      sampler.hashCode ^
+      scheduler.hashCode ^
+      cfgScale.hashCode ^
+      seed.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      model.hashCode ^
+      modelHash.hashCode ^
+      loras.hashCode ^
+      workflow.hashCode ^
+      otherSettings.hashCode ^
+      sourceChunks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationParameters &&
+          runtimeType == other.runtimeType &&
+          tool == other.tool &&
+          toolVersion == other.toolVersion &&
+          prompt == other.prompt &&
+          negativePrompt == other.negativePrompt &&
+          steps == other.steps &&
+          sampler == other.sampler &&
+          scheduler == other.scheduler &&
+          cfgScale == other.cfgScale &&
+          seed == other.seed &&
+          width == other.width &&
+          height == other.height &&
+          model == other.model &&
+          modelHash == other.modelHash &&
+          loras == other.loras &&
+          workflow == other.workflow &&
+          otherSettings == other.otherSettings &&
+          sourceChunks == other.sourceChunks;
+}
+
+/// A setting without a dedicated field, e.g. "Denoising strength"
+class GenerationSetting {
+  final String key;
+  final String value;
+
+  const GenerationSetting({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationSetting &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// The front end that wrote the parameters
+enum GenerationTool {
+  /// AUTOMATIC1111 web UI and forks that share its format
+  automatic1111,
+  comfyUi,
+  fooocus,
+  invokeAi,
+  novelAi,
+  ;
+}
+
+/// A LoRA applied during generation
+class LoraReference {
+  final String name;
+  final double? weight;
+
+  const LoraReference({required this.name, this.weight});
+
+  @override
+  int get hashCode => name.hashCode ^ weight.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoraReference &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          weight == other.weight;
+}
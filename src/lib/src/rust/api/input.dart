@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `manifest_reader`, `mime_type_from_extension`, `mime_type`, `new`, `new`, `new`, `new`, `open`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>
+/// An asset streamed from Dart in chunks, e.g. from a file picker or network
+/// download, without first concatenating it into one buffer
+abstract class ChunkedAsset implements RustOpaqueInterface {
+  /// Append the next chunk of the asset
+  void append({required List<int> chunk});
+
+  bool isEmpty();
+
+  /// Total number of bytes received so far
+  BigInt len();
+
+  factory ChunkedAsset({required String mimeType}) =>
+      RustLib.instance.api.crateApiInputChunkedAssetNew(mimeType: mimeType);
+}
@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decode`, `iim_datasets`, `irb_iptc`, `parse_iim`, `read_iptc`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `eq`, `fmt`
+
+/// IPTC-IIM Application Record (record 2) fields
+class IptcInfo {
+  /// 2:05 Object Name
+  final String? objectName;
+  /// 2:105 Headline
+  final String? headline;
+  /// 2:120 Caption/Abstract
+  final String? caption;
+  /// 2:122 Writer/Editor of the caption
+  final String? captionWriter;
+  /// 2:25 Keywords
+  final List<String> keywords;
+  /// 2:80 By-line: the photographers or creators
+  final List<String> byLine;
+  /// 2:85 By-line Title, e.g. "Staff Photographer"
+  final String? byLineTitle;
+  /// 2:110 Credit: who should be credited, usually the agency
+  final String? credit;
+  /// 2:115 Source: the original owner of the content
+  final String? source;
+  /// 2:116 Copyright Notice
+  final String? copyrightNotice;
+  /// 2:40 Special Instructions, e.g. embargoes or usage restrictions
+  final String? specialInstructions;
+  /// 2:55 Date Created with 2:60 Time Created, as written
+  /// ("20240302", "20240302T141105+0100")
+  final String? dateCreated;
+  /// 2:90 City
+  final String? city;
+  /// 2:92 Sub-location
+  final String? subLocation;
+  /// 2:95 Province/State
+  final String? provinceState;
+  /// 2:100 Country/Primary Location Code
+  final String? countryCode;
+  /// 2:101 Country/Primary Location Name
+  final String? country;
+  /// 2:103 Original Transmission Reference
+  final String? transmissionReference;
+
+  const IptcInfo({
+    this.objectName,
+    this.headline,
+    this.caption,
+    this.captionWriter,
+    required this.keywords,
+    required this.byLine,
+    this.byLineTitle,
+    this.credit,
+    this.source,
+    this.copyrightNotice,
+    this.specialInstructions,
+    this.dateCreated,
+    this.city,
+    this.subLocation,
+    this.provinceState,
+    this.countryCode,
+    this.country,
+    this.transmissionReference,
+  });
+
+  @override
+  int get hashCode =>
+      objectName.hashCode ^
+      headline.hashCode ^
+      caption.hashCode ^
+      captionWriter.hashCode ^
+      keywords.hashCode ^
+      byLine.hashCode ^
+      byLineTitle.hashCode ^
+      credit.hashCode ^
+      source.hashCode ^
+      copyrightNotice.hashCode ^
+      specialInstructions.hashCode ^
+      dateCreated.hashCode ^
+      city.hashCode ^
+      subLocation.hashCode ^
+      provinceState.hashCode ^
+      countryCode.hashCode ^
+      country.hashCode ^
+      transmissionReference.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IptcInfo &&
+          runtimeType == other.runtimeType &&
+          objectName == other.objectName &&
+          headline == other.headline &&
+          caption == other.caption &&
+          captionWriter == other.captionWriter &&
+          keywords == other.keywords &&
+          byLine == other.byLine &&
+          byLineTitle == other.byLineTitle &&
+          credit == other.credit &&
+          source == other.source &&
+          copyrightNotice == other.copyrightNotice &&
+          specialInstructions == other.specialInstructions &&
+          dateCreated == other.dateCreated &&
+          city == other.city &&
+          subLocation == other.subLocation &&
+          provinceState == other.provinceState &&
+          countryCode == other.countryCode &&
+          country == other.country &&
+          transmissionReference == other.transmissionReference;
+}
@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apple_tag`, `canon_tag`, `entries`, `nikon_tag`, `parse_maker_note`, `render`, `sony_tag`, `type_size`, `u16_at_slice`, `u16_at`, `u32_at_slice`, `u32_at`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// A decoded vendor MakerNote
+class MakerNote {
+  /// "Canon", "Nikon", "Sony" or "Apple"
+  final String vendor;
+  /// The camera body's serial number as the vendor records it
+  final String? serialNumber;
+  final String? lensModel;
+  final String? firmwareVersion;
+  /// Nikon's shutter actuation count
+  final int? shutterCount;
+  /// Every entry in the directory, in order
+  final List<MakerNoteTag> tags;
+
+  const MakerNote({
+    required this.vendor,
+    this.serialNumber,
+    this.lensModel,
+    this.firmwareVersion,
+    this.shutterCount,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode =>
+      vendor.hashCode ^
+      serialNumber.hashCode ^
+      lensModel.hashCode ^
+      firmwareVersion.hashCode ^
+      shutterCount.hashCode ^
+      tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MakerNote &&
+          runtimeType == other.runtimeType &&
+          vendor == other.vendor &&
+          serialNumber == other.serialNumber &&
+          lensModel == other.lensModel &&
+          firmwareVersion == other.firmwareVersion &&
+          shutterCount == other.shutterCount &&
+          tags == other.tags;
+}
+
+/// One MakerNote entry as text
+class MakerNoteTag {
+  final int id;
+  /// The vendor's tag name, or the id in hex ("0x0019") when unknown
+  final String name;
+  final String value;
+
+  const MakerNoteTag({
+    required this.id,
+    required this.name,
+    required this.value,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MakerNoteTag &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          value == other.value;
+}
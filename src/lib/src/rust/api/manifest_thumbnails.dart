@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ingredient_thumbnail`, `manifest_thumbnail`, `read_thumbnails`, `thumbnail_mime_type`, `thumbnails_from_input`, `thumbnails_of`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Claim and ingredient thumbnails from every manifest in a file
+C2paThumbnails c2PaThumbnailsFromPath({required String filePath}) => RustLib
+    .instance
+    .api
+    .crateApiManifestThumbnailsC2PaThumbnailsFromPath(filePath: filePath);
+
+/// Claim and ingredient thumbnails from every manifest in raw bytes
+C2paThumbnails c2PaThumbnailsFromBytes({
+  required List<int> data,
+  required String mimeType,
+}) => RustLib.instance.api.crateApiManifestThumbnailsC2PaThumbnailsFromBytes(
+  data: data,
+  mimeType: mimeType,
+);
+
+/// Every thumbnail in a manifest store
+class C2paThumbnails {
+  final String? activeLabel;
+  /// By manifest label; manifests without thumbnails are left out
+  final Map<String, ManifestThumbnails> manifests;
+
+  const C2paThumbnails({this.activeLabel, required this.manifests});
+
+  @override
+  int get hashCode => activeLabel.hashCode ^ manifests.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is C2paThumbnails &&
+          runtimeType == other.runtimeType &&
+          activeLabel == other.activeLabel &&
+          manifests == other.manifests;
+}
+
+/// The thumbnail recorded for one ingredient
+class IngredientThumbnail {
+  final String title;
+  final String instanceId;
+  /// Label of the ingredient's own manifest, if it carried Content Credentials
+  final String? manifestLabel;
+  final ManifestThumbnail thumbnail;
+
+  const IngredientThumbnail({
+    required this.title,
+    required this.instanceId,
+    this.manifestLabel,
+    required this.thumbnail,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      instanceId.hashCode ^
+      manifestLabel.hashCode ^
+      thumbnail.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IngredientThumbnail &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          instanceId == other.instanceId &&
+          manifestLabel == other.manifestLabel &&
+          thumbnail == other.thumbnail;
+}
+
+/// An image stored in a manifest
+class ManifestThumbnail {
+  /// e.g. "image/jpeg", read from the image itself when recognized
+  final String mimeType;
+  /// Where the thumbnail is stored, e.g.
+  /// "self#jumbf=c2pa.assertions/c2pa.thumbnail.claim.jpeg"
+  final String? identifier;
+  final Uint8List data;
+
+  const ManifestThumbnail({
+    required this.mimeType,
+    this.identifier,
+    required this.data,
+  });
+
+  @override
+  int get hashCode => mimeType.hashCode ^ identifier.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ManifestThumbnail &&
+          runtimeType == other.runtimeType &&
+          mimeType == other.mimeType &&
+          identifier == other.identifier &&
+          data == other.data;
+}
+
+/// The thumbnails one manifest carries
+class ManifestThumbnails {
+  /// `c2pa.thumbnail.claim.*`: the asset as it was signed
+  final ManifestThumbnail? claim;
+  /// In the manifest's ingredient order; ingredients without a thumbnail
+  /// are left out
+  final List<IngredientThumbnail> ingredients;
+
+  const ManifestThumbnails({this.claim, required this.ingredients});
+
+  @override
+  int get hashCode => claim.hashCode ^ ingredients.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ManifestThumbnails &&
+          runtimeType == other.runtimeType &&
+          claim == other.claim &&
+          ingredients == other.ingredients;
+}
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bmff_mime_type`, `check_mime_type`, `is_dng`, `is_mp3_frame`, `is_svg`, `normalize`, `sniff_mime_type`, `tiff_u16`, `tiff_u32`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Detect the MIME type of a file from its leading bytes
+String? detectMimeType({required List<int> data}) =>
+    RustLib.instance.api.crateApiMimeDetectMimeType(data: data);
+
+/// Claimed versus detected MIME type of the analyzed asset
+class MimeCheck {
+  /// Type given by the caller or implied by the file extension
+  final String claimedMimeType;
+  /// Type identified from the file's leading bytes, if recognized
+  final String? detectedMimeType;
+  /// The content is not what it claims to be
+  final bool mismatch;
+
+  const MimeCheck({
+    required this.claimedMimeType,
+    this.detectedMimeType,
+    required this.mismatch,
+  });
+
+  @override
+  int get hashCode =>
+      claimedMimeType.hashCode ^ detectedMimeType.hashCode ^ mismatch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MimeCheck &&
+          runtimeType == other.runtimeType &&
+          claimedMimeType == other.claimedMimeType &&
+          detectedMimeType == other.detectedMimeType &&
+          mismatch == other.mismatch;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `identify`, `manifest_identities`, `push_identity`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Identify the tool and model a producer string names, using the loaded
+/// signature database
+ModelIdentity? identifyModel({required String text}) =>
+    RustLib.instance.api.crateApiModelIdentityIdentifyModel(text: text);
+
+/// A tool and model identified from a producer string
+class ModelIdentity {
+  final String vendor;
+  final String product;
+  /// The model or AI feature, e.g. "Stable Diffusion XL base" or "Generative Fill"
+  final String? model;
+  /// The model's version, or the product's when no model version is given
+  final String? version;
+  /// Whether the product is an AI generator rather than an editor
+  final bool isAiGenerator;
+  /// Where the string was read, e.g. "c2pa.actions[0].softwareAgent" or an EXIF tag name
+  final String field;
+  /// The string the identity was read from
+  final String matchedText;
+
+  const ModelIdentity({
+    required this.vendor,
+    required this.product,
+    this.model,
+    this.version,
+    required this.isAiGenerator,
+    required this.field,
+    required this.matchedText,
+  });
+
+  @override
+  int get hashCode =>
+      vendor.hashCode ^
+      product.hashCode ^
+      model.hashCode ^
+      version.hashCode ^
+      isAiGenerator.hashCode ^
+      field.hashCode ^
+      matchedText.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModelIdentity &&
+          runtimeType == other.runtimeType &&
+          vendor == other.vendor &&
+          product == other.product &&
+          model == other.model &&
+          version == other.version &&
+          isAiGenerator == other.isAiGenerator &&
+          field == other.field &&
+          matchedText == other.matchedText;
+}
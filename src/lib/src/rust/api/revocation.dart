@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cert_id_matches`, `certid_digest`, `check_chain_revocation`, `crl_status`, `ocsp_signature_valid`, `ocsp_status`, `reason_name`, `stapled_ocsp_responses`, `unknown`, `verify_with`, `within_window`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Revocation result for a certificate in a signer chain
+class RevocationInfo {
+  final RevocationState state;
+  final RevocationSource? source;
+  /// Seconds since the Unix epoch
+  final PlatformInt64? revokedAt;
+  /// CRL reason, e.g. "KeyCompromise"
+  final String? reason;
+  final PlatformInt64? thisUpdate;
+  final PlatformInt64? nextUpdate;
+
+  const RevocationInfo({
+    required this.state,
+    this.source,
+    this.revokedAt,
+    this.reason,
+    this.thisUpdate,
+    this.nextUpdate,
+  });
+
+  @override
+  int get hashCode =>
+      state.hashCode ^
+      source.hashCode ^
+      revokedAt.hashCode ^
+      reason.hashCode ^
+      thisUpdate.hashCode ^
+      nextUpdate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RevocationInfo &&
+          runtimeType == other.runtimeType &&
+          state == other.state &&
+          source == other.source &&
+          revokedAt == other.revokedAt &&
+          reason == other.reason &&
+          thisUpdate == other.thisUpdate &&
+          nextUpdate == other.nextUpdate;
+}
+
+/// Where a revocation status came from
+enum RevocationSource {
+  /// OCSP response stapled into the claim signature's `rVals` header
+  stapledOcsp,
+  /// OCSP response loaded with `add_ocsp_response_from_bytes`
+  cachedOcsp,
+  /// Certificate revocation list loaded with `add_crls_from_bytes`
+  crl,
+  ;
+}
+
+/// Revocation status of a single certificate
+enum RevocationState {
+  good,
+  revoked,
+  /// No applicable, verifiable OCSP response or CRL was available
+  unknown,
+  ;
+}
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_ai`, `noisy_or`, `score_evidence`, `weight`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `default`, `eq`, `eq`, `fmt`, `fmt`
+
+/// Weights applied to every analysis
+ScoringWeights scoringWeights() =>
+    RustLib.instance.api.crateApiScoringScoringWeights();
+
+/// Replace the weights used for every later analysis. Every value must lie in 0–1.
+void setScoringWeights({required ScoringWeights weights}) =>
+    RustLib.instance.api.crateApiScoringSetScoringWeights(weights: weights);
+
+/// Restore the built-in scoring weights
+void resetScoringWeights() =>
+    RustLib.instance.api.crateApiScoringResetScoringWeights();
+
+/// Categorical verdict derived from the likelihood and the kinds of evidence
+enum AiLabel {
+  /// A signed declaration says the content was generated by AI
+  confirmedAi,
+  /// Unsigned evidence makes AI generation likely
+  likelyAi,
+  /// Existing content was edited or composited with AI
+  editedWithAi,
+  /// Nothing, or too little, points either way
+  noEvidence,
+  /// Capture metadata outweighs any AI evidence
+  likelyCameraOriginal,
+  ;
+}
+
+/// Weights used to turn evidence into a likelihood
+class ScoringWeights {
+  /// Probability that a single piece of definitive evidence is right
+  final double definitive;
+  /// Probability that a single piece of strong evidence is right
+  final double strong;
+  /// Probability that a single piece of weak evidence is right
+  final double weak;
+  /// Fraction of the likelihood that conclusive camera evidence removes
+  final double cameraDiscount;
+  /// Likelihood at or above which unsigned evidence is labelled AI
+  final double likelyThreshold;
+
+  const ScoringWeights({
+    required this.definitive,
+    required this.strong,
+    required this.weak,
+    required this.cameraDiscount,
+    required this.likelyThreshold,
+  });
+
+  @override
+  int get hashCode =>
+      definitive.hashCode ^
+      strong.hashCode ^
+      weak.hashCode ^
+      cameraDiscount.hashCode ^
+      likelyThreshold.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScoringWeights &&
+          runtimeType == other.runtimeType &&
+          definitive == other.definitive &&
+          strong == other.strong &&
+          weak == other.weak &&
+          cameraDiscount == other.cameraDiscount &&
+          likelyThreshold == other.likelyThreshold;
+}
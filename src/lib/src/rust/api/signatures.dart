@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `applies_to`, `built_in_database`, `compile_product`, `compile`, `describe`, `find_generator`, `find_phrase`, `find`, `identify`, `leading_version`, `model`, `parse`, `signature_database`, `summary`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Replace the AI signature database with one in the `ai_generators.toml`
+/// format, as TOML or JSON. The current database is kept if this fails.
+AiSignatureSummary loadAiSignaturesFromBytes({required List<int> data}) => RustLib.instance.api.crateApiSignaturesLoadAiSignaturesFromBytes(
+  data: data,
+);
+
+/// Replace the AI signature database with one on disk
+AiSignatureSummary loadAiSignaturesFromFile({required String filePath}) => RustLib.instance.api.crateApiSignaturesLoadAiSignaturesFromFile(
+  filePath: filePath,
+);
+
+/// Restore the built-in AI signature database
+void resetAiSignatures() =>
+    RustLib.instance.api.crateApiSignaturesResetAiSignatures();
+
+/// Describe the AI signature database in use
+AiSignatureSummary aiSignatureSummary() =>
+    RustLib.instance.api.crateApiSignaturesAiSignatureSummary();
+
+/// Version and size of the loaded signature database
+class AiSignatureSummary {
+  final int schemaVersion;
+  /// Revision of the data, e.g. "2026.10.18"
+  final String revision;
+  final int generatorCount;
+  final int editorCount;
+  final int phraseCount;
+  /// Whether the compiled-in database is in use
+  final bool builtIn;
+
+  const AiSignatureSummary({
+    required this.schemaVersion,
+    required this.revision,
+    required this.generatorCount,
+    required this.editorCount,
+    required this.phraseCount,
+    required this.builtIn,
+  });
+
+  @override
+  int get hashCode =>
+      schemaVersion.hashCode ^
+      revision.hashCode ^
+      generatorCount.hashCode ^
+      editorCount.hashCode ^
+      phraseCount.hashCode ^
+      builtIn.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AiSignatureSummary &&
+          runtimeType == other.runtimeType &&
+          schemaVersion == other.schemaVersion &&
+          revision == other.revision &&
+          generatorCount == other.generatorCount &&
+          editorCount == other.editorCount &&
+          phraseCount == other.phraseCount &&
+          builtIn == other.builtIn;
+}
@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cell_scores`, `compare_thumbnail`, `compare`, `content_bounds`, `decode_gray`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// A rectangle in main-image pixels
+class ImageRegion {
+  final int x;
+  final int y;
+  final int width;
+  final int height;
+
+  const ImageRegion({
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      x.hashCode ^ y.hashCode ^ width.hashCode ^ height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageRegion &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height;
+}
+
+/// How the EXIF thumbnail compares with the image, both reduced to the
+/// thumbnail's size and to brightness normalized for exposure and contrast
+class ThumbnailComparison {
+  /// Size the two were compared at: the thumbnail's, less any letterbox bars
+  final int comparedWidth;
+  final int comparedHeight;
+  /// Mean brightness difference over all cells, in standard deviations
+  final double difference;
+  /// Share of cells, 0–1, that changed
+  final double changedFraction;
+  /// Bounding box of the changed cells
+  final ImageRegion? changedRegion;
+  /// The thumbnail shows different content from the image
+  final bool contentDiffers;
+
+  const ThumbnailComparison({
+    required this.comparedWidth,
+    required this.comparedHeight,
+    required this.difference,
+    required this.changedFraction,
+    this.changedRegion,
+    required this.contentDiffers,
+  });
+
+  @override
+  int get hashCode =>
+      comparedWidth.hashCode ^
+      comparedHeight.hashCode ^
+      difference.hashCode ^
+      changedFraction.hashCode ^
+      changedRegion.hashCode ^
+      contentDiffers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThumbnailComparison &&
+          runtimeType == other.runtimeType &&
+          comparedWidth == other.comparedWidth &&
+          comparedHeight == other.comparedHeight &&
+          difference == other.difference &&
+          changedFraction == other.changedFraction &&
+          changedRegion == other.changedRegion &&
+          contentDiffers == other.contentDiffers;
+}
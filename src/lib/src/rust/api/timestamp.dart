@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'certificate.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `digest`, `hash_algorithm_name`, `parse_signer`, `parse_token`, `timestamp_from_cose`, `timestamp_tokens`, `verification_algorithm`, `verify_token_signature`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// RFC 3161 time-stamp token embedded in a claim signature
+class TimestampInfo {
+  /// TSA name from the token, or the TSA certificate subject when absent
+  final String? tsaName;
+  final CertificateInfo? tsaCertificate;
+  /// Time asserted by the TSA, seconds since the Unix epoch
+  final PlatformInt64 genTime;
+  /// Hash algorithm of the message imprint, e.g. "SHA-256"
+  final String hashAlgorithm;
+  final String messageImprint;
+  final String? policy;
+  final String serialNumber;
+  /// The TSA's CMS signature over the token verifies
+  final bool signatureValid;
+  /// The TSA certificate chains to a configured TSA trust anchor
+  final bool trusted;
+  /// Whether the signer certificate's validity was checked at `gen_time`
+  /// rather than now. Only a valid, trusted token can stand in for signing time.
+  final bool signerValidityEvaluatedAtTsaTime;
+  final bool? signerValidAtGenTime;
+
+  const TimestampInfo({
+    this.tsaName,
+    this.tsaCertificate,
+    required this.genTime,
+    required this.hashAlgorithm,
+    required this.messageImprint,
+    this.policy,
+    required this.serialNumber,
+    required this.signatureValid,
+    required this.trusted,
+    required this.signerValidityEvaluatedAtTsaTime,
+    this.signerValidAtGenTime,
+  });
+
+  @override
+  int get hashCode =>
+      tsaName.hashCode ^
+      tsaCertificate.hashCode ^
+      genTime.hashCode ^
+      hashAlgorithm.hashCode ^
+      messageImprint.hashCode ^
+      policy.hashCode ^
+      serialNumber.hashCode ^
+      signatureValid.hashCode ^
+      trusted.hashCode ^
+      signerValidityEvaluatedAtTsaTime.hashCode ^
+      signerValidAtGenTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimestampInfo &&
+          runtimeType == other.runtimeType &&
+          tsaName == other.tsaName &&
+          tsaCertificate == other.tsaCertificate &&
+          genTime == other.genTime &&
+          hashAlgorithm == other.hashAlgorithm &&
+          messageImprint == other.messageImprint &&
+          policy == other.policy &&
+          serialNumber == other.serialNumber &&
+          signatureValid == other.signatureValid &&
+          trusted == other.trusted &&
+          signerValidityEvaluatedAtTsaTime == other.signerValidityEvaluatedAtTsaTime &&
+          signerValidAtGenTime == other.signerValidAtGenTime;
+}
@@ -0,0 +1,111 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apply_trust_store`, `is_valid_oid`, `parse_der_or_pem`, `parse_pem_bundle`, `pem_to_der`, `revocation_data`, `setting_value`, `split_pem_certificates`, `trust_anchors`, `tsa_trust_anchors`, `with_trust_store`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Add trust anchors from a PEM bundle. Returns the number of certificates added.
+int addTrustAnchorsFromBytes({required List<int> pem}) =>
+    RustLib.instance.api.crateApiTrustAddTrustAnchorsFromBytes(pem: pem);
+
+/// Add trust anchors from a PEM bundle on disk, such as the C2PA conformance trust list
+int addTrustAnchorsFromFile({required String filePath}) => RustLib
+    .instance
+    .api
+    .crateApiTrustAddTrustAnchorsFromFile(filePath: filePath);
+
+/// Add end-entity certificates that are trusted directly, from a PEM bundle
+int addAllowedCertificatesFromBytes({required List<int> pem}) =>
+    RustLib.instance.api.crateApiTrustAddAllowedCertificatesFromBytes(pem: pem);
+
+/// Add end-entity certificates that are trusted directly, from a PEM bundle on disk
+int addAllowedCertificatesFromFile({required String filePath}) => RustLib
+    .instance
+    .api
+    .crateApiTrustAddAllowedCertificatesFromFile(filePath: filePath);
+
+/// Add time-stamp authority trust anchors from a PEM bundle
+int addTsaTrustAnchorsFromBytes({required List<int> pem}) =>
+    RustLib.instance.api.crateApiTrustAddTsaTrustAnchorsFromBytes(pem: pem);
+
+/// Add time-stamp authority trust anchors from a PEM bundle on disk
+int addTsaTrustAnchorsFromFile({required String filePath}) => RustLib
+    .instance
+    .api
+    .crateApiTrustAddTsaTrustAnchorsFromFile(filePath: filePath);
+
+/// Add certificate revocation lists, DER or PEM ("X509 CRL"), for offline revocation checks
+int addCrlsFromBytes({required List<int> data}) =>
+    RustLib.instance.api.crateApiTrustAddCrlsFromBytes(data: data);
+
+/// Add a certificate revocation list from disk
+int addCrlsFromFile({required String filePath}) =>
+    RustLib.instance.api.crateApiTrustAddCrlsFromFile(filePath: filePath);
+
+/// Add a cached DER OCSP response, e.g. one fetched before entering an offline environment
+void addOcspResponseFromBytes({required List<int> der}) =>
+    RustLib.instance.api.crateApiTrustAddOcspResponseFromBytes(der: der);
+
+/// Add a cached DER OCSP response from disk
+void addOcspResponseFromFile({required String filePath}) => RustLib
+    .instance
+    .api
+    .crateApiTrustAddOcspResponseFromFile(filePath: filePath);
+
+/// Replace the extended key usage OIDs accepted for signing certificates,
+/// e.g. "1.3.6.1.5.5.7.3.4" (emailProtection) or "1.3.6.1.5.5.7.3.36" (documentSigning)
+void setTrustedEkus({required List<String> oids}) =>
+    RustLib.instance.api.crateApiTrustSetTrustedEkus(oids: oids);
+
+/// Remove every trust anchor, allowed certificate, TSA anchor, EKU setting and
+/// revocation data
+void clearTrustStore() => RustLib.instance.api.crateApiTrustClearTrustStore();
+
+/// Describe what is currently loaded in the trust store
+TrustStoreSummary trustStoreSummary() =>
+    RustLib.instance.api.crateApiTrustTrustStoreSummary();
+
+/// Summary of what is currently loaded in the trust store
+class TrustStoreSummary {
+  final int anchorCount;
+  final int allowedCertificateCount;
+  final List<String> ekuOids;
+  final int tsaAnchorCount;
+  final int crlCount;
+  final int ocspResponseCount;
+
+  const TrustStoreSummary({
+    required this.anchorCount,
+    required this.allowedCertificateCount,
+    required this.ekuOids,
+    required this.tsaAnchorCount,
+    required this.crlCount,
+    required this.ocspResponseCount,
+  });
+
+  @override
+  int get hashCode =>
+      anchorCount.hashCode ^
+      allowedCertificateCount.hashCode ^
+      ekuOids.hashCode ^
+      tsaAnchorCount.hashCode ^
+      crlCount.hashCode ^
+      ocspResponseCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrustStoreSummary &&
+          runtimeType == other.runtimeType &&
+          anchorCount == other.anchorCount &&
+          allowedCertificateCount == other.allowedCertificateCount &&
+          ekuOids == other.ekuOids &&
+          tsaAnchorCount == other.tsaAnchorCount &&
+          crlCount == other.crlCount &&
+          ocspResponseCount == other.ocspResponseCount;
+}
@@ -0,0 +1,190 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'validation_status.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `category`, `from_code`, `is_hash_mismatch`, `is_signature_failure`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// Category of a C2PA validation status code
+enum ValidationCategory {
+  success,
+  informational,
+  failure,
+  ;
+}
+
+@freezed
+sealed class ValidationStatusCode with _$ValidationStatusCode {
+  const ValidationStatusCode._();
+
+  const factory ValidationStatusCode.claimSignatureValidated() =
+      ValidationStatusCode_ClaimSignatureValidated;
+  const factory ValidationStatusCode.claimSignatureInsideValidity() =
+      ValidationStatusCode_ClaimSignatureInsideValidity;
+  const factory ValidationStatusCode.signingCredentialTrusted() =
+      ValidationStatusCode_SigningCredentialTrusted;
+  const factory ValidationStatusCode.signingCredentialNotRevoked() =
+      ValidationStatusCode_SigningCredentialNotRevoked;
+  const factory ValidationStatusCode.timeStampTrusted() =
+      ValidationStatusCode_TimeStampTrusted;
+  const factory ValidationStatusCode.timeStampValidated() =
+      ValidationStatusCode_TimeStampValidated;
+  const factory ValidationStatusCode.assertionHashedUriMatch() =
+      ValidationStatusCode_AssertionHashedUriMatch;
+  const factory ValidationStatusCode.assertionDataHashMatch() =
+      ValidationStatusCode_AssertionDataHashMatch;
+  const factory ValidationStatusCode.assertionBmffHashMatch() =
+      ValidationStatusCode_AssertionBmffHashMatch;
+  const factory ValidationStatusCode.assertionBoxesHashMatch() =
+      ValidationStatusCode_AssertionBoxesHashMatch;
+  const factory ValidationStatusCode.assertionCollectionHashMatch() =
+      ValidationStatusCode_AssertionCollectionHashMatch;
+  const factory ValidationStatusCode.assertionAccessible() =
+      ValidationStatusCode_AssertionAccessible;
+  const factory ValidationStatusCode.ingredientManifestValidated() =
+      ValidationStatusCode_IngredientManifestValidated;
+  const factory ValidationStatusCode.ingredientClaimSignatureValidated() =
+      ValidationStatusCode_IngredientClaimSignatureValidated;
+  const factory ValidationStatusCode.algorithmDeprecated() =
+      ValidationStatusCode_AlgorithmDeprecated;
+  const factory ValidationStatusCode.signingCredentialOcspSkipped() =
+      ValidationStatusCode_SigningCredentialOcspSkipped;
+  const factory ValidationStatusCode.signingCredentialOcspInaccessible() =
+      ValidationStatusCode_SigningCredentialOcspInaccessible;
+  const factory ValidationStatusCode.timeStampUntrusted() =
+      ValidationStatusCode_TimeStampUntrusted;
+  const factory ValidationStatusCode.timeStampOutsideValidity() =
+      ValidationStatusCode_TimeStampOutsideValidity;
+  const factory ValidationStatusCode.ingredientUnknownProvenance() =
+      ValidationStatusCode_IngredientUnknownProvenance;
+  const factory ValidationStatusCode.manifestUnknownProvenance() =
+      ValidationStatusCode_ManifestUnknownProvenance;
+  const factory ValidationStatusCode.claimMissing() =
+      ValidationStatusCode_ClaimMissing;
+  const factory ValidationStatusCode.claimMultiple() =
+      ValidationStatusCode_ClaimMultiple;
+  const factory ValidationStatusCode.claimHardBindingsMissing() =
+      ValidationStatusCode_ClaimHardBindingsMissing;
+  const factory ValidationStatusCode.claimRequiredMissing() =
+      ValidationStatusCode_ClaimRequiredMissing;
+  const factory ValidationStatusCode.claimCborInvalid() =
+      ValidationStatusCode_ClaimCborInvalid;
+  const factory ValidationStatusCode.claimSignatureMissing() =
+      ValidationStatusCode_ClaimSignatureMissing;
+  const factory ValidationStatusCode.claimSignatureMismatch() =
+      ValidationStatusCode_ClaimSignatureMismatch;
+  const factory ValidationStatusCode.claimSignatureOutsideValidity() =
+      ValidationStatusCode_ClaimSignatureOutsideValidity;
+  const factory ValidationStatusCode.algorithmUnsupported() =
+      ValidationStatusCode_AlgorithmUnsupported;
+  const factory ValidationStatusCode.signingCredentialUntrusted() =
+      ValidationStatusCode_SigningCredentialUntrusted;
+  const factory ValidationStatusCode.signingCredentialInvalid() =
+      ValidationStatusCode_SigningCredentialInvalid;
+  const factory ValidationStatusCode.signingCredentialExpired() =
+      ValidationStatusCode_SigningCredentialExpired;
+  const factory ValidationStatusCode.signingCredentialRevoked() =
+      ValidationStatusCode_SigningCredentialRevoked;
+  const factory ValidationStatusCode.signingCredentialOcspUnknown() =
+      ValidationStatusCode_SigningCredentialOcspUnknown;
+  const factory ValidationStatusCode.timeStampMismatch() =
+      ValidationStatusCode_TimeStampMismatch;
+  const factory ValidationStatusCode.timeStampMalformed() =
+      ValidationStatusCode_TimeStampMalformed;
+  const factory ValidationStatusCode.assertionHashedUriMismatch() =
+      ValidationStatusCode_AssertionHashedUriMismatch;
+  const factory ValidationStatusCode.assertionDataHashMismatch() =
+      ValidationStatusCode_AssertionDataHashMismatch;
+  const factory ValidationStatusCode.assertionDataHashMalformed() =
+      ValidationStatusCode_AssertionDataHashMalformed;
+  const factory ValidationStatusCode.assertionBmffHashMismatch() =
+      ValidationStatusCode_AssertionBmffHashMismatch;
+  const factory ValidationStatusCode.assertionBmffHashMalformed() =
+      ValidationStatusCode_AssertionBmffHashMalformed;
+  const factory ValidationStatusCode.assertionBoxesHashMismatch() =
+      ValidationStatusCode_AssertionBoxesHashMismatch;
+  const factory ValidationStatusCode.assertionBoxesHashUnknownBox() =
+      ValidationStatusCode_AssertionBoxesHashUnknownBox;
+  const factory ValidationStatusCode.assertionCollectionHashMismatch() =
+      ValidationStatusCode_AssertionCollectionHashMismatch;
+  const factory ValidationStatusCode.assertionCollectionHashMalformed() =
+      ValidationStatusCode_AssertionCollectionHashMalformed;
+  const factory ValidationStatusCode.assertionCollectionHashIncorrectFileCount() =
+      ValidationStatusCode_AssertionCollectionHashIncorrectFileCount;
+  const factory ValidationStatusCode.assertionCollectionHashInvalidUri() =
+      ValidationStatusCode_AssertionCollectionHashInvalidUri;
+  const factory ValidationStatusCode.hashedUriMissing() =
+      ValidationStatusCode_HashedUriMissing;
+  const factory ValidationStatusCode.hashedUriMismatch() =
+      ValidationStatusCode_HashedUriMismatch;
+  const factory ValidationStatusCode.assertionMissing() =
+      ValidationStatusCode_AssertionMissing;
+  const factory ValidationStatusCode.assertionRequiredMissing() =
+      ValidationStatusCode_AssertionRequiredMissing;
+  const factory ValidationStatusCode.assertionMultipleHardBindings() =
+      ValidationStatusCode_AssertionMultipleHardBindings;
+  const factory ValidationStatusCode.assertionUndeclared() =
+      ValidationStatusCode_AssertionUndeclared;
+  const factory ValidationStatusCode.assertionInaccessible() =
+      ValidationStatusCode_AssertionInaccessible;
+  const factory ValidationStatusCode.assertionNotRedacted() =
+      ValidationStatusCode_AssertionNotRedacted;
+  const factory ValidationStatusCode.assertionSelfRedacted() =
+      ValidationStatusCode_AssertionSelfRedacted;
+  const factory ValidationStatusCode.assertionJsonInvalid() =
+      ValidationStatusCode_AssertionJsonInvalid;
+  const factory ValidationStatusCode.assertionCborInvalid() =
+      ValidationStatusCode_AssertionCborInvalid;
+  const factory ValidationStatusCode.assertionActionIngredientMismatch() =
+      ValidationStatusCode_AssertionActionIngredientMismatch;
+  const factory ValidationStatusCode.assertionActionMalformed() =
+      ValidationStatusCode_AssertionActionMalformed;
+  const factory ValidationStatusCode.assertionActionRedacted() =
+      ValidationStatusCode_AssertionActionRedacted;
+  const factory ValidationStatusCode.assertionActionRedactionMismatch() =
+      ValidationStatusCode_AssertionActionRedactionMismatch;
+  const factory ValidationStatusCode.assertionCloudDataHardBinding() =
+      ValidationStatusCode_AssertionCloudDataHardBinding;
+  const factory ValidationStatusCode.assertionCloudDataActions() =
+      ValidationStatusCode_AssertionCloudDataActions;
+  const factory ValidationStatusCode.assertionCloudDataLabelMismatch() =
+      ValidationStatusCode_AssertionCloudDataLabelMismatch;
+  const factory ValidationStatusCode.ingredientHashedUriMissing() =
+      ValidationStatusCode_IngredientHashedUriMissing;
+  const factory ValidationStatusCode.ingredientHashedUriMismatch() =
+      ValidationStatusCode_IngredientHashedUriMismatch;
+  const factory ValidationStatusCode.ingredientManifestMissing() =
+      ValidationStatusCode_IngredientManifestMissing;
+  const factory ValidationStatusCode.ingredientManifestMismatch() =
+      ValidationStatusCode_IngredientManifestMismatch;
+  const factory ValidationStatusCode.ingredientClaimSignatureMissing() =
+      ValidationStatusCode_IngredientClaimSignatureMissing;
+  const factory ValidationStatusCode.ingredientClaimSignatureMismatch() =
+      ValidationStatusCode_IngredientClaimSignatureMismatch;
+  const factory ValidationStatusCode.manifestInaccessible() =
+      ValidationStatusCode_ManifestInaccessible;
+  const factory ValidationStatusCode.manifestMultipleParents() =
+      ValidationStatusCode_ManifestMultipleParents;
+  const factory ValidationStatusCode.manifestUpdateInvalid() =
+      ValidationStatusCode_ManifestUpdateInvalid;
+  const factory ValidationStatusCode.manifestUpdateWrongParents() =
+      ValidationStatusCode_ManifestUpdateWrongParents;
+  const factory ValidationStatusCode.manifestTimestampInvalid() =
+      ValidationStatusCode_ManifestTimestampInvalid;
+  const factory ValidationStatusCode.manifestTimestampWrongParents() =
+      ValidationStatusCode_ManifestTimestampWrongParents;
+  const factory ValidationStatusCode.manifestCompressedInvalid() =
+      ValidationStatusCode_ManifestCompressedInvalid;
+  const factory ValidationStatusCode.generalError() =
+      ValidationStatusCode_GeneralError;
+  /// A code not defined by the specification version this crate knows about.
+  /// Always treated as a failure.
+  const factory ValidationStatusCode.unknown({required String code}) =
+      ValidationStatusCode_Unknown;
+}
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'digital_source_type.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bmff_packet`, `jpeg_packets`, `parse_packet`, `property_values`, `rdf_text`, `read_xmp`, `webp_packet`, `xmp_packets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Provenance-related XMP properties
+class XmpInfo {
+  /// `Iptc4xmpExt:DigitalSourceType`
+  final DigitalSourceType? digitalSourceType;
+  /// `photoshop:Credit`, e.g. "Made with Google AI"
+  final String? credit;
+  /// `xmp:CreatorTool`
+  final String? creatorTool;
+  /// `dc:creator`, in order
+  final List<String> creators;
+  /// `dcterms:provenance`: where the asset's remote C2PA manifest is hosted
+  final String? provenance;
+  /// Where the packet was found, e.g. "JPEG APP1" or "PNG iTXt"
+  final String container;
+  /// The main XMP packet as stored
+  final String packet;
+
+  const XmpInfo({
+    this.digitalSourceType,
+    this.credit,
+    this.creatorTool,
+    required this.creators,
+    this.provenance,
+    required this.container,
+    required this.packet,
+  });
+
+  @override
+  int get hashCode =>
+      digitalSourceType.hashCode ^
+      credit.hashCode ^
+      creatorTool.hashCode ^
+      creators.hashCode ^
+      provenance.hashCode ^
+      container.hashCode ^
+      packet.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XmpInfo &&
+          runtimeType == other.runtimeType &&
+          digitalSourceType == other.digitalSourceType &&
+          credit == other.credit &&
+          creatorTool == other.creatorTool &&
+          creators == other.creators &&
+          provenance == other.provenance &&
+          container == other.container &&
+          packet == other.packet;
+}
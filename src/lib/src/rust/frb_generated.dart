@@ -3,7 +3,30 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/actions.dart';
 import 'api/c2pa_reader.dart';
+import 'api/certificate.dart';
+import 'api/consistency.dart';
+import 'api/digital_source_type.dart';
+import 'api/distinguished_name.dart';
+import 'api/error.dart';
+import 'api/evidence.dart';
+import 'api/exif_metadata.dart';
+import 'api/generation_parameters.dart';
+import 'api/input.dart';
+import 'api/iptc.dart';
+import 'api/maker_note.dart';
+import 'api/manifest_thumbnails.dart';
+import 'api/mime.dart';
+import 'api/model_identity.dart';
+import 'api/revocation.dart';
+import 'api/scoring.dart';
+import 'api/signatures.dart';
+import 'api/thumbnail_comparison.dart';
+import 'api/timestamp.dart';
+import 'api/trust.dart';
+import 'api/validation_status.dart';
+import 'api/xmp.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -64,7 +87,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 102767085;
+  int get rustContentHash => 1394073100;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -80,6 +103,14 @@ abstract class RustLibApi extends BaseApi {
     required String mimeType,
   });
 
+  C2paAnalysisResult crateApiC2PaReaderAnalyzeC2PaFromChunks({
+    required ChunkedAsset asset,
+  });
+
+  C2paAnalysisResult crateApiC2PaReaderAnalyzeC2PaFromMappedFile({
+    required String filePath,
+  });
+
   C2paAnalysisResult crateApiC2PaReaderAnalyzeC2PaFromPath({
     required String filePath,
   });
@@ -87,6 +118,86 @@ abstract class RustLibApi extends BaseApi {
   String crateApiC2PaReaderC2PaSdkVersion();
 
   bool crateApiC2PaReaderIsC2PaAvailable();
+
+  DistinguishedName? crateApiDistinguishedNameParseDistinguishedName({
+    required String dn,
+  });
+
+  void crateApiInputChunkedAssetAppend({
+    required ChunkedAsset that,
+    required List<int> chunk,
+  });
+
+  bool crateApiInputChunkedAssetIsEmpty({required ChunkedAsset that});
+
+  BigInt crateApiInputChunkedAssetLen({required ChunkedAsset that});
+
+  ChunkedAsset crateApiInputChunkedAssetNew({required String mimeType});
+
+  C2paThumbnails crateApiManifestThumbnailsC2PaThumbnailsFromBytes({
+    required List<int> data,
+    required String mimeType,
+  });
+
+  C2paThumbnails crateApiManifestThumbnailsC2PaThumbnailsFromPath({
+    required String filePath,
+  });
+
+  String? crateApiMimeDetectMimeType({required List<int> data});
+
+  ModelIdentity? crateApiModelIdentityIdentifyModel({required String text});
+
+  void crateApiScoringResetScoringWeights();
+
+  ScoringWeights crateApiScoringScoringWeights();
+
+  void crateApiScoringSetScoringWeights({required ScoringWeights weights});
+
+  AiSignatureSummary crateApiSignaturesAiSignatureSummary();
+
+  AiSignatureSummary crateApiSignaturesLoadAiSignaturesFromBytes({
+    required List<int> data,
+  });
+
+  AiSignatureSummary crateApiSignaturesLoadAiSignaturesFromFile({
+    required String filePath,
+  });
+
+  void crateApiSignaturesResetAiSignatures();
+
+  int crateApiTrustAddAllowedCertificatesFromBytes({required List<int> pem});
+
+  int crateApiTrustAddAllowedCertificatesFromFile({required String filePath});
+
+  int crateApiTrustAddCrlsFromBytes({required List<int> data});
+
+  int crateApiTrustAddCrlsFromFile({required String filePath});
+
+  void crateApiTrustAddOcspResponseFromBytes({required List<int> der});
+
+  void crateApiTrustAddOcspResponseFromFile({required String filePath});
+
+  int crateApiTrustAddTrustAnchorsFromBytes({required List<int> pem});
+
+  int crateApiTrustAddTrustAnchorsFromFile({required String filePath});
+
+  int crateApiTrustAddTsaTrustAnchorsFromBytes({required List<int> pem});
+
+  int crateApiTrustAddTsaTrustAnchorsFromFile({required String filePath});
+
+  void crateApiTrustClearTrustStore();
+
+  void crateApiTrustSetTrustedEkus({required List<String> oids});
+
+  TrustStoreSummary crateApiTrustTrustStoreSummary();
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ChunkedAsset;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ChunkedAsset;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ChunkedAssetPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -127,6 +238,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["data", "mimeType"],
       );
 
+  @override
+  C2paAnalysisResult crateApiC2PaReaderAnalyzeC2PaFromChunks({
+    required ChunkedAsset asset,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+            asset,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_c_2_pa_analysis_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiC2PaReaderAnalyzeC2PaFromChunksConstMeta,
+        argValues: [asset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiC2PaReaderAnalyzeC2PaFromChunksConstMeta =>
+      const TaskConstMeta(
+        debugName: "analyze_c2pa_from_chunks",
+        argNames: ["asset"],
+      );
+
+  @override
+  C2paAnalysisResult crateApiC2PaReaderAnalyzeC2PaFromMappedFile({
+    required String filePath,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_c_2_pa_analysis_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiC2PaReaderAnalyzeC2PaFromMappedFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiC2PaReaderAnalyzeC2PaFromMappedFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "analyze_c2pa_from_mapped_file",
+        argNames: ["filePath"],
+      );
+
   @override
   C2paAnalysisResult crateApiC2PaReaderAnalyzeC2PaFromPath({
     required String filePath,
@@ -136,7 +306,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(filePath, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_c_2_pa_analysis_result,
@@ -161,7 +331,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -183,7 +353,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -199,630 +369,6815 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiC2PaReaderIsC2PaAvailableConstMeta =>
       const TaskConstMeta(debugName: "is_c2pa_available", argNames: []);
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  AiInfo dco_decode_ai_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return AiInfo(
-      isAiGenerated: dco_decode_bool(arr[0]),
-      generatorName: dco_decode_opt_String(arr[1]),
-      modelName: dco_decode_opt_String(arr[2]),
-      detectionSource: dco_decode_opt_String(arr[3]),
+  @override
+  DistinguishedName? crateApiDistinguishedNameParseDistinguishedName({
+    required String dn,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dn, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_distinguished_name,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDistinguishedNameParseDistinguishedNameConstMeta,
+        argValues: [dn],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  AiInfo dco_decode_box_autoadd_ai_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ai_info(raw);
-  }
+  TaskConstMeta get kCrateApiDistinguishedNameParseDistinguishedNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_distinguished_name",
+        argNames: ["dn"],
+      );
 
-  @protected
-  ExifInfo dco_decode_box_autoadd_exif_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_exif_info(raw);
+  @override
+  void crateApiInputChunkedAssetAppend({
+    required ChunkedAsset that,
+    required List<int> chunk,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(chunk, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInputChunkedAssetAppendConstMeta,
+        argValues: [that, chunk],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  SignerInfo dco_decode_box_autoadd_signer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_signer_info(raw);
-  }
+  TaskConstMeta get kCrateApiInputChunkedAssetAppendConstMeta =>
+      const TaskConstMeta(
+        debugName: "ChunkedAsset_append",
+        argNames: ["that", "chunk"],
+      );
 
-  @protected
-  C2paAnalysisResult dco_decode_c_2_pa_analysis_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return C2paAnalysisResult(
-      status: dco_decode_verification_status(arr[0]),
-      signer: dco_decode_opt_box_autoadd_signer_info(arr[1]),
-      actions: dco_decode_list_content_action(arr[2]),
-      aiInfo: dco_decode_opt_box_autoadd_ai_info(arr[3]),
-      exifInfo: dco_decode_opt_box_autoadd_exif_info(arr[4]),
-      claimGenerator: dco_decode_opt_String(arr[5]),
-      title: dco_decode_opt_String(arr[6]),
-      format: dco_decode_opt_String(arr[7]),
-      instanceId: dco_decode_opt_String(arr[8]),
-      rawManifestJson: dco_decode_opt_String(arr[9]),
+  @override
+  bool crateApiInputChunkedAssetIsEmpty({required ChunkedAsset that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInputChunkedAssetIsEmptyConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  ContentAction dco_decode_content_action(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ContentAction(
-      action: dco_decode_String(arr[0]),
-      softwareAgent: dco_decode_opt_String(arr[1]),
-      when: dco_decode_opt_String(arr[2]),
-      description: dco_decode_opt_String(arr[3]),
+  TaskConstMeta get kCrateApiInputChunkedAssetIsEmptyConstMeta =>
+      const TaskConstMeta(
+        debugName: "ChunkedAsset_is_empty",
+        argNames: ["that"],
+      );
+
+  @override
+  BigInt crateApiInputChunkedAssetLen({required ChunkedAsset that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInputChunkedAssetLenConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  ExifInfo dco_decode_exif_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return ExifInfo(
-      software: dco_decode_opt_String(arr[0]),
-      make: dco_decode_opt_String(arr[1]),
-      model: dco_decode_opt_String(arr[2]),
-      artist: dco_decode_opt_String(arr[3]),
-      copyright: dco_decode_opt_String(arr[4]),
-      userComment: dco_decode_opt_String(arr[5]),
-      imageDescription: dco_decode_opt_String(arr[6]),
-      dateTimeOriginal: dco_decode_opt_String(arr[7]),
-      aiDetected: dco_decode_bool(arr[8]),
-      aiGenerator: dco_decode_opt_String(arr[9]),
+  TaskConstMeta get kCrateApiInputChunkedAssetLenConstMeta =>
+      const TaskConstMeta(debugName: "ChunkedAsset_len", argNames: ["that"]);
+
+  @override
+  ChunkedAsset crateApiInputChunkedAssetNew({required String mimeType}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mimeType, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInputChunkedAssetNewConstMeta,
+        argValues: [mimeType],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  List<ContentAction> dco_decode_list_content_action(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_content_action).toList();
-  }
+  TaskConstMeta get kCrateApiInputChunkedAssetNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "ChunkedAsset_new",
+        argNames: ["mimeType"],
+      );
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  @override
+  C2paThumbnails crateApiManifestThumbnailsC2PaThumbnailsFromBytes({
+    required List<int> data,
+    required String mimeType,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(mimeType, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_c_2_pa_thumbnails,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiManifestThumbnailsC2PaThumbnailsFromBytesConstMeta,
+        argValues: [data, mimeType],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
+  TaskConstMeta
+  get kCrateApiManifestThumbnailsC2PaThumbnailsFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "c2pa_thumbnails_from_bytes",
+        argNames: ["data", "mimeType"],
+      );
+
+  @override
+  C2paThumbnails crateApiManifestThumbnailsC2PaThumbnailsFromPath({
+    required String filePath,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_c_2_pa_thumbnails,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiManifestThumbnailsC2PaThumbnailsFromPathConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiManifestThumbnailsC2PaThumbnailsFromPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "c2pa_thumbnails_from_path",
+        argNames: ["filePath"],
+      );
+
+  @override
+  String? crateApiMimeDetectMimeType({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMimeDetectMimeTypeConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMimeDetectMimeTypeConstMeta =>
+      const TaskConstMeta(debugName: "detect_mime_type", argNames: ["data"]);
+
+  @override
+  ModelIdentity? crateApiModelIdentityIdentifyModel({required String text}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_model_identity,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelIdentityIdentifyModelConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelIdentityIdentifyModelConstMeta =>
+      const TaskConstMeta(debugName: "identify_model", argNames: ["text"]);
+
+  @override
+  void crateApiScoringResetScoringWeights() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScoringResetScoringWeightsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScoringResetScoringWeightsConstMeta =>
+      const TaskConstMeta(debugName: "reset_scoring_weights", argNames: []);
+
+  @override
+  ScoringWeights crateApiScoringScoringWeights() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scoring_weights,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScoringScoringWeightsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScoringScoringWeightsConstMeta =>
+      const TaskConstMeta(debugName: "scoring_weights", argNames: []);
+
+  @override
+  void crateApiScoringSetScoringWeights({required ScoringWeights weights}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_scoring_weights(weights, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiScoringSetScoringWeightsConstMeta,
+        argValues: [weights],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScoringSetScoringWeightsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_scoring_weights",
+        argNames: ["weights"],
+      );
+
+  @override
+  AiSignatureSummary crateApiSignaturesAiSignatureSummary() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ai_signature_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSignaturesAiSignatureSummaryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSignaturesAiSignatureSummaryConstMeta =>
+      const TaskConstMeta(debugName: "ai_signature_summary", argNames: []);
+
+  @override
+  AiSignatureSummary crateApiSignaturesLoadAiSignaturesFromBytes({
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ai_signature_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSignaturesLoadAiSignaturesFromBytesConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSignaturesLoadAiSignaturesFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_ai_signatures_from_bytes",
+        argNames: ["data"],
+      );
+
+  @override
+  AiSignatureSummary crateApiSignaturesLoadAiSignaturesFromFile({
+    required String filePath,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ai_signature_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSignaturesLoadAiSignaturesFromFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSignaturesLoadAiSignaturesFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_ai_signatures_from_file",
+        argNames: ["filePath"],
+      );
+
+  @override
+  void crateApiSignaturesResetAiSignatures() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSignaturesResetAiSignaturesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSignaturesResetAiSignaturesConstMeta =>
+      const TaskConstMeta(debugName: "reset_ai_signatures", argNames: []);
+
+  @override
+  int crateApiTrustAddAllowedCertificatesFromBytes({required List<int> pem}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddAllowedCertificatesFromBytesConstMeta,
+        argValues: [pem],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddAllowedCertificatesFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_allowed_certificates_from_bytes",
+        argNames: ["pem"],
+      );
+
+  @override
+  int crateApiTrustAddAllowedCertificatesFromFile({required String filePath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddAllowedCertificatesFromFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddAllowedCertificatesFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_allowed_certificates_from_file",
+        argNames: ["filePath"],
+      );
+
+  @override
+  int crateApiTrustAddCrlsFromBytes({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddCrlsFromBytesConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddCrlsFromBytesConstMeta =>
+      const TaskConstMeta(debugName: "add_crls_from_bytes", argNames: ["data"]);
+
+  @override
+  int crateApiTrustAddCrlsFromFile({required String filePath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddCrlsFromFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddCrlsFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_crls_from_file",
+        argNames: ["filePath"],
+      );
+
+  @override
+  void crateApiTrustAddOcspResponseFromBytes({required List<int> der}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddOcspResponseFromBytesConstMeta,
+        argValues: [der],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddOcspResponseFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_ocsp_response_from_bytes",
+        argNames: ["der"],
+      );
+
+  @override
+  void crateApiTrustAddOcspResponseFromFile({required String filePath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddOcspResponseFromFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddOcspResponseFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_ocsp_response_from_file",
+        argNames: ["filePath"],
+      );
+
+  @override
+  int crateApiTrustAddTrustAnchorsFromBytes({required List<int> pem}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddTrustAnchorsFromBytesConstMeta,
+        argValues: [pem],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddTrustAnchorsFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_trust_anchors_from_bytes",
+        argNames: ["pem"],
+      );
+
+  @override
+  int crateApiTrustAddTrustAnchorsFromFile({required String filePath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddTrustAnchorsFromFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddTrustAnchorsFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_trust_anchors_from_file",
+        argNames: ["filePath"],
+      );
+
+  @override
+  int crateApiTrustAddTsaTrustAnchorsFromBytes({required List<int> pem}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddTsaTrustAnchorsFromBytesConstMeta,
+        argValues: [pem],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddTsaTrustAnchorsFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_tsa_trust_anchors_from_bytes",
+        argNames: ["pem"],
+      );
+
+  @override
+  int crateApiTrustAddTsaTrustAnchorsFromFile({required String filePath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustAddTsaTrustAnchorsFromFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddTsaTrustAnchorsFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_tsa_trust_anchors_from_file",
+        argNames: ["filePath"],
+      );
+
+  @override
+  void crateApiTrustClearTrustStore() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustClearTrustStoreConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustClearTrustStoreConstMeta =>
+      const TaskConstMeta(debugName: "clear_trust_store", argNames: []);
+
+  @override
+  void crateApiTrustSetTrustedEkus({required List<String> oids}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(oids, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustSetTrustedEkusConstMeta,
+        argValues: [oids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustSetTrustedEkusConstMeta =>
+      const TaskConstMeta(debugName: "set_trusted_ekus", argNames: ["oids"]);
+
+  @override
+  TrustStoreSummary crateApiTrustTrustStoreSummary() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trust_store_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrustTrustStoreSummaryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustTrustStoreSummaryConstMeta =>
+      const TaskConstMeta(debugName: "trust_store_summary", argNames: []);
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ChunkedAsset => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ChunkedAsset => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  ChunkedAsset
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+      raw,
+    );
+  }
+
+  @protected
+  ChunkedAsset
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+      raw,
+    );
+  }
+
+  @protected
+  ChunkedAsset
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+      raw,
+    );
+  }
+
+  @protected
+  Map<String, ManifestThumbnails>
+  dco_decode_Map_String_manifest_thumbnails_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_manifest_thumbnails(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  ChunkedAsset
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChunkedAssetImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  ActionChange dco_decode_action_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ActionChange(
+      description: dco_decode_opt_String(arr[0]),
+      regions: dco_decode_list_region_range(arr[1]),
+    );
+  }
+
+  @protected
+  ActionParameter dco_decode_action_parameter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ActionParameter(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  AiInfo dco_decode_ai_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return AiInfo(
+      isAiGenerated: dco_decode_bool(arr[0]),
+      likelihood: dco_decode_f_64(arr[1]),
+      label: dco_decode_ai_label(arr[2]),
+      generatorName: dco_decode_opt_String(arr[3]),
+      modelName: dco_decode_opt_String(arr[4]),
+      model: dco_decode_opt_box_autoadd_model_identity(arr[5]),
+      detectionSource: dco_decode_opt_String(arr[6]),
+      evidence: dco_decode_list_evidence(arr[7]),
+    );
+  }
+
+  @protected
+  AiLabel dco_decode_ai_label(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AiLabel.values[raw as int];
+  }
+
+  @protected
+  AiSignatureSummary dco_decode_ai_signature_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AiSignatureSummary(
+      schemaVersion: dco_decode_u_32(arr[0]),
+      revision: dco_decode_String(arr[1]),
+      generatorCount: dco_decode_u_32(arr[2]),
+      editorCount: dco_decode_u_32(arr[3]),
+      phraseCount: dco_decode_u_32(arr[4]),
+      builtIn: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  AnalysisError dco_decode_analysis_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return AnalysisError_Io(message: dco_decode_String(raw[1]));
+      case 1:
+        return AnalysisError_UnsupportedFormat(
+          mimeType: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return AnalysisError_ManifestNotFound();
+      case 3:
+        return AnalysisError_ManifestCorrupt(detail: dco_decode_String(raw[1]));
+      case 4:
+        return AnalysisError_CborDecode(detail: dco_decode_String(raw[1]));
+      case 5:
+        return AnalysisError_RemoteManifestUnavailable(
+          url: dco_decode_String(raw[1]),
+        );
+      case 6:
+        return AnalysisError_Other(message: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AiInfo dco_decode_box_autoadd_ai_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ai_info(raw);
+  }
+
+  @protected
+  AnalysisError dco_decode_box_autoadd_analysis_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_analysis_error(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CertificateInfo dco_decode_box_autoadd_certificate_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_certificate_info(raw);
+  }
+
+  @protected
+  DigitalSourceType dco_decode_box_autoadd_digital_source_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_digital_source_type(raw);
+  }
+
+  @protected
+  DistinguishedName dco_decode_box_autoadd_distinguished_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_distinguished_name(raw);
+  }
+
+  @protected
+  ExifInfo dco_decode_box_autoadd_exif_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_exif_info(raw);
+  }
+
+  @protected
+  ExifThumbnail dco_decode_box_autoadd_exif_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_exif_thumbnail(raw);
+  }
+
+  @protected
+  ExposureInfo dco_decode_box_autoadd_exposure_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_exposure_info(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GenerationParameters dco_decode_box_autoadd_generation_parameters(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_generation_parameters(raw);
+  }
+
+  @protected
+  GpsInfo dco_decode_box_autoadd_gps_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_gps_info(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  ImageRegion dco_decode_box_autoadd_image_region(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_image_region(raw);
+  }
+
+  @protected
+  IptcInfo dco_decode_box_autoadd_iptc_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_iptc_info(raw);
+  }
+
+  @protected
+  LensInfo dco_decode_box_autoadd_lens_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lens_info(raw);
+  }
+
+  @protected
+  MakerNote dco_decode_box_autoadd_maker_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_maker_note(raw);
+  }
+
+  @protected
+  ManifestThumbnail dco_decode_box_autoadd_manifest_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_manifest_thumbnail(raw);
+  }
+
+  @protected
+  MimeCheck dco_decode_box_autoadd_mime_check(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mime_check(raw);
+  }
+
+  @protected
+  ModelIdentity dco_decode_box_autoadd_model_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_model_identity(raw);
+  }
+
+  @protected
+  ProvenanceGraph dco_decode_box_autoadd_provenance_graph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_provenance_graph(raw);
+  }
+
+  @protected
+  RevocationSource dco_decode_box_autoadd_revocation_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_revocation_source(raw);
+  }
+
+  @protected
+  ScoringWeights dco_decode_box_autoadd_scoring_weights(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scoring_weights(raw);
+  }
+
+  @protected
+  SignerInfo dco_decode_box_autoadd_signer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_signer_info(raw);
+  }
+
+  @protected
+  SoftwareAgentInfo dco_decode_box_autoadd_software_agent_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_software_agent_info(raw);
+  }
+
+  @protected
+  ThumbnailComparison dco_decode_box_autoadd_thumbnail_comparison(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_thumbnail_comparison(raw);
+  }
+
+  @protected
+  TimestampInfo dco_decode_box_autoadd_timestamp_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_timestamp_info(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  XmpInfo dco_decode_box_autoadd_xmp_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_xmp_info(raw);
+  }
+
+  @protected
+  C2paAnalysisResult dco_decode_c_2_pa_analysis_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    return C2paAnalysisResult(
+      status: dco_decode_verification_status(arr[0]),
+      error: dco_decode_opt_box_autoadd_analysis_error(arr[1]),
+      validationStatuses: dco_decode_list_validation_status_entry(arr[2]),
+      signer: dco_decode_opt_box_autoadd_signer_info(arr[3]),
+      actions: dco_decode_list_content_action(arr[4]),
+      aiInfo: dco_decode_opt_box_autoadd_ai_info(arr[5]),
+      exifInfo: dco_decode_opt_box_autoadd_exif_info(arr[6]),
+      iptcInfo: dco_decode_opt_box_autoadd_iptc_info(arr[7]),
+      xmpInfo: dco_decode_opt_box_autoadd_xmp_info(arr[8]),
+      generationParameters: dco_decode_opt_box_autoadd_generation_parameters(
+        arr[9],
+      ),
+      modelIdentities: dco_decode_list_model_identity(arr[10]),
+      claimGenerator: dco_decode_opt_String(arr[11]),
+      title: dco_decode_opt_String(arr[12]),
+      format: dco_decode_opt_String(arr[13]),
+      instanceId: dco_decode_opt_String(arr[14]),
+      rawManifestJson: dco_decode_opt_String(arr[15]),
+      provenance: dco_decode_opt_box_autoadd_provenance_graph(arr[16]),
+      mimeCheck: dco_decode_opt_box_autoadd_mime_check(arr[17]),
+      thumbnailComparison: dco_decode_opt_box_autoadd_thumbnail_comparison(
+        arr[18],
+      ),
+      consistency: dco_decode_list_consistency_finding(arr[19]),
+    );
+  }
+
+  @protected
+  C2paThumbnails dco_decode_c_2_pa_thumbnails(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return C2paThumbnails(
+      activeLabel: dco_decode_opt_String(arr[0]),
+      manifests: dco_decode_Map_String_manifest_thumbnails_None(arr[1]),
+    );
+  }
+
+  @protected
+  CertificateInfo dco_decode_certificate_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return CertificateInfo(
+      subject: dco_decode_distinguished_name(arr[0]),
+      issuer: dco_decode_distinguished_name(arr[1]),
+      serialNumber: dco_decode_String(arr[2]),
+      notBefore: dco_decode_i_64(arr[3]),
+      notAfter: dco_decode_i_64(arr[4]),
+      keyAlgorithm: dco_decode_String(arr[5]),
+      keySizeBits: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      curve: dco_decode_opt_String(arr[7]),
+      signatureAlgorithm: dco_decode_String(arr[8]),
+      sha256Fingerprint: dco_decode_String(arr[9]),
+      extendedKeyUsages: dco_decode_list_String(arr[10]),
+      isCa: dco_decode_bool(arr[11]),
+      der: dco_decode_list_prim_u_8_strict(arr[12]),
+      revocation: dco_decode_revocation_info(arr[13]),
+    );
+  }
+
+  @protected
+  ConsistencyFinding dco_decode_consistency_finding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConsistencyFinding(
+      kind: dco_decode_inconsistency_kind(arr[0]),
+      severity: dco_decode_finding_severity(arr[1]),
+      values: dco_decode_list_finding_value(arr[2]),
+      explanation: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  ContentAction dco_decode_content_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ContentAction(
+      action: dco_decode_String(arr[0]),
+      softwareAgent: dco_decode_opt_String(arr[1]),
+      softwareAgentInfo: dco_decode_opt_box_autoadd_software_agent_info(arr[2]),
+      when: dco_decode_opt_String(arr[3]),
+      description: dco_decode_opt_String(arr[4]),
+      digitalSourceType: dco_decode_opt_box_autoadd_digital_source_type(arr[5]),
+      reason: dco_decode_opt_String(arr[6]),
+      ingredients: dco_decode_list_String(arr[7]),
+      parameters: dco_decode_list_action_parameter(arr[8]),
+      changes: dco_decode_list_action_change(arr[9]),
+      related: dco_decode_list_content_action(arr[10]),
+    );
+  }
+
+  @protected
+  DigitalSourceType dco_decode_digital_source_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DigitalSourceType_DigitalCapture();
+      case 1:
+        return DigitalSourceType_ComputationalCapture();
+      case 2:
+        return DigitalSourceType_NegativeFilm();
+      case 3:
+        return DigitalSourceType_PositiveFilm();
+      case 4:
+        return DigitalSourceType_Print();
+      case 5:
+        return DigitalSourceType_ScreenCapture();
+      case 6:
+        return DigitalSourceType_VirtualRecording();
+      case 7:
+        return DigitalSourceType_HumanEdits();
+      case 8:
+        return DigitalSourceType_MinorHumanEdits();
+      case 9:
+        return DigitalSourceType_AlgorithmicallyEnhanced();
+      case 10:
+        return DigitalSourceType_DigitalCreation();
+      case 11:
+        return DigitalSourceType_DataDrivenMedia();
+      case 12:
+        return DigitalSourceType_AlgorithmicMedia();
+      case 13:
+        return DigitalSourceType_TrainedAlgorithmicMedia();
+      case 14:
+        return DigitalSourceType_Composite();
+      case 15:
+        return DigitalSourceType_CompositeCapture();
+      case 16:
+        return DigitalSourceType_CompositeSynthetic();
+      case 17:
+        return DigitalSourceType_CompositeWithTrainedAlgorithmicMedia();
+      case 18:
+        return DigitalSourceType_Unknown(uri: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DistinguishedName dco_decode_distinguished_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DistinguishedName(
+      attributes: dco_decode_list_dn_attribute(arr[0]),
+      rfc4514: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DnAttribute dco_decode_dn_attribute(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DnAttribute(
+      attrType: dco_decode_String(arr[0]),
+      oid: dco_decode_String(arr[1]),
+      value: dco_decode_String(arr[2]),
+      rdnIndex: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  Evidence dco_decode_evidence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Evidence(
+      detector: dco_decode_evidence_detector(arr[0]),
+      kind: dco_decode_evidence_kind(arr[1]),
+      field: dco_decode_String(arr[2]),
+      matchedText: dco_decode_String(arr[3]),
+      strength: dco_decode_evidence_strength(arr[4]),
+      explanation: dco_decode_String(arr[5]),
+      generator: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  EvidenceDetector dco_decode_evidence_detector(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EvidenceDetector.values[raw as int];
+  }
+
+  @protected
+  EvidenceKind dco_decode_evidence_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EvidenceKind.values[raw as int];
+  }
+
+  @protected
+  EvidenceStrength dco_decode_evidence_strength(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EvidenceStrength.values[raw as int];
+  }
+
+  @protected
+  ExifInfo dco_decode_exif_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 30)
+      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
+    return ExifInfo(
+      software: dco_decode_opt_String(arr[0]),
+      make: dco_decode_opt_String(arr[1]),
+      model: dco_decode_opt_String(arr[2]),
+      artist: dco_decode_opt_String(arr[3]),
+      copyright: dco_decode_opt_String(arr[4]),
+      userComment: dco_decode_opt_String(arr[5]),
+      imageDescription: dco_decode_opt_String(arr[6]),
+      dateTimeOriginal: dco_decode_opt_String(arr[7]),
+      dateTime: dco_decode_opt_String(arr[8]),
+      dateTimeDigitized: dco_decode_opt_String(arr[9]),
+      offsetTime: dco_decode_opt_String(arr[10]),
+      offsetTimeOriginal: dco_decode_opt_String(arr[11]),
+      offsetTimeDigitized: dco_decode_opt_String(arr[12]),
+      subSecTime: dco_decode_opt_String(arr[13]),
+      subSecTimeOriginal: dco_decode_opt_String(arr[14]),
+      subSecTimeDigitized: dco_decode_opt_String(arr[15]),
+      orientation: dco_decode_opt_box_autoadd_u_32(arr[16]),
+      imageWidth: dco_decode_opt_box_autoadd_u_32(arr[17]),
+      imageHeight: dco_decode_opt_box_autoadd_u_32(arr[18]),
+      bodySerialNumber: dco_decode_opt_String(arr[19]),
+      cameraOwnerName: dco_decode_opt_String(arr[20]),
+      imageUniqueId: dco_decode_opt_String(arr[21]),
+      gps: dco_decode_opt_box_autoadd_gps_info(arr[22]),
+      lens: dco_decode_opt_box_autoadd_lens_info(arr[23]),
+      exposure: dco_decode_opt_box_autoadd_exposure_info(arr[24]),
+      thumbnail: dco_decode_opt_box_autoadd_exif_thumbnail(arr[25]),
+      makerNote: dco_decode_opt_box_autoadd_maker_note(arr[26]),
+      tags: dco_decode_list_exif_tag(arr[27]),
+      aiDetected: dco_decode_bool(arr[28]),
+      aiGenerator: dco_decode_opt_String(arr[29]),
+    );
+  }
+
+  @protected
+  ExifTag dco_decode_exif_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExifTag(
+      ifd: dco_decode_u_16(arr[0]),
+      group: dco_decode_String(arr[1]),
+      id: dco_decode_u_16(arr[2]),
+      name: dco_decode_String(arr[3]),
+      value: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  ExifThumbnail dco_decode_exif_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ExifThumbnail(
+      mimeType: dco_decode_String(arr[0]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      data: dco_decode_list_prim_u_8_strict(arr[3]),
+    );
+  }
+
+  @protected
+  ExposureInfo dco_decode_exposure_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ExposureInfo(
+      exposureTime: dco_decode_opt_box_autoadd_f_64(arr[0]),
+      fNumber: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      iso: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      exposureBias: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      exposureProgram: dco_decode_opt_String(arr[4]),
+      meteringMode: dco_decode_opt_String(arr[5]),
+      flash: dco_decode_opt_String(arr[6]),
+      whiteBalance: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FindingSeverity dco_decode_finding_severity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FindingSeverity.values[raw as int];
+  }
+
+  @protected
+  FindingValue dco_decode_finding_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FindingValue(
+      source: dco_decode_String(arr[0]),
+      field: dco_decode_String(arr[1]),
+      value: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  GenerationParameters dco_decode_generation_parameters(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return GenerationParameters(
+      tool: dco_decode_generation_tool(arr[0]),
+      toolVersion: dco_decode_opt_String(arr[1]),
+      prompt: dco_decode_opt_String(arr[2]),
+      negativePrompt: dco_decode_opt_String(arr[3]),
+      steps: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      sampler: dco_decode_opt_String(arr[5]),
+      scheduler: dco_decode_opt_String(arr[6]),
+      cfgScale: dco_decode_opt_box_autoadd_f_64(arr[7]),
+      seed: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[10]),
+      model: dco_decode_opt_String(arr[11]),
+      modelHash: dco_decode_opt_String(arr[12]),
+      loras: dco_decode_list_lora_reference(arr[13]),
+      workflow: dco_decode_opt_String(arr[14]),
+      otherSettings: dco_decode_list_generation_setting(arr[15]),
+      sourceChunks: dco_decode_list_String(arr[16]),
+    );
+  }
+
+  @protected
+  GenerationSetting dco_decode_generation_setting(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GenerationSetting(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  GenerationTool dco_decode_generation_tool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GenerationTool.values[raw as int];
+  }
+
+  @protected
+  GpsInfo dco_decode_gps_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GpsInfo(
+      latitude: dco_decode_opt_box_autoadd_f_64(arr[0]),
+      longitude: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      altitude: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      timestamp: dco_decode_opt_String(arr[3]),
+      imageDirection: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      imageDirectionRef: dco_decode_opt_String(arr[5]),
+      mapDatum: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImageRegion dco_decode_image_region(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImageRegion(
+      x: dco_decode_u_32(arr[0]),
+      y: dco_decode_u_32(arr[1]),
+      width: dco_decode_u_32(arr[2]),
+      height: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  InconsistencyKind dco_decode_inconsistency_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return InconsistencyKind.values[raw as int];
+  }
+
+  @protected
+  IngredientEdge dco_decode_ingredient_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return IngredientEdge(
+      fromLabel: dco_decode_String(arr[0]),
+      toLabel: dco_decode_opt_String(arr[1]),
+      ingredientTitle: dco_decode_String(arr[2]),
+      ingredientFormat: dco_decode_opt_String(arr[3]),
+      ingredientInstanceId: dco_decode_opt_String(arr[4]),
+      relationship: dco_decode_ingredient_relationship(arr[5]),
+      validationStatuses: dco_decode_list_validation_status_entry(arr[6]),
+    );
+  }
+
+  @protected
+  IngredientRelationship dco_decode_ingredient_relationship(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IngredientRelationship.values[raw as int];
+  }
+
+  @protected
+  IngredientThumbnail dco_decode_ingredient_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IngredientThumbnail(
+      title: dco_decode_String(arr[0]),
+      instanceId: dco_decode_String(arr[1]),
+      manifestLabel: dco_decode_opt_String(arr[2]),
+      thumbnail: dco_decode_manifest_thumbnail(arr[3]),
+    );
+  }
+
+  @protected
+  IptcInfo dco_decode_iptc_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 18)
+      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+    return IptcInfo(
+      objectName: dco_decode_opt_String(arr[0]),
+      headline: dco_decode_opt_String(arr[1]),
+      caption: dco_decode_opt_String(arr[2]),
+      captionWriter: dco_decode_opt_String(arr[3]),
+      keywords: dco_decode_list_String(arr[4]),
+      byLine: dco_decode_list_String(arr[5]),
+      byLineTitle: dco_decode_opt_String(arr[6]),
+      credit: dco_decode_opt_String(arr[7]),
+      source: dco_decode_opt_String(arr[8]),
+      copyrightNotice: dco_decode_opt_String(arr[9]),
+      specialInstructions: dco_decode_opt_String(arr[10]),
+      dateCreated: dco_decode_opt_String(arr[11]),
+      city: dco_decode_opt_String(arr[12]),
+      subLocation: dco_decode_opt_String(arr[13]),
+      provinceState: dco_decode_opt_String(arr[14]),
+      countryCode: dco_decode_opt_String(arr[15]),
+      country: dco_decode_opt_String(arr[16]),
+      transmissionReference: dco_decode_opt_String(arr[17]),
+    );
+  }
+
+  @protected
+  LensInfo dco_decode_lens_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LensInfo(
+      make: dco_decode_opt_String(arr[0]),
+      model: dco_decode_opt_String(arr[1]),
+      serialNumber: dco_decode_opt_String(arr[2]),
+      specification: dco_decode_list_prim_f_64_strict(arr[3]),
+      focalLength: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      focalLength35Mm: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ActionChange> dco_decode_list_action_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_action_change).toList();
+  }
+
+  @protected
+  List<ActionParameter> dco_decode_list_action_parameter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_action_parameter).toList();
+  }
+
+  @protected
+  List<CertificateInfo> dco_decode_list_certificate_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_certificate_info).toList();
+  }
+
+  @protected
+  List<ConsistencyFinding> dco_decode_list_consistency_finding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_consistency_finding).toList();
+  }
+
+  @protected
+  List<ContentAction> dco_decode_list_content_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_content_action).toList();
+  }
+
+  @protected
+  List<DnAttribute> dco_decode_list_dn_attribute(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dn_attribute).toList();
+  }
+
+  @protected
+  List<Evidence> dco_decode_list_evidence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_evidence).toList();
+  }
+
+  @protected
+  List<ExifTag> dco_decode_list_exif_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_exif_tag).toList();
+  }
+
+  @protected
+  List<FindingValue> dco_decode_list_finding_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_finding_value).toList();
+  }
+
+  @protected
+  List<GenerationSetting> dco_decode_list_generation_setting(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_generation_setting).toList();
+  }
+
+  @protected
+  List<IngredientEdge> dco_decode_list_ingredient_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ingredient_edge).toList();
+  }
+
+  @protected
+  List<IngredientThumbnail> dco_decode_list_ingredient_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ingredient_thumbnail).toList();
+  }
+
+  @protected
+  List<LoraReference> dco_decode_list_lora_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lora_reference).toList();
+  }
+
+  @protected
+  List<MakerNoteTag> dco_decode_list_maker_note_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_maker_note_tag).toList();
+  }
+
+  @protected
+  List<ManifestNode> dco_decode_list_manifest_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_manifest_node).toList();
+  }
+
+  @protected
+  List<ModelIdentity> dco_decode_list_model_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_model_identity).toList();
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, ManifestThumbnails)>
+  dco_decode_list_record_string_manifest_thumbnails(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_manifest_thumbnails)
+        .toList();
+  }
+
+  @protected
+  List<RegionRange> dco_decode_list_region_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_region_range).toList();
+  }
+
+  @protected
+  List<ValidationStatusEntry> dco_decode_list_validation_status_entry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_validation_status_entry)
+        .toList();
+  }
+
+  @protected
+  LoraReference dco_decode_lora_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LoraReference(
+      name: dco_decode_String(arr[0]),
+      weight: dco_decode_opt_box_autoadd_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  MakerNote dco_decode_maker_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MakerNote(
+      vendor: dco_decode_String(arr[0]),
+      serialNumber: dco_decode_opt_String(arr[1]),
+      lensModel: dco_decode_opt_String(arr[2]),
+      firmwareVersion: dco_decode_opt_String(arr[3]),
+      shutterCount: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      tags: dco_decode_list_maker_note_tag(arr[5]),
+    );
+  }
+
+  @protected
+  MakerNoteTag dco_decode_maker_note_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MakerNoteTag(
+      id: dco_decode_u_16(arr[0]),
+      name: dco_decode_String(arr[1]),
+      value: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ManifestNode dco_decode_manifest_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ManifestNode(
+      label: dco_decode_String(arr[0]),
+      isActive: dco_decode_bool(arr[1]),
+      title: dco_decode_opt_String(arr[2]),
+      format: dco_decode_opt_String(arr[3]),
+      instanceId: dco_decode_opt_String(arr[4]),
+      claimGenerator: dco_decode_opt_String(arr[5]),
+      signer: dco_decode_opt_box_autoadd_signer_info(arr[6]),
+      actions: dco_decode_list_content_action(arr[7]),
+      validationStatuses: dco_decode_list_validation_status_entry(arr[8]),
+    );
+  }
+
+  @protected
+  ManifestThumbnail dco_decode_manifest_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ManifestThumbnail(
+      mimeType: dco_decode_String(arr[0]),
+      identifier: dco_decode_opt_String(arr[1]),
+      data: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  ManifestThumbnails dco_decode_manifest_thumbnails(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ManifestThumbnails(
+      claim: dco_decode_opt_box_autoadd_manifest_thumbnail(arr[0]),
+      ingredients: dco_decode_list_ingredient_thumbnail(arr[1]),
+    );
+  }
+
+  @protected
+  MimeCheck dco_decode_mime_check(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MimeCheck(
+      claimedMimeType: dco_decode_String(arr[0]),
+      detectedMimeType: dco_decode_opt_String(arr[1]),
+      mismatch: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ModelIdentity dco_decode_model_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ModelIdentity(
+      vendor: dco_decode_String(arr[0]),
+      product: dco_decode_String(arr[1]),
+      model: dco_decode_opt_String(arr[2]),
+      version: dco_decode_opt_String(arr[3]),
+      isAiGenerator: dco_decode_bool(arr[4]),
+      field: dco_decode_String(arr[5]),
+      matchedText: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AiInfo? dco_decode_opt_box_autoadd_ai_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_ai_info(raw);
+  }
+
+  @protected
+  AnalysisError? dco_decode_opt_box_autoadd_analysis_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_analysis_error(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  CertificateInfo? dco_decode_opt_box_autoadd_certificate_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_certificate_info(raw);
+  }
+
+  @protected
+  DigitalSourceType? dco_decode_opt_box_autoadd_digital_source_type(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_digital_source_type(raw);
+  }
+
+  @protected
+  DistinguishedName? dco_decode_opt_box_autoadd_distinguished_name(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_distinguished_name(raw);
+  }
+
+  @protected
+  ExifInfo? dco_decode_opt_box_autoadd_exif_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_exif_info(raw);
+  }
+
+  @protected
+  ExifThumbnail? dco_decode_opt_box_autoadd_exif_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_exif_thumbnail(raw);
+  }
+
+  @protected
+  ExposureInfo? dco_decode_opt_box_autoadd_exposure_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_exposure_info(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  GenerationParameters? dco_decode_opt_box_autoadd_generation_parameters(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_generation_parameters(raw);
+  }
+
+  @protected
+  GpsInfo? dco_decode_opt_box_autoadd_gps_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_gps_info(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ImageRegion? dco_decode_opt_box_autoadd_image_region(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_image_region(raw);
+  }
+
+  @protected
+  IptcInfo? dco_decode_opt_box_autoadd_iptc_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_iptc_info(raw);
+  }
+
+  @protected
+  LensInfo? dco_decode_opt_box_autoadd_lens_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lens_info(raw);
+  }
+
+  @protected
+  MakerNote? dco_decode_opt_box_autoadd_maker_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_maker_note(raw);
+  }
+
+  @protected
+  ManifestThumbnail? dco_decode_opt_box_autoadd_manifest_thumbnail(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_manifest_thumbnail(raw);
+  }
+
+  @protected
+  MimeCheck? dco_decode_opt_box_autoadd_mime_check(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_mime_check(raw);
+  }
+
+  @protected
+  ModelIdentity? dco_decode_opt_box_autoadd_model_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_model_identity(raw);
+  }
+
+  @protected
+  ProvenanceGraph? dco_decode_opt_box_autoadd_provenance_graph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_provenance_graph(raw);
+  }
+
+  @protected
+  RevocationSource? dco_decode_opt_box_autoadd_revocation_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_revocation_source(raw);
+  }
+
+  @protected
+  SignerInfo? dco_decode_opt_box_autoadd_signer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_signer_info(raw);
+  }
+
+  @protected
+  SoftwareAgentInfo? dco_decode_opt_box_autoadd_software_agent_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_software_agent_info(raw);
+  }
+
+  @protected
+  ThumbnailComparison? dco_decode_opt_box_autoadd_thumbnail_comparison(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_thumbnail_comparison(raw);
+  }
+
+  @protected
+  TimestampInfo? dco_decode_opt_box_autoadd_timestamp_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_timestamp_info(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  XmpInfo? dco_decode_opt_box_autoadd_xmp_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_xmp_info(raw);
+  }
+
+  @protected
+  ProvenanceGraph dco_decode_provenance_graph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProvenanceGraph(
+      activeLabel: dco_decode_opt_String(arr[0]),
+      nodes: dco_decode_list_manifest_node(arr[1]),
+      edges: dco_decode_list_ingredient_edge(arr[2]),
+    );
+  }
+
+  @protected
+  (String, ManifestThumbnails) dco_decode_record_string_manifest_thumbnails(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_manifest_thumbnails(arr[1]));
+  }
+
+  @protected
+  RegionRange dco_decode_region_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RegionRange_Spatial(
+          shape: dco_decode_String(raw[1]),
+          unit: dco_decode_opt_String(raw[2]),
+          x: dco_decode_opt_box_autoadd_f_64(raw[3]),
+          y: dco_decode_opt_box_autoadd_f_64(raw[4]),
+          width: dco_decode_opt_box_autoadd_f_64(raw[5]),
+          height: dco_decode_opt_box_autoadd_f_64(raw[6]),
+        );
+      case 1:
+        return RegionRange_Temporal(
+          start: dco_decode_opt_String(raw[1]),
+          end: dco_decode_opt_String(raw[2]),
+        );
+      case 2:
+        return RegionRange_Frame(
+          start: dco_decode_opt_box_autoadd_i_64(raw[1]),
+          end: dco_decode_opt_box_autoadd_i_64(raw[2]),
+        );
+      case 3:
+        return RegionRange_Other(json: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RevocationInfo dco_decode_revocation_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RevocationInfo(
+      state: dco_decode_revocation_state(arr[0]),
+      source: dco_decode_opt_box_autoadd_revocation_source(arr[1]),
+      revokedAt: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      reason: dco_decode_opt_String(arr[3]),
+      thisUpdate: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      nextUpdate: dco_decode_opt_box_autoadd_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  RevocationSource dco_decode_revocation_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RevocationSource.values[raw as int];
+  }
+
+  @protected
+  RevocationState dco_decode_revocation_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RevocationState.values[raw as int];
+  }
+
+  @protected
+  ScoringWeights dco_decode_scoring_weights(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ScoringWeights(
+      definitive: dco_decode_f_64(arr[0]),
+      strong: dco_decode_f_64(arr[1]),
+      weak: dco_decode_f_64(arr[2]),
+      cameraDiscount: dco_decode_f_64(arr[3]),
+      likelyThreshold: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  SignerInfo dco_decode_signer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SignerInfo(
+      name: dco_decode_opt_String(arr[0]),
+      organization: dco_decode_opt_String(arr[1]),
+      issuedBy: dco_decode_opt_String(arr[2]),
+      timestamp: dco_decode_opt_String(arr[3]),
+      certificateChain: dco_decode_list_certificate_info(arr[4]),
+      timestampToken: dco_decode_opt_box_autoadd_timestamp_info(arr[5]),
+    );
+  }
+
+  @protected
+  SoftwareAgentInfo dco_decode_software_agent_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SoftwareAgentInfo(
+      name: dco_decode_String(arr[0]),
+      version: dco_decode_opt_String(arr[1]),
+      icon: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ThumbnailComparison dco_decode_thumbnail_comparison(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ThumbnailComparison(
+      comparedWidth: dco_decode_u_32(arr[0]),
+      comparedHeight: dco_decode_u_32(arr[1]),
+      difference: dco_decode_f_64(arr[2]),
+      changedFraction: dco_decode_f_64(arr[3]),
+      changedRegion: dco_decode_opt_box_autoadd_image_region(arr[4]),
+      contentDiffers: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  TimestampInfo dco_decode_timestamp_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return TimestampInfo(
+      tsaName: dco_decode_opt_String(arr[0]),
+      tsaCertificate: dco_decode_opt_box_autoadd_certificate_info(arr[1]),
+      genTime: dco_decode_i_64(arr[2]),
+      hashAlgorithm: dco_decode_String(arr[3]),
+      messageImprint: dco_decode_String(arr[4]),
+      policy: dco_decode_opt_String(arr[5]),
+      serialNumber: dco_decode_String(arr[6]),
+      signatureValid: dco_decode_bool(arr[7]),
+      trusted: dco_decode_bool(arr[8]),
+      signerValidityEvaluatedAtTsaTime: dco_decode_bool(arr[9]),
+      signerValidAtGenTime: dco_decode_opt_box_autoadd_bool(arr[10]),
+    );
+  }
+
+  @protected
+  TrustStoreSummary dco_decode_trust_store_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TrustStoreSummary(
+      anchorCount: dco_decode_u_32(arr[0]),
+      allowedCertificateCount: dco_decode_u_32(arr[1]),
+      ekuOids: dco_decode_list_String(arr[2]),
+      tsaAnchorCount: dco_decode_u_32(arr[3]),
+      crlCount: dco_decode_u_32(arr[4]),
+      ocspResponseCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  ValidationCategory dco_decode_validation_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValidationCategory.values[raw as int];
+  }
+
+  @protected
+  ValidationStatusCode dco_decode_validation_status_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ValidationStatusCode_ClaimSignatureValidated();
+      case 1:
+        return ValidationStatusCode_ClaimSignatureInsideValidity();
+      case 2:
+        return ValidationStatusCode_SigningCredentialTrusted();
+      case 3:
+        return ValidationStatusCode_SigningCredentialNotRevoked();
+      case 4:
+        return ValidationStatusCode_TimeStampTrusted();
+      case 5:
+        return ValidationStatusCode_TimeStampValidated();
+      case 6:
+        return ValidationStatusCode_AssertionHashedUriMatch();
+      case 7:
+        return ValidationStatusCode_AssertionDataHashMatch();
+      case 8:
+        return ValidationStatusCode_AssertionBmffHashMatch();
+      case 9:
+        return ValidationStatusCode_AssertionBoxesHashMatch();
+      case 10:
+        return ValidationStatusCode_AssertionCollectionHashMatch();
+      case 11:
+        return ValidationStatusCode_AssertionAccessible();
+      case 12:
+        return ValidationStatusCode_IngredientManifestValidated();
+      case 13:
+        return ValidationStatusCode_IngredientClaimSignatureValidated();
+      case 14:
+        return ValidationStatusCode_AlgorithmDeprecated();
+      case 15:
+        return ValidationStatusCode_SigningCredentialOcspSkipped();
+      case 16:
+        return ValidationStatusCode_SigningCredentialOcspInaccessible();
+      case 17:
+        return ValidationStatusCode_TimeStampUntrusted();
+      case 18:
+        return ValidationStatusCode_TimeStampOutsideValidity();
+      case 19:
+        return ValidationStatusCode_IngredientUnknownProvenance();
+      case 20:
+        return ValidationStatusCode_ManifestUnknownProvenance();
+      case 21:
+        return ValidationStatusCode_ClaimMissing();
+      case 22:
+        return ValidationStatusCode_ClaimMultiple();
+      case 23:
+        return ValidationStatusCode_ClaimHardBindingsMissing();
+      case 24:
+        return ValidationStatusCode_ClaimRequiredMissing();
+      case 25:
+        return ValidationStatusCode_ClaimCborInvalid();
+      case 26:
+        return ValidationStatusCode_ClaimSignatureMissing();
+      case 27:
+        return ValidationStatusCode_ClaimSignatureMismatch();
+      case 28:
+        return ValidationStatusCode_ClaimSignatureOutsideValidity();
+      case 29:
+        return ValidationStatusCode_AlgorithmUnsupported();
+      case 30:
+        return ValidationStatusCode_SigningCredentialUntrusted();
+      case 31:
+        return ValidationStatusCode_SigningCredentialInvalid();
+      case 32:
+        return ValidationStatusCode_SigningCredentialExpired();
+      case 33:
+        return ValidationStatusCode_SigningCredentialRevoked();
+      case 34:
+        return ValidationStatusCode_SigningCredentialOcspUnknown();
+      case 35:
+        return ValidationStatusCode_TimeStampMismatch();
+      case 36:
+        return ValidationStatusCode_TimeStampMalformed();
+      case 37:
+        return ValidationStatusCode_AssertionHashedUriMismatch();
+      case 38:
+        return ValidationStatusCode_AssertionDataHashMismatch();
+      case 39:
+        return ValidationStatusCode_AssertionDataHashMalformed();
+      case 40:
+        return ValidationStatusCode_AssertionBmffHashMismatch();
+      case 41:
+        return ValidationStatusCode_AssertionBmffHashMalformed();
+      case 42:
+        return ValidationStatusCode_AssertionBoxesHashMismatch();
+      case 43:
+        return ValidationStatusCode_AssertionBoxesHashUnknownBox();
+      case 44:
+        return ValidationStatusCode_AssertionCollectionHashMismatch();
+      case 45:
+        return ValidationStatusCode_AssertionCollectionHashMalformed();
+      case 46:
+        return ValidationStatusCode_AssertionCollectionHashIncorrectFileCount();
+      case 47:
+        return ValidationStatusCode_AssertionCollectionHashInvalidUri();
+      case 48:
+        return ValidationStatusCode_HashedUriMissing();
+      case 49:
+        return ValidationStatusCode_HashedUriMismatch();
+      case 50:
+        return ValidationStatusCode_AssertionMissing();
+      case 51:
+        return ValidationStatusCode_AssertionRequiredMissing();
+      case 52:
+        return ValidationStatusCode_AssertionMultipleHardBindings();
+      case 53:
+        return ValidationStatusCode_AssertionUndeclared();
+      case 54:
+        return ValidationStatusCode_AssertionInaccessible();
+      case 55:
+        return ValidationStatusCode_AssertionNotRedacted();
+      case 56:
+        return ValidationStatusCode_AssertionSelfRedacted();
+      case 57:
+        return ValidationStatusCode_AssertionJsonInvalid();
+      case 58:
+        return ValidationStatusCode_AssertionCborInvalid();
+      case 59:
+        return ValidationStatusCode_AssertionActionIngredientMismatch();
+      case 60:
+        return ValidationStatusCode_AssertionActionMalformed();
+      case 61:
+        return ValidationStatusCode_AssertionActionRedacted();
+      case 62:
+        return ValidationStatusCode_AssertionActionRedactionMismatch();
+      case 63:
+        return ValidationStatusCode_AssertionCloudDataHardBinding();
+      case 64:
+        return ValidationStatusCode_AssertionCloudDataActions();
+      case 65:
+        return ValidationStatusCode_AssertionCloudDataLabelMismatch();
+      case 66:
+        return ValidationStatusCode_IngredientHashedUriMissing();
+      case 67:
+        return ValidationStatusCode_IngredientHashedUriMismatch();
+      case 68:
+        return ValidationStatusCode_IngredientManifestMissing();
+      case 69:
+        return ValidationStatusCode_IngredientManifestMismatch();
+      case 70:
+        return ValidationStatusCode_IngredientClaimSignatureMissing();
+      case 71:
+        return ValidationStatusCode_IngredientClaimSignatureMismatch();
+      case 72:
+        return ValidationStatusCode_ManifestInaccessible();
+      case 73:
+        return ValidationStatusCode_ManifestMultipleParents();
+      case 74:
+        return ValidationStatusCode_ManifestUpdateInvalid();
+      case 75:
+        return ValidationStatusCode_ManifestUpdateWrongParents();
+      case 76:
+        return ValidationStatusCode_ManifestTimestampInvalid();
+      case 77:
+        return ValidationStatusCode_ManifestTimestampWrongParents();
+      case 78:
+        return ValidationStatusCode_ManifestCompressedInvalid();
+      case 79:
+        return ValidationStatusCode_GeneralError();
+      case 80:
+        return ValidationStatusCode_Unknown(code: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ValidationStatusEntry dco_decode_validation_status_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ValidationStatusEntry(
+      code: dco_decode_String(arr[0]),
+      kind: dco_decode_validation_status_code(arr[1]),
+      category: dco_decode_validation_category(arr[2]),
+      url: dco_decode_opt_String(arr[3]),
+      explanation: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  VerificationStatus dco_decode_verification_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return VerificationStatus_Verified();
+      case 1:
+        return VerificationStatus_SignatureInvalid();
+      case 2:
+        return VerificationStatus_CertificateExpired();
+      case 3:
+        return VerificationStatus_CertificateUntrusted();
+      case 4:
+        return VerificationStatus_CertificateRevoked();
+      case 5:
+        return VerificationStatus_ContentModified();
+      case 6:
+        return VerificationStatus_ValidationFailed(
+          codes: dco_decode_list_String(raw[1]),
+        );
+      case 7:
+        return VerificationStatus_NoManifest();
+      case 8:
+        return VerificationStatus_Error(message: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  XmpInfo dco_decode_xmp_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return XmpInfo(
+      digitalSourceType: dco_decode_opt_box_autoadd_digital_source_type(arr[0]),
+      credit: dco_decode_opt_String(arr[1]),
+      creatorTool: dco_decode_opt_String(arr[2]),
+      creators: dco_decode_list_String(arr[3]),
+      provenance: dco_decode_opt_String(arr[4]),
+      container: dco_decode_String(arr[5]),
+      packet: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  ChunkedAsset
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  ChunkedAsset
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  ChunkedAsset
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  Map<String, ManifestThumbnails>
+  sse_decode_Map_String_manifest_thumbnails_None(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_manifest_thumbnails(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  ChunkedAsset
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ChunkedAssetImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  ActionChange sse_decode_action_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_regions = sse_decode_list_region_range(deserializer);
+    return ActionChange(description: var_description, regions: var_regions);
+  }
+
+  @protected
+  ActionParameter sse_decode_action_parameter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return ActionParameter(key: var_key, value: var_value);
+  }
+
+  @protected
+  AiInfo sse_decode_ai_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isAiGenerated = sse_decode_bool(deserializer);
+    var var_likelihood = sse_decode_f_64(deserializer);
+    var var_label = sse_decode_ai_label(deserializer);
+    var var_generatorName = sse_decode_opt_String(deserializer);
+    var var_modelName = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_opt_box_autoadd_model_identity(deserializer);
+    var var_detectionSource = sse_decode_opt_String(deserializer);
+    var var_evidence = sse_decode_list_evidence(deserializer);
+    return AiInfo(
+      isAiGenerated: var_isAiGenerated,
+      likelihood: var_likelihood,
+      label: var_label,
+      generatorName: var_generatorName,
+      modelName: var_modelName,
+      model: var_model,
+      detectionSource: var_detectionSource,
+      evidence: var_evidence,
+    );
+  }
+
+  @protected
+  AiLabel sse_decode_ai_label(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AiLabel.values[inner];
+  }
+
+  @protected
+  AiSignatureSummary sse_decode_ai_signature_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_schemaVersion = sse_decode_u_32(deserializer);
+    var var_revision = sse_decode_String(deserializer);
+    var var_generatorCount = sse_decode_u_32(deserializer);
+    var var_editorCount = sse_decode_u_32(deserializer);
+    var var_phraseCount = sse_decode_u_32(deserializer);
+    var var_builtIn = sse_decode_bool(deserializer);
+    return AiSignatureSummary(
+      schemaVersion: var_schemaVersion,
+      revision: var_revision,
+      generatorCount: var_generatorCount,
+      editorCount: var_editorCount,
+      phraseCount: var_phraseCount,
+      builtIn: var_builtIn,
+    );
+  }
+
+  @protected
+  AnalysisError sse_decode_analysis_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_message = sse_decode_String(deserializer);
+        return AnalysisError_Io(message: var_message);
+      case 1:
+        var var_mimeType = sse_decode_String(deserializer);
+        return AnalysisError_UnsupportedFormat(mimeType: var_mimeType);
+      case 2:
+        return AnalysisError_ManifestNotFound();
+      case 3:
+        var var_detail = sse_decode_String(deserializer);
+        return AnalysisError_ManifestCorrupt(detail: var_detail);
+      case 4:
+        var var_detail = sse_decode_String(deserializer);
+        return AnalysisError_CborDecode(detail: var_detail);
+      case 5:
+        var var_url = sse_decode_String(deserializer);
+        return AnalysisError_RemoteManifestUnavailable(url: var_url);
+      case 6:
+        var var_message = sse_decode_String(deserializer);
+        return AnalysisError_Other(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AiInfo sse_decode_box_autoadd_ai_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ai_info(deserializer));
+  }
+
+  @protected
+  AnalysisError sse_decode_box_autoadd_analysis_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_analysis_error(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CertificateInfo sse_decode_box_autoadd_certificate_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_certificate_info(deserializer));
+  }
+
+  @protected
+  DigitalSourceType sse_decode_box_autoadd_digital_source_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_digital_source_type(deserializer));
+  }
+
+  @protected
+  DistinguishedName sse_decode_box_autoadd_distinguished_name(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_distinguished_name(deserializer));
+  }
+
+  @protected
+  ExifInfo sse_decode_box_autoadd_exif_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_exif_info(deserializer));
+  }
+
+  @protected
+  ExifThumbnail sse_decode_box_autoadd_exif_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_exif_thumbnail(deserializer));
+  }
+
+  @protected
+  ExposureInfo sse_decode_box_autoadd_exposure_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_exposure_info(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  GenerationParameters sse_decode_box_autoadd_generation_parameters(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_generation_parameters(deserializer));
+  }
+
+  @protected
+  GpsInfo sse_decode_box_autoadd_gps_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_gps_info(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  ImageRegion sse_decode_box_autoadd_image_region(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_image_region(deserializer));
+  }
+
+  @protected
+  IptcInfo sse_decode_box_autoadd_iptc_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_iptc_info(deserializer));
+  }
+
+  @protected
+  LensInfo sse_decode_box_autoadd_lens_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lens_info(deserializer));
+  }
+
+  @protected
+  MakerNote sse_decode_box_autoadd_maker_note(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_maker_note(deserializer));
+  }
+
+  @protected
+  ManifestThumbnail sse_decode_box_autoadd_manifest_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_manifest_thumbnail(deserializer));
+  }
+
+  @protected
+  MimeCheck sse_decode_box_autoadd_mime_check(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mime_check(deserializer));
+  }
+
+  @protected
+  ModelIdentity sse_decode_box_autoadd_model_identity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_model_identity(deserializer));
+  }
+
+  @protected
+  ProvenanceGraph sse_decode_box_autoadd_provenance_graph(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_provenance_graph(deserializer));
+  }
+
+  @protected
+  RevocationSource sse_decode_box_autoadd_revocation_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_revocation_source(deserializer));
+  }
+
+  @protected
+  ScoringWeights sse_decode_box_autoadd_scoring_weights(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scoring_weights(deserializer));
+  }
+
+  @protected
+  SignerInfo sse_decode_box_autoadd_signer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_signer_info(deserializer));
+  }
+
+  @protected
+  SoftwareAgentInfo sse_decode_box_autoadd_software_agent_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_software_agent_info(deserializer));
+  }
+
+  @protected
+  ThumbnailComparison sse_decode_box_autoadd_thumbnail_comparison(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_thumbnail_comparison(deserializer));
+  }
+
+  @protected
+  TimestampInfo sse_decode_box_autoadd_timestamp_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_timestamp_info(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  XmpInfo sse_decode_box_autoadd_xmp_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_xmp_info(deserializer));
+  }
+
+  @protected
+  C2paAnalysisResult sse_decode_c_2_pa_analysis_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_verification_status(deserializer);
+    var var_error = sse_decode_opt_box_autoadd_analysis_error(deserializer);
+    var var_validationStatuses = sse_decode_list_validation_status_entry(
+      deserializer,
+    );
+    var var_signer = sse_decode_opt_box_autoadd_signer_info(deserializer);
+    var var_actions = sse_decode_list_content_action(deserializer);
+    var var_aiInfo = sse_decode_opt_box_autoadd_ai_info(deserializer);
+    var var_exifInfo = sse_decode_opt_box_autoadd_exif_info(deserializer);
+    var var_iptcInfo = sse_decode_opt_box_autoadd_iptc_info(deserializer);
+    var var_xmpInfo = sse_decode_opt_box_autoadd_xmp_info(deserializer);
+    var var_generationParameters =
+        sse_decode_opt_box_autoadd_generation_parameters(deserializer);
+    var var_modelIdentities = sse_decode_list_model_identity(deserializer);
+    var var_claimGenerator = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_format = sse_decode_opt_String(deserializer);
+    var var_instanceId = sse_decode_opt_String(deserializer);
+    var var_rawManifestJson = sse_decode_opt_String(deserializer);
+    var var_provenance = sse_decode_opt_box_autoadd_provenance_graph(
+      deserializer,
+    );
+    var var_mimeCheck = sse_decode_opt_box_autoadd_mime_check(deserializer);
+    var var_thumbnailComparison =
+        sse_decode_opt_box_autoadd_thumbnail_comparison(deserializer);
+    var var_consistency = sse_decode_list_consistency_finding(deserializer);
+    return C2paAnalysisResult(
+      status: var_status,
+      error: var_error,
+      validationStatuses: var_validationStatuses,
+      signer: var_signer,
+      actions: var_actions,
+      aiInfo: var_aiInfo,
+      exifInfo: var_exifInfo,
+      iptcInfo: var_iptcInfo,
+      xmpInfo: var_xmpInfo,
+      generationParameters: var_generationParameters,
+      modelIdentities: var_modelIdentities,
+      claimGenerator: var_claimGenerator,
+      title: var_title,
+      format: var_format,
+      instanceId: var_instanceId,
+      rawManifestJson: var_rawManifestJson,
+      provenance: var_provenance,
+      mimeCheck: var_mimeCheck,
+      thumbnailComparison: var_thumbnailComparison,
+      consistency: var_consistency,
+    );
+  }
+
+  @protected
+  C2paThumbnails sse_decode_c_2_pa_thumbnails(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_activeLabel = sse_decode_opt_String(deserializer);
+    var var_manifests = sse_decode_Map_String_manifest_thumbnails_None(
+      deserializer,
+    );
+    return C2paThumbnails(
+      activeLabel: var_activeLabel,
+      manifests: var_manifests,
+    );
+  }
+
+  @protected
+  CertificateInfo sse_decode_certificate_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subject = sse_decode_distinguished_name(deserializer);
+    var var_issuer = sse_decode_distinguished_name(deserializer);
+    var var_serialNumber = sse_decode_String(deserializer);
+    var var_notBefore = sse_decode_i_64(deserializer);
+    var var_notAfter = sse_decode_i_64(deserializer);
+    var var_keyAlgorithm = sse_decode_String(deserializer);
+    var var_keySizeBits = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_curve = sse_decode_opt_String(deserializer);
+    var var_signatureAlgorithm = sse_decode_String(deserializer);
+    var var_sha256Fingerprint = sse_decode_String(deserializer);
+    var var_extendedKeyUsages = sse_decode_list_String(deserializer);
+    var var_isCa = sse_decode_bool(deserializer);
+    var var_der = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_revocation = sse_decode_revocation_info(deserializer);
+    return CertificateInfo(
+      subject: var_subject,
+      issuer: var_issuer,
+      serialNumber: var_serialNumber,
+      notBefore: var_notBefore,
+      notAfter: var_notAfter,
+      keyAlgorithm: var_keyAlgorithm,
+      keySizeBits: var_keySizeBits,
+      curve: var_curve,
+      signatureAlgorithm: var_signatureAlgorithm,
+      sha256Fingerprint: var_sha256Fingerprint,
+      extendedKeyUsages: var_extendedKeyUsages,
+      isCa: var_isCa,
+      der: var_der,
+      revocation: var_revocation,
+    );
+  }
+
+  @protected
+  ConsistencyFinding sse_decode_consistency_finding(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_inconsistency_kind(deserializer);
+    var var_severity = sse_decode_finding_severity(deserializer);
+    var var_values = sse_decode_list_finding_value(deserializer);
+    var var_explanation = sse_decode_String(deserializer);
+    return ConsistencyFinding(
+      kind: var_kind,
+      severity: var_severity,
+      values: var_values,
+      explanation: var_explanation,
+    );
+  }
+
+  @protected
+  ContentAction sse_decode_content_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_String(deserializer);
+    var var_softwareAgent = sse_decode_opt_String(deserializer);
+    var var_softwareAgentInfo = sse_decode_opt_box_autoadd_software_agent_info(
+      deserializer,
+    );
+    var var_when = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_digitalSourceType = sse_decode_opt_box_autoadd_digital_source_type(
+      deserializer,
+    );
+    var var_reason = sse_decode_opt_String(deserializer);
+    var var_ingredients = sse_decode_list_String(deserializer);
+    var var_parameters = sse_decode_list_action_parameter(deserializer);
+    var var_changes = sse_decode_list_action_change(deserializer);
+    var var_related = sse_decode_list_content_action(deserializer);
+    return ContentAction(
+      action: var_action,
+      softwareAgent: var_softwareAgent,
+      softwareAgentInfo: var_softwareAgentInfo,
+      when: var_when,
+      description: var_description,
+      digitalSourceType: var_digitalSourceType,
+      reason: var_reason,
+      ingredients: var_ingredients,
+      parameters: var_parameters,
+      changes: var_changes,
+      related: var_related,
+    );
+  }
+
+  @protected
+  DigitalSourceType sse_decode_digital_source_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return DigitalSourceType_DigitalCapture();
+      case 1:
+        return DigitalSourceType_ComputationalCapture();
+      case 2:
+        return DigitalSourceType_NegativeFilm();
+      case 3:
+        return DigitalSourceType_PositiveFilm();
+      case 4:
+        return DigitalSourceType_Print();
+      case 5:
+        return DigitalSourceType_ScreenCapture();
+      case 6:
+        return DigitalSourceType_VirtualRecording();
+      case 7:
+        return DigitalSourceType_HumanEdits();
+      case 8:
+        return DigitalSourceType_MinorHumanEdits();
+      case 9:
+        return DigitalSourceType_AlgorithmicallyEnhanced();
+      case 10:
+        return DigitalSourceType_DigitalCreation();
+      case 11:
+        return DigitalSourceType_DataDrivenMedia();
+      case 12:
+        return DigitalSourceType_AlgorithmicMedia();
+      case 13:
+        return DigitalSourceType_TrainedAlgorithmicMedia();
+      case 14:
+        return DigitalSourceType_Composite();
+      case 15:
+        return DigitalSourceType_CompositeCapture();
+      case 16:
+        return DigitalSourceType_CompositeSynthetic();
+      case 17:
+        return DigitalSourceType_CompositeWithTrainedAlgorithmicMedia();
+      case 18:
+        var var_uri = sse_decode_String(deserializer);
+        return DigitalSourceType_Unknown(uri: var_uri);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DistinguishedName sse_decode_distinguished_name(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_attributes = sse_decode_list_dn_attribute(deserializer);
+    var var_rfc4514 = sse_decode_String(deserializer);
+    return DistinguishedName(attributes: var_attributes, rfc4514: var_rfc4514);
+  }
+
+  @protected
+  DnAttribute sse_decode_dn_attribute(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_attrType = sse_decode_String(deserializer);
+    var var_oid = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_rdnIndex = sse_decode_u_32(deserializer);
+    return DnAttribute(
+      attrType: var_attrType,
+      oid: var_oid,
+      value: var_value,
+      rdnIndex: var_rdnIndex,
+    );
+  }
+
+  @protected
+  Evidence sse_decode_evidence(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_detector = sse_decode_evidence_detector(deserializer);
+    var var_kind = sse_decode_evidence_kind(deserializer);
+    var var_field = sse_decode_String(deserializer);
+    var var_matchedText = sse_decode_String(deserializer);
+    var var_strength = sse_decode_evidence_strength(deserializer);
+    var var_explanation = sse_decode_String(deserializer);
+    var var_generator = sse_decode_opt_String(deserializer);
+    return Evidence(
+      detector: var_detector,
+      kind: var_kind,
+      field: var_field,
+      matchedText: var_matchedText,
+      strength: var_strength,
+      explanation: var_explanation,
+      generator: var_generator,
+    );
+  }
+
+  @protected
+  EvidenceDetector sse_decode_evidence_detector(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EvidenceDetector.values[inner];
+  }
+
+  @protected
+  EvidenceKind sse_decode_evidence_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EvidenceKind.values[inner];
+  }
+
+  @protected
+  EvidenceStrength sse_decode_evidence_strength(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EvidenceStrength.values[inner];
+  }
+
+  @protected
+  ExifInfo sse_decode_exif_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_software = sse_decode_opt_String(deserializer);
+    var var_make = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_copyright = sse_decode_opt_String(deserializer);
+    var var_userComment = sse_decode_opt_String(deserializer);
+    var var_imageDescription = sse_decode_opt_String(deserializer);
+    var var_dateTimeOriginal = sse_decode_opt_String(deserializer);
+    var var_dateTime = sse_decode_opt_String(deserializer);
+    var var_dateTimeDigitized = sse_decode_opt_String(deserializer);
+    var var_offsetTime = sse_decode_opt_String(deserializer);
+    var var_offsetTimeOriginal = sse_decode_opt_String(deserializer);
+    var var_offsetTimeDigitized = sse_decode_opt_String(deserializer);
+    var var_subSecTime = sse_decode_opt_String(deserializer);
+    var var_subSecTimeOriginal = sse_decode_opt_String(deserializer);
+    var var_subSecTimeDigitized = sse_decode_opt_String(deserializer);
+    var var_orientation = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_imageWidth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_imageHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bodySerialNumber = sse_decode_opt_String(deserializer);
+    var var_cameraOwnerName = sse_decode_opt_String(deserializer);
+    var var_imageUniqueId = sse_decode_opt_String(deserializer);
+    var var_gps = sse_decode_opt_box_autoadd_gps_info(deserializer);
+    var var_lens = sse_decode_opt_box_autoadd_lens_info(deserializer);
+    var var_exposure = sse_decode_opt_box_autoadd_exposure_info(deserializer);
+    var var_thumbnail = sse_decode_opt_box_autoadd_exif_thumbnail(deserializer);
+    var var_makerNote = sse_decode_opt_box_autoadd_maker_note(deserializer);
+    var var_tags = sse_decode_list_exif_tag(deserializer);
+    var var_aiDetected = sse_decode_bool(deserializer);
+    var var_aiGenerator = sse_decode_opt_String(deserializer);
+    return ExifInfo(
+      software: var_software,
+      make: var_make,
+      model: var_model,
+      artist: var_artist,
+      copyright: var_copyright,
+      userComment: var_userComment,
+      imageDescription: var_imageDescription,
+      dateTimeOriginal: var_dateTimeOriginal,
+      dateTime: var_dateTime,
+      dateTimeDigitized: var_dateTimeDigitized,
+      offsetTime: var_offsetTime,
+      offsetTimeOriginal: var_offsetTimeOriginal,
+      offsetTimeDigitized: var_offsetTimeDigitized,
+      subSecTime: var_subSecTime,
+      subSecTimeOriginal: var_subSecTimeOriginal,
+      subSecTimeDigitized: var_subSecTimeDigitized,
+      orientation: var_orientation,
+      imageWidth: var_imageWidth,
+      imageHeight: var_imageHeight,
+      bodySerialNumber: var_bodySerialNumber,
+      cameraOwnerName: var_cameraOwnerName,
+      imageUniqueId: var_imageUniqueId,
+      gps: var_gps,
+      lens: var_lens,
+      exposure: var_exposure,
+      thumbnail: var_thumbnail,
+      makerNote: var_makerNote,
+      tags: var_tags,
+      aiDetected: var_aiDetected,
+      aiGenerator: var_aiGenerator,
+    );
+  }
+
+  @protected
+  ExifTag sse_decode_exif_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ifd = sse_decode_u_16(deserializer);
+    var var_group = sse_decode_String(deserializer);
+    var var_id = sse_decode_u_16(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return ExifTag(
+      ifd: var_ifd,
+      group: var_group,
+      id: var_id,
+      name: var_name,
+      value: var_value,
+    );
+  }
+
+  @protected
+  ExifThumbnail sse_decode_exif_thumbnail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return ExifThumbnail(
+      mimeType: var_mimeType,
+      width: var_width,
+      height: var_height,
+      data: var_data,
+    );
+  }
+
+  @protected
+  ExposureInfo sse_decode_exposure_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exposureTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_fNumber = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_iso = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_exposureBias = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_exposureProgram = sse_decode_opt_String(deserializer);
+    var var_meteringMode = sse_decode_opt_String(deserializer);
+    var var_flash = sse_decode_opt_String(deserializer);
+    var var_whiteBalance = sse_decode_opt_String(deserializer);
+    return ExposureInfo(
+      exposureTime: var_exposureTime,
+      fNumber: var_fNumber,
+      iso: var_iso,
+      exposureBias: var_exposureBias,
+      exposureProgram: var_exposureProgram,
+      meteringMode: var_meteringMode,
+      flash: var_flash,
+      whiteBalance: var_whiteBalance,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FindingSeverity sse_decode_finding_severity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FindingSeverity.values[inner];
+  }
+
+  @protected
+  FindingValue sse_decode_finding_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_String(deserializer);
+    var var_field = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return FindingValue(source: var_source, field: var_field, value: var_value);
+  }
+
+  @protected
+  GenerationParameters sse_decode_generation_parameters(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tool = sse_decode_generation_tool(deserializer);
+    var var_toolVersion = sse_decode_opt_String(deserializer);
+    var var_prompt = sse_decode_opt_String(deserializer);
+    var var_negativePrompt = sse_decode_opt_String(deserializer);
+    var var_steps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sampler = sse_decode_opt_String(deserializer);
+    var var_scheduler = sse_decode_opt_String(deserializer);
+    var var_cfgScale = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_modelHash = sse_decode_opt_String(deserializer);
+    var var_loras = sse_decode_list_lora_reference(deserializer);
+    var var_workflow = sse_decode_opt_String(deserializer);
+    var var_otherSettings = sse_decode_list_generation_setting(deserializer);
+    var var_sourceChunks = sse_decode_list_String(deserializer);
+    return GenerationParameters(
+      tool: var_tool,
+      toolVersion: var_toolVersion,
+      prompt: var_prompt,
+      negativePrompt: var_negativePrompt,
+      steps: var_steps,
+      sampler: var_sampler,
+      scheduler: var_scheduler,
+      cfgScale: var_cfgScale,
+      seed: var_seed,
+      width: var_width,
+      height: var_height,
+      model: var_model,
+      modelHash: var_modelHash,
+      loras: var_loras,
+      workflow: var_workflow,
+      otherSettings: var_otherSettings,
+      sourceChunks: var_sourceChunks,
+    );
+  }
+
+  @protected
+  GenerationSetting sse_decode_generation_setting(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return GenerationSetting(key: var_key, value: var_value);
+  }
+
+  @protected
+  GenerationTool sse_decode_generation_tool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GenerationTool.values[inner];
+  }
+
+  @protected
+  GpsInfo sse_decode_gps_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_altitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_timestamp = sse_decode_opt_String(deserializer);
+    var var_imageDirection = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_imageDirectionRef = sse_decode_opt_String(deserializer);
+    var var_mapDatum = sse_decode_opt_String(deserializer);
+    return GpsInfo(
+      latitude: var_latitude,
+      longitude: var_longitude,
+      altitude: var_altitude,
+      timestamp: var_timestamp,
+      imageDirection: var_imageDirection,
+      imageDirectionRef: var_imageDirectionRef,
+      mapDatum: var_mapDatum,
+    );
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImageRegion sse_decode_image_region(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_u_32(deserializer);
+    var var_y = sse_decode_u_32(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return ImageRegion(
+      x: var_x,
+      y: var_y,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  InconsistencyKind sse_decode_inconsistency_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return InconsistencyKind.values[inner];
+  }
+
+  @protected
+  IngredientEdge sse_decode_ingredient_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromLabel = sse_decode_String(deserializer);
+    var var_toLabel = sse_decode_opt_String(deserializer);
+    var var_ingredientTitle = sse_decode_String(deserializer);
+    var var_ingredientFormat = sse_decode_opt_String(deserializer);
+    var var_ingredientInstanceId = sse_decode_opt_String(deserializer);
+    var var_relationship = sse_decode_ingredient_relationship(deserializer);
+    var var_validationStatuses = sse_decode_list_validation_status_entry(
+      deserializer,
+    );
+    return IngredientEdge(
+      fromLabel: var_fromLabel,
+      toLabel: var_toLabel,
+      ingredientTitle: var_ingredientTitle,
+      ingredientFormat: var_ingredientFormat,
+      ingredientInstanceId: var_ingredientInstanceId,
+      relationship: var_relationship,
+      validationStatuses: var_validationStatuses,
+    );
+  }
+
+  @protected
+  IngredientRelationship sse_decode_ingredient_relationship(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IngredientRelationship.values[inner];
+  }
+
+  @protected
+  IngredientThumbnail sse_decode_ingredient_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_instanceId = sse_decode_String(deserializer);
+    var var_manifestLabel = sse_decode_opt_String(deserializer);
+    var var_thumbnail = sse_decode_manifest_thumbnail(deserializer);
+    return IngredientThumbnail(
+      title: var_title,
+      instanceId: var_instanceId,
+      manifestLabel: var_manifestLabel,
+      thumbnail: var_thumbnail,
+    );
+  }
+
+  @protected
+  IptcInfo sse_decode_iptc_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_objectName = sse_decode_opt_String(deserializer);
+    var var_headline = sse_decode_opt_String(deserializer);
+    var var_caption = sse_decode_opt_String(deserializer);
+    var var_captionWriter = sse_decode_opt_String(deserializer);
+    var var_keywords = sse_decode_list_String(deserializer);
+    var var_byLine = sse_decode_list_String(deserializer);
+    var var_byLineTitle = sse_decode_opt_String(deserializer);
+    var var_credit = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    var var_copyrightNotice = sse_decode_opt_String(deserializer);
+    var var_specialInstructions = sse_decode_opt_String(deserializer);
+    var var_dateCreated = sse_decode_opt_String(deserializer);
+    var var_city = sse_decode_opt_String(deserializer);
+    var var_subLocation = sse_decode_opt_String(deserializer);
+    var var_provinceState = sse_decode_opt_String(deserializer);
+    var var_countryCode = sse_decode_opt_String(deserializer);
+    var var_country = sse_decode_opt_String(deserializer);
+    var var_transmissionReference = sse_decode_opt_String(deserializer);
+    return IptcInfo(
+      objectName: var_objectName,
+      headline: var_headline,
+      caption: var_caption,
+      captionWriter: var_captionWriter,
+      keywords: var_keywords,
+      byLine: var_byLine,
+      byLineTitle: var_byLineTitle,
+      credit: var_credit,
+      source: var_source,
+      copyrightNotice: var_copyrightNotice,
+      specialInstructions: var_specialInstructions,
+      dateCreated: var_dateCreated,
+      city: var_city,
+      subLocation: var_subLocation,
+      provinceState: var_provinceState,
+      countryCode: var_countryCode,
+      country: var_country,
+      transmissionReference: var_transmissionReference,
+    );
+  }
+
+  @protected
+  LensInfo sse_decode_lens_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_make = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_serialNumber = sse_decode_opt_String(deserializer);
+    var var_specification = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_focalLength = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_focalLength35Mm = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return LensInfo(
+      make: var_make,
+      model: var_model,
+      serialNumber: var_serialNumber,
+      specification: var_specification,
+      focalLength: var_focalLength,
+      focalLength35Mm: var_focalLength35Mm,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ActionChange> sse_decode_list_action_change(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ActionChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_action_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ActionParameter> sse_decode_list_action_parameter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ActionParameter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_action_parameter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CertificateInfo> sse_decode_list_certificate_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CertificateInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_certificate_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConsistencyFinding> sse_decode_list_consistency_finding(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConsistencyFinding>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_consistency_finding(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ContentAction> sse_decode_list_content_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ContentAction>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_content_action(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DnAttribute> sse_decode_list_dn_attribute(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DnAttribute>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dn_attribute(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Evidence> sse_decode_list_evidence(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Evidence>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_evidence(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ExifTag> sse_decode_list_exif_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ExifTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_exif_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FindingValue> sse_decode_list_finding_value(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FindingValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_finding_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GenerationSetting> sse_decode_list_generation_setting(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GenerationSetting>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_generation_setting(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IngredientEdge> sse_decode_list_ingredient_edge(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IngredientEdge>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ingredient_edge(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IngredientThumbnail> sse_decode_list_ingredient_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IngredientThumbnail>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ingredient_thumbnail(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LoraReference> sse_decode_list_lora_reference(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LoraReference>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lora_reference(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MakerNoteTag> sse_decode_list_maker_note_tag(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MakerNoteTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_maker_note_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ManifestNode> sse_decode_list_manifest_node(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ManifestNode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_manifest_node(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModelIdentity> sse_decode_list_model_identity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModelIdentity>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_model_identity(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, ManifestThumbnails)>
+  sse_decode_list_record_string_manifest_thumbnails(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, ManifestThumbnails)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_manifest_thumbnails(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RegionRange> sse_decode_list_region_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RegionRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_region_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ValidationStatusEntry> sse_decode_list_validation_status_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ValidationStatusEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_validation_status_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoraReference sse_decode_lora_reference(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_weight = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return LoraReference(name: var_name, weight: var_weight);
+  }
+
+  @protected
+  MakerNote sse_decode_maker_note(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_vendor = sse_decode_String(deserializer);
+    var var_serialNumber = sse_decode_opt_String(deserializer);
+    var var_lensModel = sse_decode_opt_String(deserializer);
+    var var_firmwareVersion = sse_decode_opt_String(deserializer);
+    var var_shutterCount = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_tags = sse_decode_list_maker_note_tag(deserializer);
+    return MakerNote(
+      vendor: var_vendor,
+      serialNumber: var_serialNumber,
+      lensModel: var_lensModel,
+      firmwareVersion: var_firmwareVersion,
+      shutterCount: var_shutterCount,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  MakerNoteTag sse_decode_maker_note_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_16(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return MakerNoteTag(id: var_id, name: var_name, value: var_value);
+  }
+
+  @protected
+  ManifestNode sse_decode_manifest_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_label = sse_decode_String(deserializer);
+    var var_isActive = sse_decode_bool(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_format = sse_decode_opt_String(deserializer);
+    var var_instanceId = sse_decode_opt_String(deserializer);
+    var var_claimGenerator = sse_decode_opt_String(deserializer);
+    var var_signer = sse_decode_opt_box_autoadd_signer_info(deserializer);
+    var var_actions = sse_decode_list_content_action(deserializer);
+    var var_validationStatuses = sse_decode_list_validation_status_entry(
+      deserializer,
+    );
+    return ManifestNode(
+      label: var_label,
+      isActive: var_isActive,
+      title: var_title,
+      format: var_format,
+      instanceId: var_instanceId,
+      claimGenerator: var_claimGenerator,
+      signer: var_signer,
+      actions: var_actions,
+      validationStatuses: var_validationStatuses,
+    );
+  }
+
+  @protected
+  ManifestThumbnail sse_decode_manifest_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_identifier = sse_decode_opt_String(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return ManifestThumbnail(
+      mimeType: var_mimeType,
+      identifier: var_identifier,
+      data: var_data,
+    );
+  }
+
+  @protected
+  ManifestThumbnails sse_decode_manifest_thumbnails(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_claim = sse_decode_opt_box_autoadd_manifest_thumbnail(deserializer);
+    var var_ingredients = sse_decode_list_ingredient_thumbnail(deserializer);
+    return ManifestThumbnails(claim: var_claim, ingredients: var_ingredients);
+  }
+
+  @protected
+  MimeCheck sse_decode_mime_check(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_claimedMimeType = sse_decode_String(deserializer);
+    var var_detectedMimeType = sse_decode_opt_String(deserializer);
+    var var_mismatch = sse_decode_bool(deserializer);
+    return MimeCheck(
+      claimedMimeType: var_claimedMimeType,
+      detectedMimeType: var_detectedMimeType,
+      mismatch: var_mismatch,
+    );
+  }
+
+  @protected
+  ModelIdentity sse_decode_model_identity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_vendor = sse_decode_String(deserializer);
+    var var_product = sse_decode_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_version = sse_decode_opt_String(deserializer);
+    var var_isAiGenerator = sse_decode_bool(deserializer);
+    var var_field = sse_decode_String(deserializer);
+    var var_matchedText = sse_decode_String(deserializer);
+    return ModelIdentity(
+      vendor: var_vendor,
+      product: var_product,
+      model: var_model,
+      version: var_version,
+      isAiGenerator: var_isAiGenerator,
+      field: var_field,
+      matchedText: var_matchedText,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AiInfo? sse_decode_opt_box_autoadd_ai_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_ai_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AnalysisError? sse_decode_opt_box_autoadd_analysis_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_analysis_error(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CertificateInfo? sse_decode_opt_box_autoadd_certificate_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_certificate_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DigitalSourceType? sse_decode_opt_box_autoadd_digital_source_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_digital_source_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DistinguishedName? sse_decode_opt_box_autoadd_distinguished_name(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_distinguished_name(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ExifInfo? sse_decode_opt_box_autoadd_exif_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_exif_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ExifThumbnail? sse_decode_opt_box_autoadd_exif_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_exif_thumbnail(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ExposureInfo? sse_decode_opt_box_autoadd_exposure_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_exposure_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GenerationParameters? sse_decode_opt_box_autoadd_generation_parameters(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_generation_parameters(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GpsInfo? sse_decode_opt_box_autoadd_gps_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_gps_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ImageRegion? sse_decode_opt_box_autoadd_image_region(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_image_region(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  IptcInfo? sse_decode_opt_box_autoadd_iptc_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_iptc_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LensInfo? sse_decode_opt_box_autoadd_lens_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lens_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MakerNote? sse_decode_opt_box_autoadd_maker_note(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_maker_note(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ManifestThumbnail? sse_decode_opt_box_autoadd_manifest_thumbnail(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_manifest_thumbnail(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MimeCheck? sse_decode_opt_box_autoadd_mime_check(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_mime_check(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ModelIdentity? sse_decode_opt_box_autoadd_model_identity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_model_identity(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProvenanceGraph? sse_decode_opt_box_autoadd_provenance_graph(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_provenance_graph(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RevocationSource? sse_decode_opt_box_autoadd_revocation_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_revocation_source(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SignerInfo? sse_decode_opt_box_autoadd_signer_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_signer_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SoftwareAgentInfo? sse_decode_opt_box_autoadd_software_agent_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_software_agent_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ThumbnailComparison? sse_decode_opt_box_autoadd_thumbnail_comparison(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_thumbnail_comparison(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TimestampInfo? sse_decode_opt_box_autoadd_timestamp_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_timestamp_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  XmpInfo? sse_decode_opt_box_autoadd_xmp_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_xmp_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProvenanceGraph sse_decode_provenance_graph(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_activeLabel = sse_decode_opt_String(deserializer);
+    var var_nodes = sse_decode_list_manifest_node(deserializer);
+    var var_edges = sse_decode_list_ingredient_edge(deserializer);
+    return ProvenanceGraph(
+      activeLabel: var_activeLabel,
+      nodes: var_nodes,
+      edges: var_edges,
+    );
+  }
+
+  @protected
+  (String, ManifestThumbnails) sse_decode_record_string_manifest_thumbnails(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_manifest_thumbnails(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RegionRange sse_decode_region_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_shape = sse_decode_String(deserializer);
+        var var_unit = sse_decode_opt_String(deserializer);
+        var var_x = sse_decode_opt_box_autoadd_f_64(deserializer);
+        var var_y = sse_decode_opt_box_autoadd_f_64(deserializer);
+        var var_width = sse_decode_opt_box_autoadd_f_64(deserializer);
+        var var_height = sse_decode_opt_box_autoadd_f_64(deserializer);
+        return RegionRange_Spatial(
+          shape: var_shape,
+          unit: var_unit,
+          x: var_x,
+          y: var_y,
+          width: var_width,
+          height: var_height,
+        );
+      case 1:
+        var var_start = sse_decode_opt_String(deserializer);
+        var var_end = sse_decode_opt_String(deserializer);
+        return RegionRange_Temporal(start: var_start, end: var_end);
+      case 2:
+        var var_start = sse_decode_opt_box_autoadd_i_64(deserializer);
+        var var_end = sse_decode_opt_box_autoadd_i_64(deserializer);
+        return RegionRange_Frame(start: var_start, end: var_end);
+      case 3:
+        var var_json = sse_decode_String(deserializer);
+        return RegionRange_Other(json: var_json);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  RevocationInfo sse_decode_revocation_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_state = sse_decode_revocation_state(deserializer);
+    var var_source = sse_decode_opt_box_autoadd_revocation_source(deserializer);
+    var var_revokedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_reason = sse_decode_opt_String(deserializer);
+    var var_thisUpdate = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_nextUpdate = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return RevocationInfo(
+      state: var_state,
+      source: var_source,
+      revokedAt: var_revokedAt,
+      reason: var_reason,
+      thisUpdate: var_thisUpdate,
+      nextUpdate: var_nextUpdate,
+    );
+  }
+
+  @protected
+  RevocationSource sse_decode_revocation_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RevocationSource.values[inner];
+  }
+
+  @protected
+  RevocationState sse_decode_revocation_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RevocationState.values[inner];
+  }
+
+  @protected
+  ScoringWeights sse_decode_scoring_weights(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_definitive = sse_decode_f_64(deserializer);
+    var var_strong = sse_decode_f_64(deserializer);
+    var var_weak = sse_decode_f_64(deserializer);
+    var var_cameraDiscount = sse_decode_f_64(deserializer);
+    var var_likelyThreshold = sse_decode_f_64(deserializer);
+    return ScoringWeights(
+      definitive: var_definitive,
+      strong: var_strong,
+      weak: var_weak,
+      cameraDiscount: var_cameraDiscount,
+      likelyThreshold: var_likelyThreshold,
+    );
+  }
+
+  @protected
+  SignerInfo sse_decode_signer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_organization = sse_decode_opt_String(deserializer);
+    var var_issuedBy = sse_decode_opt_String(deserializer);
+    var var_timestamp = sse_decode_opt_String(deserializer);
+    var var_certificateChain = sse_decode_list_certificate_info(deserializer);
+    var var_timestampToken = sse_decode_opt_box_autoadd_timestamp_info(
+      deserializer,
+    );
+    return SignerInfo(
+      name: var_name,
+      organization: var_organization,
+      issuedBy: var_issuedBy,
+      timestamp: var_timestamp,
+      certificateChain: var_certificateChain,
+      timestampToken: var_timestampToken,
+    );
+  }
+
+  @protected
+  SoftwareAgentInfo sse_decode_software_agent_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_version = sse_decode_opt_String(deserializer);
+    var var_icon = sse_decode_opt_String(deserializer);
+    return SoftwareAgentInfo(
+      name: var_name,
+      version: var_version,
+      icon: var_icon,
+    );
+  }
+
+  @protected
+  ThumbnailComparison sse_decode_thumbnail_comparison(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_comparedWidth = sse_decode_u_32(deserializer);
+    var var_comparedHeight = sse_decode_u_32(deserializer);
+    var var_difference = sse_decode_f_64(deserializer);
+    var var_changedFraction = sse_decode_f_64(deserializer);
+    var var_changedRegion = sse_decode_opt_box_autoadd_image_region(
+      deserializer,
+    );
+    var var_contentDiffers = sse_decode_bool(deserializer);
+    return ThumbnailComparison(
+      comparedWidth: var_comparedWidth,
+      comparedHeight: var_comparedHeight,
+      difference: var_difference,
+      changedFraction: var_changedFraction,
+      changedRegion: var_changedRegion,
+      contentDiffers: var_contentDiffers,
+    );
+  }
+
+  @protected
+  TimestampInfo sse_decode_timestamp_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tsaName = sse_decode_opt_String(deserializer);
+    var var_tsaCertificate = sse_decode_opt_box_autoadd_certificate_info(
+      deserializer,
+    );
+    var var_genTime = sse_decode_i_64(deserializer);
+    var var_hashAlgorithm = sse_decode_String(deserializer);
+    var var_messageImprint = sse_decode_String(deserializer);
+    var var_policy = sse_decode_opt_String(deserializer);
+    var var_serialNumber = sse_decode_String(deserializer);
+    var var_signatureValid = sse_decode_bool(deserializer);
+    var var_trusted = sse_decode_bool(deserializer);
+    var var_signerValidityEvaluatedAtTsaTime = sse_decode_bool(deserializer);
+    var var_signerValidAtGenTime = sse_decode_opt_box_autoadd_bool(
+      deserializer,
+    );
+    return TimestampInfo(
+      tsaName: var_tsaName,
+      tsaCertificate: var_tsaCertificate,
+      genTime: var_genTime,
+      hashAlgorithm: var_hashAlgorithm,
+      messageImprint: var_messageImprint,
+      policy: var_policy,
+      serialNumber: var_serialNumber,
+      signatureValid: var_signatureValid,
+      trusted: var_trusted,
+      signerValidityEvaluatedAtTsaTime: var_signerValidityEvaluatedAtTsaTime,
+      signerValidAtGenTime: var_signerValidAtGenTime,
+    );
+  }
+
+  @protected
+  TrustStoreSummary sse_decode_trust_store_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_anchorCount = sse_decode_u_32(deserializer);
+    var var_allowedCertificateCount = sse_decode_u_32(deserializer);
+    var var_ekuOids = sse_decode_list_String(deserializer);
+    var var_tsaAnchorCount = sse_decode_u_32(deserializer);
+    var var_crlCount = sse_decode_u_32(deserializer);
+    var var_ocspResponseCount = sse_decode_u_32(deserializer);
+    return TrustStoreSummary(
+      anchorCount: var_anchorCount,
+      allowedCertificateCount: var_allowedCertificateCount,
+      ekuOids: var_ekuOids,
+      tsaAnchorCount: var_tsaAnchorCount,
+      crlCount: var_crlCount,
+      ocspResponseCount: var_ocspResponseCount,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  ValidationCategory sse_decode_validation_category(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ValidationCategory.values[inner];
+  }
+
+  @protected
+  ValidationStatusCode sse_decode_validation_status_code(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ValidationStatusCode_ClaimSignatureValidated();
+      case 1:
+        return ValidationStatusCode_ClaimSignatureInsideValidity();
+      case 2:
+        return ValidationStatusCode_SigningCredentialTrusted();
+      case 3:
+        return ValidationStatusCode_SigningCredentialNotRevoked();
+      case 4:
+        return ValidationStatusCode_TimeStampTrusted();
+      case 5:
+        return ValidationStatusCode_TimeStampValidated();
+      case 6:
+        return ValidationStatusCode_AssertionHashedUriMatch();
+      case 7:
+        return ValidationStatusCode_AssertionDataHashMatch();
+      case 8:
+        return ValidationStatusCode_AssertionBmffHashMatch();
+      case 9:
+        return ValidationStatusCode_AssertionBoxesHashMatch();
+      case 10:
+        return ValidationStatusCode_AssertionCollectionHashMatch();
+      case 11:
+        return ValidationStatusCode_AssertionAccessible();
+      case 12:
+        return ValidationStatusCode_IngredientManifestValidated();
+      case 13:
+        return ValidationStatusCode_IngredientClaimSignatureValidated();
+      case 14:
+        return ValidationStatusCode_AlgorithmDeprecated();
+      case 15:
+        return ValidationStatusCode_SigningCredentialOcspSkipped();
+      case 16:
+        return ValidationStatusCode_SigningCredentialOcspInaccessible();
+      case 17:
+        return ValidationStatusCode_TimeStampUntrusted();
+      case 18:
+        return ValidationStatusCode_TimeStampOutsideValidity();
+      case 19:
+        return ValidationStatusCode_IngredientUnknownProvenance();
+      case 20:
+        return ValidationStatusCode_ManifestUnknownProvenance();
+      case 21:
+        return ValidationStatusCode_ClaimMissing();
+      case 22:
+        return ValidationStatusCode_ClaimMultiple();
+      case 23:
+        return ValidationStatusCode_ClaimHardBindingsMissing();
+      case 24:
+        return ValidationStatusCode_ClaimRequiredMissing();
+      case 25:
+        return ValidationStatusCode_ClaimCborInvalid();
+      case 26:
+        return ValidationStatusCode_ClaimSignatureMissing();
+      case 27:
+        return ValidationStatusCode_ClaimSignatureMismatch();
+      case 28:
+        return ValidationStatusCode_ClaimSignatureOutsideValidity();
+      case 29:
+        return ValidationStatusCode_AlgorithmUnsupported();
+      case 30:
+        return ValidationStatusCode_SigningCredentialUntrusted();
+      case 31:
+        return ValidationStatusCode_SigningCredentialInvalid();
+      case 32:
+        return ValidationStatusCode_SigningCredentialExpired();
+      case 33:
+        return ValidationStatusCode_SigningCredentialRevoked();
+      case 34:
+        return ValidationStatusCode_SigningCredentialOcspUnknown();
+      case 35:
+        return ValidationStatusCode_TimeStampMismatch();
+      case 36:
+        return ValidationStatusCode_TimeStampMalformed();
+      case 37:
+        return ValidationStatusCode_AssertionHashedUriMismatch();
+      case 38:
+        return ValidationStatusCode_AssertionDataHashMismatch();
+      case 39:
+        return ValidationStatusCode_AssertionDataHashMalformed();
+      case 40:
+        return ValidationStatusCode_AssertionBmffHashMismatch();
+      case 41:
+        return ValidationStatusCode_AssertionBmffHashMalformed();
+      case 42:
+        return ValidationStatusCode_AssertionBoxesHashMismatch();
+      case 43:
+        return ValidationStatusCode_AssertionBoxesHashUnknownBox();
+      case 44:
+        return ValidationStatusCode_AssertionCollectionHashMismatch();
+      case 45:
+        return ValidationStatusCode_AssertionCollectionHashMalformed();
+      case 46:
+        return ValidationStatusCode_AssertionCollectionHashIncorrectFileCount();
+      case 47:
+        return ValidationStatusCode_AssertionCollectionHashInvalidUri();
+      case 48:
+        return ValidationStatusCode_HashedUriMissing();
+      case 49:
+        return ValidationStatusCode_HashedUriMismatch();
+      case 50:
+        return ValidationStatusCode_AssertionMissing();
+      case 51:
+        return ValidationStatusCode_AssertionRequiredMissing();
+      case 52:
+        return ValidationStatusCode_AssertionMultipleHardBindings();
+      case 53:
+        return ValidationStatusCode_AssertionUndeclared();
+      case 54:
+        return ValidationStatusCode_AssertionInaccessible();
+      case 55:
+        return ValidationStatusCode_AssertionNotRedacted();
+      case 56:
+        return ValidationStatusCode_AssertionSelfRedacted();
+      case 57:
+        return ValidationStatusCode_AssertionJsonInvalid();
+      case 58:
+        return ValidationStatusCode_AssertionCborInvalid();
+      case 59:
+        return ValidationStatusCode_AssertionActionIngredientMismatch();
+      case 60:
+        return ValidationStatusCode_AssertionActionMalformed();
+      case 61:
+        return ValidationStatusCode_AssertionActionRedacted();
+      case 62:
+        return ValidationStatusCode_AssertionActionRedactionMismatch();
+      case 63:
+        return ValidationStatusCode_AssertionCloudDataHardBinding();
+      case 64:
+        return ValidationStatusCode_AssertionCloudDataActions();
+      case 65:
+        return ValidationStatusCode_AssertionCloudDataLabelMismatch();
+      case 66:
+        return ValidationStatusCode_IngredientHashedUriMissing();
+      case 67:
+        return ValidationStatusCode_IngredientHashedUriMismatch();
+      case 68:
+        return ValidationStatusCode_IngredientManifestMissing();
+      case 69:
+        return ValidationStatusCode_IngredientManifestMismatch();
+      case 70:
+        return ValidationStatusCode_IngredientClaimSignatureMissing();
+      case 71:
+        return ValidationStatusCode_IngredientClaimSignatureMismatch();
+      case 72:
+        return ValidationStatusCode_ManifestInaccessible();
+      case 73:
+        return ValidationStatusCode_ManifestMultipleParents();
+      case 74:
+        return ValidationStatusCode_ManifestUpdateInvalid();
+      case 75:
+        return ValidationStatusCode_ManifestUpdateWrongParents();
+      case 76:
+        return ValidationStatusCode_ManifestTimestampInvalid();
+      case 77:
+        return ValidationStatusCode_ManifestTimestampWrongParents();
+      case 78:
+        return ValidationStatusCode_ManifestCompressedInvalid();
+      case 79:
+        return ValidationStatusCode_GeneralError();
+      case 80:
+        var var_code = sse_decode_String(deserializer);
+        return ValidationStatusCode_Unknown(code: var_code);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ValidationStatusEntry sse_decode_validation_status_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_String(deserializer);
+    var var_kind = sse_decode_validation_status_code(deserializer);
+    var var_category = sse_decode_validation_category(deserializer);
+    var var_url = sse_decode_opt_String(deserializer);
+    var var_explanation = sse_decode_opt_String(deserializer);
+    return ValidationStatusEntry(
+      code: var_code,
+      kind: var_kind,
+      category: var_category,
+      url: var_url,
+      explanation: var_explanation,
+    );
+  }
+
+  @protected
+  VerificationStatus sse_decode_verification_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return VerificationStatus_Verified();
+      case 1:
+        return VerificationStatus_SignatureInvalid();
+      case 2:
+        return VerificationStatus_CertificateExpired();
+      case 3:
+        return VerificationStatus_CertificateUntrusted();
+      case 4:
+        return VerificationStatus_CertificateRevoked();
+      case 5:
+        return VerificationStatus_ContentModified();
+      case 6:
+        var var_codes = sse_decode_list_String(deserializer);
+        return VerificationStatus_ValidationFailed(codes: var_codes);
+      case 7:
+        return VerificationStatus_NoManifest();
+      case 8:
+        var var_message = sse_decode_String(deserializer);
+        return VerificationStatus_Error(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  XmpInfo sse_decode_xmp_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_digitalSourceType = sse_decode_opt_box_autoadd_digital_source_type(
+      deserializer,
+    );
+    var var_credit = sse_decode_opt_String(deserializer);
+    var var_creatorTool = sse_decode_opt_String(deserializer);
+    var var_creators = sse_decode_list_String(deserializer);
+    var var_provenance = sse_decode_opt_String(deserializer);
+    var var_container = sse_decode_String(deserializer);
+    var var_packet = sse_decode_String(deserializer);
+    return XmpInfo(
+      digitalSourceType: var_digitalSourceType,
+      credit: var_credit,
+      creatorTool: var_creatorTool,
+      creators: var_creators,
+      provenance: var_provenance,
+      container: var_container,
+      packet: var_packet,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ChunkedAssetImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ChunkedAssetImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ChunkedAssetImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Map_String_manifest_thumbnails_None(
+    Map<String, ManifestThumbnails> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_manifest_thumbnails(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ChunkedAssetImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_action_change(ActionChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_list_region_range(self.regions, serializer);
+  }
+
+  @protected
+  void sse_encode_action_parameter(
+    ActionParameter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_ai_info(AiInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.isAiGenerated, serializer);
+    sse_encode_f_64(self.likelihood, serializer);
+    sse_encode_ai_label(self.label, serializer);
+    sse_encode_opt_String(self.generatorName, serializer);
+    sse_encode_opt_String(self.modelName, serializer);
+    sse_encode_opt_box_autoadd_model_identity(self.model, serializer);
+    sse_encode_opt_String(self.detectionSource, serializer);
+    sse_encode_list_evidence(self.evidence, serializer);
+  }
+
+  @protected
+  void sse_encode_ai_label(AiLabel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_ai_signature_summary(
+    AiSignatureSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.schemaVersion, serializer);
+    sse_encode_String(self.revision, serializer);
+    sse_encode_u_32(self.generatorCount, serializer);
+    sse_encode_u_32(self.editorCount, serializer);
+    sse_encode_u_32(self.phraseCount, serializer);
+    sse_encode_bool(self.builtIn, serializer);
+  }
+
+  @protected
+  void sse_encode_analysis_error(AnalysisError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case AnalysisError_Io(message: final message):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(message, serializer);
+      case AnalysisError_UnsupportedFormat(mimeType: final mimeType):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(mimeType, serializer);
+      case AnalysisError_ManifestNotFound():
+        sse_encode_i_32(2, serializer);
+      case AnalysisError_ManifestCorrupt(detail: final detail):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(detail, serializer);
+      case AnalysisError_CborDecode(detail: final detail):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(detail, serializer);
+      case AnalysisError_RemoteManifestUnavailable(url: final url):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(url, serializer);
+      case AnalysisError_Other(message: final message):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(message, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_ai_info(AiInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ai_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_analysis_error(
+    AnalysisError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_analysis_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_certificate_info(
+    CertificateInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_certificate_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_digital_source_type(
+    DigitalSourceType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_digital_source_type(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_distinguished_name(
+    DistinguishedName self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distinguished_name(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_exif_info(
+    ExifInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_exif_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_exif_thumbnail(
+    ExifThumbnail self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_exif_thumbnail(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_exposure_info(
+    ExposureInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_exposure_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_generation_parameters(
+    GenerationParameters self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_generation_parameters(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_gps_info(GpsInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_gps_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_image_region(
+    ImageRegion self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_image_region(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_iptc_info(
+    IptcInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_iptc_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_lens_info(
+    LensInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lens_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_maker_note(
+    MakerNote self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_maker_note(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_manifest_thumbnail(
+    ManifestThumbnail self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_manifest_thumbnail(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_mime_check(
+    MimeCheck self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_mime_check(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_model_identity(
+    ModelIdentity self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_model_identity(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_provenance_graph(
+    ProvenanceGraph self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_provenance_graph(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_revocation_source(
+    RevocationSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_revocation_source(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scoring_weights(
+    ScoringWeights self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scoring_weights(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_signer_info(
+    SignerInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_signer_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_software_agent_info(
+    SoftwareAgentInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_software_agent_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_thumbnail_comparison(
+    ThumbnailComparison self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_thumbnail_comparison(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_timestamp_info(
+    TimestampInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_timestamp_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_xmp_info(XmpInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_xmp_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_c_2_pa_analysis_result(
+    C2paAnalysisResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_verification_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_analysis_error(self.error, serializer);
+    sse_encode_list_validation_status_entry(
+      self.validationStatuses,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_signer_info(self.signer, serializer);
+    sse_encode_list_content_action(self.actions, serializer);
+    sse_encode_opt_box_autoadd_ai_info(self.aiInfo, serializer);
+    sse_encode_opt_box_autoadd_exif_info(self.exifInfo, serializer);
+    sse_encode_opt_box_autoadd_iptc_info(self.iptcInfo, serializer);
+    sse_encode_opt_box_autoadd_xmp_info(self.xmpInfo, serializer);
+    sse_encode_opt_box_autoadd_generation_parameters(
+      self.generationParameters,
+      serializer,
+    );
+    sse_encode_list_model_identity(self.modelIdentities, serializer);
+    sse_encode_opt_String(self.claimGenerator, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.format, serializer);
+    sse_encode_opt_String(self.instanceId, serializer);
+    sse_encode_opt_String(self.rawManifestJson, serializer);
+    sse_encode_opt_box_autoadd_provenance_graph(self.provenance, serializer);
+    sse_encode_opt_box_autoadd_mime_check(self.mimeCheck, serializer);
+    sse_encode_opt_box_autoadd_thumbnail_comparison(
+      self.thumbnailComparison,
+      serializer,
+    );
+    sse_encode_list_consistency_finding(self.consistency, serializer);
+  }
+
+  @protected
+  void sse_encode_c_2_pa_thumbnails(
+    C2paThumbnails self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.activeLabel, serializer);
+    sse_encode_Map_String_manifest_thumbnails_None(self.manifests, serializer);
+  }
+
+  @protected
+  void sse_encode_certificate_info(
+    CertificateInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distinguished_name(self.subject, serializer);
+    sse_encode_distinguished_name(self.issuer, serializer);
+    sse_encode_String(self.serialNumber, serializer);
+    sse_encode_i_64(self.notBefore, serializer);
+    sse_encode_i_64(self.notAfter, serializer);
+    sse_encode_String(self.keyAlgorithm, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.keySizeBits, serializer);
+    sse_encode_opt_String(self.curve, serializer);
+    sse_encode_String(self.signatureAlgorithm, serializer);
+    sse_encode_String(self.sha256Fingerprint, serializer);
+    sse_encode_list_String(self.extendedKeyUsages, serializer);
+    sse_encode_bool(self.isCa, serializer);
+    sse_encode_list_prim_u_8_strict(self.der, serializer);
+    sse_encode_revocation_info(self.revocation, serializer);
+  }
+
+  @protected
+  void sse_encode_consistency_finding(
+    ConsistencyFinding self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_inconsistency_kind(self.kind, serializer);
+    sse_encode_finding_severity(self.severity, serializer);
+    sse_encode_list_finding_value(self.values, serializer);
+    sse_encode_String(self.explanation, serializer);
+  }
+
+  @protected
+  void sse_encode_content_action(ContentAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.action, serializer);
+    sse_encode_opt_String(self.softwareAgent, serializer);
+    sse_encode_opt_box_autoadd_software_agent_info(
+      self.softwareAgentInfo,
+      serializer,
+    );
+    sse_encode_opt_String(self.when, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_box_autoadd_digital_source_type(
+      self.digitalSourceType,
+      serializer,
+    );
+    sse_encode_opt_String(self.reason, serializer);
+    sse_encode_list_String(self.ingredients, serializer);
+    sse_encode_list_action_parameter(self.parameters, serializer);
+    sse_encode_list_action_change(self.changes, serializer);
+    sse_encode_list_content_action(self.related, serializer);
+  }
+
+  @protected
+  void sse_encode_digital_source_type(
+    DigitalSourceType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DigitalSourceType_DigitalCapture():
+        sse_encode_i_32(0, serializer);
+      case DigitalSourceType_ComputationalCapture():
+        sse_encode_i_32(1, serializer);
+      case DigitalSourceType_NegativeFilm():
+        sse_encode_i_32(2, serializer);
+      case DigitalSourceType_PositiveFilm():
+        sse_encode_i_32(3, serializer);
+      case DigitalSourceType_Print():
+        sse_encode_i_32(4, serializer);
+      case DigitalSourceType_ScreenCapture():
+        sse_encode_i_32(5, serializer);
+      case DigitalSourceType_VirtualRecording():
+        sse_encode_i_32(6, serializer);
+      case DigitalSourceType_HumanEdits():
+        sse_encode_i_32(7, serializer);
+      case DigitalSourceType_MinorHumanEdits():
+        sse_encode_i_32(8, serializer);
+      case DigitalSourceType_AlgorithmicallyEnhanced():
+        sse_encode_i_32(9, serializer);
+      case DigitalSourceType_DigitalCreation():
+        sse_encode_i_32(10, serializer);
+      case DigitalSourceType_DataDrivenMedia():
+        sse_encode_i_32(11, serializer);
+      case DigitalSourceType_AlgorithmicMedia():
+        sse_encode_i_32(12, serializer);
+      case DigitalSourceType_TrainedAlgorithmicMedia():
+        sse_encode_i_32(13, serializer);
+      case DigitalSourceType_Composite():
+        sse_encode_i_32(14, serializer);
+      case DigitalSourceType_CompositeCapture():
+        sse_encode_i_32(15, serializer);
+      case DigitalSourceType_CompositeSynthetic():
+        sse_encode_i_32(16, serializer);
+      case DigitalSourceType_CompositeWithTrainedAlgorithmicMedia():
+        sse_encode_i_32(17, serializer);
+      case DigitalSourceType_Unknown(uri: final uri):
+        sse_encode_i_32(18, serializer);
+        sse_encode_String(uri, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_distinguished_name(
+    DistinguishedName self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_dn_attribute(self.attributes, serializer);
+    sse_encode_String(self.rfc4514, serializer);
+  }
+
+  @protected
+  void sse_encode_dn_attribute(DnAttribute self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.attrType, serializer);
+    sse_encode_String(self.oid, serializer);
+    sse_encode_String(self.value, serializer);
+    sse_encode_u_32(self.rdnIndex, serializer);
+  }
+
+  @protected
+  void sse_encode_evidence(Evidence self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_evidence_detector(self.detector, serializer);
+    sse_encode_evidence_kind(self.kind, serializer);
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.matchedText, serializer);
+    sse_encode_evidence_strength(self.strength, serializer);
+    sse_encode_String(self.explanation, serializer);
+    sse_encode_opt_String(self.generator, serializer);
+  }
+
+  @protected
+  void sse_encode_evidence_detector(
+    EvidenceDetector self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_evidence_kind(EvidenceKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_evidence_strength(
+    EvidenceStrength self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_exif_info(ExifInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.software, serializer);
+    sse_encode_opt_String(self.make, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.copyright, serializer);
+    sse_encode_opt_String(self.userComment, serializer);
+    sse_encode_opt_String(self.imageDescription, serializer);
+    sse_encode_opt_String(self.dateTimeOriginal, serializer);
+    sse_encode_opt_String(self.dateTime, serializer);
+    sse_encode_opt_String(self.dateTimeDigitized, serializer);
+    sse_encode_opt_String(self.offsetTime, serializer);
+    sse_encode_opt_String(self.offsetTimeOriginal, serializer);
+    sse_encode_opt_String(self.offsetTimeDigitized, serializer);
+    sse_encode_opt_String(self.subSecTime, serializer);
+    sse_encode_opt_String(self.subSecTimeOriginal, serializer);
+    sse_encode_opt_String(self.subSecTimeDigitized, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.orientation, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.imageWidth, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.imageHeight, serializer);
+    sse_encode_opt_String(self.bodySerialNumber, serializer);
+    sse_encode_opt_String(self.cameraOwnerName, serializer);
+    sse_encode_opt_String(self.imageUniqueId, serializer);
+    sse_encode_opt_box_autoadd_gps_info(self.gps, serializer);
+    sse_encode_opt_box_autoadd_lens_info(self.lens, serializer);
+    sse_encode_opt_box_autoadd_exposure_info(self.exposure, serializer);
+    sse_encode_opt_box_autoadd_exif_thumbnail(self.thumbnail, serializer);
+    sse_encode_opt_box_autoadd_maker_note(self.makerNote, serializer);
+    sse_encode_list_exif_tag(self.tags, serializer);
+    sse_encode_bool(self.aiDetected, serializer);
+    sse_encode_opt_String(self.aiGenerator, serializer);
+  }
+
+  @protected
+  void sse_encode_exif_tag(ExifTag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self.ifd, serializer);
+    sse_encode_String(self.group, serializer);
+    sse_encode_u_16(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_exif_thumbnail(ExifThumbnail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
+
+  @protected
+  void sse_encode_exposure_info(ExposureInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_f_64(self.exposureTime, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.fNumber, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.iso, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.exposureBias, serializer);
+    sse_encode_opt_String(self.exposureProgram, serializer);
+    sse_encode_opt_String(self.meteringMode, serializer);
+    sse_encode_opt_String(self.flash, serializer);
+    sse_encode_opt_String(self.whiteBalance, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_finding_severity(
+    FindingSeverity self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_finding_value(FindingValue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.source, serializer);
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_generation_parameters(
+    GenerationParameters self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_generation_tool(self.tool, serializer);
+    sse_encode_opt_String(self.toolVersion, serializer);
+    sse_encode_opt_String(self.prompt, serializer);
+    sse_encode_opt_String(self.negativePrompt, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.steps, serializer);
+    sse_encode_opt_String(self.sampler, serializer);
+    sse_encode_opt_String(self.scheduler, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.cfgScale, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.modelHash, serializer);
+    sse_encode_list_lora_reference(self.loras, serializer);
+    sse_encode_opt_String(self.workflow, serializer);
+    sse_encode_list_generation_setting(self.otherSettings, serializer);
+    sse_encode_list_String(self.sourceChunks, serializer);
+  }
+
+  @protected
+  void sse_encode_generation_setting(
+    GenerationSetting self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_generation_tool(
+    GenerationTool self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_gps_info(GpsInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.altitude, serializer);
+    sse_encode_opt_String(self.timestamp, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.imageDirection, serializer);
+    sse_encode_opt_String(self.imageDirectionRef, serializer);
+    sse_encode_opt_String(self.mapDatum, serializer);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_image_region(ImageRegion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.x, serializer);
+    sse_encode_u_32(self.y, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+  }
+
+  @protected
+  void sse_encode_inconsistency_kind(
+    InconsistencyKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_ingredient_edge(
+    IngredientEdge self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fromLabel, serializer);
+    sse_encode_opt_String(self.toLabel, serializer);
+    sse_encode_String(self.ingredientTitle, serializer);
+    sse_encode_opt_String(self.ingredientFormat, serializer);
+    sse_encode_opt_String(self.ingredientInstanceId, serializer);
+    sse_encode_ingredient_relationship(self.relationship, serializer);
+    sse_encode_list_validation_status_entry(
+      self.validationStatuses,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_ingredient_relationship(
+    IngredientRelationship self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_ingredient_thumbnail(
+    IngredientThumbnail self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.instanceId, serializer);
+    sse_encode_opt_String(self.manifestLabel, serializer);
+    sse_encode_manifest_thumbnail(self.thumbnail, serializer);
+  }
+
+  @protected
+  void sse_encode_iptc_info(IptcInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.objectName, serializer);
+    sse_encode_opt_String(self.headline, serializer);
+    sse_encode_opt_String(self.caption, serializer);
+    sse_encode_opt_String(self.captionWriter, serializer);
+    sse_encode_list_String(self.keywords, serializer);
+    sse_encode_list_String(self.byLine, serializer);
+    sse_encode_opt_String(self.byLineTitle, serializer);
+    sse_encode_opt_String(self.credit, serializer);
+    sse_encode_opt_String(self.source, serializer);
+    sse_encode_opt_String(self.copyrightNotice, serializer);
+    sse_encode_opt_String(self.specialInstructions, serializer);
+    sse_encode_opt_String(self.dateCreated, serializer);
+    sse_encode_opt_String(self.city, serializer);
+    sse_encode_opt_String(self.subLocation, serializer);
+    sse_encode_opt_String(self.provinceState, serializer);
+    sse_encode_opt_String(self.countryCode, serializer);
+    sse_encode_opt_String(self.country, serializer);
+    sse_encode_opt_String(self.transmissionReference, serializer);
+  }
+
+  @protected
+  void sse_encode_lens_info(LensInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.make, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.serialNumber, serializer);
+    sse_encode_list_prim_f_64_strict(self.specification, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.focalLength, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.focalLength35Mm, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_action_change(
+    List<ActionChange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_action_change(item, serializer);
+    }
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_list_action_parameter(
+    List<ActionParameter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_action_parameter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_certificate_info(
+    List<CertificateInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_certificate_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_consistency_finding(
+    List<ConsistencyFinding> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_consistency_finding(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_content_action(
+    List<ContentAction> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_content_action(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_dn_attribute(
+    List<DnAttribute> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dn_attribute(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_evidence(List<Evidence> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_evidence(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_exif_tag(List<ExifTag> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_exif_tag(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_finding_value(
+    List<FindingValue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_finding_value(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_generation_setting(
+    List<GenerationSetting> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_generation_setting(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_ingredient_edge(
+    List<IngredientEdge> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ingredient_edge(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_ingredient_thumbnail(
+    List<IngredientThumbnail> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ingredient_thumbnail(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_lora_reference(
+    List<LoraReference> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lora_reference(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_maker_note_tag(
+    List<MakerNoteTag> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_maker_note_tag(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_manifest_node(
+    List<ManifestNode> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_manifest_node(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_model_identity(
+    List<ModelIdentity> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_model_identity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_record_string_manifest_thumbnails(
+    List<(String, ManifestThumbnails)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_manifest_thumbnails(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_region_range(
+    List<RegionRange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_region_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_validation_status_entry(
+    List<ValidationStatusEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_validation_status_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_lora_reference(LoraReference self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.weight, serializer);
+  }
+
+  @protected
+  void sse_encode_maker_note(MakerNote self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.vendor, serializer);
+    sse_encode_opt_String(self.serialNumber, serializer);
+    sse_encode_opt_String(self.lensModel, serializer);
+    sse_encode_opt_String(self.firmwareVersion, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.shutterCount, serializer);
+    sse_encode_list_maker_note_tag(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_maker_note_tag(MakerNoteTag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_manifest_node(ManifestNode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.label, serializer);
+    sse_encode_bool(self.isActive, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.format, serializer);
+    sse_encode_opt_String(self.instanceId, serializer);
+    sse_encode_opt_String(self.claimGenerator, serializer);
+    sse_encode_opt_box_autoadd_signer_info(self.signer, serializer);
+    sse_encode_list_content_action(self.actions, serializer);
+    sse_encode_list_validation_status_entry(
+      self.validationStatuses,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_manifest_thumbnail(
+    ManifestThumbnail self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_opt_String(self.identifier, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
+
+  @protected
+  void sse_encode_manifest_thumbnails(
+    ManifestThumbnails self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_manifest_thumbnail(self.claim, serializer);
+    sse_encode_list_ingredient_thumbnail(self.ingredients, serializer);
+  }
+
+  @protected
+  void sse_encode_mime_check(MimeCheck self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.claimedMimeType, serializer);
+    sse_encode_opt_String(self.detectedMimeType, serializer);
+    sse_encode_bool(self.mismatch, serializer);
+  }
+
+  @protected
+  void sse_encode_model_identity(ModelIdentity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.vendor, serializer);
+    sse_encode_String(self.product, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.version, serializer);
+    sse_encode_bool(self.isAiGenerator, serializer);
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.matchedText, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_ai_info(
+    AiInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_ai_info(self, serializer);
+    }
   }
 
   @protected
-  AiInfo? dco_decode_opt_box_autoadd_ai_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_ai_info(raw);
-  }
+  void sse_encode_opt_box_autoadd_analysis_error(
+    AnalysisError? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @protected
-  ExifInfo? dco_decode_opt_box_autoadd_exif_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_exif_info(raw);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_analysis_error(self, serializer);
+    }
   }
 
   @protected
-  SignerInfo? dco_decode_opt_box_autoadd_signer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_signer_info(raw);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  SignerInfo dco_decode_signer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return SignerInfo(
-      name: dco_decode_opt_String(arr[0]),
-      organization: dco_decode_opt_String(arr[1]),
-      issuedBy: dco_decode_opt_String(arr[2]),
-      timestamp: dco_decode_opt_String(arr[3]),
-    );
+  void sse_encode_opt_box_autoadd_certificate_info(
+    CertificateInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_certificate_info(self, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_opt_box_autoadd_digital_source_type(
+    DigitalSourceType? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_digital_source_type(self, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_opt_box_autoadd_distinguished_name(
+    DistinguishedName? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_distinguished_name(self, serializer);
+    }
   }
 
   @protected
-  VerificationStatus dco_decode_verification_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return VerificationStatus_Verified();
-      case 1:
-        return VerificationStatus_SignatureInvalid();
-      case 2:
-        return VerificationStatus_CertificateExpired();
-      case 3:
-        return VerificationStatus_CertificateUntrusted();
-      case 4:
-        return VerificationStatus_NoManifest();
-      case 5:
-        return VerificationStatus_Error(message: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
+  void sse_encode_opt_box_autoadd_exif_info(
+    ExifInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_exif_info(self, serializer);
     }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_exif_thumbnail(
+    ExifThumbnail? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_exif_thumbnail(self, serializer);
+    }
   }
 
   @protected
-  AiInfo sse_decode_ai_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_exposure_info(
+    ExposureInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_isAiGenerated = sse_decode_bool(deserializer);
-    var var_generatorName = sse_decode_opt_String(deserializer);
-    var var_modelName = sse_decode_opt_String(deserializer);
-    var var_detectionSource = sse_decode_opt_String(deserializer);
-    return AiInfo(
-      isAiGenerated: var_isAiGenerated,
-      generatorName: var_generatorName,
-      modelName: var_modelName,
-      detectionSource: var_detectionSource,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_exposure_info(self, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  AiInfo sse_decode_box_autoadd_ai_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_generation_parameters(
+    GenerationParameters? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ai_info(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_generation_parameters(self, serializer);
+    }
   }
 
   @protected
-  ExifInfo sse_decode_box_autoadd_exif_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_gps_info(
+    GpsInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_exif_info(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_gps_info(self, serializer);
+    }
   }
 
   @protected
-  SignerInfo sse_decode_box_autoadd_signer_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_signer_info(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  C2paAnalysisResult sse_decode_c_2_pa_analysis_result(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_image_region(
+    ImageRegion? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_status = sse_decode_verification_status(deserializer);
-    var var_signer = sse_decode_opt_box_autoadd_signer_info(deserializer);
-    var var_actions = sse_decode_list_content_action(deserializer);
-    var var_aiInfo = sse_decode_opt_box_autoadd_ai_info(deserializer);
-    var var_exifInfo = sse_decode_opt_box_autoadd_exif_info(deserializer);
-    var var_claimGenerator = sse_decode_opt_String(deserializer);
-    var var_title = sse_decode_opt_String(deserializer);
-    var var_format = sse_decode_opt_String(deserializer);
-    var var_instanceId = sse_decode_opt_String(deserializer);
-    var var_rawManifestJson = sse_decode_opt_String(deserializer);
-    return C2paAnalysisResult(
-      status: var_status,
-      signer: var_signer,
-      actions: var_actions,
-      aiInfo: var_aiInfo,
-      exifInfo: var_exifInfo,
-      claimGenerator: var_claimGenerator,
-      title: var_title,
-      format: var_format,
-      instanceId: var_instanceId,
-      rawManifestJson: var_rawManifestJson,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_image_region(self, serializer);
+    }
   }
 
   @protected
-  ContentAction sse_decode_content_action(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_iptc_info(
+    IptcInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_action = sse_decode_String(deserializer);
-    var var_softwareAgent = sse_decode_opt_String(deserializer);
-    var var_when = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    return ContentAction(
-      action: var_action,
-      softwareAgent: var_softwareAgent,
-      when: var_when,
-      description: var_description,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_iptc_info(self, serializer);
+    }
   }
 
   @protected
-  ExifInfo sse_decode_exif_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_lens_info(
+    LensInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_software = sse_decode_opt_String(deserializer);
-    var var_make = sse_decode_opt_String(deserializer);
-    var var_model = sse_decode_opt_String(deserializer);
-    var var_artist = sse_decode_opt_String(deserializer);
-    var var_copyright = sse_decode_opt_String(deserializer);
-    var var_userComment = sse_decode_opt_String(deserializer);
-    var var_imageDescription = sse_decode_opt_String(deserializer);
-    var var_dateTimeOriginal = sse_decode_opt_String(deserializer);
-    var var_aiDetected = sse_decode_bool(deserializer);
-    var var_aiGenerator = sse_decode_opt_String(deserializer);
-    return ExifInfo(
-      software: var_software,
-      make: var_make,
-      model: var_model,
-      artist: var_artist,
-      copyright: var_copyright,
-      userComment: var_userComment,
-      imageDescription: var_imageDescription,
-      dateTimeOriginal: var_dateTimeOriginal,
-      aiDetected: var_aiDetected,
-      aiGenerator: var_aiGenerator,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lens_info(self, serializer);
+    }
   }
 
   @protected
-  List<ContentAction> sse_decode_list_content_action(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_maker_note(
+    MakerNote? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ContentAction>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_content_action(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_maker_note(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_manifest_thumbnail(
+    ManifestThumbnail? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_manifest_thumbnail(self, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_mime_check(
+    MimeCheck? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_mime_check(self, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_model_identity(
+    ModelIdentity? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_model_identity(self, serializer);
     }
   }
 
   @protected
-  AiInfo? sse_decode_opt_box_autoadd_ai_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_provenance_graph(
+    ProvenanceGraph? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_ai_info(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_provenance_graph(self, serializer);
     }
   }
 
   @protected
-  ExifInfo? sse_decode_opt_box_autoadd_exif_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_revocation_source(
+    RevocationSource? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exif_info(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_revocation_source(self, serializer);
     }
   }
 
   @protected
-  SignerInfo? sse_decode_opt_box_autoadd_signer_info(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_signer_info(
+    SignerInfo? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_signer_info(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_signer_info(self, serializer);
     }
   }
 
   @protected
-  SignerInfo sse_decode_signer_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_software_agent_info(
+    SoftwareAgentInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_organization = sse_decode_opt_String(deserializer);
-    var var_issuedBy = sse_decode_opt_String(deserializer);
-    var var_timestamp = sse_decode_opt_String(deserializer);
-    return SignerInfo(
-      name: var_name,
-      organization: var_organization,
-      issuedBy: var_issuedBy,
-      timestamp: var_timestamp,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_software_agent_info(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_thumbnail_comparison(
+    ThumbnailComparison? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_thumbnail_comparison(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_timestamp_info(
+    TimestampInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_timestamp_info(self, serializer);
+    }
   }
 
   @protected
-  VerificationStatus sse_decode_verification_status(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return VerificationStatus_Verified();
-      case 1:
-        return VerificationStatus_SignatureInvalid();
-      case 2:
-        return VerificationStatus_CertificateExpired();
-      case 3:
-        return VerificationStatus_CertificateUntrusted();
-      case 4:
-        return VerificationStatus_NoManifest();
-      case 5:
-        var var_message = sse_decode_String(deserializer);
-        return VerificationStatus_Error(message: var_message);
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_xmp_info(
+    XmpInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_xmp_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_ai_info(AiInfo self, SseSerializer serializer) {
+  void sse_encode_provenance_graph(
+    ProvenanceGraph self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isAiGenerated, serializer);
-    sse_encode_opt_String(self.generatorName, serializer);
-    sse_encode_opt_String(self.modelName, serializer);
-    sse_encode_opt_String(self.detectionSource, serializer);
+    sse_encode_opt_String(self.activeLabel, serializer);
+    sse_encode_list_manifest_node(self.nodes, serializer);
+    sse_encode_list_ingredient_edge(self.edges, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_record_string_manifest_thumbnails(
+    (String, ManifestThumbnails) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_manifest_thumbnails(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_ai_info(AiInfo self, SseSerializer serializer) {
+  void sse_encode_region_range(RegionRange self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ai_info(self, serializer);
+    switch (self) {
+      case RegionRange_Spatial(
+        shape: final shape,
+        unit: final unit,
+        x: final x,
+        y: final y,
+        width: final width,
+        height: final height,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(shape, serializer);
+        sse_encode_opt_String(unit, serializer);
+        sse_encode_opt_box_autoadd_f_64(x, serializer);
+        sse_encode_opt_box_autoadd_f_64(y, serializer);
+        sse_encode_opt_box_autoadd_f_64(width, serializer);
+        sse_encode_opt_box_autoadd_f_64(height, serializer);
+      case RegionRange_Temporal(start: final start, end: final end):
+        sse_encode_i_32(1, serializer);
+        sse_encode_opt_String(start, serializer);
+        sse_encode_opt_String(end, serializer);
+      case RegionRange_Frame(start: final start, end: final end):
+        sse_encode_i_32(2, serializer);
+        sse_encode_opt_box_autoadd_i_64(start, serializer);
+        sse_encode_opt_box_autoadd_i_64(end, serializer);
+      case RegionRange_Other(json: final json):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(json, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_exif_info(
-    ExifInfo self,
+  void sse_encode_revocation_info(
+    RevocationInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_exif_info(self, serializer);
+    sse_encode_revocation_state(self.state, serializer);
+    sse_encode_opt_box_autoadd_revocation_source(self.source, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.revokedAt, serializer);
+    sse_encode_opt_String(self.reason, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.thisUpdate, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextUpdate, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_signer_info(
-    SignerInfo self,
+  void sse_encode_revocation_source(
+    RevocationSource self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_signer_info(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_c_2_pa_analysis_result(
-    C2paAnalysisResult self,
+  void sse_encode_revocation_state(
+    RevocationState self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_verification_status(self.status, serializer);
-    sse_encode_opt_box_autoadd_signer_info(self.signer, serializer);
-    sse_encode_list_content_action(self.actions, serializer);
-    sse_encode_opt_box_autoadd_ai_info(self.aiInfo, serializer);
-    sse_encode_opt_box_autoadd_exif_info(self.exifInfo, serializer);
-    sse_encode_opt_String(self.claimGenerator, serializer);
-    sse_encode_opt_String(self.title, serializer);
-    sse_encode_opt_String(self.format, serializer);
-    sse_encode_opt_String(self.instanceId, serializer);
-    sse_encode_opt_String(self.rawManifestJson, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_content_action(ContentAction self, SseSerializer serializer) {
+  void sse_encode_scoring_weights(
+    ScoringWeights self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.action, serializer);
-    sse_encode_opt_String(self.softwareAgent, serializer);
-    sse_encode_opt_String(self.when, serializer);
-    sse_encode_opt_String(self.description, serializer);
+    sse_encode_f_64(self.definitive, serializer);
+    sse_encode_f_64(self.strong, serializer);
+    sse_encode_f_64(self.weak, serializer);
+    sse_encode_f_64(self.cameraDiscount, serializer);
+    sse_encode_f_64(self.likelyThreshold, serializer);
   }
 
   @protected
-  void sse_encode_exif_info(ExifInfo self, SseSerializer serializer) {
+  void sse_encode_signer_info(SignerInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.software, serializer);
-    sse_encode_opt_String(self.make, serializer);
-    sse_encode_opt_String(self.model, serializer);
-    sse_encode_opt_String(self.artist, serializer);
-    sse_encode_opt_String(self.copyright, serializer);
-    sse_encode_opt_String(self.userComment, serializer);
-    sse_encode_opt_String(self.imageDescription, serializer);
-    sse_encode_opt_String(self.dateTimeOriginal, serializer);
-    sse_encode_bool(self.aiDetected, serializer);
-    sse_encode_opt_String(self.aiGenerator, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.organization, serializer);
+    sse_encode_opt_String(self.issuedBy, serializer);
+    sse_encode_opt_String(self.timestamp, serializer);
+    sse_encode_list_certificate_info(self.certificateChain, serializer);
+    sse_encode_opt_box_autoadd_timestamp_info(self.timestampToken, serializer);
   }
 
   @protected
-  void sse_encode_list_content_action(
-    List<ContentAction> self,
+  void sse_encode_software_agent_info(
+    SoftwareAgentInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_content_action(item, serializer);
-    }
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.version, serializer);
+    sse_encode_opt_String(self.icon, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_thumbnail_comparison(
+    ThumbnailComparison self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
+    sse_encode_u_32(self.comparedWidth, serializer);
+    sse_encode_u_32(self.comparedHeight, serializer);
+    sse_encode_f_64(self.difference, serializer);
+    sse_encode_f_64(self.changedFraction, serializer);
+    sse_encode_opt_box_autoadd_image_region(self.changedRegion, serializer);
+    sse_encode_bool(self.contentDiffers, serializer);
+  }
+
+  @protected
+  void sse_encode_timestamp_info(TimestampInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.tsaName, serializer);
+    sse_encode_opt_box_autoadd_certificate_info(
+      self.tsaCertificate,
+      serializer,
     );
+    sse_encode_i_64(self.genTime, serializer);
+    sse_encode_String(self.hashAlgorithm, serializer);
+    sse_encode_String(self.messageImprint, serializer);
+    sse_encode_opt_String(self.policy, serializer);
+    sse_encode_String(self.serialNumber, serializer);
+    sse_encode_bool(self.signatureValid, serializer);
+    sse_encode_bool(self.trusted, serializer);
+    sse_encode_bool(self.signerValidityEvaluatedAtTsaTime, serializer);
+    sse_encode_opt_box_autoadd_bool(self.signerValidAtGenTime, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_trust_store_summary(
+    TrustStoreSummary self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_u_32(self.anchorCount, serializer);
+    sse_encode_u_32(self.allowedCertificateCount, serializer);
+    sse_encode_list_String(self.ekuOids, serializer);
+    sse_encode_u_32(self.tsaAnchorCount, serializer);
+    sse_encode_u_32(self.crlCount, serializer);
+    sse_encode_u_32(self.ocspResponseCount, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    serializer.buffer.putUint16(self);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_ai_info(
-    AiInfo? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_ai_info(self, serializer);
-    }
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_exif_info(
-    ExifInfo? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exif_info(self, serializer);
-    }
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_signer_info(
-    SignerInfo? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_signer_info(self, serializer);
-    }
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_signer_info(SignerInfo self, SseSerializer serializer) {
+  void sse_encode_validation_category(
+    ValidationCategory self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_opt_String(self.organization, serializer);
-    sse_encode_opt_String(self.issuedBy, serializer);
-    sse_encode_opt_String(self.timestamp, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_validation_status_code(
+    ValidationStatusCode self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    switch (self) {
+      case ValidationStatusCode_ClaimSignatureValidated():
+        sse_encode_i_32(0, serializer);
+      case ValidationStatusCode_ClaimSignatureInsideValidity():
+        sse_encode_i_32(1, serializer);
+      case ValidationStatusCode_SigningCredentialTrusted():
+        sse_encode_i_32(2, serializer);
+      case ValidationStatusCode_SigningCredentialNotRevoked():
+        sse_encode_i_32(3, serializer);
+      case ValidationStatusCode_TimeStampTrusted():
+        sse_encode_i_32(4, serializer);
+      case ValidationStatusCode_TimeStampValidated():
+        sse_encode_i_32(5, serializer);
+      case ValidationStatusCode_AssertionHashedUriMatch():
+        sse_encode_i_32(6, serializer);
+      case ValidationStatusCode_AssertionDataHashMatch():
+        sse_encode_i_32(7, serializer);
+      case ValidationStatusCode_AssertionBmffHashMatch():
+        sse_encode_i_32(8, serializer);
+      case ValidationStatusCode_AssertionBoxesHashMatch():
+        sse_encode_i_32(9, serializer);
+      case ValidationStatusCode_AssertionCollectionHashMatch():
+        sse_encode_i_32(10, serializer);
+      case ValidationStatusCode_AssertionAccessible():
+        sse_encode_i_32(11, serializer);
+      case ValidationStatusCode_IngredientManifestValidated():
+        sse_encode_i_32(12, serializer);
+      case ValidationStatusCode_IngredientClaimSignatureValidated():
+        sse_encode_i_32(13, serializer);
+      case ValidationStatusCode_AlgorithmDeprecated():
+        sse_encode_i_32(14, serializer);
+      case ValidationStatusCode_SigningCredentialOcspSkipped():
+        sse_encode_i_32(15, serializer);
+      case ValidationStatusCode_SigningCredentialOcspInaccessible():
+        sse_encode_i_32(16, serializer);
+      case ValidationStatusCode_TimeStampUntrusted():
+        sse_encode_i_32(17, serializer);
+      case ValidationStatusCode_TimeStampOutsideValidity():
+        sse_encode_i_32(18, serializer);
+      case ValidationStatusCode_IngredientUnknownProvenance():
+        sse_encode_i_32(19, serializer);
+      case ValidationStatusCode_ManifestUnknownProvenance():
+        sse_encode_i_32(20, serializer);
+      case ValidationStatusCode_ClaimMissing():
+        sse_encode_i_32(21, serializer);
+      case ValidationStatusCode_ClaimMultiple():
+        sse_encode_i_32(22, serializer);
+      case ValidationStatusCode_ClaimHardBindingsMissing():
+        sse_encode_i_32(23, serializer);
+      case ValidationStatusCode_ClaimRequiredMissing():
+        sse_encode_i_32(24, serializer);
+      case ValidationStatusCode_ClaimCborInvalid():
+        sse_encode_i_32(25, serializer);
+      case ValidationStatusCode_ClaimSignatureMissing():
+        sse_encode_i_32(26, serializer);
+      case ValidationStatusCode_ClaimSignatureMismatch():
+        sse_encode_i_32(27, serializer);
+      case ValidationStatusCode_ClaimSignatureOutsideValidity():
+        sse_encode_i_32(28, serializer);
+      case ValidationStatusCode_AlgorithmUnsupported():
+        sse_encode_i_32(29, serializer);
+      case ValidationStatusCode_SigningCredentialUntrusted():
+        sse_encode_i_32(30, serializer);
+      case ValidationStatusCode_SigningCredentialInvalid():
+        sse_encode_i_32(31, serializer);
+      case ValidationStatusCode_SigningCredentialExpired():
+        sse_encode_i_32(32, serializer);
+      case ValidationStatusCode_SigningCredentialRevoked():
+        sse_encode_i_32(33, serializer);
+      case ValidationStatusCode_SigningCredentialOcspUnknown():
+        sse_encode_i_32(34, serializer);
+      case ValidationStatusCode_TimeStampMismatch():
+        sse_encode_i_32(35, serializer);
+      case ValidationStatusCode_TimeStampMalformed():
+        sse_encode_i_32(36, serializer);
+      case ValidationStatusCode_AssertionHashedUriMismatch():
+        sse_encode_i_32(37, serializer);
+      case ValidationStatusCode_AssertionDataHashMismatch():
+        sse_encode_i_32(38, serializer);
+      case ValidationStatusCode_AssertionDataHashMalformed():
+        sse_encode_i_32(39, serializer);
+      case ValidationStatusCode_AssertionBmffHashMismatch():
+        sse_encode_i_32(40, serializer);
+      case ValidationStatusCode_AssertionBmffHashMalformed():
+        sse_encode_i_32(41, serializer);
+      case ValidationStatusCode_AssertionBoxesHashMismatch():
+        sse_encode_i_32(42, serializer);
+      case ValidationStatusCode_AssertionBoxesHashUnknownBox():
+        sse_encode_i_32(43, serializer);
+      case ValidationStatusCode_AssertionCollectionHashMismatch():
+        sse_encode_i_32(44, serializer);
+      case ValidationStatusCode_AssertionCollectionHashMalformed():
+        sse_encode_i_32(45, serializer);
+      case ValidationStatusCode_AssertionCollectionHashIncorrectFileCount():
+        sse_encode_i_32(46, serializer);
+      case ValidationStatusCode_AssertionCollectionHashInvalidUri():
+        sse_encode_i_32(47, serializer);
+      case ValidationStatusCode_HashedUriMissing():
+        sse_encode_i_32(48, serializer);
+      case ValidationStatusCode_HashedUriMismatch():
+        sse_encode_i_32(49, serializer);
+      case ValidationStatusCode_AssertionMissing():
+        sse_encode_i_32(50, serializer);
+      case ValidationStatusCode_AssertionRequiredMissing():
+        sse_encode_i_32(51, serializer);
+      case ValidationStatusCode_AssertionMultipleHardBindings():
+        sse_encode_i_32(52, serializer);
+      case ValidationStatusCode_AssertionUndeclared():
+        sse_encode_i_32(53, serializer);
+      case ValidationStatusCode_AssertionInaccessible():
+        sse_encode_i_32(54, serializer);
+      case ValidationStatusCode_AssertionNotRedacted():
+        sse_encode_i_32(55, serializer);
+      case ValidationStatusCode_AssertionSelfRedacted():
+        sse_encode_i_32(56, serializer);
+      case ValidationStatusCode_AssertionJsonInvalid():
+        sse_encode_i_32(57, serializer);
+      case ValidationStatusCode_AssertionCborInvalid():
+        sse_encode_i_32(58, serializer);
+      case ValidationStatusCode_AssertionActionIngredientMismatch():
+        sse_encode_i_32(59, serializer);
+      case ValidationStatusCode_AssertionActionMalformed():
+        sse_encode_i_32(60, serializer);
+      case ValidationStatusCode_AssertionActionRedacted():
+        sse_encode_i_32(61, serializer);
+      case ValidationStatusCode_AssertionActionRedactionMismatch():
+        sse_encode_i_32(62, serializer);
+      case ValidationStatusCode_AssertionCloudDataHardBinding():
+        sse_encode_i_32(63, serializer);
+      case ValidationStatusCode_AssertionCloudDataActions():
+        sse_encode_i_32(64, serializer);
+      case ValidationStatusCode_AssertionCloudDataLabelMismatch():
+        sse_encode_i_32(65, serializer);
+      case ValidationStatusCode_IngredientHashedUriMissing():
+        sse_encode_i_32(66, serializer);
+      case ValidationStatusCode_IngredientHashedUriMismatch():
+        sse_encode_i_32(67, serializer);
+      case ValidationStatusCode_IngredientManifestMissing():
+        sse_encode_i_32(68, serializer);
+      case ValidationStatusCode_IngredientManifestMismatch():
+        sse_encode_i_32(69, serializer);
+      case ValidationStatusCode_IngredientClaimSignatureMissing():
+        sse_encode_i_32(70, serializer);
+      case ValidationStatusCode_IngredientClaimSignatureMismatch():
+        sse_encode_i_32(71, serializer);
+      case ValidationStatusCode_ManifestInaccessible():
+        sse_encode_i_32(72, serializer);
+      case ValidationStatusCode_ManifestMultipleParents():
+        sse_encode_i_32(73, serializer);
+      case ValidationStatusCode_ManifestUpdateInvalid():
+        sse_encode_i_32(74, serializer);
+      case ValidationStatusCode_ManifestUpdateWrongParents():
+        sse_encode_i_32(75, serializer);
+      case ValidationStatusCode_ManifestTimestampInvalid():
+        sse_encode_i_32(76, serializer);
+      case ValidationStatusCode_ManifestTimestampWrongParents():
+        sse_encode_i_32(77, serializer);
+      case ValidationStatusCode_ManifestCompressedInvalid():
+        sse_encode_i_32(78, serializer);
+      case ValidationStatusCode_GeneralError():
+        sse_encode_i_32(79, serializer);
+      case ValidationStatusCode_Unknown(code: final code):
+        sse_encode_i_32(80, serializer);
+        sse_encode_String(code, serializer);
+    }
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_validation_status_entry(
+    ValidationStatusEntry self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.code, serializer);
+    sse_encode_validation_status_code(self.kind, serializer);
+    sse_encode_validation_category(self.category, serializer);
+    sse_encode_opt_String(self.url, serializer);
+    sse_encode_opt_String(self.explanation, serializer);
   }
 
   @protected
@@ -840,17 +7195,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(2, serializer);
       case VerificationStatus_CertificateUntrusted():
         sse_encode_i_32(3, serializer);
-      case VerificationStatus_NoManifest():
+      case VerificationStatus_CertificateRevoked():
         sse_encode_i_32(4, serializer);
-      case VerificationStatus_Error(message: final message):
+      case VerificationStatus_ContentModified():
         sse_encode_i_32(5, serializer);
+      case VerificationStatus_ValidationFailed(codes: final codes):
+        sse_encode_i_32(6, serializer);
+        sse_encode_list_String(codes, serializer);
+      case VerificationStatus_NoManifest():
+        sse_encode_i_32(7, serializer);
+      case VerificationStatus_Error(message: final message):
+        sse_encode_i_32(8, serializer);
         sse_encode_String(message, serializer);
     }
   }
 
+  @protected
+  void sse_encode_xmp_info(XmpInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_digital_source_type(
+      self.digitalSourceType,
+      serializer,
+    );
+    sse_encode_opt_String(self.credit, serializer);
+    sse_encode_opt_String(self.creatorTool, serializer);
+    sse_encode_list_String(self.creators, serializer);
+    sse_encode_opt_String(self.provenance, serializer);
+    sse_encode_String(self.container, serializer);
+    sse_encode_String(self.packet, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putInt32(self);
   }
 }
+
+@sealed
+class ChunkedAssetImpl extends RustOpaque implements ChunkedAsset {
+  // Not to be used by end users
+  ChunkedAssetImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ChunkedAssetImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ChunkedAsset,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ChunkedAsset,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ChunkedAssetPtr,
+  );
+
+  /// Append the next chunk of the asset
+  void append({required List<int> chunk}) => RustLib.instance.api
+      .crateApiInputChunkedAssetAppend(that: this, chunk: chunk);
+
+  bool isEmpty() =>
+      RustLib.instance.api.crateApiInputChunkedAssetIsEmpty(that: this);
+
+  /// Total number of bytes received so far
+  BigInt len() => RustLib.instance.api.crateApiInputChunkedAssetLen(that: this);
+}
@@ -3,7 +3,30 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/actions.dart';
 import 'api/c2pa_reader.dart';
+import 'api/certificate.dart';
+import 'api/consistency.dart';
+import 'api/digital_source_type.dart';
+import 'api/distinguished_name.dart';
+import 'api/error.dart';
+import 'api/evidence.dart';
+import 'api/exif_metadata.dart';
+import 'api/generation_parameters.dart';
+import 'api/input.dart';
+import 'api/iptc.dart';
+import 'api/maker_note.dart';
+import 'api/manifest_thumbnails.dart';
+import 'api/mime.dart';
+import 'api/model_identity.dart';
+import 'api/revocation.dart';
+import 'api/scoring.dart';
+import 'api/signatures.dart';
+import 'api/thumbnail_comparison.dart';
+import 'api/timestamp.dart';
+import 'api/trust.dart';
+import 'api/validation_status.dart';
+import 'api/xmp.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -18,200 +41,1789 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ChunkedAssetPtr => wire
+      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAssetPtr;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  ChunkedAsset
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  );
+
+  @protected
+  ChunkedAsset
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  );
+
+  @protected
+  ChunkedAsset
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  );
+
+  @protected
+  Map<String, ManifestThumbnails>
+  dco_decode_Map_String_manifest_thumbnails_None(dynamic raw);
+
+  @protected
+  ChunkedAsset
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  ActionChange dco_decode_action_change(dynamic raw);
+
+  @protected
+  ActionParameter dco_decode_action_parameter(dynamic raw);
+
+  @protected
+  AiInfo dco_decode_ai_info(dynamic raw);
+
+  @protected
+  AiLabel dco_decode_ai_label(dynamic raw);
+
+  @protected
+  AiSignatureSummary dco_decode_ai_signature_summary(dynamic raw);
+
+  @protected
+  AnalysisError dco_decode_analysis_error(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AiInfo dco_decode_box_autoadd_ai_info(dynamic raw);
+
+  @protected
+  AnalysisError dco_decode_box_autoadd_analysis_error(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CertificateInfo dco_decode_box_autoadd_certificate_info(dynamic raw);
+
+  @protected
+  DigitalSourceType dco_decode_box_autoadd_digital_source_type(dynamic raw);
+
+  @protected
+  DistinguishedName dco_decode_box_autoadd_distinguished_name(dynamic raw);
+
+  @protected
+  ExifInfo dco_decode_box_autoadd_exif_info(dynamic raw);
+
+  @protected
+  ExifThumbnail dco_decode_box_autoadd_exif_thumbnail(dynamic raw);
+
+  @protected
+  ExposureInfo dco_decode_box_autoadd_exposure_info(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  GenerationParameters dco_decode_box_autoadd_generation_parameters(
+    dynamic raw,
+  );
+
+  @protected
+  GpsInfo dco_decode_box_autoadd_gps_info(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ImageRegion dco_decode_box_autoadd_image_region(dynamic raw);
+
+  @protected
+  IptcInfo dco_decode_box_autoadd_iptc_info(dynamic raw);
+
+  @protected
+  LensInfo dco_decode_box_autoadd_lens_info(dynamic raw);
+
+  @protected
+  MakerNote dco_decode_box_autoadd_maker_note(dynamic raw);
+
+  @protected
+  ManifestThumbnail dco_decode_box_autoadd_manifest_thumbnail(dynamic raw);
+
+  @protected
+  MimeCheck dco_decode_box_autoadd_mime_check(dynamic raw);
+
+  @protected
+  ModelIdentity dco_decode_box_autoadd_model_identity(dynamic raw);
+
+  @protected
+  ProvenanceGraph dco_decode_box_autoadd_provenance_graph(dynamic raw);
+
+  @protected
+  RevocationSource dco_decode_box_autoadd_revocation_source(dynamic raw);
+
+  @protected
+  ScoringWeights dco_decode_box_autoadd_scoring_weights(dynamic raw);
+
+  @protected
+  SignerInfo dco_decode_box_autoadd_signer_info(dynamic raw);
+
+  @protected
+  SoftwareAgentInfo dco_decode_box_autoadd_software_agent_info(dynamic raw);
+
+  @protected
+  ThumbnailComparison dco_decode_box_autoadd_thumbnail_comparison(dynamic raw);
+
+  @protected
+  TimestampInfo dco_decode_box_autoadd_timestamp_info(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  XmpInfo dco_decode_box_autoadd_xmp_info(dynamic raw);
+
+  @protected
+  C2paAnalysisResult dco_decode_c_2_pa_analysis_result(dynamic raw);
+
+  @protected
+  C2paThumbnails dco_decode_c_2_pa_thumbnails(dynamic raw);
+
+  @protected
+  CertificateInfo dco_decode_certificate_info(dynamic raw);
+
+  @protected
+  ConsistencyFinding dco_decode_consistency_finding(dynamic raw);
+
+  @protected
+  ContentAction dco_decode_content_action(dynamic raw);
+
+  @protected
+  DigitalSourceType dco_decode_digital_source_type(dynamic raw);
+
+  @protected
+  DistinguishedName dco_decode_distinguished_name(dynamic raw);
+
+  @protected
+  DnAttribute dco_decode_dn_attribute(dynamic raw);
+
+  @protected
+  Evidence dco_decode_evidence(dynamic raw);
+
+  @protected
+  EvidenceDetector dco_decode_evidence_detector(dynamic raw);
+
+  @protected
+  EvidenceKind dco_decode_evidence_kind(dynamic raw);
+
+  @protected
+  EvidenceStrength dco_decode_evidence_strength(dynamic raw);
+
+  @protected
+  ExifInfo dco_decode_exif_info(dynamic raw);
+
+  @protected
+  ExifTag dco_decode_exif_tag(dynamic raw);
+
+  @protected
+  ExifThumbnail dco_decode_exif_thumbnail(dynamic raw);
+
+  @protected
+  ExposureInfo dco_decode_exposure_info(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FindingSeverity dco_decode_finding_severity(dynamic raw);
+
+  @protected
+  FindingValue dco_decode_finding_value(dynamic raw);
+
+  @protected
+  GenerationParameters dco_decode_generation_parameters(dynamic raw);
+
+  @protected
+  GenerationSetting dco_decode_generation_setting(dynamic raw);
+
+  @protected
+  GenerationTool dco_decode_generation_tool(dynamic raw);
+
+  @protected
+  GpsInfo dco_decode_gps_info(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  ImageRegion dco_decode_image_region(dynamic raw);
+
+  @protected
+  InconsistencyKind dco_decode_inconsistency_kind(dynamic raw);
+
+  @protected
+  IngredientEdge dco_decode_ingredient_edge(dynamic raw);
+
+  @protected
+  IngredientRelationship dco_decode_ingredient_relationship(dynamic raw);
+
+  @protected
+  IngredientThumbnail dco_decode_ingredient_thumbnail(dynamic raw);
+
+  @protected
+  IptcInfo dco_decode_iptc_info(dynamic raw);
+
+  @protected
+  LensInfo dco_decode_lens_info(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ActionChange> dco_decode_list_action_change(dynamic raw);
+
+  @protected
+  List<ActionParameter> dco_decode_list_action_parameter(dynamic raw);
+
+  @protected
+  List<CertificateInfo> dco_decode_list_certificate_info(dynamic raw);
+
+  @protected
+  List<ConsistencyFinding> dco_decode_list_consistency_finding(dynamic raw);
+
+  @protected
+  List<ContentAction> dco_decode_list_content_action(dynamic raw);
+
+  @protected
+  List<DnAttribute> dco_decode_list_dn_attribute(dynamic raw);
+
+  @protected
+  List<Evidence> dco_decode_list_evidence(dynamic raw);
+
+  @protected
+  List<ExifTag> dco_decode_list_exif_tag(dynamic raw);
+
+  @protected
+  List<FindingValue> dco_decode_list_finding_value(dynamic raw);
+
+  @protected
+  List<GenerationSetting> dco_decode_list_generation_setting(dynamic raw);
+
+  @protected
+  List<IngredientEdge> dco_decode_list_ingredient_edge(dynamic raw);
+
+  @protected
+  List<IngredientThumbnail> dco_decode_list_ingredient_thumbnail(dynamic raw);
+
+  @protected
+  List<LoraReference> dco_decode_list_lora_reference(dynamic raw);
+
+  @protected
+  List<MakerNoteTag> dco_decode_list_maker_note_tag(dynamic raw);
+
+  @protected
+  List<ManifestNode> dco_decode_list_manifest_node(dynamic raw);
+
+  @protected
+  List<ModelIdentity> dco_decode_list_model_identity(dynamic raw);
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<(String, ManifestThumbnails)>
+  dco_decode_list_record_string_manifest_thumbnails(dynamic raw);
+
+  @protected
+  List<RegionRange> dco_decode_list_region_range(dynamic raw);
+
+  @protected
+  List<ValidationStatusEntry> dco_decode_list_validation_status_entry(
+    dynamic raw,
+  );
+
+  @protected
+  LoraReference dco_decode_lora_reference(dynamic raw);
+
+  @protected
+  MakerNote dco_decode_maker_note(dynamic raw);
+
+  @protected
+  MakerNoteTag dco_decode_maker_note_tag(dynamic raw);
+
+  @protected
+  ManifestNode dco_decode_manifest_node(dynamic raw);
+
+  @protected
+  ManifestThumbnail dco_decode_manifest_thumbnail(dynamic raw);
+
+  @protected
+  ManifestThumbnails dco_decode_manifest_thumbnails(dynamic raw);
+
+  @protected
+  MimeCheck dco_decode_mime_check(dynamic raw);
+
+  @protected
+  ModelIdentity dco_decode_model_identity(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AiInfo? dco_decode_opt_box_autoadd_ai_info(dynamic raw);
+
+  @protected
+  AnalysisError? dco_decode_opt_box_autoadd_analysis_error(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CertificateInfo? dco_decode_opt_box_autoadd_certificate_info(dynamic raw);
+
+  @protected
+  DigitalSourceType? dco_decode_opt_box_autoadd_digital_source_type(
+    dynamic raw,
+  );
+
+  @protected
+  DistinguishedName? dco_decode_opt_box_autoadd_distinguished_name(dynamic raw);
+
+  @protected
+  ExifInfo? dco_decode_opt_box_autoadd_exif_info(dynamic raw);
+
+  @protected
+  ExifThumbnail? dco_decode_opt_box_autoadd_exif_thumbnail(dynamic raw);
+
+  @protected
+  ExposureInfo? dco_decode_opt_box_autoadd_exposure_info(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  GenerationParameters? dco_decode_opt_box_autoadd_generation_parameters(
+    dynamic raw,
+  );
+
+  @protected
+  GpsInfo? dco_decode_opt_box_autoadd_gps_info(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ImageRegion? dco_decode_opt_box_autoadd_image_region(dynamic raw);
+
+  @protected
+  IptcInfo? dco_decode_opt_box_autoadd_iptc_info(dynamic raw);
+
+  @protected
+  LensInfo? dco_decode_opt_box_autoadd_lens_info(dynamic raw);
+
+  @protected
+  MakerNote? dco_decode_opt_box_autoadd_maker_note(dynamic raw);
+
+  @protected
+  ManifestThumbnail? dco_decode_opt_box_autoadd_manifest_thumbnail(dynamic raw);
+
+  @protected
+  MimeCheck? dco_decode_opt_box_autoadd_mime_check(dynamic raw);
+
+  @protected
+  ModelIdentity? dco_decode_opt_box_autoadd_model_identity(dynamic raw);
+
+  @protected
+  ProvenanceGraph? dco_decode_opt_box_autoadd_provenance_graph(dynamic raw);
+
+  @protected
+  RevocationSource? dco_decode_opt_box_autoadd_revocation_source(dynamic raw);
+
+  @protected
+  SignerInfo? dco_decode_opt_box_autoadd_signer_info(dynamic raw);
+
+  @protected
+  SoftwareAgentInfo? dco_decode_opt_box_autoadd_software_agent_info(
+    dynamic raw,
+  );
+
+  @protected
+  ThumbnailComparison? dco_decode_opt_box_autoadd_thumbnail_comparison(
+    dynamic raw,
+  );
+
+  @protected
+  TimestampInfo? dco_decode_opt_box_autoadd_timestamp_info(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  XmpInfo? dco_decode_opt_box_autoadd_xmp_info(dynamic raw);
+
+  @protected
+  ProvenanceGraph dco_decode_provenance_graph(dynamic raw);
+
+  @protected
+  (String, ManifestThumbnails) dco_decode_record_string_manifest_thumbnails(
+    dynamic raw,
+  );
+
+  @protected
+  RegionRange dco_decode_region_range(dynamic raw);
+
+  @protected
+  RevocationInfo dco_decode_revocation_info(dynamic raw);
+
+  @protected
+  RevocationSource dco_decode_revocation_source(dynamic raw);
+
+  @protected
+  RevocationState dco_decode_revocation_state(dynamic raw);
+
+  @protected
+  ScoringWeights dco_decode_scoring_weights(dynamic raw);
+
+  @protected
+  SignerInfo dco_decode_signer_info(dynamic raw);
+
+  @protected
+  SoftwareAgentInfo dco_decode_software_agent_info(dynamic raw);
+
+  @protected
+  ThumbnailComparison dco_decode_thumbnail_comparison(dynamic raw);
+
+  @protected
+  TimestampInfo dco_decode_timestamp_info(dynamic raw);
+
+  @protected
+  TrustStoreSummary dco_decode_trust_store_summary(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  ValidationCategory dco_decode_validation_category(dynamic raw);
+
+  @protected
+  ValidationStatusCode dco_decode_validation_status_code(dynamic raw);
+
+  @protected
+  ValidationStatusEntry dco_decode_validation_status_entry(dynamic raw);
+
+  @protected
+  VerificationStatus dco_decode_verification_status(dynamic raw);
+
+  @protected
+  XmpInfo dco_decode_xmp_info(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  ChunkedAsset
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChunkedAsset
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChunkedAsset
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Map<String, ManifestThumbnails>
+  sse_decode_Map_String_manifest_thumbnails_None(SseDeserializer deserializer);
+
+  @protected
+  ChunkedAsset
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  ActionChange sse_decode_action_change(SseDeserializer deserializer);
+
+  @protected
+  ActionParameter sse_decode_action_parameter(SseDeserializer deserializer);
+
+  @protected
+  AiInfo sse_decode_ai_info(SseDeserializer deserializer);
+
+  @protected
+  AiLabel sse_decode_ai_label(SseDeserializer deserializer);
+
+  @protected
+  AiSignatureSummary sse_decode_ai_signature_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AnalysisError sse_decode_analysis_error(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AiInfo sse_decode_box_autoadd_ai_info(SseDeserializer deserializer);
+
+  @protected
+  AnalysisError sse_decode_box_autoadd_analysis_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CertificateInfo sse_decode_box_autoadd_certificate_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DigitalSourceType sse_decode_box_autoadd_digital_source_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistinguishedName sse_decode_box_autoadd_distinguished_name(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExifInfo sse_decode_box_autoadd_exif_info(SseDeserializer deserializer);
+
+  @protected
+  ExifThumbnail sse_decode_box_autoadd_exif_thumbnail(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExposureInfo sse_decode_box_autoadd_exposure_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GenerationParameters sse_decode_box_autoadd_generation_parameters(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GpsInfo sse_decode_box_autoadd_gps_info(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageRegion sse_decode_box_autoadd_image_region(SseDeserializer deserializer);
+
+  @protected
+  IptcInfo sse_decode_box_autoadd_iptc_info(SseDeserializer deserializer);
+
+  @protected
+  LensInfo sse_decode_box_autoadd_lens_info(SseDeserializer deserializer);
+
+  @protected
+  MakerNote sse_decode_box_autoadd_maker_note(SseDeserializer deserializer);
+
+  @protected
+  ManifestThumbnail sse_decode_box_autoadd_manifest_thumbnail(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MimeCheck sse_decode_box_autoadd_mime_check(SseDeserializer deserializer);
+
+  @protected
+  ModelIdentity sse_decode_box_autoadd_model_identity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProvenanceGraph sse_decode_box_autoadd_provenance_graph(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RevocationSource sse_decode_box_autoadd_revocation_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ScoringWeights sse_decode_box_autoadd_scoring_weights(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SignerInfo sse_decode_box_autoadd_signer_info(SseDeserializer deserializer);
+
+  @protected
+  SoftwareAgentInfo sse_decode_box_autoadd_software_agent_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailComparison sse_decode_box_autoadd_thumbnail_comparison(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimestampInfo sse_decode_box_autoadd_timestamp_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  XmpInfo sse_decode_box_autoadd_xmp_info(SseDeserializer deserializer);
+
+  @protected
+  C2paAnalysisResult sse_decode_c_2_pa_analysis_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  C2paThumbnails sse_decode_c_2_pa_thumbnails(SseDeserializer deserializer);
+
+  @protected
+  CertificateInfo sse_decode_certificate_info(SseDeserializer deserializer);
+
+  @protected
+  ConsistencyFinding sse_decode_consistency_finding(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ContentAction sse_decode_content_action(SseDeserializer deserializer);
+
+  @protected
+  DigitalSourceType sse_decode_digital_source_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistinguishedName sse_decode_distinguished_name(SseDeserializer deserializer);
+
+  @protected
+  DnAttribute sse_decode_dn_attribute(SseDeserializer deserializer);
+
+  @protected
+  Evidence sse_decode_evidence(SseDeserializer deserializer);
+
+  @protected
+  EvidenceDetector sse_decode_evidence_detector(SseDeserializer deserializer);
+
+  @protected
+  EvidenceKind sse_decode_evidence_kind(SseDeserializer deserializer);
+
+  @protected
+  EvidenceStrength sse_decode_evidence_strength(SseDeserializer deserializer);
+
+  @protected
+  ExifInfo sse_decode_exif_info(SseDeserializer deserializer);
+
+  @protected
+  ExifTag sse_decode_exif_tag(SseDeserializer deserializer);
+
+  @protected
+  ExifThumbnail sse_decode_exif_thumbnail(SseDeserializer deserializer);
+
+  @protected
+  ExposureInfo sse_decode_exposure_info(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FindingSeverity sse_decode_finding_severity(SseDeserializer deserializer);
+
+  @protected
+  FindingValue sse_decode_finding_value(SseDeserializer deserializer);
+
+  @protected
+  GenerationParameters sse_decode_generation_parameters(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GenerationSetting sse_decode_generation_setting(SseDeserializer deserializer);
+
+  @protected
+  GenerationTool sse_decode_generation_tool(SseDeserializer deserializer);
+
+  @protected
+  GpsInfo sse_decode_gps_info(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageRegion sse_decode_image_region(SseDeserializer deserializer);
+
+  @protected
+  InconsistencyKind sse_decode_inconsistency_kind(SseDeserializer deserializer);
+
+  @protected
+  IngredientEdge sse_decode_ingredient_edge(SseDeserializer deserializer);
+
+  @protected
+  IngredientRelationship sse_decode_ingredient_relationship(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IngredientThumbnail sse_decode_ingredient_thumbnail(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IptcInfo sse_decode_iptc_info(SseDeserializer deserializer);
+
+  @protected
+  LensInfo sse_decode_lens_info(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ActionChange> sse_decode_list_action_change(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ActionParameter> sse_decode_list_action_parameter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CertificateInfo> sse_decode_list_certificate_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConsistencyFinding> sse_decode_list_consistency_finding(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ContentAction> sse_decode_list_content_action(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DnAttribute> sse_decode_list_dn_attribute(SseDeserializer deserializer);
+
+  @protected
+  List<Evidence> sse_decode_list_evidence(SseDeserializer deserializer);
+
+  @protected
+  List<ExifTag> sse_decode_list_exif_tag(SseDeserializer deserializer);
+
+  @protected
+  List<FindingValue> sse_decode_list_finding_value(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<GenerationSetting> sse_decode_list_generation_setting(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<IngredientEdge> sse_decode_list_ingredient_edge(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<IngredientThumbnail> sse_decode_list_ingredient_thumbnail(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LoraReference> sse_decode_list_lora_reference(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MakerNoteTag> sse_decode_list_maker_note_tag(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ManifestNode> sse_decode_list_manifest_node(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModelIdentity> sse_decode_list_model_identity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, ManifestThumbnails)>
+  sse_decode_list_record_string_manifest_thumbnails(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RegionRange> sse_decode_list_region_range(SseDeserializer deserializer);
+
+  @protected
+  List<ValidationStatusEntry> sse_decode_list_validation_status_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoraReference sse_decode_lora_reference(SseDeserializer deserializer);
+
+  @protected
+  MakerNote sse_decode_maker_note(SseDeserializer deserializer);
+
+  @protected
+  MakerNoteTag sse_decode_maker_note_tag(SseDeserializer deserializer);
+
+  @protected
+  ManifestNode sse_decode_manifest_node(SseDeserializer deserializer);
+
+  @protected
+  ManifestThumbnail sse_decode_manifest_thumbnail(SseDeserializer deserializer);
+
+  @protected
+  ManifestThumbnails sse_decode_manifest_thumbnails(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MimeCheck sse_decode_mime_check(SseDeserializer deserializer);
+
+  @protected
+  ModelIdentity sse_decode_model_identity(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AiInfo? sse_decode_opt_box_autoadd_ai_info(SseDeserializer deserializer);
+
+  @protected
+  AnalysisError? sse_decode_opt_box_autoadd_analysis_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CertificateInfo? sse_decode_opt_box_autoadd_certificate_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DigitalSourceType? sse_decode_opt_box_autoadd_digital_source_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistinguishedName? sse_decode_opt_box_autoadd_distinguished_name(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExifInfo? sse_decode_opt_box_autoadd_exif_info(SseDeserializer deserializer);
+
+  @protected
+  ExifThumbnail? sse_decode_opt_box_autoadd_exif_thumbnail(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExposureInfo? sse_decode_opt_box_autoadd_exposure_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GenerationParameters? sse_decode_opt_box_autoadd_generation_parameters(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GpsInfo? sse_decode_opt_box_autoadd_gps_info(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageRegion? sse_decode_opt_box_autoadd_image_region(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IptcInfo? sse_decode_opt_box_autoadd_iptc_info(SseDeserializer deserializer);
+
+  @protected
+  LensInfo? sse_decode_opt_box_autoadd_lens_info(SseDeserializer deserializer);
+
+  @protected
+  MakerNote? sse_decode_opt_box_autoadd_maker_note(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ManifestThumbnail? sse_decode_opt_box_autoadd_manifest_thumbnail(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MimeCheck? sse_decode_opt_box_autoadd_mime_check(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModelIdentity? sse_decode_opt_box_autoadd_model_identity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProvenanceGraph? sse_decode_opt_box_autoadd_provenance_graph(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RevocationSource? sse_decode_opt_box_autoadd_revocation_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SignerInfo? sse_decode_opt_box_autoadd_signer_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SoftwareAgentInfo? sse_decode_opt_box_autoadd_software_agent_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailComparison? sse_decode_opt_box_autoadd_thumbnail_comparison(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimestampInfo? sse_decode_opt_box_autoadd_timestamp_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  XmpInfo? sse_decode_opt_box_autoadd_xmp_info(SseDeserializer deserializer);
+
+  @protected
+  ProvenanceGraph sse_decode_provenance_graph(SseDeserializer deserializer);
+
+  @protected
+  (String, ManifestThumbnails) sse_decode_record_string_manifest_thumbnails(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RegionRange sse_decode_region_range(SseDeserializer deserializer);
+
+  @protected
+  RevocationInfo sse_decode_revocation_info(SseDeserializer deserializer);
+
+  @protected
+  RevocationSource sse_decode_revocation_source(SseDeserializer deserializer);
+
+  @protected
+  RevocationState sse_decode_revocation_state(SseDeserializer deserializer);
+
+  @protected
+  ScoringWeights sse_decode_scoring_weights(SseDeserializer deserializer);
+
+  @protected
+  SignerInfo sse_decode_signer_info(SseDeserializer deserializer);
+
+  @protected
+  SoftwareAgentInfo sse_decode_software_agent_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailComparison sse_decode_thumbnail_comparison(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimestampInfo sse_decode_timestamp_info(SseDeserializer deserializer);
+
+  @protected
+  TrustStoreSummary sse_decode_trust_store_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  ValidationCategory sse_decode_validation_category(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ValidationStatusCode sse_decode_validation_status_code(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ValidationStatusEntry sse_decode_validation_status_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  VerificationStatus sse_decode_verification_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XmpInfo sse_decode_xmp_info(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_manifest_thumbnails_None(
+    Map<String, ManifestThumbnails> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ChunkedAsset self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_action_change(ActionChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_action_parameter(
+    ActionParameter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_ai_info(AiInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ai_label(AiLabel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ai_signature_summary(
+    AiSignatureSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_analysis_error(AnalysisError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ai_info(AiInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_analysis_error(
+    AnalysisError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_certificate_info(
+    CertificateInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_digital_source_type(
+    DigitalSourceType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_distinguished_name(
+    DistinguishedName self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_exif_info(
+    ExifInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_exif_thumbnail(
+    ExifThumbnail self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_exposure_info(
+    ExposureInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_generation_parameters(
+    GenerationParameters self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_gps_info(GpsInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_image_region(
+    ImageRegion self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_iptc_info(
+    IptcInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_lens_info(
+    LensInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_maker_note(
+    MakerNote self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_manifest_thumbnail(
+    ManifestThumbnail self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_mime_check(
+    MimeCheck self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_model_identity(
+    ModelIdentity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_provenance_graph(
+    ProvenanceGraph self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_revocation_source(
+    RevocationSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_scoring_weights(
+    ScoringWeights self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_signer_info(
+    SignerInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_software_agent_info(
+    SoftwareAgentInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_thumbnail_comparison(
+    ThumbnailComparison self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_timestamp_info(
+    TimestampInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_xmp_info(XmpInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_c_2_pa_analysis_result(
+    C2paAnalysisResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_c_2_pa_thumbnails(
+    C2paThumbnails self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_certificate_info(
+    CertificateInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_consistency_finding(
+    ConsistencyFinding self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_content_action(ContentAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_digital_source_type(
+    DigitalSourceType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_distinguished_name(
+    DistinguishedName self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dn_attribute(DnAttribute self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_evidence(Evidence self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_evidence_detector(
+    EvidenceDetector self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_evidence_kind(EvidenceKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_evidence_strength(
+    EvidenceStrength self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_exif_info(ExifInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_exif_tag(ExifTag self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_exif_thumbnail(ExifThumbnail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_exposure_info(ExposureInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_finding_severity(
+    FindingSeverity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_finding_value(FindingValue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_generation_parameters(
+    GenerationParameters self,
+    SseSerializer serializer,
+  );
+
   @protected
-  String dco_decode_String(dynamic raw);
+  void sse_encode_generation_setting(
+    GenerationSetting self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AiInfo dco_decode_ai_info(dynamic raw);
+  void sse_encode_generation_tool(
+    GenerationTool self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool dco_decode_bool(dynamic raw);
+  void sse_encode_gps_info(GpsInfo self, SseSerializer serializer);
 
   @protected
-  AiInfo dco_decode_box_autoadd_ai_info(dynamic raw);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  ExifInfo dco_decode_box_autoadd_exif_info(dynamic raw);
+  void sse_encode_image_region(ImageRegion self, SseSerializer serializer);
 
   @protected
-  SignerInfo dco_decode_box_autoadd_signer_info(dynamic raw);
+  void sse_encode_inconsistency_kind(
+    InconsistencyKind self,
+    SseSerializer serializer,
+  );
 
   @protected
-  C2paAnalysisResult dco_decode_c_2_pa_analysis_result(dynamic raw);
+  void sse_encode_ingredient_edge(
+    IngredientEdge self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ContentAction dco_decode_content_action(dynamic raw);
+  void sse_encode_ingredient_relationship(
+    IngredientRelationship self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ExifInfo dco_decode_exif_info(dynamic raw);
+  void sse_encode_ingredient_thumbnail(
+    IngredientThumbnail self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ContentAction> dco_decode_list_content_action(dynamic raw);
+  void sse_encode_iptc_info(IptcInfo self, SseSerializer serializer);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  void sse_encode_lens_info(LensInfo self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_list_action_change(
+    List<ActionChange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AiInfo? dco_decode_opt_box_autoadd_ai_info(dynamic raw);
+  void sse_encode_list_action_parameter(
+    List<ActionParameter> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ExifInfo? dco_decode_opt_box_autoadd_exif_info(dynamic raw);
+  void sse_encode_list_certificate_info(
+    List<CertificateInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SignerInfo? dco_decode_opt_box_autoadd_signer_info(dynamic raw);
+  void sse_encode_list_consistency_finding(
+    List<ConsistencyFinding> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SignerInfo dco_decode_signer_info(dynamic raw);
+  void sse_encode_list_content_action(
+    List<ContentAction> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_list_dn_attribute(
+    List<DnAttribute> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_list_evidence(List<Evidence> self, SseSerializer serializer);
 
   @protected
-  VerificationStatus dco_decode_verification_status(dynamic raw);
+  void sse_encode_list_exif_tag(List<ExifTag> self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_finding_value(
+    List<FindingValue> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AiInfo sse_decode_ai_info(SseDeserializer deserializer);
+  void sse_encode_list_generation_setting(
+    List<GenerationSetting> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_ingredient_edge(
+    List<IngredientEdge> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AiInfo sse_decode_box_autoadd_ai_info(SseDeserializer deserializer);
+  void sse_encode_list_ingredient_thumbnail(
+    List<IngredientThumbnail> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ExifInfo sse_decode_box_autoadd_exif_info(SseDeserializer deserializer);
+  void sse_encode_list_lora_reference(
+    List<LoraReference> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SignerInfo sse_decode_box_autoadd_signer_info(SseDeserializer deserializer);
+  void sse_encode_list_maker_note_tag(
+    List<MakerNoteTag> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  C2paAnalysisResult sse_decode_c_2_pa_analysis_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_manifest_node(
+    List<ManifestNode> self,
+    SseSerializer serializer,
   );
 
   @protected
-  ContentAction sse_decode_content_action(SseDeserializer deserializer);
+  void sse_encode_list_model_identity(
+    List<ModelIdentity> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ExifInfo sse_decode_exif_info(SseDeserializer deserializer);
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ContentAction> sse_decode_list_content_action(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  void sse_encode_list_record_string_manifest_thumbnails(
+    List<(String, ManifestThumbnails)> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_region_range(
+    List<RegionRange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_list_validation_status_entry(
+    List<ValidationStatusEntry> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AiInfo? sse_decode_opt_box_autoadd_ai_info(SseDeserializer deserializer);
+  void sse_encode_lora_reference(LoraReference self, SseSerializer serializer);
 
   @protected
-  ExifInfo? sse_decode_opt_box_autoadd_exif_info(SseDeserializer deserializer);
+  void sse_encode_maker_note(MakerNote self, SseSerializer serializer);
 
   @protected
-  SignerInfo? sse_decode_opt_box_autoadd_signer_info(
-    SseDeserializer deserializer,
+  void sse_encode_maker_note_tag(MakerNoteTag self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_manifest_node(ManifestNode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_manifest_thumbnail(
+    ManifestThumbnail self,
+    SseSerializer serializer,
   );
 
   @protected
-  SignerInfo sse_decode_signer_info(SseDeserializer deserializer);
+  void sse_encode_manifest_thumbnails(
+    ManifestThumbnails self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_mime_check(MimeCheck self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_model_identity(ModelIdentity self, SseSerializer serializer);
 
   @protected
-  VerificationStatus sse_decode_verification_status(
-    SseDeserializer deserializer,
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_ai_info(
+    AiInfo? self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_analysis_error(
+    AnalysisError? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_ai_info(AiInfo self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_certificate_info(
+    CertificateInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_digital_source_type(
+    DigitalSourceType? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_ai_info(AiInfo self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_distinguished_name(
+    DistinguishedName? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_exif_info(
-    ExifInfo self,
+  void sse_encode_opt_box_autoadd_exif_info(
+    ExifInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_signer_info(
-    SignerInfo self,
+  void sse_encode_opt_box_autoadd_exif_thumbnail(
+    ExifThumbnail? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_c_2_pa_analysis_result(
-    C2paAnalysisResult self,
+  void sse_encode_opt_box_autoadd_exposure_info(
+    ExposureInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_content_action(ContentAction self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_exif_info(ExifInfo self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_generation_parameters(
+    GenerationParameters? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_content_action(
-    List<ContentAction> self,
+  void sse_encode_opt_box_autoadd_gps_info(
+    GpsInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_image_region(
+    ImageRegion? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_iptc_info(
+    IptcInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_ai_info(
-    AiInfo? self,
+  void sse_encode_opt_box_autoadd_lens_info(
+    LensInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_exif_info(
-    ExifInfo? self,
+  void sse_encode_opt_box_autoadd_maker_note(
+    MakerNote? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_manifest_thumbnail(
+    ManifestThumbnail? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_mime_check(
+    MimeCheck? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_model_identity(
+    ModelIdentity? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_provenance_graph(
+    ProvenanceGraph? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_revocation_source(
+    RevocationSource? self,
     SseSerializer serializer,
   );
 
@@ -221,21 +1833,144 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_software_agent_info(
+    SoftwareAgentInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_thumbnail_comparison(
+    ThumbnailComparison? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_timestamp_info(
+    TimestampInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_xmp_info(
+    XmpInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_provenance_graph(
+    ProvenanceGraph self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_manifest_thumbnails(
+    (String, ManifestThumbnails) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_region_range(RegionRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_revocation_info(
+    RevocationInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_revocation_source(
+    RevocationSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_revocation_state(
+    RevocationState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_scoring_weights(
+    ScoringWeights self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_signer_info(SignerInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_software_agent_info(
+    SoftwareAgentInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thumbnail_comparison(
+    ThumbnailComparison self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_timestamp_info(TimestampInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_trust_store_summary(
+    TrustStoreSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_validation_category(
+    ValidationCategory self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_validation_status_code(
+    ValidationStatusCode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_validation_status_entry(
+    ValidationStatusEntry self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_verification_status(
     VerificationStatus self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_xmp_info(XmpInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 }
@@ -253,4 +1988,36 @@ class RustLibWire implements BaseWire {
   /// The symbols are looked up in [dynamicLibrary].
   RustLibWire(ffi.DynamicLibrary dynamicLibrary)
     : _lookup = dynamicLibrary.lookup;
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAssetPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_rust_lib_origin_lens_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset',
+      );
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAssetPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAssetPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_rust_lib_origin_lens_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset',
+      );
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAssetPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
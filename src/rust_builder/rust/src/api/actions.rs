@@ -0,0 +1,565 @@
+//! `c2pa.actions` and `c2pa.actions.v2` assertions.
+//!
+//! Actions are read from the assertion JSON rather than the SDK's typed
+//! `Actions`, which only exposes the v1 fields, so both schema versions,
+//! vendor parameters and templates come through.
+
+use c2pa::Manifest;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::digital_source_type::DigitalSourceType;
+
+/// Software that performed an action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoftwareAgentInfo {
+    pub name: String,
+    pub version: Option<String>,
+    /// URI of the agent's icon resource in the manifest
+    pub icon: Option<String>,
+}
+
+/// An action parameter not covered by a dedicated field, e.g. `com.adobe.acr`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionParameter {
+    pub key: String,
+    /// String values as written, anything else as compact JSON
+    pub value: String,
+}
+
+/// One range of a changed region
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RegionRange {
+    /// An area of the image; `x`/`y` are the shape's origin
+    Spatial {
+        shape: String,
+        unit: Option<String>,
+        x: Option<f64>,
+        y: Option<f64>,
+        width: Option<f64>,
+        height: Option<f64>,
+    },
+    /// A time span, as NPT or other time strings
+    Temporal {
+        start: Option<String>,
+        end: Option<String>,
+    },
+    /// A span of video frames
+    Frame {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
+    /// Any other range type, as compact JSON
+    Other { json: String },
+}
+
+/// A part of the asset an action changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionChange {
+    pub description: Option<String>,
+    pub regions: Vec<RegionRange>,
+}
+
+/// A single action in the content's edit history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentAction {
+    pub action: String,
+    /// Display name of the software agent
+    pub software_agent: Option<String>,
+    pub software_agent_info: Option<SoftwareAgentInfo>,
+    pub when: Option<String>,
+    pub description: Option<String>,
+    /// IPTC digital source type the action declares for its output
+    pub digital_source_type: Option<DigitalSourceType>,
+    /// Why the action was taken, e.g. "c2pa.PII.present"
+    pub reason: Option<String>,
+    /// JUMBF URIs or instance IDs of the ingredients the action used
+    pub ingredients: Vec<String>,
+    pub parameters: Vec<ActionParameter>,
+    pub changes: Vec<ActionChange>,
+    /// Actions performed as part of this one
+    pub related: Vec<ContentAction>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RawActions {
+    actions: Vec<Value>,
+    templates: Vec<Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RawAction {
+    action: String,
+    when: Option<String>,
+    software_agent: Option<Value>,
+    description: Option<String>,
+    digital_source_type: Option<String>,
+    reason: Option<String>,
+    parameters: Map<String, Value>,
+    changes: Vec<Value>,
+    /// v1: the changed parts as a single string
+    changed: Option<String>,
+    /// v1: the ingredient the action used
+    instance_id: Option<String>,
+    related: Vec<Value>,
+}
+
+/// Whether an assertion label is an actions assertion, in any version or instance
+pub(crate) fn is_actions_label(label: &str) -> bool {
+    let base = label.split("__").next().unwrap_or(label);
+    base == "c2pa.actions" || base == "c2pa.actions.v2"
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// URI of a hashed URI or resource reference
+fn uri_of(value: &Value) -> Option<String> {
+    value
+        .get("url")
+        .or_else(|| value.get("identifier"))
+        .and_then(Value::as_str)
+        .map(|s| s.to_string())
+}
+
+fn software_agent(value: &Value) -> Option<SoftwareAgentInfo> {
+    match value {
+        Value::String(name) => Some(SoftwareAgentInfo {
+            name: name.clone(),
+            version: None,
+            icon: None,
+        }),
+        Value::Object(info) => Some(SoftwareAgentInfo {
+            name: info.get("name")?.as_str()?.to_string(),
+            version: info.get("version").map(text),
+            icon: info.get("icon").and_then(uri_of),
+        }),
+        _ => None,
+    }
+}
+
+fn region_range(range: &Value) -> RegionRange {
+    let number = |v: &Value| v.as_f64();
+    match range.get("type").and_then(Value::as_str) {
+        Some("spatial") => {
+            let shape = &range["shape"];
+            RegionRange::Spatial {
+                shape: shape["type"].as_str().unwrap_or("rectangle").to_string(),
+                unit: shape["unit"].as_str().map(|s| s.to_string()),
+                x: number(&shape["origin"]["x"]),
+                y: number(&shape["origin"]["y"]),
+                width: number(&shape["width"]),
+                height: number(&shape["height"]),
+            }
+        }
+        Some("temporal") => RegionRange::Temporal {
+            start: range["time"].get("start").map(text),
+            end: range["time"].get("end").map(text),
+        },
+        Some("frame") => RegionRange::Frame {
+            start: range["frame"]["start"].as_i64(),
+            end: range["frame"]["end"].as_i64(),
+        },
+        _ => RegionRange::Other {
+            json: range.to_string(),
+        },
+    }
+}
+
+fn action_change(change: &Value) -> ActionChange {
+    let regions = change
+        .get("region")
+        .and_then(Value::as_array)
+        .map(|ranges| ranges.iter().map(region_range).collect())
+        .unwrap_or_default();
+    ActionChange {
+        description: change.get("description").map(text),
+        regions,
+    }
+}
+
+fn content_action(value: &Value) -> ContentAction {
+    // Fall back to just the action name if a field has an unexpected type
+    let raw: RawAction = serde_json::from_value(value.clone()).unwrap_or_else(|_| RawAction {
+        action: value["action"].as_str().unwrap_or_default().to_string(),
+        ..RawAction::default()
+    });
+    let mut parameters = raw.parameters;
+
+    let description = raw
+        .description
+        .or_else(|| parameters.remove("description").map(|d| text(&d)));
+    let mut ingredients: Vec<String> = raw.instance_id.into_iter().collect();
+    if let Some(list) = parameters.remove("ingredients") {
+        ingredients.extend(list.as_array().into_iter().flatten().filter_map(uri_of));
+    }
+    if let Some(single) = parameters.remove("ingredient") {
+        ingredients.extend(uri_of(&single));
+    }
+    let mut changes: Vec<ActionChange> = raw.changes.iter().map(action_change).collect();
+    if let Some(changed) = raw.changed {
+        changes.push(ActionChange {
+            description: Some(changed),
+            regions: vec![],
+        });
+    }
+    let software_agent_info = raw.software_agent.as_ref().and_then(software_agent);
+
+    ContentAction {
+        action: raw.action,
+        software_agent: software_agent_info.as_ref().map(|a| a.name.clone()),
+        software_agent_info,
+        when: raw.when,
+        description,
+        digital_source_type: raw
+            .digital_source_type
+            .as_deref()
+            .map(DigitalSourceType::from_uri),
+        reason: raw.reason,
+        ingredients,
+        parameters: parameters
+            .iter()
+            .map(|(key, value)| ActionParameter {
+                key: key.clone(),
+                value: text(value),
+            })
+            .collect(),
+        changes,
+        related: raw.related.iter().map(content_action).collect(),
+    }
+}
+
+/// Fill fields an action leaves out from the template for its action type,
+/// or the "*" template that applies to every action
+fn apply_templates(action: &mut ContentAction, templates: &[ContentAction]) {
+    let template = templates
+        .iter()
+        .find(|t| t.action == action.action)
+        .or_else(|| templates.iter().find(|t| t.action == "*"));
+    let Some(template) = template else {
+        return;
+    };
+    if action.software_agent_info.is_none() {
+        action.software_agent_info = template.software_agent_info.clone();
+        action.software_agent = template.software_agent.clone();
+    }
+    if action.description.is_none() {
+        action.description = template.description.clone();
+    }
+    if action.digital_source_type.is_none() {
+        action.digital_source_type = template.digital_source_type.clone();
+    }
+}
+
+/// Actions listed by the data of one actions assertion
+pub(crate) fn actions_from_assertion(data: &Value) -> Vec<ContentAction> {
+    let raw: RawActions = serde_json::from_value(data.clone()).unwrap_or_default();
+    let templates: Vec<ContentAction> = raw.templates.iter().map(content_action).collect();
+    raw.actions
+        .iter()
+        .map(|value| {
+            let mut action = content_action(value);
+            apply_templates(&mut action, &templates);
+            action
+        })
+        .collect()
+}
+
+/// Every action a manifest records, across all of its actions assertions
+pub(crate) fn parse_actions(manifest: &Manifest) -> Vec<ContentAction> {
+    manifest
+        .assertions()
+        .iter()
+        .filter(|a| is_actions_label(a.label()))
+        .filter_map(|a| a.value().ok())
+        .flat_map(actions_from_assertion)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parameter<'a>(action: &'a ContentAction, key: &str) -> Option<&'a str> {
+        action
+            .parameters
+            .iter()
+            .find(|p| p.key == key)
+            .map(|p| p.value.as_str())
+    }
+
+    #[test]
+    fn actions_labels_are_recognized() {
+        assert!(is_actions_label("c2pa.actions"));
+        assert!(is_actions_label("c2pa.actions.v2"));
+        assert!(is_actions_label("c2pa.actions.v2__2"));
+        assert!(!is_actions_label("c2pa.actions.v3"));
+        assert!(!is_actions_label("c2pa.action"));
+        assert!(!is_actions_label("com.example.c2pa.actions"));
+    }
+
+    #[test]
+    fn v1_actions_are_read() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [{
+                "action": "c2pa.color_adjustments",
+                "when": "2024-03-02T13:11:07Z",
+                "softwareAgent": "Photo Editor 5.1",
+                "changed": "sky",
+                "instanceId": "xmp.iid:1234",
+                "parameters": {"name": "brightness", "value": 12}
+            }]
+        }));
+        assert_eq!(actions.len(), 1);
+        let action = &actions[0];
+        assert_eq!(action.action, "c2pa.color_adjustments");
+        assert_eq!(action.when.as_deref(), Some("2024-03-02T13:11:07Z"));
+        assert_eq!(action.software_agent.as_deref(), Some("Photo Editor 5.1"));
+        let agent = action.software_agent_info.as_ref().unwrap();
+        assert_eq!(agent.version, None);
+        assert_eq!(action.ingredients, ["xmp.iid:1234"]);
+        assert_eq!(action.changes.len(), 1);
+        assert_eq!(action.changes[0].description.as_deref(), Some("sky"));
+        assert!(action.changes[0].regions.is_empty());
+        assert_eq!(parameter(action, "name"), Some("brightness"));
+        assert_eq!(parameter(action, "value"), Some("12"));
+    }
+
+    #[test]
+    fn v2_parameters_fill_their_fields() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [{
+                "action": "c2pa.placed",
+                "reason": "c2pa.PII.present",
+                "digitalSourceType": "http://cv.iptc.org/newscodes/digitalsourcetype/compositeWithTrainedAlgorithmicMedia",
+                "parameters": {
+                    "description": "Generative fill",
+                    "ingredients": [
+                        {"url": "self#jumbf=c2pa.assertions/c2pa.ingredient.v3", "hash": "AAAA"},
+                        {"identifier": "ingredient-2"},
+                        {"hash": "no uri"}
+                    ],
+                    "ingredient": {"url": "self#jumbf=c2pa.assertions/c2pa.ingredient__1"},
+                    "com.adobe.acr": {"exposure": 0.5}
+                }
+            }]
+        }));
+        let action = &actions[0];
+        assert_eq!(action.description.as_deref(), Some("Generative fill"));
+        assert_eq!(action.reason.as_deref(), Some("c2pa.PII.present"));
+        assert_eq!(
+            action.digital_source_type,
+            Some(DigitalSourceType::CompositeWithTrainedAlgorithmicMedia)
+        );
+        assert_eq!(
+            action.ingredients,
+            [
+                "self#jumbf=c2pa.assertions/c2pa.ingredient.v3",
+                "ingredient-2",
+                "self#jumbf=c2pa.assertions/c2pa.ingredient__1",
+            ]
+        );
+        // Only parameters without a dedicated field are listed
+        assert_eq!(action.parameters.len(), 1);
+        assert_eq!(
+            parameter(action, "com.adobe.acr"),
+            Some(r#"{"exposure":0.5}"#)
+        );
+    }
+
+    #[test]
+    fn explicit_description_wins_over_parameter() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [{
+                "action": "c2pa.edited",
+                "description": "Cropped",
+                "parameters": {"description": "Resized"}
+            }]
+        }));
+        assert_eq!(actions[0].description.as_deref(), Some("Cropped"));
+        // The shadowed parameter is still listed rather than lost
+        assert_eq!(parameter(&actions[0], "description"), Some("Resized"));
+    }
+
+    #[test]
+    fn software_agent_claim_generator_info_is_read() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [
+                {
+                    "action": "c2pa.created",
+                    "softwareAgent": {
+                        "name": "Adobe Firefly",
+                        "version": "3.0",
+                        "icon": {"format": "image/svg+xml", "identifier": "firefly.svg"},
+                        "operating_system": "cloud"
+                    }
+                },
+                {
+                    "action": "c2pa.edited",
+                    "softwareAgent": {
+                        "name": "Editor",
+                        "version": 2,
+                        "icon": {"url": "self#jumbf=c2pa.assertions/c2pa.icon", "hash": "AAAA"}
+                    }
+                },
+                {"action": "c2pa.edited", "softwareAgent": {"version": "1.0"}},
+                {"action": "c2pa.edited", "softwareAgent": 7}
+            ]
+        }));
+        let agent = actions[0].software_agent_info.as_ref().unwrap();
+        assert_eq!(agent.name, "Adobe Firefly");
+        assert_eq!(agent.version.as_deref(), Some("3.0"));
+        assert_eq!(agent.icon.as_deref(), Some("firefly.svg"));
+        assert_eq!(actions[0].software_agent.as_deref(), Some("Adobe Firefly"));
+
+        let agent = actions[1].software_agent_info.as_ref().unwrap();
+        assert_eq!(agent.version.as_deref(), Some("2"));
+        assert_eq!(
+            agent.icon.as_deref(),
+            Some("self#jumbf=c2pa.assertions/c2pa.icon")
+        );
+
+        // Agents without a name are dropped
+        assert!(actions[2].software_agent_info.is_none());
+        assert!(actions[2].software_agent.is_none());
+        assert!(actions[3].software_agent_info.is_none());
+    }
+
+    #[test]
+    fn changes_list_their_regions() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [{
+                "action": "c2pa.edited",
+                "changes": [
+                    {
+                        "description": "Removed a person",
+                        "region": [
+                            {
+                                "type": "spatial",
+                                "shape": {
+                                    "type": "rectangle",
+                                    "unit": "pixel",
+                                    "origin": {"x": 10, "y": 20.5},
+                                    "width": 100,
+                                    "height": 50
+                                }
+                            },
+                            {"type": "temporal", "time": {"start": "0", "end": "5.5"}},
+                            {"type": "frame", "frame": {"start": 10, "end": 20}},
+                            {"type": "textual", "text": {"selectors": []}}
+                        ]
+                    },
+                    {"description": "Adjusted colors"}
+                ]
+            }]
+        }));
+        let changes = &actions[0].changes;
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].description.as_deref(), Some("Removed a person"));
+        let regions = &changes[0].regions;
+        assert_eq!(regions.len(), 4);
+        assert!(matches!(
+            &regions[0],
+            RegionRange::Spatial { shape, unit: Some(unit), x: Some(x), y: Some(y), width: Some(w), height: Some(h) }
+                if shape == "rectangle" && unit == "pixel" && *x == 10.0 && *y == 20.5 && *w == 100.0 && *h == 50.0
+        ));
+        assert!(matches!(
+            &regions[1],
+            RegionRange::Temporal { start: Some(start), end: Some(end) } if start == "0" && end == "5.5"
+        ));
+        assert!(matches!(
+            regions[2],
+            RegionRange::Frame {
+                start: Some(10),
+                end: Some(20)
+            }
+        ));
+        assert!(matches!(
+            &regions[3],
+            RegionRange::Other { json } if json.contains("textual")
+        ));
+        assert!(changes[1].regions.is_empty());
+    }
+
+    #[test]
+    fn related_actions_are_nested() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [{
+                "action": "c2pa.edited",
+                "related": [
+                    {"action": "c2pa.cropped", "softwareAgent": "Editor"},
+                    {"action": "c2pa.resized", "related": [{"action": "c2pa.converted"}]}
+                ]
+            }]
+        }));
+        let related = &actions[0].related;
+        assert_eq!(related.len(), 2);
+        assert_eq!(related[0].action, "c2pa.cropped");
+        assert_eq!(related[0].software_agent.as_deref(), Some("Editor"));
+        assert_eq!(related[1].related[0].action, "c2pa.converted");
+    }
+
+    #[test]
+    fn templates_fill_missing_fields() {
+        let actions = actions_from_assertion(&json!({
+            "templates": [
+                {
+                    "action": "c2pa.created",
+                    "softwareAgent": "Generator",
+                    "description": "Generated from a prompt",
+                    "digitalSourceType": "trainedAlgorithmicMedia"
+                },
+                {"action": "*", "softwareAgent": {"name": "Fallback", "version": "1"}}
+            ],
+            "actions": [
+                {"action": "c2pa.created"},
+                {"action": "c2pa.created", "softwareAgent": "Own", "description": "Own words"},
+                {"action": "c2pa.edited"}
+            ]
+        }));
+        assert_eq!(actions[0].software_agent.as_deref(), Some("Generator"));
+        assert_eq!(
+            actions[0].description.as_deref(),
+            Some("Generated from a prompt")
+        );
+        assert_eq!(
+            actions[0].digital_source_type,
+            Some(DigitalSourceType::TrainedAlgorithmicMedia)
+        );
+
+        // The action's own fields are kept
+        assert_eq!(actions[1].software_agent.as_deref(), Some("Own"));
+        assert_eq!(actions[1].description.as_deref(), Some("Own words"));
+        assert_eq!(
+            actions[1].digital_source_type,
+            Some(DigitalSourceType::TrainedAlgorithmicMedia)
+        );
+
+        // Other actions fall back to the "*" template
+        assert_eq!(actions[2].software_agent.as_deref(), Some("Fallback"));
+        let agent = actions[2].software_agent_info.as_ref().unwrap();
+        assert_eq!(agent.version.as_deref(), Some("1"));
+        assert_eq!(actions[2].description, None);
+    }
+
+    #[test]
+    fn mistyped_fields_keep_the_action_name() {
+        let actions = actions_from_assertion(&json!({
+            "actions": [{"action": "c2pa.edited", "when": 5, "softwareAgent": "Editor"}]
+        }));
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action, "c2pa.edited");
+        assert!(actions[0].when.is_none());
+        assert!(actions[0].software_agent.is_none());
+
+        assert!(actions_from_assertion(&json!({"actions": "none"})).is_empty());
+        assert!(actions_from_assertion(&json!({})).is_empty());
+    }
+}
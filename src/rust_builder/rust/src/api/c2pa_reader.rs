@@ -1,108 +1,94 @@
-use c2pa::{Reader, assertions::{Actions, SoftwareAgent}};
+use c2pa::{Manifest, Reader, Relationship, ValidationStatus};
 use flutter_rust_bridge::frb;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::fs::File;
-use std::io::{BufReader, Seek, SeekFrom};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{BufReader, Read, Seek};
 use std::path::Path;
 use exif::{In, Tag};
 
-// Helper function to convert SoftwareAgent to String
-fn software_agent_to_string(agent: &SoftwareAgent) -> String {
-    match agent {
-        SoftwareAgent::String(s) => s.clone(),
-        SoftwareAgent::ClaimGeneratorInfo(info) => info.name.clone(),
-    }
-}
-
-// Known AI generator identifiers - comprehensive list
-const AI_GENERATORS: &[&str] = &[
-    // Image generators
-    "midjourney",
-    "dall-e",
-    "dalle",
-    "dall·e",
-    "stable diffusion",
-    "stability.ai",
-    "stability ai",
-    "firefly",
-    "adobe firefly",
-    "imagen",
-    "openai",
-    "runway",
-    "runwayml",
-    "leonardo.ai",
-    "leonardo ai",
-    "bing image creator",
-    "bing image",
-    "copilot",
-    "microsoft designer",
-    "nightcafe",
-    "artbreeder",
-    "jasper art",
-    "dreamstudio",
-    "craiyon",
-    "deep dream",
-    "starryai",
-    "wombo",
-    "pixlr",
-    "canva ai",
-    "fotor ai",
-    "ideogram",
-    "bluewillow",
-    "playground ai",
-    "lexica",
-    "invoke ai",
-    "automatic1111",
-    "comfyui",
-    "fooocus",
-    // Video generators
-    "sora",
-    "pika labs",
-    "pika",
-    "gen-2",
-    "gen-3",
-    "kaiber",
-    "synthesia",
-    "heygen",
-    "d-id",
-    "luma ai",
-    "luma dream machine",
-    // Audio generators
-    "elevenlabs",
-    "murf",
-    "resemble ai",
-    "descript",
-    // General AI/ML indicators
-    "generative ai",
-    "ai generated",
-    "ai-generated",
-    "machine learning",
-    "neural network",
-    "diffusion model",
-    "text-to-image",
-    "text to image",
-    "t2i",
-    "img2img",
-    // Google AI
-    "google ai",
-    "gemini",
-    "bard",
-    "vertex ai",
-    "synthid",
-];
+use super::actions::{parse_actions, ContentAction};
+use super::certificate::{parse_certificate_chain, CertificateInfo};
+use super::consistency::{check_consistency, ConsistencyFinding};
+use super::container::image_dimensions;
+use super::digital_source_type::DigitalSourceType;
+use super::distinguished_name::parse_distinguished_name;
+use super::error::AnalysisError;
+use super::evidence::{
+    contains_word, manifest_fields, Evidence, EvidenceDetector, EvidenceKind, EvidenceStrength,
+    ManifestField,
+};
+use super::exif_metadata::{
+    all_tags, exif_text, exposure_info, gps_info, image_size, lens_info, maker_note, thumbnail,
+    ExifTag, ExifThumbnail, ExposureInfo, GpsInfo, LensInfo,
+};
+use super::generation_parameters::{
+    generation_evidence, generation_parameters, GenerationParameters,
+};
+use super::input::{AssetInput, BytesInput, ChunkedAsset, FileInput, MappedFileInput, OpenedAsset};
+use super::iptc::{read_iptc, IptcInfo};
+use super::jumbf::{claim_signatures, extract_manifest_store, ClaimSignature};
+use super::maker_note::MakerNote;
+use super::mime::{check_mime_type, MimeCheck};
+use super::model_identity::{identify, manifest_identities, push_identity, ModelIdentity};
+use super::png::read_png_text;
+use super::revocation::{check_chain_revocation, RevocationState};
+use super::scoring::{score_evidence, scoring_weights, AiLabel};
+use super::signatures::{signature_database, MatchField};
+use super::thumbnail_comparison::{compare_thumbnail, ThumbnailComparison};
+use super::timestamp::{timestamp_from_cose, TimestampInfo};
+use super::trust::tsa_trust_anchors;
+use super::validation_status::{ValidationCategory, ValidationStatusCode};
+use super::xmp::{read_xmp, XmpInfo};
+
+// Words that mark an assertion label as describing AI generation, e.g.
+// "c2pa.ai_generative_info". Matched as whole words, so the
+// "c2pa.training-mining" opt-out assertion is not one of them.
+const AI_ASSERTION_LABEL_WORDS: &[&str] = &["ai", "genai", "generative"];
 
-// C2PA digital source types that indicate AI generation
-const AI_DIGITAL_SOURCE_TYPES: &[&str] = &[
-    "http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia",
-    "http://cv.iptc.org/newscodes/digitalsourcetype/algorithmicMedia",
-    "http://cv.iptc.org/newscodes/digitalsourcetype/compositeWithTrainedAlgorithmicMedia",
-    "trainedAlgorithmicMedia",
-    "algorithmicMedia",
-    "compositeWithTrainedAlgorithmicMedia",
-    "digitalArt",
+// Standard actions whose software agent or description may name the AI tool
+// that made the content
+const AI_CANDIDATE_ACTIONS: &[&str] = &[
+    "c2pa.created",
+    "c2pa.placed",
+    "c2pa.drawing",
+    "c2pa.unknown",
 ];
 
+// Words that mark a custom action label as AI work, e.g. "com.example.ai.fill".
+// Matched as whole words, so "c2pa.painted" is not one of them.
+const AI_ACTION_WORDS: &[&str] = &["ai", "generated"];
+
+/// AI or camera-capture evidence from a declared digital source type. Only a
+/// source type inside a C2PA manifest is signed; anyone can write one into
+/// XMP, so unsigned declarations are at most strong evidence.
+fn source_type_evidence(
+    source_type: &DigitalSourceType,
+    detector: EvidenceDetector,
+    field: String,
+    signed: bool,
+) -> Option<Evidence> {
+    let (kind, strength) = match source_type.ai_involvement() {
+        Some(involvement) => involvement,
+        None if source_type.is_capture() => (EvidenceKind::CameraCapture, EvidenceStrength::Strong),
+        None => return None,
+    };
+    let strength = if signed {
+        strength
+    } else {
+        strength.min(EvidenceStrength::Strong)
+    };
+    Some(Evidence {
+        detector,
+        kind,
+        field,
+        matched_text: source_type.uri(),
+        strength,
+        explanation: format!("The digital source type is {}", source_type.term()),
+        generator: None,
+    })
+}
+
 /// Verification status of a C2PA manifest
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum VerificationStatus {
@@ -110,6 +96,11 @@ pub enum VerificationStatus {
     SignatureInvalid,
     CertificateExpired,
     CertificateUntrusted,
+    CertificateRevoked,
+    /// The asset or one of its assertions no longer matches its signed hash
+    ContentModified,
+    /// Any other failure code reported by the validator
+    ValidationFailed { codes: Vec<String> },
     NoManifest,
     Error { message: String },
 }
@@ -121,28 +112,33 @@ pub struct SignerInfo {
     pub organization: Option<String>,
     pub issued_by: Option<String>,
     pub timestamp: Option<String>,
-}
-
-/// A single action in the content's edit history
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContentAction {
-    pub action: String,
-    pub software_agent: Option<String>,
-    pub when: Option<String>,
-    pub description: Option<String>,
+    /// Every certificate in the COSE signature's chain, leaf first
+    pub certificate_chain: Vec<CertificateInfo>,
+    /// RFC 3161 time-stamp token from the claim signature, if present
+    pub timestamp_token: Option<TimestampInfo>,
 }
 
 /// AI generation information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiInfo {
     pub is_ai_generated: bool,
+    /// Combined likelihood of AI involvement, 0–1, from the configured scoring weights
+    pub likelihood: f64,
+    pub label: AiLabel,
     pub generator_name: Option<String>,
+    /// Display name of the model, from `model`
     pub model_name: Option<String>,
-    pub detection_source: Option<String>, // "c2pa", "exif", or "both"
+    /// Vendor, product, model and version of the AI tool the evidence names
+    pub model: Option<ModelIdentity>,
+    /// Metadata the evidence came from: "c2pa", "exif", "png" or "xmp", joined
+    /// with " + " when there is more than one
+    pub detection_source: Option<String>,
+    /// Every match behind this verdict, strongest first
+    pub evidence: Vec<Evidence>,
 }
 
 /// EXIF metadata result
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ExifInfo {
     pub software: Option<String>,
     pub make: Option<String>,
@@ -152,778 +148,1127 @@ pub struct ExifInfo {
     pub user_comment: Option<String>,
     pub image_description: Option<String>,
     pub date_time_original: Option<String>,
+    /// Last modification, "YYYY-MM-DD HH:MM:SS"
+    pub date_time: Option<String>,
+    pub date_time_digitized: Option<String>,
+    /// UTC offsets of the three timestamps, e.g. "+01:00"
+    pub offset_time: Option<String>,
+    pub offset_time_original: Option<String>,
+    pub offset_time_digitized: Option<String>,
+    /// Fractions of a second of the three timestamps, e.g. "042"
+    pub sub_sec_time: Option<String>,
+    pub sub_sec_time_original: Option<String>,
+    pub sub_sec_time_digitized: Option<String>,
+    pub orientation: Option<u32>,
+    pub image_width: Option<u32>,
+    pub image_height: Option<u32>,
+    pub body_serial_number: Option<String>,
+    pub camera_owner_name: Option<String>,
+    pub image_unique_id: Option<String>,
+    pub gps: Option<GpsInfo>,
+    pub lens: Option<LensInfo>,
+    pub exposure: Option<ExposureInfo>,
+    pub thumbnail: Option<ExifThumbnail>,
+    pub maker_note: Option<MakerNote>,
+    /// Every field, including those above, as text
+    pub tags: Vec<ExifTag>,
     pub ai_detected: bool,
     pub ai_generator: Option<String>,
 }
 
+/// A single validation status code reported by the C2PA validator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationStatusEntry {
+    pub code: String,
+    pub kind: ValidationStatusCode,
+    pub category: ValidationCategory,
+    /// JUMBF URI of the element the code refers to, e.g. "self#jumbf=/c2pa/<label>/c2pa.assertions/c2pa.hash.data"
+    pub url: Option<String>,
+    pub explanation: Option<String>,
+}
+
+/// How an ingredient was used by the manifest that embeds it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IngredientRelationship {
+    ParentOf,
+    ComponentOf,
+    InputTo,
+}
+
+/// A single manifest in the provenance graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestNode {
+    pub label: String,
+    pub is_active: bool,
+    pub title: Option<String>,
+    pub format: Option<String>,
+    pub instance_id: Option<String>,
+    pub claim_generator: Option<String>,
+    pub signer: Option<SignerInfo>,
+    pub actions: Vec<ContentAction>,
+    pub validation_statuses: Vec<ValidationStatusEntry>,
+}
+
+/// An ingredient link from a manifest to the ingredient it consumed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngredientEdge {
+    pub from_label: String,
+    /// Label of the ingredient's own manifest, if the ingredient carried Content Credentials
+    pub to_label: Option<String>,
+    pub ingredient_title: String,
+    pub ingredient_format: Option<String>,
+    pub ingredient_instance_id: Option<String>,
+    pub relationship: IngredientRelationship,
+    /// Validation codes recorded for this ingredient when it was added
+    pub validation_statuses: Vec<ValidationStatusEntry>,
+}
+
+/// Every manifest in the store and the ingredient relationships between them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceGraph {
+    pub active_label: Option<String>,
+    pub nodes: Vec<ManifestNode>,
+    pub edges: Vec<IngredientEdge>,
+}
+
 /// The full C2PA analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct C2paAnalysisResult {
     pub status: VerificationStatus,
+    /// Why analysis failed, or `ManifestNotFound` when there was nothing to verify
+    pub error: Option<AnalysisError>,
+    pub validation_statuses: Vec<ValidationStatusEntry>,
     pub signer: Option<SignerInfo>,
     pub actions: Vec<ContentAction>,
     pub ai_info: Option<AiInfo>,
     pub exif_info: Option<ExifInfo>,
+    /// IPTC-IIM newsroom metadata: by-line, credit, source, caption and location
+    pub iptc_info: Option<IptcInfo>,
+    /// Provenance properties from the asset's XMP packet
+    pub xmp_info: Option<XmpInfo>,
+    /// Generation parameters from PNG text chunks
+    pub generation_parameters: Option<GenerationParameters>,
+    /// Every tool identified from software agents, claim generator info,
+    /// EXIF Software, XMP CreatorTool and generation parameters, AI or not
+    pub model_identities: Vec<ModelIdentity>,
     pub claim_generator: Option<String>,
     pub title: Option<String>,
     pub format: Option<String>,
     pub instance_id: Option<String>,
     pub raw_manifest_json: Option<String>,
+    pub provenance: Option<ProvenanceGraph>,
+    pub mime_check: Option<MimeCheck>,
+    /// The EXIF thumbnail compared with the image it previews
+    pub thumbnail_comparison: Option<ThumbnailComparison>,
+    /// Contradictions between EXIF, XMP, IPTC and the manifest
+    pub consistency: Vec<ConsistencyFinding>,
 }
 
 impl C2paAnalysisResult {
-    fn no_manifest() -> Self {
+    pub(crate) fn no_manifest() -> Self {
         C2paAnalysisResult {
             status: VerificationStatus::NoManifest,
+            error: None,
+            validation_statuses: vec![],
             signer: None,
             actions: vec![],
             ai_info: None,
             exif_info: None,
+            iptc_info: None,
+            xmp_info: None,
+            generation_parameters: None,
+            model_identities: vec![],
             claim_generator: None,
             title: None,
             format: None,
             instance_id: None,
             raw_manifest_json: None,
+            provenance: None,
+            mime_check: None,
+            thumbnail_comparison: None,
+            consistency: vec![],
         }
     }
 
-    fn no_manifest_with_exif(exif: ExifInfo) -> Self {
-        let ai_info = if exif.ai_detected {
-            Some(AiInfo {
-                is_ai_generated: true,
-                generator_name: exif.ai_generator.clone(),
-                model_name: None,
-                detection_source: Some("exif".to_string()),
-            })
-        } else {
-            None
-        };
-        
-        C2paAnalysisResult {
-            status: VerificationStatus::NoManifest,
-            signer: None,
-            actions: vec![],
-            ai_info,
-            exif_info: Some(exif),
-            claim_generator: None,
-            title: None,
-            format: None,
-            instance_id: None,
-            raw_manifest_json: None,
+    fn from_error(error: AnalysisError) -> Self {
+        if error == AnalysisError::ManifestNotFound {
+            return C2paAnalysisResult {
+                error: Some(error),
+                ..Self::no_manifest()
+            };
         }
-    }
-
-    fn error(message: String) -> Self {
         C2paAnalysisResult {
-            status: VerificationStatus::Error { message },
+            status: VerificationStatus::Error {
+                message: error.to_string(),
+            },
+            error: Some(error),
+            validation_statuses: vec![],
             signer: None,
             actions: vec![],
             ai_info: None,
             exif_info: None,
+            iptc_info: None,
+            xmp_info: None,
+            generation_parameters: None,
+            model_identities: vec![],
             claim_generator: None,
             title: None,
             format: None,
             instance_id: None,
             raw_manifest_json: None,
+            provenance: None,
+            mime_check: None,
+            thumbnail_comparison: None,
+            consistency: vec![],
         }
     }
 }
 
-/// Parse EXIF metadata from a file and detect AI generators
-fn parse_exif_from_file(file_path: &Path) -> Option<ExifInfo> {
-    let file = File::open(file_path).ok()?;
-    let mut bufreader = BufReader::new(&file);
+/// Parse EXIF metadata from any supported container and detect AI generators
+fn parse_exif<R: Read + Seek>(stream: &mut R) -> Option<ExifInfo> {
+    stream.rewind().ok()?;
+    let mut bufreader = BufReader::new(stream);
     let exifreader = exif::Reader::new();
     let exif = exifreader.read_from_container(&mut bufreader).ok()?;
-    
-    let get_field = |tag: Tag| -> Option<String> {
-        exif.get_field(tag, In::PRIMARY)
-            .map(|f| f.display_value().with_unit(&exif).to_string())
+
+    let get_field = |tag: Tag| exif_text(&exif, tag);
+    let (image_width, image_height) = image_size(&exif);
+    let mut info = ExifInfo {
+        software: get_field(Tag::Software),
+        make: get_field(Tag::Make),
+        model: get_field(Tag::Model),
+        artist: get_field(Tag::Artist),
+        copyright: get_field(Tag::Copyright),
+        user_comment: get_field(Tag::UserComment),
+        image_description: get_field(Tag::ImageDescription),
+        date_time_original: get_field(Tag::DateTimeOriginal),
+        date_time: get_field(Tag::DateTime),
+        date_time_digitized: get_field(Tag::DateTimeDigitized),
+        offset_time: get_field(Tag::OffsetTime),
+        offset_time_original: get_field(Tag::OffsetTimeOriginal),
+        offset_time_digitized: get_field(Tag::OffsetTimeDigitized),
+        sub_sec_time: get_field(Tag::SubSecTime),
+        sub_sec_time_original: get_field(Tag::SubSecTimeOriginal),
+        sub_sec_time_digitized: get_field(Tag::SubSecTimeDigitized),
+        orientation: exif
+            .get_field(Tag::Orientation, In::PRIMARY)
+            .and_then(|f| f.value.get_uint(0)),
+        image_width,
+        image_height,
+        body_serial_number: get_field(Tag::BodySerialNumber),
+        camera_owner_name: get_field(Tag::CameraOwnerName),
+        image_unique_id: get_field(Tag::ImageUniqueID),
+        gps: gps_info(&exif),
+        lens: lens_info(&exif),
+        exposure: exposure_info(&exif),
+        thumbnail: thumbnail(&exif),
+        maker_note: maker_note(&exif),
+        tags: all_tags(&exif),
+        ai_detected: false,
+        ai_generator: None,
     };
-    
-    let software = get_field(Tag::Software);
-    let make = get_field(Tag::Make);
-    let model = get_field(Tag::Model);
-    let artist = get_field(Tag::Artist);
-    let copyright = get_field(Tag::Copyright);
-    let user_comment = get_field(Tag::UserComment);
-    let image_description = get_field(Tag::ImageDescription);
-    let date_time_original = get_field(Tag::DateTimeOriginal);
-    
-    // Check all text fields for AI generator signatures
-    let all_text = [
-        &software,
-        &make,
-        &model,
-        &artist,
-        &user_comment,
-        &image_description,
-    ]
-    .iter()
-    .filter_map(|o| o.as_ref())
-    .map(|s| s.to_lowercase())
-    .collect::<Vec<_>>()
-    .join(" ");
-    
-    let mut ai_detected = false;
-    let mut ai_generator: Option<String> = None;
-    
-    for gen in AI_GENERATORS {
-        if all_text.contains(gen) {
-            ai_detected = true;
-            ai_generator = Some(gen.to_string());
-            break;
+    let evidence = exif_evidence(&info);
+    info.ai_detected = evidence.iter().any(|e| e.kind.is_ai());
+    info.ai_generator = evidence
+        .iter()
+        .filter(|e| e.kind.is_ai())
+        .find_map(|e| e.generator.clone());
+    Some(info)
+}
+
+/// AI evidence from metadata text fields. A generator named in the
+/// `producer` field, the one a tool writes its own name to, is strong
+/// evidence; elsewhere it is a weak mention. `describe` names a field for
+/// explanations, e.g. "The EXIF Software tag".
+fn text_field_evidence(
+    detector: EvidenceDetector,
+    fields: &[(&str, MatchField, &Option<String>)],
+    producer: MatchField,
+    describe: impl Fn(&str) -> String,
+) -> Vec<Evidence> {
+    let mut evidence = Vec::new();
+    let signatures = signature_database();
+    for &(field, scope, value) in fields {
+        let Some(value) = value else {
+            continue;
+        };
+        if let Some(found) = signatures.find_generator(value, scope) {
+            // A tool naming itself as the producer says more than a mention
+            let (strength, explanation, generator) = if scope == producer {
+                (
+                    EvidenceStrength::Strong,
+                    format!(
+                        "{} names {} (\"{}\")",
+                        describe(field),
+                        found.generator.describe(),
+                        found.matched
+                    ),
+                    value.clone(),
+                )
+            } else {
+                (
+                    EvidenceStrength::Weak,
+                    format!("{} mentions \"{}\"", describe(field), found.matched),
+                    found.matched,
+                )
+            };
+            evidence.push(Evidence {
+                detector,
+                kind: EvidenceKind::AiGeneration,
+                field: field.to_string(),
+                matched_text: value.clone(),
+                strength,
+                explanation,
+                generator: Some(generator),
+            });
         }
-    }
-    
-    // Check for specific EXIF patterns that indicate AI generation
-    if let Some(ref sw) = software {
-        let sw_lower = sw.to_lowercase();
-        // Check for known AI tools in Software field
-        if sw_lower.contains("midjourney") 
-            || sw_lower.contains("dall-e") 
-            || sw_lower.contains("stable diffusion")
-            || sw_lower.contains("firefly")
-            || sw_lower.contains("imagen")
-            || sw_lower.contains("novelai")
-            || sw_lower.contains("automatic1111")
-            || sw_lower.contains("comfyui")
-        {
-            ai_detected = true;
-            ai_generator = Some(sw.clone());
+        if let Some(phrase) = signatures.find_phrase(value, scope) {
+            evidence.push(Evidence {
+                detector,
+                kind: EvidenceKind::AiGeneration,
+                field: field.to_string(),
+                matched_text: value.clone(),
+                strength: phrase.strength,
+                explanation: format!(
+                    "{} contains \"{}\", typical of AI tool output",
+                    describe(field),
+                    phrase.text
+                ),
+                generator: None,
+            });
         }
     }
-    
-    // Check UserComment for AI indicators (common in some AI-generated images)
-    if let Some(ref comment) = user_comment {
-        let comment_lower = comment.to_lowercase();
-        if comment_lower.contains("generated by ai")
-            || comment_lower.contains("ai generated")
-            || comment_lower.contains("created with ai")
-            || comment_lower.contains("made with ai")
-            || comment_lower.contains("prompt:")
-            || comment_lower.contains("negative prompt:")
-            || comment_lower.contains("cfg scale")
-            || comment_lower.contains("sampling steps")
-            || comment_lower.contains("seed:")
-        {
-            ai_detected = true;
-            if ai_generator.is_none() {
-                ai_generator = Some("AI Generator (from metadata)".to_string());
-            }
+    evidence
+}
+
+/// AI and camera-capture evidence from EXIF text fields
+pub(crate) fn exif_evidence(info: &ExifInfo) -> Vec<Evidence> {
+    let text_fields = [
+        ("Software", MatchField::ExifSoftware, &info.software),
+        ("Make", MatchField::ExifText, &info.make),
+        ("Model", MatchField::ExifText, &info.model),
+        ("Artist", MatchField::ExifText, &info.artist),
+        ("UserComment", MatchField::ExifComment, &info.user_comment),
+        ("ImageDescription", MatchField::ExifText, &info.image_description),
+    ];
+    let mut evidence = text_field_evidence(
+        EvidenceDetector::Exif,
+        &text_fields,
+        MatchField::ExifSoftware,
+        |field| format!("The EXIF {} tag", field),
+    );
+
+    // A named camera body is weak evidence of a capture: EXIF is easy to copy
+    if let (Some(make), Some(model)) = (&info.make, &info.model) {
+        let named_ai = evidence.iter().any(|e| e.field == "Make" || e.field == "Model");
+        if !named_ai && !make.trim().is_empty() && !model.trim().is_empty() {
+            // Many vendors repeat the make in the model, e.g. "Canon" / "Canon EOS R5"
+            let camera = if model.to_lowercase().starts_with(&make.to_lowercase()) {
+                model.clone()
+            } else {
+                format!("{} {}", make, model)
+            };
+            evidence.push(Evidence {
+                detector: EvidenceDetector::Exif,
+                kind: EvidenceKind::CameraCapture,
+                field: "Make".to_string(),
+                explanation: format!("The EXIF Make and Model tags name the camera {}", camera),
+                matched_text: camera,
+                strength: EvidenceStrength::Weak,
+                generator: None,
+            });
         }
     }
-    
-    Some(ExifInfo {
-        software,
-        make,
-        model,
-        artist,
-        copyright,
-        user_comment,
-        image_description,
-        date_time_original,
-        ai_detected,
-        ai_generator,
-    })
+    evidence
 }
 
-/// Parse EXIF metadata from bytes
-fn parse_exif_from_bytes(data: &[u8]) -> Option<ExifInfo> {
-    let exifreader = exif::Reader::new();
-    let exif = exifreader.read_raw(data.to_vec()).ok()?;
-    
-    let get_field = |tag: Tag| -> Option<String> {
-        exif.get_field(tag, In::PRIMARY)
-            .map(|f| f.display_value().with_unit(&exif).to_string())
+/// AI and camera-capture evidence from XMP: the declared digital source
+/// type, the creator tool and the credit and creator lines
+pub(crate) fn xmp_evidence(info: &XmpInfo) -> Vec<Evidence> {
+    let mut evidence = Vec::new();
+    if let Some(source_type) = &info.digital_source_type {
+        evidence.extend(source_type_evidence(
+            source_type,
+            EvidenceDetector::Xmp,
+            "Iptc4xmpExt:DigitalSourceType".to_string(),
+            false,
+        ));
+    }
+    let creators = (!info.creators.is_empty()).then(|| info.creators.join(", "));
+    let text_fields = [
+        ("xmp:CreatorTool", MatchField::XmpCreatorTool, &info.creator_tool),
+        ("photoshop:Credit", MatchField::XmpText, &info.credit),
+        ("dc:creator", MatchField::XmpText, &creators),
+    ];
+    evidence.extend(text_field_evidence(
+        EvidenceDetector::Xmp,
+        &text_fields,
+        MatchField::XmpCreatorTool,
+        |field| format!("The XMP {} property", field),
+    ));
+    evidence
+}
+
+/// Fold XMP evidence and the creator tool it names into the result
+fn merge_xmp(result: &mut C2paAnalysisResult, xmp_info: Option<XmpInfo>) {
+    let Some(xmp_info) = xmp_info else {
+        return;
     };
-    
-    let software = get_field(Tag::Software);
-    let make = get_field(Tag::Make);
-    let model = get_field(Tag::Model);
-    let artist = get_field(Tag::Artist);
-    let copyright = get_field(Tag::Copyright);
-    let user_comment = get_field(Tag::UserComment);
-    let image_description = get_field(Tag::ImageDescription);
-    let date_time_original = get_field(Tag::DateTimeOriginal);
-    
-    // Check all text fields for AI generator signatures
-    let all_text = [
-        &software,
-        &make,
-        &model,
-        &artist,
-        &user_comment,
-        &image_description,
-    ]
-    .iter()
-    .filter_map(|o| o.as_ref())
-    .map(|s| s.to_lowercase())
-    .collect::<Vec<_>>()
-    .join(" ");
-    
-    let mut ai_detected = false;
-    let mut ai_generator: Option<String> = None;
-    
-    for gen in AI_GENERATORS {
-        if all_text.contains(gen) {
-            ai_detected = true;
-            ai_generator = Some(gen.to_string());
-            break;
+    if let Some(tool) = &xmp_info.creator_tool {
+        if let Some(identity) = identify(tool, None, "xmp:CreatorTool") {
+            push_identity(&mut result.model_identities, identity);
         }
     }
-    
-    if let Some(ref sw) = software {
-        let sw_lower = sw.to_lowercase();
-        if sw_lower.contains("midjourney") 
-            || sw_lower.contains("dall-e") 
-            || sw_lower.contains("stable diffusion")
-            || sw_lower.contains("firefly")
-            || sw_lower.contains("imagen")
-            || sw_lower.contains("novelai")
-            || sw_lower.contains("automatic1111")
-            || sw_lower.contains("comfyui")
-        {
-            ai_detected = true;
-            ai_generator = Some(sw.clone());
+    merge_evidence(result, xmp_evidence(&xmp_info));
+    result.xmp_info = Some(xmp_info);
+}
+
+/// Fold EXIF evidence into the result
+fn merge_exif(result: &mut C2paAnalysisResult, exif_info: Option<ExifInfo>) {
+    let Some(exif_info) = exif_info else {
+        return;
+    };
+    if let Some(software) = &exif_info.software {
+        if let Some(identity) = identify(software, None, "Software") {
+            push_identity(&mut result.model_identities, identity);
         }
     }
-    
-    if let Some(ref comment) = user_comment {
-        let comment_lower = comment.to_lowercase();
-        if comment_lower.contains("generated by ai")
-            || comment_lower.contains("ai generated")
-            || comment_lower.contains("created with ai")
-            || comment_lower.contains("made with ai")
-            || comment_lower.contains("prompt:")
-            || comment_lower.contains("negative prompt:")
-            || comment_lower.contains("cfg scale")
-            || comment_lower.contains("sampling steps")
-            || comment_lower.contains("seed:")
-        {
-            ai_detected = true;
-            if ai_generator.is_none() {
-                ai_generator = Some("AI Generator (from metadata)".to_string());
-            }
-        }
+    merge_evidence(result, exif_evidence(&exif_info));
+    result.exif_info = Some(exif_info);
+}
+
+/// Fold generation parameters, the tool and model they name and their
+/// evidence into the result
+fn merge_generation_parameters(
+    result: &mut C2paAnalysisResult,
+    params: Option<GenerationParameters>,
+) {
+    let Some(params) = params else {
+        return;
+    };
+    let field = params.source_chunks.first().cloned().unwrap_or_default();
+    let tool = identify(params.tool.name(), params.tool_version.as_deref(), &field);
+    let model = params
+        .model
+        .as_deref()
+        .and_then(|model| identify(model, None, &field));
+    for identity in tool.into_iter().chain(model) {
+        push_identity(&mut result.model_identities, identity);
     }
-    
-    Some(ExifInfo {
-        software,
-        make,
-        model,
-        artist,
-        copyright,
-        user_comment,
-        image_description,
-        date_time_original,
-        ai_detected,
-        ai_generator,
-    })
+    merge_evidence(result, vec![generation_evidence(&params)]);
+    result.generation_parameters = Some(params);
+}
+
+/// Add evidence to whatever the manifest produced and rebuild the verdict
+fn merge_evidence(result: &mut C2paAnalysisResult, more: Vec<Evidence>) {
+    if more.is_empty() {
+        return;
+    }
+    let mut evidence = result
+        .ai_info
+        .take()
+        .map(|info| info.evidence)
+        .unwrap_or_default();
+    evidence.extend(more);
+    result.ai_info = ai_info_from_evidence(evidence);
+}
+
+/// The single analysis pipeline every entry point runs: C2PA manifest,
+/// claim signatures, EXIF, IPTC, XMP and PNG generation parameters, all
+/// read from the same stream, then checked against each other
+fn analyze_input<I: AssetInput>(input: I) -> C2paAnalysisResult {
+    let mut asset = match OpenedAsset::open(input) {
+        Ok(asset) => asset,
+        Err(e) => return C2paAnalysisResult::from_error(e),
+    };
+    let mime_check = check_mime_type(&asset.claimed_mime_type, asset.detected_mime_type);
+    let mime_type = asset.mime_type();
+
+    let stream = &mut asset.stream;
+    let exif_info = parse_exif(stream);
+    let iptc_info = read_iptc(stream, &mime_type);
+    let xmp_info = read_xmp(stream, &mime_type);
+    let image_size = image_dimensions(stream, &mime_type);
+    let thumbnail_comparison = exif_info
+        .as_ref()
+        .and_then(|exif| exif.thumbnail.as_ref())
+        .and_then(|thumbnail| compare_thumbnail(stream, thumbnail));
+    let generation = if mime_type == "image/png" {
+        generation_parameters(&read_png_text(stream))
+    } else {
+        None
+    };
+
+    let mut result = match asset.manifest_reader() {
+        Ok(manifest_reader) => {
+            // Claim signatures are read from the raw store, found in the same stream
+            let signatures = read_claim_signatures(&mut asset.stream);
+            parse_manifest_reader(&manifest_reader, &signatures)
+        }
+        Err(e) => C2paAnalysisResult::from_error(e),
+    };
+    merge_exif(&mut result, exif_info);
+    result.iptc_info = iptc_info;
+    merge_xmp(&mut result, xmp_info);
+    merge_generation_parameters(&mut result, generation);
+    result.mime_check = Some(mime_check);
+    result.thumbnail_comparison = thumbnail_comparison;
+    result.consistency = check_consistency(&result, image_size);
+    result
 }
 
 /// Analyzes a file at the given path for C2PA metadata
 #[frb(sync)]
 pub fn analyze_c2pa_from_path(file_path: String) -> C2paAnalysisResult {
-    let path = Path::new(&file_path);
+    if !Path::new(&file_path).exists() {
+        return C2paAnalysisResult::from_error(AnalysisError::Io {
+            message: format!("File not found: {}", file_path),
+        });
+    }
+    analyze_input(FileInput::new(file_path))
+}
 
-    if !path.exists() {
-        return C2paAnalysisResult::error(format!("File not found: {}", file_path));
+/// Analyzes a file through a memory map instead of buffered reads, for large videos
+#[frb(sync)]
+pub fn analyze_c2pa_from_mapped_file(file_path: String) -> C2paAnalysisResult {
+    if !Path::new(&file_path).exists() {
+        return C2paAnalysisResult::from_error(AnalysisError::Io {
+            message: format!("File not found: {}", file_path),
+        });
     }
+    analyze_input(MappedFileInput::new(file_path))
+}
 
-    let file = match File::open(path) {
-        Ok(f) => f,
-        Err(e) => return C2paAnalysisResult::error(format!("Failed to open file: {}", e)),
-    };
+/// Analyzes raw bytes for C2PA metadata
+#[frb(sync)]
+pub fn analyze_c2pa_from_bytes(data: Vec<u8>, mime_type: String) -> C2paAnalysisResult {
+    analyze_input(BytesInput::new(data, mime_type))
+}
 
-    let reader = BufReader::new(file);
-
-    // Determine format from extension
-    let format = path
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .map(|ext| match ext.to_lowercase().as_str() {
-            "jpg" | "jpeg" => "image/jpeg",
-            "png" => "image/png",
-            "webp" => "image/webp",
-            "gif" => "image/gif",
-            "avif" => "image/avif",
-            "heic" | "heif" => "image/heif",
-            "tif" | "tiff" => "image/tiff",
-            "mp4" => "video/mp4",
-            "mov" => "video/quicktime",
-            _ => "application/octet-stream",
-        })
-        .unwrap_or("application/octet-stream");
+/// Analyzes an asset streamed from Dart in chunks
+#[frb(sync)]
+pub fn analyze_c2pa_from_chunks(asset: ChunkedAsset) -> C2paAnalysisResult {
+    analyze_input(asset)
+}
 
-    match Reader::from_stream(format, reader) {
-        Ok(manifest_reader) => {
-            let mut result = parse_manifest_reader(&manifest_reader);
-            // Also parse EXIF and merge AI detection
-            if let Some(exif_info) = parse_exif_from_file(path) {
-                // Merge EXIF AI detection with C2PA AI detection
-                if exif_info.ai_detected {
-                    if let Some(ref mut ai_info) = result.ai_info {
-                        // Already detected via C2PA, add EXIF as secondary source
-                        if ai_info.detection_source == Some("c2pa".to_string()) {
-                            ai_info.detection_source = Some("both".to_string());
-                        }
-                        if ai_info.generator_name.is_none() {
-                            ai_info.generator_name = exif_info.ai_generator.clone();
-                        }
-                    } else {
-                        // Not detected via C2PA, use EXIF detection
-                        result.ai_info = Some(AiInfo {
-                            is_ai_generated: true,
-                            generator_name: exif_info.ai_generator.clone(),
-                            model_name: None,
-                            detection_source: Some("exif".to_string()),
-                        });
-                    }
-                }
-                result.exif_info = Some(exif_info);
-            }
-            result
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Claims and their COSE_Sign1 signatures read from the asset's manifest store, by manifest label
+type ClaimSignatures = HashMap<String, ClaimSignature>;
+
+fn read_claim_signatures<R: Read + Seek>(stream: &mut R) -> ClaimSignatures {
+    extract_manifest_store(stream)
+        .map(|store| claim_signatures(&store).into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// Parse signer info from a manifest's signature
+fn parse_signer_info(manifest: &Manifest, signature: Option<&ClaimSignature>) -> Option<SignerInfo> {
+    manifest.signature_info().map(|sig| {
+        let issuer_dn = sig
+            .issuer
+            .as_ref()
+            .and_then(|issuer| parse_distinguished_name(issuer.clone()));
+
+        // Prefer O, falling back to OU for certificates without an organization
+        let organization = issuer_dn
+            .as_ref()
+            .and_then(|dn| dn.first("O").or_else(|| dn.first("OU")))
+            .map(|s| s.to_string());
+
+        // Extract common name for display; issuers that aren't DNs are shown as-is
+        let name = issuer_dn
+            .as_ref()
+            .and_then(|dn| dn.first("CN"))
+            .map(|s| s.to_string())
+            .or_else(|| sig.issuer.clone());
+
+        let mut certificate_chain = parse_certificate_chain(sig.cert_chain());
+        let timestamp_token = signature.and_then(|signature| {
+            timestamp_from_cose(
+                &signature.cose,
+                signature.claim.as_deref(),
+                certificate_chain.first(),
+                &tsa_trust_anchors(),
+            )
+        });
+
+        // Revocation is judged at the trusted signing time when there is one
+        let evaluation_time = timestamp_token
+            .as_ref()
+            .filter(|t| t.signer_validity_evaluated_at_tsa_time)
+            .map(|t| t.gen_time)
+            .unwrap_or_else(unix_now);
+        let cose = signature.map(|s| s.cose.as_slice());
+        check_chain_revocation(&mut certificate_chain, cose, evaluation_time);
+
+        SignerInfo {
+            name,
+            organization,
+            issued_by: sig.issuer.clone(),
+            timestamp: sig.time.clone(),
+            certificate_chain,
+            timestamp_token,
         }
-        Err(e) => {
-            let error_msg = e.to_string().to_lowercase();
-            // Check for various "no manifest" conditions
-            if error_msg.contains("not found")
-                || error_msg.contains("jumbfnotfound")
-                || error_msg.contains("no manifest")
-                || error_msg.contains("jumbf")
-            {
-                // No C2PA manifest, but still parse EXIF
-                if let Some(exif_info) = parse_exif_from_file(path) {
-                    C2paAnalysisResult::no_manifest_with_exif(exif_info)
-                } else {
-                    C2paAnalysisResult::no_manifest()
-                }
-            } 
-            // CBOR parsing errors often mean corrupted or incompatible manifest
-            else if error_msg.contains("cbor") 
-                || error_msg.contains("claim could not be")
-                || error_msg.contains("deserialization")
-                || error_msg.contains("invalid")
-            {
-                // Parse EXIF even on C2PA error
-                let exif_info = parse_exif_from_file(path);
-                C2paAnalysisResult {
-                    status: VerificationStatus::Error { 
-                        message: "This image contains C2PA data that could not be parsed. It may be corrupted or use an unsupported format.".to_string() 
-                    },
-                    signer: None,
-                    actions: vec![],
-                    ai_info: exif_info.as_ref().filter(|e| e.ai_detected).map(|e| AiInfo {
-                        is_ai_generated: true,
-                        generator_name: e.ai_generator.clone(),
-                        model_name: None,
-                        detection_source: Some("exif".to_string()),
-                    }),
-                    exif_info,
-                    claim_generator: None,
-                    title: None,
-                    format: None,
-                    instance_id: None,
-                    raw_manifest_json: None,
-                }
-            }
-            else {
-                C2paAnalysisResult::error(e.to_string())
+    })
+}
+
+/// The report's name for an SDK ingredient relationship
+fn to_ingredient_relationship(relationship: &Relationship) -> IngredientRelationship {
+    match relationship {
+        Relationship::ParentOf => IngredientRelationship::ParentOf,
+        Relationship::ComponentOf => IngredientRelationship::ComponentOf,
+        Relationship::InputTo => IngredientRelationship::InputTo,
+    }
+}
+
+fn to_validation_entries(statuses: &[ValidationStatus]) -> Vec<ValidationStatusEntry> {
+    statuses
+        .iter()
+        .map(|s| {
+            let kind = ValidationStatusCode::from_code(s.code());
+            ValidationStatusEntry {
+                code: s.code().to_string(),
+                category: kind.category(),
+                kind,
+                url: s.url().map(|u| u.to_string()),
+                explanation: s.explanation().map(|e| e.to_string()),
             }
+        })
+        .collect()
+}
+
+/// Derive the overall verdict from typed validation codes. Unknown codes are
+/// failures, so anything not explicitly success or informational blocks `Verified`.
+fn verification_status_from_entries(entries: &[ValidationStatusEntry]) -> VerificationStatus {
+    let failures: Vec<&ValidationStatusEntry> = entries
+        .iter()
+        .filter(|e| e.category == ValidationCategory::Failure)
+        .collect();
+
+    if failures.is_empty() {
+        VerificationStatus::Verified
+    } else if failures.iter().any(|e| e.kind.is_signature_failure()) {
+        VerificationStatus::SignatureInvalid
+    } else if failures
+        .iter()
+        .any(|e| e.kind == ValidationStatusCode::SigningCredentialRevoked)
+    {
+        VerificationStatus::CertificateRevoked
+    } else if failures.iter().any(|e| e.kind.is_hash_mismatch()) {
+        VerificationStatus::ContentModified
+    } else if failures.iter().any(|e| {
+        matches!(
+            e.kind,
+            ValidationStatusCode::SigningCredentialExpired
+                | ValidationStatusCode::ClaimSignatureOutsideValidity
+        )
+    }) {
+        VerificationStatus::CertificateExpired
+    } else if failures
+        .iter()
+        .any(|e| e.kind == ValidationStatusCode::SigningCredentialUntrusted)
+    {
+        VerificationStatus::CertificateUntrusted
+    } else {
+        VerificationStatus::ValidationFailed {
+            codes: failures.iter().map(|e| e.code.clone()).collect(),
         }
     }
 }
 
-/// Analyzes raw bytes for C2PA metadata
-#[frb(sync)]
-pub fn analyze_c2pa_from_bytes(data: Vec<u8>, mime_type: String) -> C2paAnalysisResult {
-    let data_clone = data.clone();
-    let cursor = std::io::Cursor::new(data);
+/// Whether a validation status URL points into the given manifest's JUMBF box.
+/// The label must end at a path separator, so "urn:uuid:a" does not claim
+/// statuses of "urn:uuid:ab".
+fn status_refers_to_manifest(entry: &ValidationStatusEntry, label: &str) -> bool {
+    let prefix = format!("/c2pa/{}", label);
+    entry.url.as_deref().is_some_and(|url| {
+        url.match_indices(&prefix).any(|(start, _)| {
+            matches!(url[start + prefix.len()..].chars().next(), None | Some('/'))
+        })
+    })
+}
 
-    match Reader::from_stream(&mime_type, cursor) {
-        Ok(manifest_reader) => {
-            let mut result = parse_manifest_reader(&manifest_reader);
-            // Also parse EXIF and merge AI detection
-            if let Some(exif_info) = parse_exif_from_bytes(&data_clone) {
-                // Merge EXIF AI detection with C2PA AI detection
-                if exif_info.ai_detected {
-                    if let Some(ref mut ai_info) = result.ai_info {
-                        // Already detected via C2PA, add EXIF as secondary source
-                        if ai_info.detection_source == Some("c2pa".to_string()) {
-                            ai_info.detection_source = Some("both".to_string());
-                        }
-                        if ai_info.generator_name.is_none() {
-                            ai_info.generator_name = exif_info.ai_generator.clone();
-                        }
-                    } else {
-                        // Not detected via C2PA, use EXIF detection
-                        result.ai_info = Some(AiInfo {
-                            is_ai_generated: true,
-                            generator_name: exif_info.ai_generator.clone(),
-                            model_name: None,
-                            detection_source: Some("exif".to_string()),
-                        });
-                    }
+fn manifest_node(
+    label: &str,
+    manifest: &Manifest,
+    is_active: bool,
+    signatures: &ClaimSignatures,
+) -> ManifestNode {
+    ManifestNode {
+        label: label.to_string(),
+        is_active,
+        title: manifest.title().map(|s| s.to_string()),
+        format: Some(manifest.format().to_string()),
+        instance_id: Some(manifest.instance_id().to_string()),
+        claim_generator: Some(manifest.claim_generator().to_string()),
+        signer: parse_signer_info(manifest, signatures.get(label)),
+        actions: parse_actions(manifest),
+        validation_statuses: vec![],
+    }
+}
+
+/// Walk every manifest in the store, starting from the active one, and
+/// record the ingredient relationships between them
+fn build_provenance_graph(reader: &Reader, signatures: &ClaimSignatures) -> ProvenanceGraph {
+    let active_label = reader.active_label().map(|s| s.to_string());
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut visited = HashSet::new();
+
+    // Breadth-first from the active manifest so nodes read from newest to oldest
+    let mut queue = VecDeque::new();
+    if let Some(label) = &active_label {
+        queue.push_back(label.clone());
+    }
+
+    // Manifests not reachable from the active one are still part of the store
+    let mut remaining: Vec<String> = reader
+        .iter_manifests()
+        .filter_map(|m| m.label().map(|s| s.to_string()))
+        .collect();
+    remaining.sort();
+
+    loop {
+        let label = match queue.pop_front() {
+            Some(label) => label,
+            None => match remaining.iter().find(|l| !visited.contains(*l)) {
+                Some(label) => label.clone(),
+                None => break,
+            },
+        };
+        if !visited.insert(label.clone()) {
+            continue;
+        }
+        let manifest = match reader.get_manifest(&label) {
+            Some(m) => m,
+            None => continue,
+        };
+
+        let is_active = active_label.as_deref() == Some(label.as_str());
+        nodes.push(manifest_node(&label, manifest, is_active, signatures));
+
+        for ingredient in manifest.ingredients() {
+            let to_label = ingredient.active_manifest().map(|s| s.to_string());
+            if let Some(child) = &to_label {
+                if !visited.contains(child) {
+                    queue.push_back(child.clone());
                 }
-                result.exif_info = Some(exif_info);
             }
-            result
+            edges.push(IngredientEdge {
+                from_label: label.clone(),
+                to_label,
+                ingredient_title: ingredient.title().to_string(),
+                ingredient_format: Some(ingredient.format().to_string()),
+                ingredient_instance_id: Some(ingredient.instance_id().to_string()),
+                relationship: to_ingredient_relationship(ingredient.relationship()),
+                validation_statuses: ingredient
+                    .validation_status()
+                    .map(to_validation_entries)
+                    .unwrap_or_default(),
+            });
         }
-        Err(e) => {
-            let error_msg = e.to_string().to_lowercase();
-            // Check for various "no manifest" conditions
-            if error_msg.contains("not found")
-                || error_msg.contains("jumbfnotfound")
-                || error_msg.contains("no manifest")
-                || error_msg.contains("jumbf")
-            {
-                // No C2PA manifest, but still parse EXIF
-                if let Some(exif_info) = parse_exif_from_bytes(&data_clone) {
-                    C2paAnalysisResult::no_manifest_with_exif(exif_info)
-                } else {
-                    C2paAnalysisResult::no_manifest()
-                }
-            } 
-            // CBOR parsing errors often mean corrupted or incompatible manifest
-            else if error_msg.contains("cbor") 
-                || error_msg.contains("claim could not be")
-                || error_msg.contains("deserialization")
-                || error_msg.contains("invalid")
-            {
-                // Parse EXIF even on C2PA error
-                let exif_info = parse_exif_from_bytes(&data_clone);
-                C2paAnalysisResult {
-                    status: VerificationStatus::Error { 
-                        message: "This image contains C2PA data that could not be parsed. It may be corrupted or use an unsupported format.".to_string() 
-                    },
-                    signer: None,
-                    actions: vec![],
-                    ai_info: exif_info.as_ref().filter(|e| e.ai_detected).map(|e| AiInfo {
-                        is_ai_generated: true,
-                        generator_name: e.ai_generator.clone(),
-                        model_name: None,
-                        detection_source: Some("exif".to_string()),
-                    }),
-                    exif_info,
-                    claim_generator: None,
-                    title: None,
-                    format: None,
-                    instance_id: None,
-                    raw_manifest_json: None,
+    }
+
+    // Attribute each store-level status to the manifest its URL points into,
+    // falling back to the active manifest for codes without a manifest path
+    let store_statuses = reader
+        .validation_status()
+        .map(to_validation_entries)
+        .unwrap_or_default();
+    for entry in store_statuses {
+        let owner = nodes
+            .iter()
+            .position(|n| status_refers_to_manifest(&entry, &n.label))
+            .or_else(|| nodes.iter().position(|n| n.is_active));
+        if let Some(index) = owner {
+            nodes[index].validation_statuses.push(entry);
+        }
+    }
+
+    // Ingredient statuses were captured when the ingredient's manifest was
+    // validated at import time, so they belong to that manifest as well
+    for edge in &edges {
+        let Some(to_label) = &edge.to_label else { continue };
+        if let Some(node) = nodes.iter_mut().find(|n| &n.label == to_label) {
+            for entry in &edge.validation_statuses {
+                let duplicate = node
+                    .validation_statuses
+                    .iter()
+                    .any(|e| e.code == entry.code && e.url == entry.url);
+                if !duplicate {
+                    node.validation_statuses.push(entry.clone());
                 }
             }
-            else {
-                C2paAnalysisResult::error(e.to_string())
-            }
         }
     }
+
+    ProvenanceGraph {
+        active_label,
+        nodes,
+        edges,
+    }
 }
 
-/// Extract a field from a certificate distinguished name string
-/// e.g., "CN=Name, O=Organization, C=US" -> extract_cert_field(s, "O=") returns "Organization"
-fn extract_cert_field(dn: &str, field_prefix: &str) -> Option<String> {
-    // Handle both comma-separated and slash-separated DNs
-    let parts: Vec<&str> = if dn.contains(',') {
-        dn.split(',').collect()
-    } else if dn.contains('/') {
-        dn.split('/').collect()
-    } else {
-        vec![dn]
-    };
-    
-    for part in parts {
-        let trimmed = part.trim();
-        if trimmed.starts_with(field_prefix) {
-            let value = trimmed[field_prefix.len()..].trim();
-            // Remove quotes if present
-            let cleaned = value.trim_matches('"').trim_matches('\'');
-            if !cleaned.is_empty() {
-                return Some(cleaned.to_string());
-            }
-        }
+/// An expired signer certificate is acceptable when a verified, trusted
+/// time-stamp shows the signature was made while the certificate was valid
+fn classify_expired_with_timestamp(
+    entries: &[ValidationStatusEntry],
+    timestamp: Option<&TimestampInfo>,
+) -> Option<VerificationStatus> {
+    let timestamp = timestamp?;
+    if !timestamp.signer_validity_evaluated_at_tsa_time
+        || timestamp.signer_valid_at_gen_time != Some(true)
+    {
+        return None;
     }
-    None
+    let remaining: Vec<ValidationStatusEntry> = entries
+        .iter()
+        .filter(|e| {
+            !matches!(
+                e.kind,
+                ValidationStatusCode::SigningCredentialExpired
+                    | ValidationStatusCode::ClaimSignatureOutsideValidity
+            )
+        })
+        .cloned()
+        .collect();
+    Some(verification_status_from_entries(&remaining))
 }
 
-fn parse_manifest_reader(reader: &Reader) -> C2paAnalysisResult {
+fn parse_manifest_reader(reader: &Reader, signatures: &ClaimSignatures) -> C2paAnalysisResult {
     let manifest = match reader.active_manifest() {
         Some(m) => m,
         None => return C2paAnalysisResult::no_manifest(),
     };
 
-    // Parse signer info with improved extraction
-    let signer = manifest.signature_info().map(|sig| {
-        // Try to extract organization from issuer string
-        // Common formats: "CN=Name, O=Organization, C=Country"
-        let organization = sig.issuer.as_ref().and_then(|issuer| {
-            extract_cert_field(issuer, "O=")
-                .or_else(|| extract_cert_field(issuer, "OU="))
-        });
-        
-        // Extract common name for display
-        let name = sig.issuer.as_ref().and_then(|issuer| {
-            extract_cert_field(issuer, "CN=")
-        }).or_else(|| sig.issuer.clone());
-
-        SignerInfo {
-            name,
-            organization,
-            issued_by: sig.issuer.clone(),
-            timestamp: sig.time.clone(),
-        }
-    });
-
-    // Parse actions - get them from assertions
-    let mut actions = Vec::new();
-    if let Ok(action_assertions) = manifest.find_assertion::<Actions>(Actions::LABEL) {
-        for action in action_assertions.actions() {
-            actions.push(ContentAction {
-                action: action.action().to_string(),
-                software_agent: action.software_agent().map(software_agent_to_string),
-                when: action.when().map(|t| t.to_string()),
-                description: None,
-            });
-        }
-    }
+    let active_signature = reader
+        .active_label()
+        .and_then(|label| signatures.get(label));
+    let signer = parse_signer_info(manifest, active_signature);
+    let actions = parse_actions(manifest);
 
     // Get claim generator - returns &str not Option<&str>
     let claim_gen = manifest.claim_generator();
 
-    // Get raw JSON first so we can use it for AI detection
-    let raw_json = serde_json::to_string_pretty(&reader.json()).ok();
-
-    // Check for AI generation indicators (now with raw JSON)
-    let ai_info = detect_ai_generation(&actions, claim_gen, raw_json.as_deref());
-
-    // Determine verification status
-    let status = match reader.validation_status() {
-        None => VerificationStatus::Verified,
-        Some(statuses) => {
-            if statuses.iter().any(|s| s.code().contains("signature")) {
-                VerificationStatus::SignatureInvalid
-            } else if statuses.iter().any(|s| s.code().contains("expired")) {
-                VerificationStatus::CertificateExpired
-            } else if statuses.iter().any(|s| s.code().contains("trust")) {
-                VerificationStatus::CertificateUntrusted
-            } else {
-                VerificationStatus::Verified
-            }
-        }
+    // Parse the manifest store JSON once, for AI detection and the raw output
+    let manifest_json = serde_json::from_str::<Value>(&reader.json()).ok();
+    let raw_json = manifest_json
+        .as_ref()
+        .and_then(|json| serde_json::to_string_pretty(json).ok());
+
+    let active_json = manifest_json.as_ref().and_then(|json| {
+        let active = json.get("active_manifest")?.as_str()?;
+        json["manifests"].get(active)
+    });
+    let model_identities = manifest_identities(&actions, active_json);
+
+    let validation_statuses = reader
+        .validation_status()
+        .map(to_validation_entries)
+        .unwrap_or_default();
+
+    let status = match verification_status_from_entries(&validation_statuses) {
+        VerificationStatus::CertificateExpired => classify_expired_with_timestamp(
+            &validation_statuses,
+            signer.as_ref().and_then(|s| s.timestamp_token.as_ref()),
+        )
+        .unwrap_or(VerificationStatus::CertificateExpired),
+        status => status,
+    };
+    let chain_revoked = signer.as_ref().is_some_and(|s| {
+        s.certificate_chain
+            .iter()
+            .any(|c| c.revocation.state == RevocationState::Revoked)
+    });
+    let status = match status {
+        VerificationStatus::SignatureInvalid => status,
+        _ if chain_revoked => VerificationStatus::CertificateRevoked,
+        status => status,
     };
 
+    // Collect evidence from actions, claim generator and manifest JSON
+    let ai_info = ai_info_from_evidence(c2pa_evidence(
+        &actions,
+        claim_gen,
+        manifest_json.as_ref(),
+        &status,
+    ));
+
     C2paAnalysisResult {
         status,
+        error: None,
+        validation_statuses,
         signer,
         actions,
         ai_info,
         exif_info: None, // Will be filled in by caller if needed
+        iptc_info: None,
+        xmp_info: None,
+        generation_parameters: None,
+        model_identities,
         claim_generator: Some(claim_gen.to_string()),
         title: manifest.title().map(|s| s.to_string()),
         format: Some(manifest.format().to_string()),
         instance_id: Some(manifest.instance_id().to_string()),
         raw_manifest_json: raw_json,
+        provenance: Some(build_provenance_graph(reader, signatures)),
+        mime_check: None, // Filled in by the pipeline
+        thumbnail_comparison: None,
+        consistency: vec![],
     }
 }
 
-fn detect_ai_generation(actions: &[ContentAction], claim_generator: &str, raw_json: Option<&str>) -> Option<AiInfo> {
+/// Build the AI verdict from collected evidence, strongest evidence first
+fn ai_info_from_evidence(mut evidence: Vec<Evidence>) -> Option<AiInfo> {
+    if evidence.is_empty() {
+        return None;
+    }
+    evidence.sort_by_key(|e| std::cmp::Reverse(e.strength));
+    let score = score_evidence(&evidence, &scoring_weights());
+
+    let ai_evidence = || evidence.iter().filter(|e| e.kind.is_ai());
+    let generator_name = ai_evidence().find_map(|e| e.generator.clone());
+    // The first tool that names a model, else the first tool at all
+    let identities: Vec<ModelIdentity> = ai_evidence()
+        .flat_map(|e| {
+            let generator = e.generator.as_deref().and_then(|g| identify(g, None, &e.field));
+            generator.into_iter().chain(identify(&e.matched_text, None, &e.field))
+        })
+        .collect();
+    let model = identities
+        .iter()
+        .find(|i| i.model.is_some())
+        .or(identities.first())
+        .cloned();
+    let model_name = model.as_ref().and_then(|m| m.model.clone());
+    let mut sources: Vec<&str> = Vec::new();
+    for source in evidence.iter().map(|e| e.detector.source()) {
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    Some(AiInfo {
+        is_ai_generated: score.label.is_ai(),
+        likelihood: score.likelihood,
+        label: score.label,
+        generator_name,
+        model_name,
+        model,
+        detection_source: Some(sources.join(" + ")),
+        evidence,
+    })
+}
+
+/// Whether the manifest's signature and content hashes check out. Expired or
+/// untrusted certificates still leave the signed declaration intact.
+fn manifest_intact(status: &VerificationStatus) -> bool {
+    matches!(
+        status,
+        VerificationStatus::Verified
+            | VerificationStatus::CertificateExpired
+            | VerificationStatus::CertificateUntrusted
+    )
+}
+
+/// AI and camera-capture evidence from the active manifest's actions, its
+/// claim generator and the manifest store JSON. Nothing from a manifest that
+/// failed verification counts as more than strong evidence.
+pub(crate) fn c2pa_evidence(
+    actions: &[ContentAction],
+    claim_generator: &str,
+    manifest_json: Option<&Value>,
+    status: &VerificationStatus,
+) -> Vec<Evidence> {
+    let mut evidence = Vec::new();
+    let signatures = signature_database();
+
     // 1. Check actions for AI-related activities
-    for action in actions {
+    for (index, action) in actions.iter().enumerate() {
+        // The declared source type is the signed, standard answer
+        if let Some(source_type) = &action.digital_source_type {
+            evidence.extend(source_type_evidence(
+                source_type,
+                EvidenceDetector::C2paAction,
+                format!("c2pa.actions[{}].digitalSourceType", index),
+                true,
+            ));
+        }
+
         let action_lower = action.action.to_lowercase();
-        
+        // AI used for anything but the initial creation edited existing content
+        let kind = if action_lower == "c2pa.created" {
+            EvidenceKind::AiGeneration
+        } else {
+            EvidenceKind::AiEditing
+        };
+
         // Check for standard C2PA AI-related action types
-        if action_lower.contains("c2pa.created")
-            || action_lower.contains("c2pa.placed")
-            || action_lower.contains("generated")
-            || action_lower.contains("ai")
-            || action_lower.contains("c2pa.drawing")
-            || action_lower.contains("c2pa.unknown")
+        if !(AI_CANDIDATE_ACTIONS.contains(&action_lower.as_str())
+            || AI_ACTION_WORDS.iter().any(|w| contains_word(&action_lower, w)))
         {
-            if let Some(agent) = &action.software_agent {
-                let agent_lower = agent.to_lowercase();
-                for gen in AI_GENERATORS {
-                    if agent_lower.contains(gen) {
-                        return Some(AiInfo {
-                            is_ai_generated: true,
-                            generator_name: Some(agent.clone()),
-                            model_name: extract_model_name(&agent_lower),
-                            detection_source: Some("c2pa".to_string()),
-                        });
-                    }
-                }
-            }
-            
-            // Check description for AI indicators
-            if let Some(desc) = &action.description {
-                let desc_lower = desc.to_lowercase();
-                for gen in AI_GENERATORS {
-                    if desc_lower.contains(gen) {
-                        return Some(AiInfo {
-                            is_ai_generated: true,
-                            generator_name: Some(desc.clone()),
-                            model_name: extract_model_name(&desc_lower),
-                            detection_source: Some("c2pa".to_string()),
-                        });
-                    }
-                }
-            }
+            continue;
         }
-    }
 
-    // 2. Check claim generator
-    let gen_lower = claim_generator.to_lowercase();
-    for ai_gen in AI_GENERATORS {
-        if gen_lower.contains(ai_gen) {
-            return Some(AiInfo {
-                is_ai_generated: true,
-                generator_name: Some(claim_generator.to_string()),
-                model_name: extract_model_name(&gen_lower),
-                detection_source: Some("c2pa".to_string()),
-            });
+        if let Some(agent) = &action.software_agent {
+            if let Some(found) = signatures.find_generator(agent, MatchField::SoftwareAgent) {
+                evidence.push(Evidence {
+                    detector: EvidenceDetector::C2paAction,
+                    kind,
+                    field: format!("c2pa.actions[{}].softwareAgent", index),
+                    matched_text: agent.clone(),
+                    strength: EvidenceStrength::Strong,
+                    explanation: format!(
+                        "The {} action was performed by {}, {} (\"{}\")",
+                        action.action,
+                        agent,
+                        found.generator.describe(),
+                        found.matched
+                    ),
+                    generator: Some(agent.clone()),
+                });
+            }
         }
-    }
 
-    // 3. Parse raw JSON to check for digitalSourceType and other AI indicators
-    if let Some(json_str) = raw_json {
-        if let Ok(json_value) = serde_json::from_str::<Value>(json_str) {
-            // Check for AI indicators in the JSON
-            if let Some(ai_info) = check_json_for_ai_indicators(&json_value) {
-                return Some(ai_info);
+        // Check description for AI indicators
+        if let Some(desc) = &action.description {
+            let mention = signatures
+                .find_generator(desc, MatchField::ActionDescription)
+                .map(|found| found.matched)
+                .or_else(|| {
+                    signatures
+                        .find_phrase(desc, MatchField::ActionDescription)
+                        .map(|phrase| phrase.text.clone())
+                });
+            if let Some(mention) = mention {
+                evidence.push(Evidence {
+                    detector: EvidenceDetector::C2paAction,
+                    kind,
+                    field: format!("c2pa.actions[{}].description", index),
+                    matched_text: desc.clone(),
+                    strength: EvidenceStrength::Weak,
+                    explanation: format!(
+                        "The description of the {} action mentions \"{}\"",
+                        action.action, mention
+                    ),
+                    generator: None,
+                });
             }
         }
     }
 
-    None
-}
-
-/// Extract model name from generator string
-fn extract_model_name(generator: &str) -> Option<String> {
-    // Try to extract version/model info
-    let patterns = [
-        ("dall-e-3", "DALL-E 3"),
-        ("dall-e-2", "DALL-E 2"),
-        ("dall-e 3", "DALL-E 3"),
-        ("dall-e 2", "DALL-E 2"),
-        ("midjourney v6", "Midjourney v6"),
-        ("midjourney v5", "Midjourney v5"),
-        ("sd-xl", "Stable Diffusion XL"),
-        ("sdxl", "Stable Diffusion XL"),
-        ("sd 1.5", "Stable Diffusion 1.5"),
-        ("sd 2.1", "Stable Diffusion 2.1"),
-        ("stable diffusion xl", "Stable Diffusion XL"),
-        ("firefly 2", "Adobe Firefly 2"),
-        ("firefly 3", "Adobe Firefly 3"),
-        ("imagen 2", "Google Imagen 2"),
-        ("imagen 3", "Google Imagen 3"),
-        ("gen-3", "Runway Gen-3"),
-        ("gen-2", "Runway Gen-2"),
-        ("sora", "OpenAI Sora"),
-    ];
-    
-    for (pattern, name) in patterns {
-        if generator.contains(pattern) {
-            return Some(name.to_string());
-        }
-    }
-    None
-}
-
-/// Check JSON manifest for AI generation indicators
-fn check_json_for_ai_indicators(json: &Value) -> Option<AiInfo> {
-    let json_str = json.to_string().to_lowercase();
-    
-    // Check for digitalSourceType indicating AI generation
-    for source_type in AI_DIGITAL_SOURCE_TYPES {
-        if json_str.contains(&source_type.to_lowercase()) {
-            // Try to extract more specific generator info
-            let generator = extract_generator_from_json(json);
-            return Some(AiInfo {
-                is_ai_generated: true,
-                generator_name: generator.or_else(|| Some("AI Generated (from digitalSourceType)".to_string())),
-                model_name: None,
-                detection_source: Some("c2pa".to_string()),
-            });
-        }
-    }
-    
-    // Check for c2pa.ai assertions
-    if json_str.contains("c2pa.ai") || json_str.contains("\"ai\"") {
-        let generator = extract_generator_from_json(json);
-        return Some(AiInfo {
-            is_ai_generated: true,
-            generator_name: generator.or_else(|| Some("AI Generated".to_string())),
-            model_name: None,
-            detection_source: Some("c2pa".to_string()),
+    // 2. Check claim generator
+    if let Some(found) = signatures.find_generator(claim_generator, MatchField::ClaimGenerator) {
+        evidence.push(Evidence {
+            detector: EvidenceDetector::C2paClaimGenerator,
+            kind: EvidenceKind::AiGeneration,
+            field: "claim_generator".to_string(),
+            matched_text: claim_generator.to_string(),
+            strength: EvidenceStrength::Strong,
+            explanation: format!(
+                "The manifest was produced by {}, {} (\"{}\")",
+                claim_generator,
+                found.generator.describe(),
+                found.matched
+            ),
+            generator: Some(claim_generator.to_string()),
         });
     }
-    
-    // Check for specific AI tool mentions in assertions
-    for gen in AI_GENERATORS {
-        if json_str.contains(gen) {
-            return Some(AiInfo {
-                is_ai_generated: true,
-                generator_name: Some(format!("Detected: {}", gen)),
-                model_name: extract_model_name(&json_str),
-                detection_source: Some("c2pa".to_string()),
-            });
-        }
-    }
-    
-    // Check for "trained" or "synthetic" indicators
-    if json_str.contains("trained") && json_str.contains("algorithmic") {
-        return Some(AiInfo {
-            is_ai_generated: true,
-            generator_name: Some("AI/ML Generated Content".to_string()),
-            model_name: None,
-            detection_source: Some("c2pa".to_string()),
-        });
+
+    // 3. Check the rest of the manifest store: ingredient source types and
+    // generator names anywhere in the provenance
+    if let Some(json) = manifest_json {
+        evidence.extend(manifest_store_evidence(json));
     }
-    
-    if json_str.contains("synthetic") || json_str.contains("artificially generated") {
-        return Some(AiInfo {
-            is_ai_generated: true,
-            generator_name: Some("Synthetic/AI Generated".to_string()),
-            model_name: None,
-            detection_source: Some("c2pa".to_string()),
-        });
+
+    // A declaration from a tampered or badly signed manifest is no longer a
+    // signed declaration, so it can neither confirm AI nor beat camera evidence
+    if !manifest_intact(status) {
+        for e in &mut evidence {
+            e.strength = e.strength.min(EvidenceStrength::Strong);
+        }
     }
-    
-    None
+
+    evidence
 }
 
-/// Try to extract generator name from JSON manifest
-fn extract_generator_from_json(json: &Value) -> Option<String> {
-    // Try common paths where generator info might be stored
-    if let Some(manifests) = json.get("manifests").and_then(|m| m.as_object()) {
-        for (_, manifest) in manifests {
-            // Check claim_generator
-            if let Some(gen) = manifest.get("claim_generator").and_then(|g| g.as_str()) {
-                return Some(gen.to_string());
+/// Check the manifest store for AI generation indicators and ingredient
+/// source types. Only the fields that say what produced the content are
+/// searched, never hashes, titles, thumbnails or free text.
+fn manifest_store_evidence(json: &Value) -> Vec<Evidence> {
+    let mut evidence: Vec<Evidence> = Vec::new();
+    let signatures = signature_database();
+    let mut mentioned: Vec<&str> = Vec::new();
+    let active = json.get("active_manifest").and_then(Value::as_str);
+
+    for scoped in manifest_fields(json) {
+        let (strength, kind, explanation) = match scoped.field {
+            ManifestField::DigitalSourceType => {
+                // The active manifest's source types come from its typed actions
+                if Some(scoped.manifest) == active {
+                    continue;
+                }
+                let source_type = DigitalSourceType::from_uri(scoped.text);
+                evidence.extend(source_type_evidence(
+                    &source_type,
+                    EvidenceDetector::C2paManifest,
+                    scoped.path,
+                    true,
+                ));
+                continue;
             }
-            
-            // Check assertions for software agents
-            if let Some(assertions) = manifest.get("assertions").and_then(|a| a.as_array()) {
-                for assertion in assertions {
-                    if let Some(data) = assertion.get("data") {
-                        // Check for software_agent in actions
-                        if let Some(actions) = data.get("actions").and_then(|a| a.as_array()) {
-                            for action in actions {
-                                if let Some(agent) = action.get("softwareAgent").and_then(|a| a.as_str()) {
-                                    let agent_lower = agent.to_lowercase();
-                                    for gen in AI_GENERATORS {
-                                        if agent_lower.contains(gen) {
-                                            return Some(agent.to_string());
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+            ManifestField::AssertionLabel => {
+                let lower = scoped.text.to_lowercase();
+                if !AI_ASSERTION_LABEL_WORDS.iter().any(|w| contains_word(&lower, w)) {
+                    continue;
                 }
+                (
+                    EvidenceStrength::Weak,
+                    EvidenceKind::AiGeneration,
+                    format!("The manifest contains the AI-related assertion {}", scoped.text),
+                )
             }
-        }
+            ManifestField::ClaimGenerator
+            | ManifestField::ClaimGeneratorInfo
+            | ManifestField::SoftwareAgent => {
+                let scope = if scoped.field == ManifestField::SoftwareAgent {
+                    MatchField::SoftwareAgent
+                } else {
+                    MatchField::ClaimGenerator
+                };
+                // Each generator is reported once, where it is first named
+                let Some(found) = signatures
+                    .find_generator(scoped.text, scope)
+                    .filter(|f| !mentioned.contains(&f.generator.product.as_str()))
+                else {
+                    continue;
+                };
+                mentioned.push(&found.generator.product);
+                (
+                    EvidenceStrength::Weak,
+                    EvidenceKind::AiGeneration,
+                    format!("The manifest mentions \"{}\"", found.matched),
+                )
+            }
+        };
+        evidence.push(Evidence {
+            detector: EvidenceDetector::C2paManifest,
+            kind,
+            field: scoped.path,
+            matched_text: scoped.text.to_string(),
+            strength,
+            explanation,
+            generator: None,
+        });
     }
-    None
+
+    evidence
 }
 
 /// Returns the C2PA SDK version
@@ -937,3 +1282,161 @@ pub fn c2pa_sdk_version() -> String {
 pub fn is_c2pa_available() -> bool {
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(code: &str) -> ValidationStatusEntry {
+        let kind = ValidationStatusCode::from_code(code);
+        ValidationStatusEntry {
+            code: code.to_string(),
+            category: kind.category(),
+            kind,
+            url: None,
+            explanation: None,
+        }
+    }
+
+    fn status_of(codes: &[&str]) -> VerificationStatus {
+        let entries: Vec<ValidationStatusEntry> = codes.iter().map(|c| entry(c)).collect();
+        verification_status_from_entries(&entries)
+    }
+
+    #[test]
+    fn success_and_informational_codes_verify() {
+        assert!(matches!(status_of(&[]), VerificationStatus::Verified));
+        assert!(matches!(
+            status_of(&[
+                "claimSignature.validated",
+                "assertion.dataHash.match",
+                "signingCredential.ocsp.skipped",
+                "timeStamp.untrusted",
+            ]),
+            VerificationStatus::Verified
+        ));
+    }
+
+    #[test]
+    fn failures_take_precedence_in_order() {
+        let untrusted = "signingCredential.untrusted";
+        let expired = "signingCredential.expired";
+        let revoked = "signingCredential.revoked";
+        let modified = "assertion.dataHash.mismatch";
+        let bad_signature = "claimSignature.mismatch";
+
+        assert!(matches!(
+            status_of(&[untrusted]),
+            VerificationStatus::CertificateUntrusted
+        ));
+        assert!(matches!(
+            status_of(&[untrusted, expired]),
+            VerificationStatus::CertificateExpired
+        ));
+        assert!(matches!(
+            status_of(&[expired, untrusted, revoked]),
+            VerificationStatus::CertificateRevoked
+        ));
+        assert!(matches!(
+            status_of(&[untrusted, expired, modified]),
+            VerificationStatus::ContentModified
+        ));
+        assert!(matches!(
+            status_of(&[modified, revoked]),
+            VerificationStatus::CertificateRevoked
+        ));
+        assert!(matches!(
+            status_of(&[revoked, modified, expired, untrusted, bad_signature]),
+            VerificationStatus::SignatureInvalid
+        ));
+        // Signing outside the certificate's validity counts as expiry
+        assert!(matches!(
+            status_of(&[untrusted, "claimSignature.outsideValidity"]),
+            VerificationStatus::CertificateExpired
+        ));
+    }
+
+    #[test]
+    fn statuses_match_whole_manifest_labels() {
+        let with_url = |url: &str| ValidationStatusEntry {
+            url: Some(url.to_string()),
+            ..entry("assertion.dataHash.mismatch")
+        };
+        let label = "urn:uuid:1234";
+        assert!(status_refers_to_manifest(
+            &with_url("self#jumbf=/c2pa/urn:uuid:1234/c2pa.assertions/c2pa.hash.data"),
+            label
+        ));
+        assert!(status_refers_to_manifest(
+            &with_url("self#jumbf=/c2pa/urn:uuid:1234"),
+            label
+        ));
+        // A label that merely starts with this one belongs to another manifest
+        assert!(!status_refers_to_manifest(
+            &with_url("self#jumbf=/c2pa/urn:uuid:12345/c2pa.signature"),
+            label
+        ));
+        assert!(!status_refers_to_manifest(
+            &with_url("self#jumbf=/c2pa/urn:uuid:123/c2pa.signature"),
+            label
+        ));
+        assert!(!status_refers_to_manifest(&entry("claim.missing"), label));
+    }
+
+    fn agent_action(name: &str, agent: &str) -> ContentAction {
+        ContentAction {
+            action: name.to_string(),
+            software_agent: Some(agent.to_string()),
+            software_agent_info: None,
+            when: None,
+            description: None,
+            digital_source_type: None,
+            reason: None,
+            ingredients: vec![],
+            parameters: vec![],
+            changes: vec![],
+            related: vec![],
+        }
+    }
+
+    #[test]
+    fn only_candidate_actions_are_checked_for_ai_agents() {
+        let cases = [
+            ("c2pa.created", Some(EvidenceKind::AiGeneration)),
+            ("c2pa.placed", Some(EvidenceKind::AiEditing)),
+            ("c2pa.unknown", Some(EvidenceKind::AiEditing)),
+            ("com.example.ai.fill", Some(EvidenceKind::AiEditing)),
+            ("com.example.generated_fill", Some(EvidenceKind::AiEditing)),
+            // Labels that merely contain "ai" or "generated"
+            ("c2pa.painted", None),
+            ("com.example.retrained", None),
+            ("com.example.regenerated", None),
+            ("c2pa.created.v2", None),
+            ("c2pa.cropped", None),
+        ];
+        for (label, expected) in cases {
+            let evidence = c2pa_evidence(
+                &[agent_action(label, "Adobe Firefly")],
+                "",
+                None,
+                &VerificationStatus::Verified,
+            );
+            let kinds: Vec<_> = evidence.iter().map(|e| e.kind).collect();
+            assert_eq!(kinds, Vec::from_iter(expected), "{}", label);
+        }
+    }
+
+    #[test]
+    fn other_failures_are_listed() {
+        match status_of(&[
+            "claimSignature.validated",
+            "claim.missing",
+            "com.example.custom",
+        ]) {
+            VerificationStatus::ValidationFailed { codes } => {
+                assert_eq!(codes, ["claim.missing", "com.example.custom"]);
+            }
+            other => panic!("unexpected status {:?}", other),
+        }
+    }
+}
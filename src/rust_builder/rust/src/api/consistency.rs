@@ -0,0 +1,715 @@
+//! Cross-checks between EXIF, XMP, IPTC and the C2PA manifest. Each source
+//! is read on its own elsewhere; this is where they are compared.
+
+use serde::{Deserialize, Serialize};
+
+use super::c2pa_reader::{C2paAnalysisResult, ExifInfo};
+use super::der::days_from_civil;
+use super::thumbnail_comparison::ThumbnailComparison;
+
+/// How far a camera clock may run ahead of the signing clock, in seconds
+const CLOCK_SKEW: i64 = 10 * 60;
+
+/// UTC offsets run from -12:00 to +14:00, so a local time with no offset
+/// lies anywhere in this range around its UTC reading
+const MAX_OFFSET_AHEAD: i64 = 14 * 3600;
+const MAX_OFFSET_BEHIND: i64 = 12 * 3600;
+
+/// Relative difference in aspect ratio tolerated between the EXIF thumbnail
+/// and the image. Cameras letterbox 3:2 frames into 160×120 thumbnails, a
+/// difference of 12%.
+const ASPECT_TOLERANCE: f64 = 0.2;
+
+/// Words in producer strings that name no product
+const NON_PRODUCT_WORDS: &[&str] = &[
+    "and",
+    "android",
+    "app",
+    "arm64",
+    "bit",
+    "build",
+    "c2pa",
+    "edition",
+    "firmware",
+    "for",
+    "ios",
+    "lib",
+    "linux",
+    "mac",
+    "macintosh",
+    "macos",
+    "release",
+    "rs",
+    "sdk",
+    "the",
+    "ver",
+    "version",
+    "windows",
+    "x64",
+    "x86",
+];
+
+/// What a consistency finding is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InconsistencyKind {
+    /// The recorded capture time is later than the manifest was signed
+    CaptureAfterSigning,
+    /// A C2PA action is dated before the content was captured
+    ActionBeforeCapture,
+    /// EXIF names a camera but has none of the exposure data cameras record
+    CameraWithoutSensorData,
+    /// EXIF Software or XMP CreatorTool names a different tool than the
+    /// manifest's claim generator
+    SoftwareMismatch,
+    /// The EXIF thumbnail's shape differs from the image's, as when an image
+    /// is cropped without regenerating its thumbnail
+    ThumbnailMismatch,
+    /// The EXIF thumbnail shows different content from the image, as when an
+    /// object is removed or the image replaced after capture
+    ThumbnailContentMismatch,
+}
+
+/// How much a finding on its own says about the asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum FindingSeverity {
+    /// Worth a look; ordinary workflows often produce it
+    Info,
+    /// Unlikely unless the asset or its metadata was altered, or a clock
+    /// was set wrong
+    Warning,
+}
+
+/// One of the values a finding compares
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindingValue {
+    /// "c2pa", "exif", "image", "iptc" or "xmp"
+    pub source: String,
+    /// The field the value was read from, e.g. "DateTimeOriginal" or
+    /// "claim_generator"
+    pub field: String,
+    pub value: String,
+}
+
+/// A contradiction between metadata sources
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsistencyFinding {
+    pub kind: InconsistencyKind,
+    pub severity: FindingSeverity,
+    /// The values that disagree, or for a missing-data finding, the values
+    /// that led to it
+    pub values: Vec<FindingValue>,
+    /// Human-readable reason for reviewers
+    pub explanation: String,
+}
+
+fn value(source: &str, field: &str, value: impl Into<String>) -> FindingValue {
+    FindingValue {
+        source: source.to_string(),
+        field: field.to_string(),
+        value: value.into(),
+    }
+}
+
+/// The UTC instants, in seconds since the Unix epoch, a written time may
+/// denote once a missing offset or time of day is allowed for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimeRange {
+    earliest: i64,
+    latest: i64,
+}
+
+fn digits(text: &str) -> Option<i64> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// "+01:00", "-0500", "+09" or "Z" as seconds east of UTC
+fn parse_offset(text: &str) -> Option<i64> {
+    if text == "Z" {
+        return Some(0);
+    }
+    let sign = match text.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let rest = text[1..].replace(':', "");
+    let hours = digits(rest.get(..2)?)?;
+    let minutes = match rest.len() {
+        2 => 0,
+        4 => digits(&rest[2..])?,
+        _ => return None,
+    };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Parse "2024-03-02 14:11:05" (EXIF), "2024-03-02T14:11:05.123+01:00"
+/// (RFC 3339) or "20240302T141105+0100" (IPTC). `offset` applies when the
+/// text carries none, as with EXIF's separate OffsetTime tags.
+fn parse_time(text: &str, offset: Option<&str>) -> Option<TimeRange> {
+    let text = text.trim();
+    let (date, rest) = if text.get(4..5) == Some("-") {
+        (text.get(..10)?.replace('-', ""), text.get(10..)?)
+    } else {
+        (text.get(..8)?.to_string(), text.get(8..)?)
+    };
+    let year = digits(date.get(..4)?)?;
+    let month = digits(date.get(4..6)?)? as u32;
+    let day = digits(date.get(6..)?)? as u32;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let mut local = days_from_civil(year, month, day) * 86400;
+
+    // A date alone covers the whole day
+    let mut span = 86400 - 1;
+    let mut zone = rest;
+    if let Some(time) = rest.strip_prefix(['T', ' ']) {
+        let end = time
+            .find(|c: char| !c.is_ascii_digit() && c != ':')
+            .unwrap_or(time.len());
+        let clock = time[..end].replace(':', "");
+        let hour = digits(clock.get(..2)?)?;
+        let minute = digits(clock.get(2..4)?)?;
+        let second = match clock.len() {
+            4 => 0,
+            6 => digits(&clock[4..])?,
+            _ => return None,
+        };
+        local += hour * 3600 + minute * 60 + second;
+        span = 0;
+        // Fractional seconds are dropped
+        zone = time[end..].trim_start_matches(|c: char| c == '.' || c == ',' || c.is_ascii_digit());
+    }
+
+    let zone = zone.trim();
+    let offset = if zone.is_empty() {
+        offset.and_then(|o| parse_offset(o.trim()))
+    } else {
+        Some(parse_offset(zone)?)
+    };
+    Some(match offset {
+        Some(offset) => TimeRange {
+            earliest: local - offset,
+            latest: local - offset + span,
+        },
+        None => TimeRange {
+            earliest: local - MAX_OFFSET_AHEAD,
+            latest: local + MAX_OFFSET_BEHIND + span,
+        },
+    })
+}
+
+/// When the content was captured: EXIF DateTimeOriginal, else IPTC Date
+/// Created
+fn capture_time(result: &C2paAnalysisResult) -> Option<(FindingValue, TimeRange)> {
+    let exif = result.exif_info.as_ref().and_then(|exif| {
+        let text = exif.date_time_original.as_deref()?;
+        let offset = exif.offset_time_original.as_deref();
+        let range = parse_time(text, offset)?;
+        let written = match offset {
+            Some(offset) => format!("{} {}", text, offset),
+            None => text.to_string(),
+        };
+        Some((value("exif", "DateTimeOriginal", written), range))
+    });
+    exif.or_else(|| {
+        let text = result.iptc_info.as_ref()?.date_created.as_deref()?;
+        Some((
+            value("iptc", "2:55 Date Created", text),
+            parse_time(text, None)?,
+        ))
+    })
+}
+
+/// When the active manifest was signed: the RFC 3161 token's time when
+/// there is one, else the time the signature records
+fn signing_time(result: &C2paAnalysisResult) -> Option<(FindingValue, TimeRange)> {
+    let signer = result.signer.as_ref()?;
+    if let Some(token) = &signer.timestamp_token {
+        let range = TimeRange {
+            earliest: token.gen_time,
+            latest: token.gen_time,
+        };
+        return Some((
+            value(
+                "c2pa",
+                "timestamp_token.gen_time",
+                token.gen_time.to_string(),
+            ),
+            range,
+        ));
+    }
+    let text = signer.timestamp.as_deref()?;
+    Some((
+        value("c2pa", "signer.timestamp", text),
+        parse_time(text, None)?,
+    ))
+}
+
+fn check_times(result: &C2paAnalysisResult, findings: &mut Vec<ConsistencyFinding>) {
+    let Some((captured, capture)) = capture_time(result) else {
+        return;
+    };
+    if let Some((signed, signing)) = signing_time(result) {
+        if capture.earliest > signing.latest + CLOCK_SKEW {
+            findings.push(ConsistencyFinding {
+                kind: InconsistencyKind::CaptureAfterSigning,
+                severity: FindingSeverity::Warning,
+                explanation: format!(
+                    "The content was captured at {}, after the manifest was signed at {}",
+                    captured.value, signed.value
+                ),
+                values: vec![captured.clone(), signed],
+            });
+        }
+    }
+    for (index, action) in result.actions.iter().enumerate() {
+        let Some(when) = action.when.as_deref() else {
+            continue;
+        };
+        let Some(done) = parse_time(when, None) else {
+            continue;
+        };
+        if done.latest + CLOCK_SKEW < capture.earliest {
+            findings.push(ConsistencyFinding {
+                kind: InconsistencyKind::ActionBeforeCapture,
+                severity: FindingSeverity::Warning,
+                explanation: format!(
+                    "The {} action is dated {}, before the content was captured at {}",
+                    action.action, when, captured.value
+                ),
+                values: vec![
+                    captured.clone(),
+                    value("c2pa", &format!("actions[{}].when", index), when),
+                ],
+            });
+        }
+    }
+}
+
+/// Exposure time, aperture, ISO, focal length and MakerNotes: what a camera
+/// writes and a converter or generator copying its Make and Model rarely does
+fn has_sensor_data(exif: &ExifInfo) -> bool {
+    let exposure = exif
+        .exposure
+        .as_ref()
+        .is_some_and(|e| e.exposure_time.is_some() || e.f_number.is_some() || e.iso.is_some());
+    let focal_length = exif.lens.as_ref().is_some_and(|l| l.focal_length.is_some());
+    exposure || focal_length || exif.maker_note.is_some()
+}
+
+fn check_camera(exif: &ExifInfo, findings: &mut Vec<ConsistencyFinding>) {
+    if has_sensor_data(exif) {
+        return;
+    }
+    let values: Vec<FindingValue> = [("Make", &exif.make), ("Model", &exif.model)]
+        .into_iter()
+        .filter_map(|(field, text)| Some(value("exif", field, text.as_deref()?)))
+        .collect();
+    if values.is_empty() {
+        return;
+    }
+    let camera = values
+        .iter()
+        .map(|v| v.value.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    findings.push(ConsistencyFinding {
+        kind: InconsistencyKind::CameraWithoutSensorData,
+        severity: FindingSeverity::Info,
+        explanation: format!(
+            "EXIF names the camera {} but records no exposure time, aperture, ISO, focal length or MakerNote; scanners and some exporters omit these too",
+            camera
+        ),
+        values,
+    });
+}
+
+/// The lowercase words of a producer string that could name a product:
+/// version numbers, platforms and library names are dropped
+fn product_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 2)
+        .filter(|word| !word.chars().all(|c| c.is_ascii_digit()))
+        .filter(|word| !(word.starts_with('v') && word[1..].chars().all(|c| c.is_ascii_digit())))
+        .filter(|word| !NON_PRODUCT_WORDS.contains(word))
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// Whether two producer strings could name the same tool. Strings that name
+/// no product, such as a bare firmware version, agree with anything.
+fn same_producer(a: &str, b: &str) -> bool {
+    let a = product_words(a);
+    let b = product_words(b);
+    a.is_empty() || b.is_empty() || a.iter().any(|word| b.contains(word))
+}
+
+fn check_software(result: &C2paAnalysisResult, findings: &mut Vec<ConsistencyFinding>) {
+    let Some(claim_generator) = result.claim_generator.as_deref() else {
+        return;
+    };
+    let software = result
+        .exif_info
+        .as_ref()
+        .and_then(|exif| exif.software.as_deref())
+        .map(|text| ("exif", "Software", "EXIF Software", text));
+    let creator_tool = result
+        .xmp_info
+        .as_ref()
+        .and_then(|xmp| xmp.creator_tool.as_deref())
+        .map(|text| ("xmp", "xmp:CreatorTool", "XMP CreatorTool", text));
+    for (source, field, name, text) in software.into_iter().chain(creator_tool) {
+        if same_producer(text, claim_generator) {
+            continue;
+        }
+        findings.push(ConsistencyFinding {
+            kind: InconsistencyKind::SoftwareMismatch,
+            severity: FindingSeverity::Info,
+            explanation: format!(
+                "{} names \"{}\" but the manifest's claim generator is \"{}\"",
+                name, text, claim_generator
+            ),
+            values: vec![
+                value(source, field, text),
+                value("c2pa", "claim_generator", claim_generator),
+            ],
+        });
+    }
+}
+
+fn check_thumbnail(
+    exif: &ExifInfo,
+    image_size: Option<(u32, u32)>,
+    findings: &mut Vec<ConsistencyFinding>,
+) {
+    let Some(thumbnail) = &exif.thumbnail else {
+        return;
+    };
+    let (Some(thumb_width), Some(thumb_height)) = (thumbnail.width, thumbnail.height) else {
+        return;
+    };
+    // The EXIF size is what the writer claimed; prefer the image's own
+    let image = match (image_size, exif.image_width, exif.image_height) {
+        (Some((width, height)), _, _) => Some(("image", "frame size", width, height)),
+        (None, Some(width), Some(height)) => {
+            Some(("exif", "PixelXDimension × PixelYDimension", width, height))
+        }
+        _ => None,
+    };
+    let Some((source, field, width, height)) = image else {
+        return;
+    };
+    if thumb_width == 0 || thumb_height == 0 || width == 0 || height == 0 {
+        return;
+    }
+    let thumb_aspect = thumb_width as f64 / thumb_height as f64;
+    let aspect = width as f64 / height as f64;
+    if thumb_aspect.max(aspect) / thumb_aspect.min(aspect) - 1.0 <= ASPECT_TOLERANCE {
+        return;
+    }
+    findings.push(ConsistencyFinding {
+        kind: InconsistencyKind::ThumbnailMismatch,
+        severity: FindingSeverity::Warning,
+        explanation: format!(
+            "The EXIF thumbnail is {}×{} but the image is {}×{}; the image may have been cropped or replaced without updating its thumbnail",
+            thumb_width, thumb_height, width, height
+        ),
+        values: vec![
+            value(
+                "exif",
+                "thumbnail size",
+                format!("{}×{}", thumb_width, thumb_height),
+            ),
+            value(source, field, format!("{}×{}", width, height)),
+        ],
+    });
+}
+
+fn check_thumbnail_content(
+    comparison: &ThumbnailComparison,
+    findings: &mut Vec<ConsistencyFinding>,
+) {
+    if !comparison.content_differs {
+        return;
+    }
+    let percent = (comparison.changed_fraction * 100.0).round();
+    let region = comparison.changed_region.map(|r| {
+        value(
+            "image",
+            "changed region",
+            format!("{}×{} at ({}, {})", r.width, r.height, r.x, r.y),
+        )
+    });
+    findings.push(ConsistencyFinding {
+        kind: InconsistencyKind::ThumbnailContentMismatch,
+        severity: FindingSeverity::Warning,
+        explanation: format!(
+            "The EXIF thumbnail differs from the image in {}% of its area; the image may have been edited after the thumbnail was written",
+            percent
+        ),
+        values: std::iter::once(value(
+            "exif",
+            "thumbnail",
+            format!("{}% changed", percent),
+        ))
+        .chain(region)
+        .collect(),
+    });
+}
+
+/// Compare the metadata sources in an analysis result. `image_size` is the
+/// width and height read from the image itself, when its format allows.
+pub(crate) fn check_consistency(
+    result: &C2paAnalysisResult,
+    image_size: Option<(u32, u32)>,
+) -> Vec<ConsistencyFinding> {
+    let mut findings = Vec::new();
+    check_times(result, &mut findings);
+    if let Some(exif) = &result.exif_info {
+        check_camera(exif, &mut findings);
+    }
+    check_software(result, &mut findings);
+    if let Some(exif) = &result.exif_info {
+        check_thumbnail(exif, image_size, &mut findings);
+    }
+    // A reshaped image differs from its thumbnail anyway; report it once
+    let reshaped = findings
+        .iter()
+        .any(|f| f.kind == InconsistencyKind::ThumbnailMismatch);
+    if let (Some(comparison), false) = (&result.thumbnail_comparison, reshaped) {
+        check_thumbnail_content(comparison, &mut findings);
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::actions::ContentAction;
+    use crate::api::c2pa_reader::SignerInfo;
+    use crate::api::exif_metadata::{ExifThumbnail, ExposureInfo};
+    use crate::api::thumbnail_comparison::ImageRegion;
+
+    fn camera_exif() -> ExifInfo {
+        ExifInfo {
+            make: Some("Canon".to_string()),
+            model: Some("Canon EOS R5".to_string()),
+            date_time_original: Some("2024-03-02 14:11:05".to_string()),
+            offset_time_original: Some("+01:00".to_string()),
+            exposure: Some(ExposureInfo {
+                exposure_time: Some(1.0 / 250.0),
+                f_number: Some(2.8),
+                iso: Some(200),
+                ..ExposureInfo::default()
+            }),
+            ..ExifInfo::default()
+        }
+    }
+
+    fn signed_at(result: &mut C2paAnalysisResult, time: &str) {
+        result.signer = Some(SignerInfo {
+            name: None,
+            organization: None,
+            issued_by: None,
+            timestamp: Some(time.to_string()),
+            certificate_chain: vec![],
+            timestamp_token: None,
+        });
+    }
+
+    fn action(name: &str, when: &str) -> ContentAction {
+        ContentAction {
+            action: name.to_string(),
+            software_agent: None,
+            software_agent_info: None,
+            when: Some(when.to_string()),
+            description: None,
+            digital_source_type: None,
+            reason: None,
+            ingredients: vec![],
+            parameters: vec![],
+            changes: vec![],
+            related: vec![],
+        }
+    }
+
+    fn kinds(findings: &[ConsistencyFinding]) -> Vec<InconsistencyKind> {
+        findings.iter().map(|f| f.kind).collect()
+    }
+
+    #[test]
+    fn parses_exif_rfc3339_and_iptc_times() {
+        let exif = parse_time("2024-03-02 14:11:05", Some("+01:00")).unwrap();
+        let rfc = parse_time("2024-03-02T13:11:05.250Z", None).unwrap();
+        let iptc = parse_time("20240302T141105+0100", None).unwrap();
+        assert_eq!(exif, rfc);
+        assert_eq!(exif, iptc);
+        assert_eq!(exif.earliest, 1709385065);
+
+        // Without an offset the reading could be in any time zone
+        let local = parse_time("2024-03-02 14:11:05", None).unwrap();
+        assert_eq!(local.earliest, exif.earliest + 3600 - 14 * 3600);
+        assert_eq!(local.latest, exif.earliest + 3600 + 12 * 3600);
+
+        let day = parse_time("20240302", Some("+00:00")).unwrap();
+        assert_eq!(day.latest - day.earliest, 86399);
+
+        assert_eq!(parse_time("    :  :     ", None), None);
+        assert_eq!(parse_time("2024-13-02 14:11:05", None), None);
+    }
+
+    #[test]
+    fn flags_capture_after_signing_and_actions_before_capture() {
+        let mut result = C2paAnalysisResult::no_manifest();
+        result.exif_info = Some(camera_exif());
+        signed_at(&mut result, "2024-03-02T12:00:00+00:00");
+        result.actions = vec![
+            action("c2pa.created", "2024-03-02T13:11:07Z"),
+            action("c2pa.edited", "2024-03-01T09:00:00Z"),
+        ];
+
+        let findings = check_consistency(&result, None);
+        assert_eq!(
+            kinds(&findings),
+            [
+                InconsistencyKind::CaptureAfterSigning,
+                InconsistencyKind::ActionBeforeCapture
+            ]
+        );
+        assert_eq!(findings[0].values[0].value, "2024-03-02 14:11:05 +01:00");
+        assert_eq!(findings[1].values[1].field, "actions[1].when");
+
+        // Signed ten minutes after capture: consistent
+        signed_at(&mut result, "2024-03-02T13:21:05Z");
+        result.actions.clear();
+        assert!(check_consistency(&result, None).is_empty());
+    }
+
+    #[test]
+    fn unknown_offsets_are_not_flagged_within_a_day() {
+        let mut result = C2paAnalysisResult::no_manifest();
+        result.exif_info = Some(ExifInfo {
+            offset_time_original: None,
+            ..camera_exif()
+        });
+        // 14:11 local could be 00:11 UTC in UTC+14
+        signed_at(&mut result, "2024-03-02T06:00:00Z");
+        assert!(check_consistency(&result, None).is_empty());
+    }
+
+    #[test]
+    fn flags_camera_without_sensor_data() {
+        let mut result = C2paAnalysisResult::no_manifest();
+        result.exif_info = Some(ExifInfo {
+            exposure: None,
+            ..camera_exif()
+        });
+        let findings = check_consistency(&result, None);
+        assert_eq!(
+            kinds(&findings),
+            [InconsistencyKind::CameraWithoutSensorData]
+        );
+        assert_eq!(findings[0].severity, FindingSeverity::Info);
+        assert_eq!(findings[0].values.len(), 2);
+    }
+
+    #[test]
+    fn compares_software_with_claim_generator() {
+        let mut result = C2paAnalysisResult::no_manifest();
+        result.claim_generator =
+            Some("Adobe_Photoshop/25.4.0 adobe_c2pa/0.9.0 c2pa-rs/0.29.2".to_string());
+        result.exif_info = Some(ExifInfo {
+            software: Some("Adobe Photoshop 25.4 (Windows)".to_string()),
+            ..camera_exif()
+        });
+        assert!(check_consistency(&result, None).is_empty());
+
+        result.exif_info.as_mut().unwrap().software = Some("GIMP 2.10.36".to_string());
+        let findings = check_consistency(&result, None);
+        assert_eq!(kinds(&findings), [InconsistencyKind::SoftwareMismatch]);
+        assert_eq!(findings[0].values[0].value, "GIMP 2.10.36");
+
+        // A bare firmware version names no tool
+        result.claim_generator = Some("Leica Camera AG M11-P".to_string());
+        result.exif_info.as_mut().unwrap().software = Some("Ver.2.0.1".to_string());
+        assert!(check_consistency(&result, None).is_empty());
+    }
+
+    #[test]
+    fn flags_thumbnails_of_a_different_shape() {
+        let thumbnail = |width, height| ExifThumbnail {
+            mime_type: "image/jpeg".to_string(),
+            width: Some(width),
+            height: Some(height),
+            data: vec![],
+        };
+        let mut result = C2paAnalysisResult::no_manifest();
+        result.exif_info = Some(ExifInfo {
+            image_width: Some(6000),
+            image_height: Some(4000),
+            thumbnail: Some(thumbnail(160, 120)),
+            ..camera_exif()
+        });
+        // A letterboxed 4:3 thumbnail of a 3:2 frame is normal
+        assert!(check_consistency(&result, None).is_empty());
+
+        // Cropped to a square, thumbnail left alone
+        let findings = check_consistency(&result, Some((4000, 4000)));
+        assert_eq!(kinds(&findings), [InconsistencyKind::ThumbnailMismatch]);
+        assert_eq!(findings[0].values[1].source, "image");
+        assert_eq!(findings[0].values[1].value, "4000×4000");
+
+        result.exif_info.as_mut().unwrap().thumbnail = Some(thumbnail(120, 160));
+        let findings = check_consistency(&result, None);
+        assert_eq!(kinds(&findings), [InconsistencyKind::ThumbnailMismatch]);
+        assert_eq!(findings[0].values[1].source, "exif");
+    }
+
+    #[test]
+    fn reports_thumbnail_content_once() {
+        let mut comparison = ThumbnailComparison {
+            compared_width: 160,
+            compared_height: 107,
+            difference: 0.15,
+            changed_fraction: 0.057,
+            changed_region: Some(ImageRegion {
+                x: 96,
+                y: 224,
+                width: 96,
+                height: 160,
+            }),
+            content_differs: true,
+        };
+        let mut result = C2paAnalysisResult::no_manifest();
+        result.thumbnail_comparison = Some(comparison.clone());
+        let findings = check_consistency(&result, None);
+        assert_eq!(
+            kinds(&findings),
+            [InconsistencyKind::ThumbnailContentMismatch]
+        );
+        assert_eq!(findings[0].values[0].value, "6% changed");
+        assert_eq!(findings[0].values[1].value, "96×160 at (96, 224)");
+
+        // Already reported as a change of shape
+        result.exif_info = Some(ExifInfo {
+            thumbnail: Some(ExifThumbnail {
+                mime_type: "image/jpeg".to_string(),
+                width: Some(160),
+                height: Some(120),
+                data: vec![],
+            }),
+            ..camera_exif()
+        });
+        let findings = check_consistency(&result, Some((4000, 4000)));
+        assert_eq!(kinds(&findings), [InconsistencyKind::ThumbnailMismatch]);
+
+        comparison.content_differs = false;
+        result.thumbnail_comparison = Some(comparison);
+        assert!(check_consistency(&result, None).is_empty());
+    }
+}
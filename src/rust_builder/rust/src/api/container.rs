@@ -0,0 +1,185 @@
+//! Metadata blocks in JPEG segments and TIFF tags, read from the stream
+//! without loading image data.
+
+use std::io::{Read, Seek, SeekFrom};
+
+/// Blocks larger than this are skipped
+pub(crate) const MAX_BLOCK: u64 = 16 * 1024 * 1024;
+
+/// Read `len` bytes, refusing lengths over [`MAX_BLOCK`]
+pub(crate) fn read_bytes<R: Read>(stream: &mut R, len: u64) -> Option<Vec<u8>> {
+    if len > MAX_BLOCK {
+        return None;
+    }
+    let mut data = vec![0u8; len as usize];
+    stream.read_exact(&mut data).ok()?;
+    Some(data)
+}
+
+/// Payloads of every JPEG segment with the given marker (e.g. 0xe1 for
+/// APP1) before the first scan, in file order
+pub(crate) fn jpeg_segments<R: Read + Seek>(stream: &mut R, wanted: u8) -> Vec<Vec<u8>> {
+    let mut segments = Vec::new();
+    let mut soi = [0u8; 2];
+    if stream.rewind().is_err() || stream.read_exact(&mut soi).is_err() || soi != [0xff, 0xd8] {
+        return segments;
+    }
+    let mut marker = [0u8; 2];
+    while stream.read_exact(&mut marker).is_ok() && marker[0] == 0xff {
+        match marker[1] {
+            // Fill bytes before a marker
+            0xff => {
+                if stream.seek(SeekFrom::Current(-1)).is_err() {
+                    break;
+                }
+                continue;
+            }
+            // Standalone markers carry no length
+            0x01 | 0xd0..=0xd7 => continue,
+            // Start of scan or end of image: no metadata follows
+            0xd9 | 0xda => break,
+            _ => {}
+        }
+        let mut len = [0u8; 2];
+        if stream.read_exact(&mut len).is_err() {
+            break;
+        }
+        let len = u16::from_be_bytes(len).saturating_sub(2) as u64;
+        if marker[1] != wanted {
+            if stream.seek(SeekFrom::Current(len as i64)).is_err() {
+                break;
+            }
+            continue;
+        }
+        match read_bytes(stream, len) {
+            Some(segment) => segments.push(segment),
+            None => break,
+        }
+    }
+    segments
+}
+
+/// The value bytes of a BYTE, UNDEFINED or LONG array tag in a TIFF's first
+/// IFD, e.g. XMLPacket (700) or IPTC-NAA (33723)
+pub(crate) fn tiff_tag<R: Read + Seek>(stream: &mut R, tag: u16) -> Option<Vec<u8>> {
+    let mut header = [0u8; 8];
+    stream.rewind().ok()?;
+    stream.read_exact(&mut header).ok()?;
+    let little_endian = match &header[..4] {
+        b"II*\0" => true,
+        b"MM\0*" => false,
+        _ => return None,
+    };
+    let u16_at = |b: &[u8]| {
+        let b = [b[0], b[1]];
+        if little_endian {
+            u16::from_le_bytes(b)
+        } else {
+            u16::from_be_bytes(b)
+        }
+    };
+    let u32_at = |b: &[u8]| {
+        let b = [b[0], b[1], b[2], b[3]];
+        if little_endian {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        }
+    };
+
+    stream
+        .seek(SeekFrom::Start(u32_at(&header[4..]) as u64))
+        .ok()?;
+    let mut count = [0u8; 2];
+    stream.read_exact(&mut count).ok()?;
+    let entries = read_bytes(stream, u16_at(&count) as u64 * 12)?;
+    let entry = entries.chunks_exact(12).find(|e| u16_at(&e[0..2]) == tag)?;
+    // Writers store IPTC-NAA as LONG as often as UNDEFINED
+    let unit = match u16_at(&entry[2..4]) {
+        1 | 2 | 7 => 1,
+        4 => 4,
+        _ => return None,
+    };
+    let len = u32_at(&entry[4..8]) as u64 * unit;
+    if len <= 4 {
+        return Some(entry[8..8 + len as usize].to_vec());
+    }
+    stream
+        .seek(SeekFrom::Start(u32_at(&entry[8..12]) as u64))
+        .ok()?;
+    read_bytes(stream, len)
+}
+
+/// Width and height from a JPEG's start-of-frame header
+pub(crate) fn jpeg_dimensions<R: Read + Seek>(stream: &mut R) -> Option<(u32, u32)> {
+    let mut soi = [0u8; 2];
+    stream.rewind().ok()?;
+    stream.read_exact(&mut soi).ok()?;
+    if soi != [0xff, 0xd8] {
+        return None;
+    }
+    let mut marker = [0u8; 2];
+    loop {
+        stream.read_exact(&mut marker).ok()?;
+        if marker[0] != 0xff {
+            return None;
+        }
+        match marker[1] {
+            0xff => {
+                stream.seek(SeekFrom::Current(-1)).ok()?;
+                continue;
+            }
+            0x01 | 0xd0..=0xd7 => continue,
+            0xd9 | 0xda => return None,
+            _ => {}
+        }
+        let mut len = [0u8; 2];
+        stream.read_exact(&mut len).ok()?;
+        let len = u16::from_be_bytes(len).saturating_sub(2) as i64;
+        // SOF0–SOF15, except DHT, JPG and DAC which share the range
+        if matches!(marker[1], 0xc0..=0xcf) && !matches!(marker[1], 0xc4 | 0xc8 | 0xcc) {
+            let mut frame = [0u8; 5];
+            stream.read_exact(&mut frame).ok()?;
+            let height = u16::from_be_bytes([frame[1], frame[2]]) as u32;
+            let width = u16::from_be_bytes([frame[3], frame[4]]) as u32;
+            return Some((width, height));
+        }
+        stream.seek(SeekFrom::Current(len)).ok()?;
+    }
+}
+
+/// Pixel width and height of a JPEG, PNG or extended-format WebP, read from
+/// its header
+pub(crate) fn image_dimensions<R: Read + Seek>(
+    stream: &mut R,
+    mime_type: &str,
+) -> Option<(u32, u32)> {
+    match mime_type {
+        "image/jpeg" => jpeg_dimensions(stream),
+        "image/png" => {
+            // Signature, then IHDR's length and type, then width and height
+            let mut header = [0u8; 24];
+            stream.rewind().ok()?;
+            stream.read_exact(&mut header).ok()?;
+            if &header[12..16] != b"IHDR" {
+                return None;
+            }
+            let width = u32::from_be_bytes([header[16], header[17], header[18], header[19]]);
+            let height = u32::from_be_bytes([header[20], header[21], header[22], header[23]]);
+            Some((width, height))
+        }
+        "image/webp" => {
+            // RIFF header, then a VP8X chunk whose canvas size is stored minus one
+            let mut header = [0u8; 30];
+            stream.rewind().ok()?;
+            stream.read_exact(&mut header).ok()?;
+            if &header[12..16] != b"VP8X" {
+                return None;
+            }
+            let width = u32::from_le_bytes([header[24], header[25], header[26], 0]) + 1;
+            let height = u32::from_le_bytes([header[27], header[28], header[29], 0]) + 1;
+            Some((width, height))
+        }
+        _ => None,
+    }
+}
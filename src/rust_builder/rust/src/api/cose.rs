@@ -0,0 +1,193 @@
+//! Headers and signed structures of COSE_Sign1 claim signatures read from the manifest store
+
+use serde_cbor::Value;
+use std::collections::BTreeMap;
+
+/// Unprotected then protected header maps of a COSE_Sign1 structure
+pub(crate) fn headers(cose: &[u8]) -> Vec<BTreeMap<Value, Value>> {
+    let Ok(Value::Array(items)) = serde_cbor::from_slice::<Value>(cose) else {
+        return vec![];
+    };
+
+    let mut headers = Vec::new();
+    if let Some(Value::Map(unprotected)) = items.get(1) {
+        headers.push(unprotected.clone());
+    }
+    if let Some(Value::Bytes(protected)) = items.first() {
+        if let Ok(Value::Map(map)) = serde_cbor::from_slice::<Value>(protected) {
+            headers.push(map);
+        }
+    }
+    headers
+}
+
+/// Look up a text-keyed entry in a CBOR map
+pub(crate) fn get<'a>(map: &'a BTreeMap<Value, Value>, key: &str) -> Option<&'a Value> {
+    map.get(&Value::Text(key.to_string()))
+}
+
+/// The parts of a COSE_Sign1 structure that its signature and any
+/// countersignature cover
+pub(crate) struct Sign1Parts {
+    /// The serialized protected header, exactly as signed
+    pub protected: Vec<u8>,
+    /// The payload, or `None` when it is detached. C2PA claim signatures are
+    /// detached; their payload is the manifest's claim.
+    pub payload: Option<Vec<u8>>,
+    pub signature: Vec<u8>,
+}
+
+pub(crate) fn sign1_parts(cose: &[u8]) -> Option<Sign1Parts> {
+    let Ok(Value::Array(items)) = serde_cbor::from_slice::<Value>(cose) else {
+        return None;
+    };
+    let payload = match items.get(2)? {
+        Value::Bytes(payload) => Some(payload.clone()),
+        Value::Null => None,
+        _ => return None,
+    };
+    match (items.first()?, items.get(3)?) {
+        (Value::Bytes(protected), Value::Bytes(signature)) => Some(Sign1Parts {
+            protected: protected.clone(),
+            payload,
+            signature: signature.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// The `Sig_structure` a COSE countersignature signs, with no external data
+/// (RFC 8152 section 4.4). c2pa-rs leaves the countersigner's own protected
+/// header out, as coset does when there is none; the full form carries it as
+/// an empty byte string.
+pub(crate) fn countersignature_data(
+    body_protected: &[u8],
+    payload: &[u8],
+    with_sign_protected: bool,
+) -> Vec<u8> {
+    let mut structure = vec![
+        Value::Text("CounterSignature".to_string()),
+        Value::Bytes(body_protected.to_vec()),
+    ];
+    if with_sign_protected {
+        structure.push(Value::Bytes(vec![]));
+    }
+    structure.push(Value::Bytes(vec![]));
+    structure.push(Value::Bytes(payload.to_vec()));
+    serde_cbor::to_vec(&Value::Array(structure)).unwrap_or_default()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn text(s: &str) -> Value {
+        Value::Text(s.to_string())
+    }
+
+    /// Encode a COSE_Sign1 array with a detached payload
+    pub(crate) fn sign1(
+        protected: BTreeMap<Value, Value>,
+        unprotected: BTreeMap<Value, Value>,
+        signature: &[u8],
+    ) -> Vec<u8> {
+        let protected = serde_cbor::to_vec(&Value::Map(protected)).unwrap();
+        serde_cbor::to_vec(&Value::Array(vec![
+            Value::Bytes(protected),
+            Value::Map(unprotected),
+            Value::Null,
+            Value::Bytes(signature.to_vec()),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn headers_are_unprotected_then_protected() {
+        let protected = BTreeMap::from([(text("alg"), text("protected"))]);
+        let unprotected = BTreeMap::from([(text("alg"), text("unprotected"))]);
+        let cose = sign1(protected, unprotected, b"sig");
+
+        let headers = headers(&cose);
+        assert_eq!(headers.len(), 2);
+        assert_eq!(get(&headers[0], "alg"), Some(&text("unprotected")));
+        assert_eq!(get(&headers[1], "alg"), Some(&text("protected")));
+        assert_eq!(get(&headers[1], "missing"), None);
+        // Integer labels are not matched by text lookups
+        let numeric = BTreeMap::from([(Value::Integer(1), text("x"))]);
+        assert_eq!(get(&numeric, "1"), None);
+    }
+
+    #[test]
+    fn sign1_parts_are_read() {
+        let protected = BTreeMap::from([(Value::Integer(1), Value::Integer(-7))]);
+        let cose = sign1(protected.clone(), BTreeMap::new(), b"signature bytes");
+        let parts = sign1_parts(&cose).unwrap();
+        assert_eq!(
+            parts.protected,
+            serde_cbor::to_vec(&Value::Map(protected)).unwrap()
+        );
+        assert_eq!(parts.payload, None);
+        assert_eq!(parts.signature, b"signature bytes");
+
+        let attached = serde_cbor::to_vec(&Value::Array(vec![
+            Value::Bytes(vec![0xa0]),
+            Value::Map(BTreeMap::new()),
+            Value::Bytes(b"payload".to_vec()),
+            Value::Bytes(b"signature".to_vec()),
+        ]))
+        .unwrap();
+        let parts = sign1_parts(&attached).unwrap();
+        assert_eq!(parts.payload.as_deref(), Some(&b"payload"[..]));
+
+        let no_signature = serde_cbor::to_vec(&Value::Array(vec![Value::Null; 4])).unwrap();
+        assert!(sign1_parts(&no_signature).is_none());
+        let short = serde_cbor::to_vec(&Value::Array(vec![Value::Null; 3])).unwrap();
+        assert!(sign1_parts(&short).is_none());
+    }
+
+    #[test]
+    fn countersignature_data_is_a_sig_structure() {
+        // ["CounterSignature", h'A10126', h'', h'7061796C6F6164']
+        let expected = [
+            &[0x84, 0x70][..],
+            b"CounterSignature",
+            &[0x43, 0xa1, 0x01, 0x26, 0x40, 0x47],
+            b"payload",
+        ]
+        .concat();
+        assert_eq!(
+            countersignature_data(&[0xa1, 0x01, 0x26], b"payload", false),
+            expected
+        );
+
+        // With the empty sign_protected field before the external data
+        let expected = [
+            &[0x85, 0x70][..],
+            b"CounterSignature",
+            &[0x43, 0xa1, 0x01, 0x26, 0x40, 0x40, 0x47],
+            b"payload",
+        ]
+        .concat();
+        assert_eq!(
+            countersignature_data(&[0xa1, 0x01, 0x26], b"payload", true),
+            expected
+        );
+    }
+
+    #[test]
+    fn malformed_structures_have_no_headers() {
+        assert!(headers(b"not cbor").is_empty());
+        assert!(sign1_parts(b"not cbor").is_none());
+
+        let map = serde_cbor::to_vec(&Value::Map(BTreeMap::new())).unwrap();
+        assert!(headers(&map).is_empty());
+
+        // A protected header that does not decode to a map is skipped
+        let cose = serde_cbor::to_vec(&Value::Array(vec![
+            Value::Bytes(b"\xff".to_vec()),
+            Value::Map(BTreeMap::from([(text("k"), text("v"))])),
+        ]))
+        .unwrap();
+        assert_eq!(headers(&cose).len(), 1);
+    }
+}
@@ -0,0 +1,337 @@
+//! Minimal DER reader for the CMS and OCSP structures x509-parser does not cover
+
+pub(crate) const TAG_INTEGER: u8 = 0x02;
+pub(crate) const TAG_BIT_STRING: u8 = 0x03;
+pub(crate) const TAG_OCTET_STRING: u8 = 0x04;
+pub(crate) const TAG_OID: u8 = 0x06;
+pub(crate) const TAG_ENUMERATED: u8 = 0x0a;
+pub(crate) const TAG_UTC_TIME: u8 = 0x17;
+pub(crate) const TAG_GENERALIZED_TIME: u8 = 0x18;
+pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+pub(crate) const TAG_SET: u8 = 0x31;
+
+/// Context-specific constructed tag, e.g. `context(0)` is `[0]`
+pub(crate) const fn context(n: u8) -> u8 {
+    0xa0 | n
+}
+
+/// Context-specific primitive tag, e.g. `context_primitive(0)` is implicit `[0]`
+pub(crate) const fn context_primitive(n: u8) -> u8 {
+    0x80 | n
+}
+
+/// A single DER element
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Tlv<'a> {
+    pub tag: u8,
+    pub content: &'a [u8],
+    /// The full encoding including tag and length
+    pub raw: &'a [u8],
+}
+
+/// Read one element, returning it and the remaining input
+pub(crate) fn read_tlv(input: &[u8]) -> Option<(Tlv<'_>, &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    // High tag numbers never appear in the structures parsed here
+    if tag & 0x1f == 0x1f {
+        return None;
+    }
+    let (&first, rest) = rest.split_first()?;
+    let (len, rest) = if first & 0x80 == 0 {
+        (first as usize, rest)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        (len, &rest[count..])
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let header_len = input.len() - rest.len();
+    let tlv = Tlv {
+        tag,
+        content: &rest[..len],
+        raw: &input[..header_len + len],
+    };
+    Some((tlv, &rest[len..]))
+}
+
+/// Read an element and require a specific tag
+pub(crate) fn expect_tlv(input: &[u8], tag: u8) -> Option<(Tlv<'_>, &[u8])> {
+    read_tlv(input).filter(|(tlv, _)| tlv.tag == tag)
+}
+
+impl<'a> Tlv<'a> {
+    /// Parse the content as a sequence of elements
+    pub(crate) fn children(&self) -> Option<Vec<Tlv<'a>>> {
+        let mut items = Vec::new();
+        let mut rest = self.content;
+        while !rest.is_empty() {
+            let (tlv, next) = read_tlv(rest)?;
+            items.push(tlv);
+            rest = next;
+        }
+        Some(items)
+    }
+
+    /// Content of an explicitly tagged element, e.g. the inner value of `[0] EXPLICIT`
+    pub(crate) fn inner(&self) -> Option<Tlv<'a>> {
+        read_tlv(self.content).map(|(tlv, _)| tlv)
+    }
+
+    pub(crate) fn as_oid(&self) -> Option<String> {
+        if self.tag != TAG_OID {
+            return None;
+        }
+        oid_to_string(self.content)
+    }
+
+    /// Seconds since the Unix epoch for UTCTime and GeneralizedTime values
+    pub(crate) fn as_time(&self) -> Option<i64> {
+        let text = std::str::from_utf8(self.content).ok()?;
+        match self.tag {
+            TAG_UTC_TIME => parse_time(text, false),
+            TAG_GENERALIZED_TIME => parse_time(text, true),
+            _ => None,
+        }
+    }
+}
+
+/// Dotted form of an encoded OID
+pub(crate) fn oid_to_string(content: &[u8]) -> Option<String> {
+    let mut arcs: Vec<u64> = Vec::new();
+    let mut value: u64 = 0;
+    for &b in content {
+        value = value.checked_mul(128)? | (b & 0x7f) as u64;
+        if b & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = (value / 40).min(2);
+                arcs.push(first);
+                arcs.push(value - first * 40);
+            } else {
+                arcs.push(value);
+            }
+            value = 0;
+        }
+    }
+    if arcs.is_empty() {
+        return None;
+    }
+    Some(
+        arcs.iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Parse "YYMMDDHHMMSSZ" (UTCTime) or "YYYYMMDDHHMMSS[.fff]Z" (GeneralizedTime)
+fn parse_time(text: &str, four_digit_year: bool) -> Option<i64> {
+    let text = text.strip_suffix('Z')?;
+    // Fractional seconds are dropped
+    let text = text.split('.').next()?;
+    let digits = |s: &str| -> Option<i64> {
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    };
+
+    let (year, rest) = if four_digit_year {
+        (digits(text.get(0..4)?)?, text.get(4..)?)
+    } else {
+        let yy = digits(text.get(0..2)?)?;
+        // RFC 5280: two-digit years 50-99 are 19xx
+        (if yy >= 50 { 1900 + yy } else { 2000 + yy }, text.get(2..)?)
+    };
+    if rest.len() != 10 {
+        return None;
+    }
+    let month = digits(&rest[0..2])? as u32;
+    let day = digits(&rest[2..4])? as u32;
+    let hour = digits(&rest[4..6])?;
+    let minute = digits(&rest[6..8])?;
+    let second = digits(&rest[8..10])?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second)
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// DER-encode one element, using the long length form when needed
+    pub(crate) fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+        let mut out = vec![tag];
+        let len = content.len();
+        if len < 0x80 {
+            out.push(len as u8);
+        } else {
+            let bytes: Vec<u8> = len
+                .to_be_bytes()
+                .into_iter()
+                .skip_while(|&b| b == 0)
+                .collect();
+            out.push(0x80 | bytes.len() as u8);
+            out.extend(bytes);
+        }
+        out.extend_from_slice(content);
+        out
+    }
+
+    pub(crate) fn seq(items: &[Vec<u8>]) -> Vec<u8> {
+        tlv(TAG_SEQUENCE, &items.concat())
+    }
+
+    pub(crate) fn oid(dotted: &str) -> Vec<u8> {
+        let arcs: Vec<u64> = dotted.split('.').map(|a| a.parse().unwrap()).collect();
+        let mut content = Vec::new();
+        for arc in std::iter::once(arcs[0] * 40 + arcs[1]).chain(arcs[2..].iter().copied()) {
+            let mut groups = vec![(arc & 0x7f) as u8];
+            let mut rest = arc >> 7;
+            while rest > 0 {
+                groups.push((rest & 0x7f) as u8 | 0x80);
+                rest >>= 7;
+            }
+            content.extend(groups.into_iter().rev());
+        }
+        tlv(TAG_OID, &content)
+    }
+
+    #[test]
+    fn short_and_long_lengths_are_read() {
+        let short = tlv(TAG_OCTET_STRING, b"abc");
+        let (element, rest) = read_tlv(&short).unwrap();
+        assert_eq!(element.tag, TAG_OCTET_STRING);
+        assert_eq!(element.content, b"abc");
+        assert_eq!(element.raw, short.as_slice());
+        assert!(rest.is_empty());
+
+        let content = vec![7u8; 300];
+        let mut long = tlv(TAG_OCTET_STRING, &content);
+        assert_eq!(&long[1..4], &[0x82, 0x01, 0x2c]);
+        long.extend_from_slice(b"tail");
+        let (element, rest) = read_tlv(&long).unwrap();
+        assert_eq!(element.content, content.as_slice());
+        assert_eq!(rest, b"tail");
+    }
+
+    #[test]
+    fn malformed_elements_are_rejected() {
+        // Content shorter than the declared length
+        assert!(read_tlv(&[TAG_OCTET_STRING, 4, 1, 2]).is_none());
+        // Long form with no length bytes, too many length bytes, or truncated
+        assert!(read_tlv(&[TAG_OCTET_STRING, 0x80]).is_none());
+        assert!(read_tlv(&[TAG_OCTET_STRING, 0x85, 0, 0, 0, 0, 1, 0]).is_none());
+        assert!(read_tlv(&[TAG_OCTET_STRING, 0x82, 0x01]).is_none());
+        // High tag number form
+        assert!(read_tlv(&[0x1f, 0x81, 0x00, 0x00]).is_none());
+        assert!(read_tlv(&[]).is_none());
+        assert!(read_tlv(&[TAG_SEQUENCE]).is_none());
+
+        assert!(expect_tlv(&tlv(TAG_SET, &[]), TAG_SEQUENCE).is_none());
+        assert!(expect_tlv(&tlv(TAG_SET, &[]), TAG_SET).is_some());
+    }
+
+    #[test]
+    fn children_and_explicit_tags_are_walked() {
+        let encoded = seq(&[
+            tlv(TAG_INTEGER, &[1]),
+            tlv(context(0), &tlv(TAG_INTEGER, &[2])),
+        ]);
+        let (outer, _) = read_tlv(&encoded).unwrap();
+        let children = outer.children().unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].content, &[1]);
+        assert_eq!(children[1].tag, context(0));
+        assert_eq!(children[1].inner().unwrap().content, &[2]);
+
+        // A child running past the end of its parent fails the whole walk
+        let broken = tlv(TAG_SEQUENCE, &[TAG_INTEGER, 5, 1]);
+        assert!(read_tlv(&broken).unwrap().0.children().is_none());
+    }
+
+    #[test]
+    fn oids_are_decoded() {
+        let encoded = oid("1.2.840.113549.1.7.2");
+        assert_eq!(
+            &encoded[2..],
+            &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x02]
+        );
+        let (element, _) = read_tlv(&encoded).unwrap();
+        assert_eq!(element.as_oid().as_deref(), Some("1.2.840.113549.1.7.2"));
+
+        // First-arc 2 allows second arcs above 39
+        assert_eq!(oid_to_string(&[0x88, 0x37]).as_deref(), Some("2.999"));
+        assert_eq!(oid_to_string(&[]), None);
+        // Only OID elements are decoded as OIDs
+        let integer = tlv(TAG_INTEGER, &[0x2a]);
+        assert_eq!(read_tlv(&integer).unwrap().0.as_oid(), None);
+    }
+
+    fn time(tag: u8, text: &str) -> Option<i64> {
+        let encoded = tlv(tag, text.as_bytes());
+        read_tlv(&encoded).unwrap().0.as_time()
+    }
+
+    #[test]
+    fn utc_and_generalized_times_are_parsed() {
+        // Two-digit years pivot at 50
+        assert_eq!(time(TAG_UTC_TIME, "500101000000Z"), Some(-631152000));
+        assert_eq!(time(TAG_UTC_TIME, "491231235959Z"), Some(2524607999));
+        // Fractional seconds are dropped
+        assert_eq!(
+            time(TAG_GENERALIZED_TIME, "20240229123456.789Z"),
+            Some(1709210096)
+        );
+        assert_eq!(
+            time(TAG_GENERALIZED_TIME, "20240229123456Z"),
+            Some(1709210096)
+        );
+
+        assert_eq!(time(TAG_GENERALIZED_TIME, "20241301000000Z"), None);
+        assert_eq!(time(TAG_GENERALIZED_TIME, "20240229123456"), None);
+        assert_eq!(time(TAG_UTC_TIME, "24022912345Z"), None);
+        assert_eq!(time(TAG_UTC_TIME, "2402291234+6Z"), None);
+        assert_eq!(time(TAG_OCTET_STRING, "500101000000Z"), None);
+    }
+
+    #[test]
+    fn civil_dates_count_from_the_epoch() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(
+            days_from_civil(2024, 3, 1) - days_from_civil(2024, 2, 28),
+            2
+        );
+    }
+
+    #[test]
+    fn hex_is_lowercase_without_separators() {
+        assert_eq!(hex(&[0x00, 0xab, 0x7f]), "00ab7f");
+        assert_eq!(hex(&[]), "");
+    }
+}
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+use super::evidence::{EvidenceKind, EvidenceStrength};
+
+/// Namespace of the IPTC NewsCodes digital source type vocabulary
+const IPTC_NAMESPACE: &str = "http://cv.iptc.org/newscodes/digitalsourcetype/";
+
+/// Typed IPTC NewsCodes digital source type, as declared by a `c2pa.actions`
+/// entry's `digitalSourceType`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DigitalSourceType {
+    // Captured from real life
+    DigitalCapture,
+    ComputationalCapture,
+    NegativeFilm,
+    PositiveFilm,
+    Print,
+    ScreenCapture,
+    VirtualRecording,
+
+    // Edited by people or conventional software
+    HumanEdits,
+    /// Deprecated by IPTC in favour of `HumanEdits`, still common in manifests
+    MinorHumanEdits,
+    AlgorithmicallyEnhanced,
+
+    // Created without a camera
+    /// Also covers the deprecated `digitalArt` and `softwareImage` terms
+    DigitalCreation,
+    DataDrivenMedia,
+    /// Produced by an algorithm that was not trained on sampled data
+    AlgorithmicMedia,
+    /// Produced by a model trained on sampled content: generative AI
+    TrainedAlgorithmicMedia,
+
+    // Composites
+    Composite,
+    CompositeCapture,
+    CompositeSynthetic,
+    CompositeWithTrainedAlgorithmicMedia,
+
+    /// A term outside the IPTC vocabulary, kept as written
+    Unknown { uri: String },
+}
+
+impl DigitalSourceType {
+    /// Parse a full IPTC URI ("http://cv.iptc.org/newscodes/digitalsourcetype/digitalCapture")
+    /// or a bare term. Terms are matched case-insensitively.
+    pub(crate) fn from_uri(uri: &str) -> Self {
+        use DigitalSourceType::*;
+        let value = uri.trim();
+        let term = value.rsplit('/').next().unwrap_or(value);
+        match term.to_ascii_lowercase().as_str() {
+            "digitalcapture" => DigitalCapture,
+            "computationalcapture" => ComputationalCapture,
+            "negativefilm" => NegativeFilm,
+            "positivefilm" => PositiveFilm,
+            "print" => Print,
+            "screencapture" => ScreenCapture,
+            "virtualrecording" => VirtualRecording,
+            "humanedits" => HumanEdits,
+            "minorhumanedits" => MinorHumanEdits,
+            "algorithmicallyenhanced" => AlgorithmicallyEnhanced,
+            "digitalcreation" | "digitalart" | "softwareimage" => DigitalCreation,
+            "datadrivenmedia" => DataDrivenMedia,
+            "algorithmicmedia" => AlgorithmicMedia,
+            "trainedalgorithmicmedia" => TrainedAlgorithmicMedia,
+            "composite" => Composite,
+            "compositecapture" => CompositeCapture,
+            "compositesynthetic" => CompositeSynthetic,
+            "compositewithtrainedalgorithmicmedia" => CompositeWithTrainedAlgorithmicMedia,
+            _ => Unknown {
+                uri: value.to_string(),
+            },
+        }
+    }
+
+    /// The IPTC term, e.g. "digitalCapture"
+    pub(crate) fn term(&self) -> &str {
+        use DigitalSourceType::*;
+        match self {
+            DigitalCapture => "digitalCapture",
+            ComputationalCapture => "computationalCapture",
+            NegativeFilm => "negativeFilm",
+            PositiveFilm => "positiveFilm",
+            Print => "print",
+            ScreenCapture => "screenCapture",
+            VirtualRecording => "virtualRecording",
+            HumanEdits => "humanEdits",
+            MinorHumanEdits => "minorHumanEdits",
+            AlgorithmicallyEnhanced => "algorithmicallyEnhanced",
+            DigitalCreation => "digitalCreation",
+            DataDrivenMedia => "dataDrivenMedia",
+            AlgorithmicMedia => "algorithmicMedia",
+            TrainedAlgorithmicMedia => "trainedAlgorithmicMedia",
+            Composite => "composite",
+            CompositeCapture => "compositeCapture",
+            CompositeSynthetic => "compositeSynthetic",
+            CompositeWithTrainedAlgorithmicMedia => "compositeWithTrainedAlgorithmicMedia",
+            Unknown { uri } => uri,
+        }
+    }
+
+    /// Full IPTC URI, or the original text for unknown terms
+    pub(crate) fn uri(&self) -> String {
+        match self {
+            DigitalSourceType::Unknown { uri } => uri.clone(),
+            known => format!("{}{}", IPTC_NAMESPACE, known.term()),
+        }
+    }
+
+    /// What the source type says about AI involvement. Hand-made digital art
+    /// and untrained algorithms are not AI; synthetic composites might be.
+    pub(crate) fn ai_involvement(&self) -> Option<(EvidenceKind, EvidenceStrength)> {
+        use DigitalSourceType::*;
+        match self {
+            TrainedAlgorithmicMedia => {
+                Some((EvidenceKind::AiGeneration, EvidenceStrength::Definitive))
+            }
+            CompositeWithTrainedAlgorithmicMedia => {
+                Some((EvidenceKind::AiEditing, EvidenceStrength::Definitive))
+            }
+            CompositeSynthetic => Some((EvidenceKind::AiEditing, EvidenceStrength::Weak)),
+            _ => None,
+        }
+    }
+
+    /// Whether the content was sampled from real life by a camera or scanner
+    pub(crate) fn is_capture(&self) -> bool {
+        use DigitalSourceType::*;
+        matches!(
+            self,
+            DigitalCapture | ComputationalCapture | NegativeFilm | PositiveFilm | Print
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DigitalSourceType::*;
+
+    type Ai = Option<(EvidenceKind, EvidenceStrength)>;
+
+    const GENERATION: Ai = Some((EvidenceKind::AiGeneration, EvidenceStrength::Definitive));
+    const EDITING: Ai = Some((EvidenceKind::AiEditing, EvidenceStrength::Definitive));
+    const MAYBE_EDITING: Ai = Some((EvidenceKind::AiEditing, EvidenceStrength::Weak));
+
+    // Every current IPTC term: (term, type, AI involvement, captured)
+    const VOCABULARY: &[(&str, DigitalSourceType, Ai, bool)] = &[
+        ("digitalCapture", DigitalCapture, None, true),
+        ("computationalCapture", ComputationalCapture, None, true),
+        ("negativeFilm", NegativeFilm, None, true),
+        ("positiveFilm", PositiveFilm, None, true),
+        ("print", Print, None, true),
+        ("screenCapture", ScreenCapture, None, false),
+        ("virtualRecording", VirtualRecording, None, false),
+        ("humanEdits", HumanEdits, None, false),
+        ("minorHumanEdits", MinorHumanEdits, None, false),
+        (
+            "algorithmicallyEnhanced",
+            AlgorithmicallyEnhanced,
+            None,
+            false,
+        ),
+        ("digitalCreation", DigitalCreation, None, false),
+        ("dataDrivenMedia", DataDrivenMedia, None, false),
+        ("algorithmicMedia", AlgorithmicMedia, None, false),
+        (
+            "trainedAlgorithmicMedia",
+            TrainedAlgorithmicMedia,
+            GENERATION,
+            false,
+        ),
+        ("composite", Composite, None, false),
+        ("compositeCapture", CompositeCapture, None, false),
+        (
+            "compositeSynthetic",
+            CompositeSynthetic,
+            MAYBE_EDITING,
+            false,
+        ),
+        (
+            "compositeWithTrainedAlgorithmicMedia",
+            CompositeWithTrainedAlgorithmicMedia,
+            EDITING,
+            false,
+        ),
+    ];
+
+    #[test]
+    fn vocabulary_round_trips_through_uris() {
+        for (term, source_type, _, _) in VOCABULARY {
+            let uri = format!("{}{}", IPTC_NAMESPACE, term);
+            assert_eq!(&DigitalSourceType::from_uri(&uri), source_type, "{}", uri);
+            assert_eq!(source_type.term(), *term);
+            assert_eq!(source_type.uri(), uri);
+            assert_eq!(&DigitalSourceType::from_uri(term), source_type, "{}", term);
+            assert_eq!(
+                &DigitalSourceType::from_uri(&format!(" {} ", uri.to_uppercase())),
+                source_type,
+                "{}",
+                term
+            );
+        }
+    }
+
+    #[test]
+    fn vocabulary_classifies_ai_and_capture() {
+        for (term, source_type, ai, capture) in VOCABULARY {
+            assert_eq!(source_type.ai_involvement(), *ai, "{}", term);
+            assert_eq!(source_type.is_capture(), *capture, "{}", term);
+            // Nothing is both captured and AI-made
+            assert!(!(source_type.is_capture() && source_type.ai_involvement().is_some()));
+        }
+    }
+
+    #[test]
+    fn deprecated_terms_are_digital_creation() {
+        for term in ["digitalArt", "softwareImage"] {
+            let source_type = DigitalSourceType::from_uri(&format!("{}{}", IPTC_NAMESPACE, term));
+            assert_eq!(source_type, DigitalCreation);
+            assert_eq!(source_type.ai_involvement(), None, "{} is not AI", term);
+            assert!(!source_type.is_capture());
+        }
+    }
+
+    #[test]
+    fn unknown_terms_are_kept_as_written() {
+        let uri = "https://example.com/sourcetype/aiUpscaled";
+        let source_type = DigitalSourceType::from_uri(uri);
+        assert_eq!(
+            source_type,
+            Unknown {
+                uri: uri.to_string()
+            }
+        );
+        assert_eq!(source_type.term(), uri);
+        assert_eq!(source_type.uri(), uri);
+        assert_eq!(source_type.ai_involvement(), None);
+        assert!(!source_type.is_capture());
+    }
+}
@@ -0,0 +1,575 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// A single attribute of an X.500 distinguished name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnAttribute {
+    /// Short name such as "CN" or "O", or the dotted OID when the type is not known
+    pub attr_type: String,
+    pub oid: String,
+    pub value: String,
+    /// Index of the RDN this attribute belongs to; multi-valued RDNs share an index
+    pub rdn_index: u32,
+}
+
+/// An X.500 distinguished name, with attributes in ASN.1 order (most significant first)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DistinguishedName {
+    pub attributes: Vec<DnAttribute>,
+    /// RFC 4514 string form, least significant RDN first
+    pub rfc4514: String,
+}
+
+// Attribute types with well-known short names
+const ATTRIBUTE_NAMES: &[(&str, &str)] = &[
+    ("2.5.4.3", "CN"),
+    ("2.5.4.4", "SN"),
+    ("2.5.4.5", "serialNumber"),
+    ("2.5.4.6", "C"),
+    ("2.5.4.7", "L"),
+    ("2.5.4.8", "ST"),
+    ("2.5.4.9", "STREET"),
+    ("2.5.4.10", "O"),
+    ("2.5.4.11", "OU"),
+    ("2.5.4.12", "title"),
+    ("2.5.4.17", "postalCode"),
+    ("2.5.4.42", "GN"),
+    ("2.5.4.43", "initials"),
+    ("2.5.4.44", "generationQualifier"),
+    ("2.5.4.46", "dnQualifier"),
+    ("2.5.4.65", "pseudonym"),
+    ("2.5.4.97", "organizationIdentifier"),
+    ("1.2.840.113549.1.9.1", "emailAddress"),
+    ("0.9.2342.19200300.100.1.1", "UID"),
+    ("0.9.2342.19200300.100.1.25", "DC"),
+];
+
+// Alternative keywords seen in real-world DN strings, mapped to the short name
+const ATTRIBUTE_ALIASES: &[(&str, &str)] = &[
+    ("E", "emailAddress"),
+    ("EMAIL", "emailAddress"),
+    ("S", "ST"),
+    ("T", "title"),
+    ("COMMONNAME", "CN"),
+    ("SURNAME", "SN"),
+    ("GIVENNAME", "GN"),
+    ("COUNTRYNAME", "C"),
+    ("LOCALITYNAME", "L"),
+    ("STATEORPROVINCENAME", "ST"),
+    ("STREETADDRESS", "STREET"),
+    ("ORGANIZATIONNAME", "O"),
+    ("ORGANIZATIONALUNITNAME", "OU"),
+    ("DOMAINCOMPONENT", "DC"),
+    ("USERID", "UID"),
+];
+
+pub(crate) fn attribute_name_for_oid(oid: &str) -> Option<&'static str> {
+    ATTRIBUTE_NAMES
+        .iter()
+        .find(|(o, _)| *o == oid)
+        .map(|(_, name)| *name)
+}
+
+/// Resolve an attribute type as written in a DN string to its short name and OID.
+/// Unknown keywords are kept as written with an empty OID.
+fn canonical_attribute_type(raw: &str) -> (String, String) {
+    let raw = raw
+        .strip_prefix("OID.")
+        .or_else(|| raw.strip_prefix("oid."))
+        .unwrap_or(raw);
+
+    if raw.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        let name = attribute_name_for_oid(raw).unwrap_or(raw);
+        return (name.to_string(), raw.to_string());
+    }
+
+    let upper = raw.to_ascii_uppercase();
+    let name = ATTRIBUTE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == upper)
+        .map(|(_, name)| *name)
+        .unwrap_or(raw);
+    match ATTRIBUTE_NAMES
+        .iter()
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+    {
+        Some((oid, n)) => (n.to_string(), oid.to_string()),
+        None => (raw.to_string(), String::new()),
+    }
+}
+
+/// Decode a BER-encoded "#hex" value when it holds a string type
+fn decode_hex_value(hex: &str) -> Option<String> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect::<Option<_>>()?;
+    let (&tag, rest) = bytes.split_first()?;
+    let (&len, content) = rest.split_first()?;
+    // Only short-form lengths are expected for DN attribute values
+    if len as usize != content.len() {
+        return None;
+    }
+    match tag {
+        // UTF8String, NumericString, PrintableString, IA5String
+        0x0c | 0x12 | 0x13 | 0x16 => String::from_utf8(content.to_vec()).ok(),
+        // T61String, treated as Latin-1
+        0x14 => Some(content.iter().map(|&b| b as char).collect()),
+        // BMPString
+        0x1e => {
+            let units: Vec<u16> = content
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            String::from_utf16(&units).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Escape an attribute value for use in an RFC 4514 string
+pub(crate) fn escape_rfc4514_value(value: &str) -> String {
+    let char_count = value.chars().count();
+    let mut out = String::with_capacity(value.len());
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            '"' | '+' | ',' | ';' | '<' | '>' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\0' => out.push_str("\\00"),
+            ' ' if i == 0 || i == char_count - 1 => out.push_str("\\ "),
+            '#' if i == 0 => out.push_str("\\#"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+impl DistinguishedName {
+    pub(crate) fn from_attributes(attributes: Vec<DnAttribute>) -> Self {
+        let rfc4514 = format_rfc4514(&attributes);
+        DistinguishedName {
+            attributes,
+            rfc4514,
+        }
+    }
+
+    /// First value of the given attribute type, e.g. `first("CN")`
+    pub(crate) fn first(&self, attr_type: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.attr_type.eq_ignore_ascii_case(attr_type))
+            .map(|a| a.value.as_str())
+    }
+}
+
+/// Parse a distinguished name string.
+///
+/// Accepts RFC 4514 / RFC 2253 strings ("CN=Name,O=Org,C=US"), the RFC 1779
+/// variants with spaces, semicolons and quoted values, and OpenSSL's
+/// one-line form ("/C=US/O=Org/CN=Name"). Returns `None` if the string is
+/// not a DN at all, e.g. a bare organization name.
+#[frb(sync)]
+pub fn parse_distinguished_name(dn: String) -> Option<DistinguishedName> {
+    let trimmed = dn.trim();
+    let rdns = if let Some(slash_form) = trimmed.strip_prefix('/') {
+        // OpenSSL lists the most significant RDN first already
+        DnTokenizer::new(slash_form, &['/']).parse()?
+    } else {
+        // RFC 4514 lists the least significant RDN first
+        let mut rdns = DnTokenizer::new(trimmed, &[',', ';']).parse()?;
+        rdns.reverse();
+        rdns
+    };
+
+    let mut attributes = Vec::new();
+    for (rdn_index, rdn) in rdns.into_iter().enumerate() {
+        for (raw_type, value) in rdn {
+            let (attr_type, oid) = canonical_attribute_type(&raw_type);
+            attributes.push(DnAttribute {
+                attr_type,
+                oid,
+                value,
+                rdn_index: rdn_index as u32,
+            });
+        }
+    }
+    Some(DistinguishedName::from_attributes(attributes))
+}
+
+type Rdn = Vec<(String, String)>;
+
+/// Splits a DN string into RDNs of (type, value) pairs
+struct DnTokenizer<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    rdn_separators: &'a [char],
+}
+
+impl<'a> DnTokenizer<'a> {
+    fn new(input: &str, rdn_separators: &'a [char]) -> Self {
+        DnTokenizer {
+            chars: input.chars().collect(),
+            pos: 0,
+            rdn_separators,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(' ') {
+            self.pos += 1;
+        }
+    }
+
+    /// Whether `type=` starts at `pos`, used to tell a separator from a
+    /// stray unescaped comma inside a value such as "Samsung Co., Ltd."
+    fn attribute_starts_at(&self, mut pos: usize) -> bool {
+        while self.chars.get(pos) == Some(&' ') {
+            pos += 1;
+        }
+        let start = pos;
+        while let Some(c) = self.chars.get(pos) {
+            if c.is_ascii_alphanumeric() || *c == '.' || *c == '-' {
+                pos += 1;
+            } else {
+                break;
+            }
+        }
+        if pos == start {
+            return false;
+        }
+        while self.chars.get(pos) == Some(&' ') {
+            pos += 1;
+        }
+        self.chars.get(pos) == Some(&'=')
+    }
+
+    fn is_separator_at(&self, pos: usize) -> bool {
+        match self.chars.get(pos) {
+            Some(c) if *c == '+' || self.rdn_separators.contains(c) => {
+                self.attribute_starts_at(pos + 1)
+            }
+            _ => false,
+        }
+    }
+
+    fn parse(mut self) -> Option<Vec<Rdn>> {
+        let mut rdns = Vec::new();
+        let mut current: Rdn = Vec::new();
+
+        loop {
+            self.skip_spaces();
+            let attr_type = self.read_type()?;
+            self.skip_spaces();
+            let value = self.read_value()?;
+            current.push((attr_type, value));
+
+            self.skip_spaces();
+            match self.peek() {
+                None => {
+                    rdns.push(current);
+                    return Some(rdns);
+                }
+                Some('+') => {
+                    self.pos += 1;
+                }
+                Some(c) if self.rdn_separators.contains(&c) => {
+                    self.pos += 1;
+                    rdns.push(std::mem::take(&mut current));
+                }
+                Some(_) => return None,
+            }
+        }
+    }
+
+    fn read_type(&mut self) -> Option<String> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == '=' {
+                let attr_type = self.chars[start..self.pos].iter().collect::<String>();
+                let attr_type = attr_type.trim().to_string();
+                self.pos += 1;
+                return (!attr_type.is_empty()).then_some(attr_type);
+            }
+            if c == '+' || c == '"' || c == '\\' || self.rdn_separators.contains(&c) {
+                return None;
+            }
+            self.pos += 1;
+        }
+        None
+    }
+
+    fn read_value(&mut self) -> Option<String> {
+        match self.peek() {
+            Some('"') => self.read_quoted_value(),
+            Some('#') => {
+                let start = self.pos + 1;
+                let mut end = start;
+                while self.chars.get(end).is_some_and(|c| c.is_ascii_hexdigit()) {
+                    end += 1;
+                }
+                let hex: String = self.chars[start..end].iter().collect();
+                match decode_hex_value(&hex) {
+                    Some(value) if end > start => {
+                        self.pos = end;
+                        Some(value)
+                    }
+                    // Not a decodable BER string, so treat '#' as literal text
+                    _ => self.read_plain_value(),
+                }
+            }
+            _ => self.read_plain_value(),
+        }
+    }
+
+    fn read_quoted_value(&mut self) -> Option<String> {
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.peek()? {
+                '"' => {
+                    self.pos += 1;
+                    return String::from_utf8(bytes).ok();
+                }
+                '\\' => {
+                    self.pos += 1;
+                    self.read_escape(&mut bytes)?;
+                }
+                c => {
+                    push_char(&mut bytes, c);
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn read_plain_value(&mut self) -> Option<String> {
+        let mut bytes = Vec::new();
+        // Length up to the last character that must be kept, so unescaped
+        // trailing spaces are dropped but escaped ones survive
+        let mut keep_len = 0;
+        while let Some(c) = self.peek() {
+            if self.is_separator_at(self.pos) {
+                break;
+            }
+            if c == '\\' {
+                self.pos += 1;
+                self.read_escape(&mut bytes)?;
+                keep_len = bytes.len();
+                continue;
+            }
+            push_char(&mut bytes, c);
+            if c != ' ' {
+                keep_len = bytes.len();
+            }
+            self.pos += 1;
+        }
+        bytes.truncate(keep_len);
+        String::from_utf8(bytes).ok()
+    }
+
+    /// Read an escape sequence after a backslash: either a hex byte pair
+    /// (UTF-8 encoded, so "\C3\BC" is "ü") or a single escaped character
+    fn read_escape(&mut self, bytes: &mut Vec<u8>) -> Option<()> {
+        let first = self.peek()?;
+        let second = self.chars.get(self.pos + 1).copied();
+        if let (Some(hi), Some(lo)) = (first.to_digit(16), second.and_then(|c| c.to_digit(16))) {
+            bytes.push((hi * 16 + lo) as u8);
+            self.pos += 2;
+        } else {
+            push_char(bytes, first);
+            self.pos += 1;
+        }
+        Some(())
+    }
+}
+
+fn push_char(bytes: &mut Vec<u8>, c: char) {
+    let mut buf = [0u8; 4];
+    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+}
+
+fn format_rfc4514(attributes: &[DnAttribute]) -> String {
+    let mut rdns: Vec<Vec<&DnAttribute>> = Vec::new();
+    for attr in attributes {
+        match rdns.last_mut() {
+            Some(rdn) if rdn[0].rdn_index == attr.rdn_index => rdn.push(attr),
+            _ => rdns.push(vec![attr]),
+        }
+    }
+
+    rdns.iter()
+        .rev()
+        .map(|rdn| {
+            rdn.iter()
+                .map(|a| format!("{}={}", a.attr_type, escape_rfc4514_value(&a.value)))
+                .collect::<Vec<_>>()
+                .join("+")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(dn: &str) -> DistinguishedName {
+        parse_distinguished_name(dn.to_string()).expect(dn)
+    }
+
+    fn pairs(dn: &DistinguishedName) -> Vec<(&str, &str)> {
+        dn.attributes
+            .iter()
+            .map(|a| (a.attr_type.as_str(), a.value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_rfc1779_style_with_spaces() {
+        let dn = parse("CN=Adobe Content Credentials, O=Adobe Inc., L=San Jose, ST=California, C=US");
+        assert_eq!(
+            pairs(&dn),
+            vec![
+                ("C", "US"),
+                ("ST", "California"),
+                ("L", "San Jose"),
+                ("O", "Adobe Inc."),
+                ("CN", "Adobe Content Credentials"),
+            ]
+        );
+        assert_eq!(
+            dn.rfc4514,
+            "CN=Adobe Content Credentials,O=Adobe Inc.,L=San Jose,ST=California,C=US"
+        );
+    }
+
+    #[test]
+    fn parses_escaped_commas() {
+        let dn = parse(r"CN=Smith\, John,O=Example\, Inc.,C=US");
+        assert_eq!(dn.first("CN"), Some("Smith, John"));
+        assert_eq!(dn.first("O"), Some("Example, Inc."));
+        assert_eq!(dn.rfc4514, r"CN=Smith\, John,O=Example\, Inc.,C=US");
+    }
+
+    #[test]
+    fn parses_quoted_values_containing_commas() {
+        let dn = parse(r#"CN="Doe, Jane", O="Acme, Ltd.", C=GB"#);
+        assert_eq!(dn.first("CN"), Some("Doe, Jane"));
+        assert_eq!(dn.first("O"), Some("Acme, Ltd."));
+    }
+
+    #[test]
+    fn keeps_unescaped_commas_inside_values() {
+        let dn = parse("CN=Galaxy Camera, O=Samsung Electronics Co., Ltd., C=KR");
+        assert_eq!(dn.first("O"), Some("Samsung Electronics Co., Ltd."));
+        assert_eq!(dn.first("C"), Some("KR"));
+    }
+
+    #[test]
+    fn parses_multi_valued_rdns() {
+        let dn = parse("CN=Jane+UID=jdoe,OU=Newsroom,DC=example,DC=com");
+        let cn = dn.attributes.iter().find(|a| a.attr_type == "CN").unwrap();
+        let uid = dn.attributes.iter().find(|a| a.attr_type == "UID").unwrap();
+        assert_eq!(cn.rdn_index, uid.rdn_index);
+        assert_eq!(dn.attributes[0], DnAttribute {
+            attr_type: "DC".to_string(),
+            oid: "0.9.2342.19200300.100.1.25".to_string(),
+            value: "com".to_string(),
+            rdn_index: 0,
+        });
+        assert_eq!(dn.rfc4514, "CN=Jane+UID=jdoe,OU=Newsroom,DC=example,DC=com");
+    }
+
+    #[test]
+    fn parses_openssl_slash_form() {
+        let dn = parse("/C=US/ST=California/O=Truepic/CN=Truepic Lens");
+        assert_eq!(
+            pairs(&dn),
+            vec![("C", "US"), ("ST", "California"), ("O", "Truepic"), ("CN", "Truepic Lens")]
+        );
+        assert_eq!(dn.rfc4514, "CN=Truepic Lens,O=Truepic,ST=California,C=US");
+    }
+
+    #[test]
+    fn parses_slash_form_with_url_value() {
+        let dn = parse("/O=Example News/CN=https://example.com/signing");
+        assert_eq!(dn.first("CN"), Some("https://example.com/signing"));
+    }
+
+    #[test]
+    fn decodes_utf8_hex_escapes() {
+        let dn = parse(r"CN=M\C3\BCller,O=S\C3\BCddeutsche Zeitung,C=DE");
+        assert_eq!(dn.first("CN"), Some("Müller"));
+        assert_eq!(dn.first("O"), Some("Süddeutsche Zeitung"));
+    }
+
+    #[test]
+    fn decodes_oid_types_and_ber_values() {
+        let dn = parse("1.2.840.113549.1.9.1=#16106e657773406578616d706c652e636f6d,CN=News Desk");
+        assert_eq!(dn.first("emailAddress"), Some("news@example.com"));
+        assert_eq!(dn.attributes[1].oid, "1.2.840.113549.1.9.1");
+        assert_eq!(dn.rfc4514, "emailAddress=news@example.com,CN=News Desk");
+    }
+
+    #[test]
+    fn resolves_keyword_aliases_case_insensitively() {
+        let dn = parse("cn=Foto Desk, e=desk@example.de, s=Bayern, OID.2.5.4.6=DE");
+        assert_eq!(
+            pairs(&dn),
+            vec![("C", "DE"), ("ST", "Bayern"), ("emailAddress", "desk@example.de"), ("CN", "Foto Desk")]
+        );
+    }
+
+    #[test]
+    fn accepts_semicolon_separators() {
+        let dn = parse("CN=Wire Service; O=Associated Example; C=US");
+        assert_eq!(dn.rfc4514, "CN=Wire Service,O=Associated Example,C=US");
+    }
+
+    #[test]
+    fn escapes_leading_hash_and_trailing_space() {
+        let dn = parse(r"CN=\#1 Newsroom\ ,O=Example");
+        assert_eq!(dn.first("CN"), Some("#1 Newsroom "));
+        assert_eq!(dn.rfc4514, r"CN=\#1 Newsroom\ ,O=Example");
+    }
+
+    #[test]
+    fn rejects_strings_that_are_not_dns() {
+        assert!(parse_distinguished_name("Adobe Inc.".to_string()).is_none());
+        assert!(parse_distinguished_name("".to_string()).is_none());
+        assert!(parse_distinguished_name("=value".to_string()).is_none());
+    }
+
+    #[test]
+    fn falls_back_to_ou_when_o_is_absent() {
+        let dn = parse("CN=C2PA Signer, OU=Verification Unit, C=US");
+        assert_eq!(dn.first("O"), None);
+        assert_eq!(dn.first("OU"), Some("Verification Unit"));
+    }
+
+    #[test]
+    fn round_trips_rfc4514_strings() {
+        let corpus = [
+            "CN=Truepic Lens CA,O=Truepic,C=US",
+            r"CN=Smith\, John+UID=jsmith,OU=Photo Desk,O=Example\; News,C=GB",
+            r"CN=\\server\\share,O=Example",
+            r#"CN=a\+b,O=\"Quoted\" Media"#,
+            "CN=Reuters Signing,O=Thomson Reuters,L=Toronto,ST=Ontario,C=CA",
+            "2.5.4.97=VATDE-123456789,CN=Nachrichten GmbH,C=DE",
+        ];
+        for dn in corpus {
+            let parsed = parse(dn);
+            assert_eq!(parsed.rfc4514, dn.replace("2.5.4.97", "organizationIdentifier"));
+            assert_eq!(parse(&parsed.rfc4514), parsed);
+        }
+    }
+}
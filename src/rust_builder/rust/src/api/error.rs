@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Why an analysis could not produce a verification result.
+/// Carried on `C2paAnalysisResult::error` so the UI can branch on the kind.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum AnalysisError {
+    /// The asset could not be opened or read
+    #[error("Failed to read asset: {message}")]
+    Io { message: String },
+    /// The C2PA SDK cannot read manifests from this type of asset
+    #[error("Unsupported format: {mime_type}")]
+    UnsupportedFormat { mime_type: String },
+    /// The asset carries no C2PA manifest
+    #[error("No C2PA manifest found")]
+    ManifestNotFound,
+    /// A manifest store is present but its JUMBF or claim structure is broken
+    #[error("This image contains C2PA data that could not be parsed. It may be corrupted or use an unsupported format.")]
+    ManifestCorrupt { detail: String },
+    /// A claim or assertion is not valid CBOR
+    #[error("C2PA data could not be decoded: {detail}")]
+    CborDecode { detail: String },
+    /// The manifest is stored remotely and could not be fetched; analysis never goes online
+    #[error("Manifest is stored remotely and is not available offline: {url}")]
+    RemoteManifestUnavailable { url: String },
+    /// Any other failure reported by the C2PA SDK
+    #[error("{message}")]
+    Other { message: String },
+}
+
+impl AnalysisError {
+    pub(crate) fn io(error: &std::io::Error) -> Self {
+        AnalysisError::Io {
+            message: error.to_string(),
+        }
+    }
+
+    pub(crate) fn from_c2pa(error: c2pa::Error, mime_type: &str) -> Self {
+        use c2pa::Error as E;
+        match error {
+            E::JumbfNotFound | E::ProvenanceMissing => AnalysisError::ManifestNotFound,
+            E::UnsupportedType => AnalysisError::UnsupportedFormat {
+                mime_type: mime_type.to_string(),
+            },
+            E::RemoteManifestUrl(url) | E::RemoteManifestFetch(url) => {
+                AnalysisError::RemoteManifestUnavailable { url }
+            }
+            E::CborError(e) => AnalysisError::CborDecode {
+                detail: e.to_string(),
+            },
+            e @ (E::ClaimDecoding
+            | E::JumbfParseError(_)
+            | E::JumbfBoxNotFound
+            | E::InvalidClaim(_)) => AnalysisError::ManifestCorrupt {
+                detail: e.to_string(),
+            },
+            E::IoError(e) => AnalysisError::io(&e),
+            e => AnalysisError::Other {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use c2pa::Error as E;
+
+    #[test]
+    fn c2pa_errors_are_classified() {
+        let cases = [
+            (E::JumbfNotFound, AnalysisError::ManifestNotFound),
+            (E::ProvenanceMissing, AnalysisError::ManifestNotFound),
+            (
+                E::UnsupportedType,
+                AnalysisError::UnsupportedFormat {
+                    mime_type: "image/x-test".to_string(),
+                },
+            ),
+            (
+                E::RemoteManifestUrl("https://example.com/m.c2pa".to_string()),
+                AnalysisError::RemoteManifestUnavailable {
+                    url: "https://example.com/m.c2pa".to_string(),
+                },
+            ),
+            (
+                E::RemoteManifestFetch("https://example.com/m.c2pa".to_string()),
+                AnalysisError::RemoteManifestUnavailable {
+                    url: "https://example.com/m.c2pa".to_string(),
+                },
+            ),
+            (
+                E::IoError(std::io::Error::other("disk gone")),
+                AnalysisError::Io {
+                    message: "disk gone".to_string(),
+                },
+            ),
+        ];
+        for (error, expected) in cases {
+            let name = format!("{:?}", error);
+            assert_eq!(
+                AnalysisError::from_c2pa(error, "image/x-test"),
+                expected,
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn c2pa_errors_keep_their_detail() {
+        let cbor = serde_cbor::from_slice::<String>(&[0xff]).unwrap_err();
+        let detail = cbor.to_string();
+        assert_eq!(
+            AnalysisError::from_c2pa(E::CborError(cbor), "image/jpeg"),
+            AnalysisError::CborDecode { detail }
+        );
+
+        for error in [E::ClaimDecoding, E::JumbfBoxNotFound] {
+            let detail = error.to_string();
+            assert_eq!(
+                AnalysisError::from_c2pa(error, "image/jpeg"),
+                AnalysisError::ManifestCorrupt { detail }
+            );
+        }
+
+        let error = E::BadParam("no reader".to_string());
+        let message = error.to_string();
+        assert_eq!(
+            AnalysisError::from_c2pa(error, "image/jpeg"),
+            AnalysisError::Other { message }
+        );
+    }
+
+    #[test]
+    fn messages_name_the_cause() {
+        assert_eq!(
+            AnalysisError::ManifestNotFound.to_string(),
+            "No C2PA manifest found"
+        );
+        assert_eq!(
+            AnalysisError::UnsupportedFormat {
+                mime_type: "image/x-test".to_string()
+            }
+            .to_string(),
+            "Unsupported format: image/x-test"
+        );
+        assert_eq!(
+            AnalysisError::RemoteManifestUnavailable {
+                url: "https://example.com/m.c2pa".to_string()
+            }
+            .to_string(),
+            "Manifest is stored remotely and is not available offline: https://example.com/m.c2pa"
+        );
+    }
+}
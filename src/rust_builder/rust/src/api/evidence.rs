@@ -0,0 +1,398 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::actions::is_actions_label;
+
+/// Which detector produced a piece of evidence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvidenceDetector {
+    /// A `c2pa.actions` entry: its software agent or description
+    C2paAction,
+    /// The manifest's claim generator
+    C2paClaimGenerator,
+    /// Any other part of the manifest store JSON
+    C2paManifest,
+    /// EXIF text fields
+    Exif,
+    /// Generation parameters in PNG text chunks
+    PngText,
+    /// XMP properties
+    Xmp,
+}
+
+impl EvidenceDetector {
+    /// The metadata source the detector reads: "c2pa", "exif", "png" or "xmp"
+    pub(crate) fn source(self) -> &'static str {
+        match self {
+            EvidenceDetector::C2paAction
+            | EvidenceDetector::C2paClaimGenerator
+            | EvidenceDetector::C2paManifest => "c2pa",
+            EvidenceDetector::Exif => "exif",
+            EvidenceDetector::PngText => "png",
+            EvidenceDetector::Xmp => "xmp",
+        }
+    }
+}
+
+/// What a piece of evidence points towards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvidenceKind {
+    /// The content was generated by an AI model
+    AiGeneration,
+    /// Existing content was edited or composited with AI
+    AiEditing,
+    /// The content was captured by a camera; counts against AI generation
+    CameraCapture,
+}
+
+impl EvidenceKind {
+    pub(crate) fn is_ai(self) -> bool {
+        self != EvidenceKind::CameraCapture
+    }
+}
+
+/// How much a piece of evidence on its own says about AI generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum EvidenceStrength {
+    /// A label or keyword that often, but not always, accompanies AI content
+    Weak,
+    /// A known AI tool is named as the producer
+    Strong,
+    /// A signed, standardized declaration of AI generation
+    Definitive,
+}
+
+/// One reason an asset was, or could be, flagged as AI-generated, or a
+/// sign that it is a camera original
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Evidence {
+    pub detector: EvidenceDetector,
+    pub kind: EvidenceKind,
+    /// Where the match was found: a JSON path into the manifest store such
+    /// as `$.manifests["urn:uuid:…"].claim_generator`, an EXIF tag name, a
+    /// PNG text chunk keyword or an XMP property such as `xmp:CreatorTool`
+    pub field: String,
+    /// The text that matched
+    pub matched_text: String,
+    pub strength: EvidenceStrength,
+    /// Human-readable reason for reviewers
+    pub explanation: String,
+    /// The generator this evidence names, when it names one
+    pub generator: Option<String>,
+}
+
+fn push_key(path: &str, key: &str) -> String {
+    let simple = !key.is_empty()
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !key.starts_with(|c: char| c.is_ascii_digit());
+    if simple {
+        format!("{}.{}", path, key)
+    } else {
+        format!("{}[{:?}]", path, key)
+    }
+}
+
+/// Which part of a manifest a scoped string was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ManifestField {
+    ClaimGenerator,
+    /// `claim_generator_info[].name`
+    ClaimGeneratorInfo,
+    /// `softwareAgent` of a `c2pa.actions` entry, or its `name`
+    SoftwareAgent,
+    /// `digitalSourceType` of a `c2pa.actions` entry
+    DigitalSourceType,
+    /// An assertion's label
+    AssertionLabel,
+}
+
+/// A manifest string AI detection may look at, with its JSON path
+#[derive(Debug, Clone)]
+pub(crate) struct ScopedText<'a> {
+    /// Label of the manifest the string belongs to
+    pub manifest: &'a str,
+    pub field: ManifestField,
+    pub path: String,
+    pub text: &'a str,
+}
+
+fn push_text<'a>(
+    fields: &mut Vec<ScopedText<'a>>,
+    manifest: &'a str,
+    field: ManifestField,
+    path: String,
+    value: &'a Value,
+) {
+    if let Some(text) = value.as_str() {
+        fields.push(ScopedText {
+            manifest,
+            field,
+            path,
+            text,
+        });
+    }
+}
+
+/// The strings of every manifest in the store that say who or what produced
+/// the content, in document order. Titles, hashes, thumbnails, URLs and free
+/// text are never returned, so keywords inside them cannot raise evidence.
+pub(crate) fn manifest_fields(json: &Value) -> Vec<ScopedText<'_>> {
+    let mut fields = Vec::new();
+    let Some(manifests) = json.get("manifests").and_then(Value::as_object) else {
+        return fields;
+    };
+    for (manifest_label, manifest) in manifests {
+        let base = push_key("$.manifests", manifest_label);
+
+        let assertions = manifest.get("assertions").and_then(Value::as_array);
+        for (i, assertion) in assertions.into_iter().flatten().enumerate() {
+            let path = format!("{}.assertions[{}]", base, i);
+            let Some(label) = assertion.get("label").and_then(Value::as_str) else {
+                continue;
+            };
+            push_text(
+                &mut fields,
+                manifest_label,
+                ManifestField::AssertionLabel,
+                format!("{}.label", path),
+                &assertion["label"],
+            );
+            if !is_actions_label(label) {
+                continue;
+            }
+            let actions = assertion["data"].get("actions").and_then(Value::as_array);
+            for (j, action) in actions.into_iter().flatten().enumerate() {
+                let path = format!("{}.data.actions[{}]", path, j);
+                match action.get("softwareAgent") {
+                    Some(agent @ Value::String(_)) => push_text(
+                        &mut fields,
+                        manifest_label,
+                        ManifestField::SoftwareAgent,
+                        format!("{}.softwareAgent", path),
+                        agent,
+                    ),
+                    Some(agent) => push_text(
+                        &mut fields,
+                        manifest_label,
+                        ManifestField::SoftwareAgent,
+                        format!("{}.softwareAgent.name", path),
+                        &agent["name"],
+                    ),
+                    None => {}
+                }
+                push_text(
+                    &mut fields,
+                    manifest_label,
+                    ManifestField::DigitalSourceType,
+                    format!("{}.digitalSourceType", path),
+                    &action["digitalSourceType"],
+                );
+            }
+        }
+
+        push_text(
+            &mut fields,
+            manifest_label,
+            ManifestField::ClaimGenerator,
+            format!("{}.claim_generator", base),
+            &manifest["claim_generator"],
+        );
+        let infos = manifest
+            .get("claim_generator_info")
+            .and_then(Value::as_array);
+        for (i, info) in infos.into_iter().flatten().enumerate() {
+            push_text(
+                &mut fields,
+                manifest_label,
+                ManifestField::ClaimGeneratorInfo,
+                format!("{}.claim_generator_info[{}].name", base, i),
+                &info["name"],
+            );
+        }
+    }
+    fields
+}
+
+/// Whether `needle` occurs in `haystack` as a whole word. The word may be
+/// followed by digits, so "dalle3" and "sora2" still match "dalle" and "sora",
+/// but "pikachu" does not match "pika" and "description" does not match "descript".
+/// Needles that start or end with punctuation, like "prompt:", need no
+/// boundary on that side.
+pub(crate) fn contains_word(haystack: &str, needle: &str) -> bool {
+    find_word(haystack, needle).is_some()
+}
+
+/// Byte offset of the first whole-word occurrence of `needle`, as matched by
+/// [`contains_word`]
+pub(crate) fn find_word(haystack: &str, needle: &str) -> Option<usize> {
+    let bounded_start = needle.starts_with(char::is_alphanumeric);
+    let bounded_end = needle.ends_with(char::is_alphanumeric);
+    haystack
+        .match_indices(needle)
+        .map(|(start, _)| start)
+        .find(|&start| {
+            let before = haystack[..start].chars().next_back();
+            let after = haystack[start + needle.len()..].chars().next();
+            let joined_before = bounded_start && before.is_some_and(char::is_alphanumeric);
+            let joined_after = bounded_end && after.is_some_and(char::is_alphabetic);
+            !joined_before && !joined_after
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn words_need_boundaries() {
+        let cases = [
+            ("made with pika", "pika", Some(10)),
+            ("pikachu", "pika", None),
+            ("pikachu and pika", "pika", Some(12)),
+            ("description", "descript", None),
+            ("mydalle", "dalle", None),
+            ("image_dalle.png", "dalle", Some(6)),
+            ("", "dalle", None),
+        ];
+        for (haystack, needle, expected) in cases {
+            assert_eq!(
+                find_word(haystack, needle),
+                expected,
+                "{} in {}",
+                needle,
+                haystack
+            );
+        }
+    }
+
+    #[test]
+    fn words_may_end_in_digits() {
+        assert_eq!(find_word("dalle3", "dalle"), Some(0));
+        assert_eq!(find_word("sora2 video", "sora"), Some(0));
+        assert_eq!(find_word("midjourney v6", "midjourney"), Some(0));
+        // Digits before the word still join it to the previous one
+        assert_eq!(find_word("3dalle", "dalle"), None);
+        assert_eq!(find_word("dalle3x", "dalle"), Some(0));
+    }
+
+    #[test]
+    fn punctuation_needles_are_unbounded_on_that_side() {
+        assert_eq!(find_word("prompt: a cat", "prompt:"), Some(0));
+        assert_eq!(find_word("prompt:a cat", "prompt:"), Some(0));
+        assert_eq!(find_word("negative prompt:x", "prompt:"), Some(9));
+        // The alphanumeric start still needs a boundary
+        assert_eq!(find_word("negativeprompt: x", "prompt:"), None);
+        assert_eq!(find_word("made with(ai)inside", "(ai)"), Some(9));
+        assert!(contains_word("steps: 20, seed: 1", "seed:"));
+        assert!(!contains_word("steps: 20, reseed: 1", "seed:"));
+    }
+
+    #[test]
+    fn non_ascii_letters_join_words() {
+        assert_eq!(find_word("soraé", "sora"), None);
+        assert_eq!(find_word("ésora", "sora"), None);
+        assert_eq!(find_word("Ωsora", "sora"), None);
+        // Non-ASCII punctuation and digits are boundaries; offsets are in bytes
+        assert_eq!(find_word("—sora—", "sora"), Some(3));
+        assert_eq!(find_word("日本 sora", "sora"), Some(7));
+        assert_eq!(find_word("sora٣", "sora"), Some(0));
+    }
+
+    #[test]
+    fn manifest_fields_are_scoped_to_producers() {
+        let json = json!({
+            "active_manifest": "urn:uuid:1",
+            "manifests": {
+                "urn:uuid:1": {
+                    "title": "midjourney.jpg",
+                    "claim_generator": "Example/1.0",
+                    "claim_generator_info": [{"name": "Example", "version": "1.0"}, {"version": "2"}],
+                    "assertions": [
+                        {
+                            "label": "c2pa.actions.v2",
+                            "data": {
+                                "actions": [
+                                    {
+                                        "action": "c2pa.created",
+                                        "softwareAgent": {"name": "Adobe Firefly"},
+                                        "digitalSourceType": "http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia",
+                                        "description": "made with dalle"
+                                    },
+                                    {"action": "c2pa.edited", "softwareAgent": "Editor 2"},
+                                ]
+                            }
+                        },
+                        {
+                            "label": "stds.schema-org.CreativeWork",
+                            "data": {"author": [{"name": "Stable Diffusion"}]}
+                        },
+                        {"data": {"softwareAgent": "unlabeled"}},
+                    ]
+                }
+            }
+        });
+        let fields: Vec<_> = manifest_fields(&json)
+            .into_iter()
+            .map(|f| (f.manifest, f.field, f.path, f.text))
+            .collect();
+        let base = "$.manifests[\"urn:uuid:1\"]";
+        let expected = vec![
+            (
+                "urn:uuid:1",
+                ManifestField::AssertionLabel,
+                format!("{}.assertions[0].label", base),
+                "c2pa.actions.v2",
+            ),
+            (
+                "urn:uuid:1",
+                ManifestField::SoftwareAgent,
+                format!("{}.assertions[0].data.actions[0].softwareAgent.name", base),
+                "Adobe Firefly",
+            ),
+            (
+                "urn:uuid:1",
+                ManifestField::DigitalSourceType,
+                format!("{}.assertions[0].data.actions[0].digitalSourceType", base),
+                "http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia",
+            ),
+            (
+                "urn:uuid:1",
+                ManifestField::SoftwareAgent,
+                format!("{}.assertions[0].data.actions[1].softwareAgent", base),
+                "Editor 2",
+            ),
+            (
+                "urn:uuid:1",
+                ManifestField::AssertionLabel,
+                format!("{}.assertions[1].label", base),
+                "stds.schema-org.CreativeWork",
+            ),
+            (
+                "urn:uuid:1",
+                ManifestField::ClaimGenerator,
+                format!("{}.claim_generator", base),
+                "Example/1.0",
+            ),
+            (
+                "urn:uuid:1",
+                ManifestField::ClaimGeneratorInfo,
+                format!("{}.claim_generator_info[0].name", base),
+                "Example",
+            ),
+        ];
+        assert_eq!(fields, expected);
+    }
+
+    #[test]
+    fn manifest_fields_need_manifests() {
+        assert!(manifest_fields(&json!({})).is_empty());
+        assert!(manifest_fields(&json!({"manifests": []})).is_empty());
+        assert!(manifest_fields(&json!("urn:uuid:1")).is_empty());
+
+        let json = json!({"manifests": {"plain": {"claim_generator": "Other"}}});
+        let fields = manifest_fields(&json);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].path, "$.manifests.plain.claim_generator");
+    }
+}
@@ -0,0 +1,502 @@
+//! Typed EXIF beyond the producer tags: GPS position, lens, exposure, the
+//! IFD1 thumbnail and vendor MakerNotes, plus every field as text.
+
+use std::io::Cursor;
+
+use exif::{Exif, Field, In, Tag, Value};
+use serde::{Deserialize, Serialize};
+
+use super::container::jpeg_dimensions;
+use super::maker_note::{parse_maker_note, MakerNote};
+
+/// Undefined values longer than this are summarized rather than dumped
+const MAX_BYTES_SHOWN: usize = 64;
+
+/// Where and how the camera was pointed, from the GPS IFD
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpsInfo {
+    /// Decimal degrees, negative south of the equator
+    pub latitude: Option<f64>,
+    /// Decimal degrees, negative west of Greenwich
+    pub longitude: Option<f64>,
+    /// Metres, negative below sea level
+    pub altitude: Option<f64>,
+    /// GPSDateStamp and GPSTimeStamp, in UTC, e.g. "2024-03-02T13:11:05Z"
+    pub timestamp: Option<String>,
+    /// Direction the camera faced, in degrees
+    pub image_direction: Option<f64>,
+    /// "T" for true north, "M" for magnetic
+    pub image_direction_ref: Option<String>,
+    /// Geodetic datum, usually "WGS-84"
+    pub map_datum: Option<String>,
+}
+
+/// The lens, from the EXIF IFD
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LensInfo {
+    pub make: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    /// LensSpecification: shortest and longest focal length in mm, then the
+    /// widest aperture at each
+    pub specification: Vec<f64>,
+    /// Focal length in mm
+    pub focal_length: Option<f64>,
+    pub focal_length_35mm: Option<u32>,
+}
+
+/// Exposure settings, from the EXIF IFD
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExposureInfo {
+    /// Seconds
+    pub exposure_time: Option<f64>,
+    pub f_number: Option<f64>,
+    /// PhotographicSensitivity
+    pub iso: Option<u32>,
+    /// Exposure compensation in EV
+    pub exposure_bias: Option<f64>,
+    /// e.g. "manual" or "aperture priority"
+    pub exposure_program: Option<String>,
+    pub metering_mode: Option<String>,
+    /// e.g. "fired, no return light detection function"
+    pub flash: Option<String>,
+    pub white_balance: Option<String>,
+}
+
+/// The JPEG thumbnail in IFD1
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExifThumbnail {
+    pub mime_type: String,
+    /// Read from the JPEG itself, not IFD1's tags, which writers rarely set
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub data: Vec<u8>,
+}
+
+/// One EXIF field as text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExifTag {
+    /// 0 for the primary image, 1 for the thumbnail
+    pub ifd: u16,
+    /// "Tiff", "Exif", "Gps" or "Interop"
+    pub group: String,
+    pub id: u16,
+    /// The tag's EXIF name, or its id in hex ("0xc4a5") when unknown
+    pub name: String,
+    pub value: String,
+}
+
+/// The ASCII date "2024:03:02 14:11:05" as "2024-03-02 14:11:05"
+fn dashed_date(text: &str) -> String {
+    // The slices below fall on character boundaries only in an ASCII prefix
+    let is_date = text
+        .as_bytes()
+        .get(..10)
+        .is_some_and(|date| date.is_ascii() && date[4] == b':' && date[7] == b':');
+    if is_date {
+        format!(
+            "{}-{}-{}{}",
+            &text[..4],
+            &text[5..7],
+            &text[8..10],
+            &text[10..]
+        )
+    } else {
+        text.to_string()
+    }
+}
+
+/// UserComment: an 8-byte character code, then the text
+fn user_comment(bytes: &[u8], little_endian: bool) -> String {
+    let (code, text) = bytes.split_at(bytes.len().min(8));
+    if code == b"UNICODE\0" {
+        let units: Vec<u16> = text
+            .chunks_exact(2)
+            .map(|c| {
+                if little_endian {
+                    u16::from_le_bytes([c[0], c[1]])
+                } else {
+                    u16::from_be_bytes([c[0], c[1]])
+                }
+            })
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        // ASCII, JIS or undefined; UTF-8 in practice
+        String::from_utf8_lossy(text).into_owned()
+    }
+}
+
+/// A field as text: strings unquoted, UserComment decoded, dates with
+/// dashes, and other values formatted with their units
+pub(crate) fn field_text(exif: &Exif, field: &Field) -> Option<String> {
+    let text = match &field.value {
+        Value::Ascii(parts) => {
+            let joined = parts
+                .iter()
+                .map(|p| String::from_utf8_lossy(p).into_owned())
+                .collect::<Vec<_>>()
+                .join(", ");
+            match field.tag {
+                Tag::DateTime | Tag::DateTimeOriginal | Tag::DateTimeDigitized => {
+                    dashed_date(&joined)
+                }
+                Tag::GPSDateStamp => joined.replace(':', "-"),
+                _ => joined,
+            }
+        }
+        Value::Undefined(bytes, _) if field.tag == Tag::UserComment => {
+            user_comment(bytes, exif.little_endian())
+        }
+        Value::Undefined(bytes, _) if bytes.len() > MAX_BYTES_SHOWN => {
+            format!("{} bytes", bytes.len())
+        }
+        _ => field.display_value().with_unit(exif).to_string(),
+    };
+    let text = text.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// A primary-image field as text
+pub(crate) fn exif_text(exif: &Exif, tag: Tag) -> Option<String> {
+    field_text(exif, exif.get_field(tag, In::PRIMARY)?)
+}
+
+fn number_at(value: &Value, index: usize) -> Option<f64> {
+    match value {
+        Value::Rational(v) => v.get(index).filter(|r| r.denom != 0).map(|r| r.to_f64()),
+        Value::SRational(v) => v.get(index).filter(|r| r.denom != 0).map(|r| r.to_f64()),
+        Value::Float(v) => v.get(index).map(|f| *f as f64),
+        Value::Double(v) => v.get(index).copied(),
+        other => other.get_uint(index).map(f64::from),
+    }
+}
+
+fn number(exif: &Exif, tag: Tag, ifd: In) -> Option<f64> {
+    number_at(&exif.get_field(tag, ifd)?.value, 0)
+}
+
+fn uint(exif: &Exif, tag: Tag) -> Option<u32> {
+    exif.get_field(tag, In::PRIMARY)?.value.get_uint(0)
+}
+
+fn display(exif: &Exif, tag: Tag) -> Option<String> {
+    let field = exif.get_field(tag, In::PRIMARY)?;
+    Some(field.display_value().to_string())
+}
+
+/// Degrees, minutes and seconds as signed decimal degrees
+fn coordinate(exif: &Exif, tag: Tag, reference: Tag, negative: &str) -> Option<f64> {
+    let value = &exif.get_field(tag, In::PRIMARY)?.value;
+    let degrees = number_at(value, 0)?;
+    let minutes = number_at(value, 1).unwrap_or(0.0);
+    let seconds = number_at(value, 2).unwrap_or(0.0);
+    let decimal = degrees + minutes / 60.0 + seconds / 3600.0;
+    let sign = match exif_text(exif, reference) {
+        Some(r) if r.eq_ignore_ascii_case(negative) => -1.0,
+        _ => 1.0,
+    };
+    Some(sign * decimal)
+}
+
+fn gps_timestamp(exif: &Exif) -> Option<String> {
+    let date = exif_text(exif, Tag::GPSDateStamp)?;
+    let time = &exif.get_field(Tag::GPSTimeStamp, In::PRIMARY)?.value;
+    let (hours, minutes, seconds) = (
+        number_at(time, 0)?,
+        number_at(time, 1)?,
+        number_at(time, 2)?,
+    );
+    let seconds = if seconds.fract() == 0.0 {
+        format!("{:02}", seconds as u32)
+    } else {
+        format!("{:06.3}", seconds)
+    };
+    Some(format!(
+        "{}T{:02}:{:02}:{}Z",
+        date, hours as u32, minutes as u32, seconds
+    ))
+}
+
+pub(crate) fn gps_info(exif: &Exif) -> Option<GpsInfo> {
+    let altitude = number(exif, Tag::GPSAltitude, In::PRIMARY).map(|metres| {
+        let below_sea_level = exif
+            .get_field(Tag::GPSAltitudeRef, In::PRIMARY)
+            .and_then(|f| f.value.get_uint(0))
+            == Some(1);
+        if below_sea_level {
+            -metres
+        } else {
+            metres
+        }
+    });
+    let gps = GpsInfo {
+        latitude: coordinate(exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, "S"),
+        longitude: coordinate(exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, "W"),
+        altitude,
+        timestamp: gps_timestamp(exif),
+        image_direction: number(exif, Tag::GPSImgDirection, In::PRIMARY),
+        image_direction_ref: exif_text(exif, Tag::GPSImgDirectionRef),
+        map_datum: exif_text(exif, Tag::GPSMapDatum),
+    };
+    (gps != GpsInfo::default()).then_some(gps)
+}
+
+pub(crate) fn lens_info(exif: &Exif) -> Option<LensInfo> {
+    let specification = exif
+        .get_field(Tag::LensSpecification, In::PRIMARY)
+        .map(|f| (0..4).filter_map(|i| number_at(&f.value, i)).collect())
+        .unwrap_or_default();
+    let lens = LensInfo {
+        make: exif_text(exif, Tag::LensMake),
+        model: exif_text(exif, Tag::LensModel),
+        serial_number: exif_text(exif, Tag::LensSerialNumber),
+        specification,
+        focal_length: number(exif, Tag::FocalLength, In::PRIMARY),
+        focal_length_35mm: uint(exif, Tag::FocalLengthIn35mmFilm),
+    };
+    (lens != LensInfo::default()).then_some(lens)
+}
+
+pub(crate) fn exposure_info(exif: &Exif) -> Option<ExposureInfo> {
+    let exposure = ExposureInfo {
+        exposure_time: number(exif, Tag::ExposureTime, In::PRIMARY),
+        f_number: number(exif, Tag::FNumber, In::PRIMARY),
+        iso: uint(exif, Tag::PhotographicSensitivity),
+        exposure_bias: number(exif, Tag::ExposureBiasValue, In::PRIMARY),
+        exposure_program: display(exif, Tag::ExposureProgram),
+        metering_mode: display(exif, Tag::MeteringMode),
+        flash: display(exif, Tag::Flash),
+        white_balance: display(exif, Tag::WhiteBalance),
+    };
+    (exposure != ExposureInfo::default()).then_some(exposure)
+}
+
+/// Main image size: PixelXDimension/PixelYDimension, else IFD0's
+/// ImageWidth/ImageLength
+pub(crate) fn image_size(exif: &Exif) -> (Option<u32>, Option<u32>) {
+    let width = uint(exif, Tag::PixelXDimension).or_else(|| uint(exif, Tag::ImageWidth));
+    let height = uint(exif, Tag::PixelYDimension).or_else(|| uint(exif, Tag::ImageLength));
+    (width, height)
+}
+
+pub(crate) fn thumbnail(exif: &Exif) -> Option<ExifThumbnail> {
+    let offset = exif
+        .get_field(Tag::JPEGInterchangeFormat, In::THUMBNAIL)?
+        .value
+        .get_uint(0)? as usize;
+    let len = exif
+        .get_field(Tag::JPEGInterchangeFormatLength, In::THUMBNAIL)?
+        .value
+        .get_uint(0)? as usize;
+    let data = exif.buf().get(offset..offset.checked_add(len)?)?;
+    if !data.starts_with(&[0xff, 0xd8]) {
+        return None;
+    }
+    let (width, height) = jpeg_dimensions(&mut Cursor::new(data)).unzip();
+    Some(ExifThumbnail {
+        mime_type: "image/jpeg".to_string(),
+        width,
+        height,
+        data: data.to_vec(),
+    })
+}
+
+pub(crate) fn maker_note(exif: &Exif) -> Option<MakerNote> {
+    let field = exif.get_field(Tag::MakerNote, In::PRIMARY)?;
+    let Value::Undefined(_, offset) = field.value else {
+        return None;
+    };
+    let make = exif_text(exif, Tag::Make);
+    parse_maker_note(
+        make.as_deref(),
+        exif.buf(),
+        offset as usize,
+        exif.little_endian(),
+    )
+}
+
+/// Every field in file order, except the pointers to other IFDs
+pub(crate) fn all_tags(exif: &Exif) -> Vec<ExifTag> {
+    exif.fields()
+        .filter(|f| {
+            !matches!(
+                f.tag,
+                Tag::ExifIFDPointer | Tag::GPSInfoIFDPointer | Tag::InteropIFDPointer
+            )
+        })
+        .filter_map(|f| {
+            let name = match f.tag.description() {
+                Some(_) => f.tag.to_string(),
+                None => format!("{:#06x}", f.tag.number()),
+            };
+            Some(ExifTag {
+                ifd: f.ifd_num.0,
+                group: format!("{:?}", f.tag.context()),
+                id: f.tag.number(),
+                name,
+                value: field_text(exif, f)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exif::experimental::Writer;
+    use exif::{Rational, Reader};
+    use std::io::Cursor;
+
+    fn field(tag: Tag, value: Value) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value,
+        }
+    }
+
+    fn ascii(text: &str) -> Value {
+        Value::Ascii(vec![text.as_bytes().to_vec()])
+    }
+
+    fn rationals(values: &[(u32, u32)]) -> Value {
+        Value::Rational(
+            values
+                .iter()
+                .map(|&(num, denom)| Rational { num, denom })
+                .collect(),
+        )
+    }
+
+    /// A 160x120 baseline JPEG header, enough for its dimensions
+    const THUMBNAIL: &[u8] = &[
+        0xff, 0xd8, 0xff, 0xe0, 0x00, 0x04, 0x00, 0x00, 0xff, 0xc0, 0x00, 0x0b, 0x08, 0x00, 0x78,
+        0x00, 0xa0, 0x01, 0x01, 0x11, 0x00, 0xff, 0xd9,
+    ];
+
+    fn camera_exif(little_endian: bool) -> Exif {
+        let fields = vec![
+            field(Tag::Make, ascii("Canon")),
+            field(Tag::Model, ascii("Canon EOS R5")),
+            field(Tag::DateTimeOriginal, ascii("2024:03:02 14:11:05")),
+            field(Tag::OffsetTimeOriginal, ascii("+01:00")),
+            field(Tag::SubSecTimeOriginal, ascii("042")),
+            field(Tag::BodySerialNumber, ascii("032021001234")),
+            field(Tag::LensModel, ascii("RF24-105mm F4 L IS USM")),
+            field(
+                Tag::LensSpecification,
+                rationals(&[(24, 1), (105, 1), (4, 1), (4, 1)]),
+            ),
+            field(Tag::FocalLength, rationals(&[(50, 1)])),
+            field(Tag::ExposureTime, rationals(&[(1, 250)])),
+            field(Tag::FNumber, rationals(&[(56, 10)])),
+            field(Tag::PhotographicSensitivity, Value::Short(vec![400])),
+            field(Tag::ExposureProgram, Value::Short(vec![1])),
+            field(
+                Tag::UserComment,
+                Value::Undefined(b"ASCII\0\0\0shot on assignment".to_vec(), 0),
+            ),
+            field(Tag::GPSLatitudeRef, ascii("N")),
+            field(Tag::GPSLatitude, rationals(&[(47, 1), (22, 1), (12, 1)])),
+            field(Tag::GPSLongitudeRef, ascii("W")),
+            field(Tag::GPSLongitude, rationals(&[(8, 1), (32, 1), (24, 1)])),
+            field(Tag::GPSAltitudeRef, Value::Byte(vec![0])),
+            field(Tag::GPSAltitude, rationals(&[(4085, 10)])),
+            field(Tag::GPSDateStamp, ascii("2024:03:02")),
+            field(Tag::GPSTimeStamp, rationals(&[(13, 1), (11, 1), (5, 1)])),
+        ];
+        let thumbnail_field = Field {
+            tag: Tag::Compression,
+            ifd_num: In::THUMBNAIL,
+            value: Value::Short(vec![6]),
+        };
+        let mut writer = Writer::new();
+        for f in &fields {
+            writer.push_field(f);
+        }
+        writer.push_field(&thumbnail_field);
+        writer.set_jpeg(THUMBNAIL, In::THUMBNAIL);
+        let mut buf = Cursor::new(Vec::new());
+        writer.write(&mut buf, little_endian).unwrap();
+        Reader::new().read_raw(buf.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn reads_typed_fields() {
+        for little_endian in [true, false] {
+            let exif = camera_exif(little_endian);
+            assert_eq!(exif_text(&exif, Tag::Make).as_deref(), Some("Canon"));
+            assert_eq!(
+                exif_text(&exif, Tag::DateTimeOriginal).as_deref(),
+                Some("2024-03-02 14:11:05")
+            );
+            assert_eq!(
+                exif_text(&exif, Tag::UserComment).as_deref(),
+                Some("shot on assignment")
+            );
+
+            let gps = gps_info(&exif).unwrap();
+            assert!((gps.latitude.unwrap() - 47.37).abs() < 1e-9);
+            assert!((gps.longitude.unwrap() + 8.54).abs() < 1e-9);
+            assert_eq!(gps.altitude, Some(408.5));
+            assert_eq!(gps.timestamp.as_deref(), Some("2024-03-02T13:11:05Z"));
+
+            let lens = lens_info(&exif).unwrap();
+            assert_eq!(lens.model.as_deref(), Some("RF24-105mm F4 L IS USM"));
+            assert_eq!(lens.specification, [24.0, 105.0, 4.0, 4.0]);
+            assert_eq!(lens.focal_length, Some(50.0));
+
+            let exposure = exposure_info(&exif).unwrap();
+            assert_eq!(exposure.exposure_time, Some(0.004));
+            assert_eq!(exposure.f_number, Some(5.6));
+            assert_eq!(exposure.iso, Some(400));
+            assert_eq!(exposure.exposure_program.as_deref(), Some("manual"));
+
+            let thumbnail = thumbnail(&exif).unwrap();
+            assert_eq!((thumbnail.width, thumbnail.height), (Some(160), Some(120)));
+            assert_eq!(thumbnail.data, THUMBNAIL);
+        }
+    }
+
+    #[test]
+    fn lists_every_tag() {
+        let exif = camera_exif(true);
+        let tags = all_tags(&exif);
+        let find = |name: &str| tags.iter().find(|t| t.name == name).unwrap();
+        assert_eq!(find("BodySerialNumber").value, "032021001234");
+        assert_eq!(find("OffsetTimeOriginal").value, "+01:00");
+        assert_eq!(find("SubSecTimeOriginal").group, "Exif");
+        assert_eq!(find("GPSLatitudeRef").group, "Gps");
+        assert_eq!(find("Compression").ifd, 1);
+        assert!(tags.iter().all(|t| t.name != "ExifIFDPointer"));
+    }
+
+    #[test]
+    fn decodes_unicode_user_comments() {
+        let mut comment = b"UNICODE\0".to_vec();
+        comment.extend("Zoë".encode_utf16().flat_map(|u| u.to_be_bytes()));
+        assert_eq!(user_comment(&comment, false), "Zoë");
+        assert_eq!(dashed_date("2024:03:02 14:11:05"), "2024-03-02 14:11:05");
+        assert_eq!(dashed_date("unknown"), "unknown");
+    }
+
+    #[test]
+    fn non_ascii_dates_are_left_as_is() {
+        assert_eq!(dashed_date("2024:03:0é"), "2024:03:0é");
+        assert_eq!(dashed_date("2024:03:é 12:00"), "2024:03:é 12:00");
+        // A multi-byte character after the date is kept whole
+        assert_eq!(dashed_date("2024:03:02é"), "2024-03-02é");
+
+        // Invalid UTF-8 is replaced before the date is read
+        let exif = camera_exif(false);
+        let date = field(
+            Tag::DateTimeOriginal,
+            Value::Ascii(vec![b"2024:03:0\xff".to_vec()]),
+        );
+        assert_eq!(
+            field_text(&exif, &date).as_deref(),
+            Some("2024:03:0\u{fffd}")
+        );
+    }
+}
@@ -0,0 +1,796 @@
+//! Generation parameters that Stable Diffusion front ends write into PNG
+//! text chunks.
+//!
+//! | Tool          | Chunks                                              |
+//! |---------------|-----------------------------------------------------|
+//! | AUTOMATIC1111 | `parameters` (text, also used by Forge and SD.Next) |
+//! | ComfyUI       | `prompt` (API graph), `workflow` (UI graph)         |
+//! | Fooocus       | `parameters` (JSON, or A1111 text with its version) |
+//! | InvokeAI      | `invokeai_metadata`, `invokeai_graph`, `sd-metadata`|
+//! | NovelAI       | `Comment` (JSON), `Description`, `Source`           |
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::evidence::{Evidence, EvidenceDetector, EvidenceKind, EvidenceStrength};
+use super::png::PngText;
+
+/// Nodes followed when resolving a ComfyUI prompt through its graph
+const MAX_GRAPH_DEPTH: usize = 16;
+
+/// The front end that wrote the parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GenerationTool {
+    /// AUTOMATIC1111 web UI and forks that share its format
+    Automatic1111,
+    ComfyUi,
+    Fooocus,
+    InvokeAi,
+    NovelAi,
+}
+
+impl GenerationTool {
+    /// Name as the signature database knows it
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            GenerationTool::Automatic1111 => "AUTOMATIC1111",
+            GenerationTool::ComfyUi => "ComfyUI",
+            GenerationTool::Fooocus => "Fooocus",
+            GenerationTool::InvokeAi => "InvokeAI",
+            GenerationTool::NovelAi => "NovelAI",
+        }
+    }
+}
+
+/// A LoRA applied during generation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoraReference {
+    pub name: String,
+    pub weight: Option<f64>,
+}
+
+/// A setting without a dedicated field, e.g. "Denoising strength"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerationSetting {
+    pub key: String,
+    pub value: String,
+}
+
+/// Typed generation parameters read from PNG text chunks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerationParameters {
+    pub tool: GenerationTool,
+    /// Tool version, e.g. "v1.7.0"
+    pub tool_version: Option<String>,
+    pub prompt: Option<String>,
+    pub negative_prompt: Option<String>,
+    pub steps: Option<u32>,
+    pub sampler: Option<String>,
+    pub scheduler: Option<String>,
+    pub cfg_scale: Option<f64>,
+    pub seed: Option<u64>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Checkpoint name, e.g. "sd_xl_base_1.0.safetensors"
+    pub model: Option<String>,
+    pub model_hash: Option<String>,
+    pub loras: Vec<LoraReference>,
+    /// The ComfyUI or InvokeAI node graph, as JSON
+    pub workflow: Option<String>,
+    pub other_settings: Vec<GenerationSetting>,
+    /// Keywords of the chunks the parameters were read from
+    pub source_chunks: Vec<String>,
+}
+
+impl GenerationParameters {
+    fn new(tool: GenerationTool, source_chunks: &[&str]) -> Self {
+        GenerationParameters {
+            tool,
+            tool_version: None,
+            prompt: None,
+            negative_prompt: None,
+            steps: None,
+            sampler: None,
+            scheduler: None,
+            cfg_scale: None,
+            seed: None,
+            width: None,
+            height: None,
+            model: None,
+            model_hash: None,
+            loras: vec![],
+            workflow: None,
+            other_settings: vec![],
+            source_chunks: source_chunks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Whether anything beyond free text was found: a prompt alone is not
+    /// enough to tell generation parameters from a caption
+    fn has_settings(&self) -> bool {
+        self.negative_prompt.is_some()
+            || self.steps.is_some()
+            || self.sampler.is_some()
+            || self.cfg_scale.is_some()
+            || self.seed.is_some()
+            || self.model.is_some()
+            || self.workflow.is_some()
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// A JSON string or number as text
+fn json_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => non_empty(s),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// A JSON number, or a string holding one
+fn json_f64(value: &Value) -> Option<f64> {
+    value
+        .as_f64()
+        .or_else(|| value.as_str()?.trim().parse().ok())
+}
+
+fn json_u64(value: &Value) -> Option<u64> {
+    value
+        .as_u64()
+        .or_else(|| value.as_str()?.trim().parse().ok())
+}
+
+fn json_u32(value: &Value) -> Option<u32> {
+    json_u64(value).and_then(|n| u32::try_from(n).ok())
+}
+
+/// "512x768" or "(1024, 1024)"
+fn parse_size(text: &str) -> Option<(u32, u32)> {
+    let mut numbers = text
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().ok());
+    Some((numbers.next()??, numbers.next()??))
+}
+
+/// "name : 0.5" or "name:0.5", as written by Fooocus
+fn parse_lora(text: &str) -> Option<LoraReference> {
+    let (name, weight) = match text.rsplit_once(':') {
+        Some((name, weight)) if weight.trim().parse::<f64>().is_ok() => {
+            (name, weight.trim().parse().ok())
+        }
+        _ => (text, None),
+    };
+    Some(LoraReference {
+        name: non_empty(name)?,
+        weight,
+    })
+}
+
+/// `<lora:name:weight>` and `<lyco:name:weight>` tags in an A1111 prompt
+fn prompt_loras(prompt: &str) -> Vec<LoraReference> {
+    let mut loras = Vec::new();
+    let mut rest = prompt;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        let mut parts = tag.split(':');
+        if matches!(parts.next(), Some("lora") | Some("lyco")) {
+            if let Some(name) = parts.next().and_then(non_empty) {
+                let weight = parts.next().and_then(|w| w.trim().parse().ok());
+                loras.push(LoraReference { name, weight });
+            }
+        }
+        rest = &rest[start + end + 1..];
+    }
+    loras
+}
+
+/// Split A1111's settings line, `Steps: 20, Sampler: Euler a, Lora hashes:
+/// "a: 1f2e, b: 3c4d"`, into key/value pairs. Quoted values may hold commas.
+fn split_settings(line: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut rest = line.trim();
+    while !rest.is_empty() {
+        let Some(colon) = rest.find(':') else {
+            break;
+        };
+        let key = rest[..colon].trim().to_string();
+        let after = rest[colon + 1..].trim_start();
+        let (value, remainder) = if let Some(quoted) = after.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            let remainder = quoted.get(end + 1..).unwrap_or("");
+            (&quoted[..end], remainder)
+        } else {
+            let end = after.find(',').unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        };
+        pairs.push((key, value.trim().to_string()));
+        rest = remainder.trim_start_matches([',', ' ']);
+    }
+    pairs
+}
+
+/// AUTOMATIC1111 text: the prompt, an optional "Negative prompt:" section,
+/// then one settings line starting with "Steps:"
+fn parse_a1111(text: &str, chunk: &str) -> Option<GenerationParameters> {
+    let lines: Vec<&str> = text.lines().collect();
+    let settings_at = lines
+        .iter()
+        .rposition(|l| l.trim_start().starts_with("Steps:"));
+    let (body, settings) = match settings_at {
+        Some(i) => (lines[..i].join("\n"), split_settings(lines[i])),
+        None => (text.to_string(), vec![]),
+    };
+    let mut params = GenerationParameters::new(GenerationTool::Automatic1111, &[chunk]);
+    match body.find("Negative prompt:") {
+        Some(at) => {
+            params.prompt = non_empty(&body[..at]);
+            params.negative_prompt = non_empty(&body[at + "Negative prompt:".len()..]);
+        }
+        None => params.prompt = non_empty(&body),
+    }
+    params.loras = params
+        .prompt
+        .as_deref()
+        .map(prompt_loras)
+        .unwrap_or_default();
+
+    for (key, value) in settings {
+        match key.as_str() {
+            "Steps" => params.steps = value.parse().ok(),
+            "Sampler" => params.sampler = non_empty(&value),
+            "Schedule type" => params.scheduler = non_empty(&value),
+            "CFG scale" => params.cfg_scale = value.parse().ok(),
+            "Seed" => params.seed = value.parse().ok(),
+            "Size" => {
+                if let Some((width, height)) = parse_size(&value) {
+                    params.width = Some(width);
+                    params.height = Some(height);
+                }
+            }
+            "Model" => params.model = non_empty(&value),
+            "Model hash" => params.model_hash = non_empty(&value),
+            "Version" => {
+                if value.starts_with("Fooocus") {
+                    params.tool = GenerationTool::Fooocus;
+                }
+                params.tool_version = non_empty(&value);
+            }
+            // "name: hash" pairs, for LoRAs not written into the prompt
+            "Lora hashes" => {
+                for entry in value.split(',') {
+                    let name = entry.split(':').next().unwrap_or_default().trim();
+                    if !name.is_empty() && !params.loras.iter().any(|l| l.name == name) {
+                        params.loras.push(LoraReference {
+                            name: name.to_string(),
+                            weight: None,
+                        });
+                    }
+                }
+            }
+            _ => params.other_settings.push(GenerationSetting { key, value }),
+        }
+    }
+    params.has_settings().then_some(params)
+}
+
+/// Fooocus JSON `parameters`, in its own metadata scheme
+fn parse_fooocus(json: &Map<String, Value>, chunk: &str) -> GenerationParameters {
+    let mut params = GenerationParameters::new(GenerationTool::Fooocus, &[chunk]);
+    for (key, value) in json {
+        let normalized = key.to_lowercase().replace(' ', "_");
+        match normalized.as_str() {
+            "prompt" => params.prompt = json_text(value),
+            "negative_prompt" => params.negative_prompt = json_text(value),
+            "steps" => params.steps = json_u32(value),
+            "sampler" => params.sampler = json_text(value),
+            "scheduler" => params.scheduler = json_text(value),
+            "guidance_scale" | "cfg_scale" => params.cfg_scale = json_f64(value),
+            "seed" => params.seed = json_u64(value),
+            "base_model" => params.model = json_text(value),
+            "base_model_hash" => params.model_hash = json_text(value),
+            "version" => params.tool_version = json_text(value),
+            "resolution" => {
+                if let Some((width, height)) = json_text(value).as_deref().and_then(parse_size) {
+                    params.width = Some(width);
+                    params.height = Some(height);
+                }
+            }
+            "loras" => {
+                for lora in value.as_array().into_iter().flatten() {
+                    let reference = match lora {
+                        Value::Array(pair) => {
+                            pair.first().and_then(json_text).map(|name| LoraReference {
+                                name,
+                                weight: pair.get(1).and_then(json_f64),
+                            })
+                        }
+                        other => json_text(other).as_deref().and_then(parse_lora),
+                    };
+                    params.loras.extend(reference);
+                }
+            }
+            _ if normalized.starts_with("lora_combined_") => {
+                params
+                    .loras
+                    .extend(json_text(value).as_deref().and_then(parse_lora));
+            }
+            _ => {
+                if let Some(text) = json_text(value) {
+                    params.other_settings.push(GenerationSetting {
+                        key: key.clone(),
+                        value: text,
+                    });
+                }
+            }
+        }
+    }
+    params
+}
+
+/// The text a ComfyUI conditioning input resolves to, following links
+/// (`["node id", output]`) through the API graph
+fn comfy_text(graph: &Map<String, Value>, input: &Value, depth: usize) -> Option<String> {
+    if depth > MAX_GRAPH_DEPTH {
+        return None;
+    }
+    if let Some(text) = input.as_str() {
+        return non_empty(text);
+    }
+    let node = graph.get(input.get(0)?.as_str()?)?;
+    let inputs = node.get("inputs")?;
+    for key in ["text", "text_g", "prompt", "conditioning", "conditioning_1"] {
+        if let Some(text) = inputs
+            .get(key)
+            .and_then(|v| comfy_text(graph, v, depth + 1))
+        {
+            return Some(text);
+        }
+    }
+    None
+}
+
+fn class_type(node: &Value) -> &str {
+    node.get("class_type")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+/// ComfyUI's API-format `prompt` graph: a map of node id to
+/// `{"class_type", "inputs"}`
+fn parse_comfyui_prompt(graph: &Map<String, Value>, params: &mut GenerationParameters) {
+    let mut nodes: Vec<(&String, &Value)> = graph.iter().collect();
+    // Node ids are numbers written as strings; sample in execution order
+    nodes.sort_by_key(|(id, _)| id.parse::<u64>().unwrap_or(u64::MAX));
+
+    for (_, node) in &nodes {
+        let Some(inputs) = node.get("inputs") else {
+            continue;
+        };
+        let class = class_type(node);
+        if class.contains("KSampler") && params.steps.is_none() {
+            params.seed = inputs
+                .get("seed")
+                .or_else(|| inputs.get("noise_seed"))
+                .and_then(json_u64);
+            params.steps = inputs.get("steps").and_then(json_u32);
+            params.cfg_scale = inputs.get("cfg").and_then(json_f64);
+            params.sampler = inputs.get("sampler_name").and_then(json_text);
+            params.scheduler = inputs.get("scheduler").and_then(json_text);
+            params.prompt = inputs.get("positive").and_then(|p| comfy_text(graph, p, 0));
+            params.negative_prompt = inputs.get("negative").and_then(|n| comfy_text(graph, n, 0));
+        }
+        if params.model.is_none() {
+            params.model = inputs
+                .get("ckpt_name")
+                .or_else(|| inputs.get("unet_name"))
+                .and_then(json_text);
+        }
+        if let Some(name) = inputs.get("lora_name").and_then(json_text) {
+            params.loras.push(LoraReference {
+                name,
+                weight: inputs.get("strength_model").and_then(json_f64),
+            });
+        }
+        if class == "EmptyLatentImage" {
+            params.width = inputs.get("width").and_then(json_u32);
+            params.height = inputs.get("height").and_then(json_u32);
+        }
+    }
+}
+
+/// ComfyUI's UI-format `workflow`, used when the API graph is missing: only
+/// the checkpoint and LoRAs can be read reliably from widget values
+fn parse_comfyui_workflow(workflow: &Value, params: &mut GenerationParameters) {
+    for node in workflow["nodes"].as_array().into_iter().flatten() {
+        let widgets = &node["widgets_values"];
+        match node["type"].as_str().unwrap_or_default() {
+            "CheckpointLoaderSimple" | "UNETLoader" if params.model.is_none() => {
+                params.model = widgets.get(0).and_then(json_text);
+            }
+            "LoraLoader" | "LoraLoaderModelOnly" => {
+                if let Some(name) = widgets.get(0).and_then(json_text) {
+                    params.loras.push(LoraReference {
+                        name,
+                        weight: widgets.get(1).and_then(json_f64),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_comfyui(prompt: Option<&PngText>, workflow: Option<&PngText>) -> GenerationParameters {
+    let chunks: Vec<&str> = prompt
+        .iter()
+        .chain(&workflow)
+        .map(|c| c.keyword.as_str())
+        .collect();
+    let mut params = GenerationParameters::new(GenerationTool::ComfyUi, &chunks);
+    let graph = prompt.and_then(|p| serde_json::from_str::<Value>(&p.text).ok());
+    if let Some(Value::Object(graph)) = &graph {
+        parse_comfyui_prompt(graph, &mut params);
+    }
+    if let Some(workflow) = workflow {
+        if let Ok(parsed) = serde_json::from_str::<Value>(&workflow.text) {
+            if graph.is_none() {
+                parse_comfyui_workflow(&parsed, &mut params);
+            }
+        }
+        params.workflow = Some(workflow.text.clone());
+    } else {
+        params.workflow = prompt.map(|p| p.text.clone());
+    }
+    params
+}
+
+/// `{"model_name": …}` or `{"name": …}`, as InvokeAI writes model references
+fn invoke_model_name(model: &Value) -> Option<String> {
+    model
+        .get("model_name")
+        .or_else(|| model.get("name"))
+        .and_then(json_text)
+        .or_else(|| json_text(model))
+}
+
+/// InvokeAI 3+ `invokeai_metadata`, or the legacy 2.x `sd-metadata`
+fn parse_invokeai(metadata: &Value, chunk: &str, graph: Option<&PngText>) -> GenerationParameters {
+    let mut chunks = vec![chunk];
+    chunks.extend(graph.map(|g| g.keyword.as_str()));
+    let mut params = GenerationParameters::new(GenerationTool::InvokeAi, &chunks);
+    params.tool_version = metadata.get("app_version").and_then(json_text);
+    params.workflow = graph.map(|g| g.text.clone());
+
+    // Legacy metadata nests the settings under "image"
+    let image = metadata.get("image").unwrap_or(metadata);
+    params.prompt = match image
+        .get("prompt")
+        .or_else(|| metadata.get("positive_prompt"))
+    {
+        Some(Value::Array(prompts)) => prompts
+            .first()
+            .and_then(|p| p.get("prompt"))
+            .and_then(json_text),
+        Some(prompt) => json_text(prompt),
+        None => None,
+    };
+    params.negative_prompt = metadata.get("negative_prompt").and_then(json_text);
+    params.steps = image.get("steps").and_then(json_u32);
+    params.cfg_scale = image.get("cfg_scale").and_then(json_f64);
+    params.seed = image.get("seed").and_then(json_u64);
+    params.width = image.get("width").and_then(json_u32);
+    params.height = image.get("height").and_then(json_u32);
+    params.sampler = image
+        .get("sampler")
+        .or_else(|| image.get("scheduler"))
+        .and_then(json_text);
+    match metadata.get("model") {
+        Some(model) => {
+            params.model = invoke_model_name(model);
+            params.model_hash = model.get("hash").and_then(json_text);
+        }
+        None => params.model = metadata.get("model_weights").and_then(json_text),
+    }
+    for lora in metadata["loras"].as_array().into_iter().flatten() {
+        let model = lora.get("lora").or_else(|| lora.get("model"));
+        if let Some(name) = model.and_then(invoke_model_name) {
+            params.loras.push(LoraReference {
+                name,
+                weight: lora.get("weight").and_then(json_f64),
+            });
+        }
+    }
+    params
+}
+
+/// NovelAI: settings as JSON in `Comment`, the prompt in `Description` and
+/// the model and its hash in `Source`, e.g. "Stable Diffusion XL C1E1DE52"
+fn parse_novelai(
+    comment: &Value,
+    description: Option<&PngText>,
+    source: Option<&PngText>,
+) -> GenerationParameters {
+    let mut chunks = vec!["Comment"];
+    chunks.extend(description.map(|d| d.keyword.as_str()));
+    chunks.extend(source.map(|s| s.keyword.as_str()));
+    let mut params = GenerationParameters::new(GenerationTool::NovelAi, &chunks);
+    params.prompt = comment
+        .get("prompt")
+        .and_then(json_text)
+        .or_else(|| description.and_then(|d| non_empty(&d.text)));
+    params.negative_prompt = comment.get("uc").and_then(json_text);
+    params.steps = comment.get("steps").and_then(json_u32);
+    params.cfg_scale = comment.get("scale").and_then(json_f64);
+    params.seed = comment.get("seed").and_then(json_u64);
+    params.sampler = comment.get("sampler").and_then(json_text);
+    params.scheduler = comment.get("noise_schedule").and_then(json_text);
+    params.width = comment.get("width").and_then(json_u32);
+    params.height = comment.get("height").and_then(json_u32);
+    if let Some(source) = source {
+        let text = source.text.trim();
+        match text.rsplit_once(' ') {
+            Some((model, hash))
+                if hash.len() == 8 && hash.chars().all(|c| c.is_ascii_hexdigit()) =>
+            {
+                params.model = non_empty(model);
+                params.model_hash = Some(hash.to_string());
+            }
+            _ => params.model = non_empty(text),
+        }
+    }
+    params
+}
+
+/// Generation parameters from a PNG's text chunks, if a known tool wrote any
+pub(crate) fn generation_parameters(chunks: &[PngText]) -> Option<GenerationParameters> {
+    let chunk = |keyword: &str| chunks.iter().find(|c| c.keyword == keyword);
+    let json = |keyword: &str| {
+        chunk(keyword).and_then(|c| match serde_json::from_str::<Value>(&c.text) {
+            Ok(value @ Value::Object(_)) => Some(value),
+            _ => None,
+        })
+    };
+
+    if let Some(metadata) = json("invokeai_metadata") {
+        let graph = chunk("invokeai_workflow").or_else(|| chunk("invokeai_graph"));
+        return Some(parse_invokeai(&metadata, "invokeai_metadata", graph));
+    }
+    if let Some(metadata) = json("sd-metadata") {
+        return Some(parse_invokeai(&metadata, "sd-metadata", None));
+    }
+    // ComfyUI's graph nodes all carry a class_type
+    let comfy_prompt = json("prompt").filter(|graph| {
+        graph
+            .as_object()
+            .is_some_and(|nodes| nodes.values().any(|n| n.get("class_type").is_some()))
+    });
+    if comfy_prompt.is_some() || json("workflow").is_some() {
+        let prompt = comfy_prompt.and(chunk("prompt"));
+        return Some(parse_comfyui(prompt, chunk("workflow")));
+    }
+    if let Some(parameters) = chunk("parameters") {
+        return match json("parameters") {
+            Some(Value::Object(map)) => Some(parse_fooocus(&map, "parameters")),
+            _ => parse_a1111(&parameters.text, "parameters"),
+        };
+    }
+    let novelai = chunk("Software").is_some_and(|s| s.text.contains("NovelAI"));
+    if let Some(comment) = json("Comment").filter(|_| novelai) {
+        return Some(parse_novelai(
+            &comment,
+            chunk("Description"),
+            chunk("Source"),
+        ));
+    }
+    None
+}
+
+/// Strong AI evidence from generation parameters: only image generators
+/// write them
+pub(crate) fn generation_evidence(params: &GenerationParameters) -> Evidence {
+    let chunk = params.source_chunks.first().cloned().unwrap_or_default();
+    Evidence {
+        detector: EvidenceDetector::PngText,
+        kind: EvidenceKind::AiGeneration,
+        explanation: format!(
+            "The PNG \"{}\" text chunk holds {} generation parameters",
+            chunk,
+            params.tool.name()
+        ),
+        field: chunk,
+        matched_text: params
+            .model
+            .clone()
+            .unwrap_or_else(|| params.tool.name().to_string()),
+        strength: EvidenceStrength::Strong,
+        generator: Some(params.tool.name().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::model_identity::identify;
+    use crate::api::png::read_png_text;
+    use crate::api::png::tests::png_with_text;
+    use std::io::Cursor;
+
+    fn text(keyword: &str, text: &str) -> PngText {
+        PngText {
+            chunk_type: "tEXt".to_string(),
+            keyword: keyword.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    const A1111: &str = "masterpiece, a red fox in snow <lora:add_detail:0.6>\n\
+        Negative prompt: blurry, lowres\n\
+        Steps: 30, Sampler: DPM++ 2M, Schedule type: Karras, CFG scale: 6.5, Seed: 3141592653, \
+        Size: 832x1216, Model hash: 31e35c80fc, Model: sd_xl_base_1.0, Denoising strength: 0.4, \
+        Lora hashes: \"add_detail: 7c6bad76eb54, film_grain: 0123abcd\", Version: v1.7.0";
+
+    #[test]
+    fn parses_automatic1111_parameters() {
+        let params = generation_parameters(&[text("parameters", A1111)]).unwrap();
+        assert_eq!(params.tool, GenerationTool::Automatic1111);
+        assert_eq!(
+            params.prompt.as_deref(),
+            Some("masterpiece, a red fox in snow <lora:add_detail:0.6>")
+        );
+        assert_eq!(params.negative_prompt.as_deref(), Some("blurry, lowres"));
+        assert_eq!(params.steps, Some(30));
+        assert_eq!(params.sampler.as_deref(), Some("DPM++ 2M"));
+        assert_eq!(params.scheduler.as_deref(), Some("Karras"));
+        assert_eq!(params.cfg_scale, Some(6.5));
+        assert_eq!(params.seed, Some(3141592653));
+        assert_eq!((params.width, params.height), (Some(832), Some(1216)));
+        assert_eq!(params.model.as_deref(), Some("sd_xl_base_1.0"));
+        assert_eq!(params.model_hash.as_deref(), Some("31e35c80fc"));
+        assert_eq!(params.tool_version.as_deref(), Some("v1.7.0"));
+        let loras: Vec<_> = params
+            .loras
+            .iter()
+            .map(|l| (l.name.as_str(), l.weight))
+            .collect();
+        assert_eq!(loras, [("add_detail", Some(0.6)), ("film_grain", None)]);
+        assert_eq!(
+            params.other_settings,
+            [GenerationSetting {
+                key: "Denoising strength".to_string(),
+                value: "0.4".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn every_tool_is_a_known_generator() {
+        let tools = [
+            GenerationTool::Automatic1111,
+            GenerationTool::ComfyUi,
+            GenerationTool::Fooocus,
+            GenerationTool::InvokeAi,
+            GenerationTool::NovelAi,
+        ];
+        for tool in tools {
+            let identity = identify(tool.name(), None, "parameters").unwrap();
+            assert!(identity.is_ai_generator, "{}", tool.name());
+        }
+    }
+
+    #[test]
+    fn captions_are_not_generation_parameters() {
+        assert!(generation_parameters(&[text("parameters", "Holiday in Rome")]).is_none());
+        assert!(generation_parameters(&[text("Comment", "{\"prompt\": \"x\"}")]).is_none());
+    }
+
+    #[test]
+    fn parses_comfyui_graphs() {
+        let prompt = r#"{
+            "4": {"class_type": "CheckpointLoaderSimple", "inputs": {"ckpt_name": "sd_xl_base_1.0.safetensors"}},
+            "5": {"class_type": "EmptyLatentImage", "inputs": {"width": 1024, "height": 1024, "batch_size": 1}},
+            "6": {"class_type": "CLIPTextEncode", "inputs": {"text": "a lighthouse at dusk", "clip": ["10", 1]}},
+            "7": {"class_type": "CLIPTextEncode", "inputs": {"text": "text, watermark", "clip": ["10", 1]}},
+            "3": {"class_type": "KSampler", "inputs": {"seed": 18446744073709551615, "steps": 25, "cfg": 7.0,
+                  "sampler_name": "euler", "scheduler": "normal", "positive": ["6", 0], "negative": ["7", 0],
+                  "model": ["10", 0], "latent_image": ["5", 0]}},
+            "10": {"class_type": "LoraLoader", "inputs": {"lora_name": "pixel_art.safetensors",
+                   "strength_model": 0.8, "model": ["4", 0], "clip": ["4", 1]}}
+        }"#;
+        let png = png_with_text(&[
+            (b"tEXt", "prompt", prompt),
+            (b"iTXt", "workflow", "{\"nodes\": []}"),
+        ]);
+        let chunks = read_png_text(&mut Cursor::new(png));
+        let params = generation_parameters(&chunks).unwrap();
+        assert_eq!(params.tool, GenerationTool::ComfyUi);
+        assert_eq!(params.prompt.as_deref(), Some("a lighthouse at dusk"));
+        assert_eq!(params.negative_prompt.as_deref(), Some("text, watermark"));
+        assert_eq!(params.seed, Some(u64::MAX));
+        assert_eq!(params.steps, Some(25));
+        assert_eq!(params.sampler.as_deref(), Some("euler"));
+        assert_eq!(params.model.as_deref(), Some("sd_xl_base_1.0.safetensors"));
+        assert_eq!(params.loras[0].name, "pixel_art.safetensors");
+        assert_eq!(params.loras[0].weight, Some(0.8));
+        assert_eq!(params.workflow.as_deref(), Some("{\"nodes\": []}"));
+        assert_eq!(params.source_chunks, ["prompt", "workflow"]);
+    }
+
+    #[test]
+    fn parses_fooocus_json() {
+        let json = r#"{"Prompt": "a koi pond", "Negative Prompt": "", "Steps": 30,
+            "Guidance Scale": 4, "Seed": "1234", "Resolution": "(1152, 896)",
+            "Base Model": "juggernautXL_v8Rundiffusion.safetensors",
+            "LoRA 1": "sd_xl_offset_example-lora_1.0.safetensors : 0.1",
+            "loras": [["sd_xl_offset_example-lora_1.0.safetensors", 0.1]],
+            "Version": "Fooocus v2.5.3"}"#;
+        let params = generation_parameters(&[text("parameters", json)]).unwrap();
+        assert_eq!(params.tool, GenerationTool::Fooocus);
+        assert_eq!(params.prompt.as_deref(), Some("a koi pond"));
+        assert_eq!(params.negative_prompt, None);
+        assert_eq!(params.cfg_scale, Some(4.0));
+        assert_eq!(params.seed, Some(1234));
+        assert_eq!((params.width, params.height), (Some(1152), Some(896)));
+        assert_eq!(params.loras[0].weight, Some(0.1));
+        assert_eq!(params.tool_version.as_deref(), Some("Fooocus v2.5.3"));
+
+        let a1111_scheme = A1111.replace("Version: v1.7.0", "Version: Fooocus v2.5.3");
+        let params = generation_parameters(&[text("parameters", &a1111_scheme)]).unwrap();
+        assert_eq!(params.tool, GenerationTool::Fooocus);
+    }
+
+    #[test]
+    fn parses_invokeai_metadata() {
+        let metadata = r#"{"app_version": "4.2.4", "positive_prompt": "an astronaut riding a horse",
+            "negative_prompt": "", "seed": 42, "steps": 40, "cfg_scale": 7.5, "scheduler": "dpmpp_2m",
+            "width": 1024, "height": 768,
+            "model": {"name": "Juggernaut XL v9", "hash": "blake3:0123"},
+            "loras": [{"model": {"name": "detail-tweaker"}, "weight": 0.5}]}"#;
+        let chunks = [
+            text("invokeai_metadata", metadata),
+            text("invokeai_graph", "{}"),
+        ];
+        let params = generation_parameters(&chunks).unwrap();
+        assert_eq!(params.tool, GenerationTool::InvokeAi);
+        assert_eq!(
+            params.prompt.as_deref(),
+            Some("an astronaut riding a horse")
+        );
+        assert_eq!(params.sampler.as_deref(), Some("dpmpp_2m"));
+        assert_eq!(params.model.as_deref(), Some("Juggernaut XL v9"));
+        assert_eq!(params.model_hash.as_deref(), Some("blake3:0123"));
+        assert_eq!(params.loras[0].name, "detail-tweaker");
+        assert_eq!(params.workflow.as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn parses_novelai_comments() {
+        let comment = r#"{"prompt": "1girl, cherry blossoms", "steps": 28, "height": 1216,
+            "width": 832, "scale": 5.0, "seed": 987654321, "sampler": "k_euler_ancestral",
+            "noise_schedule": "native", "uc": "lowres, bad anatomy"}"#;
+        let chunks = [
+            text("Title", "AI generated image"),
+            text("Description", "1girl, cherry blossoms"),
+            text("Software", "NovelAI"),
+            text("Source", "Stable Diffusion XL C1E1DE52"),
+            text("Comment", comment),
+        ];
+        let params = generation_parameters(&chunks).unwrap();
+        assert_eq!(params.tool, GenerationTool::NovelAi);
+        assert_eq!(
+            params.negative_prompt.as_deref(),
+            Some("lowres, bad anatomy")
+        );
+        assert_eq!(params.cfg_scale, Some(5.0));
+        assert_eq!(params.model.as_deref(), Some("Stable Diffusion XL"));
+        assert_eq!(params.model_hash.as_deref(), Some("C1E1DE52"));
+    }
+}
@@ -0,0 +1,387 @@
+//! Asset inputs for the analysis pipeline.
+//!
+//! Every entry point wraps its input in an [`AssetInput`] and hands it to the
+//! same pipeline, so detectors only ever deal with a `Read + Seek` stream.
+
+use c2pa::Reader;
+use flutter_rust_bridge::frb;
+use std::fs::File;
+use std::io::{self, BufReader, Cursor, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use super::error::AnalysisError;
+use super::mime::{sniff_mime_type, SNIFF_LEN};
+
+/// A source of asset bytes the analysis pipeline can read from
+pub(crate) trait AssetInput {
+    type Stream: Read + Seek + Send;
+
+    /// MIME type claimed by the caller or implied by the file name
+    fn mime_type(&self) -> String;
+
+    /// Open a stream positioned at the start of the asset
+    fn open(self) -> io::Result<Self::Stream>;
+}
+
+/// Guess a MIME type from a file extension
+fn mime_type_from_extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| match ext.to_lowercase().as_str() {
+            "jpg" | "jpeg" => "image/jpeg",
+            "png" => "image/png",
+            "webp" => "image/webp",
+            "gif" => "image/gif",
+            "avif" => "image/avif",
+            "heic" | "heif" => "image/heif",
+            "tif" | "tiff" => "image/tiff",
+            "dng" => "image/x-adobe-dng",
+            "mp4" => "video/mp4",
+            "mov" => "video/quicktime",
+            "wav" => "audio/wav",
+            "mp3" => "audio/mpeg",
+            "pdf" => "application/pdf",
+            "svg" => "image/svg+xml",
+            _ => "application/octet-stream",
+        })
+        .unwrap_or("application/octet-stream")
+        .to_string()
+}
+
+/// A file read through a buffered handle
+pub(crate) struct FileInput {
+    path: PathBuf,
+}
+
+impl FileInput {
+    pub(crate) fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl AssetInput for FileInput {
+    type Stream = BufReader<File>;
+
+    fn mime_type(&self) -> String {
+        mime_type_from_extension(&self.path)
+    }
+
+    fn open(self) -> io::Result<Self::Stream> {
+        File::open(&self.path).map(BufReader::new)
+    }
+}
+
+/// A file mapped into memory, for large assets that should not be copied
+pub(crate) struct MappedFileInput {
+    path: PathBuf,
+}
+
+impl MappedFileInput {
+    pub(crate) fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl AssetInput for MappedFileInput {
+    type Stream = Cursor<memmap2::Mmap>;
+
+    fn mime_type(&self) -> String {
+        mime_type_from_extension(&self.path)
+    }
+
+    fn open(self) -> io::Result<Self::Stream> {
+        let file = File::open(&self.path)?;
+        // Safety: the mapping is read-only and dropped when analysis finishes.
+        // Truncating the file while it is analyzed is not supported.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Cursor::new(map))
+    }
+}
+
+/// Bytes already held in memory
+pub(crate) struct BytesInput {
+    data: Vec<u8>,
+    mime_type: String,
+}
+
+impl BytesInput {
+    pub(crate) fn new(data: Vec<u8>, mime_type: String) -> Self {
+        Self { data, mime_type }
+    }
+}
+
+impl AssetInput for BytesInput {
+    type Stream = Cursor<Vec<u8>>;
+
+    fn mime_type(&self) -> String {
+        self.mime_type.clone()
+    }
+
+    fn open(self) -> io::Result<Self::Stream> {
+        Ok(Cursor::new(self.data))
+    }
+}
+
+/// An opened asset and the MIME type its content actually has
+pub(crate) struct OpenedAsset<S> {
+    pub stream: S,
+    /// MIME type claimed by the caller or implied by the file name
+    pub claimed_mime_type: String,
+    /// MIME type sniffed from the first bytes, if recognized
+    pub detected_mime_type: Option<&'static str>,
+}
+
+impl<S: Read + Seek + Send> OpenedAsset<S> {
+    /// Open `input` and sniff its type from the first bytes. The stream is
+    /// left after them; [`OpenedAsset::manifest_reader`] rewinds it.
+    pub(crate) fn open<I: AssetInput<Stream = S>>(input: I) -> Result<Self, AnalysisError> {
+        let claimed_mime_type = input.mime_type();
+        let mut stream = input.open().map_err(|e| AnalysisError::io(&e))?;
+        let mut header = Vec::new();
+        stream
+            .by_ref()
+            .take(SNIFF_LEN)
+            .read_to_end(&mut header)
+            .map_err(|e| AnalysisError::io(&e))?;
+        Ok(Self {
+            stream,
+            claimed_mime_type,
+            detected_mime_type: sniff_mime_type(&header),
+        })
+    }
+
+    /// The sniffed MIME type, else the claimed one, so renamed files still parse
+    pub(crate) fn mime_type(&self) -> String {
+        self.detected_mime_type
+            .map(|m| m.to_string())
+            .unwrap_or_else(|| self.claimed_mime_type.clone())
+    }
+
+    /// Read the manifest store from the start of the stream
+    pub(crate) fn manifest_reader(&mut self) -> Result<Reader, AnalysisError> {
+        let mime_type = self.mime_type();
+        self.stream.rewind().map_err(|e| AnalysisError::io(&e))?;
+        Reader::from_stream(&mime_type, &mut self.stream)
+            .map_err(|e| AnalysisError::from_c2pa(e, &mime_type))
+    }
+}
+
+/// An asset streamed from Dart in chunks, e.g. from a file picker or network
+/// download, without first concatenating it into one buffer
+#[frb(opaque)]
+pub struct ChunkedAsset {
+    mime_type: String,
+    chunks: Vec<Vec<u8>>,
+}
+
+impl ChunkedAsset {
+    #[frb(sync)]
+    pub fn new(mime_type: String) -> Self {
+        Self {
+            mime_type,
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Append the next chunk of the asset
+    #[frb(sync)]
+    pub fn append(&mut self, chunk: Vec<u8>) {
+        if !chunk.is_empty() {
+            self.chunks.push(chunk);
+        }
+    }
+
+    /// Total number of bytes received so far
+    #[frb(sync)]
+    pub fn len(&self) -> u64 {
+        self.chunks.iter().map(|c| c.len() as u64).sum()
+    }
+
+    #[frb(sync)]
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+}
+
+impl AssetInput for ChunkedAsset {
+    type Stream = ChunkedReader;
+
+    fn mime_type(&self) -> String {
+        self.mime_type.clone()
+    }
+
+    fn open(self) -> io::Result<Self::Stream> {
+        Ok(ChunkedReader::new(self.chunks))
+    }
+}
+
+/// `Read + Seek` over a list of chunks
+pub(crate) struct ChunkedReader {
+    chunks: Vec<Vec<u8>>,
+    /// Offset of the first byte of each chunk
+    starts: Vec<u64>,
+    len: u64,
+    pos: u64,
+}
+
+impl ChunkedReader {
+    fn new(chunks: Vec<Vec<u8>>) -> Self {
+        let mut starts = Vec::with_capacity(chunks.len());
+        let mut len = 0;
+        for chunk in &chunks {
+            starts.push(len);
+            len += chunk.len() as u64;
+        }
+        Self {
+            chunks,
+            starts,
+            len,
+            pos: 0,
+        }
+    }
+}
+
+impl Read for ChunkedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos >= self.len || buf.is_empty() {
+            return Ok(0);
+        }
+        // Chunks are never empty, so the containing chunk is the last one starting at or before pos
+        let index = self.starts.partition_point(|&start| start <= self.pos) - 1;
+        let offset = (self.pos - self.starts[index]) as usize;
+        let available = &self.chunks[index][offset..];
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.pos += count as u64;
+        Ok(count)
+    }
+}
+
+impl Seek for ChunkedReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(delta) => self.len.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta),
+        };
+        match target {
+            Some(target) => {
+                self.pos = target;
+                Ok(target)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reader(chunks: &[&[u8]]) -> ChunkedReader {
+        ChunkedReader::new(chunks.iter().map(|c| c.to_vec()).collect())
+    }
+
+    #[test]
+    fn reads_stop_at_chunk_boundaries_and_continue() {
+        let mut stream = reader(&[b"abc", b"de", b"fghij"]);
+        let mut buf = [0u8; 4];
+        assert_eq!(stream.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf[..3], b"abc");
+        assert_eq!(stream.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"de");
+
+        // read_exact and read_to_end stitch chunks together
+        stream.rewind().unwrap();
+        let mut exact = [0u8; 7];
+        stream.read_exact(&mut exact).unwrap();
+        assert_eq!(&exact, b"abcdefg");
+        let mut rest = Vec::new();
+        stream.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"hij");
+        assert_eq!(stream.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn seeks_land_inside_the_right_chunk() {
+        let mut stream = reader(&[b"abc", b"de", b"fghij"]);
+        let mut byte = [0u8; 1];
+
+        // Every absolute position reads the byte stored there
+        for (position, expected) in b"abcdefghij".iter().enumerate() {
+            assert_eq!(
+                stream.seek(SeekFrom::Start(position as u64)).unwrap(),
+                position as u64
+            );
+            stream.read_exact(&mut byte).unwrap();
+            assert_eq!(byte[0], *expected, "position {}", position);
+        }
+
+        assert_eq!(stream.seek(SeekFrom::End(-2)).unwrap(), 8);
+        stream.read_exact(&mut byte).unwrap();
+        assert_eq!(&byte, b"i");
+
+        // Back across two chunk boundaries from the current position
+        assert_eq!(stream.seek(SeekFrom::Current(-7)).unwrap(), 2);
+        let mut span = [0u8; 4];
+        stream.read_exact(&mut span).unwrap();
+        assert_eq!(&span, b"cdef");
+    }
+
+    #[test]
+    fn seeks_outside_the_data() {
+        let mut stream = reader(&[b"abc", b"de"]);
+        // Past the end is allowed and reads nothing
+        assert_eq!(stream.seek(SeekFrom::Start(20)).unwrap(), 20);
+        let mut buf = [0u8; 4];
+        assert_eq!(stream.read(&mut buf).unwrap(), 0);
+        // Before the start is an error and leaves the position alone
+        assert!(stream.seek(SeekFrom::End(-6)).is_err());
+        assert!(stream.seek(SeekFrom::Current(-21)).is_err());
+        assert_eq!(stream.stream_position().unwrap(), 20);
+    }
+
+    #[test]
+    fn empty_chunks_are_dropped() {
+        let mut asset = ChunkedAsset::new("image/png".to_string());
+        assert!(asset.is_empty());
+        asset.append(vec![]);
+        assert!(asset.is_empty());
+        asset.append(b"abc".to_vec());
+        asset.append(vec![]);
+        asset.append(b"de".to_vec());
+        assert_eq!(asset.len(), 5);
+        assert_eq!(asset.mime_type(), "image/png");
+
+        let mut data = Vec::new();
+        asset.open().unwrap().read_to_end(&mut data).unwrap();
+        assert_eq!(data, b"abcde");
+
+        let mut empty = Vec::new();
+        reader(&[]).read_to_end(&mut empty).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn mime_types_follow_the_extension() {
+        assert_eq!(
+            mime_type_from_extension(Path::new("a/photo.JPG")),
+            "image/jpeg"
+        );
+        assert_eq!(
+            mime_type_from_extension(Path::new("clip.mov")),
+            "video/quicktime"
+        );
+        assert_eq!(
+            mime_type_from_extension(Path::new("no_extension")),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            mime_type_from_extension(Path::new("archive.zip")),
+            "application/octet-stream"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! IPTC-IIM metadata, as newsrooms write it: inside the Photoshop Image
+//! Resource Blocks of a JPEG APP13 segment, or in the TIFF IPTC-NAA and
+//! Photoshop tags.
+
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Seek};
+
+use super::container::{jpeg_segments, tiff_tag};
+
+const PHOTOSHOP_HEADER: &[u8] = b"Photoshop 3.0\0";
+const IRB_SIGNATURE: &[u8] = b"8BIM";
+/// Image resource holding the IPTC-NAA record
+const IRB_IPTC: u16 = 0x0404;
+const TIFF_IPTC_TAG: u16 = 33723;
+const TIFF_PHOTOSHOP_TAG: u16 = 34377;
+/// Start of every IIM dataset
+const IIM_TAG_MARKER: u8 = 0x1c;
+/// ISO 2022 escape sequence in dataset 1:90 declaring UTF-8
+const UTF8_ESCAPE: &[u8] = b"\x1b%G";
+
+/// IPTC-IIM Application Record (record 2) fields
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IptcInfo {
+    /// 2:05 Object Name
+    pub object_name: Option<String>,
+    /// 2:105 Headline
+    pub headline: Option<String>,
+    /// 2:120 Caption/Abstract
+    pub caption: Option<String>,
+    /// 2:122 Writer/Editor of the caption
+    pub caption_writer: Option<String>,
+    /// 2:25 Keywords
+    pub keywords: Vec<String>,
+    /// 2:80 By-line: the photographers or creators
+    pub by_line: Vec<String>,
+    /// 2:85 By-line Title, e.g. "Staff Photographer"
+    pub by_line_title: Option<String>,
+    /// 2:110 Credit: who should be credited, usually the agency
+    pub credit: Option<String>,
+    /// 2:115 Source: the original owner of the content
+    pub source: Option<String>,
+    /// 2:116 Copyright Notice
+    pub copyright_notice: Option<String>,
+    /// 2:40 Special Instructions, e.g. embargoes or usage restrictions
+    pub special_instructions: Option<String>,
+    /// 2:55 Date Created with 2:60 Time Created, as written
+    /// ("20240302", "20240302T141105+0100")
+    pub date_created: Option<String>,
+    /// 2:90 City
+    pub city: Option<String>,
+    /// 2:92 Sub-location
+    pub sub_location: Option<String>,
+    /// 2:95 Province/State
+    pub province_state: Option<String>,
+    /// 2:100 Country/Primary Location Code
+    pub country_code: Option<String>,
+    /// 2:101 Country/Primary Location Name
+    pub country: Option<String>,
+    /// 2:103 Original Transmission Reference
+    pub transmission_reference: Option<String>,
+}
+
+/// Each dataset's record, number and value, in order. Datasets with an
+/// extended length are read too; a truncated dataset ends the stream.
+fn iim_datasets(data: &[u8]) -> Vec<(u8, u8, &[u8])> {
+    let mut datasets = Vec::new();
+    let mut rest = data;
+    while let [IIM_TAG_MARKER, record, dataset, hi, lo, tail @ ..] = rest {
+        let declared = u16::from_be_bytes([*hi, *lo]) as usize;
+        let (len, tail) = if declared & 0x8000 == 0 {
+            (declared, tail)
+        } else {
+            // The low bits count the bytes of the real length that follow
+            let count = declared & 0x7fff;
+            if count > 4 || tail.len() < count {
+                break;
+            }
+            let len = tail[..count]
+                .iter()
+                .fold(0usize, |len, &b| (len << 8) | b as usize);
+            (len, &tail[count..])
+        };
+        let Some(value) = tail.get(..len) else {
+            break;
+        };
+        datasets.push((*record, *dataset, value));
+        rest = &tail[len..];
+    }
+    datasets
+}
+
+/// The IPTC-NAA resource among Photoshop Image Resource Blocks
+fn irb_iptc(data: &[u8]) -> Option<&[u8]> {
+    let mut rest = data;
+    while rest.len() >= 12 && rest.starts_with(IRB_SIGNATURE) {
+        let id = u16::from_be_bytes([rest[4], rest[5]]);
+        // Pascal string name, padded so length byte and name are even
+        let name_len = rest[6] as usize;
+        let name_end = 6 + (name_len + 1).next_multiple_of(2);
+        let size_bytes = rest.get(name_end..name_end + 4)?;
+        let size = u32::from_be_bytes(size_bytes.try_into().ok()?) as usize;
+        let start = name_end + 4;
+        let value = rest.get(start..start.checked_add(size)?)?;
+        if id == IRB_IPTC {
+            return Some(value);
+        }
+        // Resource data is padded to an even length
+        rest = rest.get(start + size.next_multiple_of(2)..)?;
+    }
+    None
+}
+
+/// Text in the declared character set: UTF-8 when 1:90 says so, otherwise
+/// UTF-8 if it decodes, else Latin-1
+fn decode(value: &[u8], utf8: bool) -> Option<String> {
+    let text = match std::str::from_utf8(value) {
+        Ok(text) => text.to_string(),
+        Err(_) if utf8 => String::from_utf8_lossy(value).into_owned(),
+        Err(_) => value.iter().map(|&b| b as char).collect(),
+    };
+    let text = text.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Parse an IPTC-IIM stream into its Application Record fields
+pub(crate) fn parse_iim(data: &[u8]) -> Option<IptcInfo> {
+    let datasets = iim_datasets(data);
+    let utf8 = datasets
+        .iter()
+        .any(|&(record, number, value)| record == 1 && number == 90 && value == UTF8_ESCAPE);
+
+    let mut info = IptcInfo::default();
+    let mut date = None;
+    let mut time = None;
+    let mut found = false;
+    for (record, number, value) in datasets {
+        if record != 2 {
+            continue;
+        }
+        let Some(text) = decode(value, utf8) else {
+            continue;
+        };
+        found = true;
+        let single = |field: &mut Option<String>| {
+            field.get_or_insert(text.clone());
+        };
+        match number {
+            5 => single(&mut info.object_name),
+            25 => info.keywords.push(text),
+            40 => single(&mut info.special_instructions),
+            55 => date = Some(text),
+            60 => time = Some(text),
+            80 => info.by_line.push(text),
+            85 => single(&mut info.by_line_title),
+            90 => single(&mut info.city),
+            92 => single(&mut info.sub_location),
+            95 => single(&mut info.province_state),
+            100 => single(&mut info.country_code),
+            101 => single(&mut info.country),
+            103 => single(&mut info.transmission_reference),
+            105 => single(&mut info.headline),
+            110 => single(&mut info.credit),
+            115 => single(&mut info.source),
+            116 => single(&mut info.copyright_notice),
+            120 => single(&mut info.caption),
+            122 => single(&mut info.caption_writer),
+            _ => {}
+        }
+    }
+    info.date_created = match (date, time) {
+        (Some(date), Some(time)) => Some(format!("{}T{}", date, time)),
+        (date, _) => date,
+    };
+    found.then_some(info)
+}
+
+/// IPTC-IIM from a JPEG's APP13 segments or a TIFF's IPTC-NAA or Photoshop
+/// tag, if the asset has any
+pub(crate) fn read_iptc<R: Read + Seek>(stream: &mut R, mime_type: &str) -> Option<IptcInfo> {
+    match mime_type {
+        "image/jpeg" => {
+            // Large resource blocks continue across several APP13 segments
+            let mut resources = Vec::new();
+            for segment in jpeg_segments(stream, 0xed) {
+                if let Some(data) = segment.strip_prefix(PHOTOSHOP_HEADER) {
+                    resources.extend_from_slice(data);
+                }
+            }
+            parse_iim(irb_iptc(&resources)?)
+        }
+        "image/tiff" | "image/x-adobe-dng" => match tiff_tag(stream, TIFF_IPTC_TAG) {
+            Some(iim) => parse_iim(&iim),
+            None => parse_iim(irb_iptc(&tiff_tag(stream, TIFF_PHOTOSHOP_TAG)?)?),
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn dataset(record: u8, number: u8, value: &[u8]) -> Vec<u8> {
+        let mut out = vec![IIM_TAG_MARKER, record, number];
+        out.extend((value.len() as u16).to_be_bytes());
+        out.extend_from_slice(value);
+        out
+    }
+
+    fn newsroom_iim() -> Vec<u8> {
+        [
+            dataset(1, 90, UTF8_ESCAPE),
+            dataset(2, 0, &[0, 4]),
+            dataset(2, 5, b"ELECTION-RALLY"),
+            dataset(2, 25, b"politics"),
+            dataset(2, 25, b"election"),
+            dataset(2, 40, b"EMBARGOED UNTIL 18:00 CET"),
+            dataset(2, 55, b"20240302"),
+            dataset(2, 60, b"141105+0100"),
+            dataset(2, 80, "Zoë Müller".as_bytes()),
+            dataset(2, 85, b"Staff Photographer"),
+            dataset(2, 90, "Zürich".as_bytes()),
+            dataset(2, 101, b"Switzerland"),
+            dataset(2, 100, b"CHE"),
+            dataset(2, 110, b"Example News Agency"),
+            dataset(2, 115, b"ENA"),
+            dataset(2, 116, b"(c) 2024 ENA"),
+            dataset(2, 120, b"Supporters gather before the rally."),
+        ]
+        .concat()
+    }
+
+    fn irb(id: u16, data: &[u8]) -> Vec<u8> {
+        let mut out = IRB_SIGNATURE.to_vec();
+        out.extend(id.to_be_bytes());
+        // Empty name, padded
+        out.extend([0, 0]);
+        out.extend((data.len() as u32).to_be_bytes());
+        out.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            out.push(0);
+        }
+        out
+    }
+
+    fn check(info: IptcInfo) {
+        assert_eq!(info.object_name.as_deref(), Some("ELECTION-RALLY"));
+        assert_eq!(info.keywords, ["politics", "election"]);
+        assert_eq!(info.by_line, ["Zoë Müller"]);
+        assert_eq!(info.by_line_title.as_deref(), Some("Staff Photographer"));
+        assert_eq!(info.credit.as_deref(), Some("Example News Agency"));
+        assert_eq!(info.source.as_deref(), Some("ENA"));
+        assert_eq!(info.city.as_deref(), Some("Zürich"));
+        assert_eq!(info.country.as_deref(), Some("Switzerland"));
+        assert_eq!(info.country_code.as_deref(), Some("CHE"));
+        assert_eq!(info.date_created.as_deref(), Some("20240302T141105+0100"));
+        assert_eq!(
+            info.special_instructions.as_deref(),
+            Some("EMBARGOED UNTIL 18:00 CET")
+        );
+        assert_eq!(
+            info.caption.as_deref(),
+            Some("Supporters gather before the rally.")
+        );
+    }
+
+    #[test]
+    fn reads_jpeg_app13_across_segments() {
+        let resources = [irb(0x03ed, &[0; 16]), irb(IRB_IPTC, &newsroom_iim())].concat();
+        let (first, second) = resources.split_at(40);
+        let mut jpeg = vec![0xff, 0xd8];
+        for part in [first, second] {
+            let payload = [PHOTOSHOP_HEADER, part].concat();
+            jpeg.extend([0xff, 0xed]);
+            jpeg.extend(((payload.len() + 2) as u16).to_be_bytes());
+            jpeg.extend(payload);
+        }
+        jpeg.extend([0xff, 0xd9]);
+        check(read_iptc(&mut Cursor::new(jpeg), "image/jpeg").unwrap());
+    }
+
+    #[test]
+    fn reads_tiff_iptc_tag() {
+        let iim = newsroom_iim();
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend(8u32.to_le_bytes());
+        tiff.extend(1u16.to_le_bytes());
+        tiff.extend(TIFF_IPTC_TAG.to_le_bytes());
+        tiff.extend(7u16.to_le_bytes());
+        tiff.extend((iim.len() as u32).to_le_bytes());
+        tiff.extend(26u32.to_le_bytes());
+        tiff.extend(0u32.to_le_bytes());
+        tiff.extend(iim);
+        check(read_iptc(&mut Cursor::new(tiff), "image/tiff").unwrap());
+    }
+
+    #[test]
+    fn reads_extended_lengths_and_latin1() {
+        let mut long = vec![IIM_TAG_MARKER, 2, 120, 0x80, 0x02];
+        long.extend(3u16.to_be_bytes());
+        long.extend(b"abc");
+        let latin1 = dataset(2, 110, b"Agence Fran\xe7aise");
+        let info = parse_iim(&[long, latin1].concat()).unwrap();
+        assert_eq!(info.caption.as_deref(), Some("abc"));
+        assert_eq!(info.credit.as_deref(), Some("Agence Française"));
+    }
+
+    #[test]
+    fn ignores_truncated_and_missing_records() {
+        assert!(parse_iim(&[IIM_TAG_MARKER, 2, 110, 0, 50, b'x']).is_none());
+        assert!(parse_iim(&dataset(1, 90, UTF8_ESCAPE)).is_none());
+        assert!(irb_iptc(&irb(0x03ed, &[1, 2, 3])).is_none());
+        let png = b"\x89PNG\r\n\x1a\n".to_vec();
+        assert!(read_iptc(&mut Cursor::new(png), "image/png").is_none());
+    }
+}
@@ -0,0 +1,693 @@
+//! Reads a JPEG's luma at one eighth of its size from the DC coefficient of
+//! each 8×8 block. The DC coefficient is the block's mean, so this is an 8×
+//! box downsample that costs only the entropy decoding: no inverse DCT,
+//! colour conversion or full-size pixel buffer.
+
+use std::io::{BufReader, Bytes, Read};
+
+use image::GrayImage;
+
+/// Images with more luma blocks than this, about 268 megapixels, are not read
+const MAX_BLOCKS: usize = 1 << 22;
+
+/// A JPEG's luma at one eighth scale, with the full image's size
+pub(crate) struct ReducedLuma {
+    pub image: GrayImage,
+    pub width: u32,
+    pub height: u32,
+}
+
+struct Component {
+    id: u8,
+    h: u32,
+    v: u32,
+    quant_table: usize,
+}
+
+/// The start-of-frame header
+struct Frame {
+    progressive: bool,
+    width: u32,
+    height: u32,
+    components: Vec<Component>,
+    max_h: u32,
+    max_v: u32,
+}
+
+impl Frame {
+    fn parse(segment: &[u8], progressive: bool) -> Option<Self> {
+        // Only 8-bit samples; 12-bit JPEGs are left to the full decoder
+        if *segment.first()? != 8 {
+            return None;
+        }
+        let height = u16::from_be_bytes([*segment.get(1)?, *segment.get(2)?]) as u32;
+        let width = u16::from_be_bytes([*segment.get(3)?, *segment.get(4)?]) as u32;
+        let count = *segment.get(5)? as usize;
+        // Grayscale or YCbCr; a height of zero defers it to a DNL marker
+        if width == 0 || height == 0 || !matches!(count, 1 | 3) {
+            return None;
+        }
+        let mut components = Vec::new();
+        for spec in segment.get(6..6 + count * 3)?.chunks_exact(3) {
+            let (h, v) = ((spec[1] >> 4) as u32, (spec[1] & 0x0f) as u32);
+            if !(1..=4).contains(&h) || !(1..=4).contains(&v) || spec[2] > 3 {
+                return None;
+            }
+            components.push(Component {
+                id: spec[0],
+                h,
+                v,
+                quant_table: spec[2] as usize,
+            });
+        }
+        let max_h = components.iter().map(|c| c.h).max()?;
+        let max_v = components.iter().map(|c| c.v).max()?;
+        Some(Self {
+            progressive,
+            width,
+            height,
+            components,
+            max_h,
+            max_v,
+        })
+    }
+
+    /// MCUs across and down an interleaved scan
+    fn mcus(&self) -> (u32, u32) {
+        (
+            self.width.div_ceil(8 * self.max_h),
+            self.height.div_ceil(8 * self.max_v),
+        )
+    }
+
+    /// Blocks across and down that hold a component's samples
+    fn blocks(&self, component: &Component) -> (u32, u32) {
+        let width = (self.width * component.h).div_ceil(self.max_h);
+        let height = (self.height * component.v).div_ceil(self.max_v);
+        (width.div_ceil(8), height.div_ceil(8))
+    }
+}
+
+/// A Huffman table from a DHT segment, decoded a bit at a time from its
+/// canonical code lengths (ITU T.81 annex F.2.2.3)
+struct Huffman {
+    /// Largest code of each length, or -1 when there is none
+    max_code: [i32; 17],
+    /// Index into `values` of each length's codes, less its first code
+    offset: [i32; 17],
+    values: Vec<u8>,
+}
+
+impl Huffman {
+    fn new(counts: &[u8], values: Vec<u8>) -> Self {
+        let mut max_code = [-1; 17];
+        let mut offset = [0; 17];
+        let (mut code, mut index) = (0i32, 0i32);
+        for (len, &count) in (1..=16).zip(counts) {
+            offset[len] = index - code;
+            if count > 0 {
+                index += count as i32;
+                code += count as i32;
+                max_code[len] = code - 1;
+            }
+            code <<= 1;
+        }
+        Self {
+            max_code,
+            offset,
+            values,
+        }
+    }
+}
+
+/// The file's bytes, read as marker segments or as entropy-coded bits
+struct JpegStream<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    bits: u32,
+    bit_count: u32,
+    /// A marker met inside entropy-coded data; zeros are read past it
+    marker: Option<u8>,
+}
+
+impl<R: Read> JpegStream<R> {
+    fn byte(&mut self) -> Option<u8> {
+        self.bytes.next()?.ok()
+    }
+
+    fn take(&mut self, len: usize) -> Option<Vec<u8>> {
+        (0..len).map(|_| self.byte()).collect()
+    }
+
+    /// The next marker, skipping any data before it. Restart markers are
+    /// returned only when `restarts` is set.
+    fn marker(&mut self, restarts: bool) -> Option<u8> {
+        self.bit_count = 0;
+        let mut pending = self.marker.take();
+        loop {
+            if let Some(marker) = pending.take() {
+                if restarts || !matches!(marker, 0xd0..=0xd7) {
+                    return Some(marker);
+                }
+            }
+            if self.byte()? != 0xff {
+                continue;
+            }
+            let mut next = self.byte()?;
+            // Fill bytes before the marker
+            while next == 0xff {
+                next = self.byte()?;
+            }
+            // A stuffed zero is data
+            if next != 0 {
+                pending = Some(next);
+            }
+        }
+    }
+
+    fn bit(&mut self) -> Option<i32> {
+        if self.bit_count == 0 {
+            let byte = match self.marker {
+                Some(_) => 0,
+                None => match self.byte()? {
+                    0xff => {
+                        let mut next = self.byte()?;
+                        while next == 0xff {
+                            next = self.byte()?;
+                        }
+                        if next == 0 {
+                            0xff
+                        } else {
+                            self.marker = Some(next);
+                            0
+                        }
+                    }
+                    byte => byte,
+                },
+            };
+            self.bits = byte as u32;
+            self.bit_count = 8;
+        }
+        self.bit_count -= 1;
+        Some(((self.bits >> self.bit_count) & 1) as i32)
+    }
+
+    fn decode(&mut self, table: &Huffman) -> Option<u8> {
+        let mut code = 0;
+        for len in 1..=16 {
+            code = (code << 1) | self.bit()?;
+            if code <= table.max_code[len] {
+                return table
+                    .values
+                    .get((table.offset[len] + code) as usize)
+                    .copied();
+            }
+        }
+        None
+    }
+
+    /// A `size`-bit coefficient, sign-extended (ITU T.81 annex F.2.2.1)
+    fn receive_extend(&mut self, size: u8) -> Option<i32> {
+        if size > 16 {
+            return None;
+        }
+        let mut value = 0;
+        for _ in 0..size {
+            value = (value << 1) | self.bit()?;
+        }
+        if size > 0 && value < 1 << (size - 1) {
+            value -= (1 << size) - 1;
+        }
+        Some(value)
+    }
+
+    /// Step over the AC coefficients of a sequential block
+    fn skip_ac(&mut self, table: &Huffman) -> Option<()> {
+        let mut k = 1;
+        while k < 64 {
+            let symbol = self.decode(table)?;
+            let (run, size) = (symbol >> 4, symbol & 0x0f);
+            if size == 0 {
+                // Sixteen zeros, or the end of the block
+                if run != 15 {
+                    break;
+                }
+                k += 16;
+                continue;
+            }
+            k += run as usize + 1;
+            self.receive_extend(size)?;
+        }
+        Some(())
+    }
+}
+
+/// Tables and coefficients gathered while walking the file
+#[derive(Default)]
+struct Decoder {
+    frame: Option<Frame>,
+    /// DC quantizer of each quantization table
+    quantizers: [Option<u16>; 4],
+    dc_tables: [Option<Huffman>; 4],
+    ac_tables: [Option<Huffman>; 4],
+    restart_interval: u32,
+    /// Luma DC coefficients, row by row over whole MCUs
+    coefficients: Vec<i32>,
+    scanned: bool,
+}
+
+impl Decoder {
+    fn define_quantizers(&mut self, mut segment: &[u8]) -> Option<()> {
+        while let Some(&spec) = segment.first() {
+            let (precision, table) = (spec >> 4, (spec & 0x0f) as usize);
+            let (quantizer, len) = match precision {
+                0 => (*segment.get(1)? as u16, 65),
+                1 => (
+                    u16::from_be_bytes([*segment.get(1)?, *segment.get(2)?]),
+                    129,
+                ),
+                _ => return None,
+            };
+            *self.quantizers.get_mut(table)? = Some(quantizer);
+            segment = segment.get(len..)?;
+        }
+        Some(())
+    }
+
+    fn define_huffman_tables(&mut self, mut segment: &[u8]) -> Option<()> {
+        while let Some(&spec) = segment.first() {
+            let counts = segment.get(1..17)?;
+            let total = counts.iter().map(|&c| c as usize).sum::<usize>();
+            let values = segment.get(17..17 + total)?.to_vec();
+            let table = Some(Huffman::new(counts, values));
+            let index = (spec & 0x0f) as usize;
+            match spec >> 4 {
+                0 => *self.dc_tables.get_mut(index)? = table,
+                1 => *self.ac_tables.get_mut(index)? = table,
+                _ => return None,
+            }
+            segment = &segment[17 + total..];
+        }
+        Some(())
+    }
+
+    fn start_frame(&mut self, segment: &[u8], progressive: bool) -> Option<()> {
+        if self.frame.is_some() {
+            return None;
+        }
+        let frame = Frame::parse(segment, progressive)?;
+        let (mcus_x, mcus_y) = frame.mcus();
+        let luma = &frame.components[0];
+        let blocks = (mcus_x * luma.h) as usize * (mcus_y * luma.v) as usize;
+        if blocks > MAX_BLOCKS {
+            return None;
+        }
+        self.coefficients = vec![0; blocks];
+        self.frame = Some(frame);
+        Some(())
+    }
+
+    /// Read one scan's DC coefficients, stepping over everything else
+    fn scan<R: Read>(&mut self, stream: &mut JpegStream<R>, segment: &[u8]) -> Option<()> {
+        let frame = self.frame.as_ref()?;
+        let count = *segment.first()? as usize;
+        let specs = segment.get(1..1 + count * 2)?;
+        let &[start, _, approximation] = segment.get(1 + count * 2..4 + count * 2)? else {
+            return None;
+        };
+        let (high, low) = (approximation >> 4, (approximation & 0x0f) as u32);
+        // Progressive AC scans carry no DC coefficients
+        if start > 0 || low > 13 {
+            return Some(());
+        }
+        let refining = frame.progressive && high > 0;
+
+        let mut components = Vec::new();
+        for spec in specs.chunks_exact(2) {
+            let index = frame.components.iter().position(|c| c.id == spec[0])?;
+            let dc = self.dc_tables.get((spec[1] >> 4) as usize)?.as_ref();
+            let ac = self.ac_tables.get((spec[1] & 0x0f) as usize)?.as_ref();
+            if (dc.is_none() && !refining) || (ac.is_none() && !frame.progressive) {
+                return None;
+            }
+            components.push((index, dc, ac));
+        }
+        // Scans without luma are left for `marker` to step over
+        if !components.iter().any(|(index, _, _)| *index == 0) {
+            return Some(());
+        }
+
+        let interleaved = components.len() > 1;
+        let (mcus_x, _) = frame.mcus();
+        let luma_columns = (mcus_x * frame.components[0].h) as usize;
+        let (units_x, units_y) = if interleaved {
+            frame.mcus()
+        } else {
+            frame.blocks(&frame.components[0])
+        };
+        let mut predictions = vec![0i32; components.len()];
+        for unit in 0..units_x * units_y {
+            if self.restart_interval > 0 && unit > 0 && unit % self.restart_interval == 0 {
+                if !matches!(stream.marker(true)?, 0xd0..=0xd7) {
+                    return None;
+                }
+                predictions.fill(0);
+            }
+            let (unit_x, unit_y) = (unit % units_x, unit / units_x);
+            for (prediction, (index, dc, ac)) in predictions.iter_mut().zip(&components) {
+                let component = &frame.components[*index];
+                let (h, v) = if interleaved {
+                    (component.h, component.v)
+                } else {
+                    (1, 1)
+                };
+                for block in 0..h * v {
+                    let x = (unit_x * h + block % h) as usize;
+                    let y = (unit_y * v + block / h) as usize;
+                    let coefficient =
+                        (*index == 0).then(|| &mut self.coefficients[y * luma_columns + x]);
+                    if refining {
+                        let bit = stream.bit()?;
+                        if let Some(coefficient) = coefficient {
+                            *coefficient |= bit << low;
+                        }
+                        continue;
+                    }
+                    let size = stream.decode((*dc)?)?;
+                    *prediction = prediction.wrapping_add(stream.receive_extend(size)?);
+                    if let Some(coefficient) = coefficient {
+                        *coefficient = prediction.wrapping_shl(low);
+                    }
+                    if !frame.progressive {
+                        stream.skip_ac((*ac)?)?;
+                    }
+                }
+            }
+        }
+        self.scanned = true;
+        Some(())
+    }
+
+    fn finish(self) -> Option<ReducedLuma> {
+        let frame = self.frame?;
+        let luma = &frame.components[0];
+        let quantizer = self.quantizers[luma.quant_table]? as f64;
+        if !self.scanned {
+            return None;
+        }
+        let columns = (frame.mcus().0 * luma.h) as usize;
+        let (width, height) = frame.blocks(luma);
+        // The DC coefficient is eight times the block's mean, less the level shift
+        let image = GrayImage::from_fn(width, height, |x, y| {
+            let coefficient = self.coefficients[y as usize * columns + x as usize] as f64;
+            let mean = 128.0 + coefficient * quantizer / 8.0;
+            image::Luma([mean.round().clamp(0.0, 255.0) as u8])
+        });
+        Some(ReducedLuma {
+            image,
+            width: frame.width,
+            height: frame.height,
+        })
+    }
+}
+
+/// The luma of a baseline or progressive JPEG at one eighth scale. `None` for
+/// other images, arithmetic-coded, lossless and 12-bit JPEGs, and data that
+/// does not decode.
+pub(crate) fn decode_dc_luma<R: Read>(stream: R) -> Option<ReducedLuma> {
+    let mut stream = JpegStream {
+        bytes: BufReader::new(stream).bytes(),
+        bits: 0,
+        bit_count: 0,
+        marker: None,
+    };
+    if (stream.byte()?, stream.byte()?) != (0xff, 0xd8) {
+        return None;
+    }
+    let mut decoder = Decoder::default();
+    // A file cut short after its scans still has everything needed
+    while let Some(marker) = stream.marker(false) {
+        if marker == 0xd9 {
+            break;
+        }
+        let len = u16::from_be_bytes([stream.byte()?, stream.byte()?]);
+        let segment = stream.take(len.checked_sub(2)? as usize)?;
+        match marker {
+            0xc0 | 0xc1 => decoder.start_frame(&segment, false)?,
+            0xc2 => decoder.start_frame(&segment, true)?,
+            // Lossless, hierarchical and arithmetic-coded frames
+            0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf => return None,
+            0xc4 => decoder.define_huffman_tables(&segment)?,
+            0xdb => decoder.define_quantizers(&segment)?,
+            0xdd => {
+                decoder.restart_interval =
+                    u16::from_be_bytes([*segment.first()?, *segment.get(1)?]) as u32
+            }
+            0xda => decoder.scan(&mut stream, &segment)?,
+            _ => {}
+        }
+    }
+    decoder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::codecs::jpeg::JpegEncoder;
+    use image::{Luma, Rgb, RgbImage};
+
+    /// Entropy-coded bits, with a zero stuffed after every 0xff byte
+    #[derive(Default)]
+    struct BitWriter {
+        out: Vec<u8>,
+        bits: u32,
+        count: u32,
+    }
+
+    impl BitWriter {
+        fn put(&mut self, value: u32, len: u32) {
+            for i in (0..len).rev() {
+                self.bits = (self.bits << 1) | ((value >> i) & 1);
+                self.count += 1;
+                if self.count == 8 {
+                    self.out.push(self.bits as u8);
+                    if self.bits == 0xff {
+                        self.out.push(0);
+                    }
+                    (self.bits, self.count) = (0, 0);
+                }
+            }
+        }
+
+        /// Pad the last byte with ones
+        fn flush(&mut self) {
+            while self.count > 0 {
+                self.put(1, 1);
+            }
+        }
+
+        /// A DC difference: its size from the DC table, then its bits
+        fn dc(&mut self, diff: i32) {
+            let size = 32 - diff.unsigned_abs().leading_zeros();
+            let bits = if diff < 0 {
+                diff + (1 << size) - 1
+            } else {
+                diff
+            };
+            self.put(size, 4);
+            self.put(bits as u32, size);
+        }
+    }
+
+    fn segment(marker: u8, payload: &[u8]) -> Vec<u8> {
+        let mut out = vec![0xff, marker];
+        out.extend_from_slice(&(payload.len() as u16 + 2).to_be_bytes());
+        out.extend_from_slice(payload);
+        out
+    }
+
+    /// Luma DC coefficient of each test block
+    fn coefficient(x: u32, y: u32) -> i32 {
+        4 * (x as i32 + 2 * y as i32) - 20
+    }
+
+    /// Mean of a test block, with the DC quantizer of 2 the test files use
+    fn mean(x: u32, y: u32) -> u8 {
+        (128 + coefficient(x, y) * 2 / 8) as u8
+    }
+
+    /// A JPEG whose blocks hold only DC coefficients, from `coefficient` for
+    /// luma and a constant for chroma. Sequential blocks also carry an AC
+    /// coefficient and a run of zeros to step over. Every scan but the
+    /// first is progressive, each splitting its DC coefficients over two
+    /// scans and adding an AC scan.
+    fn test_jpeg(
+        width: u32,
+        height: u32,
+        sampling: &[(u32, u32)],
+        restart_interval: u32,
+        progressive: bool,
+    ) -> Vec<u8> {
+        let mut out = vec![0xff, 0xd8];
+        let mut quantizers = vec![0];
+        quantizers.extend([2; 64]);
+        out.extend(segment(0xdb, &quantizers));
+
+        let mut frame = vec![8];
+        frame.extend_from_slice(&(height as u16).to_be_bytes());
+        frame.extend_from_slice(&(width as u16).to_be_bytes());
+        frame.push(sampling.len() as u8);
+        for (id, (h, v)) in sampling.iter().enumerate() {
+            frame.extend_from_slice(&[id as u8 + 1, (h << 4 | v) as u8, 0]);
+        }
+        out.extend(segment(if progressive { 0xc2 } else { 0xc0 }, &frame));
+
+        // DC sizes 0-11 as 4-bit codes; AC end of block, a one-bit
+        // coefficient and sixteen zeros as 2-bit codes
+        let mut tables = vec![0x00, 0, 0, 0, 12];
+        tables.extend([0; 12]);
+        tables.extend(0..12);
+        tables.extend([0x10, 0, 3]);
+        tables.extend([0; 14]);
+        tables.extend([0x00, 0x01, 0xf0]);
+        out.extend(segment(0xc4, &tables));
+        if restart_interval > 0 {
+            out.extend(segment(0xdd, &(restart_interval as u16).to_be_bytes()));
+        }
+
+        let max_h = sampling.iter().map(|s| s.0).max().unwrap();
+        let max_v = sampling.iter().map(|s| s.1).max().unwrap();
+        let (mcus_x, mcus_y) = (width.div_ceil(8 * max_h), height.div_ceil(8 * max_v));
+        let units = if sampling.len() > 1 {
+            (mcus_x, mcus_y)
+        } else {
+            (width.div_ceil(8), height.div_ceil(8))
+        };
+        let scan = |out: &mut Vec<u8>, approximation: u8, shift: u32, refining: bool| {
+            let mut header = vec![sampling.len() as u8];
+            for id in 0..sampling.len() {
+                header.extend_from_slice(&[id as u8 + 1, 0x00]);
+            }
+            let end = if progressive { 0 } else { 63 };
+            header.extend_from_slice(&[0, end, approximation]);
+            out.extend(segment(0xda, &header));
+
+            let mut bits = BitWriter::default();
+            let mut predictions = vec![0; sampling.len()];
+            for unit in 0..units.0 * units.1 {
+                if restart_interval > 0 && unit > 0 && unit % restart_interval == 0 {
+                    bits.flush();
+                    let index = (unit / restart_interval - 1) % 8;
+                    bits.out.extend_from_slice(&[0xff, 0xd0 + index as u8]);
+                    predictions.fill(0);
+                }
+                let (unit_x, unit_y) = (unit % units.0, unit / units.0);
+                for (id, &(h, v)) in sampling.iter().enumerate() {
+                    let (h, v) = if sampling.len() > 1 { (h, v) } else { (1, 1) };
+                    for block in 0..h * v {
+                        let (x, y) = (unit_x * h + block % h, unit_y * v + block / h);
+                        let value = if id == 0 { coefficient(x, y) } else { 7 };
+                        if refining {
+                            bits.put((value & 1) as u32, 1);
+                            continue;
+                        }
+                        bits.dc((value >> shift) - predictions[id]);
+                        predictions[id] = value >> shift;
+                        if !progressive {
+                            // An AC coefficient of -1, sixteen zeros, end of block
+                            bits.put(0b01, 2);
+                            bits.put(0, 1);
+                            bits.put(0b10, 2);
+                            bits.put(0b00, 2);
+                        }
+                    }
+                }
+            }
+            bits.flush();
+            out.extend(bits.out);
+        };
+        if progressive {
+            scan(&mut out, 0x01, 1, false);
+            scan(&mut out, 0x10, 0, true);
+            // An AC scan of luma, stepped over
+            out.extend(segment(0xda, &[1, 1, 0x00, 1, 63, 0x00]));
+            out.extend_from_slice(&[0x12, 0xff, 0x00, 0x34, 0xff, 0xd0, 0x56]);
+        } else {
+            scan(&mut out, 0x00, 0, false);
+        }
+        out.extend_from_slice(&[0xff, 0xd9]);
+        out
+    }
+
+    fn assert_means(reduced: &ReducedLuma, blocks: (u32, u32)) {
+        assert_eq!(reduced.image.dimensions(), blocks);
+        for (x, y, pixel) in reduced.image.enumerate_pixels() {
+            assert_eq!(pixel.0[0], mean(x, y), "block {x},{y}");
+        }
+    }
+
+    #[test]
+    fn reads_block_means_of_each_scan_layout() {
+        // Grayscale, one block per restart interval, edge blocks partly filled
+        let jpeg = test_jpeg(20, 12, &[(1, 1)], 1, false);
+        let reduced = decode_dc_luma(&jpeg[..]).unwrap();
+        assert_eq!((reduced.width, reduced.height), (20, 12));
+        assert_means(&reduced, (3, 2));
+
+        // 4:2:0 YCbCr, sequential and progressive, restarting every two MCUs
+        for progressive in [false, true] {
+            let jpeg = test_jpeg(40, 24, &[(2, 2), (1, 1), (1, 1)], 2, progressive);
+            let reduced = decode_dc_luma(&jpeg[..]).unwrap();
+            assert_eq!((reduced.width, reduced.height), (40, 24));
+            assert_means(&reduced, (5, 3));
+        }
+    }
+
+    #[test]
+    fn matches_block_means_of_encoded_images() {
+        let gray = image::GrayImage::from_fn(64, 48, |x, y| Luma([(x * 2 + y * 2 + 20) as u8]));
+        let rgb = RgbImage::from_fn(64, 48, |x, y| {
+            let luma = (x * 2 + y * 2 + 20) as u8;
+            Rgb([luma, luma, luma])
+        });
+        let mut encoded = [Vec::new(), Vec::new()];
+        JpegEncoder::new_with_quality(&mut encoded[0], 90)
+            .encode_image(&gray)
+            .unwrap();
+        JpegEncoder::new_with_quality(&mut encoded[1], 90)
+            .encode_image(&rgb)
+            .unwrap();
+
+        for jpeg in encoded {
+            let reduced = decode_dc_luma(&jpeg[..]).unwrap();
+            assert_eq!(reduced.image.dimensions(), (8, 6));
+            for (bx, by, pixel) in reduced.image.enumerate_pixels() {
+                let sum: u32 = (0..64)
+                    .map(|i| gray.get_pixel(bx * 8 + i % 8, by * 8 + i / 8).0[0] as u32)
+                    .sum();
+                let mean = sum as f64 / 64.0;
+                assert!((pixel.0[0] as f64 - mean).abs() <= 2.0, "block {bx},{by}");
+            }
+        }
+    }
+
+    #[test]
+    fn other_images_and_codings_are_not_read() {
+        let jpeg = test_jpeg(16, 16, &[(1, 1)], 0, false);
+        assert!(decode_dc_luma(&jpeg[..]).is_some());
+
+        // Arithmetic coding and lossless frames
+        for marker in [0xc9, 0xc3] {
+            let mut other = jpeg.clone();
+            let frame = other.windows(2).position(|w| w == [0xff, 0xc0]).unwrap();
+            other[frame + 1] = marker;
+            assert!(decode_dc_luma(&other[..]).is_none());
+        }
+
+        // Cut off inside the scan
+        let scan = jpeg.windows(2).rposition(|w| w == [0xff, 0xda]).unwrap();
+        assert!(decode_dc_luma(&jpeg[..scan + 12]).is_none());
+        // No scan at all
+        assert!(decode_dc_luma(&jpeg[..scan]).is_none());
+        assert!(decode_dc_luma(&b"\x89PNG\r\n\x1a\n"[..]).is_none());
+    }
+}
@@ -0,0 +1,528 @@
+//! Locates the C2PA JUMBF manifest store inside an asset and walks its boxes.
+//!
+//! The c2pa SDK does not expose the raw claim signatures, so the parts of
+//! the store we inspect ourselves (claims and their COSE signatures) are read
+//! from here.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, SeekFrom};
+
+use super::container::{jpeg_segments, read_bytes};
+
+const C2PA_BMFF_UUID: [u8; 16] = [
+    0xd8, 0xfe, 0xc3, 0xd6, 0x1b, 0x0e, 0x48, 0x3c, 0x92, 0x97, 0x58, 0x28, 0x87, 0x7e, 0xc4, 0x81,
+];
+pub(crate) const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+fn be_u16(data: &[u8], pos: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(pos..pos + 2)?.try_into().ok()?))
+}
+
+fn be_u32(data: &[u8], pos: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+fn be_u64(data: &[u8], pos: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(data.get(pos..pos + 8)?.try_into().ok()?))
+}
+
+/// A box in an ISO BMFF / JUMBF box sequence
+pub(crate) struct BmffBox<'a> {
+    pub box_type: [u8; 4],
+    pub payload: &'a [u8],
+}
+
+/// Split a byte range into ISO BMFF style boxes (LBox, TBox, optional XLBox)
+pub(crate) fn read_boxes(mut data: &[u8]) -> Vec<BmffBox<'_>> {
+    let mut boxes = Vec::new();
+    while data.len() >= 8 {
+        let size = be_u32(data, 0).unwrap_or(0) as u64;
+        let box_type: [u8; 4] = data[4..8].try_into().unwrap_or_default();
+        let (header, total) = match size {
+            0 => (8, data.len() as u64),
+            1 => match be_u64(data, 8) {
+                Some(large) => (16, large),
+                None => break,
+            },
+            n => (8, n),
+        };
+        if total < header as u64 || total > data.len() as u64 {
+            break;
+        }
+        boxes.push(BmffBox {
+            box_type,
+            payload: &data[header..total as usize],
+        });
+        data = &data[total as usize..];
+    }
+    boxes
+}
+
+/// A JUMBF superbox: its description label and child boxes
+pub(crate) struct Superbox<'a> {
+    pub label: Option<String>,
+    pub children: Vec<BmffBox<'a>>,
+}
+
+/// Parse the payload of a 'jumb' box
+pub(crate) fn parse_superbox(payload: &[u8]) -> Option<Superbox<'_>> {
+    let mut boxes = read_boxes(payload);
+    if boxes.is_empty() || &boxes[0].box_type != b"jumd" {
+        return None;
+    }
+    let description = boxes.remove(0).payload;
+    // 16-byte content type UUID followed by the toggles byte
+    let toggles = *description.get(16)?;
+    let label = if toggles & 0x02 != 0 {
+        let rest = description.get(17..)?;
+        let end = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+        Some(String::from_utf8_lossy(&rest[..end]).into_owned())
+    } else {
+        None
+    };
+    Some(Superbox {
+        label,
+        children: boxes,
+    })
+}
+
+/// Child superboxes of a superbox, with their labels
+pub(crate) fn child_superboxes<'a>(superbox: &Superbox<'a>) -> Vec<Superbox<'a>> {
+    superbox
+        .children
+        .iter()
+        .filter(|b| &b.box_type == b"jumb")
+        .filter_map(|b| parse_superbox(b.payload))
+        .collect()
+}
+
+fn is_manifest_store(jumbf: &[u8]) -> bool {
+    read_boxes(jumbf)
+        .first()
+        .filter(|b| &b.box_type == b"jumb")
+        .and_then(|b| parse_superbox(b.payload))
+        .is_some_and(|s| s.label.as_deref() == Some("c2pa"))
+}
+
+/// JPEG stores JUMBF in APP11 segments, split across packets per box instance
+fn jumbf_from_jpeg<R: Read + Seek>(stream: &mut R) -> Option<Vec<u8>> {
+    let segments = jpeg_segments(stream, 0xeb);
+    let mut instances: BTreeMap<u16, Vec<(u32, &[u8])>> = BTreeMap::new();
+    for segment in &segments {
+        // APP11 with the "JP" common identifier
+        if segment.len() > 8 && &segment[0..2] == b"JP" {
+            let instance = be_u16(segment, 2)?;
+            let sequence = be_u32(segment, 4)?;
+            instances
+                .entry(instance)
+                .or_default()
+                .push((sequence, &segment[8..]));
+        }
+    }
+
+    for (_, mut packets) in instances {
+        packets.sort_by_key(|(sequence, _)| *sequence);
+        let mut jumbf = Vec::new();
+        for (i, (_, packet)) in packets.iter().enumerate() {
+            if i == 0 {
+                jumbf.extend_from_slice(packet);
+            } else {
+                // Continuation packets repeat the box header, which must be dropped
+                let header = if be_u32(packet, 0) == Some(1) { 16 } else { 8 };
+                jumbf.extend_from_slice(packet.get(header..)?);
+            }
+        }
+        if is_manifest_store(&jumbf) {
+            return Some(jumbf);
+        }
+    }
+    None
+}
+
+fn jumbf_from_png<R: Read + Seek>(stream: &mut R) -> Option<Vec<u8>> {
+    stream
+        .seek(SeekFrom::Start(PNG_SIGNATURE.len() as u64))
+        .ok()?;
+    let mut header = [0u8; 8];
+    while stream.read_exact(&mut header).is_ok() {
+        let len = be_u32(&header, 0)? as u64;
+        match &header[4..8] {
+            b"caBX" => return read_bytes(stream, len),
+            b"IEND" => break,
+            // Skip the data and its CRC
+            _ => {
+                stream.seek(SeekFrom::Current(len as i64 + 4)).ok()?;
+            }
+        }
+    }
+    None
+}
+
+fn jumbf_from_riff<R: Read + Seek>(stream: &mut R) -> Option<Vec<u8>> {
+    stream.seek(SeekFrom::Start(12)).ok()?;
+    let mut header = [0u8; 8];
+    while stream.read_exact(&mut header).is_ok() {
+        let len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as u64;
+        if &header[..4] == b"C2PA" {
+            return read_bytes(stream, len);
+        }
+        // Chunks are padded to an even length
+        stream
+            .seek(SeekFrom::Current((len + (len & 1)) as i64))
+            .ok()?;
+    }
+    None
+}
+
+/// The manifest store in the body of a C2PA `uuid` box, after the UUID
+fn store_from_uuid_body(body: &[u8]) -> Option<Vec<u8>> {
+    // Full box version and flags, then a NUL-terminated purpose string
+    let rest = body.get(4..)?;
+    let end = rest.iter().position(|&c| c == 0)?;
+    if &rest[..end] != b"manifest" {
+        return None;
+    }
+    let after_purpose = &rest[end + 1..];
+    // The manifest purpose carries a 64-bit Merkle offset before the store
+    if let Some(store) = after_purpose.get(8..).filter(|s| is_manifest_store(s)) {
+        return Some(store.to_vec());
+    }
+    is_manifest_store(after_purpose).then(|| after_purpose.to_vec())
+}
+
+/// Walk the top-level boxes, reading only the body of C2PA `uuid` boxes
+fn jumbf_from_bmff<R: Read + Seek>(stream: &mut R) -> Option<Vec<u8>> {
+    let end = stream.seek(SeekFrom::End(0)).ok()?;
+    let mut position = 0;
+    while position + 8 <= end {
+        stream.seek(SeekFrom::Start(position)).ok()?;
+        let mut header = [0u8; 8];
+        stream.read_exact(&mut header).ok()?;
+        let (header_len, size) = match be_u32(&header, 0)? {
+            0 => (8, end - position),
+            1 => {
+                let mut large = [0u8; 8];
+                stream.read_exact(&mut large).ok()?;
+                (16, u64::from_be_bytes(large))
+            }
+            n => (8, n as u64),
+        };
+        if size < header_len {
+            return None;
+        }
+        if &header[4..8] == b"uuid" {
+            let mut uuid = [0u8; 16];
+            stream.read_exact(&mut uuid).ok()?;
+            if uuid == C2PA_BMFF_UUID {
+                let body_len = size.min(end - position).saturating_sub(header_len + 16);
+                if let Some(store) = read_bytes(stream, body_len)
+                    .as_deref()
+                    .and_then(store_from_uuid_body)
+                {
+                    return Some(store);
+                }
+            }
+        }
+        position = position.checked_add(size)?;
+    }
+    None
+}
+
+/// Find the C2PA manifest store in an asset stream. Supports JPEG, PNG, RIFF
+/// (WebP/WAV), ISO BMFF (MP4/MOV/HEIF/AVIF) and standalone .c2pa stores.
+/// Only the blocks that may hold the store are read.
+pub(crate) fn extract_manifest_store<R: Read + Seek>(stream: &mut R) -> Option<Vec<u8>> {
+    let mut header = Vec::new();
+    stream.rewind().ok()?;
+    stream.by_ref().take(8).read_to_end(&mut header).ok()?;
+    if header.starts_with(&[0xff, 0xd8]) {
+        jumbf_from_jpeg(stream)
+    } else if header.starts_with(&PNG_SIGNATURE) {
+        jumbf_from_png(stream)
+    } else if header.starts_with(b"RIFF") {
+        jumbf_from_riff(stream)
+    } else if header.get(4..8) == Some(b"ftyp") {
+        jumbf_from_bmff(stream)
+    } else if header.get(4..8) == Some(b"jumb") {
+        // A standalone manifest store
+        let len = stream.seek(SeekFrom::End(0)).ok()?;
+        stream.rewind().ok()?;
+        read_bytes(stream, len).filter(|store| is_manifest_store(store))
+    } else {
+        None
+    }
+}
+
+/// Manifests in a store, keyed by label, as parsed superboxes
+pub(crate) fn manifests(store: &[u8]) -> Vec<Superbox<'_>> {
+    read_boxes(store)
+        .into_iter()
+        .filter(|b| &b.box_type == b"jumb")
+        .filter_map(|b| parse_superbox(b.payload))
+        .filter(|s| s.label.as_deref() == Some("c2pa"))
+        .flat_map(|store| child_superboxes(&store))
+        .collect()
+}
+
+/// A manifest's claim and the COSE_Sign1 signature over it, as stored
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ClaimSignature {
+    /// The claim's CBOR, the detached payload of the signature
+    pub claim: Option<Vec<u8>>,
+    pub cose: Vec<u8>,
+}
+
+/// Payload of the `cbor` box in the manifest's child superbox with one of `labels`
+fn cbor_content(manifest: &Superbox<'_>, labels: &[&str]) -> Option<Vec<u8>> {
+    child_superboxes(manifest)
+        .into_iter()
+        .find(|s| s.label.as_deref().is_some_and(|l| labels.contains(&l)))
+        .and_then(|s| {
+            s.children
+                .iter()
+                .find(|b| &b.box_type == b"cbor")
+                .map(|b| b.payload.to_vec())
+        })
+}
+
+/// The claim signature of every manifest in the store, by manifest label
+pub(crate) fn claim_signatures(store: &[u8]) -> Vec<(String, ClaimSignature)> {
+    let mut signatures = Vec::new();
+    for manifest in manifests(store) {
+        let Some(label) = manifest.label.clone() else {
+            continue;
+        };
+        if let Some(cose) = cbor_content(&manifest, &["c2pa.signature"]) {
+            let claim = cbor_content(&manifest, &["c2pa.claim", "c2pa.claim.v2"]);
+            signatures.push((label, ClaimSignature { claim, cose }));
+        }
+    }
+    signatures
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    pub(crate) fn bmff_box(box_type: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut out = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(box_type);
+        out.extend_from_slice(payload);
+        out
+    }
+
+    /// A labelled JUMBF superbox around `children`
+    pub(crate) fn superbox(label: &str, children: &[Vec<u8>]) -> Vec<u8> {
+        let mut description = vec![0u8; 16];
+        // Requestable, label present
+        description.push(0x03);
+        description.extend_from_slice(label.as_bytes());
+        description.push(0);
+        let mut payload = bmff_box(b"jumd", &description);
+        payload.extend(children.concat());
+        bmff_box(b"jumb", &payload)
+    }
+
+    /// A manifest store holding one signature box per manifest
+    pub(crate) fn manifest_store(manifests: &[(&str, &[u8])]) -> Vec<u8> {
+        let manifests: Vec<Vec<u8>> = manifests
+            .iter()
+            .map(|(label, cose)| {
+                let signature = superbox("c2pa.signature", &[bmff_box(b"cbor", cose)]);
+                superbox(label, &[signature])
+            })
+            .collect();
+        superbox("c2pa", &manifests)
+    }
+
+    fn extract(data: &[u8]) -> Option<Vec<u8>> {
+        extract_manifest_store(&mut Cursor::new(data))
+    }
+
+    fn app11(instance: u16, sequence: u32, payload: &[u8]) -> Vec<u8> {
+        let mut out = vec![0xff, 0xeb];
+        out.extend_from_slice(&((payload.len() + 10) as u16).to_be_bytes());
+        out.extend_from_slice(b"JP");
+        out.extend_from_slice(&instance.to_be_bytes());
+        out.extend_from_slice(&sequence.to_be_bytes());
+        out.extend_from_slice(payload);
+        out
+    }
+
+    #[test]
+    fn boxes_honour_size_forms() {
+        let mut data = bmff_box(b"free", b"abc");
+        // Large size form
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(b"wide");
+        data.extend_from_slice(&18u64.to_be_bytes());
+        data.extend_from_slice(b"xy");
+        // Size zero runs to the end of the input
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(b"last");
+        data.extend_from_slice(b"rest");
+
+        let boxes = read_boxes(&data);
+        let types: Vec<&[u8; 4]> = boxes.iter().map(|b| &b.box_type).collect();
+        assert_eq!(types, [b"free", b"wide", b"last"]);
+        assert_eq!(boxes[0].payload, b"abc");
+        assert_eq!(boxes[1].payload, b"xy");
+        assert_eq!(boxes[2].payload, b"rest");
+    }
+
+    #[test]
+    fn truncated_boxes_stop_the_walk() {
+        let mut data = bmff_box(b"free", b"abc");
+        data.extend_from_slice(&100u32.to_be_bytes());
+        data.extend_from_slice(b"long");
+        assert_eq!(read_boxes(&data).len(), 1);
+
+        // Declared sizes smaller than the header are invalid
+        let mut tiny = 4u32.to_be_bytes().to_vec();
+        tiny.extend_from_slice(b"tiny");
+        assert!(read_boxes(&tiny).is_empty());
+    }
+
+    #[test]
+    fn superbox_labels_are_read() {
+        let store = superbox("c2pa", &[superbox("urn:uuid:1", &[])]);
+        let boxes = read_boxes(&store);
+        let parsed = parse_superbox(boxes[0].payload).unwrap();
+        assert_eq!(parsed.label.as_deref(), Some("c2pa"));
+        let children = child_superboxes(&parsed);
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].label.as_deref(), Some("urn:uuid:1"));
+
+        // Without the label toggle there is no label
+        let mut description = vec![0u8; 16];
+        description.push(0x01);
+        let unlabelled = bmff_box(b"jumd", &description);
+        assert_eq!(parse_superbox(&unlabelled).unwrap().label, None);
+
+        // The description box must come first
+        assert!(parse_superbox(&bmff_box(b"cbor", b"x")).is_none());
+    }
+
+    #[test]
+    fn claim_signatures_are_keyed_by_manifest() {
+        let store = manifest_store(&[("urn:uuid:a", b"first"), ("urn:uuid:b", b"second")]);
+        let signature = |cose: &[u8]| ClaimSignature {
+            claim: None,
+            cose: cose.to_vec(),
+        };
+        assert_eq!(
+            claim_signatures(&store),
+            vec![
+                ("urn:uuid:a".to_string(), signature(b"first")),
+                ("urn:uuid:b".to_string(), signature(b"second")),
+            ]
+        );
+        assert_eq!(manifests(&store).len(), 2);
+        // Stores not labelled "c2pa" are ignored
+        assert!(claim_signatures(&superbox("other", &[])).is_empty());
+
+        // Both claim versions are read alongside the signature
+        for label in ["c2pa.claim", "c2pa.claim.v2"] {
+            let claim = superbox(label, &[bmff_box(b"cbor", b"claim")]);
+            let signature = superbox("c2pa.signature", &[bmff_box(b"cbor", b"cose")]);
+            let store = superbox("c2pa", &[superbox("urn:uuid:a", &[claim, signature])]);
+            let signatures = claim_signatures(&store);
+            assert_eq!(signatures[0].1.claim.as_deref(), Some(&b"claim"[..]));
+        }
+    }
+
+    #[test]
+    fn real_claim_signatures_cover_the_stored_claim() {
+        use ring::signature::{UnparsedPublicKey, ECDSA_P256_SHA256_FIXED};
+        use serde_cbor::Value;
+        use x509_parser::prelude::*;
+
+        // The manifest store of an image generated by ChatGPT
+        let store = include_bytes!("../../fixtures/chatgpt_manifest_store.c2pa");
+        let signatures = claim_signatures(store);
+        assert_eq!(signatures.len(), 2);
+
+        for (_, signature) in signatures {
+            let claim = signature.claim.unwrap();
+            let parts = crate::api::cose::sign1_parts(&signature.cose).unwrap();
+            assert_eq!(parts.payload, None);
+
+            let Ok(Value::Map(protected)) = serde_cbor::from_slice(&parts.protected) else {
+                panic!("protected header is not a map");
+            };
+            let Some(Value::Array(chain)) = protected.get(&Value::Integer(33)) else {
+                panic!("no x5chain");
+            };
+            let Value::Bytes(leaf) = &chain[0] else {
+                panic!("x5chain entry is not a certificate");
+            };
+            let (_, leaf) = X509Certificate::from_der(leaf).unwrap();
+
+            // ES256 over ["Signature1", protected, h'', claim]
+            let to_be_signed = serde_cbor::to_vec(&Value::Array(vec![
+                Value::Text("Signature1".to_string()),
+                Value::Bytes(parts.protected.clone()),
+                Value::Bytes(vec![]),
+                Value::Bytes(claim),
+            ]))
+            .unwrap();
+            let key = UnparsedPublicKey::new(
+                &ECDSA_P256_SHA256_FIXED,
+                &leaf.public_key().subject_public_key.data,
+            );
+            assert!(key.verify(&to_be_signed, &parts.signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn stores_are_found_in_each_container() {
+        let store = manifest_store(&[("urn:uuid:a", b"cose")]);
+
+        // JPEG, split across two APP11 packets; the continuation repeats the box header
+        let mut jpeg = vec![0xff, 0xd8];
+        jpeg.extend(app11(1, 1, &store[..20]));
+        let mut continuation = store[..8].to_vec();
+        continuation.extend_from_slice(&store[20..]);
+        jpeg.extend(app11(1, 2, &continuation));
+        jpeg.extend_from_slice(&[0xff, 0xda, 0x00, 0x02, 0xff, 0xd9]);
+        assert_eq!(extract(&jpeg).as_deref(), Some(&store[..]));
+
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend_from_slice(&(store.len() as u32).to_be_bytes());
+        png.extend_from_slice(b"caBX");
+        png.extend_from_slice(&store);
+        png.extend_from_slice(&[0; 4]);
+        assert_eq!(extract(&png).as_deref(), Some(&store[..]));
+
+        let mut riff = b"RIFF\0\0\0\0WEBP".to_vec();
+        riff.extend_from_slice(b"ICCP\x01\0\0\0\0\0");
+        riff.extend_from_slice(b"C2PA");
+        riff.extend_from_slice(&(store.len() as u32).to_le_bytes());
+        riff.extend_from_slice(&store);
+        assert_eq!(extract(&riff).as_deref(), Some(&store[..]));
+
+        let mut uuid_payload = C2PA_BMFF_UUID.to_vec();
+        uuid_payload.extend_from_slice(&[0; 4]);
+        uuid_payload.extend_from_slice(b"manifest\0");
+        uuid_payload.extend_from_slice(&[0; 8]);
+        uuid_payload.extend_from_slice(&store);
+        let mut mp4 = bmff_box(b"ftyp", b"isom\0\0\0\0");
+        mp4.extend(bmff_box(b"uuid", &uuid_payload));
+        assert_eq!(extract(&mp4).as_deref(), Some(&store[..]));
+
+        assert_eq!(extract(&store).as_deref(), Some(&store[..]));
+        assert_eq!(extract(b"GIF89a"), None);
+    }
+
+    #[test]
+    fn jpeg_without_a_manifest_store_yields_nothing() {
+        let mut jpeg = vec![0xff, 0xd8];
+        jpeg.extend(app11(1, 1, &superbox("not-c2pa", &[])));
+        jpeg.extend_from_slice(&[0xff, 0xd9]);
+        assert_eq!(extract(&jpeg), None);
+
+        // A segment length running past the end of the file
+        assert_eq!(extract(&[0xff, 0xd8, 0xff, 0xeb, 0xff, 0xff]), None);
+    }
+}
@@ -0,0 +1,463 @@
+//! Vendor MakerNote directories in EXIF.
+//!
+//! | Vendor | Layout                                                       |
+//! |--------|--------------------------------------------------------------|
+//! | Canon  | Bare IFD, offsets from the EXIF TIFF header                  |
+//! | Nikon  | `Nikon\0` and a version, then its own TIFF header and IFD    |
+//! | Sony   | `SONY DSC ` (or `CAM `/`MOBILE`) header, then an IFD with offsets from the EXIF TIFF header |
+//! | Apple  | `Apple iOS\0`, a version and `MM`, then a big-endian IFD with offsets from the note |
+
+use serde::{Deserialize, Serialize};
+
+/// Entries read from a single directory at most
+const MAX_ENTRIES: usize = 1024;
+
+/// Undefined values longer than this are summarized rather than dumped
+const MAX_BYTES_SHOWN: usize = 64;
+
+/// One MakerNote entry as text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MakerNoteTag {
+    pub id: u16,
+    /// The vendor's tag name, or the id in hex ("0x0019") when unknown
+    pub name: String,
+    pub value: String,
+}
+
+/// A decoded vendor MakerNote
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MakerNote {
+    /// "Canon", "Nikon", "Sony" or "Apple"
+    pub vendor: String,
+    /// The camera body's serial number as the vendor records it
+    pub serial_number: Option<String>,
+    pub lens_model: Option<String>,
+    pub firmware_version: Option<String>,
+    /// Nikon's shutter actuation count
+    pub shutter_count: Option<u32>,
+    /// Every entry in the directory, in order
+    pub tags: Vec<MakerNoteTag>,
+}
+
+#[derive(Clone, Copy)]
+struct Directory<'a> {
+    data: &'a [u8],
+    /// Where value offsets count from within `data`
+    base: usize,
+    little_endian: bool,
+}
+
+impl Directory<'_> {
+    fn u16_at(&self, at: usize) -> Option<u16> {
+        let b = self.data.get(at..at + 2)?;
+        let b = [b[0], b[1]];
+        Some(if self.little_endian {
+            u16::from_le_bytes(b)
+        } else {
+            u16::from_be_bytes(b)
+        })
+    }
+
+    fn u32_at(&self, at: usize) -> Option<u32> {
+        let b = self.data.get(at..at + 4)?;
+        let b = [b[0], b[1], b[2], b[3]];
+        Some(if self.little_endian {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        })
+    }
+
+    /// Each entry's id, type and raw value bytes, for an IFD at `at`
+    fn entries(&self, at: usize) -> Vec<(u16, u16, &[u8])> {
+        let mut entries = Vec::new();
+        let Some(count) = self.u16_at(at) else {
+            return entries;
+        };
+        for index in 0..(count as usize).min(MAX_ENTRIES) {
+            let entry = at + 2 + index * 12;
+            let (Some(id), Some(kind), Some(count)) = (
+                self.u16_at(entry),
+                self.u16_at(entry + 2),
+                self.u32_at(entry + 4),
+            ) else {
+                break;
+            };
+            let Some(len) = type_size(kind).and_then(|s| s.checked_mul(count as usize)) else {
+                continue;
+            };
+            let value = if len <= 4 {
+                self.data.get(entry + 8..entry + 8 + len)
+            } else {
+                self.u32_at(entry + 8).and_then(|offset| {
+                    let start = self.base.checked_add(offset as usize)?;
+                    self.data.get(start..start.checked_add(len)?)
+                })
+            };
+            if let Some(value) = value {
+                entries.push((id, kind, value));
+            }
+        }
+        entries
+    }
+
+    /// A value as text: strings trimmed, numbers comma-separated
+    fn render(&self, kind: u16, value: &[u8]) -> String {
+        let join = |items: Vec<String>| items.join(", ");
+        match kind {
+            2 => String::from_utf8_lossy(value)
+                .trim_end_matches('\0')
+                .trim()
+                .to_string(),
+            1 | 7 => {
+                let text = value.strip_suffix(&[0]).unwrap_or(value);
+                if !text.is_empty() && text.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+                    String::from_utf8_lossy(text).trim().to_string()
+                } else if value.len() > MAX_BYTES_SHOWN {
+                    format!("{} bytes", value.len())
+                } else {
+                    join(value.iter().map(|b| b.to_string()).collect())
+                }
+            }
+            3 | 8 => join(
+                (0..value.len() / 2)
+                    .filter_map(|i| {
+                        let n = self.u16_at_slice(value, i * 2)?;
+                        Some(if kind == 8 {
+                            (n as i16).to_string()
+                        } else {
+                            n.to_string()
+                        })
+                    })
+                    .collect(),
+            ),
+            4 | 9 => join(
+                (0..value.len() / 4)
+                    .filter_map(|i| {
+                        let n = self.u32_at_slice(value, i * 4)?;
+                        Some(if kind == 9 {
+                            (n as i32).to_string()
+                        } else {
+                            n.to_string()
+                        })
+                    })
+                    .collect(),
+            ),
+            5 | 10 => join(
+                (0..value.len() / 8)
+                    .filter_map(|i| {
+                        let num = self.u32_at_slice(value, i * 8)?;
+                        let denom = self.u32_at_slice(value, i * 8 + 4)?;
+                        Some(if kind == 10 {
+                            format!("{}/{}", num as i32, denom as i32)
+                        } else {
+                            format!("{}/{}", num, denom)
+                        })
+                    })
+                    .collect(),
+            ),
+            _ => format!("{} bytes", value.len()),
+        }
+    }
+
+    fn u16_at_slice(&self, value: &[u8], at: usize) -> Option<u16> {
+        Directory {
+            data: value,
+            ..*self
+        }
+        .u16_at(at)
+    }
+
+    fn u32_at_slice(&self, value: &[u8], at: usize) -> Option<u32> {
+        Directory {
+            data: value,
+            ..*self
+        }
+        .u32_at(at)
+    }
+}
+
+/// Bytes per component of each TIFF field type
+fn type_size(kind: u16) -> Option<usize> {
+    match kind {
+        1 | 2 | 6 | 7 => Some(1),
+        3 | 8 => Some(2),
+        4 | 9 | 11 | 13 => Some(4),
+        5 | 10 | 12 => Some(8),
+        _ => None,
+    }
+}
+
+fn canon_tag(id: u16) -> Option<&'static str> {
+    Some(match id {
+        0x0006 => "ImageType",
+        0x0007 => "FirmwareVersion",
+        0x0009 => "OwnerName",
+        0x000c => "SerialNumber",
+        0x0010 => "ModelID",
+        0x0095 => "LensModel",
+        0x0096 => "InternalSerialNumber",
+        _ => return None,
+    })
+}
+
+fn nikon_tag(id: u16) -> Option<&'static str> {
+    Some(match id {
+        0x0001 => "MakerNoteVersion",
+        0x0002 => "ISO",
+        0x0004 => "Quality",
+        0x0005 => "WhiteBalance",
+        0x001d => "SerialNumber",
+        0x0084 => "Lens",
+        0x00a7 => "ShutterCount",
+        _ => return None,
+    })
+}
+
+fn sony_tag(id: u16) -> Option<&'static str> {
+    Some(match id {
+        0x0102 => "Quality",
+        0x0104 => "FlashExposureComp",
+        0xb000 => "FileFormat",
+        0xb001 => "SonyModelID",
+        0xb027 => "LensType",
+        _ => return None,
+    })
+}
+
+fn apple_tag(id: u16) -> Option<&'static str> {
+    Some(match id {
+        0x0001 => "MakerNoteVersion",
+        0x0008 => "AccelerationVector",
+        0x000a => "HDRImageType",
+        0x000b => "BurstUUID",
+        0x0011 => "ContentIdentifier",
+        0x0014 => "ImageCaptureType",
+        0x0015 => "ImageUniqueID",
+        0x0017 => "LivePhotoVideoIndex",
+        _ => return None,
+    })
+}
+
+/// Decode a MakerNote. `note_offset` is where the note starts in `tiff`, the
+/// EXIF TIFF structure whose byte order is `little_endian`; Canon and Sony
+/// count value offsets from that structure's start.
+pub(crate) fn parse_maker_note(
+    make: Option<&str>,
+    tiff: &[u8],
+    note_offset: usize,
+    little_endian: bool,
+) -> Option<MakerNote> {
+    let note = tiff.get(note_offset..)?;
+    let make = make.unwrap_or_default().trim().to_lowercase();
+
+    let (vendor, directory, ifd, names): (_, _, _, fn(u16) -> Option<&'static str>) =
+        if note.starts_with(b"Apple iOS\0") {
+            let directory = Directory {
+                data: note,
+                base: 0,
+                little_endian: false,
+            };
+            ("Apple", directory, 14, apple_tag)
+        } else if note.starts_with(b"Nikon\0") && note.len() > 18 {
+            // An embedded TIFF header at offset 10 sets the byte order
+            let little_endian = match note.get(10..12)? {
+                b"II" => true,
+                b"MM" => false,
+                _ => return None,
+            };
+            let directory = Directory {
+                data: note,
+                base: 10,
+                little_endian,
+            };
+            let ifd = 10 + directory.u32_at(14)? as usize;
+            ("Nikon", directory, ifd, nikon_tag)
+        } else if note.starts_with(b"SONY DSC ")
+            || note.starts_with(b"SONY CAM ")
+            || note.starts_with(b"SONY MOBILE")
+        {
+            let directory = Directory {
+                data: tiff,
+                base: 0,
+                little_endian,
+            };
+            ("Sony", directory, note_offset + 12, sony_tag)
+        } else if make.starts_with("canon") {
+            let directory = Directory {
+                data: tiff,
+                base: 0,
+                little_endian,
+            };
+            ("Canon", directory, note_offset, canon_tag)
+        } else {
+            return None;
+        };
+
+    let entries = directory.entries(ifd);
+    if entries.is_empty() {
+        return None;
+    }
+    let mut maker_note = MakerNote {
+        vendor: vendor.to_string(),
+        serial_number: None,
+        lens_model: None,
+        firmware_version: None,
+        shutter_count: None,
+        tags: Vec::with_capacity(entries.len()),
+    };
+    for (id, kind, value) in entries {
+        let text = directory.render(kind, value);
+        let name = names(id);
+        match name {
+            Some("SerialNumber") => maker_note.serial_number = Some(text.clone()),
+            Some("LensModel") | Some("Lens") => maker_note.lens_model = Some(text.clone()),
+            Some("FirmwareVersion") => maker_note.firmware_version = Some(text.clone()),
+            Some("ShutterCount") => maker_note.shutter_count = text.parse().ok(),
+            _ => {}
+        }
+        maker_note.tags.push(MakerNoteTag {
+            id,
+            name: name.map_or_else(|| format!("{:#06x}", id), |n| n.to_string()),
+            value: text,
+        });
+    }
+    Some(maker_note)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A big- or little-endian IFD at `ifd_at` whose out-of-line values are
+    /// placed after it, with offsets counted from `base`
+    fn ifd(entries: &[(u16, u16, Vec<u8>)], ifd_at: usize, base: usize, le: bool) -> Vec<u8> {
+        let u16b = |v: u16| if le { v.to_le_bytes() } else { v.to_be_bytes() };
+        let u32b = |v: u32| if le { v.to_le_bytes() } else { v.to_be_bytes() };
+        let mut out = u16b(entries.len() as u16).to_vec();
+        let mut extra: Vec<u8> = Vec::new();
+        let extra_at = ifd_at + 2 + entries.len() * 12 + 4;
+        for (id, kind, value) in entries {
+            out.extend(u16b(*id));
+            out.extend(u16b(*kind));
+            let count = value.len() / type_size(*kind).unwrap();
+            out.extend(u32b(count as u32));
+            if value.len() <= 4 {
+                let mut inline = value.clone();
+                inline.resize(4, 0);
+                out.extend(inline);
+            } else {
+                out.extend(u32b((extra_at + extra.len() - base) as u32));
+                extra.extend(value);
+            }
+        }
+        out.extend([0; 4]);
+        out.extend(extra);
+        out
+    }
+
+    #[test]
+    fn reads_canon_directories_from_the_tiff_base() {
+        let tiff_header = b"II*\0\x08\0\0\0".to_vec();
+        let note_offset = 40;
+        let mut tiff = tiff_header;
+        tiff.resize(note_offset, 0);
+        tiff.extend(ifd(
+            &[
+                (0x0006, 2, b"Canon EOS R5\0".to_vec()),
+                (0x0007, 2, b"Firmware Version 1.8.1\0".to_vec()),
+                (0x000c, 4, 123456789u32.to_le_bytes().to_vec()),
+                (0x0095, 2, b"RF24-105mm F4 L IS USM\0".to_vec()),
+                (0x4001, 3, vec![1, 0, 2, 0, 3, 0]),
+            ],
+            note_offset,
+            0,
+            true,
+        ));
+        let note = parse_maker_note(Some("Canon"), &tiff, note_offset, true).unwrap();
+        assert_eq!(note.vendor, "Canon");
+        assert_eq!(note.serial_number.as_deref(), Some("123456789"));
+        assert_eq!(note.lens_model.as_deref(), Some("RF24-105mm F4 L IS USM"));
+        assert_eq!(
+            note.firmware_version.as_deref(),
+            Some("Firmware Version 1.8.1")
+        );
+        assert_eq!(note.tags[4].name, "0x4001");
+        assert_eq!(note.tags[4].value, "1, 2, 3");
+    }
+
+    #[test]
+    fn reads_nikon_embedded_tiff() {
+        let mut note = b"Nikon\0\x02\x10\0\0MM\0\x2a\0\0\0\x08".to_vec();
+        note.extend(ifd(
+            &[
+                (0x0001, 7, b"0211".to_vec()),
+                (0x001d, 2, b"6012345\0".to_vec()),
+                (
+                    0x0084,
+                    5,
+                    [24u32, 1, 70, 1, 28, 10, 28, 10]
+                        .iter()
+                        .flat_map(|n| n.to_be_bytes())
+                        .collect(),
+                ),
+                (0x00a7, 4, 48213u32.to_be_bytes().to_vec()),
+            ],
+            18,
+            10,
+            false,
+        ));
+        let tiff = [b"II*\0\x08\0\0\0".as_slice(), &note].concat();
+        let note = parse_maker_note(Some("NIKON CORPORATION"), &tiff, 8, true).unwrap();
+        assert_eq!(note.vendor, "Nikon");
+        assert_eq!(note.serial_number.as_deref(), Some("6012345"));
+        assert_eq!(note.shutter_count, Some(48213));
+        assert_eq!(note.lens_model.as_deref(), Some("24/1, 70/1, 28/10, 28/10"));
+        assert_eq!(note.tags[0].value, "0211");
+    }
+
+    #[test]
+    fn reads_sony_and_apple_headers() {
+        let note_offset = 8;
+        let mut tiff = b"MM\0*\0\0\0\x08".to_vec();
+        tiff.extend(b"SONY DSC \0\0\0");
+        tiff.extend(ifd(
+            &[(0xb027, 4, 32850u32.to_be_bytes().to_vec())],
+            note_offset + 12,
+            0,
+            false,
+        ));
+        let sony = parse_maker_note(Some("SONY"), &tiff, note_offset, false).unwrap();
+        assert_eq!(sony.vendor, "Sony");
+        assert_eq!(sony.tags[0].name, "LensType");
+        assert_eq!(sony.tags[0].value, "32850");
+
+        let mut note = b"Apple iOS\0\0\x01MM".to_vec();
+        note.extend(ifd(
+            &[(
+                0x0011,
+                2,
+                b"8C3B6F52-1A2B-4C5D-9E8F-0A1B2C3D4E5F\0".to_vec(),
+            )],
+            14,
+            0,
+            false,
+        ));
+        // Apple notes keep their own byte order, whatever the EXIF uses
+        let tiff = [b"II*\0\x08\0\0\0".as_slice(), &note].concat();
+        let apple = parse_maker_note(Some("Apple"), &tiff, 8, true).unwrap();
+        assert_eq!(apple.vendor, "Apple");
+        assert_eq!(apple.tags[0].name, "ContentIdentifier");
+        assert_eq!(apple.tags[0].value, "8C3B6F52-1A2B-4C5D-9E8F-0A1B2C3D4E5F");
+    }
+
+    #[test]
+    fn ignores_unknown_and_truncated_notes() {
+        let tiff = b"II*\0\x08\0\0\0FUJIFILM\x0c\0\0\0".to_vec();
+        assert!(parse_maker_note(Some("FUJIFILM"), &tiff, 8, true).is_none());
+        // Claims 200 entries, holds none
+        let tiff = b"II*\0\x08\0\0\0\xc8\0".to_vec();
+        assert!(parse_maker_note(Some("Canon"), &tiff, 8, true).is_none());
+        assert!(parse_maker_note(Some("Canon"), &tiff, 400, true).is_none());
+    }
+}
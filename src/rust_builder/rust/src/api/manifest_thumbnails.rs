@@ -0,0 +1,221 @@
+//! Thumbnails embedded in C2PA manifests: the claim thumbnail
+//! (`c2pa.thumbnail.claim.*`) of each manifest and the thumbnails recorded
+//! for its ingredients, showing what each looked like when it was signed.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use c2pa::{Ingredient, Manifest, Reader};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::error::AnalysisError;
+use super::input::{AssetInput, BytesInput, FileInput, OpenedAsset};
+use super::mime::sniff_mime_type;
+
+/// An image stored in a manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestThumbnail {
+    /// e.g. "image/jpeg", read from the image itself when recognized
+    pub mime_type: String,
+    /// Where the thumbnail is stored, e.g.
+    /// "self#jumbf=c2pa.assertions/c2pa.thumbnail.claim.jpeg"
+    pub identifier: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// The thumbnail recorded for one ingredient
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IngredientThumbnail {
+    pub title: String,
+    pub instance_id: String,
+    /// Label of the ingredient's own manifest, if it carried Content Credentials
+    pub manifest_label: Option<String>,
+    pub thumbnail: ManifestThumbnail,
+}
+
+/// The thumbnails one manifest carries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestThumbnails {
+    /// `c2pa.thumbnail.claim.*`: the asset as it was signed
+    pub claim: Option<ManifestThumbnail>,
+    /// In the manifest's ingredient order; ingredients without a thumbnail
+    /// are left out
+    pub ingredients: Vec<IngredientThumbnail>,
+}
+
+/// Every thumbnail in a manifest store
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct C2paThumbnails {
+    pub active_label: Option<String>,
+    /// By manifest label; manifests without thumbnails are left out
+    pub manifests: HashMap<String, ManifestThumbnails>,
+}
+
+/// The MIME type of a thumbnail: sniffed from its bytes, else from the
+/// format the manifest records, which may be a MIME type or an extension
+fn thumbnail_mime_type(format: &str, data: &[u8]) -> String {
+    if let Some(sniffed) = sniff_mime_type(data) {
+        return sniffed.to_string();
+    }
+    if format.contains('/') {
+        return format.to_string();
+    }
+    match format.to_lowercase().as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "avif" => "image/avif",
+        "heic" | "heif" => "image/heif",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+    .to_string()
+}
+
+fn manifest_thumbnail(manifest: &Manifest) -> Option<ManifestThumbnail> {
+    let (format, data) = manifest.thumbnail()?;
+    Some(ManifestThumbnail {
+        mime_type: thumbnail_mime_type(format, &data),
+        identifier: manifest.thumbnail_ref().map(|r| r.identifier.clone()),
+        data: data.into_owned(),
+    })
+}
+
+fn ingredient_thumbnail(ingredient: &Ingredient) -> Option<IngredientThumbnail> {
+    let (format, data) = ingredient.thumbnail()?;
+    Some(IngredientThumbnail {
+        title: ingredient.title().to_string(),
+        instance_id: ingredient.instance_id().to_string(),
+        manifest_label: ingredient.active_manifest().map(|s| s.to_string()),
+        thumbnail: ManifestThumbnail {
+            mime_type: thumbnail_mime_type(format, &data),
+            identifier: ingredient.thumbnail_ref().map(|r| r.identifier.clone()),
+            data: data.into_owned(),
+        },
+    })
+}
+
+/// The thumbnails one manifest carries, if it carries any
+fn thumbnails_of(manifest: &Manifest) -> Option<ManifestThumbnails> {
+    let thumbnails = ManifestThumbnails {
+        claim: manifest_thumbnail(manifest),
+        ingredients: manifest
+            .ingredients()
+            .iter()
+            .filter_map(ingredient_thumbnail)
+            .collect(),
+    };
+    let empty = thumbnails.claim.is_none() && thumbnails.ingredients.is_empty();
+    (!empty).then_some(thumbnails)
+}
+
+fn read_thumbnails(reader: &Reader) -> C2paThumbnails {
+    let manifests = reader
+        .iter_manifests()
+        .filter_map(|manifest| Some((manifest.label()?.to_string(), thumbnails_of(manifest)?)))
+        .collect();
+    C2paThumbnails {
+        active_label: reader.active_label().map(|s| s.to_string()),
+        manifests,
+    }
+}
+
+fn thumbnails_from_input<I: AssetInput>(input: I) -> Result<C2paThumbnails, AnalysisError> {
+    match OpenedAsset::open(input)?.manifest_reader() {
+        Ok(reader) => Ok(read_thumbnails(&reader)),
+        // No manifest, so no thumbnails
+        Err(AnalysisError::ManifestNotFound) => Ok(C2paThumbnails {
+            active_label: None,
+            manifests: HashMap::new(),
+        }),
+        Err(error) => Err(error),
+    }
+}
+
+/// Claim and ingredient thumbnails from every manifest in a file
+#[frb(sync)]
+pub fn c2pa_thumbnails_from_path(file_path: String) -> anyhow::Result<C2paThumbnails> {
+    if !Path::new(&file_path).exists() {
+        anyhow::bail!(AnalysisError::Io {
+            message: format!("File not found: {}", file_path),
+        });
+    }
+    Ok(thumbnails_from_input(FileInput::new(file_path))?)
+}
+
+/// Claim and ingredient thumbnails from every manifest in raw bytes
+#[frb(sync)]
+pub fn c2pa_thumbnails_from_bytes(
+    data: Vec<u8>,
+    mime_type: String,
+) -> anyhow::Result<C2paThumbnails> {
+    Ok(thumbnails_from_input(BytesInput::new(data, mime_type))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnail_types_come_from_the_bytes_first() {
+        let jpeg = [0xff, 0xd8, 0xff, 0xe0, 0, 0x10];
+        assert_eq!(thumbnail_mime_type("image/png", &jpeg), "image/jpeg");
+        assert_eq!(thumbnail_mime_type("image/heic", b"...."), "image/heic");
+        assert_eq!(thumbnail_mime_type("jpeg", b"...."), "image/jpeg");
+        assert_eq!(thumbnail_mime_type("PNG", b"...."), "image/png");
+        assert_eq!(thumbnail_mime_type("", b"...."), "application/octet-stream");
+    }
+
+    const JPEG: &[u8] = &[0xff, 0xd8, 0xff, 0xe0, 0, 0x10, b'J', b'F', b'I', b'F'];
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    #[test]
+    fn claim_and_ingredient_thumbnails_are_read() {
+        let mut with_thumbnail = Ingredient::new("photo.jpg", "image/jpeg", "xmp.iid:1");
+        with_thumbnail.set_thumbnail("jpeg", JPEG).unwrap();
+        let without_thumbnail = Ingredient::new("logo.svg", "image/svg+xml", "xmp.iid:2");
+        let mut second = Ingredient::new("sky.png", "image/png", "xmp.iid:3");
+        second.set_thumbnail("image/png", PNG).unwrap();
+
+        let mut manifest = Manifest::new("Test/1.0");
+        manifest.set_thumbnail("image/png", JPEG).unwrap();
+        manifest.add_ingredient(with_thumbnail);
+        manifest.add_ingredient(without_thumbnail);
+        manifest.add_ingredient(second);
+
+        let thumbnails = thumbnails_of(&manifest).unwrap();
+        let claim = thumbnails.claim.unwrap();
+        // The bytes decide the type over the recorded format
+        assert_eq!(claim.mime_type, "image/jpeg");
+        assert_eq!(claim.data, JPEG);
+        assert!(claim.identifier.is_some());
+
+        let ingredients = thumbnails.ingredients;
+        assert_eq!(ingredients.len(), 2);
+        assert_eq!(ingredients[0].title, "photo.jpg");
+        assert_eq!(ingredients[0].instance_id, "xmp.iid:1");
+        assert_eq!(ingredients[0].manifest_label, None);
+        assert_eq!(ingredients[0].thumbnail.mime_type, "image/jpeg");
+        assert_eq!(ingredients[0].thumbnail.data, JPEG);
+        assert!(ingredients[0].thumbnail.identifier.is_some());
+        assert_eq!(ingredients[1].title, "sky.png");
+        assert_eq!(ingredients[1].thumbnail.mime_type, "image/png");
+        assert_eq!(ingredients[1].thumbnail.data, PNG);
+    }
+
+    #[test]
+    fn manifests_without_thumbnails_are_left_out() {
+        let mut manifest = Manifest::new("Test/1.0");
+        manifest.add_ingredient(Ingredient::new("logo.svg", "image/svg+xml", "xmp.iid:2"));
+        assert_eq!(thumbnails_of(&manifest), None);
+
+        let mut ingredient = Ingredient::new("photo.jpg", "image/jpeg", "xmp.iid:1");
+        ingredient.set_thumbnail("image/jpeg", JPEG).unwrap();
+        manifest.add_ingredient(ingredient);
+        let thumbnails = thumbnails_of(&manifest).unwrap();
+        assert_eq!(thumbnails.claim, None);
+        assert_eq!(thumbnails.ingredients.len(), 1);
+    }
+}
@@ -0,0 +1,252 @@
+//! Magic-byte MIME type detection.
+//!
+//! The claimed type (from the caller or a file extension) is only a hint: the
+//! pipeline reads with the detected type and reports disagreements, since a
+//! file renamed to hide what it is deserves a second look.
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// How many leading bytes detection looks at
+pub(crate) const SNIFF_LEN: u64 = 4096;
+
+/// TIFF tag that marks a TIFF file as a DNG raw
+const TIFF_TAG_DNG_VERSION: u16 = 0xc612;
+
+/// Claimed versus detected MIME type of the analyzed asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MimeCheck {
+    /// Type given by the caller or implied by the file extension
+    pub claimed_mime_type: String,
+    /// Type identified from the file's leading bytes, if recognized
+    pub detected_mime_type: Option<String>,
+    /// The content is not what it claims to be
+    pub mismatch: bool,
+}
+
+fn tiff_u16(data: &[u8], pos: usize, little_endian: bool) -> Option<u16> {
+    let bytes: [u8; 2] = data.get(pos..pos + 2)?.try_into().ok()?;
+    Some(if little_endian {
+        u16::from_le_bytes(bytes)
+    } else {
+        u16::from_be_bytes(bytes)
+    })
+}
+
+fn tiff_u32(data: &[u8], pos: usize, little_endian: bool) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(pos..pos + 4)?.try_into().ok()?;
+    Some(if little_endian {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    })
+}
+
+/// DNG is TIFF with a DNGVersion tag in IFD0. Returns `None` when IFD0 lies
+/// beyond the sniffed header, in which case the file is reported as TIFF.
+fn is_dng(data: &[u8], little_endian: bool) -> Option<bool> {
+    let ifd = tiff_u32(data, 4, little_endian)? as usize;
+    let count = tiff_u16(data, ifd, little_endian)? as usize;
+    for i in 0..count {
+        let tag = tiff_u16(data, ifd + 2 + i * 12, little_endian)?;
+        if tag == TIFF_TAG_DNG_VERSION {
+            return Some(true);
+        }
+    }
+    Some(false)
+}
+
+/// Classify an ISO BMFF file by its major and compatible brands
+fn bmff_mime_type(data: &[u8]) -> Option<&'static str> {
+    // Size, type, major brand and minor version
+    if data.len() < 16 {
+        return None;
+    }
+    let size = u32::from_be_bytes(data[0..4].try_into().ok()?) as usize;
+    // A declared size below the header (0 = to end of file, 1 = 64-bit size)
+    // leaves only the major brand
+    let end = size.min(data.len()).max(16);
+    let brands: Vec<&[u8]> = std::iter::once(&data[8..12])
+        .chain(data[16..end].chunks_exact(4))
+        .collect();
+    let has = |names: &[&[u8; 4]]| brands.iter().any(|b| names.iter().any(|n| *b == *n));
+
+    // Image brands win over generic ISO brands some still images also carry
+    if has(&[b"avif", b"avis"]) {
+        Some("image/avif")
+    } else if has(&[b"heic", b"heix", b"heim", b"heis", b"hevc", b"hevx"]) {
+        Some("image/heic")
+    } else if has(&[b"mif1", b"msf1"]) {
+        Some("image/heif")
+    } else if brands[0] == b"qt  " {
+        Some("video/quicktime")
+    } else if has(&[b"M4A ", b"M4B "]) {
+        Some("audio/mp4")
+    } else {
+        Some("video/mp4")
+    }
+}
+
+/// MPEG audio frame header: 11-bit sync, MPEG version not reserved, layer III
+fn is_mp3_frame(data: &[u8]) -> bool {
+    matches!(data, [0xff, b, ..] if b & 0xe0 == 0xe0 && b & 0x18 != 0x08 && b & 0x06 == 0x02)
+}
+
+fn is_svg(data: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(data);
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    text.starts_with('<') && text.contains("<svg")
+}
+
+/// Identify a file from its leading bytes
+pub(crate) fn sniff_mime_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(&[0xff, 0xd8, 0xff]) {
+        return Some("image/jpeg");
+    }
+    if data.starts_with(&[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]) {
+        return Some("image/png");
+    }
+    if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        return Some("image/gif");
+    }
+    if data.starts_with(b"RIFF") {
+        return match data.get(8..12) {
+            Some(b"WEBP") => Some("image/webp"),
+            Some(b"WAVE") => Some("audio/wav"),
+            _ => None,
+        };
+    }
+    if data.starts_with(b"II*\0") || data.starts_with(b"MM\0*") {
+        let little_endian = data[0] == b'I';
+        return if is_dng(data, little_endian) == Some(true) {
+            Some("image/x-adobe-dng")
+        } else {
+            Some("image/tiff")
+        };
+    }
+    if data.get(4..8) == Some(b"ftyp") {
+        return bmff_mime_type(data);
+    }
+    if data.starts_with(b"%PDF-") {
+        return Some("application/pdf");
+    }
+    if data.starts_with(b"ID3") || is_mp3_frame(data) {
+        return Some("audio/mpeg");
+    }
+    if is_svg(data) {
+        return Some("image/svg+xml");
+    }
+    None
+}
+
+/// Canonical form of a MIME type so aliases compare equal
+fn normalize(mime_type: &str) -> String {
+    let lower = mime_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase();
+    match lower.as_str() {
+        "image/jpg" | "image/pjpeg" => "image/jpeg",
+        "image/heif" | "image/heic" | "image/heif-sequence" | "image/heic-sequence" => "image/heif",
+        "audio/x-wav" | "audio/wave" | "audio/vnd.wave" => "audio/wav",
+        "audio/mp3" | "audio/x-mp3" | "audio/mpeg3" => "audio/mpeg",
+        "image/x-tiff" => "image/tiff",
+        "image/dng" => "image/x-adobe-dng",
+        _ => return lower,
+    }
+    .to_string()
+}
+
+/// Compare the claimed type against the detected one
+pub(crate) fn check_mime_type(claimed: &str, detected: Option<&str>) -> MimeCheck {
+    let claimed_normalized = normalize(claimed);
+    let mismatch = match detected {
+        // An unspecific claim cannot be contradicted
+        Some(_)
+            if claimed_normalized.is_empty()
+                || claimed_normalized == "application/octet-stream" =>
+        {
+            false
+        }
+        Some(detected) => {
+            let detected = normalize(detected);
+            // DNG is a TIFF profile, so a DNG labelled as TIFF is not misleading
+            let dng_as_tiff = detected == "image/x-adobe-dng" && claimed_normalized == "image/tiff";
+            detected != claimed_normalized && !dng_as_tiff
+        }
+        None => false,
+    };
+    MimeCheck {
+        claimed_mime_type: claimed.to_string(),
+        detected_mime_type: detected.map(|d| d.to_string()),
+        mismatch,
+    }
+}
+
+/// Detect the MIME type of a file from its leading bytes
+#[frb(sync)]
+pub fn detect_mime_type(data: Vec<u8>) -> Option<String> {
+    sniff_mime_type(&data).map(|m| m.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ftyp(size: u32, major: &[u8; 4], compatible: &[&[u8; 4]]) -> Vec<u8> {
+        let mut out = size.to_be_bytes().to_vec();
+        out.extend_from_slice(b"ftyp");
+        out.extend_from_slice(major);
+        out.extend_from_slice(&[0; 4]);
+        for brand in compatible {
+            out.extend_from_slice(*brand);
+        }
+        out
+    }
+
+    fn sniff_ftyp(size: u32, major: &[u8; 4], compatible: &[&[u8; 4]]) -> Option<&'static str> {
+        sniff_mime_type(&ftyp(size, major, compatible))
+    }
+
+    #[test]
+    fn bmff_brands_pick_the_type() {
+        assert_eq!(
+            sniff_ftyp(24, b"mif1", &[b"avif", b"mif1"]),
+            Some("image/avif")
+        );
+        assert_eq!(
+            sniff_ftyp(24, b"heic", &[b"mif1", b"heic"]),
+            Some("image/heic")
+        );
+        assert_eq!(sniff_ftyp(20, b"mif1", &[b"mif1"]), Some("image/heif"));
+        assert_eq!(sniff_ftyp(20, b"qt  ", &[b"qt  "]), Some("video/quicktime"));
+        assert_eq!(sniff_ftyp(20, b"M4A ", &[b"isom"]), Some("audio/mp4"));
+        assert_eq!(sniff_ftyp(20, b"isom", &[b"mp41"]), Some("video/mp4"));
+    }
+
+    #[test]
+    fn truncated_bmff_headers_are_not_identified() {
+        let header = ftyp(24, b"heic", &[b"mif1", b"heic"]);
+        for len in 0..16 {
+            assert_eq!(sniff_mime_type(&header[..len]), None, "{len} bytes");
+        }
+        // The compatible brands may be cut off by the sniff window
+        assert_eq!(sniff_mime_type(&header[..16]), Some("image/heic"));
+        assert_eq!(sniff_mime_type(&header[..22]), Some("image/heic"));
+    }
+
+    #[test]
+    fn odd_bmff_box_sizes_stay_in_bounds() {
+        // 0 (to end of file) and 1 (64-bit size follows) fall back to the major brand
+        assert_eq!(sniff_ftyp(0, b"avif", &[b"mif1"]), Some("image/avif"));
+        assert_eq!(sniff_ftyp(1, b"isom", &[b"avif"]), Some("video/mp4"));
+        // Sizes that are not a whole number of brands, or overrun the data
+        assert_eq!(sniff_ftyp(22, b"isom", &[b"avif"]), Some("image/avif"));
+        assert_eq!(
+            sniff_ftyp(u32::MAX, b"isom", &[b"heic"]),
+            Some("image/heic")
+        );
+    }
+}
@@ -1,3 +1,55 @@
+pub mod actions;
 pub mod c2pa_reader;
+pub mod certificate;
+pub mod consistency;
+mod container;
+mod cose;
+mod der;
+pub mod digital_source_type;
+pub mod distinguished_name;
+pub mod error;
+pub mod evidence;
+pub mod exif_metadata;
+pub mod generation_parameters;
+pub mod input;
+pub mod iptc;
+mod jpeg_dc;
+mod jumbf;
+pub mod maker_note;
+pub mod manifest_thumbnails;
+pub mod mime;
+pub mod model_identity;
+mod png;
+pub mod revocation;
+pub mod scoring;
+pub mod signatures;
+pub mod thumbnail_comparison;
+pub mod timestamp;
+pub mod trust;
+pub mod validation_status;
+pub mod xmp;
 
+pub use actions::*;
 pub use c2pa_reader::*;
+pub use certificate::*;
+pub use consistency::*;
+pub use digital_source_type::*;
+pub use distinguished_name::*;
+pub use error::*;
+pub use evidence::*;
+pub use exif_metadata::*;
+pub use generation_parameters::*;
+pub use input::*;
+pub use iptc::*;
+pub use maker_note::*;
+pub use manifest_thumbnails::*;
+pub use mime::*;
+pub use model_identity::*;
+pub use revocation::*;
+pub use scoring::*;
+pub use signatures::*;
+pub use thumbnail_comparison::*;
+pub use timestamp::*;
+pub use trust::*;
+pub use validation_status::*;
+pub use xmp::*;
@@ -0,0 +1,170 @@
+//! Which software and model produced an asset.
+//!
+//! Software agents, claim generator info, EXIF Software and generation
+//! parameters name tools in many shapes ("Adobe Photoshop 25.4 (Generative
+//! Fill)", "sd_xl_base_1.0.safetensors", "Midjourney Model V6.1"). The
+//! signature database turns each into a vendor, product, model and version.
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::actions::ContentAction;
+use super::signatures::signature_database;
+
+/// A tool and model identified from a producer string
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelIdentity {
+    pub vendor: String,
+    pub product: String,
+    /// The model or AI feature, e.g. "Stable Diffusion XL base" or "Generative Fill"
+    pub model: Option<String>,
+    /// The model's version, or the product's when no model version is given
+    pub version: Option<String>,
+    /// Whether the product is an AI generator rather than an editor
+    pub is_ai_generator: bool,
+    /// Where the string was read, e.g. "c2pa.actions[0].softwareAgent" or an EXIF tag name
+    pub field: String,
+    /// The string the identity was read from
+    pub matched_text: String,
+}
+
+/// Identify the tool a producer string names. `version` is a version
+/// recorded separately, as in `claim_generator_info`, and is used when the
+/// string itself carries none.
+pub(crate) fn identify(text: &str, version: Option<&str>, field: &str) -> Option<ModelIdentity> {
+    let signatures = signature_database();
+    let found = signatures.identify(text)?;
+    Some(ModelIdentity {
+        vendor: found.product.vendor.clone(),
+        product: found.product.product.clone(),
+        model: found.model,
+        version: found.version.or_else(|| version.map(|v| v.to_string())),
+        is_ai_generator: found.is_generator,
+        field: field.to_string(),
+        matched_text: text.to_string(),
+    })
+}
+
+/// Add an identity unless the same tool, model and version is already listed
+pub(crate) fn push_identity(identities: &mut Vec<ModelIdentity>, identity: ModelIdentity) {
+    let known = identities.iter().any(|i| {
+        i.vendor == identity.vendor
+            && i.product == identity.product
+            && i.model == identity.model
+            && i.version == identity.version
+    });
+    if !known {
+        identities.push(identity);
+    }
+}
+
+/// Tools named by the active manifest's actions, claim generator info and
+/// claim generator, in that order
+pub(crate) fn manifest_identities(
+    actions: &[ContentAction],
+    manifest: Option<&Value>,
+) -> Vec<ModelIdentity> {
+    let mut identities = Vec::new();
+    for (index, action) in actions.iter().enumerate() {
+        let Some(agent) = &action.software_agent_info else {
+            continue;
+        };
+        let field = format!("c2pa.actions[{}].softwareAgent", index);
+        if let Some(identity) = identify(&agent.name, agent.version.as_deref(), &field) {
+            push_identity(&mut identities, identity);
+        }
+    }
+
+    let Some(manifest) = manifest else {
+        return identities;
+    };
+    let infos = manifest
+        .get("claim_generator_info")
+        .and_then(Value::as_array);
+    for (index, info) in infos.into_iter().flatten().enumerate() {
+        let Some(name) = info.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        let version = info.get("version").and_then(Value::as_str);
+        let field = format!("claim_generator_info[{}]", index);
+        if let Some(identity) = identify(name, version, &field) {
+            push_identity(&mut identities, identity);
+        }
+    }
+    if let Some(claim_generator) = manifest.get("claim_generator").and_then(Value::as_str) {
+        if let Some(identity) = identify(claim_generator, None, "claim_generator") {
+            push_identity(&mut identities, identity);
+        }
+    }
+    identities
+}
+
+/// Identify the tool and model a producer string names, using the loaded
+/// signature database
+#[frb(sync)]
+pub fn identify_model(text: String) -> Option<ModelIdentity> {
+    identify(&text, None, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(text: &str) -> ModelIdentity {
+        identify(text, None, "test").unwrap_or_else(|| panic!("{} not identified", text))
+    }
+
+    #[test]
+    fn identifies_editor_features_and_versions() {
+        let ps = identity("Adobe Photoshop 25.4 (Generative Fill)");
+        assert_eq!(ps.vendor, "Adobe");
+        assert_eq!(ps.product, "Photoshop");
+        assert_eq!(ps.model.as_deref(), Some("Generative Fill"));
+        assert_eq!(ps.version.as_deref(), Some("25.4"));
+        assert!(!ps.is_ai_generator);
+    }
+
+    #[test]
+    fn identifies_checkpoint_file_names() {
+        let sd = identity("sd_xl_base_1.0.safetensors");
+        assert_eq!(sd.vendor, "Stability AI");
+        assert_eq!(sd.product, "Stable Diffusion");
+        assert_eq!(sd.model.as_deref(), Some("Stable Diffusion XL base"));
+        assert_eq!(sd.version.as_deref(), Some("1.0"));
+        assert!(sd.is_ai_generator);
+    }
+
+    #[test]
+    fn identifies_model_versions() {
+        let mj = identity("Midjourney Model V6.1");
+        assert_eq!(mj.product, "Midjourney");
+        assert_eq!(mj.model.as_deref(), Some("Midjourney v6.1"));
+        assert_eq!(mj.version.as_deref(), Some("6.1"));
+
+        let dalle = identity("dall-e-3");
+        assert_eq!(dalle.vendor, "OpenAI");
+        assert_eq!(dalle.model.as_deref(), Some("DALL-E 3"));
+
+        let comfy = identity("ComfyUI sdxl");
+        assert_eq!(comfy.product, "ComfyUI");
+        assert_eq!(comfy.model.as_deref(), Some("Stable Diffusion XL"));
+    }
+
+    #[test]
+    fn separate_versions_fill_in() {
+        let firefly = identify("Adobe_Firefly", Some("1.0"), "claim_generator_info[0]").unwrap();
+        assert_eq!(firefly.product, "Firefly");
+        assert_eq!(firefly.model, None);
+        assert_eq!(firefly.version.as_deref(), Some("1.0"));
+
+        let inline = identity("Adobe_Firefly/2.1");
+        assert_eq!(inline.version.as_deref(), Some("2.1"));
+    }
+
+    #[test]
+    fn unknown_software_is_not_identified() {
+        assert!(identify("Capture One 23", None, "Software").is_none());
+        assert!(identify("Imagenomic Portraiture", None, "Software").is_none());
+    }
+}
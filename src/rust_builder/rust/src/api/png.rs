@@ -0,0 +1,188 @@
+//! PNG text chunks (`tEXt`, `zTXt`, `iTXt`), read from the stream chunk by
+//! chunk so image data is skipped rather than loaded.
+
+use flate2::read::ZlibDecoder;
+use std::io::{Read, Seek, SeekFrom};
+
+use super::jumbf::PNG_SIGNATURE;
+
+/// Text chunks larger than this are skipped
+const MAX_TEXT_CHUNK: u64 = 32 * 1024 * 1024;
+
+/// Compressed text is not inflated beyond this, so small chunks cannot
+/// expand into huge allocations
+const MAX_INFLATED: u64 = 64 * 1024 * 1024;
+
+/// One keyword/text pair from a PNG text chunk
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PngText {
+    /// "tEXt", "zTXt" or "iTXt"
+    pub chunk_type: String,
+    pub keyword: String,
+    pub text: String,
+}
+
+fn inflate(data: &[u8]) -> Option<Vec<u8>> {
+    let mut inflated = Vec::new();
+    ZlibDecoder::new(data)
+        .take(MAX_INFLATED)
+        .read_to_end(&mut inflated)
+        .ok()?;
+    Some(inflated)
+}
+
+/// `tEXt` and `zTXt` are Latin-1 by the spec, but several generators write
+/// UTF-8 into them, so UTF-8 is tried first
+fn decode_text(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+/// Split a NUL-terminated field off the front of `data`
+fn split_nul(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let end = data.iter().position(|&b| b == 0)?;
+    Some((&data[..end], &data[end + 1..]))
+}
+
+fn parse_text_chunk(chunk_type: &[u8; 4], data: &[u8]) -> Option<PngText> {
+    let (keyword, rest) = split_nul(data)?;
+    let text = match chunk_type {
+        b"tEXt" => decode_text(rest),
+        // Compression method (always 0, zlib), then the compressed text
+        b"zTXt" => decode_text(&inflate(rest.get(1..)?)?),
+        b"iTXt" => {
+            let compressed = *rest.first()? == 1;
+            let (_language, rest) = split_nul(rest.get(2..)?)?;
+            let (_translated_keyword, text) = split_nul(rest)?;
+            if compressed {
+                String::from_utf8_lossy(&inflate(text)?).into_owned()
+            } else {
+                String::from_utf8_lossy(text).into_owned()
+            }
+        }
+        _ => return None,
+    };
+    Some(PngText {
+        chunk_type: String::from_utf8_lossy(chunk_type).into_owned(),
+        keyword: decode_text(keyword),
+        text,
+    })
+}
+
+/// Every text chunk in a PNG stream, in file order. Returns nothing for
+/// other formats.
+pub(crate) fn read_png_text<R: Read + Seek>(stream: &mut R) -> Vec<PngText> {
+    let mut chunks = Vec::new();
+    let mut signature = [0u8; 8];
+    if stream.rewind().is_err()
+        || stream.read_exact(&mut signature).is_err()
+        || signature != PNG_SIGNATURE
+    {
+        return chunks;
+    }
+
+    let mut header = [0u8; 8];
+    while stream.read_exact(&mut header).is_ok() {
+        let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        let chunk_type = [header[4], header[5], header[6], header[7]];
+        let is_text = matches!(&chunk_type, b"tEXt" | b"zTXt" | b"iTXt");
+        if &chunk_type == b"IEND" {
+            break;
+        }
+        if is_text && len <= MAX_TEXT_CHUNK {
+            let mut data = vec![0u8; len as usize];
+            if stream.read_exact(&mut data).is_err() {
+                break;
+            }
+            chunks.extend(parse_text_chunk(&chunk_type, &data));
+            // CRC
+            if stream.seek(SeekFrom::Current(4)).is_err() {
+                break;
+            }
+        } else if stream.seek(SeekFrom::Current(len as i64 + 4)).is_err() {
+            break;
+        }
+    }
+    chunks
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::{Cursor, Write};
+
+    fn chunk(chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut out = (data.len() as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(chunk_type);
+        out.extend_from_slice(data);
+        // The reader does not check CRCs
+        out.extend_from_slice(&[0; 4]);
+        out
+    }
+
+    fn deflate(text: &str) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// A minimal PNG carrying the given text chunks, one of each type per entry
+    pub(crate) fn png_with_text(entries: &[(&[u8; 4], &str, &str)]) -> Vec<u8> {
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend(chunk(b"IHDR", &[0; 13]));
+        for (chunk_type, keyword, text) in entries {
+            let mut data = keyword.as_bytes().to_vec();
+            data.push(0);
+            match *chunk_type {
+                b"tEXt" => data.extend_from_slice(text.as_bytes()),
+                b"zTXt" => {
+                    data.push(0);
+                    data.extend(deflate(text));
+                }
+                _ => {
+                    // Compressed, no language tag or translated keyword
+                    data.extend_from_slice(&[1, 0, 0, 0]);
+                    data.extend(deflate(text));
+                }
+            }
+            png.extend(chunk(chunk_type, &data));
+        }
+        png.extend(chunk(b"IDAT", &[0; 64]));
+        png.extend(chunk(b"IEND", &[]));
+        png
+    }
+
+    #[test]
+    fn reads_every_text_chunk_type() {
+        let png = png_with_text(&[
+            (b"tEXt", "parameters", "a cat\nSteps: 20"),
+            (b"zTXt", "prompt", "{\"3\": {}}"),
+            (b"iTXt", "workflow", "{\"nodes\": []}"),
+        ]);
+        let chunks = read_png_text(&mut Cursor::new(png));
+        let pairs: Vec<_> = chunks
+            .iter()
+            .map(|c| (c.chunk_type.as_str(), c.keyword.as_str(), c.text.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("tEXt", "parameters", "a cat\nSteps: 20"),
+                ("zTXt", "prompt", "{\"3\": {}}"),
+                ("iTXt", "workflow", "{\"nodes\": []}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_other_formats_and_truncated_files() {
+        assert!(read_png_text(&mut Cursor::new(b"GIF89a....".to_vec())).is_empty());
+        let mut png = png_with_text(&[(b"tEXt", "Comment", "hello")]);
+        png.truncate(PNG_SIGNATURE.len() + 30);
+        assert!(read_png_text(&mut Cursor::new(png)).is_empty());
+    }
+}
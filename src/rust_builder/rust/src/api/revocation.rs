@@ -0,0 +1,687 @@
+//! Offline certificate revocation checking.
+//!
+//! Nothing here touches the network: status comes from OCSP responses stapled
+//! into the claim signature, cached OCSP responses and CRLs loaded through the
+//! trust store API.
+
+use ring::digest;
+use serde::{Deserialize, Serialize};
+use serde_cbor::Value;
+use x509_parser::der_parser::asn1_rs::BitString;
+use x509_parser::prelude::*;
+
+use super::certificate::CertificateInfo;
+use super::cose;
+use super::der::{
+    context, context_primitive, expect_tlv, Tlv, TAG_BIT_STRING, TAG_ENUMERATED,
+    TAG_GENERALIZED_TIME, TAG_INTEGER, TAG_OCTET_STRING, TAG_SEQUENCE,
+};
+use super::trust::{revocation_data, trust_anchors};
+
+const OID_OCSP_BASIC: &str = "1.3.6.1.5.5.7.48.1.1";
+
+/// Revocation status of a single certificate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevocationState {
+    Good,
+    Revoked,
+    /// No applicable, verifiable OCSP response or CRL was available
+    Unknown,
+}
+
+/// Where a revocation status came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevocationSource {
+    /// OCSP response stapled into the claim signature's `rVals` header
+    StapledOcsp,
+    /// OCSP response loaded with `add_ocsp_response_from_bytes`
+    CachedOcsp,
+    /// Certificate revocation list loaded with `add_crls_from_bytes`
+    Crl,
+}
+
+/// Revocation result for a certificate in a signer chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevocationInfo {
+    pub state: RevocationState,
+    pub source: Option<RevocationSource>,
+    /// Seconds since the Unix epoch
+    pub revoked_at: Option<i64>,
+    /// CRL reason, e.g. "KeyCompromise"
+    pub reason: Option<String>,
+    pub this_update: Option<i64>,
+    pub next_update: Option<i64>,
+}
+
+impl RevocationInfo {
+    pub(crate) fn unknown() -> Self {
+        Self {
+            state: RevocationState::Unknown,
+            source: None,
+            revoked_at: None,
+            reason: None,
+            this_update: None,
+            next_update: None,
+        }
+    }
+}
+
+fn reason_name(code: u8) -> String {
+    ReasonCode(code).to_string()
+}
+
+fn certid_digest(oid: &str, data: &[u8]) -> Option<Vec<u8>> {
+    let algorithm = match oid {
+        "1.3.14.3.2.26" => &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        "2.16.840.1.101.3.4.2.1" => &digest::SHA256,
+        "2.16.840.1.101.3.4.2.2" => &digest::SHA384,
+        "2.16.840.1.101.3.4.2.3" => &digest::SHA512,
+        _ => return None,
+    };
+    Some(digest::digest(algorithm, data).as_ref().to_vec())
+}
+
+/// DER OCSP responses from the claim signature's `rVals` header
+fn stapled_ocsp_responses(cose: &[u8]) -> Vec<Vec<u8>> {
+    let mut responses = Vec::new();
+    for header in cose::headers(cose) {
+        let Some(Value::Map(r_vals)) = cose::get(&header, "rVals") else {
+            continue;
+        };
+        let Some(Value::Array(values)) = cose::get(r_vals, "ocspVals") else {
+            continue;
+        };
+        for value in values {
+            if let Value::Bytes(der) = value {
+                responses.push(der.clone());
+            }
+        }
+    }
+    responses
+}
+
+/// A status is only usable when the evaluation time falls inside its window
+fn within_window(this_update: i64, next_update: Option<i64>, time: i64) -> bool {
+    this_update <= time && next_update.is_none_or(|next| time <= next)
+}
+
+fn verify_with(
+    key: &SubjectPublicKeyInfo,
+    algorithm: &AlgorithmIdentifier,
+    signature: &Tlv,
+    data: &[u8],
+) -> bool {
+    let Some((&unused_bits, bits)) = signature.content.split_first() else {
+        return false;
+    };
+    x509_parser::verify::verify_signature(key, algorithm, &BitString::new(unused_bits, bits), data)
+        .is_ok()
+}
+
+/// The response must be signed by the issuer itself or by a responder
+/// certificate the issuer delegated OCSP signing to
+fn ocsp_signature_valid(basic: &[Tlv], issuer: &X509Certificate) -> bool {
+    let (Some(tbs), Some(algorithm), Some(signature)) = (basic.first(), basic.get(1), basic.get(2))
+    else {
+        return false;
+    };
+    if signature.tag != TAG_BIT_STRING {
+        return false;
+    }
+    let Ok((_, algorithm)) = AlgorithmIdentifier::from_der(algorithm.raw) else {
+        return false;
+    };
+    if verify_with(issuer.public_key(), &algorithm, signature, tbs.raw) {
+        return true;
+    }
+
+    let responders = basic
+        .get(3)
+        .filter(|certs| certs.tag == context(0))
+        .and_then(|certs| certs.inner())
+        .and_then(|seq| seq.children())
+        .unwrap_or_default();
+    responders.iter().any(|der| {
+        let Ok((_, responder)) = X509Certificate::from_der(der.raw) else {
+            return false;
+        };
+        let delegated =
+            matches!(responder.extended_key_usage(), Ok(Some(eku)) if eku.value.ocsp_signing);
+        delegated
+            && responder.issuer().as_raw() == issuer.subject().as_raw()
+            && responder
+                .verify_signature(Some(issuer.public_key()))
+                .is_ok()
+            && verify_with(responder.public_key(), &algorithm, signature, tbs.raw)
+    })
+}
+
+fn cert_id_matches(cert_id: &Tlv, cert: &X509Certificate, issuer: &X509Certificate) -> bool {
+    let Some(fields) = cert_id.children() else {
+        return false;
+    };
+    let (Some(algorithm), Some(name_hash), Some(key_hash), Some(serial)) =
+        (fields.first(), fields.get(1), fields.get(2), fields.get(3))
+    else {
+        return false;
+    };
+    let Some(oid) = algorithm.children().and_then(|a| a.first()?.as_oid()) else {
+        return false;
+    };
+    name_hash.tag == TAG_OCTET_STRING
+        && key_hash.tag == TAG_OCTET_STRING
+        && serial.tag == TAG_INTEGER
+        && serial.content == cert.raw_serial()
+        && certid_digest(&oid, issuer.subject().as_raw()).as_deref() == Some(name_hash.content)
+        && certid_digest(&oid, &issuer.public_key().subject_public_key.data).as_deref()
+            == Some(key_hash.content)
+}
+
+/// Evaluate one OCSP response for `cert`. Returns `None` when the response
+/// does not cover the certificate, cannot be verified or is not current.
+fn ocsp_status(
+    response: &[u8],
+    cert: &X509Certificate,
+    issuer: &X509Certificate,
+    time: i64,
+    source: RevocationSource,
+) -> Option<RevocationInfo> {
+    // OCSPResponse: responseStatus, [0] responseBytes
+    let (outer, _) = expect_tlv(response, TAG_SEQUENCE)?;
+    let outer = outer.children()?;
+    let status = outer.first().filter(|s| s.tag == TAG_ENUMERATED)?;
+    if status.content != [0] {
+        return None;
+    }
+    let response_bytes = outer
+        .get(1)
+        .filter(|b| b.tag == context(0))?
+        .inner()?
+        .children()?;
+    if response_bytes.first()?.as_oid()?.as_str() != OID_OCSP_BASIC {
+        return None;
+    }
+    let basic_octets = response_bytes
+        .get(1)
+        .filter(|b| b.tag == TAG_OCTET_STRING)?;
+    let (basic, _) = expect_tlv(basic_octets.content, TAG_SEQUENCE)?;
+    let basic = basic.children()?;
+    if !ocsp_signature_valid(&basic, issuer) {
+        return None;
+    }
+
+    // ResponseData: [0] version?, responderID, producedAt, responses, [1] extensions?
+    let data = basic.first()?.children()?;
+    let offset = usize::from(data.first()?.tag == context(0));
+    let responses = data
+        .get(offset + 2)
+        .filter(|r| r.tag == TAG_SEQUENCE)?
+        .children()?;
+
+    for single in responses {
+        // SingleResponse: certID, certStatus, thisUpdate, [0] nextUpdate?, [1] extensions?
+        let fields = single.children()?;
+        if !cert_id_matches(fields.first()?, cert, issuer) {
+            continue;
+        }
+        let cert_status = fields.get(1)?;
+        let this_update = fields
+            .get(2)
+            .filter(|t| t.tag == TAG_GENERALIZED_TIME)?
+            .as_time()?;
+        let next_update = fields
+            .iter()
+            .skip(3)
+            .find(|f| f.tag == context(0))
+            .and_then(|f| f.inner()?.as_time());
+
+        let mut info = RevocationInfo {
+            state: RevocationState::Unknown,
+            source: Some(source),
+            revoked_at: None,
+            reason: None,
+            this_update: Some(this_update),
+            next_update,
+        };
+        if cert_status.tag == context(1) {
+            // RevokedInfo: revocationTime, [0] revocationReason?
+            let revoked = cert_status.children()?;
+            let revoked_at = revoked.first()?.as_time()?;
+            if revoked_at > time {
+                return None;
+            }
+            info.state = RevocationState::Revoked;
+            info.revoked_at = Some(revoked_at);
+            info.reason = revoked
+                .get(1)
+                .filter(|r| r.tag == context(0))
+                .and_then(|r| r.inner())
+                .and_then(|r| r.content.first().copied())
+                .map(reason_name);
+            return Some(info);
+        }
+        if cert_status.tag == context_primitive(0) && within_window(this_update, next_update, time)
+        {
+            info.state = RevocationState::Good;
+            return Some(info);
+        }
+        // context_primitive(2) is an explicit "unknown" from the responder
+        return None;
+    }
+    None
+}
+
+/// Evaluate one CRL for `cert`. Returns `None` when the CRL was not issued by
+/// the certificate's issuer, does not verify or is not current.
+fn crl_status(
+    crl: &[u8],
+    cert: &X509Certificate,
+    issuer: &X509Certificate,
+    time: i64,
+) -> Option<RevocationInfo> {
+    let (_, crl) = CertificateRevocationList::from_der(crl).ok()?;
+    if crl.issuer().as_raw() != cert.issuer().as_raw()
+        || crl.verify_signature(issuer.public_key()).is_err()
+    {
+        return None;
+    }
+    let this_update = crl.last_update().timestamp();
+    let next_update = crl.next_update().map(|t| t.timestamp());
+
+    let mut info = RevocationInfo {
+        state: RevocationState::Good,
+        source: Some(RevocationSource::Crl),
+        revoked_at: None,
+        reason: None,
+        this_update: Some(this_update),
+        next_update,
+    };
+    if let Some(entry) = crl
+        .iter_revoked_certificates()
+        .find(|entry| entry.raw_serial() == cert.raw_serial())
+    {
+        let revoked_at = entry.revocation_date.timestamp();
+        if revoked_at <= time {
+            info.state = RevocationState::Revoked;
+            info.revoked_at = Some(revoked_at);
+            info.reason = entry.reason_code().map(|(_, code)| reason_name(code.0));
+            return Some(info);
+        }
+    }
+    within_window(this_update, next_update, time).then_some(info)
+}
+
+/// Fill in `revocation` for every certificate in a signer chain, leaf first.
+/// `time` is the moment the chain is evaluated at: the trusted time-stamp when
+/// there is one, otherwise now. A revoked result from any source wins.
+pub(crate) fn check_chain_revocation(
+    chain: &mut [CertificateInfo],
+    cose: Option<&[u8]>,
+    time: i64,
+) {
+    let stapled = cose.map(stapled_ocsp_responses).unwrap_or_default();
+    let (crls, cached) = revocation_data();
+    let anchors = trust_anchors();
+
+    let ders: Vec<Vec<u8>> = chain.iter().map(|c| c.der.clone()).collect();
+    for (index, info) in chain.iter_mut().enumerate() {
+        let Ok((_, cert)) = X509Certificate::from_der(&ders[index]) else {
+            continue;
+        };
+        // The issuer is the next certificate in the chain, or a configured anchor
+        let issuer = ders
+            .get(index + 1)
+            .into_iter()
+            .chain(anchors.iter())
+            .filter_map(|der| X509Certificate::from_der(der).ok().map(|(_, c)| c))
+            .find(|candidate| candidate.subject().as_raw() == cert.issuer().as_raw());
+        let Some(issuer) = issuer else {
+            continue;
+        };
+
+        let results: Vec<RevocationInfo> =
+            stapled
+                .iter()
+                .filter_map(|r| ocsp_status(r, &cert, &issuer, time, RevocationSource::StapledOcsp))
+                .chain(cached.iter().filter_map(|r| {
+                    ocsp_status(r, &cert, &issuer, time, RevocationSource::CachedOcsp)
+                }))
+                .chain(
+                    crls.iter()
+                        .filter_map(|crl| crl_status(crl, &cert, &issuer, time)),
+                )
+                .collect();
+
+        let revoked = results.iter().find(|r| r.state == RevocationState::Revoked);
+        let good = results.iter().find(|r| r.state == RevocationState::Good);
+        if let Some(result) = revoked.or(good) {
+            info.revocation = result.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::certificate::parse_certificate_der;
+    use crate::api::certificate::tests::{ed25519_algorithm, key, name, signed, TestCert};
+    use crate::api::cose::tests::{sign1, text};
+    use crate::api::der::tests::{oid, seq, tlv};
+    use crate::api::der::TAG_UTC_TIME;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use std::collections::BTreeMap;
+
+    const OID_SHA256: &str = "2.16.840.1.101.3.4.2.1";
+    const OID_OCSP_SIGNING: &str = "1.3.6.1.5.5.7.3.9";
+    // 2024-06-01T00:00:00Z
+    const NOW: i64 = 1717200000;
+    const THIS_UPDATE: &str = "20240501000000Z";
+    const NEXT_UPDATE: &str = "20240608000000Z";
+    const REVOKED_AT: &str = "20240520000000Z";
+
+    /// A CA and a certificate it issued with serial 5
+    struct Fixture {
+        ca_key: Ed25519KeyPair,
+        ca: Vec<u8>,
+        leaf: Vec<u8>,
+    }
+
+    fn fixture() -> Fixture {
+        let ca_key = key(30);
+        let mut ca = TestCert::new("Test CA", "Test CA");
+        ca.ca = true;
+        let mut leaf = TestCert::new("Test Signer", "Test CA");
+        leaf.serial = 5;
+        Fixture {
+            ca: ca.sign(&ca_key, &ca_key),
+            leaf: leaf.sign(&key(31), &ca_key),
+            ca_key,
+        }
+    }
+
+    fn parse(der: &[u8]) -> X509Certificate<'_> {
+        X509Certificate::from_der(der).unwrap().1
+    }
+
+    fn cert_id(issuer: &str, issuer_key: &Ed25519KeyPair, serial: u8) -> Vec<u8> {
+        let name_hash = digest::digest(&digest::SHA256, &name(issuer));
+        let key_hash = digest::digest(&digest::SHA256, issuer_key.public_key().as_ref());
+        seq(&[
+            seq(&[oid(OID_SHA256)]),
+            tlv(TAG_OCTET_STRING, name_hash.as_ref()),
+            tlv(TAG_OCTET_STRING, key_hash.as_ref()),
+            tlv(TAG_INTEGER, &[serial]),
+        ])
+    }
+
+    fn good() -> Vec<u8> {
+        tlv(context_primitive(0), &[])
+    }
+
+    fn revoked(reason: u8) -> Vec<u8> {
+        let mut content = tlv(TAG_GENERALIZED_TIME, REVOKED_AT.as_bytes());
+        content.extend(tlv(context(0), &tlv(TAG_ENUMERATED, &[reason])));
+        tlv(context(1), &content)
+    }
+
+    fn single(cert_id: Vec<u8>, status: Vec<u8>, next_update: Option<&str>) -> Vec<u8> {
+        let mut fields = vec![
+            cert_id,
+            status,
+            tlv(TAG_GENERALIZED_TIME, THIS_UPDATE.as_bytes()),
+        ];
+        if let Some(next) = next_update {
+            fields.push(tlv(context(0), &tlv(TAG_GENERALIZED_TIME, next.as_bytes())));
+        }
+        seq(&fields)
+    }
+
+    /// A successful basic OCSP response signed by `signer`, optionally
+    /// carrying responder certificates
+    fn ocsp(single: Vec<u8>, signer: &Ed25519KeyPair, responder_certs: &[Vec<u8>]) -> Vec<u8> {
+        let tbs = seq(&[
+            tlv(context(1), &name("Test CA")),
+            tlv(TAG_GENERALIZED_TIME, THIS_UPDATE.as_bytes()),
+            seq(&[single]),
+        ]);
+        let mut signature = vec![0];
+        signature.extend_from_slice(signer.sign(&tbs).as_ref());
+        let mut basic = vec![tbs, ed25519_algorithm(), tlv(TAG_BIT_STRING, &signature)];
+        if !responder_certs.is_empty() {
+            basic.push(tlv(context(0), &seq(responder_certs)));
+        }
+        seq(&[
+            tlv(TAG_ENUMERATED, &[0]),
+            tlv(
+                context(0),
+                &seq(&[oid(OID_OCSP_BASIC), tlv(TAG_OCTET_STRING, &seq(&basic))]),
+            ),
+        ])
+    }
+
+    /// A CRL from "Test CA", revoking serial 5 when `revoked_reason` is set.
+    /// `next_update` is a UTCTime.
+    fn crl(signer: &Ed25519KeyPair, next_update: &str, revoked_reason: Option<u8>) -> Vec<u8> {
+        let mut tbs = vec![
+            tlv(TAG_INTEGER, &[1]),
+            ed25519_algorithm(),
+            name("Test CA"),
+            tlv(TAG_UTC_TIME, &THIS_UPDATE.as_bytes()[2..]),
+            tlv(TAG_UTC_TIME, next_update.as_bytes()),
+        ];
+        if let Some(reason) = revoked_reason {
+            let reason_ext = seq(&[
+                oid("2.5.29.21"),
+                tlv(TAG_OCTET_STRING, &tlv(TAG_ENUMERATED, &[reason])),
+            ]);
+            tbs.push(seq(&[seq(&[
+                tlv(TAG_INTEGER, &[5]),
+                tlv(TAG_UTC_TIME, &REVOKED_AT.as_bytes()[2..]),
+                seq(&[reason_ext]),
+            ])]));
+        }
+        signed(seq(&tbs), signer)
+    }
+
+    fn status_of(response: &[u8], f: &Fixture, time: i64) -> Option<RevocationInfo> {
+        ocsp_status(
+            response,
+            &parse(&f.leaf),
+            &parse(&f.ca),
+            time,
+            RevocationSource::CachedOcsp,
+        )
+    }
+
+    #[test]
+    fn good_ocsp_response_within_its_window() {
+        let f = fixture();
+        let id = cert_id("Test CA", &f.ca_key, 5);
+        let response = ocsp(single(id, good(), Some(NEXT_UPDATE)), &f.ca_key, &[]);
+
+        let info = status_of(&response, &f, NOW).unwrap();
+        assert_eq!(info.state, RevocationState::Good);
+        assert_eq!(info.source, Some(RevocationSource::CachedOcsp));
+        assert_eq!(info.this_update, Some(1714521600));
+        assert_eq!(info.next_update, Some(1717804800));
+        assert_eq!(info.revoked_at, None);
+    }
+
+    #[test]
+    fn revoked_ocsp_response_carries_time_and_reason() {
+        let f = fixture();
+        let id = cert_id("Test CA", &f.ca_key, 5);
+        let response = ocsp(single(id, revoked(1), Some(NEXT_UPDATE)), &f.ca_key, &[]);
+
+        let info = status_of(&response, &f, NOW).unwrap();
+        assert_eq!(info.state, RevocationState::Revoked);
+        assert_eq!(info.revoked_at, Some(1716163200));
+        assert_eq!(info.reason.as_deref(), Some("KeyCompromise"));
+
+        // Revocation after the evaluation time does not apply yet
+        assert!(status_of(&response, &f, 1716163200 - 1).is_none());
+    }
+
+    #[test]
+    fn ocsp_response_past_next_update_is_not_used() {
+        let f = fixture();
+        let id = cert_id("Test CA", &f.ca_key, 5);
+        let response = ocsp(single(id, good(), Some("20240515000000Z")), &f.ca_key, &[]);
+        assert!(status_of(&response, &f, NOW).is_none());
+        // Nor one evaluated before it was produced
+        let id = cert_id("Test CA", &f.ca_key, 5);
+        let response = ocsp(single(id, good(), None), &f.ca_key, &[]);
+        assert!(status_of(&response, &f, 1714521600 - 1).is_none());
+        assert!(status_of(&response, &f, NOW).is_some());
+    }
+
+    #[test]
+    fn responder_must_be_the_issuer_or_delegated_by_it() {
+        let f = fixture();
+        let id = || cert_id("Test CA", &f.ca_key, 5);
+
+        // Signed by an unrelated key, with no responder certificate
+        let stranger = key(40);
+        let response = ocsp(single(id(), good(), Some(NEXT_UPDATE)), &stranger, &[]);
+        assert!(status_of(&response, &f, NOW).is_none());
+
+        // A responder the issuer delegated OCSP signing to
+        let mut delegated = TestCert::new("Test OCSP Responder", "Test CA");
+        delegated.ekus = &[OID_OCSP_SIGNING];
+        let responder = delegated.sign(&stranger, &f.ca_key);
+        let response = ocsp(
+            single(id(), good(), Some(NEXT_UPDATE)),
+            &stranger,
+            &[responder],
+        );
+        assert_eq!(
+            status_of(&response, &f, NOW).map(|i| i.state),
+            Some(RevocationState::Good)
+        );
+
+        // Without the OCSP signing usage, or issued by another CA, it is not trusted
+        let mut plain = TestCert::new("Test OCSP Responder", "Test CA");
+        plain.ekus = &["1.3.6.1.5.5.7.3.4"];
+        let undelegated = plain.sign(&stranger, &f.ca_key);
+        let response = ocsp(
+            single(id(), good(), Some(NEXT_UPDATE)),
+            &stranger,
+            &[undelegated],
+        );
+        assert!(status_of(&response, &f, NOW).is_none());
+
+        let mut foreign = TestCert::new("Test OCSP Responder", "Other CA");
+        foreign.ekus = &[OID_OCSP_SIGNING];
+        let foreign = foreign.sign(&stranger, &key(41));
+        let response = ocsp(
+            single(id(), good(), Some(NEXT_UPDATE)),
+            &stranger,
+            &[foreign],
+        );
+        assert!(status_of(&response, &f, NOW).is_none());
+    }
+
+    #[test]
+    fn responses_for_other_certificates_are_ignored() {
+        let f = fixture();
+        // A different serial
+        let response = ocsp(
+            single(
+                cert_id("Test CA", &f.ca_key, 6),
+                revoked(1),
+                Some(NEXT_UPDATE),
+            ),
+            &f.ca_key,
+            &[],
+        );
+        assert!(status_of(&response, &f, NOW).is_none());
+
+        // A different issuer name
+        let response = ocsp(
+            single(
+                cert_id("Other CA", &f.ca_key, 5),
+                revoked(1),
+                Some(NEXT_UPDATE),
+            ),
+            &f.ca_key,
+            &[],
+        );
+        assert!(status_of(&response, &f, NOW).is_none());
+
+        // The responder's explicit "unknown"
+        let unknown = tlv(context_primitive(2), &[]);
+        let response = ocsp(
+            single(cert_id("Test CA", &f.ca_key, 5), unknown, Some(NEXT_UPDATE)),
+            &f.ca_key,
+            &[],
+        );
+        assert!(status_of(&response, &f, NOW).is_none());
+    }
+
+    #[test]
+    fn unsuccessful_or_malformed_ocsp_responses_are_ignored() {
+        let f = fixture();
+        let try_later = seq(&[tlv(TAG_ENUMERATED, &[3])]);
+        assert!(status_of(&try_later, &f, NOW).is_none());
+        assert!(status_of(b"not der", &f, NOW).is_none());
+    }
+
+    #[test]
+    fn crl_status_follows_entries_and_window() {
+        let f = fixture();
+        let (leaf, ca) = (parse(&f.leaf), parse(&f.ca));
+
+        let clean = crl(&f.ca_key, "240608000000Z", None);
+        let info = crl_status(&clean, &leaf, &ca, NOW).unwrap();
+        assert_eq!(info.state, RevocationState::Good);
+        assert_eq!(info.source, Some(RevocationSource::Crl));
+
+        let revoking = crl(&f.ca_key, "240608000000Z", Some(1));
+        let info = crl_status(&revoking, &leaf, &ca, NOW).unwrap();
+        assert_eq!(info.state, RevocationState::Revoked);
+        assert_eq!(info.revoked_at, Some(1716163200));
+        assert_eq!(info.reason.as_deref(), Some("KeyCompromise"));
+
+        // A stale CRL cannot vouch for a certificate, but its revocations still stand
+        let stale = crl(&f.ca_key, "240515000000Z", None);
+        assert!(crl_status(&stale, &leaf, &ca, NOW).is_none());
+        let stale_revoking = crl(&f.ca_key, "240515000000Z", Some(1));
+        assert_eq!(
+            crl_status(&stale_revoking, &leaf, &ca, NOW).map(|i| i.state),
+            Some(RevocationState::Revoked)
+        );
+
+        // Signed by someone other than the issuer
+        let forged = crl(&key(40), "240608000000Z", Some(1));
+        assert!(crl_status(&forged, &leaf, &ca, NOW).is_none());
+    }
+
+    #[test]
+    fn stapled_responses_fill_in_the_chain() {
+        let f = fixture();
+        let id = cert_id("Test CA", &f.ca_key, 5);
+        let response = ocsp(single(id, revoked(1), Some(NEXT_UPDATE)), &f.ca_key, &[]);
+        let r_vals = Value::Map(BTreeMap::from([(
+            text("ocspVals"),
+            Value::Array(vec![Value::Bytes(response)]),
+        )]));
+        let cose = sign1(
+            BTreeMap::new(),
+            BTreeMap::from([(text("rVals"), r_vals)]),
+            b"sig",
+        );
+
+        let mut chain = vec![
+            parse_certificate_der(&f.leaf).unwrap(),
+            parse_certificate_der(&f.ca).unwrap(),
+        ];
+        check_chain_revocation(&mut chain, Some(&cose), NOW);
+        assert_eq!(chain[0].revocation.state, RevocationState::Revoked);
+        assert_eq!(
+            chain[0].revocation.source,
+            Some(RevocationSource::StapledOcsp)
+        );
+        // Nothing covers the root
+        assert_eq!(chain[1].revocation.state, RevocationState::Unknown);
+    }
+}
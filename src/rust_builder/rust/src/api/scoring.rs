@@ -0,0 +1,354 @@
+//! Calibrated AI likelihood.
+//!
+//! Every piece of evidence is treated as an independent, imperfect witness:
+//! AI evidence is combined with a noisy-OR, where a witness of a given
+//! strength is right with the probability set in [`ScoringWeights`].
+//! Camera-capture evidence then discounts the result, unless a signed
+//! declaration makes the AI involvement definitive.
+
+use anyhow::anyhow;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+use super::evidence::{Evidence, EvidenceKind, EvidenceStrength};
+
+/// Categorical verdict derived from the likelihood and the kinds of evidence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AiLabel {
+    /// A signed declaration says the content was generated by AI
+    ConfirmedAi,
+    /// Unsigned evidence makes AI generation likely
+    LikelyAi,
+    /// Existing content was edited or composited with AI
+    EditedWithAi,
+    /// Nothing, or too little, points either way
+    NoEvidence,
+    /// Capture metadata outweighs any AI evidence
+    LikelyCameraOriginal,
+}
+
+impl AiLabel {
+    /// Whether the label reports AI involvement
+    pub(crate) fn is_ai(self) -> bool {
+        matches!(
+            self,
+            AiLabel::ConfirmedAi | AiLabel::LikelyAi | AiLabel::EditedWithAi
+        )
+    }
+}
+
+/// Weights used to turn evidence into a likelihood
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoringWeights {
+    /// Probability that a single piece of definitive evidence is right
+    pub definitive: f64,
+    /// Probability that a single piece of strong evidence is right
+    pub strong: f64,
+    /// Probability that a single piece of weak evidence is right
+    pub weak: f64,
+    /// Fraction of the likelihood that conclusive camera evidence removes
+    pub camera_discount: f64,
+    /// Likelihood at or above which unsigned evidence is labelled AI
+    pub likely_threshold: f64,
+}
+
+const DEFAULT_WEIGHTS: ScoringWeights = ScoringWeights {
+    definitive: 0.97,
+    strong: 0.75,
+    weak: 0.2,
+    camera_discount: 0.3,
+    likely_threshold: 0.6,
+};
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        DEFAULT_WEIGHTS
+    }
+}
+
+impl ScoringWeights {
+    fn weight(&self, strength: EvidenceStrength) -> f64 {
+        match strength {
+            EvidenceStrength::Definitive => self.definitive,
+            EvidenceStrength::Strong => self.strong,
+            EvidenceStrength::Weak => self.weak,
+        }
+    }
+}
+
+static SCORING_WEIGHTS: Mutex<ScoringWeights> = Mutex::new(DEFAULT_WEIGHTS);
+
+/// Likelihood and label for one asset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct AiScore {
+    pub likelihood: f64,
+    pub label: AiLabel,
+}
+
+/// Probability that at least one of several independent witnesses is right
+fn noisy_or(weights: impl IntoIterator<Item = f64>) -> f64 {
+    1.0 - weights.into_iter().fold(1.0, |miss, w| miss * (1.0 - w))
+}
+
+/// Combine evidence into a likelihood and label
+pub(crate) fn score_evidence(evidence: &[Evidence], weights: &ScoringWeights) -> AiScore {
+    let combined = |kind: EvidenceKind| {
+        noisy_or(
+            evidence
+                .iter()
+                .filter(|e| e.kind == kind)
+                .map(|e| weights.weight(e.strength)),
+        )
+    };
+    let definitive = |kind: EvidenceKind| {
+        evidence
+            .iter()
+            .any(|e| e.kind == kind && e.strength == EvidenceStrength::Definitive)
+    };
+
+    let generation = combined(EvidenceKind::AiGeneration);
+    let editing = combined(EvidenceKind::AiEditing);
+    let camera = combined(EvidenceKind::CameraCapture);
+    let ai = noisy_or([generation, editing]);
+
+    let signed_generation = definitive(EvidenceKind::AiGeneration);
+    let signed_editing = definitive(EvidenceKind::AiEditing);
+    // Camera metadata is easily copied, so it never outweighs a signed AI declaration
+    let likelihood = if signed_generation || signed_editing {
+        ai
+    } else {
+        ai * (1.0 - weights.camera_discount * camera)
+    };
+
+    let label = if signed_generation {
+        AiLabel::ConfirmedAi
+    } else if signed_editing {
+        AiLabel::EditedWithAi
+    } else if likelihood >= weights.likely_threshold {
+        if editing > generation {
+            AiLabel::EditedWithAi
+        } else {
+            AiLabel::LikelyAi
+        }
+    } else if camera > 0.0 && camera >= likelihood {
+        AiLabel::LikelyCameraOriginal
+    } else {
+        AiLabel::NoEvidence
+    };
+
+    AiScore { likelihood, label }
+}
+
+/// Weights applied to every analysis
+#[frb(sync)]
+pub fn scoring_weights() -> ScoringWeights {
+    *SCORING_WEIGHTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Replace the weights used for every later analysis. Every value must lie in 0–1.
+#[frb(sync)]
+pub fn set_scoring_weights(weights: ScoringWeights) -> anyhow::Result<()> {
+    let values = [
+        ("definitive", weights.definitive),
+        ("strong", weights.strong),
+        ("weak", weights.weak),
+        ("camera_discount", weights.camera_discount),
+        ("likely_threshold", weights.likely_threshold),
+    ];
+    if let Some((name, value)) = values.iter().find(|(_, v)| !(0.0..=1.0).contains(v)) {
+        return Err(anyhow!(
+            "Scoring weight {} must be between 0 and 1, got {}",
+            name,
+            value
+        ));
+    }
+    *SCORING_WEIGHTS.lock().unwrap_or_else(|e| e.into_inner()) = weights;
+    Ok(())
+}
+
+/// Restore the built-in scoring weights
+#[frb(sync)]
+pub fn reset_scoring_weights() {
+    *SCORING_WEIGHTS.lock().unwrap_or_else(|e| e.into_inner()) = DEFAULT_WEIGHTS;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::actions::{actions_from_assertion, is_actions_label, ContentAction};
+    use crate::api::c2pa_reader::{
+        c2pa_evidence, exif_evidence, xmp_evidence, ExifInfo, VerificationStatus,
+    };
+    use crate::api::digital_source_type::DigitalSourceType;
+    use crate::api::xmp::XmpInfo;
+    use serde_json::Value;
+
+    /// Labelled assets; scores are pinned against the default weights
+    const CORPUS: &str = include_str!("../../fixtures/ai_scoring.json");
+
+    /// Manifests with AI keywords in hashes, URLs, titles and longer words;
+    /// none of them may raise AI evidence
+    const NON_AI: &str = include_str!("../../fixtures/non_ai_manifests.json");
+
+    fn text(value: &Value, key: &str) -> Option<String> {
+        value
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+
+    fn actions_of(manifest: &Value) -> Vec<ContentAction> {
+        let assertions = manifest["assertions"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        assertions
+            .iter()
+            .filter(|a| a["label"].as_str().is_some_and(is_actions_label))
+            .flat_map(|a| actions_from_assertion(&a["data"]))
+            .collect()
+    }
+
+    fn exif_of(exif: &Value) -> ExifInfo {
+        ExifInfo {
+            software: text(exif, "software"),
+            make: text(exif, "make"),
+            model: text(exif, "model"),
+            artist: text(exif, "artist"),
+            copyright: text(exif, "copyright"),
+            user_comment: text(exif, "user_comment"),
+            image_description: text(exif, "image_description"),
+            date_time_original: text(exif, "date_time_original"),
+            ..ExifInfo::default()
+        }
+    }
+
+    fn xmp_of(xmp: &Value) -> XmpInfo {
+        XmpInfo {
+            digital_source_type: text(xmp, "digital_source_type")
+                .map(|uri| DigitalSourceType::from_uri(&uri)),
+            credit: text(xmp, "credit"),
+            creator_tool: text(xmp, "creator_tool"),
+            creators: vec![],
+            provenance: None,
+            container: "JPEG APP1".to_string(),
+            packet: String::new(),
+        }
+    }
+
+    fn evidence_of(case: &Value) -> Vec<Evidence> {
+        let mut evidence = Vec::new();
+        if let Some(store) = case.get("manifest_store") {
+            let active = store["active_manifest"].as_str().unwrap_or_default();
+            let manifest = &store["manifests"][active];
+            let claim_generator = text(manifest, "claim_generator").unwrap_or_default();
+            // Manifests verify unless the case says otherwise
+            let status: VerificationStatus = case
+                .get("status")
+                .map(|s| serde_json::from_value(s.clone()).expect("unknown status"))
+                .unwrap_or(VerificationStatus::Verified);
+            evidence.extend(c2pa_evidence(
+                &actions_of(manifest),
+                &claim_generator,
+                Some(store),
+                &status,
+            ));
+        }
+        if let Some(exif) = case.get("exif") {
+            evidence.extend(exif_evidence(&exif_of(exif)));
+        }
+        if let Some(xmp) = case.get("xmp") {
+            evidence.extend(xmp_evidence(&xmp_of(xmp)));
+        }
+        evidence
+    }
+
+    fn label_named(name: &str) -> AiLabel {
+        serde_json::from_value(Value::String(name.to_string())).expect("unknown label")
+    }
+
+    #[test]
+    fn corpus_scores_are_pinned() {
+        let corpus: Vec<Value> = serde_json::from_str(CORPUS).expect("corpus is valid JSON");
+        assert!(!corpus.is_empty());
+        for case in &corpus {
+            let name = case["name"].as_str().unwrap();
+            let score = score_evidence(&evidence_of(case), &ScoringWeights::default());
+            let expected = case["likelihood"].as_f64().unwrap();
+            assert_eq!(
+                score.label,
+                label_named(case["label"].as_str().unwrap()),
+                "label of {}",
+                name
+            );
+            assert!(
+                (score.likelihood - expected).abs() < 1e-4,
+                "likelihood of {}: {} != {}",
+                name,
+                score.likelihood,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn corpus_covers_every_label() {
+        let corpus: Vec<Value> = serde_json::from_str(CORPUS).unwrap();
+        for label in [
+            AiLabel::ConfirmedAi,
+            AiLabel::LikelyAi,
+            AiLabel::EditedWithAi,
+            AiLabel::NoEvidence,
+            AiLabel::LikelyCameraOriginal,
+        ] {
+            assert!(
+                corpus
+                    .iter()
+                    .any(|c| label_named(c["label"].as_str().unwrap()) == label),
+                "no fixture labelled {:?}",
+                label
+            );
+        }
+    }
+
+    #[test]
+    fn non_ai_manifests_stay_clean() {
+        let corpus: Vec<Value> = serde_json::from_str(NON_AI).expect("corpus is valid JSON");
+        assert!(!corpus.is_empty());
+        for case in &corpus {
+            let name = case["name"].as_str().unwrap();
+            let evidence = evidence_of(case);
+            let ai: Vec<_> = evidence.iter().filter(|e| e.kind.is_ai()).collect();
+            assert!(ai.is_empty(), "{} raised {:?}", name, ai);
+            let label = score_evidence(&evidence, &ScoringWeights::default()).label;
+            assert!(!label.is_ai(), "{} labelled {:?}", name, label);
+        }
+    }
+
+    #[test]
+    fn weights_change_the_score() {
+        let corpus: Vec<Value> = serde_json::from_str(CORPUS).unwrap();
+        let case = corpus
+            .iter()
+            .find(|c| c["name"] == "midjourney_exif_software")
+            .unwrap();
+        let strict = ScoringWeights {
+            strong: 0.5,
+            ..ScoringWeights::default()
+        };
+        let score = score_evidence(&evidence_of(case), &strict);
+        assert!((score.likelihood - 0.5).abs() < 1e-9);
+        assert_eq!(score.label, AiLabel::NoEvidence);
+    }
+
+    #[test]
+    fn out_of_range_weights_are_rejected() {
+        let bad = ScoringWeights {
+            weak: 1.5,
+            ..ScoringWeights::default()
+        };
+        assert!(set_scoring_weights(bad).is_err());
+        assert_eq!(scoring_weights(), ScoringWeights::default());
+    }
+}
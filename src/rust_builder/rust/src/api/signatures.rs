@@ -0,0 +1,549 @@
+//! AI generator signature database.
+//!
+//! Which tools count as AI generators, which editors have AI features, the
+//! phrases their output leaves behind and how their model names and versions
+//! are written all live in one versioned data file,
+//! `signatures/ai_generators.toml`. A copy is compiled in; the
+//! app can replace it at runtime with a newer file in the same format,
+//! written as TOML or JSON, without rebuilding the native library.
+
+use anyhow::{bail, Context};
+use flutter_rust_bridge::frb;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+use super::evidence::{contains_word, find_word, EvidenceStrength};
+
+const BUILT_IN: &str = include_str!("../../signatures/ai_generators.toml");
+
+/// Newest `schema_version` this build understands
+const SCHEMA_VERSION: u32 = 1;
+
+/// Where a signature may be matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MatchField {
+    /// `softwareAgent` of a C2PA action
+    SoftwareAgent,
+    /// `claim_generator` and `claim_generator_info[].name`
+    ClaimGenerator,
+    /// `description` of a C2PA action
+    ActionDescription,
+    /// EXIF Software tag
+    ExifSoftware,
+    /// EXIF Make, Model, Artist and ImageDescription tags
+    ExifText,
+    /// EXIF UserComment tag
+    ExifComment,
+    /// XMP `xmp:CreatorTool`
+    XmpCreatorTool,
+    /// XMP `dc:creator` and `photoshop:Credit`
+    XmpText,
+}
+
+/// What a generator produces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GeneratorKind {
+    #[default]
+    Image,
+    Video,
+    Audio,
+    /// A vendor or model family used for several media types
+    General,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawDatabase {
+    schema_version: u32,
+    revision: String,
+    #[serde(default)]
+    generators: Vec<RawProduct>,
+    #[serde(default)]
+    editors: Vec<RawProduct>,
+    #[serde(default)]
+    phrases: Vec<RawPhrase>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawProduct {
+    vendor: String,
+    product: String,
+    #[serde(default)]
+    kind: GeneratorKind,
+    #[serde(default)]
+    keywords: Vec<String>,
+    #[serde(default)]
+    patterns: Vec<String>,
+    #[serde(default)]
+    fields: Vec<MatchField>,
+    #[serde(default)]
+    models: Vec<RawModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawModel {
+    pattern: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawPhrase {
+    text: String,
+    strength: EvidenceStrength,
+    #[serde(default)]
+    fields: Vec<MatchField>,
+}
+
+/// Version and size of the loaded signature database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiSignatureSummary {
+    pub schema_version: u32,
+    /// Revision of the data, e.g. "2026.10.18"
+    pub revision: String,
+    pub generator_count: u32,
+    pub editor_count: u32,
+    pub phrase_count: u32,
+    /// Whether the compiled-in database is in use
+    pub built_in: bool,
+}
+
+#[derive(Debug)]
+struct ModelPattern {
+    pattern: Regex,
+    /// Model name, with `$1` or `${name}` standing for capture groups
+    name: String,
+}
+
+/// One AI generator or editor and the ways it names itself
+#[derive(Debug)]
+pub(crate) struct Product {
+    pub vendor: String,
+    pub product: String,
+    pub kind: GeneratorKind,
+    /// Lowercase whole-word keywords
+    keywords: Vec<String>,
+    /// Matched against the lowercased text
+    patterns: Vec<Regex>,
+    /// Empty when the generator may be matched anywhere
+    fields: Vec<MatchField>,
+    models: Vec<ModelPattern>,
+}
+
+/// A model named by a piece of text
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NamedModel {
+    pub name: String,
+    /// The pattern's `version` group
+    pub version: Option<String>,
+}
+
+impl Product {
+    fn applies_to(&self, field: MatchField) -> bool {
+        self.fields.is_empty() || self.fields.contains(&field)
+    }
+
+    /// How explanations refer to the generator, e.g. "the AI image generator Adobe Firefly"
+    pub(crate) fn describe(&self) -> String {
+        let name = if self.product.starts_with(&self.vendor) {
+            self.product.clone()
+        } else {
+            format!("{} {}", self.vendor, self.product)
+        };
+        match self.kind {
+            GeneratorKind::Image => format!("the AI image generator {}", name),
+            GeneratorKind::Video => format!("the AI video generator {}", name),
+            GeneratorKind::Audio => format!("the AI audio generator {}", name),
+            GeneratorKind::General => format!("the AI provider {}", name),
+        }
+    }
+
+    /// The keyword or pattern match that names this product in lowercased
+    /// text, and the offset just past it
+    fn find(&self, lower: &str) -> Option<(String, usize)> {
+        self.keywords
+            .iter()
+            .find_map(|k| find_word(lower, k).map(|start| (k.clone(), start + k.len())))
+            .or_else(|| {
+                self.patterns
+                    .iter()
+                    .find_map(|p| p.find(lower))
+                    .map(|m| (m.as_str().to_string(), m.end()))
+            })
+    }
+
+    /// The first of this product's models that `text` names
+    fn model(&self, text: &str) -> Option<NamedModel> {
+        self.models.iter().find_map(|m| {
+            let captures = m.pattern.captures(text)?;
+            let mut name = String::new();
+            captures.expand(&m.name, &mut name);
+            // Optional groups that did not match leave gaps behind
+            Some(NamedModel {
+                name: name.split_whitespace().collect::<Vec<_>>().join(" "),
+                version: captures.name("version").map(|v| v.as_str().to_string()),
+            })
+        })
+    }
+}
+
+/// A phrase that points to AI without naming a generator
+#[derive(Debug)]
+pub(crate) struct Phrase {
+    pub text: String,
+    pub strength: EvidenceStrength,
+    fields: Vec<MatchField>,
+}
+
+/// A generator named in a piece of text
+#[derive(Debug)]
+pub(crate) struct GeneratorMatch<'a> {
+    pub generator: &'a Product,
+    /// The keyword or text that matched, lowercase
+    pub matched: String,
+}
+
+/// The product, model and version a piece of text names
+#[derive(Debug)]
+pub(crate) struct Identification<'a> {
+    pub product: &'a Product,
+    /// Whether the product is an AI generator rather than an editor
+    pub is_generator: bool,
+    pub model: Option<String>,
+    /// The model's version, or else the version written after the product name
+    pub version: Option<String>,
+}
+
+/// A version number at the start of `rest`, as in "photoshop 25.4",
+/// "firefly/1.0" or "comfyui v0.2.2"
+fn leading_version(rest: &str) -> Option<String> {
+    let rest = rest.trim_start_matches([' ', '/', '_', ':', '-']);
+    let rest = rest.strip_prefix('v').unwrap_or(rest);
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(rest.len());
+    let version = rest[..end].trim_end_matches('.');
+    let followed_by_letter = rest[end..].starts_with(char::is_alphabetic);
+    if version.starts_with(|c: char| c.is_ascii_digit()) && !followed_by_letter {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
+
+/// Compiled signature database
+#[derive(Debug)]
+pub(crate) struct SignatureDatabase {
+    schema_version: u32,
+    revision: String,
+    built_in: bool,
+    generators: Vec<Product>,
+    editors: Vec<Product>,
+    phrases: Vec<Phrase>,
+}
+
+fn compile(pattern: &str, owner: &str) -> anyhow::Result<Regex> {
+    Regex::new(pattern).with_context(|| format!("Invalid pattern for {}: {}", owner, pattern))
+}
+
+fn compile_product(raw: RawProduct) -> anyhow::Result<Product> {
+    if raw.keywords.iter().any(|k| k.trim().is_empty()) {
+        bail!("Empty keyword for {}", raw.product);
+    }
+    if raw.keywords.is_empty() && raw.patterns.is_empty() {
+        bail!("{} has neither keywords nor patterns", raw.product);
+    }
+    let patterns = raw
+        .patterns
+        .iter()
+        .map(|p| compile(p, &raw.product))
+        .collect::<anyhow::Result<_>>()?;
+    let models = raw
+        .models
+        .into_iter()
+        .map(|m| {
+            Ok(ModelPattern {
+                pattern: compile(&m.pattern, &raw.product)?,
+                name: m.name,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Product {
+        keywords: raw.keywords.iter().map(|k| k.to_lowercase()).collect(),
+        patterns,
+        fields: raw.fields,
+        models,
+        kind: raw.kind,
+        vendor: raw.vendor,
+        product: raw.product,
+    })
+}
+
+impl SignatureDatabase {
+    /// Parse and compile a database written as TOML, or as JSON if it starts with `{`
+    fn parse(text: &str, built_in: bool) -> anyhow::Result<Self> {
+        let raw: RawDatabase = if text.trim_start().starts_with('{') {
+            serde_json::from_str(text).context("Invalid JSON signature database")?
+        } else {
+            toml::from_str(text).context("Invalid TOML signature database")?
+        };
+        if raw.schema_version == 0 || raw.schema_version > SCHEMA_VERSION {
+            bail!(
+                "Unsupported signature schema version {}, expected 1 to {}",
+                raw.schema_version,
+                SCHEMA_VERSION
+            );
+        }
+
+        let generators = raw
+            .generators
+            .into_iter()
+            .map(compile_product)
+            .collect::<anyhow::Result<_>>()?;
+        let editors = raw
+            .editors
+            .into_iter()
+            .map(compile_product)
+            .collect::<anyhow::Result<_>>()?;
+
+        let mut phrases = Vec::with_capacity(raw.phrases.len());
+        for p in raw.phrases {
+            if p.text.trim().is_empty() {
+                bail!("Empty phrase in signature database");
+            }
+            phrases.push(Phrase {
+                text: p.text.to_lowercase(),
+                strength: p.strength,
+                fields: p.fields,
+            });
+        }
+
+        Ok(SignatureDatabase {
+            schema_version: raw.schema_version,
+            revision: raw.revision,
+            built_in,
+            generators,
+            editors,
+            phrases,
+        })
+    }
+
+    /// The first generator, in database order, that `text` names in `field`
+    pub(crate) fn find_generator(
+        &self,
+        text: &str,
+        field: MatchField,
+    ) -> Option<GeneratorMatch<'_>> {
+        let lower = text.to_lowercase();
+        self.generators
+            .iter()
+            .filter(|g| g.applies_to(field))
+            .find_map(|generator| {
+                let (matched, _) = generator.find(&lower)?;
+                Some(GeneratorMatch { generator, matched })
+            })
+    }
+
+    /// The first AI phrase, in database order, that `text` contains in `field`
+    pub(crate) fn find_phrase(&self, text: &str, field: MatchField) -> Option<&Phrase> {
+        let lower = text.to_lowercase();
+        self.phrases
+            .iter()
+            .filter(|p| p.fields.is_empty() || p.fields.contains(&field))
+            .find(|p| contains_word(&lower, &p.text))
+    }
+
+    /// The generator or editor a piece of text names, with its model and
+    /// version. A model alone is enough: "sd_xl_base_1.0" names Stable
+    /// Diffusion XL even though "Stable Diffusion" does not appear.
+    pub(crate) fn identify(&self, text: &str) -> Option<Identification<'_>> {
+        let lower = text.to_lowercase();
+        let products = || {
+            let generators = self.generators.iter().map(|p| (p, true));
+            generators.chain(self.editors.iter().map(|p| (p, false)))
+        };
+        let named = products().find_map(|(product, is_generator)| {
+            let (_, end) = product.find(&lower)?;
+            Some((product, is_generator, leading_version(&lower[end..])))
+        });
+        if let Some((product, is_generator, version)) = named {
+            // Prefer the product's own models, then any other's, as in "ComfyUI sdxl"
+            let model = product
+                .model(text)
+                .or_else(|| products().find_map(|(p, _)| p.model(text)));
+            return Some(Identification {
+                product,
+                is_generator,
+                version: model.as_ref().and_then(|m| m.version.clone()).or(version),
+                model: model.map(|m| m.name),
+            });
+        }
+        products().find_map(|(product, is_generator)| {
+            let model = product.model(text)?;
+            Some(Identification {
+                product,
+                is_generator,
+                model: Some(model.name),
+                version: model.version,
+            })
+        })
+    }
+
+    fn summary(&self) -> AiSignatureSummary {
+        AiSignatureSummary {
+            schema_version: self.schema_version,
+            revision: self.revision.clone(),
+            generator_count: self.generators.len() as u32,
+            editor_count: self.editors.len() as u32,
+            phrase_count: self.phrases.len() as u32,
+            built_in: self.built_in,
+        }
+    }
+}
+
+static SIGNATURES: Mutex<Option<Arc<SignatureDatabase>>> = Mutex::new(None);
+
+fn built_in_database() -> SignatureDatabase {
+    SignatureDatabase::parse(BUILT_IN, true).expect("built-in signature database is valid")
+}
+
+/// The database used by every analysis; the built-in one until another is loaded
+pub(crate) fn signature_database() -> Arc<SignatureDatabase> {
+    let mut slot = SIGNATURES.lock().unwrap_or_else(|e| e.into_inner());
+    slot.get_or_insert_with(|| Arc::new(built_in_database()))
+        .clone()
+}
+
+/// Replace the AI signature database with one in the `ai_generators.toml`
+/// format, as TOML or JSON. The current database is kept if this fails.
+#[frb(sync)]
+pub fn load_ai_signatures_from_bytes(data: Vec<u8>) -> anyhow::Result<AiSignatureSummary> {
+    let text = std::str::from_utf8(&data).context("Signature database is not valid UTF-8")?;
+    let database = SignatureDatabase::parse(text, false)?;
+    let summary = database.summary();
+    *SIGNATURES.lock().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(database));
+    Ok(summary)
+}
+
+/// Replace the AI signature database with one on disk
+#[frb(sync)]
+pub fn load_ai_signatures_from_file(file_path: String) -> anyhow::Result<AiSignatureSummary> {
+    let data = std::fs::read(&file_path)
+        .with_context(|| format!("Failed to read signature database: {}", file_path))?;
+    load_ai_signatures_from_bytes(data)
+}
+
+/// Restore the built-in AI signature database
+#[frb(sync)]
+pub fn reset_ai_signatures() {
+    *SIGNATURES.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Describe the AI signature database in use
+#[frb(sync)]
+pub fn ai_signature_summary() -> AiSignatureSummary {
+    signature_database().summary()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn built_in() -> SignatureDatabase {
+        SignatureDatabase::parse(BUILT_IN, true).unwrap()
+    }
+
+    #[test]
+    fn built_in_database_compiles() {
+        let db = built_in();
+        assert_eq!(db.schema_version, SCHEMA_VERSION);
+        assert!(!db.generators.is_empty());
+        assert!(!db.phrases.is_empty());
+    }
+
+    #[test]
+    fn every_field_sees_the_same_generators() {
+        let db = built_in();
+        for field in [
+            MatchField::SoftwareAgent,
+            MatchField::ClaimGenerator,
+            MatchField::ExifSoftware,
+            MatchField::ExifText,
+            MatchField::XmpCreatorTool,
+            MatchField::XmpText,
+        ] {
+            let found = db.find_generator("NovelAI Diffusion", field).unwrap();
+            assert_eq!(found.generator.product, "NovelAI");
+        }
+        assert!(db
+            .find_generator("Imagenomic Portraiture", MatchField::ExifSoftware)
+            .is_none());
+    }
+
+    #[test]
+    fn phrases_are_scoped_to_their_fields() {
+        let db = built_in();
+        let comment = db
+            .find_phrase("Negative prompt: blurry", MatchField::ExifComment)
+            .unwrap();
+        assert_eq!(comment.strength, EvidenceStrength::Strong);
+        assert!(db
+            .find_phrase("Negative prompt: blurry", MatchField::SoftwareAgent)
+            .is_none());
+    }
+
+    #[test]
+    fn editors_are_never_generators() {
+        let db = built_in();
+        let text = "Adobe Photoshop 25.4 (Generative Fill)";
+        assert!(db.find_generator(text, MatchField::SoftwareAgent).is_none());
+        let found = db.identify(text).unwrap();
+        assert_eq!(found.product.product, "Photoshop");
+        assert!(!found.is_generator);
+    }
+
+    #[test]
+    fn versions_follow_product_names() {
+        assert_eq!(
+            leading_version(" 25.4 (generative fill)").as_deref(),
+            Some("25.4")
+        );
+        assert_eq!(leading_version("/1.0").as_deref(), Some("1.0"));
+        assert_eq!(leading_version(" v0.2.2").as_deref(), Some("0.2.2"));
+        assert_eq!(leading_version(" 2x upscale"), None);
+        assert_eq!(leading_version(" vacation"), None);
+    }
+
+    #[test]
+    fn json_databases_are_accepted() {
+        let json = r#"{
+            "schema_version": 1,
+            "revision": "test",
+            "generators": [{ "vendor": "Acme", "product": "Painter", "keywords": ["acme painter"] }]
+        }"#;
+        let db = SignatureDatabase::parse(json, false).unwrap();
+        let found = db
+            .find_generator("Acme Painter 2", MatchField::ClaimGenerator)
+            .unwrap();
+        assert_eq!(found.generator.vendor, "Acme");
+        assert_eq!(found.generator.kind, GeneratorKind::Image);
+    }
+
+    #[test]
+    fn invalid_databases_are_rejected() {
+        let newer = "schema_version = 99\nrevision = \"x\"\n";
+        assert!(SignatureDatabase::parse(newer, false).is_err());
+
+        let bad_regex = r#"
+            schema_version = 1
+            revision = "x"
+            [[generators]]
+            vendor = "Acme"
+            product = "Painter"
+            patterns = ["(unclosed"]
+        "#;
+        assert!(SignatureDatabase::parse(bad_regex, false).is_err());
+        assert!(load_ai_signatures_from_bytes(bad_regex.as_bytes().to_vec()).is_err());
+    }
+}
@@ -0,0 +1,411 @@
+//! Compares the EXIF thumbnail with the image it previews. Editors often
+//! rewrite the image but keep the camera's thumbnail in IFD1, so a thumbnail
+//! showing something else is a sign the content was changed.
+
+use std::io::{BufReader, Cursor, Read, Seek};
+
+use image::imageops::{self, FilterType};
+use image::{GrayImage, ImageReader, Limits};
+use serde::{Deserialize, Serialize};
+
+use super::exif_metadata::ExifThumbnail;
+use super::jpeg_dc::decode_dc_luma;
+
+/// Side, in compared pixels, of the square cells whose brightness is compared
+const CELL: u32 = 8;
+
+/// A cell has changed when its mean brightness moves by more than this many
+/// standard deviations of the image's brightness
+const CELL_THRESHOLD: f64 = 0.5;
+
+/// Changed cells needed to report different content. A single cell is
+/// usually JPEG noise along a hard edge.
+const MIN_CHANGED_CELLS: usize = 2;
+
+/// Thumbnails larger than this on either side are downsampled before comparing
+const MAX_SIDE: u32 = 256;
+
+/// Rows and columns no brighter than this are letterbox bars
+const BAR_LUMA: u8 = 24;
+
+/// Brightness spread assumed for near-flat images, so noise is not amplified
+const MIN_STD: f64 = 4.0;
+
+/// Largest allocation allowed while decoding the thumbnail. EXIF thumbnails
+/// fit in a 64 KiB segment, typically 160×120 pixels.
+const MAX_THUMBNAIL_DECODE_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Largest allocation allowed while fully decoding the main image, which is
+/// only done for images a one-eighth scale JPEG decode cannot serve: a
+/// 16-megapixel RGBA PNG, or a JPEG too small to reduce
+const MAX_DECODE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// A rectangle in main-image pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// How the EXIF thumbnail compares with the image, both reduced to the
+/// thumbnail's size and to brightness normalized for exposure and contrast
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThumbnailComparison {
+    /// Size the two were compared at: the thumbnail's, less any letterbox bars
+    pub compared_width: u32,
+    pub compared_height: u32,
+    /// Mean brightness difference over all cells, in standard deviations
+    pub difference: f64,
+    /// Share of cells, 0–1, that changed
+    pub changed_fraction: f64,
+    /// Bounding box of the changed cells
+    pub changed_region: Option<ImageRegion>,
+    /// The thumbnail shows different content from the image
+    pub content_differs: bool,
+}
+
+fn decode_gray<R: Read + Seek>(reader: R, max_alloc: u64) -> Option<GrayImage> {
+    let mut reader = ImageReader::new(BufReader::new(reader))
+        .with_guessed_format()
+        .ok()?;
+    let mut limits = Limits::default();
+    limits.max_alloc = Some(max_alloc);
+    reader.limits(limits);
+    let image = reader.decode().ok()?.to_luma8();
+    (image.width() > 0 && image.height() > 0).then_some(image)
+}
+
+/// The part of `image` inside any black bars, kept only when removing the
+/// bars brings its aspect ratio closer to `aspect`, so dark scenes are not
+/// mistaken for letterboxing
+fn content_bounds(image: &GrayImage, aspect: f64) -> (u32, u32, u32, u32) {
+    let (width, height) = image.dimensions();
+    let row_is_bar = |y: u32| (0..width).all(|x| image.get_pixel(x, y).0[0] <= BAR_LUMA);
+    let mut top = 0;
+    while top < height && row_is_bar(top) {
+        top += 1;
+    }
+    let mut bottom = height;
+    while bottom > top && row_is_bar(bottom - 1) {
+        bottom -= 1;
+    }
+    let column_is_bar = |x: u32| (top..bottom).all(|y| image.get_pixel(x, y).0[0] <= BAR_LUMA);
+    let mut left = 0;
+    while left < width && column_is_bar(left) {
+        left += 1;
+    }
+    let mut right = width;
+    while right > left && column_is_bar(right - 1) {
+        right -= 1;
+    }
+
+    let full = (0, 0, width, height);
+    if bottom <= top || right <= left {
+        return full;
+    }
+    let distance = |w: u32, h: u32| (w as f64 / h as f64 / aspect).ln().abs();
+    if distance(right - left, bottom - top) < distance(width, height) {
+        (left, top, right - left, bottom - top)
+    } else {
+        full
+    }
+}
+
+/// Mean brightness of each cell, row by row, as standard scores
+fn cell_scores(image: &GrayImage) -> Vec<f64> {
+    let (width, height) = image.dimensions();
+    let count = (width * height) as f64;
+    let mean = image.pixels().map(|p| p.0[0] as f64).sum::<f64>() / count;
+    let variance = image
+        .pixels()
+        .map(|p| (p.0[0] as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    let std = variance.sqrt().max(MIN_STD);
+
+    let mut scores = Vec::new();
+    for cy in 0..height.div_ceil(CELL) {
+        for cx in 0..width.div_ceil(CELL) {
+            let xs = cx * CELL..((cx + 1) * CELL).min(width);
+            let ys = cy * CELL..((cy + 1) * CELL).min(height);
+            let cell_count = (xs.len() * ys.len()) as f64;
+            let sum: f64 = ys
+                .flat_map(|y| xs.clone().map(move |x| (x, y)))
+                .map(|(x, y)| image.get_pixel(x, y).0[0] as f64)
+                .sum();
+            scores.push((sum / cell_count - mean) / std);
+        }
+    }
+    scores
+}
+
+/// Compare `thumbnail` with `image`, which may be a reduction of an image
+/// `image_width` by `image_height` pixels
+fn compare(
+    thumbnail: &GrayImage,
+    image: &GrayImage,
+    (image_width, image_height): (u32, u32),
+) -> ThumbnailComparison {
+    let aspect = image_width as f64 / image_height as f64;
+    let (x, y, width, height) = content_bounds(thumbnail, aspect);
+    let mut thumbnail = imageops::crop_imm(thumbnail, x, y, width, height).to_image();
+    if width.max(height) > MAX_SIDE {
+        let scale = MAX_SIDE as f64 / width.max(height) as f64;
+        let (w, h) = (
+            ((width as f64 * scale).round() as u32).max(1),
+            ((height as f64 * scale).round() as u32).max(1),
+        );
+        thumbnail = imageops::resize(&thumbnail, w, h, FilterType::Triangle);
+    }
+    let (width, height) = thumbnail.dimensions();
+    let reduced = imageops::thumbnail(image, width, height);
+
+    let before = cell_scores(&thumbnail);
+    let after = cell_scores(&reduced);
+    let columns = width.div_ceil(CELL);
+    let mut total = 0.0;
+    let mut changed = 0;
+    // Changed cells' first and last column and row
+    let mut bounds: Option<(u32, u32, u32, u32)> = None;
+    for (index, (a, b)) in before.iter().zip(&after).enumerate() {
+        let delta = (a - b).abs();
+        total += delta;
+        if delta <= CELL_THRESHOLD {
+            continue;
+        }
+        changed += 1;
+        let (cx, cy) = (index as u32 % columns, index as u32 / columns);
+        bounds = Some(match bounds {
+            Some((x0, y0, x1, y1)) => (x0.min(cx), y0.min(cy), x1.max(cx), y1.max(cy)),
+            None => (cx, cy, cx, cy),
+        });
+    }
+
+    // Cells scaled back up to the main image
+    let scale_x = image_width as f64 / width as f64;
+    let scale_y = image_height as f64 / height as f64;
+    let changed_region = bounds.map(|(x0, y0, x1, y1)| {
+        let left = (x0 * CELL) as f64 * scale_x;
+        let top = (y0 * CELL) as f64 * scale_y;
+        let right = (((x1 + 1) * CELL).min(width) as f64 * scale_x).min(image_width as f64);
+        let bottom = (((y1 + 1) * CELL).min(height) as f64 * scale_y).min(image_height as f64);
+        ImageRegion {
+            x: left as u32,
+            y: top as u32,
+            width: (right - left).round() as u32,
+            height: (bottom - top).round() as u32,
+        }
+    });
+
+    ThumbnailComparison {
+        compared_width: width,
+        compared_height: height,
+        difference: total / before.len() as f64,
+        changed_fraction: changed as f64 / before.len() as f64,
+        changed_region,
+        content_differs: changed >= MIN_CHANGED_CELLS,
+    }
+}
+
+/// Decode the thumbnail and the image in `stream` and compare them. `None`
+/// when either cannot be decoded.
+pub(crate) fn compare_thumbnail<R: Read + Seek>(
+    stream: &mut R,
+    thumbnail: &ExifThumbnail,
+) -> Option<ThumbnailComparison> {
+    let small = decode_gray(Cursor::new(&thumbnail.data), MAX_THUMBNAIL_DECODE_BYTES)?;
+    // Only a thumbnail-sized reduction of the image is compared, so JPEGs are
+    // read at one eighth scale when that still covers the thumbnail
+    stream.rewind().ok()?;
+    let reduced = decode_dc_luma(&mut *stream)
+        .filter(|r| r.image.width() >= small.width() && r.image.height() >= small.height());
+    if let Some(reduced) = reduced {
+        return Some(compare(
+            &small,
+            &reduced.image,
+            (reduced.width, reduced.height),
+        ));
+    }
+    stream.rewind().ok()?;
+    let image = decode_gray(&mut *stream, MAX_DECODE_BYTES)?;
+    let size = image.dimensions();
+    Some(compare(&small, &image, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::codecs::jpeg::JpegEncoder;
+    use image::Luma;
+
+    /// A 600×400 scene with gradients, a disc and a bar, so every region
+    /// has some structure
+    fn scene() -> GrayImage {
+        GrayImage::from_fn(600, 400, |x, y| {
+            let (fx, fy) = (x as f64, y as f64);
+            let mut luma = 60.0 + fx / 600.0 * 120.0 + (fy / 40.0).sin() * 20.0;
+            if (fx - 420.0).powi(2) + (fy - 150.0).powi(2) < 70.0f64.powi(2) {
+                luma = 230.0;
+            }
+            if (100..180).contains(&x) && (220..380).contains(&y) {
+                luma = 20.0;
+            }
+            Luma([luma as u8])
+        })
+    }
+
+    /// The thumbnail a camera would write for `image`, as JPEG
+    fn camera_thumbnail(image: &GrayImage, width: u32, height: u32) -> ExifThumbnail {
+        let small = imageops::resize(image, width, height, FilterType::Triangle);
+        let mut data = Vec::new();
+        JpegEncoder::new_with_quality(&mut data, 75)
+            .encode_image(&small)
+            .unwrap();
+        ExifThumbnail {
+            mime_type: "image/jpeg".to_string(),
+            width: Some(width),
+            height: Some(height),
+            data,
+        }
+    }
+
+    fn png(image: &GrayImage) -> Cursor<Vec<u8>> {
+        let mut data = Cursor::new(Vec::new());
+        image.write_to(&mut data, image::ImageFormat::Png).unwrap();
+        data
+    }
+
+    #[test]
+    fn matching_thumbnail_passes_despite_tone_edits() {
+        let original = scene();
+        let thumbnail = camera_thumbnail(&original, 150, 100);
+
+        let result = compare_thumbnail(&mut png(&original), &thumbnail).unwrap();
+        assert!(!result.content_differs, "{:?}", result);
+        assert_eq!((result.compared_width, result.compared_height), (150, 100));
+        assert_eq!(result.changed_region, None);
+
+        // Brighter and flatter, but the same picture
+        let mut toned = original.clone();
+        for p in toned.pixels_mut() {
+            p.0[0] = (p.0[0] as f64 * 0.7 + 50.0) as u8;
+        }
+        let result = compare_thumbnail(&mut png(&toned), &thumbnail).unwrap();
+        assert!(!result.content_differs, "{:?}", result);
+    }
+
+    #[test]
+    fn flags_objects_removed_after_the_thumbnail_was_written() {
+        let original = scene();
+        let thumbnail = camera_thumbnail(&original, 150, 100);
+
+        // Paint out the dark bar with the background around it
+        let mut edited = original.clone();
+        for y in 220..380 {
+            for x in 100..180 {
+                let background = edited.get_pixel(90, y).0[0];
+                edited.put_pixel(x, y, Luma([background]));
+            }
+        }
+        let result = compare_thumbnail(&mut png(&edited), &thumbnail).unwrap();
+        assert!(result.content_differs, "{:?}", result);
+        // Within a cell, 32 image pixels, of the bar: a cell changes only
+        // once enough of it is painted over
+        let region = result.changed_region.unwrap();
+        let (left, top) = (region.x, region.y);
+        let (right, bottom) = (left + region.width, top + region.height);
+        assert!(
+            left.abs_diff(100) <= 32 && right.abs_diff(180) <= 32,
+            "{:?}",
+            region
+        );
+        assert!(
+            top.abs_diff(220) <= 32 && bottom.abs_diff(380) <= 32,
+            "{:?}",
+            region
+        );
+        assert!(result.changed_fraction < 0.2);
+
+        // A different picture altogether
+        let other = GrayImage::from_fn(600, 400, |x, y| Luma([((x * 7 + y * 3) % 256) as u8]));
+        let result = compare_thumbnail(&mut png(&other), &thumbnail).unwrap();
+        assert!(result.content_differs);
+        assert!(result.changed_fraction > 0.3, "{:?}", result);
+    }
+
+    #[test]
+    fn large_jpegs_are_compared_at_reduced_scale() {
+        let original = imageops::resize(&scene(), 1200, 800, FilterType::Triangle);
+        let thumbnail = camera_thumbnail(&original, 150, 100);
+        let jpeg = |image: &GrayImage| {
+            let mut data = Vec::new();
+            JpegEncoder::new_with_quality(&mut data, 90)
+                .encode_image(image)
+                .unwrap();
+            Cursor::new(data)
+        };
+        assert!(decode_dc_luma(jpeg(&original)).is_some());
+
+        let result = compare_thumbnail(&mut jpeg(&original), &thumbnail).unwrap();
+        assert!(!result.content_differs, "{:?}", result);
+
+        // The dark bar painted out, found in full-size pixels
+        let mut edited = original.clone();
+        for y in 440..760 {
+            for x in 200..360 {
+                let background = edited.get_pixel(180, y).0[0];
+                edited.put_pixel(x, y, Luma([background]));
+            }
+        }
+        let result = compare_thumbnail(&mut jpeg(&edited), &thumbnail).unwrap();
+        assert!(result.content_differs, "{:?}", result);
+        let region = result.changed_region.unwrap();
+        assert!(region.x.abs_diff(200) <= 64 && region.y.abs_diff(440) <= 64);
+        assert!(
+            (region.x + region.width).abs_diff(360) <= 64,
+            "{:?}",
+            region
+        );
+        assert!(
+            (region.y + region.height).abs_diff(760) <= 64,
+            "{:?}",
+            region
+        );
+    }
+
+    #[test]
+    fn ignores_letterbox_bars() {
+        let original = scene();
+        // 160×120 with the 3:2 frame in the middle, as many cameras write it
+        let small = imageops::resize(&original, 160, 107, FilterType::Triangle);
+        let mut boxed = GrayImage::new(160, 120);
+        imageops::replace(&mut boxed, &small, 0, 6);
+        let mut data = Vec::new();
+        JpegEncoder::new_with_quality(&mut data, 75)
+            .encode_image(&boxed)
+            .unwrap();
+        let thumbnail = ExifThumbnail {
+            mime_type: "image/jpeg".to_string(),
+            width: Some(160),
+            height: Some(120),
+            data,
+        };
+
+        let result = compare_thumbnail(&mut png(&original), &thumbnail).unwrap();
+        assert_eq!((result.compared_width, result.compared_height), (160, 107));
+        assert!(!result.content_differs, "{:?}", result);
+    }
+
+    #[test]
+    fn undecodable_thumbnails_are_skipped() {
+        let thumbnail = ExifThumbnail {
+            mime_type: "image/jpeg".to_string(),
+            width: None,
+            height: None,
+            data: vec![0xff, 0xd8, 0xff, 0xd9],
+        };
+        assert_eq!(compare_thumbnail(&mut png(&scene()), &thumbnail), None);
+    }
+}
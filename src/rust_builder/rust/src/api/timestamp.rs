@@ -0,0 +1,640 @@
+use serde::{Deserialize, Serialize};
+use serde_cbor::Value;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use x509_parser::der_parser::asn1_rs::{BitString, Oid};
+use x509_parser::oid_registry::{
+    OID_PKCS1_SHA256WITHRSA, OID_PKCS1_SHA384WITHRSA, OID_PKCS1_SHA512WITHRSA,
+    OID_SIG_ECDSA_WITH_SHA256, OID_SIG_ECDSA_WITH_SHA384, OID_SIG_ED25519,
+};
+use x509_parser::prelude::*;
+
+use super::certificate::{
+    chains_to_anchor, parse_certificate_der, to_distinguished_name, CertificateInfo,
+};
+use super::cose;
+use super::der::{
+    self, context, expect_tlv, read_tlv, Tlv, TAG_OCTET_STRING, TAG_SEQUENCE, TAG_SET,
+};
+
+const OID_SIGNED_DATA: &str = "1.2.840.113549.1.7.2";
+const OID_TST_INFO: &str = "1.2.840.113549.1.9.16.1.4";
+const OID_MESSAGE_DIGEST: &str = "1.2.840.113549.1.9.4";
+
+/// RFC 3161 time-stamp token embedded in a claim signature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimestampInfo {
+    /// TSA name from the token, or the TSA certificate subject when absent
+    pub tsa_name: Option<String>,
+    pub tsa_certificate: Option<CertificateInfo>,
+    /// Time asserted by the TSA, seconds since the Unix epoch
+    pub gen_time: i64,
+    /// Hash algorithm of the message imprint, e.g. "SHA-256"
+    pub hash_algorithm: String,
+    pub message_imprint: String,
+    pub policy: Option<String>,
+    pub serial_number: String,
+    /// The TSA's CMS signature over the token verifies
+    pub signature_valid: bool,
+    /// The TSA certificate chains to a configured TSA trust anchor
+    pub trusted: bool,
+    /// Whether the signer certificate's validity was checked at `gen_time`
+    /// rather than now. Only a valid, trusted token can stand in for signing time.
+    pub signer_validity_evaluated_at_tsa_time: bool,
+    pub signer_valid_at_gen_time: Option<bool>,
+}
+
+/// The parts of a SignedData signerInfo needed for verification
+struct CmsSigner<'a> {
+    serial: Option<&'a [u8]>,
+    digest_oid: String,
+    signed_attrs: Option<Tlv<'a>>,
+    signature_oid: String,
+    signature: &'a [u8],
+}
+
+struct TimestampToken<'a> {
+    tst_info: &'a [u8],
+    certificates: Vec<&'a [u8]>,
+    signer: CmsSigner<'a>,
+}
+
+fn hash_algorithm_name(oid: &str) -> String {
+    match oid {
+        "1.3.14.3.2.26" => "SHA-1".to_string(),
+        "2.16.840.1.101.3.4.2.1" => "SHA-256".to_string(),
+        "2.16.840.1.101.3.4.2.2" => "SHA-384".to_string(),
+        "2.16.840.1.101.3.4.2.3" => "SHA-512".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn digest(oid: &str, data: &[u8]) -> Option<Vec<u8>> {
+    match oid {
+        "2.16.840.1.101.3.4.2.1" => Some(Sha256::digest(data).to_vec()),
+        "2.16.840.1.101.3.4.2.2" => Some(Sha384::digest(data).to_vec()),
+        "2.16.840.1.101.3.4.2.3" => Some(Sha512::digest(data).to_vec()),
+        _ => None,
+    }
+}
+
+/// Signature algorithm to verify with, given the signerInfo's digest and
+/// signature algorithms. CMS commonly names only the key type here.
+fn verification_algorithm(digest_oid: &str, signature_oid: &str) -> Option<Oid<'static>> {
+    let rsa = signature_oid.starts_with("1.2.840.113549.1.1.");
+    let ecdsa =
+        signature_oid == "1.2.840.10045.2.1" || signature_oid.starts_with("1.2.840.10045.4.3.");
+    if signature_oid == "1.3.101.112" {
+        return Some(OID_SIG_ED25519);
+    }
+    match (digest_oid, rsa, ecdsa) {
+        ("2.16.840.1.101.3.4.2.1", true, _) => Some(OID_PKCS1_SHA256WITHRSA),
+        ("2.16.840.1.101.3.4.2.2", true, _) => Some(OID_PKCS1_SHA384WITHRSA),
+        ("2.16.840.1.101.3.4.2.3", true, _) => Some(OID_PKCS1_SHA512WITHRSA),
+        ("2.16.840.1.101.3.4.2.1", _, true) => Some(OID_SIG_ECDSA_WITH_SHA256),
+        ("2.16.840.1.101.3.4.2.2", _, true) => Some(OID_SIG_ECDSA_WITH_SHA384),
+        _ => None,
+    }
+}
+
+/// Time-stamp tokens from a COSE_Sign1 claim signature's `sigTst` / `sigTst2`
+/// headers, with the header each was found in
+fn timestamp_tokens(cose: &[u8]) -> Vec<(&'static str, Vec<u8>)> {
+    let mut tokens = Vec::new();
+    for header in cose::headers(cose) {
+        for key in ["sigTst2", "sigTst"] {
+            let Some(Value::Map(container)) = cose::get(&header, key) else {
+                continue;
+            };
+            let Some(Value::Array(entries)) = cose::get(container, "tstTokens") else {
+                continue;
+            };
+            for entry in entries {
+                if let Value::Map(entry) = entry {
+                    if let Some(Value::Bytes(token)) = cose::get(entry, "val") {
+                        tokens.push((key, token.clone()));
+                    }
+                }
+            }
+        }
+    }
+    tokens
+}
+
+fn parse_signer<'a>(signer: &Tlv<'a>) -> Option<CmsSigner<'a>> {
+    let fields = signer.children()?;
+    // version, sid, digestAlgorithm, [0] signedAttrs?, signatureAlgorithm, signature
+    let sid = fields.get(1)?;
+    let serial = if sid.tag == TAG_SEQUENCE {
+        sid.children()?.get(1).map(|s| s.content)
+    } else {
+        None
+    };
+    let digest_oid = fields.get(2)?.children()?.first()?.as_oid()?;
+    let mut index = 3;
+    let signed_attrs = match fields.get(index) {
+        Some(attrs) if attrs.tag == context(0) => {
+            index += 1;
+            Some(*attrs)
+        }
+        _ => None,
+    };
+    let signature_oid = fields.get(index)?.children()?.first()?.as_oid()?;
+    let signature = fields
+        .get(index + 1)
+        .filter(|s| s.tag == TAG_OCTET_STRING)?;
+    Some(CmsSigner {
+        serial,
+        digest_oid,
+        signed_attrs,
+        signature_oid,
+        signature: signature.content,
+    })
+}
+
+fn parse_token(token: &[u8]) -> Option<TimestampToken<'_>> {
+    let (content_info, _) = expect_tlv(token, TAG_SEQUENCE)?;
+    let content_info = content_info.children()?;
+    if content_info.first()?.as_oid()?.as_str() != OID_SIGNED_DATA {
+        return None;
+    }
+    let signed_data = content_info.get(1)?.inner()?.children()?;
+
+    // version, digestAlgorithms, encapContentInfo, [0] certificates?, [1] crls?, signerInfos
+    let encap = signed_data.get(2)?.children()?;
+    if encap.first()?.as_oid()?.as_str() != OID_TST_INFO {
+        return None;
+    }
+    let tst_info = encap.get(1)?.inner()?;
+    if tst_info.tag != TAG_OCTET_STRING {
+        return None;
+    }
+
+    let certificates = signed_data
+        .iter()
+        .find(|f| f.tag == context(0))
+        .and_then(|c| c.children())
+        .map(|certs| certs.iter().map(|c| c.raw).collect())
+        .unwrap_or_default();
+
+    let signer_infos = signed_data.last().filter(|s| s.tag == TAG_SET)?;
+    let signer = parse_signer(&signer_infos.children()?.into_iter().next()?)?;
+
+    Some(TimestampToken {
+        tst_info: tst_info.content,
+        certificates,
+        signer,
+    })
+}
+
+/// Check the message digest attribute and the TSA's signature over the token
+fn verify_token_signature(token: &TimestampToken<'_>, tsa_cert: &X509Certificate<'_>) -> bool {
+    let signer = &token.signer;
+    let signed_data = match &signer.signed_attrs {
+        Some(attrs) => {
+            let Some(expected) = digest(&signer.digest_oid, token.tst_info) else {
+                return false;
+            };
+            let digest_matches = attrs.children().unwrap_or_default().iter().any(|attr| {
+                let Some(parts) = attr.children() else {
+                    return false;
+                };
+                parts.first().and_then(|o| o.as_oid()).as_deref() == Some(OID_MESSAGE_DIGEST)
+                    && parts
+                        .get(1)
+                        .and_then(|set| set.inner())
+                        .is_some_and(|v| v.content == expected.as_slice())
+            });
+            if !digest_matches {
+                return false;
+            }
+            // Signed attributes are signed as an explicit SET OF, not the implicit [0]
+            let mut encoded = attrs.raw.to_vec();
+            encoded[0] = TAG_SET;
+            encoded
+        }
+        None => token.tst_info.to_vec(),
+    };
+
+    let Some(algorithm) = verification_algorithm(&signer.digest_oid, &signer.signature_oid) else {
+        return false;
+    };
+    x509_parser::verify::verify_signature(
+        tsa_cert.public_key(),
+        &AlgorithmIdentifier::new(algorithm, None),
+        &BitString::new(0, signer.signature),
+        &signed_data,
+    )
+    .is_ok()
+}
+
+/// Parse and verify the first time-stamp token in a claim signature. `claim`
+/// is the signature's detached payload, the manifest's claim.
+pub(crate) fn timestamp_from_cose(
+    cose: &[u8],
+    claim: Option<&[u8]>,
+    signer_leaf: Option<&CertificateInfo>,
+    tsa_anchors: &[Vec<u8>],
+) -> Option<TimestampInfo> {
+    let (header, raw_token) = timestamp_tokens(cose).into_iter().next()?;
+    let token = parse_token(&raw_token)?;
+
+    // TSTInfo: version, policy, messageImprint, serialNumber, genTime, accuracy?,
+    // ordering?, nonce?, [0] tsa?, [1] extensions?
+    let (tst_info, _) = expect_tlv(token.tst_info, TAG_SEQUENCE)?;
+    let fields = tst_info.children()?;
+    let policy = fields.get(1).and_then(|p| p.as_oid());
+    let imprint = fields.get(2)?.children()?;
+    let hash_oid = imprint.first()?.children()?.first()?.as_oid()?;
+    let imprint_digest = imprint.get(1)?.content;
+    // A token is only evidence of signing time when it time-stamps this claim
+    // signature; one lifted from another manifest must not stand in for it.
+    // Tokens countersign the COSE structure: v1 (`sigTst`) over its payload,
+    // the claim, and v2 (`sigTst2`) over the signature itself.
+    let parts = cose::sign1_parts(cose)?;
+    let payload = if header == "sigTst2" {
+        parts.signature
+    } else {
+        parts.payload.or_else(|| claim.map(<[u8]>::to_vec))?
+    };
+    let bound = [false, true].into_iter().any(|with_sign_protected| {
+        let data = cose::countersignature_data(&parts.protected, &payload, with_sign_protected);
+        digest(&hash_oid, &data).as_deref() == Some(imprint_digest)
+    });
+    if !bound {
+        return None;
+    }
+    let message_imprint = der::hex(imprint_digest);
+    let serial_number = der::hex(fields.get(3)?.content);
+    let gen_time = fields.get(4)?.as_time()?;
+
+    // The TSA field is a GeneralName; only directoryName ([4]) is meaningful here
+    let tsa_field_name = fields
+        .iter()
+        .skip(5)
+        .find(|f| f.tag == context(0))
+        .and_then(|f| f.inner())
+        .filter(|general_name| general_name.tag == context(4))
+        .and_then(|general_name| read_tlv(general_name.content))
+        .and_then(|(name, _)| X509Name::from_der(name.raw).ok())
+        .map(|(_, name)| to_distinguished_name(&name).rfc4514);
+
+    // The signing certificate is the one whose serial the signerInfo names,
+    // falling back to the first certificate for tokens identified by key id
+    let parsed_certs: Vec<(&[u8], X509Certificate)> = token
+        .certificates
+        .iter()
+        .filter_map(|der| X509Certificate::from_der(der).ok().map(|(_, c)| (*der, c)))
+        .collect();
+    let tsa = parsed_certs
+        .iter()
+        .find(|(_, c)| Some(c.raw_serial()) == token.signer.serial)
+        .or_else(|| parsed_certs.first());
+
+    let signature_valid = tsa.is_some_and(|(_, cert)| verify_token_signature(&token, cert));
+    let trusted = signature_valid
+        && tsa.is_some_and(|(der, _)| {
+            let intermediates: Vec<&[u8]> = token.certificates.clone();
+            chains_to_anchor(der, &intermediates, tsa_anchors, gen_time)
+        });
+
+    let tsa_certificate = tsa.and_then(|(der, _)| parse_certificate_der(der));
+    let tsa_name =
+        tsa_field_name.or_else(|| tsa_certificate.as_ref().map(|c| c.subject.rfc4514.clone()));
+
+    let signer_validity_evaluated_at_tsa_time = signature_valid && trusted;
+    let signer_valid_at_gen_time =
+        signer_leaf.map(|leaf| leaf.not_before <= gen_time && gen_time <= leaf.not_after);
+
+    Some(TimestampInfo {
+        tsa_name,
+        tsa_certificate,
+        gen_time,
+        hash_algorithm: hash_algorithm_name(&hash_oid),
+        message_imprint,
+        policy,
+        serial_number,
+        signature_valid,
+        trusted,
+        signer_validity_evaluated_at_tsa_time,
+        signer_valid_at_gen_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::certificate::tests::{
+        ed25519_algorithm, key, name, TestCert, OID_ED25519, OID_TIME_STAMPING,
+    };
+    use crate::api::cose::tests::{sign1, text};
+    use crate::api::cose::Sign1Parts;
+    use crate::api::der::tests::{oid, seq, tlv};
+    use crate::api::der::{TAG_GENERALIZED_TIME, TAG_INTEGER};
+    use crate::api::jumbf::claim_signatures;
+    use ring::signature::Ed25519KeyPair;
+    use std::collections::BTreeMap;
+
+    const SHA256: &str = "2.16.840.1.101.3.4.2.1";
+    // 2024-02-29T12:34:56Z
+    const GEN_TIME: i64 = 1709210096;
+
+    struct Tsa {
+        key: Ed25519KeyPair,
+        certificate: Vec<u8>,
+        anchors: Vec<Vec<u8>>,
+    }
+
+    fn tsa() -> Tsa {
+        let root_key = key(10);
+        let mut root = TestCert::new("Test TSA Root", "Test TSA Root");
+        root.ca = true;
+        let mut leaf = TestCert::new("Test TSA", "Test TSA Root");
+        leaf.serial = 7;
+        leaf.ekus = &[OID_TIME_STAMPING];
+        let tsa_key = key(11);
+        Tsa {
+            certificate: leaf.sign(&tsa_key, &root_key),
+            anchors: vec![root.sign(&root_key, &root_key)],
+            key: tsa_key,
+        }
+    }
+
+    fn tst_info(hash_oid: &str, imprint: &[u8]) -> Vec<u8> {
+        seq(&[
+            tlv(TAG_INTEGER, &[1]),
+            oid("1.2.3.4"),
+            seq(&[seq(&[oid(hash_oid)]), tlv(TAG_OCTET_STRING, imprint)]),
+            tlv(TAG_INTEGER, &[0x01, 0x02]),
+            tlv(TAG_GENERALIZED_TIME, b"20240229123456Z"),
+            tlv(context(0), &tlv(context(4), &name("Named TSA"))),
+        ])
+    }
+
+    /// A SignedData time-stamp token over `tst_info`, signed through signed attributes
+    fn token(tsa: &Tsa, tst_info: &[u8], signed_content: &[u8]) -> Vec<u8> {
+        let message_digest = seq(&[
+            oid(OID_MESSAGE_DIGEST),
+            tlv(
+                TAG_SET,
+                &tlv(TAG_OCTET_STRING, &Sha256::digest(signed_content)),
+            ),
+        ]);
+        let signature = tsa.key.sign(&tlv(TAG_SET, &message_digest));
+        let signer_info = seq(&[
+            tlv(TAG_INTEGER, &[1]),
+            seq(&[name("Test TSA Root"), tlv(TAG_INTEGER, &[7])]),
+            seq(&[oid(SHA256)]),
+            tlv(context(0), &message_digest),
+            ed25519_algorithm(),
+            tlv(TAG_OCTET_STRING, signature.as_ref()),
+        ]);
+        let signed_data = seq(&[
+            tlv(TAG_INTEGER, &[3]),
+            tlv(TAG_SET, &seq(&[oid(SHA256)])),
+            seq(&[
+                oid(OID_TST_INFO),
+                tlv(context(0), &tlv(TAG_OCTET_STRING, tst_info)),
+            ]),
+            tlv(context(0), &tsa.certificate),
+            tlv(TAG_SET, &signer_info),
+        ]);
+        seq(&[oid(OID_SIGNED_DATA), tlv(context(0), &signed_data)])
+    }
+
+    /// The active manifest's claim and the parts of its claim signature, from
+    /// the manifest store of an image generated by ChatGPT
+    fn chatgpt_claim() -> (Vec<u8>, Sign1Parts) {
+        let store = include_bytes!("../../fixtures/chatgpt_manifest_store.c2pa");
+        let (_, signature) = claim_signatures(store)
+            .into_iter()
+            .find(|(label, _)| label == "urn:c2pa:f4de21f9-a905-4943-815c-7f6cebd1c7d3")
+            .unwrap();
+        let parts = cose::sign1_parts(&signature.cose).unwrap();
+        (signature.claim.unwrap(), parts)
+    }
+
+    /// The real claim signature with `token` in its unprotected `header`, and
+    /// `payload` attached when given
+    fn claim_signature_with_payload(
+        header: &str,
+        token: Vec<u8>,
+        payload: Option<&[u8]>,
+    ) -> Vec<u8> {
+        let (_, parts) = chatgpt_claim();
+        let entry = Value::Map(BTreeMap::from([(text("val"), Value::Bytes(token))]));
+        let container = Value::Map(BTreeMap::from([(
+            text("tstTokens"),
+            Value::Array(vec![entry]),
+        )]));
+        serde_cbor::to_vec(&Value::Array(vec![
+            Value::Bytes(parts.protected),
+            Value::Map(BTreeMap::from([(text(header), container)])),
+            payload.map_or(Value::Null, |p| Value::Bytes(p.to_vec())),
+            Value::Bytes(parts.signature),
+        ]))
+        .unwrap()
+    }
+
+    fn claim_signature_with(header: &str, token: Vec<u8>) -> Vec<u8> {
+        claim_signature_with_payload(header, token, None)
+    }
+
+    /// SHA-256 of the countersignature structure over `payload`
+    fn imprint_over(payload: &[u8], with_sign_protected: bool) -> Vec<u8> {
+        let (_, parts) = chatgpt_claim();
+        let data = cose::countersignature_data(&parts.protected, payload, with_sign_protected);
+        Sha256::digest(data).to_vec()
+    }
+
+    /// The imprint a token in `header` carries for the real claim signature
+    fn imprint(header: &str) -> Vec<u8> {
+        let (claim, parts) = chatgpt_claim();
+        match header {
+            "sigTst2" => imprint_over(&parts.signature, false),
+            _ => imprint_over(&claim, false),
+        }
+    }
+
+    fn token_with_imprint(tsa: &Tsa, imprint: &[u8]) -> Vec<u8> {
+        let info = tst_info(SHA256, imprint);
+        token(tsa, &info, &info)
+    }
+
+    fn bound_token(tsa: &Tsa, header: &str) -> Vec<u8> {
+        token_with_imprint(tsa, &imprint(header))
+    }
+
+    fn signer_leaf(not_after: &str) -> CertificateInfo {
+        let mut spec = TestCert::new("Claim Signer", "Claim Root");
+        spec.not_after = not_after;
+        parse_certificate_der(&spec.sign(&key(20), &key(21))).unwrap()
+    }
+
+    #[test]
+    fn token_over_the_claim_signature_is_trusted() {
+        let tsa = tsa();
+        let (claim, _) = chatgpt_claim();
+        let cose = claim_signature_with("sigTst2", bound_token(&tsa, "sigTst2"));
+        let leaf = signer_leaf("230101000000Z");
+
+        let info = timestamp_from_cose(&cose, Some(&claim), Some(&leaf), &tsa.anchors).unwrap();
+        assert_eq!(info.gen_time, GEN_TIME);
+        assert_eq!(info.hash_algorithm, "SHA-256");
+        assert_eq!(info.message_imprint, der::hex(&imprint("sigTst2")));
+        assert_eq!(info.policy.as_deref(), Some("1.2.3.4"));
+        assert_eq!(info.serial_number, "0102");
+        assert_eq!(info.tsa_name.as_deref(), Some("CN=Named TSA"));
+        assert_eq!(
+            info.tsa_certificate.map(|c| c.subject.rfc4514).as_deref(),
+            Some("CN=Test TSA")
+        );
+        assert!(info.signature_valid);
+        assert!(info.trusted);
+        assert!(info.signer_validity_evaluated_at_tsa_time);
+        // The signer certificate had expired by the time-stamp
+        assert_eq!(info.signer_valid_at_gen_time, Some(false));
+    }
+
+    #[test]
+    fn each_header_version_countersigns_its_payload() {
+        let tsa = tsa();
+        let (claim, parts) = chatgpt_claim();
+        let bound = |header: &str, imprint: &[u8], claim: Option<&[u8]>| {
+            let cose = claim_signature_with(header, token_with_imprint(&tsa, imprint));
+            timestamp_from_cose(&cose, claim, None, &tsa.anchors).is_some()
+        };
+
+        // v1 covers the claim, v2 the claim signature; both with or without
+        // the countersigner's empty protected header in the structure
+        for with_sign_protected in [false, true] {
+            let v1 = imprint_over(&claim, with_sign_protected);
+            let v2 = imprint_over(&parts.signature, with_sign_protected);
+            assert!(bound("sigTst", &v1, Some(&claim)));
+            assert!(bound("sigTst2", &v2, Some(&claim)));
+            // The versions are not interchangeable
+            assert!(!bound("sigTst", &v2, Some(&claim)));
+            assert!(!bound("sigTst2", &v1, Some(&claim)));
+        }
+
+        // A v1 token cannot be checked without the detached claim
+        assert!(!bound("sigTst", &imprint("sigTst"), None));
+        // A hash of the bare signature is not a countersignature
+        assert!(!bound(
+            "sigTst2",
+            &Sha256::digest(&parts.signature),
+            Some(&claim)
+        ));
+
+        // An attached payload is what v1 covers
+        let token = bound_token(&tsa, "sigTst");
+        let cose = claim_signature_with_payload("sigTst", token, Some(&claim));
+        assert!(timestamp_from_cose(&cose, None, None, &tsa.anchors).is_some());
+    }
+
+    #[test]
+    fn token_for_another_claim_is_ignored() {
+        let tsa = tsa();
+        let (claim, _) = chatgpt_claim();
+        let other = token_with_imprint(&tsa, &imprint_over(b"a different claim", false));
+        let cose = claim_signature_with("sigTst", other);
+        assert!(timestamp_from_cose(&cose, Some(&claim), None, &tsa.anchors).is_none());
+
+        // The right token on a different claim
+        let cose = claim_signature_with("sigTst", bound_token(&tsa, "sigTst"));
+        assert!(
+            timestamp_from_cose(&cose, Some(b"a different claim"), None, &tsa.anchors).is_none()
+        );
+    }
+
+    #[test]
+    fn imprint_with_unsupported_hash_is_ignored() {
+        let tsa = tsa();
+        let (claim, _) = chatgpt_claim();
+        // SHA-1 imprints cannot be checked against the claim signature
+        let info = tst_info("1.3.14.3.2.26", &[0; 20]);
+        let cose = claim_signature_with("sigTst2", token(&tsa, &info, &info));
+        assert!(timestamp_from_cose(&cose, Some(&claim), None, &tsa.anchors).is_none());
+    }
+
+    #[test]
+    fn untrusted_tsa_does_not_stand_in_for_signing_time() {
+        let tsa = tsa();
+        let (claim, _) = chatgpt_claim();
+        let cose = claim_signature_with("sigTst", bound_token(&tsa, "sigTst"));
+        let leaf = signer_leaf("491231235959Z");
+
+        let info = timestamp_from_cose(&cose, Some(&claim), Some(&leaf), &[]).unwrap();
+        assert!(info.signature_valid);
+        assert!(!info.trusted);
+        assert!(!info.signer_validity_evaluated_at_tsa_time);
+        assert_eq!(info.signer_valid_at_gen_time, Some(true));
+    }
+
+    #[test]
+    fn altered_tst_info_fails_the_tsa_signature() {
+        let tsa = tsa();
+        let (claim, _) = chatgpt_claim();
+        let info = tst_info(SHA256, &imprint("sigTst2"));
+        // The signed message digest covers different content than the token carries
+        let cose = claim_signature_with("sigTst2", token(&tsa, &info, b"original content"));
+
+        let info = timestamp_from_cose(&cose, Some(&claim), None, &tsa.anchors).unwrap();
+        assert!(!info.signature_valid);
+        assert!(!info.trusted);
+        assert!(!info.signer_validity_evaluated_at_tsa_time);
+    }
+
+    #[test]
+    fn tokens_are_read_from_both_header_versions() {
+        let v1 = BTreeMap::from([(
+            text("sigTst"),
+            Value::Map(BTreeMap::from([(
+                text("tstTokens"),
+                Value::Array(vec![Value::Map(BTreeMap::from([(
+                    text("val"),
+                    Value::Bytes(b"v1".to_vec()),
+                )]))]),
+            )])),
+        )]);
+        let v2 = BTreeMap::from([(
+            text("sigTst2"),
+            Value::Map(BTreeMap::from([(
+                text("tstTokens"),
+                Value::Array(vec![
+                    Value::Map(BTreeMap::from([(
+                        text("val"),
+                        Value::Bytes(b"v2".to_vec()),
+                    )])),
+                    // Entries without a byte string value are skipped
+                    Value::Map(BTreeMap::from([(text("val"), text("v2"))])),
+                ]),
+            )])),
+        )]);
+        let cose = sign1(v2, v1, b"sig");
+        assert_eq!(
+            timestamp_tokens(&cose),
+            [("sigTst", b"v1".to_vec()), ("sigTst2", b"v2".to_vec())]
+        );
+        assert!(timestamp_tokens(&sign1(BTreeMap::new(), BTreeMap::new(), b"")).is_empty());
+    }
+
+    #[test]
+    fn cms_signature_algorithms_follow_the_digest() {
+        assert_eq!(
+            verification_algorithm(SHA256, "1.2.840.113549.1.1.1"),
+            Some(OID_PKCS1_SHA256WITHRSA)
+        );
+        assert_eq!(
+            verification_algorithm("2.16.840.1.101.3.4.2.2", "1.2.840.10045.2.1"),
+            Some(OID_SIG_ECDSA_WITH_SHA384)
+        );
+        assert_eq!(
+            verification_algorithm(SHA256, OID_ED25519),
+            Some(OID_SIG_ED25519)
+        );
+        assert_eq!(
+            verification_algorithm("1.3.14.3.2.26", "1.2.840.10045.2.1"),
+            None
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use anyhow::{anyhow, Context};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Mutex;
+use x509_parser::pem::Pem;
+use x509_parser::prelude::{CertificateRevocationList, FromDer, X509Certificate};
+
+const PEM_CERT_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_CERT_END: &str = "-----END CERTIFICATE-----";
+
+/// Trust configuration applied to every analysis after it is loaded
+#[derive(Debug, Default)]
+struct TrustStore {
+    /// Root and intermediate CAs signer chains may terminate in
+    anchors: Vec<String>,
+    /// End-entity certificates trusted directly, regardless of their issuer
+    allowed: Vec<String>,
+    /// Extended key usage OIDs a signing certificate must carry
+    eku_oids: Vec<String>,
+    /// Roots for RFC 3161 time-stamp authorities, kept separate from signer anchors
+    tsa_anchors: Vec<String>,
+    /// DER certificate revocation lists supplied for offline revocation checks
+    crls: Vec<Vec<u8>>,
+    /// DER OCSP responses cached ahead of time, used alongside stapled responses
+    ocsp_responses: Vec<Vec<u8>>,
+}
+
+static TRUST_STORE: Mutex<TrustStore> = Mutex::new(TrustStore {
+    anchors: Vec::new(),
+    allowed: Vec::new(),
+    eku_oids: Vec::new(),
+    tsa_anchors: Vec::new(),
+    crls: Vec::new(),
+    ocsp_responses: Vec::new(),
+});
+
+/// Summary of what is currently loaded in the trust store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustStoreSummary {
+    pub anchor_count: u32,
+    pub allowed_certificate_count: u32,
+    pub eku_oids: Vec<String>,
+    pub tsa_anchor_count: u32,
+    pub crl_count: u32,
+    pub ocsp_response_count: u32,
+}
+
+/// Split a PEM bundle into its individual certificate blocks
+pub(crate) fn split_pem_certificates(bundle: &str) -> Vec<String> {
+    let mut certs = Vec::new();
+    let mut rest = bundle;
+    while let Some(start) = rest.find(PEM_CERT_BEGIN) {
+        let after_begin = &rest[start..];
+        let Some(end) = after_begin.find(PEM_CERT_END) else {
+            break;
+        };
+        let block_end = end + PEM_CERT_END.len();
+        certs.push(after_begin[..block_end].to_string());
+        rest = &after_begin[block_end..];
+    }
+    certs
+}
+
+fn parse_pem_bundle(pem: &[u8]) -> anyhow::Result<Vec<String>> {
+    let text = std::str::from_utf8(pem).context("PEM bundle is not valid UTF-8")?;
+    let certs = split_pem_certificates(text);
+    if certs.is_empty() {
+        return Err(anyhow!("No PEM certificates found in bundle"));
+    }
+    for (index, cert) in certs.iter().enumerate() {
+        let der = Pem::iter_from_buffer(cert.as_bytes())
+            .next()
+            .and_then(|pem| pem.ok())
+            .with_context(|| format!("Certificate {} in bundle is not valid PEM", index + 1))?;
+        X509Certificate::from_der(&der.contents)
+            .map_err(|e| anyhow!("Certificate {} in bundle is not X.509: {}", index + 1, e))?;
+    }
+    Ok(certs)
+}
+
+/// Accept DER or PEM input, returning the DER of every block carrying `label`
+fn parse_der_or_pem(data: &[u8], label: &str) -> anyhow::Result<Vec<Vec<u8>>> {
+    if data.first() == Some(&0x30) {
+        return Ok(vec![data.to_vec()]);
+    }
+    let blocks: Vec<Vec<u8>> = Pem::iter_from_buffer(data)
+        .filter_map(|pem| pem.ok())
+        .filter(|pem| pem.label == label)
+        .map(|pem| pem.contents)
+        .collect();
+    if blocks.is_empty() {
+        return Err(anyhow!("No DER or PEM {} data found", label));
+    }
+    Ok(blocks)
+}
+
+fn pem_to_der(pems: &[String]) -> Vec<Vec<u8>> {
+    pems.iter()
+        .filter_map(|pem| Pem::iter_from_buffer(pem.as_bytes()).next()?.ok())
+        .map(|pem| pem.contents)
+        .collect()
+}
+
+fn is_valid_oid(oid: &str) -> bool {
+    let mut arcs = 0;
+    for arc in oid.split('.') {
+        if arc.is_empty() || !arc.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+        arcs += 1;
+    }
+    arcs >= 2
+}
+
+/// Joined entries, or null so the SDK falls back to its default
+fn setting_value(entries: &[String]) -> serde_json::Value {
+    if entries.is_empty() {
+        serde_json::Value::Null
+    } else {
+        json!(entries.join("\n"))
+    }
+}
+
+/// Push the trust store into the c2pa SDK settings so the validator uses it.
+/// OCSP fetching is disabled so validation never leaves the device.
+fn apply_trust_store(store: &TrustStore) -> anyhow::Result<()> {
+    // Loaded settings are merged into the current ones, so every field is
+    // written, including empty ones, or a cleared store would keep old values
+    let settings = json!({
+        "trust": {
+            "trust_anchors": setting_value(&store.anchors),
+            "allowed_list": setting_value(&store.allowed),
+            "trust_config": setting_value(&store.eku_oids),
+        },
+        "verify": {
+            "verify_trust": !store.anchors.is_empty() || !store.allowed.is_empty(),
+            "ocsp_fetch": false,
+        },
+    });
+
+    c2pa::settings::load_settings_from_str(&settings.to_string(), "json")
+        .map_err(|e| anyhow!("Failed to apply trust settings: {}", e))
+}
+
+fn with_trust_store<T>(f: impl FnOnce(&mut TrustStore) -> T) -> anyhow::Result<T> {
+    let mut store = TRUST_STORE
+        .lock()
+        .map_err(|_| anyhow!("Trust store lock poisoned"))?;
+    let result = f(&mut store);
+    apply_trust_store(&store)?;
+    Ok(result)
+}
+
+/// Add trust anchors from a PEM bundle. Returns the number of certificates added.
+#[frb(sync)]
+pub fn add_trust_anchors_from_bytes(pem: Vec<u8>) -> anyhow::Result<u32> {
+    let certs = parse_pem_bundle(&pem)?;
+    let count = certs.len() as u32;
+    with_trust_store(|store| store.anchors.extend(certs))?;
+    Ok(count)
+}
+
+/// Add trust anchors from a PEM bundle on disk, such as the C2PA conformance trust list
+#[frb(sync)]
+pub fn add_trust_anchors_from_file(file_path: String) -> anyhow::Result<u32> {
+    let pem = std::fs::read(&file_path)
+        .with_context(|| format!("Failed to read trust anchors: {}", file_path))?;
+    add_trust_anchors_from_bytes(pem)
+}
+
+/// Add end-entity certificates that are trusted directly, from a PEM bundle
+#[frb(sync)]
+pub fn add_allowed_certificates_from_bytes(pem: Vec<u8>) -> anyhow::Result<u32> {
+    let certs = parse_pem_bundle(&pem)?;
+    let count = certs.len() as u32;
+    with_trust_store(|store| store.allowed.extend(certs))?;
+    Ok(count)
+}
+
+/// Add end-entity certificates that are trusted directly, from a PEM bundle on disk
+#[frb(sync)]
+pub fn add_allowed_certificates_from_file(file_path: String) -> anyhow::Result<u32> {
+    let pem = std::fs::read(&file_path)
+        .with_context(|| format!("Failed to read allowed certificates: {}", file_path))?;
+    add_allowed_certificates_from_bytes(pem)
+}
+
+/// Add time-stamp authority trust anchors from a PEM bundle
+#[frb(sync)]
+pub fn add_tsa_trust_anchors_from_bytes(pem: Vec<u8>) -> anyhow::Result<u32> {
+    let certs = parse_pem_bundle(&pem)?;
+    let count = certs.len() as u32;
+    with_trust_store(|store| store.tsa_anchors.extend(certs))?;
+    Ok(count)
+}
+
+/// Add time-stamp authority trust anchors from a PEM bundle on disk
+#[frb(sync)]
+pub fn add_tsa_trust_anchors_from_file(file_path: String) -> anyhow::Result<u32> {
+    let pem = std::fs::read(&file_path)
+        .with_context(|| format!("Failed to read TSA trust anchors: {}", file_path))?;
+    add_tsa_trust_anchors_from_bytes(pem)
+}
+
+/// DER encodings of the configured TSA trust anchors
+pub(crate) fn tsa_trust_anchors() -> Vec<Vec<u8>> {
+    let store = TRUST_STORE.lock().unwrap_or_else(|e| e.into_inner());
+    pem_to_der(&store.tsa_anchors)
+}
+
+/// DER encodings of the configured signer trust anchors
+pub(crate) fn trust_anchors() -> Vec<Vec<u8>> {
+    let store = TRUST_STORE.lock().unwrap_or_else(|e| e.into_inner());
+    pem_to_der(&store.anchors)
+}
+
+/// Add certificate revocation lists, DER or PEM ("X509 CRL"), for offline revocation checks
+#[frb(sync)]
+pub fn add_crls_from_bytes(data: Vec<u8>) -> anyhow::Result<u32> {
+    let crls = parse_der_or_pem(&data, "X509 CRL")?;
+    for crl in &crls {
+        CertificateRevocationList::from_der(crl)
+            .map_err(|e| anyhow!("Invalid certificate revocation list: {}", e))?;
+    }
+    let count = crls.len() as u32;
+    with_trust_store(|store| store.crls.extend(crls))?;
+    Ok(count)
+}
+
+/// Add a certificate revocation list from disk
+#[frb(sync)]
+pub fn add_crls_from_file(file_path: String) -> anyhow::Result<u32> {
+    let data =
+        std::fs::read(&file_path).with_context(|| format!("Failed to read CRL: {}", file_path))?;
+    add_crls_from_bytes(data)
+}
+
+/// Add a cached DER OCSP response, e.g. one fetched before entering an offline environment
+#[frb(sync)]
+pub fn add_ocsp_response_from_bytes(der: Vec<u8>) -> anyhow::Result<()> {
+    if der.first() != Some(&0x30) {
+        return Err(anyhow!("OCSP response is not DER encoded"));
+    }
+    with_trust_store(|store| store.ocsp_responses.push(der))
+}
+
+/// Add a cached DER OCSP response from disk
+#[frb(sync)]
+pub fn add_ocsp_response_from_file(file_path: String) -> anyhow::Result<()> {
+    let der = std::fs::read(&file_path)
+        .with_context(|| format!("Failed to read OCSP response: {}", file_path))?;
+    add_ocsp_response_from_bytes(der)
+}
+
+/// Configured CRLs and cached OCSP responses, as DER
+pub(crate) fn revocation_data() -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+    let store = TRUST_STORE.lock().unwrap_or_else(|e| e.into_inner());
+    (store.crls.clone(), store.ocsp_responses.clone())
+}
+
+/// Replace the extended key usage OIDs accepted for signing certificates,
+/// e.g. "1.3.6.1.5.5.7.3.4" (emailProtection) or "1.3.6.1.5.5.7.3.36" (documentSigning)
+#[frb(sync)]
+pub fn set_trusted_ekus(oids: Vec<String>) -> anyhow::Result<()> {
+    let oids: Vec<String> = oids.iter().map(|o| o.trim().to_string()).collect();
+    if let Some(bad) = oids.iter().find(|o| !is_valid_oid(o)) {
+        return Err(anyhow!("Invalid EKU OID: {}", bad));
+    }
+    with_trust_store(|store| store.eku_oids = oids)
+}
+
+/// Remove every trust anchor, allowed certificate, TSA anchor, EKU setting and
+/// revocation data
+#[frb(sync)]
+pub fn clear_trust_store() -> anyhow::Result<()> {
+    with_trust_store(|store| *store = TrustStore::default())
+}
+
+/// Describe what is currently loaded in the trust store
+#[frb(sync)]
+pub fn trust_store_summary() -> TrustStoreSummary {
+    let store = TRUST_STORE.lock().unwrap_or_else(|e| e.into_inner());
+    TrustStoreSummary {
+        anchor_count: store.anchors.len() as u32,
+        allowed_certificate_count: store.allowed.len() as u32,
+        eku_oids: store.eku_oids.clone(),
+        tsa_anchor_count: store.tsa_anchors.len() as u32,
+        crl_count: store.crls.len() as u32,
+        ocsp_response_count: store.ocsp_responses.len() as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANCHORS: &[u8] = include_bytes!("../../fixtures/trust_anchors.pem");
+
+    /// Tests share the global trust store, so they run one at a time
+    static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock() -> std::sync::MutexGuard<'static, ()> {
+        STORE_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[test]
+    fn bundles_are_added_and_cleared() {
+        let _guard = lock();
+        clear_trust_store().unwrap();
+
+        assert_eq!(add_trust_anchors_from_bytes(ANCHORS.to_vec()).unwrap(), 2);
+        assert_eq!(
+            add_allowed_certificates_from_bytes(ANCHORS.to_vec()).unwrap(),
+            2
+        );
+        assert_eq!(
+            add_tsa_trust_anchors_from_bytes(ANCHORS[..ANCHORS.len() / 2].to_vec()).unwrap(),
+            1
+        );
+        set_trusted_ekus(vec![" 1.3.6.1.5.5.7.3.36 ".to_string()]).unwrap();
+
+        let summary = trust_store_summary();
+        assert_eq!(summary.anchor_count, 2);
+        assert_eq!(summary.allowed_certificate_count, 2);
+        assert_eq!(summary.tsa_anchor_count, 1);
+        assert_eq!(summary.eku_oids, ["1.3.6.1.5.5.7.3.36"]);
+        assert_eq!(trust_anchors().len(), 2);
+        assert_eq!(tsa_trust_anchors().len(), 1);
+
+        clear_trust_store().unwrap();
+        let summary = trust_store_summary();
+        assert_eq!(summary.anchor_count, 0);
+        assert_eq!(summary.allowed_certificate_count, 0);
+        assert_eq!(summary.tsa_anchor_count, 0);
+        assert!(summary.eku_oids.is_empty());
+        assert!(trust_anchors().is_empty());
+    }
+
+    #[test]
+    fn invalid_certificates_are_rejected() {
+        let _guard = lock();
+        clear_trust_store().unwrap();
+
+        let garbage =
+            "-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydGlmaWNhdGU=\n-----END CERTIFICATE-----\n";
+        let error = add_trust_anchors_from_bytes(garbage.as_bytes().to_vec()).unwrap_err();
+        assert!(error.to_string().contains("not X.509"), "{}", error);
+
+        let not_base64 = "-----BEGIN CERTIFICATE-----\n!!!!\n-----END CERTIFICATE-----\n";
+        assert!(add_trust_anchors_from_bytes(not_base64.as_bytes().to_vec()).is_err());
+
+        // One bad block rejects the whole bundle
+        let mut mixed = ANCHORS.to_vec();
+        mixed.extend_from_slice(garbage.as_bytes());
+        assert!(add_allowed_certificates_from_bytes(mixed).is_err());
+
+        assert!(add_trust_anchors_from_bytes(b"no certificates here".to_vec()).is_err());
+        assert!(add_trust_anchors_from_bytes(vec![0xff, 0xfe]).is_err());
+        assert!(set_trusted_ekus(vec!["1.3.6.x".to_string()]).is_err());
+
+        let summary = trust_store_summary();
+        assert_eq!(summary.anchor_count, 0);
+        assert_eq!(summary.allowed_certificate_count, 0);
+        assert!(summary.eku_oids.is_empty());
+    }
+
+    #[test]
+    fn empty_settings_are_written_explicitly() {
+        assert_eq!(setting_value(&[]), serde_json::Value::Null);
+        assert_eq!(
+            setting_value(&["a".to_string(), "b".to_string()]),
+            json!("a\nb")
+        );
+    }
+}
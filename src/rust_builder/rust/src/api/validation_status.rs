@@ -0,0 +1,624 @@
+use serde::{Deserialize, Serialize};
+
+/// Category of a C2PA validation status code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidationCategory {
+    Success,
+    Informational,
+    Failure,
+}
+
+/// Typed C2PA validation status code, covering the codes defined in the
+/// C2PA specification's validation chapter
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidationStatusCode {
+    // Success codes
+    ClaimSignatureValidated,
+    ClaimSignatureInsideValidity,
+    SigningCredentialTrusted,
+    SigningCredentialNotRevoked,
+    TimeStampTrusted,
+    TimeStampValidated,
+    AssertionHashedUriMatch,
+    AssertionDataHashMatch,
+    AssertionBmffHashMatch,
+    AssertionBoxesHashMatch,
+    AssertionCollectionHashMatch,
+    AssertionAccessible,
+    IngredientManifestValidated,
+    IngredientClaimSignatureValidated,
+
+    // Informational codes
+    AlgorithmDeprecated,
+    SigningCredentialOcspSkipped,
+    SigningCredentialOcspInaccessible,
+    TimeStampUntrusted,
+    TimeStampOutsideValidity,
+    IngredientUnknownProvenance,
+    ManifestUnknownProvenance,
+
+    // Claim failures
+    ClaimMissing,
+    ClaimMultiple,
+    ClaimHardBindingsMissing,
+    ClaimRequiredMissing,
+    ClaimCborInvalid,
+
+    // Signature failures
+    ClaimSignatureMissing,
+    ClaimSignatureMismatch,
+    ClaimSignatureOutsideValidity,
+    AlgorithmUnsupported,
+
+    // Signing credential failures
+    SigningCredentialUntrusted,
+    SigningCredentialInvalid,
+    SigningCredentialExpired,
+    SigningCredentialRevoked,
+    SigningCredentialOcspUnknown,
+
+    // Time-stamp failures
+    TimeStampMismatch,
+    TimeStampMalformed,
+
+    // Hash binding failures
+    AssertionHashedUriMismatch,
+    AssertionDataHashMismatch,
+    AssertionDataHashMalformed,
+    AssertionBmffHashMismatch,
+    AssertionBmffHashMalformed,
+    AssertionBoxesHashMismatch,
+    AssertionBoxesHashUnknownBox,
+    AssertionCollectionHashMismatch,
+    AssertionCollectionHashMalformed,
+    AssertionCollectionHashIncorrectFileCount,
+    AssertionCollectionHashInvalidUri,
+    HashedUriMissing,
+    HashedUriMismatch,
+
+    // Assertion failures
+    AssertionMissing,
+    AssertionRequiredMissing,
+    AssertionMultipleHardBindings,
+    AssertionUndeclared,
+    AssertionInaccessible,
+    AssertionNotRedacted,
+    AssertionSelfRedacted,
+    AssertionJsonInvalid,
+    AssertionCborInvalid,
+    AssertionActionIngredientMismatch,
+    AssertionActionMalformed,
+    AssertionActionRedacted,
+    AssertionActionRedactionMismatch,
+    AssertionCloudDataHardBinding,
+    AssertionCloudDataActions,
+    AssertionCloudDataLabelMismatch,
+
+    // Ingredient failures
+    IngredientHashedUriMissing,
+    IngredientHashedUriMismatch,
+    IngredientManifestMissing,
+    IngredientManifestMismatch,
+    IngredientClaimSignatureMissing,
+    IngredientClaimSignatureMismatch,
+
+    // Manifest failures
+    ManifestInaccessible,
+    ManifestMultipleParents,
+    ManifestUpdateInvalid,
+    ManifestUpdateWrongParents,
+    ManifestTimestampInvalid,
+    ManifestTimestampWrongParents,
+    ManifestCompressedInvalid,
+
+    GeneralError,
+
+    /// A code not defined by the specification version this crate knows about.
+    /// Always treated as a failure.
+    Unknown { code: String },
+}
+
+impl ValidationStatusCode {
+    pub(crate) fn from_code(code: &str) -> Self {
+        use ValidationStatusCode::*;
+        match code {
+            "claimSignature.validated" => ClaimSignatureValidated,
+            "claimSignature.insideValidity" => ClaimSignatureInsideValidity,
+            "signingCredential.trusted" => SigningCredentialTrusted,
+            "signingCredential.ocsp.notRevoked" => SigningCredentialNotRevoked,
+            "timeStamp.trusted" => TimeStampTrusted,
+            "timeStamp.validated" => TimeStampValidated,
+            "assertion.hashedURI.match" => AssertionHashedUriMatch,
+            "assertion.dataHash.match" => AssertionDataHashMatch,
+            "assertion.bmffHash.match" => AssertionBmffHashMatch,
+            "assertion.boxesHash.match" => AssertionBoxesHashMatch,
+            "assertion.collectionHash.match" => AssertionCollectionHashMatch,
+            "assertion.accessible" => AssertionAccessible,
+            "ingredient.manifest.validated" => IngredientManifestValidated,
+            "ingredient.claimSignature.validated" => IngredientClaimSignatureValidated,
+
+            "algorithm.deprecated" => AlgorithmDeprecated,
+            "signingCredential.ocsp.skipped" => SigningCredentialOcspSkipped,
+            "signingCredential.ocsp.inaccessible" => SigningCredentialOcspInaccessible,
+            "timeStamp.untrusted" => TimeStampUntrusted,
+            "timeStamp.outsideValidity" => TimeStampOutsideValidity,
+            "ingredient.unknownProvenance" => IngredientUnknownProvenance,
+            "manifest.unknownProvenance" => ManifestUnknownProvenance,
+
+            "claim.missing" => ClaimMissing,
+            "claim.multiple" => ClaimMultiple,
+            "claim.hardBindings.missing" => ClaimHardBindingsMissing,
+            "claim.required.missing" => ClaimRequiredMissing,
+            "claim.cbor.invalid" => ClaimCborInvalid,
+
+            "claimSignature.missing" => ClaimSignatureMissing,
+            "claimSignature.mismatch" => ClaimSignatureMismatch,
+            "claimSignature.outsideValidity" => ClaimSignatureOutsideValidity,
+            "algorithm.unsupported" => AlgorithmUnsupported,
+
+            "signingCredential.untrusted" => SigningCredentialUntrusted,
+            "signingCredential.invalid" => SigningCredentialInvalid,
+            "signingCredential.expired" => SigningCredentialExpired,
+            "signingCredential.revoked" | "signingCredential.ocsp.revoked" => {
+                SigningCredentialRevoked
+            }
+            "signingCredential.ocsp.unknown" => SigningCredentialOcspUnknown,
+
+            "timeStamp.mismatch" => TimeStampMismatch,
+            "timeStamp.malformed" => TimeStampMalformed,
+
+            "assertion.hashedURI.mismatch" => AssertionHashedUriMismatch,
+            "assertion.dataHash.mismatch" => AssertionDataHashMismatch,
+            "assertion.dataHash.malformed" => AssertionDataHashMalformed,
+            "assertion.bmffHash.mismatch" => AssertionBmffHashMismatch,
+            "assertion.bmffHash.malformed" => AssertionBmffHashMalformed,
+            "assertion.boxesHash.mismatch" => AssertionBoxesHashMismatch,
+            "assertion.boxesHash.unknownBox" => AssertionBoxesHashUnknownBox,
+            "assertion.collectionHash.mismatch" => AssertionCollectionHashMismatch,
+            "assertion.collectionHash.malformed" => AssertionCollectionHashMalformed,
+            "assertion.collectionHash.incorrectFileCount" => {
+                AssertionCollectionHashIncorrectFileCount
+            }
+            "assertion.collectionHash.invalidURI" => AssertionCollectionHashInvalidUri,
+            "hashedURI.missing" => HashedUriMissing,
+            "hashedURI.mismatch" => HashedUriMismatch,
+
+            "assertion.missing" => AssertionMissing,
+            "assertion.required.missing" => AssertionRequiredMissing,
+            "assertion.multipleHardBindings" => AssertionMultipleHardBindings,
+            "assertion.undeclared" => AssertionUndeclared,
+            "assertion.inaccessible" => AssertionInaccessible,
+            "assertion.notRedacted" => AssertionNotRedacted,
+            "assertion.selfRedacted" => AssertionSelfRedacted,
+            "assertion.json.invalid" => AssertionJsonInvalid,
+            "assertion.cbor.invalid" => AssertionCborInvalid,
+            "assertion.action.ingredientMismatch" => AssertionActionIngredientMismatch,
+            "assertion.action.malformed" => AssertionActionMalformed,
+            "assertion.action.redacted" => AssertionActionRedacted,
+            "assertion.action.redactionMismatch" => AssertionActionRedactionMismatch,
+            "assertion.cloud-data.hardBinding" => AssertionCloudDataHardBinding,
+            "assertion.cloud-data.actions" => AssertionCloudDataActions,
+            "assertion.cloud-data.labelMismatch" => AssertionCloudDataLabelMismatch,
+
+            "ingredient.hashedURI.missing" => IngredientHashedUriMissing,
+            "ingredient.hashedURI.mismatch" => IngredientHashedUriMismatch,
+            "ingredient.manifest.missing" => IngredientManifestMissing,
+            "ingredient.manifest.mismatch" => IngredientManifestMismatch,
+            "ingredient.claimSignature.missing" => IngredientClaimSignatureMissing,
+            "ingredient.claimSignature.mismatch" => IngredientClaimSignatureMismatch,
+
+            "manifest.inaccessible" => ManifestInaccessible,
+            "manifest.multipleParents" => ManifestMultipleParents,
+            "manifest.update.invalid" => ManifestUpdateInvalid,
+            "manifest.update.wrongParents" => ManifestUpdateWrongParents,
+            "manifest.timestamp.invalid" => ManifestTimestampInvalid,
+            "manifest.timestamp.wrongParents" => ManifestTimestampWrongParents,
+            "manifest.compressed.invalid" => ManifestCompressedInvalid,
+
+            "general.error" => GeneralError,
+
+            other => Unknown {
+                code: other.to_string(),
+            },
+        }
+    }
+
+    pub(crate) fn category(&self) -> ValidationCategory {
+        use ValidationStatusCode::*;
+        match self {
+            ClaimSignatureValidated
+            | ClaimSignatureInsideValidity
+            | SigningCredentialTrusted
+            | SigningCredentialNotRevoked
+            | TimeStampTrusted
+            | TimeStampValidated
+            | AssertionHashedUriMatch
+            | AssertionDataHashMatch
+            | AssertionBmffHashMatch
+            | AssertionBoxesHashMatch
+            | AssertionCollectionHashMatch
+            | AssertionAccessible
+            | IngredientManifestValidated
+            | IngredientClaimSignatureValidated => ValidationCategory::Success,
+
+            AlgorithmDeprecated
+            | SigningCredentialOcspSkipped
+            | SigningCredentialOcspInaccessible
+            | TimeStampUntrusted
+            | TimeStampOutsideValidity
+            | IngredientUnknownProvenance
+            | ManifestUnknownProvenance => ValidationCategory::Informational,
+
+            _ => ValidationCategory::Failure,
+        }
+    }
+
+    /// Failure codes meaning the asset bytes no longer match what was signed
+    pub(crate) fn is_hash_mismatch(&self) -> bool {
+        use ValidationStatusCode::*;
+        matches!(
+            self,
+            AssertionHashedUriMismatch
+                | AssertionDataHashMismatch
+                | AssertionBmffHashMismatch
+                | AssertionBoxesHashMismatch
+                | AssertionCollectionHashMismatch
+                | HashedUriMismatch
+                | IngredientHashedUriMismatch
+        )
+    }
+
+    /// Failure codes meaning the claim signature itself cannot be trusted
+    pub(crate) fn is_signature_failure(&self) -> bool {
+        use ValidationStatusCode::*;
+        matches!(
+            self,
+            ClaimSignatureMissing
+                | ClaimSignatureMismatch
+                | SigningCredentialInvalid
+                | AlgorithmUnsupported
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ValidationCategory::*;
+    use ValidationStatusCode::*;
+
+    /// Every code the specification defines, with its variant and category
+    const CODES: &[(&str, ValidationStatusCode, ValidationCategory)] = &[
+        ("claimSignature.validated", ClaimSignatureValidated, Success),
+        (
+            "claimSignature.insideValidity",
+            ClaimSignatureInsideValidity,
+            Success,
+        ),
+        (
+            "signingCredential.trusted",
+            SigningCredentialTrusted,
+            Success,
+        ),
+        (
+            "signingCredential.ocsp.notRevoked",
+            SigningCredentialNotRevoked,
+            Success,
+        ),
+        ("timeStamp.trusted", TimeStampTrusted, Success),
+        ("timeStamp.validated", TimeStampValidated, Success),
+        (
+            "assertion.hashedURI.match",
+            AssertionHashedUriMatch,
+            Success,
+        ),
+        ("assertion.dataHash.match", AssertionDataHashMatch, Success),
+        ("assertion.bmffHash.match", AssertionBmffHashMatch, Success),
+        (
+            "assertion.boxesHash.match",
+            AssertionBoxesHashMatch,
+            Success,
+        ),
+        (
+            "assertion.collectionHash.match",
+            AssertionCollectionHashMatch,
+            Success,
+        ),
+        ("assertion.accessible", AssertionAccessible, Success),
+        (
+            "ingredient.manifest.validated",
+            IngredientManifestValidated,
+            Success,
+        ),
+        (
+            "ingredient.claimSignature.validated",
+            IngredientClaimSignatureValidated,
+            Success,
+        ),
+        ("algorithm.deprecated", AlgorithmDeprecated, Informational),
+        (
+            "signingCredential.ocsp.skipped",
+            SigningCredentialOcspSkipped,
+            Informational,
+        ),
+        (
+            "signingCredential.ocsp.inaccessible",
+            SigningCredentialOcspInaccessible,
+            Informational,
+        ),
+        ("timeStamp.untrusted", TimeStampUntrusted, Informational),
+        (
+            "timeStamp.outsideValidity",
+            TimeStampOutsideValidity,
+            Informational,
+        ),
+        (
+            "ingredient.unknownProvenance",
+            IngredientUnknownProvenance,
+            Informational,
+        ),
+        (
+            "manifest.unknownProvenance",
+            ManifestUnknownProvenance,
+            Informational,
+        ),
+        ("claim.missing", ClaimMissing, Failure),
+        ("claim.multiple", ClaimMultiple, Failure),
+        (
+            "claim.hardBindings.missing",
+            ClaimHardBindingsMissing,
+            Failure,
+        ),
+        ("claim.required.missing", ClaimRequiredMissing, Failure),
+        ("claim.cbor.invalid", ClaimCborInvalid, Failure),
+        ("claimSignature.missing", ClaimSignatureMissing, Failure),
+        ("claimSignature.mismatch", ClaimSignatureMismatch, Failure),
+        (
+            "claimSignature.outsideValidity",
+            ClaimSignatureOutsideValidity,
+            Failure,
+        ),
+        ("algorithm.unsupported", AlgorithmUnsupported, Failure),
+        (
+            "signingCredential.untrusted",
+            SigningCredentialUntrusted,
+            Failure,
+        ),
+        (
+            "signingCredential.invalid",
+            SigningCredentialInvalid,
+            Failure,
+        ),
+        (
+            "signingCredential.expired",
+            SigningCredentialExpired,
+            Failure,
+        ),
+        (
+            "signingCredential.revoked",
+            SigningCredentialRevoked,
+            Failure,
+        ),
+        (
+            "signingCredential.ocsp.revoked",
+            SigningCredentialRevoked,
+            Failure,
+        ),
+        (
+            "signingCredential.ocsp.unknown",
+            SigningCredentialOcspUnknown,
+            Failure,
+        ),
+        ("timeStamp.mismatch", TimeStampMismatch, Failure),
+        ("timeStamp.malformed", TimeStampMalformed, Failure),
+        (
+            "assertion.hashedURI.mismatch",
+            AssertionHashedUriMismatch,
+            Failure,
+        ),
+        (
+            "assertion.dataHash.mismatch",
+            AssertionDataHashMismatch,
+            Failure,
+        ),
+        (
+            "assertion.dataHash.malformed",
+            AssertionDataHashMalformed,
+            Failure,
+        ),
+        (
+            "assertion.bmffHash.mismatch",
+            AssertionBmffHashMismatch,
+            Failure,
+        ),
+        (
+            "assertion.bmffHash.malformed",
+            AssertionBmffHashMalformed,
+            Failure,
+        ),
+        (
+            "assertion.boxesHash.mismatch",
+            AssertionBoxesHashMismatch,
+            Failure,
+        ),
+        (
+            "assertion.boxesHash.unknownBox",
+            AssertionBoxesHashUnknownBox,
+            Failure,
+        ),
+        (
+            "assertion.collectionHash.mismatch",
+            AssertionCollectionHashMismatch,
+            Failure,
+        ),
+        (
+            "assertion.collectionHash.malformed",
+            AssertionCollectionHashMalformed,
+            Failure,
+        ),
+        (
+            "assertion.collectionHash.incorrectFileCount",
+            AssertionCollectionHashIncorrectFileCount,
+            Failure,
+        ),
+        (
+            "assertion.collectionHash.invalidURI",
+            AssertionCollectionHashInvalidUri,
+            Failure,
+        ),
+        ("hashedURI.missing", HashedUriMissing, Failure),
+        ("hashedURI.mismatch", HashedUriMismatch, Failure),
+        ("assertion.missing", AssertionMissing, Failure),
+        (
+            "assertion.required.missing",
+            AssertionRequiredMissing,
+            Failure,
+        ),
+        (
+            "assertion.multipleHardBindings",
+            AssertionMultipleHardBindings,
+            Failure,
+        ),
+        ("assertion.undeclared", AssertionUndeclared, Failure),
+        ("assertion.inaccessible", AssertionInaccessible, Failure),
+        ("assertion.notRedacted", AssertionNotRedacted, Failure),
+        ("assertion.selfRedacted", AssertionSelfRedacted, Failure),
+        ("assertion.json.invalid", AssertionJsonInvalid, Failure),
+        ("assertion.cbor.invalid", AssertionCborInvalid, Failure),
+        (
+            "assertion.action.ingredientMismatch",
+            AssertionActionIngredientMismatch,
+            Failure,
+        ),
+        (
+            "assertion.action.malformed",
+            AssertionActionMalformed,
+            Failure,
+        ),
+        (
+            "assertion.action.redacted",
+            AssertionActionRedacted,
+            Failure,
+        ),
+        (
+            "assertion.action.redactionMismatch",
+            AssertionActionRedactionMismatch,
+            Failure,
+        ),
+        (
+            "assertion.cloud-data.hardBinding",
+            AssertionCloudDataHardBinding,
+            Failure,
+        ),
+        (
+            "assertion.cloud-data.actions",
+            AssertionCloudDataActions,
+            Failure,
+        ),
+        (
+            "assertion.cloud-data.labelMismatch",
+            AssertionCloudDataLabelMismatch,
+            Failure,
+        ),
+        (
+            "ingredient.hashedURI.missing",
+            IngredientHashedUriMissing,
+            Failure,
+        ),
+        (
+            "ingredient.hashedURI.mismatch",
+            IngredientHashedUriMismatch,
+            Failure,
+        ),
+        (
+            "ingredient.manifest.missing",
+            IngredientManifestMissing,
+            Failure,
+        ),
+        (
+            "ingredient.manifest.mismatch",
+            IngredientManifestMismatch,
+            Failure,
+        ),
+        (
+            "ingredient.claimSignature.missing",
+            IngredientClaimSignatureMissing,
+            Failure,
+        ),
+        (
+            "ingredient.claimSignature.mismatch",
+            IngredientClaimSignatureMismatch,
+            Failure,
+        ),
+        ("manifest.inaccessible", ManifestInaccessible, Failure),
+        ("manifest.multipleParents", ManifestMultipleParents, Failure),
+        ("manifest.update.invalid", ManifestUpdateInvalid, Failure),
+        (
+            "manifest.update.wrongParents",
+            ManifestUpdateWrongParents,
+            Failure,
+        ),
+        (
+            "manifest.timestamp.invalid",
+            ManifestTimestampInvalid,
+            Failure,
+        ),
+        (
+            "manifest.timestamp.wrongParents",
+            ManifestTimestampWrongParents,
+            Failure,
+        ),
+        (
+            "manifest.compressed.invalid",
+            ManifestCompressedInvalid,
+            Failure,
+        ),
+        ("general.error", GeneralError, Failure),
+    ];
+
+    #[test]
+    fn every_code_maps_to_its_variant_and_category() {
+        for (code, kind, category) in CODES {
+            let parsed = ValidationStatusCode::from_code(code);
+            assert_eq!(&parsed, kind, "{}", code);
+            assert_eq!(parsed.category(), *category, "{}", code);
+        }
+    }
+
+    #[test]
+    fn unknown_codes_are_failures() {
+        for code in [
+            "",
+            "claimSignature",
+            "com.example.custom",
+            "ClaimSignature.Validated",
+        ] {
+            let parsed = ValidationStatusCode::from_code(code);
+            assert_eq!(
+                parsed,
+                Unknown {
+                    code: code.to_string()
+                }
+            );
+            assert_eq!(parsed.category(), Failure);
+        }
+    }
+
+    #[test]
+    fn only_failures_are_hash_mismatches_or_signature_failures() {
+        for (code, kind, category) in CODES {
+            if kind.is_hash_mismatch() || kind.is_signature_failure() {
+                assert_eq!(*category, Failure, "{}", code);
+            }
+            assert!(
+                !(kind.is_hash_mismatch() && kind.is_signature_failure()),
+                "{}",
+                code
+            );
+        }
+        assert!(AssertionDataHashMismatch.is_hash_mismatch());
+        assert!(!AssertionDataHashMalformed.is_hash_mismatch());
+        assert!(ClaimSignatureMismatch.is_signature_failure());
+        assert!(!SigningCredentialExpired.is_signature_failure());
+    }
+}
@@ -0,0 +1,461 @@
+//! XMP packets, read from the places each container keeps them.
+//!
+//! | Container        | Location                                          |
+//! |------------------|---------------------------------------------------|
+//! | JPEG             | APP1 `http://ns.adobe.com/xap/1.0/`, plus extended XMP segments |
+//! | PNG              | iTXt `XML:com.adobe.xmp`                          |
+//! | WebP             | RIFF `XMP ` chunk                                 |
+//! | TIFF, DNG        | IFD0 XMLPacket tag (700)                          |
+//! | MP4, MOV, HEIF   | Top-level XMP `uuid` box, or `moov/udta/XMP_`     |
+
+use roxmltree::{Document, Node};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Seek, SeekFrom};
+
+use super::container::{jpeg_segments, read_bytes, tiff_tag, MAX_BLOCK};
+use super::digital_source_type::DigitalSourceType;
+use super::png::read_png_text;
+
+const JPEG_XMP_HEADER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+const JPEG_EXTENDED_XMP_HEADER: &[u8] = b"http://ns.adobe.com/xmp/extension/\0";
+const PNG_XMP_KEYWORD: &str = "XML:com.adobe.xmp";
+const TIFF_XMP_TAG: u16 = 700;
+const BMFF_XMP_UUID: [u8; 16] = [
+    0xbe, 0x7a, 0xcf, 0xcb, 0x97, 0xa9, 0x42, 0xe8, 0x9c, 0x71, 0x99, 0x94, 0x91, 0xe3, 0xaf, 0xac,
+];
+
+const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const IPTC_EXT_NS: &str = "http://iptc.org/std/Iptc4xmpExt/2008-02-29/";
+const PHOTOSHOP_NS: &str = "http://ns.adobe.com/photoshop/1.0/";
+const XMP_NS: &str = "http://ns.adobe.com/xap/1.0/";
+const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
+const DCTERMS_NS: &str = "http://purl.org/dc/terms/";
+
+/// Provenance-related XMP properties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XmpInfo {
+    /// `Iptc4xmpExt:DigitalSourceType`
+    pub digital_source_type: Option<DigitalSourceType>,
+    /// `photoshop:Credit`, e.g. "Made with Google AI"
+    pub credit: Option<String>,
+    /// `xmp:CreatorTool`
+    pub creator_tool: Option<String>,
+    /// `dc:creator`, in order
+    pub creators: Vec<String>,
+    /// `dcterms:provenance`: where the asset's remote C2PA manifest is hosted
+    pub provenance: Option<String>,
+    /// Where the packet was found, e.g. "JPEG APP1" or "PNG iTXt"
+    pub container: String,
+    /// The main XMP packet as stored
+    pub packet: String,
+}
+
+/// JPEG APP1 packets: the main packet first, then any extended XMP
+/// reassembled by offset
+fn jpeg_packets<R: Read + Seek>(stream: &mut R) -> Vec<Vec<u8>> {
+    let mut main = Vec::new();
+    // GUID and the bytes gathered so far
+    let mut extended: Vec<([u8; 32], Vec<u8>)> = Vec::new();
+    for segment in jpeg_segments(stream, 0xe1) {
+        if let Some(packet) = segment.strip_prefix(JPEG_XMP_HEADER) {
+            if main.is_empty() {
+                main.push(packet.to_vec());
+            }
+        } else if let Some(part) = segment.strip_prefix(JPEG_EXTENDED_XMP_HEADER) {
+            // GUID (32), full length (4), offset (4), then the data
+            let (Some(guid), Some(full), Some(offset)) =
+                (part.get(..32), part.get(32..36), part.get(36..40))
+            else {
+                continue;
+            };
+            let full = u32::from_be_bytes(full.try_into().unwrap_or_default()) as usize;
+            let offset = u32::from_be_bytes(offset.try_into().unwrap_or_default()) as usize;
+            if full as u64 > MAX_BLOCK {
+                continue;
+            }
+            let guid: [u8; 32] = guid.try_into().unwrap_or([0; 32]);
+            let index = match extended.iter().position(|(g, _)| *g == guid) {
+                Some(index) => index,
+                None => {
+                    extended.push((guid, vec![0; full]));
+                    extended.len() - 1
+                }
+            };
+            let data = &part[40..];
+            if let Some(target) = extended[index].1.get_mut(offset..offset + data.len()) {
+                target.copy_from_slice(data);
+            }
+        }
+    }
+    main.extend(extended.into_iter().map(|(_, data)| data));
+    main
+}
+
+/// The `XMP ` chunk of a WebP RIFF container
+fn webp_packet<R: Read + Seek>(stream: &mut R) -> Option<Vec<u8>> {
+    stream.seek(SeekFrom::Start(12)).ok()?;
+    let mut header = [0u8; 8];
+    while stream.read_exact(&mut header).is_ok() {
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as u64;
+        if &header[..4] == b"XMP " {
+            return read_bytes(stream, size);
+        }
+        // Chunks are padded to an even length
+        stream
+            .seek(SeekFrom::Current((size + (size & 1)) as i64))
+            .ok()?;
+    }
+    None
+}
+
+/// The XMP `uuid` box at the top level of an ISO BMFF file, or the `XMP_`
+/// box in `moov/udta`
+fn bmff_packet<R: Read + Seek>(stream: &mut R, start: u64, end: u64) -> Option<Vec<u8>> {
+    let mut position = start;
+    while position + 8 <= end {
+        stream.seek(SeekFrom::Start(position)).ok()?;
+        let mut header = [0u8; 8];
+        stream.read_exact(&mut header).ok()?;
+        let box_type = [header[4], header[5], header[6], header[7]];
+        let mut header_len = 8;
+        let size = match u32::from_be_bytes([header[0], header[1], header[2], header[3]]) {
+            0 => end - position,
+            1 => {
+                let mut large = [0u8; 8];
+                stream.read_exact(&mut large).ok()?;
+                header_len = 16;
+                u64::from_be_bytes(large)
+            }
+            size => size as u64,
+        };
+        if size < header_len {
+            return None;
+        }
+        let box_end = position.checked_add(size)?.min(end);
+        let body = position + header_len;
+        match &box_type {
+            b"uuid" => {
+                let mut uuid = [0u8; 16];
+                stream.read_exact(&mut uuid).ok()?;
+                if uuid == BMFF_XMP_UUID {
+                    return read_bytes(stream, box_end.saturating_sub(body + 16));
+                }
+            }
+            b"moov" | b"udta" => {
+                if let Some(packet) = bmff_packet(stream, body, box_end) {
+                    return Some(packet);
+                }
+            }
+            b"XMP_" => return read_bytes(stream, box_end - body),
+            _ => {}
+        }
+        position = box_end;
+    }
+    None
+}
+
+/// XMP packets in the stream, main packet first, and the container they
+/// were found in
+fn xmp_packets<R: Read + Seek>(stream: &mut R, mime_type: &str) -> (Vec<Vec<u8>>, &'static str) {
+    if stream.rewind().is_err() {
+        return (vec![], "");
+    }
+    match mime_type {
+        "image/jpeg" => (jpeg_packets(stream), "JPEG APP1"),
+        "image/png" => {
+            let packet = read_png_text(stream)
+                .into_iter()
+                .find(|c| c.keyword == PNG_XMP_KEYWORD)
+                .map(|c| c.text.into_bytes());
+            (packet.into_iter().collect(), "PNG iTXt")
+        }
+        "image/webp" => (webp_packet(stream).into_iter().collect(), "WebP XMP chunk"),
+        "image/tiff" | "image/x-adobe-dng" => {
+            let packet = tiff_tag(stream, TIFF_XMP_TAG);
+            (packet.into_iter().collect(), "TIFF XMLPacket")
+        }
+        "video/mp4" | "video/quicktime" | "audio/mp4" | "image/heic" | "image/heif"
+        | "image/avif" => {
+            let packet = stream
+                .seek(SeekFrom::End(0))
+                .ok()
+                .and_then(|len| bmff_packet(stream, 0, len));
+            (packet.into_iter().collect(), "ISO BMFF")
+        }
+        _ => (vec![], ""),
+    }
+}
+
+/// The text of an RDF value: a `rdf:resource` URI or the element's text
+fn rdf_text(node: Node) -> Option<String> {
+    let text = node
+        .attribute((RDF_NS, "resource"))
+        .or_else(|| node.text())?
+        .trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Every value of a property, whether written as an attribute of
+/// `rdf:Description`, a simple element, or an `rdf:Seq`/`Bag`/`Alt` list
+fn property_values(doc: &Document, namespace: &str, name: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let descriptions = doc
+        .descendants()
+        .filter(|n| n.has_tag_name((RDF_NS, "Description")));
+    for description in descriptions {
+        if let Some(value) = description.attribute((namespace, name)) {
+            values.extend((!value.trim().is_empty()).then(|| value.trim().to_string()));
+        }
+        let properties = description
+            .children()
+            .filter(|n| n.has_tag_name((namespace, name)));
+        for property in properties {
+            let list = property.children().find(|n| {
+                n.has_tag_name((RDF_NS, "Seq"))
+                    || n.has_tag_name((RDF_NS, "Bag"))
+                    || n.has_tag_name((RDF_NS, "Alt"))
+            });
+            match list {
+                Some(list) => values.extend(
+                    list.children()
+                        .filter(|n| n.has_tag_name((RDF_NS, "li")))
+                        .filter_map(rdf_text),
+                ),
+                None => values.extend(rdf_text(property)),
+            }
+        }
+    }
+    values
+}
+
+fn parse_packet(packet: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(packet);
+    // Writers pad packets with whitespace or NULs, and some prefix them
+    let start = text.find('<')?;
+    Some(
+        text[start..]
+            .trim_end_matches(['\0', ' ', '\n', '\r', '\t'])
+            .to_string(),
+    )
+}
+
+/// Provenance properties from a stream's XMP, if it has any
+pub(crate) fn read_xmp<R: Read + Seek>(stream: &mut R, mime_type: &str) -> Option<XmpInfo> {
+    let (packets, container) = xmp_packets(stream, mime_type);
+    let packets: Vec<String> = packets.iter().filter_map(|p| parse_packet(p)).collect();
+    let documents: Vec<Document> = packets
+        .iter()
+        .filter_map(|p| Document::parse(p).ok())
+        .collect();
+    if documents.is_empty() {
+        return None;
+    }
+    // Extended XMP only adds to the main packet
+    let all = |namespace: &str, name: &str| -> Vec<String> {
+        documents
+            .iter()
+            .flat_map(|doc| property_values(doc, namespace, name))
+            .collect()
+    };
+    let first = |namespace: &str, name: &str| all(namespace, name).into_iter().next();
+
+    Some(XmpInfo {
+        digital_source_type: first(IPTC_EXT_NS, "DigitalSourceType")
+            .map(|uri| DigitalSourceType::from_uri(&uri)),
+        credit: first(PHOTOSHOP_NS, "Credit"),
+        creator_tool: first(XMP_NS, "CreatorTool"),
+        creators: all(DC_NS, "creator"),
+        provenance: first(DCTERMS_NS, "provenance"),
+        container: container.to_string(),
+        packet: packets[0].clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::png::tests::png_with_text;
+    use std::io::Cursor;
+
+    const PACKET: &str = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:photoshop="http://ns.adobe.com/photoshop/1.0/"
+    xmlns:ext="http://iptc.org/std/Iptc4xmpExt/2008-02-29/"
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:dcterms="http://purl.org/dc/terms/"
+    xmp:CreatorTool="Adobe Firefly"
+    photoshop:Credit="Made with Google AI">
+   <ext:DigitalSourceType>http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia</ext:DigitalSourceType>
+   <dc:creator><rdf:Seq><rdf:li>Jane Doe</rdf:li><rdf:li>Studio X</rdf:li></rdf:Seq></dc:creator>
+   <dcterms:provenance rdf:resource="https://cai-manifests.example/abc.c2pa"/>
+  </rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
+
+    fn check(info: XmpInfo, container: &str) {
+        assert_eq!(info.container, container);
+        assert_eq!(
+            info.digital_source_type,
+            Some(DigitalSourceType::TrainedAlgorithmicMedia)
+        );
+        assert_eq!(info.credit.as_deref(), Some("Made with Google AI"));
+        assert_eq!(info.creator_tool.as_deref(), Some("Adobe Firefly"));
+        assert_eq!(info.creators, ["Jane Doe", "Studio X"]);
+        assert_eq!(
+            info.provenance.as_deref(),
+            Some("https://cai-manifests.example/abc.c2pa")
+        );
+    }
+
+    fn jpeg_segment(marker: u8, payload: &[u8]) -> Vec<u8> {
+        let mut out = vec![0xff, marker];
+        out.extend(((payload.len() + 2) as u16).to_be_bytes());
+        out.extend_from_slice(payload);
+        out
+    }
+
+    #[test]
+    fn reads_jpeg_app1() {
+        let mut jpeg = vec![0xff, 0xd8];
+        jpeg.extend(jpeg_segment(0xe0, b"JFIF\0\x01\x02"));
+        jpeg.extend(jpeg_segment(
+            0xe1,
+            &[JPEG_XMP_HEADER, PACKET.as_bytes()].concat(),
+        ));
+        jpeg.extend(jpeg_segment(0xda, &[0; 8]));
+        jpeg.extend([0xff, 0xd9]);
+        check(
+            read_xmp(&mut Cursor::new(jpeg), "image/jpeg").unwrap(),
+            "JPEG APP1",
+        );
+    }
+
+    #[test]
+    fn reads_jpeg_extended_xmp() {
+        let main = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+            <rdf:Description xmlns:xmp="http://ns.adobe.com/xap/1.0/" xmp:CreatorTool="Pixel Camera"/></rdf:RDF></x:xmpmeta>"#;
+        let extension = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+            <rdf:Description xmlns:photoshop="http://ns.adobe.com/photoshop/1.0/" photoshop:Credit="Edited with Google AI"/></rdf:RDF></x:xmpmeta>"#;
+        let (first, second) = extension.as_bytes().split_at(40);
+        let mut jpeg = vec![0xff, 0xd8];
+        jpeg.extend(jpeg_segment(
+            0xe1,
+            &[JPEG_XMP_HEADER, main.as_bytes()].concat(),
+        ));
+        for (offset, part) in [(40u32, second), (0, first)] {
+            let mut segment = JPEG_EXTENDED_XMP_HEADER.to_vec();
+            segment.extend([b'A'; 32]);
+            segment.extend((extension.len() as u32).to_be_bytes());
+            segment.extend(offset.to_be_bytes());
+            segment.extend_from_slice(part);
+            jpeg.extend(jpeg_segment(0xe1, &segment));
+        }
+        jpeg.extend([0xff, 0xd9]);
+        let info = read_xmp(&mut Cursor::new(jpeg), "image/jpeg").unwrap();
+        assert_eq!(info.creator_tool.as_deref(), Some("Pixel Camera"));
+        assert_eq!(info.credit.as_deref(), Some("Edited with Google AI"));
+    }
+
+    #[test]
+    fn reads_png_itxt() {
+        let png = png_with_text(&[(b"iTXt", PNG_XMP_KEYWORD, PACKET)]);
+        check(
+            read_xmp(&mut Cursor::new(png), "image/png").unwrap(),
+            "PNG iTXt",
+        );
+    }
+
+    #[test]
+    fn reads_webp_chunk() {
+        let mut body = b"WEBP".to_vec();
+        body.extend(b"VP8X");
+        body.extend(10u32.to_le_bytes());
+        body.extend([0; 10]);
+        body.extend(b"XMP ");
+        body.extend((PACKET.len() as u32).to_le_bytes());
+        body.extend(PACKET.as_bytes());
+        let mut webp = b"RIFF".to_vec();
+        webp.extend((body.len() as u32).to_le_bytes());
+        webp.extend(body);
+        check(
+            read_xmp(&mut Cursor::new(webp), "image/webp").unwrap(),
+            "WebP XMP chunk",
+        );
+    }
+
+    #[test]
+    fn reads_tiff_xml_packet() {
+        for little_endian in [true, false] {
+            let u16b = |v: u16| {
+                if little_endian {
+                    v.to_le_bytes()
+                } else {
+                    v.to_be_bytes()
+                }
+            };
+            let u32b = |v: u32| {
+                if little_endian {
+                    v.to_le_bytes()
+                } else {
+                    v.to_be_bytes()
+                }
+            };
+            let mut tiff = if little_endian {
+                b"II*\0".to_vec()
+            } else {
+                b"MM\0*".to_vec()
+            };
+            tiff.extend(u32b(8));
+            tiff.extend(u16b(1));
+            tiff.extend(u16b(TIFF_XMP_TAG));
+            tiff.extend(u16b(7));
+            tiff.extend(u32b(PACKET.len() as u32));
+            tiff.extend(u32b(8 + 2 + 12 + 4));
+            tiff.extend(u32b(0));
+            tiff.extend(PACKET.as_bytes());
+            check(
+                read_xmp(&mut Cursor::new(tiff), "image/tiff").unwrap(),
+                "TIFF XMLPacket",
+            );
+        }
+    }
+
+    fn bmff_box(box_type: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut out = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend(box_type);
+        out.extend_from_slice(body);
+        out
+    }
+
+    #[test]
+    fn reads_bmff_boxes() {
+        let ftyp = bmff_box(b"ftyp", b"isom\0\0\0\0isom");
+        let uuid = bmff_box(b"uuid", &[&BMFF_XMP_UUID[..], PACKET.as_bytes()].concat());
+        let mp4 = [ftyp.clone(), bmff_box(b"mdat", &[0; 32]), uuid].concat();
+        check(
+            read_xmp(&mut Cursor::new(mp4), "video/mp4").unwrap(),
+            "ISO BMFF",
+        );
+
+        let udta = bmff_box(b"udta", &bmff_box(b"XMP_", PACKET.as_bytes()));
+        let moov = bmff_box(b"moov", &[bmff_box(b"mvhd", &[0; 100]), udta].concat());
+        let mov = [ftyp, moov].concat();
+        check(
+            read_xmp(&mut Cursor::new(mov), "video/quicktime").unwrap(),
+            "ISO BMFF",
+        );
+    }
+
+    #[test]
+    fn ignores_missing_and_malformed_xmp() {
+        let png = png_with_text(&[(b"tEXt", "Comment", "hello")]);
+        assert!(read_xmp(&mut Cursor::new(png), "image/png").is_none());
+        let png = png_with_text(&[(b"iTXt", PNG_XMP_KEYWORD, "<x:xmpmeta><unclosed")]);
+        assert!(read_xmp(&mut Cursor::new(png), "image/png").is_none());
+        assert!(read_xmp(&mut Cursor::new(b"GIF89a".to_vec()), "image/gif").is_none());
+    }
+}
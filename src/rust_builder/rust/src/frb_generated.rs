@@ -25,6 +25,7 @@
 
 // Section: imports
 
+use crate::api::input::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -37,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 102767085;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1394073100;
 
 // Section: executor
 
@@ -78,6 +79,70 @@ fn wire__crate__api__c2pa_reader__analyze_c2pa_from_bytes_impl(
         },
     )
 }
+fn wire__crate__api__c2pa_reader__analyze_c2pa_from_chunks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "analyze_c2pa_from_chunks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_asset = <ChunkedAsset>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::c2pa_reader::analyze_c2pa_from_chunks(api_asset),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__c2pa_reader__analyze_c2pa_from_mapped_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "analyze_c2pa_from_mapped_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::c2pa_reader::analyze_c2pa_from_mapped_file(api_file_path),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__c2pa_reader__analyze_c2pa_from_path_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -168,437 +233,6490 @@ fn wire__crate__api__c2pa_reader__is_c2pa_available_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for crate::api::c2pa_reader::AiInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_isAiGenerated = <bool>::sse_decode(deserializer);
-        let mut var_generatorName = <Option<String>>::sse_decode(deserializer);
-        let mut var_modelName = <Option<String>>::sse_decode(deserializer);
-        let mut var_detectionSource = <Option<String>>::sse_decode(deserializer);
-        return crate::api::c2pa_reader::AiInfo {
-            is_ai_generated: var_isAiGenerated,
-            generator_name: var_generatorName,
-            model_name: var_modelName,
-            detection_source: var_detectionSource,
-        };
-    }
+fn wire__crate__api__distinguished_name__parse_distinguished_name_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_distinguished_name",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dn = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::distinguished_name::parse_distinguished_name(api_dn),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__input__ChunkedAsset_append_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ChunkedAsset_append",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>,
+            >>::sse_decode(&mut deserializer);
+            let api_chunk = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::input::ChunkedAsset::append(&mut *api_that_guard, api_chunk);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for crate::api::c2pa_reader::C2paAnalysisResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_status =
-            <crate::api::c2pa_reader::VerificationStatus>::sse_decode(deserializer);
-        let mut var_signer =
-            <Option<crate::api::c2pa_reader::SignerInfo>>::sse_decode(deserializer);
-        let mut var_actions =
-            <Vec<crate::api::c2pa_reader::ContentAction>>::sse_decode(deserializer);
-        let mut var_aiInfo = <Option<crate::api::c2pa_reader::AiInfo>>::sse_decode(deserializer);
-        let mut var_exifInfo =
-            <Option<crate::api::c2pa_reader::ExifInfo>>::sse_decode(deserializer);
-        let mut var_claimGenerator = <Option<String>>::sse_decode(deserializer);
-        let mut var_title = <Option<String>>::sse_decode(deserializer);
-        let mut var_format = <Option<String>>::sse_decode(deserializer);
-        let mut var_instanceId = <Option<String>>::sse_decode(deserializer);
-        let mut var_rawManifestJson = <Option<String>>::sse_decode(deserializer);
-        return crate::api::c2pa_reader::C2paAnalysisResult {
-            status: var_status,
-            signer: var_signer,
-            actions: var_actions,
-            ai_info: var_aiInfo,
-            exif_info: var_exifInfo,
-            claim_generator: var_claimGenerator,
-            title: var_title,
-            format: var_format,
-            instance_id: var_instanceId,
-            raw_manifest_json: var_rawManifestJson,
-        };
-    }
+fn wire__crate__api__input__ChunkedAsset_is_empty_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ChunkedAsset_is_empty",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::input::ChunkedAsset::is_empty(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for crate::api::c2pa_reader::ContentAction {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_action = <String>::sse_decode(deserializer);
-        let mut var_softwareAgent = <Option<String>>::sse_decode(deserializer);
-        let mut var_when = <Option<String>>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        return crate::api::c2pa_reader::ContentAction {
-            action: var_action,
-            software_agent: var_softwareAgent,
-            when: var_when,
-            description: var_description,
-        };
-    }
+fn wire__crate__api__input__ChunkedAsset_len_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ChunkedAsset_len",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::input::ChunkedAsset::len(&*api_that_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for crate::api::c2pa_reader::ExifInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_software = <Option<String>>::sse_decode(deserializer);
-        let mut var_make = <Option<String>>::sse_decode(deserializer);
-        let mut var_model = <Option<String>>::sse_decode(deserializer);
-        let mut var_artist = <Option<String>>::sse_decode(deserializer);
-        let mut var_copyright = <Option<String>>::sse_decode(deserializer);
-        let mut var_userComment = <Option<String>>::sse_decode(deserializer);
-        let mut var_imageDescription = <Option<String>>::sse_decode(deserializer);
-        let mut var_dateTimeOriginal = <Option<String>>::sse_decode(deserializer);
-        let mut var_aiDetected = <bool>::sse_decode(deserializer);
-        let mut var_aiGenerator = <Option<String>>::sse_decode(deserializer);
-        return crate::api::c2pa_reader::ExifInfo {
-            software: var_software,
-            make: var_make,
-            model: var_model,
-            artist: var_artist,
-            copyright: var_copyright,
-            user_comment: var_userComment,
-            image_description: var_imageDescription,
-            date_time_original: var_dateTimeOriginal,
-            ai_detected: var_aiDetected,
+fn wire__crate__api__input__ChunkedAsset_new_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ChunkedAsset_new",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::input::ChunkedAsset::new(api_mime_type))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__manifest_thumbnails__c2pa_thumbnails_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "c2pa_thumbnails_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::manifest_thumbnails::c2pa_thumbnails_from_bytes(
+                        api_data,
+                        api_mime_type,
+                    )?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__manifest_thumbnails__c2pa_thumbnails_from_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "c2pa_thumbnails_from_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::manifest_thumbnails::c2pa_thumbnails_from_path(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__mime__detect_mime_type_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_mime_type",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mime::detect_mime_type(api_data))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__model_identity__identify_model_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "identify_model",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::model_identity::identify_model(api_text))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__scoring__reset_scoring_weights_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_scoring_weights",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::scoring::reset_scoring_weights();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__scoring__scoring_weights_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scoring_weights",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::scoring::scoring_weights())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__scoring__set_scoring_weights_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_scoring_weights",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_weights = <crate::api::scoring::ScoringWeights>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::scoring::set_scoring_weights(api_weights)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__signatures__ai_signature_summary_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ai_signature_summary",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::signatures::ai_signature_summary())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__signatures__load_ai_signatures_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_ai_signatures_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::signatures::load_ai_signatures_from_bytes(api_data)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__signatures__load_ai_signatures_from_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_ai_signatures_from_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::signatures::load_ai_signatures_from_file(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__signatures__reset_ai_signatures_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_ai_signatures",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::signatures::reset_ai_signatures();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__trust__add_allowed_certificates_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_allowed_certificates_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pem = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::trust::add_allowed_certificates_from_bytes(api_pem)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_allowed_certificates_from_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_allowed_certificates_from_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::trust::add_allowed_certificates_from_file(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_crls_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_crls_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_crls_from_bytes(api_data)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_crls_from_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_crls_from_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_crls_from_file(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_ocsp_response_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_ocsp_response_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_ocsp_response_from_bytes(api_der)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_ocsp_response_from_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_ocsp_response_from_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_ocsp_response_from_file(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_trust_anchors_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_trust_anchors_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pem = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_trust_anchors_from_bytes(api_pem)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_trust_anchors_from_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_trust_anchors_from_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_trust_anchors_from_file(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_tsa_trust_anchors_from_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_tsa_trust_anchors_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pem = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::add_tsa_trust_anchors_from_bytes(api_pem)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__add_tsa_trust_anchors_from_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_tsa_trust_anchors_from_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::trust::add_tsa_trust_anchors_from_file(api_file_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__clear_trust_store_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_trust_store",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::clear_trust_store()?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__set_trusted_ekus_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_trusted_ekus",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_oids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::trust::set_trusted_ekus(api_oids)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__trust__trust_store_summary_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trust_store_summary",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::trust::trust_store_summary())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for ChunkedAsset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for std::collections::HashMap<String, crate::api::manifest_thumbnails::ManifestThumbnails>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner =
+            <Vec<(String, crate::api::manifest_thumbnails::ManifestThumbnails)>>::sse_decode(
+                deserializer,
+            );
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::actions::ActionChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_regions = <Vec<crate::api::actions::RegionRange>>::sse_decode(deserializer);
+        return crate::api::actions::ActionChange {
+            description: var_description,
+            regions: var_regions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::actions::ActionParameter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::actions::ActionParameter {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::AiInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isAiGenerated = <bool>::sse_decode(deserializer);
+        let mut var_likelihood = <f64>::sse_decode(deserializer);
+        let mut var_label = <crate::api::scoring::AiLabel>::sse_decode(deserializer);
+        let mut var_generatorName = <Option<String>>::sse_decode(deserializer);
+        let mut var_modelName = <Option<String>>::sse_decode(deserializer);
+        let mut var_model =
+            <Option<crate::api::model_identity::ModelIdentity>>::sse_decode(deserializer);
+        let mut var_detectionSource = <Option<String>>::sse_decode(deserializer);
+        let mut var_evidence = <Vec<crate::api::evidence::Evidence>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::AiInfo {
+            is_ai_generated: var_isAiGenerated,
+            likelihood: var_likelihood,
+            label: var_label,
+            generator_name: var_generatorName,
+            model_name: var_modelName,
+            model: var_model,
+            detection_source: var_detectionSource,
+            evidence: var_evidence,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scoring::AiLabel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scoring::AiLabel::ConfirmedAi,
+            1 => crate::api::scoring::AiLabel::LikelyAi,
+            2 => crate::api::scoring::AiLabel::EditedWithAi,
+            3 => crate::api::scoring::AiLabel::NoEvidence,
+            4 => crate::api::scoring::AiLabel::LikelyCameraOriginal,
+            _ => unreachable!("Invalid variant for AiLabel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::signatures::AiSignatureSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_schemaVersion = <u32>::sse_decode(deserializer);
+        let mut var_revision = <String>::sse_decode(deserializer);
+        let mut var_generatorCount = <u32>::sse_decode(deserializer);
+        let mut var_editorCount = <u32>::sse_decode(deserializer);
+        let mut var_phraseCount = <u32>::sse_decode(deserializer);
+        let mut var_builtIn = <bool>::sse_decode(deserializer);
+        return crate::api::signatures::AiSignatureSummary {
+            schema_version: var_schemaVersion,
+            revision: var_revision,
+            generator_count: var_generatorCount,
+            editor_count: var_editorCount,
+            phrase_count: var_phraseCount,
+            built_in: var_builtIn,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::AnalysisError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::AnalysisError::Io {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_mimeType = <String>::sse_decode(deserializer);
+                return crate::api::error::AnalysisError::UnsupportedFormat {
+                    mime_type: var_mimeType,
+                };
+            }
+            2 => {
+                return crate::api::error::AnalysisError::ManifestNotFound;
+            }
+            3 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::api::error::AnalysisError::ManifestCorrupt { detail: var_detail };
+            }
+            4 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::api::error::AnalysisError::CborDecode { detail: var_detail };
+            }
+            5 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::api::error::AnalysisError::RemoteManifestUnavailable {
+                    url: var_url,
+                };
+            }
+            6 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::AnalysisError::Other {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::C2paAnalysisResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status =
+            <crate::api::c2pa_reader::VerificationStatus>::sse_decode(deserializer);
+        let mut var_error = <Option<crate::api::error::AnalysisError>>::sse_decode(deserializer);
+        let mut var_validationStatuses =
+            <Vec<crate::api::c2pa_reader::ValidationStatusEntry>>::sse_decode(deserializer);
+        let mut var_signer =
+            <Option<crate::api::c2pa_reader::SignerInfo>>::sse_decode(deserializer);
+        let mut var_actions = <Vec<crate::api::actions::ContentAction>>::sse_decode(deserializer);
+        let mut var_aiInfo = <Option<crate::api::c2pa_reader::AiInfo>>::sse_decode(deserializer);
+        let mut var_exifInfo =
+            <Option<crate::api::c2pa_reader::ExifInfo>>::sse_decode(deserializer);
+        let mut var_iptcInfo = <Option<crate::api::iptc::IptcInfo>>::sse_decode(deserializer);
+        let mut var_xmpInfo = <Option<crate::api::xmp::XmpInfo>>::sse_decode(deserializer);
+        let mut var_generationParameters = <Option<
+            crate::api::generation_parameters::GenerationParameters,
+        >>::sse_decode(deserializer);
+        let mut var_modelIdentities =
+            <Vec<crate::api::model_identity::ModelIdentity>>::sse_decode(deserializer);
+        let mut var_claimGenerator = <Option<String>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_instanceId = <Option<String>>::sse_decode(deserializer);
+        let mut var_rawManifestJson = <Option<String>>::sse_decode(deserializer);
+        let mut var_provenance =
+            <Option<crate::api::c2pa_reader::ProvenanceGraph>>::sse_decode(deserializer);
+        let mut var_mimeCheck = <Option<crate::api::mime::MimeCheck>>::sse_decode(deserializer);
+        let mut var_thumbnailComparison = <Option<
+            crate::api::thumbnail_comparison::ThumbnailComparison,
+        >>::sse_decode(deserializer);
+        let mut var_consistency =
+            <Vec<crate::api::consistency::ConsistencyFinding>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::C2paAnalysisResult {
+            status: var_status,
+            error: var_error,
+            validation_statuses: var_validationStatuses,
+            signer: var_signer,
+            actions: var_actions,
+            ai_info: var_aiInfo,
+            exif_info: var_exifInfo,
+            iptc_info: var_iptcInfo,
+            xmp_info: var_xmpInfo,
+            generation_parameters: var_generationParameters,
+            model_identities: var_modelIdentities,
+            claim_generator: var_claimGenerator,
+            title: var_title,
+            format: var_format,
+            instance_id: var_instanceId,
+            raw_manifest_json: var_rawManifestJson,
+            provenance: var_provenance,
+            mime_check: var_mimeCheck,
+            thumbnail_comparison: var_thumbnailComparison,
+            consistency: var_consistency,
+        };
+    }
+}
+
+impl SseDecode for crate::api::manifest_thumbnails::C2paThumbnails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_activeLabel = <Option<String>>::sse_decode(deserializer);
+        let mut var_manifests = <std::collections::HashMap<
+            String,
+            crate::api::manifest_thumbnails::ManifestThumbnails,
+        >>::sse_decode(deserializer);
+        return crate::api::manifest_thumbnails::C2paThumbnails {
+            active_label: var_activeLabel,
+            manifests: var_manifests,
+        };
+    }
+}
+
+impl SseDecode for crate::api::certificate::CertificateInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subject =
+            <crate::api::distinguished_name::DistinguishedName>::sse_decode(deserializer);
+        let mut var_issuer =
+            <crate::api::distinguished_name::DistinguishedName>::sse_decode(deserializer);
+        let mut var_serialNumber = <String>::sse_decode(deserializer);
+        let mut var_notBefore = <i64>::sse_decode(deserializer);
+        let mut var_notAfter = <i64>::sse_decode(deserializer);
+        let mut var_keyAlgorithm = <String>::sse_decode(deserializer);
+        let mut var_keySizeBits = <Option<u32>>::sse_decode(deserializer);
+        let mut var_curve = <Option<String>>::sse_decode(deserializer);
+        let mut var_signatureAlgorithm = <String>::sse_decode(deserializer);
+        let mut var_sha256Fingerprint = <String>::sse_decode(deserializer);
+        let mut var_extendedKeyUsages = <Vec<String>>::sse_decode(deserializer);
+        let mut var_isCa = <bool>::sse_decode(deserializer);
+        let mut var_der = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_revocation = <crate::api::revocation::RevocationInfo>::sse_decode(deserializer);
+        return crate::api::certificate::CertificateInfo {
+            subject: var_subject,
+            issuer: var_issuer,
+            serial_number: var_serialNumber,
+            not_before: var_notBefore,
+            not_after: var_notAfter,
+            key_algorithm: var_keyAlgorithm,
+            key_size_bits: var_keySizeBits,
+            curve: var_curve,
+            signature_algorithm: var_signatureAlgorithm,
+            sha256_fingerprint: var_sha256Fingerprint,
+            extended_key_usages: var_extendedKeyUsages,
+            is_ca: var_isCa,
+            der: var_der,
+            revocation: var_revocation,
+        };
+    }
+}
+
+impl SseDecode for crate::api::consistency::ConsistencyFinding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::consistency::InconsistencyKind>::sse_decode(deserializer);
+        let mut var_severity = <crate::api::consistency::FindingSeverity>::sse_decode(deserializer);
+        let mut var_values = <Vec<crate::api::consistency::FindingValue>>::sse_decode(deserializer);
+        let mut var_explanation = <String>::sse_decode(deserializer);
+        return crate::api::consistency::ConsistencyFinding {
+            kind: var_kind,
+            severity: var_severity,
+            values: var_values,
+            explanation: var_explanation,
+        };
+    }
+}
+
+impl SseDecode for crate::api::actions::ContentAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_action = <String>::sse_decode(deserializer);
+        let mut var_softwareAgent = <Option<String>>::sse_decode(deserializer);
+        let mut var_softwareAgentInfo =
+            <Option<crate::api::actions::SoftwareAgentInfo>>::sse_decode(deserializer);
+        let mut var_when = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_digitalSourceType =
+            <Option<crate::api::digital_source_type::DigitalSourceType>>::sse_decode(deserializer);
+        let mut var_reason = <Option<String>>::sse_decode(deserializer);
+        let mut var_ingredients = <Vec<String>>::sse_decode(deserializer);
+        let mut var_parameters =
+            <Vec<crate::api::actions::ActionParameter>>::sse_decode(deserializer);
+        let mut var_changes = <Vec<crate::api::actions::ActionChange>>::sse_decode(deserializer);
+        let mut var_related = <Vec<crate::api::actions::ContentAction>>::sse_decode(deserializer);
+        return crate::api::actions::ContentAction {
+            action: var_action,
+            software_agent: var_softwareAgent,
+            software_agent_info: var_softwareAgentInfo,
+            when: var_when,
+            description: var_description,
+            digital_source_type: var_digitalSourceType,
+            reason: var_reason,
+            ingredients: var_ingredients,
+            parameters: var_parameters,
+            changes: var_changes,
+            related: var_related,
+        };
+    }
+}
+
+impl SseDecode for crate::api::digital_source_type::DigitalSourceType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::digital_source_type::DigitalSourceType::DigitalCapture;
+            }
+            1 => {
+                return crate::api::digital_source_type::DigitalSourceType::ComputationalCapture;
+            }
+            2 => {
+                return crate::api::digital_source_type::DigitalSourceType::NegativeFilm;
+            }
+            3 => {
+                return crate::api::digital_source_type::DigitalSourceType::PositiveFilm;
+            }
+            4 => {
+                return crate::api::digital_source_type::DigitalSourceType::Print;
+            }
+            5 => {
+                return crate::api::digital_source_type::DigitalSourceType::ScreenCapture;
+            }
+            6 => {
+                return crate::api::digital_source_type::DigitalSourceType::VirtualRecording;
+            }
+            7 => {
+                return crate::api::digital_source_type::DigitalSourceType::HumanEdits;
+            }
+            8 => {
+                return crate::api::digital_source_type::DigitalSourceType::MinorHumanEdits;
+            }
+            9 => {
+                return crate::api::digital_source_type::DigitalSourceType::AlgorithmicallyEnhanced;
+            }
+            10 => {
+                return crate::api::digital_source_type::DigitalSourceType::DigitalCreation;
+            }
+            11 => {
+                return crate::api::digital_source_type::DigitalSourceType::DataDrivenMedia;
+            }
+            12 => {
+                return crate::api::digital_source_type::DigitalSourceType::AlgorithmicMedia;
+            }
+            13 => {
+                return crate::api::digital_source_type::DigitalSourceType::TrainedAlgorithmicMedia;
+            }
+            14 => {
+                return crate::api::digital_source_type::DigitalSourceType::Composite;
+            }
+            15 => {
+                return crate::api::digital_source_type::DigitalSourceType::CompositeCapture;
+            }
+            16 => {
+                return crate::api::digital_source_type::DigitalSourceType::CompositeSynthetic;
+            }
+            17 => {
+                return crate::api::digital_source_type::DigitalSourceType::CompositeWithTrainedAlgorithmicMedia;
+            }
+            18 => {
+                let mut var_uri = <String>::sse_decode(deserializer);
+                return crate::api::digital_source_type::DigitalSourceType::Unknown {
+                    uri: var_uri,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::distinguished_name::DistinguishedName {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_attributes =
+            <Vec<crate::api::distinguished_name::DnAttribute>>::sse_decode(deserializer);
+        let mut var_rfc4514 = <String>::sse_decode(deserializer);
+        return crate::api::distinguished_name::DistinguishedName {
+            attributes: var_attributes,
+            rfc4514: var_rfc4514,
+        };
+    }
+}
+
+impl SseDecode for crate::api::distinguished_name::DnAttribute {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_attrType = <String>::sse_decode(deserializer);
+        let mut var_oid = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_rdnIndex = <u32>::sse_decode(deserializer);
+        return crate::api::distinguished_name::DnAttribute {
+            attr_type: var_attrType,
+            oid: var_oid,
+            value: var_value,
+            rdn_index: var_rdnIndex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::evidence::Evidence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_detector = <crate::api::evidence::EvidenceDetector>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::evidence::EvidenceKind>::sse_decode(deserializer);
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_matchedText = <String>::sse_decode(deserializer);
+        let mut var_strength = <crate::api::evidence::EvidenceStrength>::sse_decode(deserializer);
+        let mut var_explanation = <String>::sse_decode(deserializer);
+        let mut var_generator = <Option<String>>::sse_decode(deserializer);
+        return crate::api::evidence::Evidence {
+            detector: var_detector,
+            kind: var_kind,
+            field: var_field,
+            matched_text: var_matchedText,
+            strength: var_strength,
+            explanation: var_explanation,
+            generator: var_generator,
+        };
+    }
+}
+
+impl SseDecode for crate::api::evidence::EvidenceDetector {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::evidence::EvidenceDetector::C2paAction,
+            1 => crate::api::evidence::EvidenceDetector::C2paClaimGenerator,
+            2 => crate::api::evidence::EvidenceDetector::C2paManifest,
+            3 => crate::api::evidence::EvidenceDetector::Exif,
+            4 => crate::api::evidence::EvidenceDetector::PngText,
+            5 => crate::api::evidence::EvidenceDetector::Xmp,
+            _ => unreachable!("Invalid variant for EvidenceDetector: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::evidence::EvidenceKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::evidence::EvidenceKind::AiGeneration,
+            1 => crate::api::evidence::EvidenceKind::AiEditing,
+            2 => crate::api::evidence::EvidenceKind::CameraCapture,
+            _ => unreachable!("Invalid variant for EvidenceKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::evidence::EvidenceStrength {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::evidence::EvidenceStrength::Weak,
+            1 => crate::api::evidence::EvidenceStrength::Strong,
+            2 => crate::api::evidence::EvidenceStrength::Definitive,
+            _ => unreachable!("Invalid variant for EvidenceStrength: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::ExifInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_software = <Option<String>>::sse_decode(deserializer);
+        let mut var_make = <Option<String>>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_copyright = <Option<String>>::sse_decode(deserializer);
+        let mut var_userComment = <Option<String>>::sse_decode(deserializer);
+        let mut var_imageDescription = <Option<String>>::sse_decode(deserializer);
+        let mut var_dateTimeOriginal = <Option<String>>::sse_decode(deserializer);
+        let mut var_dateTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_dateTimeDigitized = <Option<String>>::sse_decode(deserializer);
+        let mut var_offsetTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_offsetTimeOriginal = <Option<String>>::sse_decode(deserializer);
+        let mut var_offsetTimeDigitized = <Option<String>>::sse_decode(deserializer);
+        let mut var_subSecTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_subSecTimeOriginal = <Option<String>>::sse_decode(deserializer);
+        let mut var_subSecTimeDigitized = <Option<String>>::sse_decode(deserializer);
+        let mut var_orientation = <Option<u32>>::sse_decode(deserializer);
+        let mut var_imageWidth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_imageHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bodySerialNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_cameraOwnerName = <Option<String>>::sse_decode(deserializer);
+        let mut var_imageUniqueId = <Option<String>>::sse_decode(deserializer);
+        let mut var_gps = <Option<crate::api::exif_metadata::GpsInfo>>::sse_decode(deserializer);
+        let mut var_lens = <Option<crate::api::exif_metadata::LensInfo>>::sse_decode(deserializer);
+        let mut var_exposure =
+            <Option<crate::api::exif_metadata::ExposureInfo>>::sse_decode(deserializer);
+        let mut var_thumbnail =
+            <Option<crate::api::exif_metadata::ExifThumbnail>>::sse_decode(deserializer);
+        let mut var_makerNote =
+            <Option<crate::api::maker_note::MakerNote>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::exif_metadata::ExifTag>>::sse_decode(deserializer);
+        let mut var_aiDetected = <bool>::sse_decode(deserializer);
+        let mut var_aiGenerator = <Option<String>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::ExifInfo {
+            software: var_software,
+            make: var_make,
+            model: var_model,
+            artist: var_artist,
+            copyright: var_copyright,
+            user_comment: var_userComment,
+            image_description: var_imageDescription,
+            date_time_original: var_dateTimeOriginal,
+            date_time: var_dateTime,
+            date_time_digitized: var_dateTimeDigitized,
+            offset_time: var_offsetTime,
+            offset_time_original: var_offsetTimeOriginal,
+            offset_time_digitized: var_offsetTimeDigitized,
+            sub_sec_time: var_subSecTime,
+            sub_sec_time_original: var_subSecTimeOriginal,
+            sub_sec_time_digitized: var_subSecTimeDigitized,
+            orientation: var_orientation,
+            image_width: var_imageWidth,
+            image_height: var_imageHeight,
+            body_serial_number: var_bodySerialNumber,
+            camera_owner_name: var_cameraOwnerName,
+            image_unique_id: var_imageUniqueId,
+            gps: var_gps,
+            lens: var_lens,
+            exposure: var_exposure,
+            thumbnail: var_thumbnail,
+            maker_note: var_makerNote,
+            tags: var_tags,
+            ai_detected: var_aiDetected,
             ai_generator: var_aiGenerator,
         };
     }
 }
 
-impl SseDecode for Vec<crate::api::c2pa_reader::ContentAction> {
+impl SseDecode for crate::api::exif_metadata::ExifTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ifd = <u16>::sse_decode(deserializer);
+        let mut var_group = <String>::sse_decode(deserializer);
+        let mut var_id = <u16>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::exif_metadata::ExifTag {
+            ifd: var_ifd,
+            group: var_group,
+            id: var_id,
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::exif_metadata::ExifThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::exif_metadata::ExifThumbnail {
+            mime_type: var_mimeType,
+            width: var_width,
+            height: var_height,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::api::exif_metadata::ExposureInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_exposureTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_fNumber = <Option<f64>>::sse_decode(deserializer);
+        let mut var_iso = <Option<u32>>::sse_decode(deserializer);
+        let mut var_exposureBias = <Option<f64>>::sse_decode(deserializer);
+        let mut var_exposureProgram = <Option<String>>::sse_decode(deserializer);
+        let mut var_meteringMode = <Option<String>>::sse_decode(deserializer);
+        let mut var_flash = <Option<String>>::sse_decode(deserializer);
+        let mut var_whiteBalance = <Option<String>>::sse_decode(deserializer);
+        return crate::api::exif_metadata::ExposureInfo {
+            exposure_time: var_exposureTime,
+            f_number: var_fNumber,
+            iso: var_iso,
+            exposure_bias: var_exposureBias,
+            exposure_program: var_exposureProgram,
+            metering_mode: var_meteringMode,
+            flash: var_flash,
+            white_balance: var_whiteBalance,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::consistency::FindingSeverity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::consistency::FindingSeverity::Info,
+            1 => crate::api::consistency::FindingSeverity::Warning,
+            _ => unreachable!("Invalid variant for FindingSeverity: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::consistency::FindingValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::consistency::FindingValue {
+            source: var_source,
+            field: var_field,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::generation_parameters::GenerationParameters {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tool =
+            <crate::api::generation_parameters::GenerationTool>::sse_decode(deserializer);
+        let mut var_toolVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_prompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_negativePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_steps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampler = <Option<String>>::sse_decode(deserializer);
+        let mut var_scheduler = <Option<String>>::sse_decode(deserializer);
+        let mut var_cfgScale = <Option<f64>>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_modelHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_loras =
+            <Vec<crate::api::generation_parameters::LoraReference>>::sse_decode(deserializer);
+        let mut var_workflow = <Option<String>>::sse_decode(deserializer);
+        let mut var_otherSettings =
+            <Vec<crate::api::generation_parameters::GenerationSetting>>::sse_decode(deserializer);
+        let mut var_sourceChunks = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::generation_parameters::GenerationParameters {
+            tool: var_tool,
+            tool_version: var_toolVersion,
+            prompt: var_prompt,
+            negative_prompt: var_negativePrompt,
+            steps: var_steps,
+            sampler: var_sampler,
+            scheduler: var_scheduler,
+            cfg_scale: var_cfgScale,
+            seed: var_seed,
+            width: var_width,
+            height: var_height,
+            model: var_model,
+            model_hash: var_modelHash,
+            loras: var_loras,
+            workflow: var_workflow,
+            other_settings: var_otherSettings,
+            source_chunks: var_sourceChunks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::generation_parameters::GenerationSetting {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::generation_parameters::GenerationSetting {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::generation_parameters::GenerationTool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::generation_parameters::GenerationTool::Automatic1111,
+            1 => crate::api::generation_parameters::GenerationTool::ComfyUi,
+            2 => crate::api::generation_parameters::GenerationTool::Fooocus,
+            3 => crate::api::generation_parameters::GenerationTool::InvokeAi,
+            4 => crate::api::generation_parameters::GenerationTool::NovelAi,
+            _ => unreachable!("Invalid variant for GenerationTool: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::exif_metadata::GpsInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_latitude = <Option<f64>>::sse_decode(deserializer);
+        let mut var_longitude = <Option<f64>>::sse_decode(deserializer);
+        let mut var_altitude = <Option<f64>>::sse_decode(deserializer);
+        let mut var_timestamp = <Option<String>>::sse_decode(deserializer);
+        let mut var_imageDirection = <Option<f64>>::sse_decode(deserializer);
+        let mut var_imageDirectionRef = <Option<String>>::sse_decode(deserializer);
+        let mut var_mapDatum = <Option<String>>::sse_decode(deserializer);
+        return crate::api::exif_metadata::GpsInfo {
+            latitude: var_latitude,
+            longitude: var_longitude,
+            altitude: var_altitude,
+            timestamp: var_timestamp,
+            image_direction: var_imageDirection,
+            image_direction_ref: var_imageDirectionRef,
+            map_datum: var_mapDatum,
+        };
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::thumbnail_comparison::ImageRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <u32>::sse_decode(deserializer);
+        let mut var_y = <u32>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::api::thumbnail_comparison::ImageRegion {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::api::consistency::InconsistencyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::consistency::InconsistencyKind::CaptureAfterSigning,
+            1 => crate::api::consistency::InconsistencyKind::ActionBeforeCapture,
+            2 => crate::api::consistency::InconsistencyKind::CameraWithoutSensorData,
+            3 => crate::api::consistency::InconsistencyKind::SoftwareMismatch,
+            4 => crate::api::consistency::InconsistencyKind::ThumbnailMismatch,
+            5 => crate::api::consistency::InconsistencyKind::ThumbnailContentMismatch,
+            _ => unreachable!("Invalid variant for InconsistencyKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::IngredientEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fromLabel = <String>::sse_decode(deserializer);
+        let mut var_toLabel = <Option<String>>::sse_decode(deserializer);
+        let mut var_ingredientTitle = <String>::sse_decode(deserializer);
+        let mut var_ingredientFormat = <Option<String>>::sse_decode(deserializer);
+        let mut var_ingredientInstanceId = <Option<String>>::sse_decode(deserializer);
+        let mut var_relationship =
+            <crate::api::c2pa_reader::IngredientRelationship>::sse_decode(deserializer);
+        let mut var_validationStatuses =
+            <Vec<crate::api::c2pa_reader::ValidationStatusEntry>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::IngredientEdge {
+            from_label: var_fromLabel,
+            to_label: var_toLabel,
+            ingredient_title: var_ingredientTitle,
+            ingredient_format: var_ingredientFormat,
+            ingredient_instance_id: var_ingredientInstanceId,
+            relationship: var_relationship,
+            validation_statuses: var_validationStatuses,
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::IngredientRelationship {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::c2pa_reader::IngredientRelationship::ParentOf,
+            1 => crate::api::c2pa_reader::IngredientRelationship::ComponentOf,
+            2 => crate::api::c2pa_reader::IngredientRelationship::InputTo,
+            _ => unreachable!("Invalid variant for IngredientRelationship: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::manifest_thumbnails::IngredientThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_instanceId = <String>::sse_decode(deserializer);
+        let mut var_manifestLabel = <Option<String>>::sse_decode(deserializer);
+        let mut var_thumbnail =
+            <crate::api::manifest_thumbnails::ManifestThumbnail>::sse_decode(deserializer);
+        return crate::api::manifest_thumbnails::IngredientThumbnail {
+            title: var_title,
+            instance_id: var_instanceId,
+            manifest_label: var_manifestLabel,
+            thumbnail: var_thumbnail,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iptc::IptcInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_objectName = <Option<String>>::sse_decode(deserializer);
+        let mut var_headline = <Option<String>>::sse_decode(deserializer);
+        let mut var_caption = <Option<String>>::sse_decode(deserializer);
+        let mut var_captionWriter = <Option<String>>::sse_decode(deserializer);
+        let mut var_keywords = <Vec<String>>::sse_decode(deserializer);
+        let mut var_byLine = <Vec<String>>::sse_decode(deserializer);
+        let mut var_byLineTitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_credit = <Option<String>>::sse_decode(deserializer);
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        let mut var_copyrightNotice = <Option<String>>::sse_decode(deserializer);
+        let mut var_specialInstructions = <Option<String>>::sse_decode(deserializer);
+        let mut var_dateCreated = <Option<String>>::sse_decode(deserializer);
+        let mut var_city = <Option<String>>::sse_decode(deserializer);
+        let mut var_subLocation = <Option<String>>::sse_decode(deserializer);
+        let mut var_provinceState = <Option<String>>::sse_decode(deserializer);
+        let mut var_countryCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_country = <Option<String>>::sse_decode(deserializer);
+        let mut var_transmissionReference = <Option<String>>::sse_decode(deserializer);
+        return crate::api::iptc::IptcInfo {
+            object_name: var_objectName,
+            headline: var_headline,
+            caption: var_caption,
+            caption_writer: var_captionWriter,
+            keywords: var_keywords,
+            by_line: var_byLine,
+            by_line_title: var_byLineTitle,
+            credit: var_credit,
+            source: var_source,
+            copyright_notice: var_copyrightNotice,
+            special_instructions: var_specialInstructions,
+            date_created: var_dateCreated,
+            city: var_city,
+            sub_location: var_subLocation,
+            province_state: var_provinceState,
+            country_code: var_countryCode,
+            country: var_country,
+            transmission_reference: var_transmissionReference,
+        };
+    }
+}
+
+impl SseDecode for crate::api::exif_metadata::LensInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_make = <Option<String>>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_serialNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_specification = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_focalLength = <Option<f64>>::sse_decode(deserializer);
+        let mut var_focalLength35Mm = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::exif_metadata::LensInfo {
+            make: var_make,
+            model: var_model,
+            serial_number: var_serialNumber,
+            specification: var_specification,
+            focal_length: var_focalLength,
+            focal_length_35mm: var_focalLength35Mm,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::actions::ActionChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::actions::ActionChange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::actions::ActionParameter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::actions::ActionParameter>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::certificate::CertificateInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::certificate::CertificateInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::consistency::ConsistencyFinding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::consistency::ConsistencyFinding>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::actions::ContentAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::actions::ContentAction>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::distinguished_name::DnAttribute> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::distinguished_name::DnAttribute>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::evidence::Evidence> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::evidence::Evidence>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::exif_metadata::ExifTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::exif_metadata::ExifTag>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::consistency::FindingValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::consistency::FindingValue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::generation_parameters::GenerationSetting> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::generation_parameters::GenerationSetting>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::c2pa_reader::IngredientEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::c2pa_reader::IngredientEdge>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::manifest_thumbnails::IngredientThumbnail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::manifest_thumbnails::IngredientThumbnail>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::generation_parameters::LoraReference> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::generation_parameters::LoraReference>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::maker_note::MakerNoteTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::maker_note::MakerNoteTag>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::c2pa_reader::ManifestNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::c2pa_reader::ManifestNode>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::model_identity::ModelIdentity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model_identity::ModelIdentity>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, crate::api::manifest_thumbnails::ManifestThumbnails)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(
+                String,
+                crate::api::manifest_thumbnails::ManifestThumbnails,
+            )>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::actions::RegionRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::actions::RegionRange>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::c2pa_reader::ValidationStatusEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::c2pa_reader::ValidationStatusEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::generation_parameters::LoraReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_weight = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::generation_parameters::LoraReference {
+            name: var_name,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::maker_note::MakerNote {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_vendor = <String>::sse_decode(deserializer);
+        let mut var_serialNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_lensModel = <Option<String>>::sse_decode(deserializer);
+        let mut var_firmwareVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_shutterCount = <Option<u32>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::maker_note::MakerNoteTag>>::sse_decode(deserializer);
+        return crate::api::maker_note::MakerNote {
+            vendor: var_vendor,
+            serial_number: var_serialNumber,
+            lens_model: var_lensModel,
+            firmware_version: var_firmwareVersion,
+            shutter_count: var_shutterCount,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::maker_note::MakerNoteTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u16>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::maker_note::MakerNoteTag {
+            id: var_id,
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::ManifestNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_isActive = <bool>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_instanceId = <Option<String>>::sse_decode(deserializer);
+        let mut var_claimGenerator = <Option<String>>::sse_decode(deserializer);
+        let mut var_signer =
+            <Option<crate::api::c2pa_reader::SignerInfo>>::sse_decode(deserializer);
+        let mut var_actions = <Vec<crate::api::actions::ContentAction>>::sse_decode(deserializer);
+        let mut var_validationStatuses =
+            <Vec<crate::api::c2pa_reader::ValidationStatusEntry>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::ManifestNode {
+            label: var_label,
+            is_active: var_isActive,
+            title: var_title,
+            format: var_format,
+            instance_id: var_instanceId,
+            claim_generator: var_claimGenerator,
+            signer: var_signer,
+            actions: var_actions,
+            validation_statuses: var_validationStatuses,
+        };
+    }
+}
+
+impl SseDecode for crate::api::manifest_thumbnails::ManifestThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_identifier = <Option<String>>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::manifest_thumbnails::ManifestThumbnail {
+            mime_type: var_mimeType,
+            identifier: var_identifier,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::api::manifest_thumbnails::ManifestThumbnails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_claim =
+            <Option<crate::api::manifest_thumbnails::ManifestThumbnail>>::sse_decode(deserializer);
+        let mut var_ingredients =
+            <Vec<crate::api::manifest_thumbnails::IngredientThumbnail>>::sse_decode(deserializer);
+        return crate::api::manifest_thumbnails::ManifestThumbnails {
+            claim: var_claim,
+            ingredients: var_ingredients,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mime::MimeCheck {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_claimedMimeType = <String>::sse_decode(deserializer);
+        let mut var_detectedMimeType = <Option<String>>::sse_decode(deserializer);
+        let mut var_mismatch = <bool>::sse_decode(deserializer);
+        return crate::api::mime::MimeCheck {
+            claimed_mime_type: var_claimedMimeType,
+            detected_mime_type: var_detectedMimeType,
+            mismatch: var_mismatch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model_identity::ModelIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_vendor = <String>::sse_decode(deserializer);
+        let mut var_product = <String>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_isAiGenerator = <bool>::sse_decode(deserializer);
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_matchedText = <String>::sse_decode(deserializer);
+        return crate::api::model_identity::ModelIdentity {
+            vendor: var_vendor,
+            product: var_product,
+            model: var_model,
+            version: var_version,
+            is_ai_generator: var_isAiGenerator,
+            field: var_field,
+            matched_text: var_matchedText,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::c2pa_reader::AiInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::c2pa_reader::AiInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::error::AnalysisError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::error::AnalysisError>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::certificate::CertificateInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::certificate::CertificateInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::digital_source_type::DigitalSourceType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::digital_source_type::DigitalSourceType>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::distinguished_name::DistinguishedName> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::distinguished_name::DistinguishedName>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::c2pa_reader::ExifInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::c2pa_reader::ExifInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::exif_metadata::ExifThumbnail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::exif_metadata::ExifThumbnail>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::exif_metadata::ExposureInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::exif_metadata::ExposureInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::generation_parameters::GenerationParameters> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::generation_parameters::GenerationParameters>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::exif_metadata::GpsInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::exif_metadata::GpsInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::thumbnail_comparison::ImageRegion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::thumbnail_comparison::ImageRegion>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::iptc::IptcInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::iptc::IptcInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::exif_metadata::LensInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::exif_metadata::LensInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::maker_note::MakerNote> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::maker_note::MakerNote>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::manifest_thumbnails::ManifestThumbnail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::manifest_thumbnails::ManifestThumbnail>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::mime::MimeCheck> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::mime::MimeCheck>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::model_identity::ModelIdentity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::model_identity::ModelIdentity>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::c2pa_reader::ProvenanceGraph> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::c2pa_reader::ProvenanceGraph>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::revocation::RevocationSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::revocation::RevocationSource>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::c2pa_reader::SignerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::c2pa_reader::SignerInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::actions::SoftwareAgentInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::actions::SoftwareAgentInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::thumbnail_comparison::ThumbnailComparison> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::thumbnail_comparison::ThumbnailComparison>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::timestamp::TimestampInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::timestamp::TimestampInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::xmp::XmpInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::xmp::XmpInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::ProvenanceGraph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_activeLabel = <Option<String>>::sse_decode(deserializer);
+        let mut var_nodes = <Vec<crate::api::c2pa_reader::ManifestNode>>::sse_decode(deserializer);
+        let mut var_edges =
+            <Vec<crate::api::c2pa_reader::IngredientEdge>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::ProvenanceGraph {
+            active_label: var_activeLabel,
+            nodes: var_nodes,
+            edges: var_edges,
+        };
+    }
+}
+
+impl SseDecode for (String, crate::api::manifest_thumbnails::ManifestThumbnails) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 =
+            <crate::api::manifest_thumbnails::ManifestThumbnails>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::actions::RegionRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_shape = <String>::sse_decode(deserializer);
+                let mut var_unit = <Option<String>>::sse_decode(deserializer);
+                let mut var_x = <Option<f64>>::sse_decode(deserializer);
+                let mut var_y = <Option<f64>>::sse_decode(deserializer);
+                let mut var_width = <Option<f64>>::sse_decode(deserializer);
+                let mut var_height = <Option<f64>>::sse_decode(deserializer);
+                return crate::api::actions::RegionRange::Spatial {
+                    shape: var_shape,
+                    unit: var_unit,
+                    x: var_x,
+                    y: var_y,
+                    width: var_width,
+                    height: var_height,
+                };
+            }
+            1 => {
+                let mut var_start = <Option<String>>::sse_decode(deserializer);
+                let mut var_end = <Option<String>>::sse_decode(deserializer);
+                return crate::api::actions::RegionRange::Temporal {
+                    start: var_start,
+                    end: var_end,
+                };
+            }
+            2 => {
+                let mut var_start = <Option<i64>>::sse_decode(deserializer);
+                let mut var_end = <Option<i64>>::sse_decode(deserializer);
+                return crate::api::actions::RegionRange::Frame {
+                    start: var_start,
+                    end: var_end,
+                };
+            }
+            3 => {
+                let mut var_json = <String>::sse_decode(deserializer);
+                return crate::api::actions::RegionRange::Other { json: var_json };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::revocation::RevocationInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_state = <crate::api::revocation::RevocationState>::sse_decode(deserializer);
+        let mut var_source =
+            <Option<crate::api::revocation::RevocationSource>>::sse_decode(deserializer);
+        let mut var_revokedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_reason = <Option<String>>::sse_decode(deserializer);
+        let mut var_thisUpdate = <Option<i64>>::sse_decode(deserializer);
+        let mut var_nextUpdate = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::revocation::RevocationInfo {
+            state: var_state,
+            source: var_source,
+            revoked_at: var_revokedAt,
+            reason: var_reason,
+            this_update: var_thisUpdate,
+            next_update: var_nextUpdate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::revocation::RevocationSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::revocation::RevocationSource::StapledOcsp,
+            1 => crate::api::revocation::RevocationSource::CachedOcsp,
+            2 => crate::api::revocation::RevocationSource::Crl,
+            _ => unreachable!("Invalid variant for RevocationSource: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::revocation::RevocationState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::revocation::RevocationState::Good,
+            1 => crate::api::revocation::RevocationState::Revoked,
+            2 => crate::api::revocation::RevocationState::Unknown,
+            _ => unreachable!("Invalid variant for RevocationState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scoring::ScoringWeights {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_definitive = <f64>::sse_decode(deserializer);
+        let mut var_strong = <f64>::sse_decode(deserializer);
+        let mut var_weak = <f64>::sse_decode(deserializer);
+        let mut var_cameraDiscount = <f64>::sse_decode(deserializer);
+        let mut var_likelyThreshold = <f64>::sse_decode(deserializer);
+        return crate::api::scoring::ScoringWeights {
+            definitive: var_definitive,
+            strong: var_strong,
+            weak: var_weak,
+            camera_discount: var_cameraDiscount,
+            likely_threshold: var_likelyThreshold,
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::SignerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_organization = <Option<String>>::sse_decode(deserializer);
+        let mut var_issuedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <Option<String>>::sse_decode(deserializer);
+        let mut var_certificateChain =
+            <Vec<crate::api::certificate::CertificateInfo>>::sse_decode(deserializer);
+        let mut var_timestampToken =
+            <Option<crate::api::timestamp::TimestampInfo>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::SignerInfo {
+            name: var_name,
+            organization: var_organization,
+            issued_by: var_issuedBy,
+            timestamp: var_timestamp,
+            certificate_chain: var_certificateChain,
+            timestamp_token: var_timestampToken,
+        };
+    }
+}
+
+impl SseDecode for crate::api::actions::SoftwareAgentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_icon = <Option<String>>::sse_decode(deserializer);
+        return crate::api::actions::SoftwareAgentInfo {
+            name: var_name,
+            version: var_version,
+            icon: var_icon,
+        };
+    }
+}
+
+impl SseDecode for crate::api::thumbnail_comparison::ThumbnailComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_comparedWidth = <u32>::sse_decode(deserializer);
+        let mut var_comparedHeight = <u32>::sse_decode(deserializer);
+        let mut var_difference = <f64>::sse_decode(deserializer);
+        let mut var_changedFraction = <f64>::sse_decode(deserializer);
+        let mut var_changedRegion =
+            <Option<crate::api::thumbnail_comparison::ImageRegion>>::sse_decode(deserializer);
+        let mut var_contentDiffers = <bool>::sse_decode(deserializer);
+        return crate::api::thumbnail_comparison::ThumbnailComparison {
+            compared_width: var_comparedWidth,
+            compared_height: var_comparedHeight,
+            difference: var_difference,
+            changed_fraction: var_changedFraction,
+            changed_region: var_changedRegion,
+            content_differs: var_contentDiffers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::timestamp::TimestampInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tsaName = <Option<String>>::sse_decode(deserializer);
+        let mut var_tsaCertificate =
+            <Option<crate::api::certificate::CertificateInfo>>::sse_decode(deserializer);
+        let mut var_genTime = <i64>::sse_decode(deserializer);
+        let mut var_hashAlgorithm = <String>::sse_decode(deserializer);
+        let mut var_messageImprint = <String>::sse_decode(deserializer);
+        let mut var_policy = <Option<String>>::sse_decode(deserializer);
+        let mut var_serialNumber = <String>::sse_decode(deserializer);
+        let mut var_signatureValid = <bool>::sse_decode(deserializer);
+        let mut var_trusted = <bool>::sse_decode(deserializer);
+        let mut var_signerValidityEvaluatedAtTsaTime = <bool>::sse_decode(deserializer);
+        let mut var_signerValidAtGenTime = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::timestamp::TimestampInfo {
+            tsa_name: var_tsaName,
+            tsa_certificate: var_tsaCertificate,
+            gen_time: var_genTime,
+            hash_algorithm: var_hashAlgorithm,
+            message_imprint: var_messageImprint,
+            policy: var_policy,
+            serial_number: var_serialNumber,
+            signature_valid: var_signatureValid,
+            trusted: var_trusted,
+            signer_validity_evaluated_at_tsa_time: var_signerValidityEvaluatedAtTsaTime,
+            signer_valid_at_gen_time: var_signerValidAtGenTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::trust::TrustStoreSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_anchorCount = <u32>::sse_decode(deserializer);
+        let mut var_allowedCertificateCount = <u32>::sse_decode(deserializer);
+        let mut var_ekuOids = <Vec<String>>::sse_decode(deserializer);
+        let mut var_tsaAnchorCount = <u32>::sse_decode(deserializer);
+        let mut var_crlCount = <u32>::sse_decode(deserializer);
+        let mut var_ocspResponseCount = <u32>::sse_decode(deserializer);
+        return crate::api::trust::TrustStoreSummary {
+            anchor_count: var_anchorCount,
+            allowed_certificate_count: var_allowedCertificateCount,
+            eku_oids: var_ekuOids,
+            tsa_anchor_count: var_tsaAnchorCount,
+            crl_count: var_crlCount,
+            ocsp_response_count: var_ocspResponseCount,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::validation_status::ValidationCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::validation_status::ValidationCategory::Success,
+            1 => crate::api::validation_status::ValidationCategory::Informational,
+            2 => crate::api::validation_status::ValidationCategory::Failure,
+            _ => unreachable!("Invalid variant for ValidationCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::validation_status::ValidationStatusCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimSignatureValidated;
+            }
+            1 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimSignatureInsideValidity;
+            }
+            2 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialTrusted;
+            }
+            3 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialNotRevoked;
+            }
+            4 => {
+                return crate::api::validation_status::ValidationStatusCode::TimeStampTrusted;
+            }
+            5 => {
+                return crate::api::validation_status::ValidationStatusCode::TimeStampValidated;
+            }
+            6 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionHashedUriMatch;
+            }
+            7 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionDataHashMatch;
+            }
+            8 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMatch;
+            }
+            9 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashMatch;
+            }
+            10 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMatch;
+            }
+            11 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionAccessible;
+            }
+            12 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientManifestValidated;
+            }
+            13 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureValidated;
+            }
+            14 => {
+                return crate::api::validation_status::ValidationStatusCode::AlgorithmDeprecated;
+            }
+            15 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspSkipped;
+            }
+            16 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspInaccessible;
+            }
+            17 => {
+                return crate::api::validation_status::ValidationStatusCode::TimeStampUntrusted;
+            }
+            18 => {
+                return crate::api::validation_status::ValidationStatusCode::TimeStampOutsideValidity;
+            }
+            19 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientUnknownProvenance;
+            }
+            20 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestUnknownProvenance;
+            }
+            21 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimMissing;
+            }
+            22 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimMultiple;
+            }
+            23 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimHardBindingsMissing;
+            }
+            24 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimRequiredMissing;
+            }
+            25 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimCborInvalid;
+            }
+            26 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimSignatureMissing;
+            }
+            27 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimSignatureMismatch;
+            }
+            28 => {
+                return crate::api::validation_status::ValidationStatusCode::ClaimSignatureOutsideValidity;
+            }
+            29 => {
+                return crate::api::validation_status::ValidationStatusCode::AlgorithmUnsupported;
+            }
+            30 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialUntrusted;
+            }
+            31 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialInvalid;
+            }
+            32 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialExpired;
+            }
+            33 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialRevoked;
+            }
+            34 => {
+                return crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspUnknown;
+            }
+            35 => {
+                return crate::api::validation_status::ValidationStatusCode::TimeStampMismatch;
+            }
+            36 => {
+                return crate::api::validation_status::ValidationStatusCode::TimeStampMalformed;
+            }
+            37 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionHashedUriMismatch;
+            }
+            38 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionDataHashMismatch;
+            }
+            39 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionDataHashMalformed;
+            }
+            40 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMismatch;
+            }
+            41 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMalformed;
+            }
+            42 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashMismatch;
+            }
+            43 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashUnknownBox;
+            }
+            44 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMismatch;
+            }
+            45 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMalformed;
+            }
+            46 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashIncorrectFileCount;
+            }
+            47 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashInvalidUri;
+            }
+            48 => {
+                return crate::api::validation_status::ValidationStatusCode::HashedUriMissing;
+            }
+            49 => {
+                return crate::api::validation_status::ValidationStatusCode::HashedUriMismatch;
+            }
+            50 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionMissing;
+            }
+            51 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionRequiredMissing;
+            }
+            52 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionMultipleHardBindings;
+            }
+            53 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionUndeclared;
+            }
+            54 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionInaccessible;
+            }
+            55 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionNotRedacted;
+            }
+            56 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionSelfRedacted;
+            }
+            57 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionJsonInvalid;
+            }
+            58 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCborInvalid;
+            }
+            59 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionActionIngredientMismatch;
+            }
+            60 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionActionMalformed;
+            }
+            61 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionActionRedacted;
+            }
+            62 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionActionRedactionMismatch;
+            }
+            63 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCloudDataHardBinding;
+            }
+            64 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCloudDataActions;
+            }
+            65 => {
+                return crate::api::validation_status::ValidationStatusCode::AssertionCloudDataLabelMismatch;
+            }
+            66 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientHashedUriMissing;
+            }
+            67 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientHashedUriMismatch;
+            }
+            68 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientManifestMissing;
+            }
+            69 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientManifestMismatch;
+            }
+            70 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureMissing;
+            }
+            71 => {
+                return crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureMismatch;
+            }
+            72 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestInaccessible;
+            }
+            73 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestMultipleParents;
+            }
+            74 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestUpdateInvalid;
+            }
+            75 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestUpdateWrongParents;
+            }
+            76 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestTimestampInvalid;
+            }
+            77 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestTimestampWrongParents;
+            }
+            78 => {
+                return crate::api::validation_status::ValidationStatusCode::ManifestCompressedInvalid;
+            }
+            79 => {
+                return crate::api::validation_status::ValidationStatusCode::GeneralError;
+            }
+            80 => {
+                let mut var_code = <String>::sse_decode(deserializer);
+                return crate::api::validation_status::ValidationStatusCode::Unknown {
+                    code: var_code,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::ValidationStatusEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <String>::sse_decode(deserializer);
+        let mut var_kind =
+            <crate::api::validation_status::ValidationStatusCode>::sse_decode(deserializer);
+        let mut var_category =
+            <crate::api::validation_status::ValidationCategory>::sse_decode(deserializer);
+        let mut var_url = <Option<String>>::sse_decode(deserializer);
+        let mut var_explanation = <Option<String>>::sse_decode(deserializer);
+        return crate::api::c2pa_reader::ValidationStatusEntry {
+            code: var_code,
+            kind: var_kind,
+            category: var_category,
+            url: var_url,
+            explanation: var_explanation,
+        };
+    }
+}
+
+impl SseDecode for crate::api::c2pa_reader::VerificationStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::c2pa_reader::VerificationStatus::Verified;
+            }
+            1 => {
+                return crate::api::c2pa_reader::VerificationStatus::SignatureInvalid;
+            }
+            2 => {
+                return crate::api::c2pa_reader::VerificationStatus::CertificateExpired;
+            }
+            3 => {
+                return crate::api::c2pa_reader::VerificationStatus::CertificateUntrusted;
+            }
+            4 => {
+                return crate::api::c2pa_reader::VerificationStatus::CertificateRevoked;
+            }
+            5 => {
+                return crate::api::c2pa_reader::VerificationStatus::ContentModified;
+            }
+            6 => {
+                let mut var_codes = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::c2pa_reader::VerificationStatus::ValidationFailed {
+                    codes: var_codes,
+                };
+            }
+            7 => {
+                return crate::api::c2pa_reader::VerificationStatus::NoManifest;
+            }
+            8 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::c2pa_reader::VerificationStatus::Error {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::xmp::XmpInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_digitalSourceType =
+            <Option<crate::api::digital_source_type::DigitalSourceType>>::sse_decode(deserializer);
+        let mut var_credit = <Option<String>>::sse_decode(deserializer);
+        let mut var_creatorTool = <Option<String>>::sse_decode(deserializer);
+        let mut var_creators = <Vec<String>>::sse_decode(deserializer);
+        let mut var_provenance = <Option<String>>::sse_decode(deserializer);
+        let mut var_container = <String>::sse_decode(deserializer);
+        let mut var_packet = <String>::sse_decode(deserializer);
+        return crate::api::xmp::XmpInfo {
+            digital_source_type: var_digitalSourceType,
+            credit: var_credit,
+            creator_tool: var_creatorTool,
+            creators: var_creators,
+            provenance: var_provenance,
+            container: var_container,
+            packet: var_packet,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => {
+            wire__crate__api__c2pa_reader__analyze_c2pa_from_bytes_impl(ptr, rust_vec_len, data_len)
+        }
+        2 => wire__crate__api__c2pa_reader__analyze_c2pa_from_chunks_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__c2pa_reader__analyze_c2pa_from_mapped_file_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => {
+            wire__crate__api__c2pa_reader__analyze_c2pa_from_path_impl(ptr, rust_vec_len, data_len)
+        }
+        5 => wire__crate__api__c2pa_reader__c2pa_sdk_version_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__c2pa_reader__is_c2pa_available_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__distinguished_name__parse_distinguished_name_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__input__ChunkedAsset_append_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__input__ChunkedAsset_is_empty_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__input__ChunkedAsset_len_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__input__ChunkedAsset_new_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__manifest_thumbnails__c2pa_thumbnails_from_bytes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__manifest_thumbnails__c2pa_thumbnails_from_path_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__mime__detect_mime_type_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__model_identity__identify_model_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__scoring__reset_scoring_weights_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__scoring__scoring_weights_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__scoring__set_scoring_weights_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__signatures__ai_signature_summary_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__signatures__load_ai_signatures_from_bytes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__signatures__load_ai_signatures_from_file_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__signatures__reset_ai_signatures_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__trust__add_allowed_certificates_from_bytes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__trust__add_allowed_certificates_from_file_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__trust__add_crls_from_bytes_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__trust__add_crls_from_file_impl(ptr, rust_vec_len, data_len),
+        27 => {
+            wire__crate__api__trust__add_ocsp_response_from_bytes_impl(ptr, rust_vec_len, data_len)
+        }
+        28 => {
+            wire__crate__api__trust__add_ocsp_response_from_file_impl(ptr, rust_vec_len, data_len)
+        }
+        29 => {
+            wire__crate__api__trust__add_trust_anchors_from_bytes_impl(ptr, rust_vec_len, data_len)
+        }
+        30 => {
+            wire__crate__api__trust__add_trust_anchors_from_file_impl(ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__trust__add_tsa_trust_anchors_from_bytes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__trust__add_tsa_trust_anchors_from_file_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__trust__clear_trust_store_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__trust__set_trusted_ekus_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__trust__trust_store_summary_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ChunkedAsset> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ChunkedAsset> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ChunkedAsset>> for ChunkedAsset {
+    fn into_into_dart(self) -> FrbWrapper<ChunkedAsset> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::actions::ActionChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.description.into_into_dart().into_dart(),
+            self.regions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::actions::ActionChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::actions::ActionChange>
+    for crate::api::actions::ActionChange
+{
+    fn into_into_dart(self) -> crate::api::actions::ActionChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::actions::ActionParameter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::actions::ActionParameter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::actions::ActionParameter>
+    for crate::api::actions::ActionParameter
+{
+    fn into_into_dart(self) -> crate::api::actions::ActionParameter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::AiInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_ai_generated.into_into_dart().into_dart(),
+            self.likelihood.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.generator_name.into_into_dart().into_dart(),
+            self.model_name.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.detection_source.into_into_dart().into_dart(),
+            self.evidence.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::AiInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::AiInfo>
+    for crate::api::c2pa_reader::AiInfo
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::AiInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scoring::AiLabel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ConfirmedAi => 0.into_dart(),
+            Self::LikelyAi => 1.into_dart(),
+            Self::EditedWithAi => 2.into_dart(),
+            Self::NoEvidence => 3.into_dart(),
+            Self::LikelyCameraOriginal => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scoring::AiLabel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scoring::AiLabel>
+    for crate::api::scoring::AiLabel
+{
+    fn into_into_dart(self) -> crate::api::scoring::AiLabel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::signatures::AiSignatureSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.schema_version.into_into_dart().into_dart(),
+            self.revision.into_into_dart().into_dart(),
+            self.generator_count.into_into_dart().into_dart(),
+            self.editor_count.into_into_dart().into_dart(),
+            self.phrase_count.into_into_dart().into_dart(),
+            self.built_in.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::signatures::AiSignatureSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::signatures::AiSignatureSummary>
+    for crate::api::signatures::AiSignatureSummary
+{
+    fn into_into_dart(self) -> crate::api::signatures::AiSignatureSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::AnalysisError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::AnalysisError::Io { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::AnalysisError::UnsupportedFormat { mime_type } => {
+                [1.into_dart(), mime_type.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::AnalysisError::ManifestNotFound => [2.into_dart()].into_dart(),
+            crate::api::error::AnalysisError::ManifestCorrupt { detail } => {
+                [3.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::AnalysisError::CborDecode { detail } => {
+                [4.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::AnalysisError::RemoteManifestUnavailable { url } => {
+                [5.into_dart(), url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::AnalysisError::Other { message } => {
+                [6.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::error::AnalysisError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::AnalysisError>
+    for crate::api::error::AnalysisError
+{
+    fn into_into_dart(self) -> crate::api::error::AnalysisError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::C2paAnalysisResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.status.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.validation_statuses.into_into_dart().into_dart(),
+            self.signer.into_into_dart().into_dart(),
+            self.actions.into_into_dart().into_dart(),
+            self.ai_info.into_into_dart().into_dart(),
+            self.exif_info.into_into_dart().into_dart(),
+            self.iptc_info.into_into_dart().into_dart(),
+            self.xmp_info.into_into_dart().into_dart(),
+            self.generation_parameters.into_into_dart().into_dart(),
+            self.model_identities.into_into_dart().into_dart(),
+            self.claim_generator.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.instance_id.into_into_dart().into_dart(),
+            self.raw_manifest_json.into_into_dart().into_dart(),
+            self.provenance.into_into_dart().into_dart(),
+            self.mime_check.into_into_dart().into_dart(),
+            self.thumbnail_comparison.into_into_dart().into_dart(),
+            self.consistency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::C2paAnalysisResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::C2paAnalysisResult>
+    for crate::api::c2pa_reader::C2paAnalysisResult
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::C2paAnalysisResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::manifest_thumbnails::C2paThumbnails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.active_label.into_into_dart().into_dart(),
+            self.manifests.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::manifest_thumbnails::C2paThumbnails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::manifest_thumbnails::C2paThumbnails>
+    for crate::api::manifest_thumbnails::C2paThumbnails
+{
+    fn into_into_dart(self) -> crate::api::manifest_thumbnails::C2paThumbnails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::certificate::CertificateInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subject.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+            self.not_before.into_into_dart().into_dart(),
+            self.not_after.into_into_dart().into_dart(),
+            self.key_algorithm.into_into_dart().into_dart(),
+            self.key_size_bits.into_into_dart().into_dart(),
+            self.curve.into_into_dart().into_dart(),
+            self.signature_algorithm.into_into_dart().into_dart(),
+            self.sha256_fingerprint.into_into_dart().into_dart(),
+            self.extended_key_usages.into_into_dart().into_dart(),
+            self.is_ca.into_into_dart().into_dart(),
+            self.der.into_into_dart().into_dart(),
+            self.revocation.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::certificate::CertificateInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::certificate::CertificateInfo>
+    for crate::api::certificate::CertificateInfo
+{
+    fn into_into_dart(self) -> crate::api::certificate::CertificateInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::consistency::ConsistencyFinding {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.severity.into_into_dart().into_dart(),
+            self.values.into_into_dart().into_dart(),
+            self.explanation.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::consistency::ConsistencyFinding
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::consistency::ConsistencyFinding>
+    for crate::api::consistency::ConsistencyFinding
+{
+    fn into_into_dart(self) -> crate::api::consistency::ConsistencyFinding {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::actions::ContentAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.action.into_into_dart().into_dart(),
+            self.software_agent.into_into_dart().into_dart(),
+            self.software_agent_info.into_into_dart().into_dart(),
+            self.when.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.digital_source_type.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.ingredients.into_into_dart().into_dart(),
+            self.parameters.into_into_dart().into_dart(),
+            self.changes.into_into_dart().into_dart(),
+            self.related.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::actions::ContentAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::actions::ContentAction>
+    for crate::api::actions::ContentAction
+{
+    fn into_into_dart(self) -> crate::api::actions::ContentAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::digital_source_type::DigitalSourceType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::digital_source_type::DigitalSourceType::DigitalCapture => [0.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::ComputationalCapture => [1.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::NegativeFilm => [2.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::PositiveFilm => [3.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::Print => [4.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::ScreenCapture => [5.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::VirtualRecording => [6.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::HumanEdits => [7.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::MinorHumanEdits => [8.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::AlgorithmicallyEnhanced => [9.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::DigitalCreation => [10.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::DataDrivenMedia => [11.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::AlgorithmicMedia => [12.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::TrainedAlgorithmicMedia => [13.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::Composite => [14.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::CompositeCapture => [15.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::CompositeSynthetic => [16.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::CompositeWithTrainedAlgorithmicMedia => [17.into_dart()].into_dart(),
+            crate::api::digital_source_type::DigitalSourceType::Unknown { uri } => {
+                [18.into_dart(), uri.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::digital_source_type::DigitalSourceType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::digital_source_type::DigitalSourceType>
+    for crate::api::digital_source_type::DigitalSourceType
+{
+    fn into_into_dart(self) -> crate::api::digital_source_type::DigitalSourceType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::distinguished_name::DistinguishedName {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.attributes.into_into_dart().into_dart(),
+            self.rfc4514.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::distinguished_name::DistinguishedName
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::distinguished_name::DistinguishedName>
+    for crate::api::distinguished_name::DistinguishedName
+{
+    fn into_into_dart(self) -> crate::api::distinguished_name::DistinguishedName {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::distinguished_name::DnAttribute {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.attr_type.into_into_dart().into_dart(),
+            self.oid.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.rdn_index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::distinguished_name::DnAttribute
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::distinguished_name::DnAttribute>
+    for crate::api::distinguished_name::DnAttribute
+{
+    fn into_into_dart(self) -> crate::api::distinguished_name::DnAttribute {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::evidence::Evidence {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.detector.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.matched_text.into_into_dart().into_dart(),
+            self.strength.into_into_dart().into_dart(),
+            self.explanation.into_into_dart().into_dart(),
+            self.generator.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::evidence::Evidence
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::evidence::Evidence>
+    for crate::api::evidence::Evidence
+{
+    fn into_into_dart(self) -> crate::api::evidence::Evidence {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::evidence::EvidenceDetector {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::C2paAction => 0.into_dart(),
+            Self::C2paClaimGenerator => 1.into_dart(),
+            Self::C2paManifest => 2.into_dart(),
+            Self::Exif => 3.into_dart(),
+            Self::PngText => 4.into_dart(),
+            Self::Xmp => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::evidence::EvidenceDetector
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::evidence::EvidenceDetector>
+    for crate::api::evidence::EvidenceDetector
+{
+    fn into_into_dart(self) -> crate::api::evidence::EvidenceDetector {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::evidence::EvidenceKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::AiGeneration => 0.into_dart(),
+            Self::AiEditing => 1.into_dart(),
+            Self::CameraCapture => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::evidence::EvidenceKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::evidence::EvidenceKind>
+    for crate::api::evidence::EvidenceKind
+{
+    fn into_into_dart(self) -> crate::api::evidence::EvidenceKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::evidence::EvidenceStrength {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Weak => 0.into_dart(),
+            Self::Strong => 1.into_dart(),
+            Self::Definitive => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::evidence::EvidenceStrength
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::evidence::EvidenceStrength>
+    for crate::api::evidence::EvidenceStrength
+{
+    fn into_into_dart(self) -> crate::api::evidence::EvidenceStrength {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::ExifInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.software.into_into_dart().into_dart(),
+            self.make.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.copyright.into_into_dart().into_dart(),
+            self.user_comment.into_into_dart().into_dart(),
+            self.image_description.into_into_dart().into_dart(),
+            self.date_time_original.into_into_dart().into_dart(),
+            self.date_time.into_into_dart().into_dart(),
+            self.date_time_digitized.into_into_dart().into_dart(),
+            self.offset_time.into_into_dart().into_dart(),
+            self.offset_time_original.into_into_dart().into_dart(),
+            self.offset_time_digitized.into_into_dart().into_dart(),
+            self.sub_sec_time.into_into_dart().into_dart(),
+            self.sub_sec_time_original.into_into_dart().into_dart(),
+            self.sub_sec_time_digitized.into_into_dart().into_dart(),
+            self.orientation.into_into_dart().into_dart(),
+            self.image_width.into_into_dart().into_dart(),
+            self.image_height.into_into_dart().into_dart(),
+            self.body_serial_number.into_into_dart().into_dart(),
+            self.camera_owner_name.into_into_dart().into_dart(),
+            self.image_unique_id.into_into_dart().into_dart(),
+            self.gps.into_into_dart().into_dart(),
+            self.lens.into_into_dart().into_dart(),
+            self.exposure.into_into_dart().into_dart(),
+            self.thumbnail.into_into_dart().into_dart(),
+            self.maker_note.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.ai_detected.into_into_dart().into_dart(),
+            self.ai_generator.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::ExifInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::ExifInfo>
+    for crate::api::c2pa_reader::ExifInfo
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::ExifInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::exif_metadata::ExifTag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ifd.into_into_dart().into_dart(),
+            self.group.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::exif_metadata::ExifTag
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::exif_metadata::ExifTag>
+    for crate::api::exif_metadata::ExifTag
+{
+    fn into_into_dart(self) -> crate::api::exif_metadata::ExifTag {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::exif_metadata::ExifThumbnail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mime_type.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::exif_metadata::ExifThumbnail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::exif_metadata::ExifThumbnail>
+    for crate::api::exif_metadata::ExifThumbnail
+{
+    fn into_into_dart(self) -> crate::api::exif_metadata::ExifThumbnail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::exif_metadata::ExposureInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.exposure_time.into_into_dart().into_dart(),
+            self.f_number.into_into_dart().into_dart(),
+            self.iso.into_into_dart().into_dart(),
+            self.exposure_bias.into_into_dart().into_dart(),
+            self.exposure_program.into_into_dart().into_dart(),
+            self.metering_mode.into_into_dart().into_dart(),
+            self.flash.into_into_dart().into_dart(),
+            self.white_balance.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::exif_metadata::ExposureInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::exif_metadata::ExposureInfo>
+    for crate::api::exif_metadata::ExposureInfo
+{
+    fn into_into_dart(self) -> crate::api::exif_metadata::ExposureInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::consistency::FindingSeverity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Info => 0.into_dart(),
+            Self::Warning => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::consistency::FindingSeverity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::consistency::FindingSeverity>
+    for crate::api::consistency::FindingSeverity
+{
+    fn into_into_dart(self) -> crate::api::consistency::FindingSeverity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::consistency::FindingValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::consistency::FindingValue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::consistency::FindingValue>
+    for crate::api::consistency::FindingValue
+{
+    fn into_into_dart(self) -> crate::api::consistency::FindingValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::generation_parameters::GenerationParameters {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tool.into_into_dart().into_dart(),
+            self.tool_version.into_into_dart().into_dart(),
+            self.prompt.into_into_dart().into_dart(),
+            self.negative_prompt.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.sampler.into_into_dart().into_dart(),
+            self.scheduler.into_into_dart().into_dart(),
+            self.cfg_scale.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.model_hash.into_into_dart().into_dart(),
+            self.loras.into_into_dart().into_dart(),
+            self.workflow.into_into_dart().into_dart(),
+            self.other_settings.into_into_dart().into_dart(),
+            self.source_chunks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::generation_parameters::GenerationParameters
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::generation_parameters::GenerationParameters>
+    for crate::api::generation_parameters::GenerationParameters
+{
+    fn into_into_dart(self) -> crate::api::generation_parameters::GenerationParameters {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::generation_parameters::GenerationSetting {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::generation_parameters::GenerationSetting
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::generation_parameters::GenerationSetting>
+    for crate::api::generation_parameters::GenerationSetting
+{
+    fn into_into_dart(self) -> crate::api::generation_parameters::GenerationSetting {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::generation_parameters::GenerationTool {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Automatic1111 => 0.into_dart(),
+            Self::ComfyUi => 1.into_dart(),
+            Self::Fooocus => 2.into_dart(),
+            Self::InvokeAi => 3.into_dart(),
+            Self::NovelAi => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::generation_parameters::GenerationTool
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::generation_parameters::GenerationTool>
+    for crate::api::generation_parameters::GenerationTool
+{
+    fn into_into_dart(self) -> crate::api::generation_parameters::GenerationTool {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::exif_metadata::GpsInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.latitude.into_into_dart().into_dart(),
+            self.longitude.into_into_dart().into_dart(),
+            self.altitude.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.image_direction.into_into_dart().into_dart(),
+            self.image_direction_ref.into_into_dart().into_dart(),
+            self.map_datum.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::exif_metadata::GpsInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::exif_metadata::GpsInfo>
+    for crate::api::exif_metadata::GpsInfo
+{
+    fn into_into_dart(self) -> crate::api::exif_metadata::GpsInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::thumbnail_comparison::ImageRegion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::thumbnail_comparison::ImageRegion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::thumbnail_comparison::ImageRegion>
+    for crate::api::thumbnail_comparison::ImageRegion
+{
+    fn into_into_dart(self) -> crate::api::thumbnail_comparison::ImageRegion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::consistency::InconsistencyKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::CaptureAfterSigning => 0.into_dart(),
+            Self::ActionBeforeCapture => 1.into_dart(),
+            Self::CameraWithoutSensorData => 2.into_dart(),
+            Self::SoftwareMismatch => 3.into_dart(),
+            Self::ThumbnailMismatch => 4.into_dart(),
+            Self::ThumbnailContentMismatch => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::consistency::InconsistencyKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::consistency::InconsistencyKind>
+    for crate::api::consistency::InconsistencyKind
+{
+    fn into_into_dart(self) -> crate::api::consistency::InconsistencyKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::IngredientEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from_label.into_into_dart().into_dart(),
+            self.to_label.into_into_dart().into_dart(),
+            self.ingredient_title.into_into_dart().into_dart(),
+            self.ingredient_format.into_into_dart().into_dart(),
+            self.ingredient_instance_id.into_into_dart().into_dart(),
+            self.relationship.into_into_dart().into_dart(),
+            self.validation_statuses.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::IngredientEdge
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::IngredientEdge>
+    for crate::api::c2pa_reader::IngredientEdge
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::IngredientEdge {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::IngredientRelationship {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ParentOf => 0.into_dart(),
+            Self::ComponentOf => 1.into_dart(),
+            Self::InputTo => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::IngredientRelationship
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::IngredientRelationship>
+    for crate::api::c2pa_reader::IngredientRelationship
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::IngredientRelationship {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::manifest_thumbnails::IngredientThumbnail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.instance_id.into_into_dart().into_dart(),
+            self.manifest_label.into_into_dart().into_dart(),
+            self.thumbnail.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::manifest_thumbnails::IngredientThumbnail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::manifest_thumbnails::IngredientThumbnail>
+    for crate::api::manifest_thumbnails::IngredientThumbnail
+{
+    fn into_into_dart(self) -> crate::api::manifest_thumbnails::IngredientThumbnail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iptc::IptcInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.object_name.into_into_dart().into_dart(),
+            self.headline.into_into_dart().into_dart(),
+            self.caption.into_into_dart().into_dart(),
+            self.caption_writer.into_into_dart().into_dart(),
+            self.keywords.into_into_dart().into_dart(),
+            self.by_line.into_into_dart().into_dart(),
+            self.by_line_title.into_into_dart().into_dart(),
+            self.credit.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.copyright_notice.into_into_dart().into_dart(),
+            self.special_instructions.into_into_dart().into_dart(),
+            self.date_created.into_into_dart().into_dart(),
+            self.city.into_into_dart().into_dart(),
+            self.sub_location.into_into_dart().into_dart(),
+            self.province_state.into_into_dart().into_dart(),
+            self.country_code.into_into_dart().into_dart(),
+            self.country.into_into_dart().into_dart(),
+            self.transmission_reference.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::iptc::IptcInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iptc::IptcInfo> for crate::api::iptc::IptcInfo {
+    fn into_into_dart(self) -> crate::api::iptc::IptcInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::exif_metadata::LensInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.make.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+            self.specification.into_into_dart().into_dart(),
+            self.focal_length.into_into_dart().into_dart(),
+            self.focal_length_35mm.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::exif_metadata::LensInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::exif_metadata::LensInfo>
+    for crate::api::exif_metadata::LensInfo
+{
+    fn into_into_dart(self) -> crate::api::exif_metadata::LensInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::generation_parameters::LoraReference {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::generation_parameters::LoraReference
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::generation_parameters::LoraReference>
+    for crate::api::generation_parameters::LoraReference
+{
+    fn into_into_dart(self) -> crate::api::generation_parameters::LoraReference {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::maker_note::MakerNote {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.vendor.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+            self.lens_model.into_into_dart().into_dart(),
+            self.firmware_version.into_into_dart().into_dart(),
+            self.shutter_count.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::maker_note::MakerNote
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::maker_note::MakerNote>
+    for crate::api::maker_note::MakerNote
+{
+    fn into_into_dart(self) -> crate::api::maker_note::MakerNote {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::maker_note::MakerNoteTag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::maker_note::MakerNoteTag
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::maker_note::MakerNoteTag>
+    for crate::api::maker_note::MakerNoteTag
+{
+    fn into_into_dart(self) -> crate::api::maker_note::MakerNoteTag {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::ManifestNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.is_active.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.instance_id.into_into_dart().into_dart(),
+            self.claim_generator.into_into_dart().into_dart(),
+            self.signer.into_into_dart().into_dart(),
+            self.actions.into_into_dart().into_dart(),
+            self.validation_statuses.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::ManifestNode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::ManifestNode>
+    for crate::api::c2pa_reader::ManifestNode
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::ManifestNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::manifest_thumbnails::ManifestThumbnail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mime_type.into_into_dart().into_dart(),
+            self.identifier.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::manifest_thumbnails::ManifestThumbnail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::manifest_thumbnails::ManifestThumbnail>
+    for crate::api::manifest_thumbnails::ManifestThumbnail
+{
+    fn into_into_dart(self) -> crate::api::manifest_thumbnails::ManifestThumbnail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::manifest_thumbnails::ManifestThumbnails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.claim.into_into_dart().into_dart(),
+            self.ingredients.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::manifest_thumbnails::ManifestThumbnails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::manifest_thumbnails::ManifestThumbnails>
+    for crate::api::manifest_thumbnails::ManifestThumbnails
+{
+    fn into_into_dart(self) -> crate::api::manifest_thumbnails::ManifestThumbnails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::mime::MimeCheck {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.claimed_mime_type.into_into_dart().into_dart(),
+            self.detected_mime_type.into_into_dart().into_dart(),
+            self.mismatch.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::mime::MimeCheck {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::mime::MimeCheck>
+    for crate::api::mime::MimeCheck
+{
+    fn into_into_dart(self) -> crate::api::mime::MimeCheck {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model_identity::ModelIdentity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.vendor.into_into_dart().into_dart(),
+            self.product.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.is_ai_generator.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.matched_text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model_identity::ModelIdentity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model_identity::ModelIdentity>
+    for crate::api::model_identity::ModelIdentity
+{
+    fn into_into_dart(self) -> crate::api::model_identity::ModelIdentity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::ProvenanceGraph {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.active_label.into_into_dart().into_dart(),
+            self.nodes.into_into_dart().into_dart(),
+            self.edges.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::ProvenanceGraph
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::ProvenanceGraph>
+    for crate::api::c2pa_reader::ProvenanceGraph
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::ProvenanceGraph {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::actions::RegionRange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::actions::RegionRange::Spatial {
+                shape,
+                unit,
+                x,
+                y,
+                width,
+                height,
+            } => [
+                0.into_dart(),
+                shape.into_into_dart().into_dart(),
+                unit.into_into_dart().into_dart(),
+                x.into_into_dart().into_dart(),
+                y.into_into_dart().into_dart(),
+                width.into_into_dart().into_dart(),
+                height.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::actions::RegionRange::Temporal { start, end } => [
+                1.into_dart(),
+                start.into_into_dart().into_dart(),
+                end.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::actions::RegionRange::Frame { start, end } => [
+                2.into_dart(),
+                start.into_into_dart().into_dart(),
+                end.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::actions::RegionRange::Other { json } => {
+                [3.into_dart(), json.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::actions::RegionRange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::actions::RegionRange>
+    for crate::api::actions::RegionRange
+{
+    fn into_into_dart(self) -> crate::api::actions::RegionRange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::revocation::RevocationInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.state.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.revoked_at.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.this_update.into_into_dart().into_dart(),
+            self.next_update.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::revocation::RevocationInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::revocation::RevocationInfo>
+    for crate::api::revocation::RevocationInfo
+{
+    fn into_into_dart(self) -> crate::api::revocation::RevocationInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::revocation::RevocationSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::StapledOcsp => 0.into_dart(),
+            Self::CachedOcsp => 1.into_dart(),
+            Self::Crl => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::revocation::RevocationSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::revocation::RevocationSource>
+    for crate::api::revocation::RevocationSource
+{
+    fn into_into_dart(self) -> crate::api::revocation::RevocationSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::revocation::RevocationState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Good => 0.into_dart(),
+            Self::Revoked => 1.into_dart(),
+            Self::Unknown => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::revocation::RevocationState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::revocation::RevocationState>
+    for crate::api::revocation::RevocationState
+{
+    fn into_into_dart(self) -> crate::api::revocation::RevocationState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scoring::ScoringWeights {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.definitive.into_into_dart().into_dart(),
+            self.strong.into_into_dart().into_dart(),
+            self.weak.into_into_dart().into_dart(),
+            self.camera_discount.into_into_dart().into_dart(),
+            self.likely_threshold.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scoring::ScoringWeights
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scoring::ScoringWeights>
+    for crate::api::scoring::ScoringWeights
+{
+    fn into_into_dart(self) -> crate::api::scoring::ScoringWeights {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::SignerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.organization.into_into_dart().into_dart(),
+            self.issued_by.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.certificate_chain.into_into_dart().into_dart(),
+            self.timestamp_token.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::SignerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::SignerInfo>
+    for crate::api::c2pa_reader::SignerInfo
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::SignerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::actions::SoftwareAgentInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.icon.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::actions::SoftwareAgentInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::actions::SoftwareAgentInfo>
+    for crate::api::actions::SoftwareAgentInfo
+{
+    fn into_into_dart(self) -> crate::api::actions::SoftwareAgentInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::thumbnail_comparison::ThumbnailComparison {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.compared_width.into_into_dart().into_dart(),
+            self.compared_height.into_into_dart().into_dart(),
+            self.difference.into_into_dart().into_dart(),
+            self.changed_fraction.into_into_dart().into_dart(),
+            self.changed_region.into_into_dart().into_dart(),
+            self.content_differs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::thumbnail_comparison::ThumbnailComparison
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::thumbnail_comparison::ThumbnailComparison>
+    for crate::api::thumbnail_comparison::ThumbnailComparison
+{
+    fn into_into_dart(self) -> crate::api::thumbnail_comparison::ThumbnailComparison {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::timestamp::TimestampInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tsa_name.into_into_dart().into_dart(),
+            self.tsa_certificate.into_into_dart().into_dart(),
+            self.gen_time.into_into_dart().into_dart(),
+            self.hash_algorithm.into_into_dart().into_dart(),
+            self.message_imprint.into_into_dart().into_dart(),
+            self.policy.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+            self.signature_valid.into_into_dart().into_dart(),
+            self.trusted.into_into_dart().into_dart(),
+            self.signer_validity_evaluated_at_tsa_time
+                .into_into_dart()
+                .into_dart(),
+            self.signer_valid_at_gen_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::timestamp::TimestampInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::timestamp::TimestampInfo>
+    for crate::api::timestamp::TimestampInfo
+{
+    fn into_into_dart(self) -> crate::api::timestamp::TimestampInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::trust::TrustStoreSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.anchor_count.into_into_dart().into_dart(),
+            self.allowed_certificate_count.into_into_dart().into_dart(),
+            self.eku_oids.into_into_dart().into_dart(),
+            self.tsa_anchor_count.into_into_dart().into_dart(),
+            self.crl_count.into_into_dart().into_dart(),
+            self.ocsp_response_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::trust::TrustStoreSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::trust::TrustStoreSummary>
+    for crate::api::trust::TrustStoreSummary
+{
+    fn into_into_dart(self) -> crate::api::trust::TrustStoreSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::validation_status::ValidationCategory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Success => 0.into_dart(),
+            Self::Informational => 1.into_dart(),
+            Self::Failure => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::validation_status::ValidationCategory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::validation_status::ValidationCategory>
+    for crate::api::validation_status::ValidationCategory
+{
+    fn into_into_dart(self) -> crate::api::validation_status::ValidationCategory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::validation_status::ValidationStatusCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureValidated => [0.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureInsideValidity => [1.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialTrusted => [2.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialNotRevoked => [3.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::TimeStampTrusted => [4.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::TimeStampValidated => [5.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionHashedUriMatch => [6.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionDataHashMatch => [7.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMatch => [8.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashMatch => [9.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMatch => [10.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionAccessible => [11.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientManifestValidated => [12.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureValidated => [13.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AlgorithmDeprecated => [14.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspSkipped => [15.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspInaccessible => [16.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::TimeStampUntrusted => [17.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::TimeStampOutsideValidity => [18.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientUnknownProvenance => [19.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestUnknownProvenance => [20.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimMissing => [21.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimMultiple => [22.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimHardBindingsMissing => [23.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimRequiredMissing => [24.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimCborInvalid => [25.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureMissing => [26.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureMismatch => [27.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureOutsideValidity => [28.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AlgorithmUnsupported => [29.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialUntrusted => [30.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialInvalid => [31.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialExpired => [32.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialRevoked => [33.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspUnknown => [34.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::TimeStampMismatch => [35.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::TimeStampMalformed => [36.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionHashedUriMismatch => [37.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionDataHashMismatch => [38.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionDataHashMalformed => [39.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMismatch => [40.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMalformed => [41.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashMismatch => [42.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashUnknownBox => [43.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMismatch => [44.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMalformed => [45.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashIncorrectFileCount => [46.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashInvalidUri => [47.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::HashedUriMissing => [48.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::HashedUriMismatch => [49.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionMissing => [50.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionRequiredMissing => [51.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionMultipleHardBindings => [52.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionUndeclared => [53.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionInaccessible => [54.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionNotRedacted => [55.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionSelfRedacted => [56.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionJsonInvalid => [57.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCborInvalid => [58.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionActionIngredientMismatch => [59.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionActionMalformed => [60.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionActionRedacted => [61.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionActionRedactionMismatch => [62.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCloudDataHardBinding => [63.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCloudDataActions => [64.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::AssertionCloudDataLabelMismatch => [65.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientHashedUriMissing => [66.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientHashedUriMismatch => [67.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientManifestMissing => [68.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientManifestMismatch => [69.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureMissing => [70.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureMismatch => [71.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestInaccessible => [72.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestMultipleParents => [73.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestUpdateInvalid => [74.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestUpdateWrongParents => [75.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestTimestampInvalid => [76.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestTimestampWrongParents => [77.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::ManifestCompressedInvalid => [78.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::GeneralError => [79.into_dart()].into_dart(),
+            crate::api::validation_status::ValidationStatusCode::Unknown { code } => {
+                [80.into_dart(), code.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::validation_status::ValidationStatusCode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::validation_status::ValidationStatusCode>
+    for crate::api::validation_status::ValidationStatusCode
+{
+    fn into_into_dart(self) -> crate::api::validation_status::ValidationStatusCode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::ValidationStatusEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.code.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.explanation.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::ValidationStatusEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::ValidationStatusEntry>
+    for crate::api::c2pa_reader::ValidationStatusEntry
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::ValidationStatusEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::VerificationStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::c2pa_reader::VerificationStatus::Verified => [0.into_dart()].into_dart(),
+            crate::api::c2pa_reader::VerificationStatus::SignatureInvalid => {
+                [1.into_dart()].into_dart()
+            }
+            crate::api::c2pa_reader::VerificationStatus::CertificateExpired => {
+                [2.into_dart()].into_dart()
+            }
+            crate::api::c2pa_reader::VerificationStatus::CertificateUntrusted => {
+                [3.into_dart()].into_dart()
+            }
+            crate::api::c2pa_reader::VerificationStatus::CertificateRevoked => {
+                [4.into_dart()].into_dart()
+            }
+            crate::api::c2pa_reader::VerificationStatus::ContentModified => {
+                [5.into_dart()].into_dart()
+            }
+            crate::api::c2pa_reader::VerificationStatus::ValidationFailed { codes } => {
+                [6.into_dart(), codes.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::c2pa_reader::VerificationStatus::NoManifest => [7.into_dart()].into_dart(),
+            crate::api::c2pa_reader::VerificationStatus::Error { message } => {
+                [8.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa_reader::VerificationStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::VerificationStatus>
+    for crate::api::c2pa_reader::VerificationStatus
+{
+    fn into_into_dart(self) -> crate::api::c2pa_reader::VerificationStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::xmp::XmpInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.digital_source_type.into_into_dart().into_dart(),
+            self.credit.into_into_dart().into_dart(),
+            self.creator_tool.into_into_dart().into_dart(),
+            self.creators.into_into_dart().into_dart(),
+            self.provenance.into_into_dart().into_dart(),
+            self.container.into_into_dart().into_dart(),
+            self.packet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::xmp::XmpInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::xmp::XmpInfo> for crate::api::xmp::XmpInfo {
+    fn into_into_dart(self) -> crate::api::xmp::XmpInfo {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for ChunkedAsset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode
+    for std::collections::HashMap<String, crate::api::manifest_thumbnails::ManifestThumbnails>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, crate::api::manifest_thumbnails::ManifestThumbnails)>>::sse_encode(
+            self.into_iter().collect(),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::actions::ActionChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Vec<crate::api::actions::RegionRange>>::sse_encode(self.regions, serializer);
+    }
+}
+
+impl SseEncode for crate::api::actions::ActionParameter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::c2pa_reader::AiInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_ai_generated, serializer);
+        <f64>::sse_encode(self.likelihood, serializer);
+        <crate::api::scoring::AiLabel>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.generator_name, serializer);
+        <Option<String>>::sse_encode(self.model_name, serializer);
+        <Option<crate::api::model_identity::ModelIdentity>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.detection_source, serializer);
+        <Vec<crate::api::evidence::Evidence>>::sse_encode(self.evidence, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scoring::AiLabel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scoring::AiLabel::ConfirmedAi => 0,
+                crate::api::scoring::AiLabel::LikelyAi => 1,
+                crate::api::scoring::AiLabel::EditedWithAi => 2,
+                crate::api::scoring::AiLabel::NoEvidence => 3,
+                crate::api::scoring::AiLabel::LikelyCameraOriginal => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::signatures::AiSignatureSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.schema_version, serializer);
+        <String>::sse_encode(self.revision, serializer);
+        <u32>::sse_encode(self.generator_count, serializer);
+        <u32>::sse_encode(self.editor_count, serializer);
+        <u32>::sse_encode(self.phrase_count, serializer);
+        <bool>::sse_encode(self.built_in, serializer);
+    }
+}
+
+impl SseEncode for crate::api::error::AnalysisError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::error::AnalysisError::Io { message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::AnalysisError::UnsupportedFormat { mime_type } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(mime_type, serializer);
+            }
+            crate::api::error::AnalysisError::ManifestNotFound => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::error::AnalysisError::ManifestCorrupt { detail } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::api::error::AnalysisError::CborDecode { detail } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::api::error::AnalysisError::RemoteManifestUnavailable { url } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::api::error::AnalysisError::Other { message } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::c2pa_reader::C2paAnalysisResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::c2pa_reader::VerificationStatus>::sse_encode(self.status, serializer);
+        <Option<crate::api::error::AnalysisError>>::sse_encode(self.error, serializer);
+        <Vec<crate::api::c2pa_reader::ValidationStatusEntry>>::sse_encode(
+            self.validation_statuses,
+            serializer,
+        );
+        <Option<crate::api::c2pa_reader::SignerInfo>>::sse_encode(self.signer, serializer);
+        <Vec<crate::api::actions::ContentAction>>::sse_encode(self.actions, serializer);
+        <Option<crate::api::c2pa_reader::AiInfo>>::sse_encode(self.ai_info, serializer);
+        <Option<crate::api::c2pa_reader::ExifInfo>>::sse_encode(self.exif_info, serializer);
+        <Option<crate::api::iptc::IptcInfo>>::sse_encode(self.iptc_info, serializer);
+        <Option<crate::api::xmp::XmpInfo>>::sse_encode(self.xmp_info, serializer);
+        <Option<crate::api::generation_parameters::GenerationParameters>>::sse_encode(
+            self.generation_parameters,
+            serializer,
+        );
+        <Vec<crate::api::model_identity::ModelIdentity>>::sse_encode(
+            self.model_identities,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.claim_generator, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.instance_id, serializer);
+        <Option<String>>::sse_encode(self.raw_manifest_json, serializer);
+        <Option<crate::api::c2pa_reader::ProvenanceGraph>>::sse_encode(self.provenance, serializer);
+        <Option<crate::api::mime::MimeCheck>>::sse_encode(self.mime_check, serializer);
+        <Option<crate::api::thumbnail_comparison::ThumbnailComparison>>::sse_encode(
+            self.thumbnail_comparison,
+            serializer,
+        );
+        <Vec<crate::api::consistency::ConsistencyFinding>>::sse_encode(
+            self.consistency,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::manifest_thumbnails::C2paThumbnails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.active_label, serializer);
+        <std::collections::HashMap<String, crate::api::manifest_thumbnails::ManifestThumbnails>>::sse_encode(self.manifests, serializer);
+    }
+}
+
+impl SseEncode for crate::api::certificate::CertificateInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::distinguished_name::DistinguishedName>::sse_encode(self.subject, serializer);
+        <crate::api::distinguished_name::DistinguishedName>::sse_encode(self.issuer, serializer);
+        <String>::sse_encode(self.serial_number, serializer);
+        <i64>::sse_encode(self.not_before, serializer);
+        <i64>::sse_encode(self.not_after, serializer);
+        <String>::sse_encode(self.key_algorithm, serializer);
+        <Option<u32>>::sse_encode(self.key_size_bits, serializer);
+        <Option<String>>::sse_encode(self.curve, serializer);
+        <String>::sse_encode(self.signature_algorithm, serializer);
+        <String>::sse_encode(self.sha256_fingerprint, serializer);
+        <Vec<String>>::sse_encode(self.extended_key_usages, serializer);
+        <bool>::sse_encode(self.is_ca, serializer);
+        <Vec<u8>>::sse_encode(self.der, serializer);
+        <crate::api::revocation::RevocationInfo>::sse_encode(self.revocation, serializer);
+    }
+}
+
+impl SseEncode for crate::api::consistency::ConsistencyFinding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::consistency::InconsistencyKind>::sse_encode(self.kind, serializer);
+        <crate::api::consistency::FindingSeverity>::sse_encode(self.severity, serializer);
+        <Vec<crate::api::consistency::FindingValue>>::sse_encode(self.values, serializer);
+        <String>::sse_encode(self.explanation, serializer);
+    }
+}
+
+impl SseEncode for crate::api::actions::ContentAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.action, serializer);
+        <Option<String>>::sse_encode(self.software_agent, serializer);
+        <Option<crate::api::actions::SoftwareAgentInfo>>::sse_encode(
+            self.software_agent_info,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.when, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<crate::api::digital_source_type::DigitalSourceType>>::sse_encode(
+            self.digital_source_type,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.reason, serializer);
+        <Vec<String>>::sse_encode(self.ingredients, serializer);
+        <Vec<crate::api::actions::ActionParameter>>::sse_encode(self.parameters, serializer);
+        <Vec<crate::api::actions::ActionChange>>::sse_encode(self.changes, serializer);
+        <Vec<crate::api::actions::ContentAction>>::sse_encode(self.related, serializer);
+    }
+}
+
+impl SseEncode for crate::api::digital_source_type::DigitalSourceType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::digital_source_type::DigitalSourceType::DigitalCapture => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::ComputationalCapture => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::NegativeFilm => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::PositiveFilm => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::Print => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::ScreenCapture => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::VirtualRecording => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::HumanEdits => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::MinorHumanEdits => {
+                <i32>::sse_encode(8, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::AlgorithmicallyEnhanced => {
+                <i32>::sse_encode(9, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::DigitalCreation => {
+                <i32>::sse_encode(10, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::DataDrivenMedia => {
+                <i32>::sse_encode(11, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::AlgorithmicMedia => {
+                <i32>::sse_encode(12, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::TrainedAlgorithmicMedia => {
+                <i32>::sse_encode(13, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::Composite => {
+                <i32>::sse_encode(14, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::CompositeCapture => {
+                <i32>::sse_encode(15, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::CompositeSynthetic => {
+                <i32>::sse_encode(16, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::CompositeWithTrainedAlgorithmicMedia => {
+                <i32>::sse_encode(17, serializer);
+            }
+            crate::api::digital_source_type::DigitalSourceType::Unknown { uri } => {
+                <i32>::sse_encode(18, serializer);
+                <String>::sse_encode(uri, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::distinguished_name::DistinguishedName {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::distinguished_name::DnAttribute>>::sse_encode(self.attributes, serializer);
+        <String>::sse_encode(self.rfc4514, serializer);
+    }
+}
+
+impl SseEncode for crate::api::distinguished_name::DnAttribute {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.attr_type, serializer);
+        <String>::sse_encode(self.oid, serializer);
+        <String>::sse_encode(self.value, serializer);
+        <u32>::sse_encode(self.rdn_index, serializer);
+    }
+}
+
+impl SseEncode for crate::api::evidence::Evidence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::evidence::EvidenceDetector>::sse_encode(self.detector, serializer);
+        <crate::api::evidence::EvidenceKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.matched_text, serializer);
+        <crate::api::evidence::EvidenceStrength>::sse_encode(self.strength, serializer);
+        <String>::sse_encode(self.explanation, serializer);
+        <Option<String>>::sse_encode(self.generator, serializer);
+    }
+}
+
+impl SseEncode for crate::api::evidence::EvidenceDetector {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::evidence::EvidenceDetector::C2paAction => 0,
+                crate::api::evidence::EvidenceDetector::C2paClaimGenerator => 1,
+                crate::api::evidence::EvidenceDetector::C2paManifest => 2,
+                crate::api::evidence::EvidenceDetector::Exif => 3,
+                crate::api::evidence::EvidenceDetector::PngText => 4,
+                crate::api::evidence::EvidenceDetector::Xmp => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::evidence::EvidenceKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::evidence::EvidenceKind::AiGeneration => 0,
+                crate::api::evidence::EvidenceKind::AiEditing => 1,
+                crate::api::evidence::EvidenceKind::CameraCapture => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::evidence::EvidenceStrength {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::evidence::EvidenceStrength::Weak => 0,
+                crate::api::evidence::EvidenceStrength::Strong => 1,
+                crate::api::evidence::EvidenceStrength::Definitive => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::c2pa_reader::ExifInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.software, serializer);
+        <Option<String>>::sse_encode(self.make, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.copyright, serializer);
+        <Option<String>>::sse_encode(self.user_comment, serializer);
+        <Option<String>>::sse_encode(self.image_description, serializer);
+        <Option<String>>::sse_encode(self.date_time_original, serializer);
+        <Option<String>>::sse_encode(self.date_time, serializer);
+        <Option<String>>::sse_encode(self.date_time_digitized, serializer);
+        <Option<String>>::sse_encode(self.offset_time, serializer);
+        <Option<String>>::sse_encode(self.offset_time_original, serializer);
+        <Option<String>>::sse_encode(self.offset_time_digitized, serializer);
+        <Option<String>>::sse_encode(self.sub_sec_time, serializer);
+        <Option<String>>::sse_encode(self.sub_sec_time_original, serializer);
+        <Option<String>>::sse_encode(self.sub_sec_time_digitized, serializer);
+        <Option<u32>>::sse_encode(self.orientation, serializer);
+        <Option<u32>>::sse_encode(self.image_width, serializer);
+        <Option<u32>>::sse_encode(self.image_height, serializer);
+        <Option<String>>::sse_encode(self.body_serial_number, serializer);
+        <Option<String>>::sse_encode(self.camera_owner_name, serializer);
+        <Option<String>>::sse_encode(self.image_unique_id, serializer);
+        <Option<crate::api::exif_metadata::GpsInfo>>::sse_encode(self.gps, serializer);
+        <Option<crate::api::exif_metadata::LensInfo>>::sse_encode(self.lens, serializer);
+        <Option<crate::api::exif_metadata::ExposureInfo>>::sse_encode(self.exposure, serializer);
+        <Option<crate::api::exif_metadata::ExifThumbnail>>::sse_encode(self.thumbnail, serializer);
+        <Option<crate::api::maker_note::MakerNote>>::sse_encode(self.maker_note, serializer);
+        <Vec<crate::api::exif_metadata::ExifTag>>::sse_encode(self.tags, serializer);
+        <bool>::sse_encode(self.ai_detected, serializer);
+        <Option<String>>::sse_encode(self.ai_generator, serializer);
+    }
+}
+
+impl SseEncode for crate::api::exif_metadata::ExifTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u16>::sse_encode(self.ifd, serializer);
+        <String>::sse_encode(self.group, serializer);
+        <u16>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::exif_metadata::ExifThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mime_type, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::exif_metadata::ExposureInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.exposure_time, serializer);
+        <Option<f64>>::sse_encode(self.f_number, serializer);
+        <Option<u32>>::sse_encode(self.iso, serializer);
+        <Option<f64>>::sse_encode(self.exposure_bias, serializer);
+        <Option<String>>::sse_encode(self.exposure_program, serializer);
+        <Option<String>>::sse_encode(self.metering_mode, serializer);
+        <Option<String>>::sse_encode(self.flash, serializer);
+        <Option<String>>::sse_encode(self.white_balance, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::consistency::FindingSeverity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::consistency::FindingSeverity::Info => 0,
+                crate::api::consistency::FindingSeverity::Warning => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::consistency::FindingValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::generation_parameters::GenerationParameters {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::generation_parameters::GenerationTool>::sse_encode(self.tool, serializer);
+        <Option<String>>::sse_encode(self.tool_version, serializer);
+        <Option<String>>::sse_encode(self.prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_prompt, serializer);
+        <Option<u32>>::sse_encode(self.steps, serializer);
+        <Option<String>>::sse_encode(self.sampler, serializer);
+        <Option<String>>::sse_encode(self.scheduler, serializer);
+        <Option<f64>>::sse_encode(self.cfg_scale, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.model_hash, serializer);
+        <Vec<crate::api::generation_parameters::LoraReference>>::sse_encode(self.loras, serializer);
+        <Option<String>>::sse_encode(self.workflow, serializer);
+        <Vec<crate::api::generation_parameters::GenerationSetting>>::sse_encode(
+            self.other_settings,
+            serializer,
+        );
+        <Vec<String>>::sse_encode(self.source_chunks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::generation_parameters::GenerationSetting {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::generation_parameters::GenerationTool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::generation_parameters::GenerationTool::Automatic1111 => 0,
+                crate::api::generation_parameters::GenerationTool::ComfyUi => 1,
+                crate::api::generation_parameters::GenerationTool::Fooocus => 2,
+                crate::api::generation_parameters::GenerationTool::InvokeAi => 3,
+                crate::api::generation_parameters::GenerationTool::NovelAi => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::exif_metadata::GpsInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.latitude, serializer);
+        <Option<f64>>::sse_encode(self.longitude, serializer);
+        <Option<f64>>::sse_encode(self.altitude, serializer);
+        <Option<String>>::sse_encode(self.timestamp, serializer);
+        <Option<f64>>::sse_encode(self.image_direction, serializer);
+        <Option<String>>::sse_encode(self.image_direction_ref, serializer);
+        <Option<String>>::sse_encode(self.map_datum, serializer);
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::thumbnail_comparison::ImageRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.x, serializer);
+        <u32>::sse_encode(self.y, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+    }
+}
+
+impl SseEncode for crate::api::consistency::InconsistencyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::consistency::InconsistencyKind::CaptureAfterSigning => 0,
+                crate::api::consistency::InconsistencyKind::ActionBeforeCapture => 1,
+                crate::api::consistency::InconsistencyKind::CameraWithoutSensorData => 2,
+                crate::api::consistency::InconsistencyKind::SoftwareMismatch => 3,
+                crate::api::consistency::InconsistencyKind::ThumbnailMismatch => 4,
+                crate::api::consistency::InconsistencyKind::ThumbnailContentMismatch => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::c2pa_reader::IngredientEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.from_label, serializer);
+        <Option<String>>::sse_encode(self.to_label, serializer);
+        <String>::sse_encode(self.ingredient_title, serializer);
+        <Option<String>>::sse_encode(self.ingredient_format, serializer);
+        <Option<String>>::sse_encode(self.ingredient_instance_id, serializer);
+        <crate::api::c2pa_reader::IngredientRelationship>::sse_encode(
+            self.relationship,
+            serializer,
+        );
+        <Vec<crate::api::c2pa_reader::ValidationStatusEntry>>::sse_encode(
+            self.validation_statuses,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::c2pa_reader::IngredientRelationship {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::c2pa_reader::IngredientRelationship::ParentOf => 0,
+                crate::api::c2pa_reader::IngredientRelationship::ComponentOf => 1,
+                crate::api::c2pa_reader::IngredientRelationship::InputTo => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::manifest_thumbnails::IngredientThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.instance_id, serializer);
+        <Option<String>>::sse_encode(self.manifest_label, serializer);
+        <crate::api::manifest_thumbnails::ManifestThumbnail>::sse_encode(
+            self.thumbnail,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::iptc::IptcInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.object_name, serializer);
+        <Option<String>>::sse_encode(self.headline, serializer);
+        <Option<String>>::sse_encode(self.caption, serializer);
+        <Option<String>>::sse_encode(self.caption_writer, serializer);
+        <Vec<String>>::sse_encode(self.keywords, serializer);
+        <Vec<String>>::sse_encode(self.by_line, serializer);
+        <Option<String>>::sse_encode(self.by_line_title, serializer);
+        <Option<String>>::sse_encode(self.credit, serializer);
+        <Option<String>>::sse_encode(self.source, serializer);
+        <Option<String>>::sse_encode(self.copyright_notice, serializer);
+        <Option<String>>::sse_encode(self.special_instructions, serializer);
+        <Option<String>>::sse_encode(self.date_created, serializer);
+        <Option<String>>::sse_encode(self.city, serializer);
+        <Option<String>>::sse_encode(self.sub_location, serializer);
+        <Option<String>>::sse_encode(self.province_state, serializer);
+        <Option<String>>::sse_encode(self.country_code, serializer);
+        <Option<String>>::sse_encode(self.country, serializer);
+        <Option<String>>::sse_encode(self.transmission_reference, serializer);
+    }
+}
+
+impl SseEncode for crate::api::exif_metadata::LensInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.make, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.serial_number, serializer);
+        <Vec<f64>>::sse_encode(self.specification, serializer);
+        <Option<f64>>::sse_encode(self.focal_length, serializer);
+        <Option<u32>>::sse_encode(self.focal_length_35mm, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::actions::ActionChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::actions::ActionChange>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::actions::ActionParameter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::actions::ActionParameter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::certificate::CertificateInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::certificate::CertificateInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::consistency::ConsistencyFinding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::consistency::ConsistencyFinding>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::actions::ContentAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::actions::ContentAction>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::distinguished_name::DnAttribute> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::distinguished_name::DnAttribute>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::evidence::Evidence> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::evidence::Evidence>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::exif_metadata::ExifTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::exif_metadata::ExifTag>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::consistency::FindingValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::consistency::FindingValue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::generation_parameters::GenerationSetting> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::generation_parameters::GenerationSetting>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::c2pa_reader::IngredientEdge> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::c2pa_reader::ContentAction>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::c2pa_reader::IngredientEdge>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::manifest_thumbnails::IngredientThumbnail> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::manifest_thumbnails::IngredientThumbnail>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::generation_parameters::LoraReference> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::generation_parameters::LoraReference>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::c2pa_reader::AiInfo> {
+impl SseEncode for Vec<crate::api::maker_note::MakerNoteTag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::c2pa_reader::AiInfo>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::maker_note::MakerNoteTag>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::c2pa_reader::ExifInfo> {
+impl SseEncode for Vec<crate::api::c2pa_reader::ManifestNode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::c2pa_reader::ExifInfo>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::c2pa_reader::ManifestNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model_identity::ModelIdentity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model_identity::ModelIdentity>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<(String, crate::api::manifest_thumbnails::ManifestThumbnails)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, crate::api::manifest_thumbnails::ManifestThumbnails)>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::actions::RegionRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::actions::RegionRange>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::c2pa_reader::ValidationStatusEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::c2pa_reader::ValidationStatusEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::generation_parameters::LoraReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Option<f64>>::sse_encode(self.weight, serializer);
+    }
+}
+
+impl SseEncode for crate::api::maker_note::MakerNote {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.vendor, serializer);
+        <Option<String>>::sse_encode(self.serial_number, serializer);
+        <Option<String>>::sse_encode(self.lens_model, serializer);
+        <Option<String>>::sse_encode(self.firmware_version, serializer);
+        <Option<u32>>::sse_encode(self.shutter_count, serializer);
+        <Vec<crate::api::maker_note::MakerNoteTag>>::sse_encode(self.tags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::maker_note::MakerNoteTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u16>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::c2pa_reader::ManifestNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.label, serializer);
+        <bool>::sse_encode(self.is_active, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.instance_id, serializer);
+        <Option<String>>::sse_encode(self.claim_generator, serializer);
+        <Option<crate::api::c2pa_reader::SignerInfo>>::sse_encode(self.signer, serializer);
+        <Vec<crate::api::actions::ContentAction>>::sse_encode(self.actions, serializer);
+        <Vec<crate::api::c2pa_reader::ValidationStatusEntry>>::sse_encode(
+            self.validation_statuses,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::manifest_thumbnails::ManifestThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mime_type, serializer);
+        <Option<String>>::sse_encode(self.identifier, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::manifest_thumbnails::ManifestThumbnails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::manifest_thumbnails::ManifestThumbnail>>::sse_encode(
+            self.claim, serializer,
+        );
+        <Vec<crate::api::manifest_thumbnails::IngredientThumbnail>>::sse_encode(
+            self.ingredients,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::mime::MimeCheck {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.claimed_mime_type, serializer);
+        <Option<String>>::sse_encode(self.detected_mime_type, serializer);
+        <bool>::sse_encode(self.mismatch, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model_identity::ModelIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.vendor, serializer);
+        <String>::sse_encode(self.product, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.version, serializer);
+        <bool>::sse_encode(self.is_ai_generator, serializer);
+        <String>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.matched_text, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::c2pa_reader::AiInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::c2pa_reader::AiInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::error::AnalysisError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::error::AnalysisError>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::certificate::CertificateInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::certificate::CertificateInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::digital_source_type::DigitalSourceType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::digital_source_type::DigitalSourceType>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::distinguished_name::DistinguishedName> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::distinguished_name::DistinguishedName>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::c2pa_reader::ExifInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::c2pa_reader::ExifInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::exif_metadata::ExifThumbnail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::exif_metadata::ExifThumbnail>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::exif_metadata::ExposureInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::exif_metadata::ExposureInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::generation_parameters::GenerationParameters> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::generation_parameters::GenerationParameters>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::exif_metadata::GpsInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::exif_metadata::GpsInfo>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::c2pa_reader::SignerInfo> {
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::c2pa_reader::SignerInfo>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::c2pa_reader::SignerInfo {
+impl SseEncode for Option<crate::api::thumbnail_comparison::ImageRegion> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <Option<String>>::sse_decode(deserializer);
-        let mut var_organization = <Option<String>>::sse_decode(deserializer);
-        let mut var_issuedBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_timestamp = <Option<String>>::sse_decode(deserializer);
-        return crate::api::c2pa_reader::SignerInfo {
-            name: var_name,
-            organization: var_organization,
-            issued_by: var_issuedBy,
-            timestamp: var_timestamp,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::thumbnail_comparison::ImageRegion>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<crate::api::iptc::IptcInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::iptc::IptcInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<crate::api::exif_metadata::LensInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::exif_metadata::LensInfo>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for crate::api::c2pa_reader::VerificationStatus {
+impl SseEncode for Option<crate::api::maker_note::MakerNote> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::api::c2pa_reader::VerificationStatus::Verified;
-            }
-            1 => {
-                return crate::api::c2pa_reader::VerificationStatus::SignatureInvalid;
-            }
-            2 => {
-                return crate::api::c2pa_reader::VerificationStatus::CertificateExpired;
-            }
-            3 => {
-                return crate::api::c2pa_reader::VerificationStatus::CertificateUntrusted;
-            }
-            4 => {
-                return crate::api::c2pa_reader::VerificationStatus::NoManifest;
-            }
-            5 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::c2pa_reader::VerificationStatus::Error {
-                    message: var_message,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::maker_note::MakerNote>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<crate::api::manifest_thumbnails::ManifestThumbnail> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::manifest_thumbnails::ManifestThumbnail>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::mime::MimeCheck> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::mime::MimeCheck>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => {
-            wire__crate__api__c2pa_reader__analyze_c2pa_from_bytes_impl(ptr, rust_vec_len, data_len)
-        }
-        2 => {
-            wire__crate__api__c2pa_reader__analyze_c2pa_from_path_impl(ptr, rust_vec_len, data_len)
+impl SseEncode for Option<crate::api::model_identity::ModelIdentity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::model_identity::ModelIdentity>::sse_encode(value, serializer);
         }
-        3 => wire__crate__api__c2pa_reader__c2pa_sdk_version_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__c2pa_reader__is_c2pa_available_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::AiInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.is_ai_generated.into_into_dart().into_dart(),
-            self.generator_name.into_into_dart().into_dart(),
-            self.model_name.into_into_dart().into_dart(),
-            self.detection_source.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<crate::api::c2pa_reader::ProvenanceGraph> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::c2pa_reader::ProvenanceGraph>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::c2pa_reader::AiInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::AiInfo>
-    for crate::api::c2pa_reader::AiInfo
-{
-    fn into_into_dart(self) -> crate::api::c2pa_reader::AiInfo {
-        self
+
+impl SseEncode for Option<crate::api::revocation::RevocationSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::revocation::RevocationSource>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::C2paAnalysisResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.status.into_into_dart().into_dart(),
-            self.signer.into_into_dart().into_dart(),
-            self.actions.into_into_dart().into_dart(),
-            self.ai_info.into_into_dart().into_dart(),
-            self.exif_info.into_into_dart().into_dart(),
-            self.claim_generator.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.format.into_into_dart().into_dart(),
-            self.instance_id.into_into_dart().into_dart(),
-            self.raw_manifest_json.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::c2pa_reader::SignerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::c2pa_reader::SignerInfo>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::c2pa_reader::C2paAnalysisResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::C2paAnalysisResult>
-    for crate::api::c2pa_reader::C2paAnalysisResult
-{
-    fn into_into_dart(self) -> crate::api::c2pa_reader::C2paAnalysisResult {
-        self
+
+impl SseEncode for Option<crate::api::actions::SoftwareAgentInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::actions::SoftwareAgentInfo>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::ContentAction {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.action.into_into_dart().into_dart(),
-            self.software_agent.into_into_dart().into_dart(),
-            self.when.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::thumbnail_comparison::ThumbnailComparison> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::thumbnail_comparison::ThumbnailComparison>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::c2pa_reader::ContentAction
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::ContentAction>
-    for crate::api::c2pa_reader::ContentAction
-{
-    fn into_into_dart(self) -> crate::api::c2pa_reader::ContentAction {
-        self
+
+impl SseEncode for Option<crate::api::timestamp::TimestampInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::timestamp::TimestampInfo>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::ExifInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.software.into_into_dart().into_dart(),
-            self.make.into_into_dart().into_dart(),
-            self.model.into_into_dart().into_dart(),
-            self.artist.into_into_dart().into_dart(),
-            self.copyright.into_into_dart().into_dart(),
-            self.user_comment.into_into_dart().into_dart(),
-            self.image_description.into_into_dart().into_dart(),
-            self.date_time_original.into_into_dart().into_dart(),
-            self.ai_detected.into_into_dart().into_dart(),
-            self.ai_generator.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::c2pa_reader::ExifInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::ExifInfo>
-    for crate::api::c2pa_reader::ExifInfo
-{
-    fn into_into_dart(self) -> crate::api::c2pa_reader::ExifInfo {
-        self
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::SignerInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.organization.into_into_dart().into_dart(),
-            self.issued_by.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+}
+
+impl SseEncode for Option<crate::api::xmp::XmpInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::xmp::XmpInfo>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::c2pa_reader::SignerInfo
-{
+
+impl SseEncode for crate::api::c2pa_reader::ProvenanceGraph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.active_label, serializer);
+        <Vec<crate::api::c2pa_reader::ManifestNode>>::sse_encode(self.nodes, serializer);
+        <Vec<crate::api::c2pa_reader::IngredientEdge>>::sse_encode(self.edges, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::SignerInfo>
-    for crate::api::c2pa_reader::SignerInfo
-{
-    fn into_into_dart(self) -> crate::api::c2pa_reader::SignerInfo {
-        self
+
+impl SseEncode for (String, crate::api::manifest_thumbnails::ManifestThumbnails) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <crate::api::manifest_thumbnails::ManifestThumbnails>::sse_encode(self.1, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::VerificationStatus {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::api::actions::RegionRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::c2pa_reader::VerificationStatus::Verified => [0.into_dart()].into_dart(),
-            crate::api::c2pa_reader::VerificationStatus::SignatureInvalid => {
-                [1.into_dart()].into_dart()
+            crate::api::actions::RegionRange::Spatial {
+                shape,
+                unit,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(shape, serializer);
+                <Option<String>>::sse_encode(unit, serializer);
+                <Option<f64>>::sse_encode(x, serializer);
+                <Option<f64>>::sse_encode(y, serializer);
+                <Option<f64>>::sse_encode(width, serializer);
+                <Option<f64>>::sse_encode(height, serializer);
             }
-            crate::api::c2pa_reader::VerificationStatus::CertificateExpired => {
-                [2.into_dart()].into_dart()
+            crate::api::actions::RegionRange::Temporal { start, end } => {
+                <i32>::sse_encode(1, serializer);
+                <Option<String>>::sse_encode(start, serializer);
+                <Option<String>>::sse_encode(end, serializer);
             }
-            crate::api::c2pa_reader::VerificationStatus::CertificateUntrusted => {
-                [3.into_dart()].into_dart()
+            crate::api::actions::RegionRange::Frame { start, end } => {
+                <i32>::sse_encode(2, serializer);
+                <Option<i64>>::sse_encode(start, serializer);
+                <Option<i64>>::sse_encode(end, serializer);
             }
-            crate::api::c2pa_reader::VerificationStatus::NoManifest => [4.into_dart()].into_dart(),
-            crate::api::c2pa_reader::VerificationStatus::Error { message } => {
-                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            crate::api::actions::RegionRange::Other { json } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(json, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -606,164 +6724,462 @@ impl flutter_rust_bridge::IntoDart for crate::api::c2pa_reader::VerificationStat
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::c2pa_reader::VerificationStatus
-{
+
+impl SseEncode for crate::api::revocation::RevocationInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::revocation::RevocationState>::sse_encode(self.state, serializer);
+        <Option<crate::api::revocation::RevocationSource>>::sse_encode(self.source, serializer);
+        <Option<i64>>::sse_encode(self.revoked_at, serializer);
+        <Option<String>>::sse_encode(self.reason, serializer);
+        <Option<i64>>::sse_encode(self.this_update, serializer);
+        <Option<i64>>::sse_encode(self.next_update, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa_reader::VerificationStatus>
-    for crate::api::c2pa_reader::VerificationStatus
-{
-    fn into_into_dart(self) -> crate::api::c2pa_reader::VerificationStatus {
-        self
+
+impl SseEncode for crate::api::revocation::RevocationSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::revocation::RevocationSource::StapledOcsp => 0,
+                crate::api::revocation::RevocationSource::CachedOcsp => 1,
+                crate::api::revocation::RevocationSource::Crl => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::revocation::RevocationState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::revocation::RevocationState::Good => 0,
+                crate::api::revocation::RevocationState::Revoked => 1,
+                crate::api::revocation::RevocationState::Unknown => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::c2pa_reader::AiInfo {
+impl SseEncode for crate::api::scoring::ScoringWeights {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_ai_generated, serializer);
-        <Option<String>>::sse_encode(self.generator_name, serializer);
-        <Option<String>>::sse_encode(self.model_name, serializer);
-        <Option<String>>::sse_encode(self.detection_source, serializer);
+        <f64>::sse_encode(self.definitive, serializer);
+        <f64>::sse_encode(self.strong, serializer);
+        <f64>::sse_encode(self.weak, serializer);
+        <f64>::sse_encode(self.camera_discount, serializer);
+        <f64>::sse_encode(self.likely_threshold, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::c2pa_reader::SignerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.organization, serializer);
+        <Option<String>>::sse_encode(self.issued_by, serializer);
+        <Option<String>>::sse_encode(self.timestamp, serializer);
+        <Vec<crate::api::certificate::CertificateInfo>>::sse_encode(
+            self.certificate_chain,
+            serializer,
+        );
+        <Option<crate::api::timestamp::TimestampInfo>>::sse_encode(
+            self.timestamp_token,
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::c2pa_reader::C2paAnalysisResult {
+impl SseEncode for crate::api::actions::SoftwareAgentInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::api::c2pa_reader::VerificationStatus>::sse_encode(self.status, serializer);
-        <Option<crate::api::c2pa_reader::SignerInfo>>::sse_encode(self.signer, serializer);
-        <Vec<crate::api::c2pa_reader::ContentAction>>::sse_encode(self.actions, serializer);
-        <Option<crate::api::c2pa_reader::AiInfo>>::sse_encode(self.ai_info, serializer);
-        <Option<crate::api::c2pa_reader::ExifInfo>>::sse_encode(self.exif_info, serializer);
-        <Option<String>>::sse_encode(self.claim_generator, serializer);
-        <Option<String>>::sse_encode(self.title, serializer);
-        <Option<String>>::sse_encode(self.format, serializer);
-        <Option<String>>::sse_encode(self.instance_id, serializer);
-        <Option<String>>::sse_encode(self.raw_manifest_json, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.version, serializer);
+        <Option<String>>::sse_encode(self.icon, serializer);
     }
 }
 
-impl SseEncode for crate::api::c2pa_reader::ContentAction {
+impl SseEncode for crate::api::thumbnail_comparison::ThumbnailComparison {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.action, serializer);
-        <Option<String>>::sse_encode(self.software_agent, serializer);
-        <Option<String>>::sse_encode(self.when, serializer);
-        <Option<String>>::sse_encode(self.description, serializer);
+        <u32>::sse_encode(self.compared_width, serializer);
+        <u32>::sse_encode(self.compared_height, serializer);
+        <f64>::sse_encode(self.difference, serializer);
+        <f64>::sse_encode(self.changed_fraction, serializer);
+        <Option<crate::api::thumbnail_comparison::ImageRegion>>::sse_encode(
+            self.changed_region,
+            serializer,
+        );
+        <bool>::sse_encode(self.content_differs, serializer);
     }
 }
 
-impl SseEncode for crate::api::c2pa_reader::ExifInfo {
+impl SseEncode for crate::api::timestamp::TimestampInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<String>>::sse_encode(self.software, serializer);
-        <Option<String>>::sse_encode(self.make, serializer);
-        <Option<String>>::sse_encode(self.model, serializer);
-        <Option<String>>::sse_encode(self.artist, serializer);
-        <Option<String>>::sse_encode(self.copyright, serializer);
-        <Option<String>>::sse_encode(self.user_comment, serializer);
-        <Option<String>>::sse_encode(self.image_description, serializer);
-        <Option<String>>::sse_encode(self.date_time_original, serializer);
-        <bool>::sse_encode(self.ai_detected, serializer);
-        <Option<String>>::sse_encode(self.ai_generator, serializer);
+        <Option<String>>::sse_encode(self.tsa_name, serializer);
+        <Option<crate::api::certificate::CertificateInfo>>::sse_encode(
+            self.tsa_certificate,
+            serializer,
+        );
+        <i64>::sse_encode(self.gen_time, serializer);
+        <String>::sse_encode(self.hash_algorithm, serializer);
+        <String>::sse_encode(self.message_imprint, serializer);
+        <Option<String>>::sse_encode(self.policy, serializer);
+        <String>::sse_encode(self.serial_number, serializer);
+        <bool>::sse_encode(self.signature_valid, serializer);
+        <bool>::sse_encode(self.trusted, serializer);
+        <bool>::sse_encode(self.signer_validity_evaluated_at_tsa_time, serializer);
+        <Option<bool>>::sse_encode(self.signer_valid_at_gen_time, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::c2pa_reader::ContentAction> {
+impl SseEncode for crate::api::trust::TrustStoreSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::c2pa_reader::ContentAction>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.anchor_count, serializer);
+        <u32>::sse_encode(self.allowed_certificate_count, serializer);
+        <Vec<String>>::sse_encode(self.eku_oids, serializer);
+        <u32>::sse_encode(self.tsa_anchor_count, serializer);
+        <u32>::sse_encode(self.crl_count, serializer);
+        <u32>::sse_encode(self.ocsp_response_count, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for Option<crate::api::c2pa_reader::AiInfo> {
+impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::c2pa_reader::AiInfo>::sse_encode(value, serializer);
-        }
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for Option<crate::api::c2pa_reader::ExifInfo> {
+impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::c2pa_reader::ExifInfo>::sse_encode(value, serializer);
-        }
+        serializer.cursor.write_u8(self).unwrap();
     }
 }
 
-impl SseEncode for Option<crate::api::c2pa_reader::SignerInfo> {
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::c2pa_reader::SignerInfo>::sse_encode(value, serializer);
-        }
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
     }
 }
 
-impl SseEncode for crate::api::c2pa_reader::SignerInfo {
+impl SseEncode for crate::api::validation_status::ValidationCategory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<String>>::sse_encode(self.name, serializer);
-        <Option<String>>::sse_encode(self.organization, serializer);
-        <Option<String>>::sse_encode(self.issued_by, serializer);
-        <Option<String>>::sse_encode(self.timestamp, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::validation_status::ValidationCategory::Success => 0,
+                crate::api::validation_status::ValidationCategory::Informational => 1,
+                crate::api::validation_status::ValidationCategory::Failure => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for u8 {
+impl SseEncode for crate::api::validation_status::ValidationStatusCode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+        match self {
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureValidated => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureInsideValidity => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialTrusted => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialNotRevoked => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::TimeStampTrusted => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::TimeStampValidated => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionHashedUriMatch => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionDataHashMatch => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMatch => {
+                <i32>::sse_encode(8, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashMatch => {
+                <i32>::sse_encode(9, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMatch => {
+                <i32>::sse_encode(10, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionAccessible => {
+                <i32>::sse_encode(11, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientManifestValidated => {
+                <i32>::sse_encode(12, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureValidated => {
+                <i32>::sse_encode(13, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AlgorithmDeprecated => {
+                <i32>::sse_encode(14, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspSkipped => {
+                <i32>::sse_encode(15, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspInaccessible => {
+                <i32>::sse_encode(16, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::TimeStampUntrusted => {
+                <i32>::sse_encode(17, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::TimeStampOutsideValidity => {
+                <i32>::sse_encode(18, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientUnknownProvenance => {
+                <i32>::sse_encode(19, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestUnknownProvenance => {
+                <i32>::sse_encode(20, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimMissing => {
+                <i32>::sse_encode(21, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimMultiple => {
+                <i32>::sse_encode(22, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimHardBindingsMissing => {
+                <i32>::sse_encode(23, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimRequiredMissing => {
+                <i32>::sse_encode(24, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimCborInvalid => {
+                <i32>::sse_encode(25, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureMissing => {
+                <i32>::sse_encode(26, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureMismatch => {
+                <i32>::sse_encode(27, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ClaimSignatureOutsideValidity => {
+                <i32>::sse_encode(28, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AlgorithmUnsupported => {
+                <i32>::sse_encode(29, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialUntrusted => {
+                <i32>::sse_encode(30, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialInvalid => {
+                <i32>::sse_encode(31, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialExpired => {
+                <i32>::sse_encode(32, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialRevoked => {
+                <i32>::sse_encode(33, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::SigningCredentialOcspUnknown => {
+                <i32>::sse_encode(34, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::TimeStampMismatch => {
+                <i32>::sse_encode(35, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::TimeStampMalformed => {
+                <i32>::sse_encode(36, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionHashedUriMismatch => {
+                <i32>::sse_encode(37, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionDataHashMismatch => {
+                <i32>::sse_encode(38, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionDataHashMalformed => {
+                <i32>::sse_encode(39, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMismatch => {
+                <i32>::sse_encode(40, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionBmffHashMalformed => {
+                <i32>::sse_encode(41, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashMismatch => {
+                <i32>::sse_encode(42, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionBoxesHashUnknownBox => {
+                <i32>::sse_encode(43, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMismatch => {
+                <i32>::sse_encode(44, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashMalformed => {
+                <i32>::sse_encode(45, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashIncorrectFileCount => {
+                <i32>::sse_encode(46, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCollectionHashInvalidUri => {
+                <i32>::sse_encode(47, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::HashedUriMissing => {
+                <i32>::sse_encode(48, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::HashedUriMismatch => {
+                <i32>::sse_encode(49, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionMissing => {
+                <i32>::sse_encode(50, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionRequiredMissing => {
+                <i32>::sse_encode(51, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionMultipleHardBindings => {
+                <i32>::sse_encode(52, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionUndeclared => {
+                <i32>::sse_encode(53, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionInaccessible => {
+                <i32>::sse_encode(54, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionNotRedacted => {
+                <i32>::sse_encode(55, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionSelfRedacted => {
+                <i32>::sse_encode(56, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionJsonInvalid => {
+                <i32>::sse_encode(57, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCborInvalid => {
+                <i32>::sse_encode(58, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionActionIngredientMismatch => {
+                <i32>::sse_encode(59, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionActionMalformed => {
+                <i32>::sse_encode(60, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionActionRedacted => {
+                <i32>::sse_encode(61, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionActionRedactionMismatch => {
+                <i32>::sse_encode(62, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCloudDataHardBinding => {
+                <i32>::sse_encode(63, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCloudDataActions => {
+                <i32>::sse_encode(64, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::AssertionCloudDataLabelMismatch => {
+                <i32>::sse_encode(65, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientHashedUriMissing => {
+                <i32>::sse_encode(66, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientHashedUriMismatch => {
+                <i32>::sse_encode(67, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientManifestMissing => {
+                <i32>::sse_encode(68, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientManifestMismatch => {
+                <i32>::sse_encode(69, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureMissing => {
+                <i32>::sse_encode(70, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::IngredientClaimSignatureMismatch => {
+                <i32>::sse_encode(71, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestInaccessible => {
+                <i32>::sse_encode(72, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestMultipleParents => {
+                <i32>::sse_encode(73, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestUpdateInvalid => {
+                <i32>::sse_encode(74, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestUpdateWrongParents => {
+                <i32>::sse_encode(75, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestTimestampInvalid => {
+                <i32>::sse_encode(76, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestTimestampWrongParents => {
+                <i32>::sse_encode(77, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::ManifestCompressedInvalid => {
+                <i32>::sse_encode(78, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::GeneralError => {
+                <i32>::sse_encode(79, serializer);
+            }
+            crate::api::validation_status::ValidationStatusCode::Unknown { code } => {
+                <i32>::sse_encode(80, serializer);
+                <String>::sse_encode(code, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for () {
+impl SseEncode for crate::api::c2pa_reader::ValidationStatusEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.code, serializer);
+        <crate::api::validation_status::ValidationStatusCode>::sse_encode(self.kind, serializer);
+        <crate::api::validation_status::ValidationCategory>::sse_encode(self.category, serializer);
+        <Option<String>>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.explanation, serializer);
+    }
 }
 
 impl SseEncode for crate::api::c2pa_reader::VerificationStatus {
@@ -782,11 +7198,21 @@ impl SseEncode for crate::api::c2pa_reader::VerificationStatus {
             crate::api::c2pa_reader::VerificationStatus::CertificateUntrusted => {
                 <i32>::sse_encode(3, serializer);
             }
-            crate::api::c2pa_reader::VerificationStatus::NoManifest => {
+            crate::api::c2pa_reader::VerificationStatus::CertificateRevoked => {
                 <i32>::sse_encode(4, serializer);
             }
-            crate::api::c2pa_reader::VerificationStatus::Error { message } => {
+            crate::api::c2pa_reader::VerificationStatus::ContentModified => {
                 <i32>::sse_encode(5, serializer);
+            }
+            crate::api::c2pa_reader::VerificationStatus::ValidationFailed { codes } => {
+                <i32>::sse_encode(6, serializer);
+                <Vec<String>>::sse_encode(codes, serializer);
+            }
+            crate::api::c2pa_reader::VerificationStatus::NoManifest => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::api::c2pa_reader::VerificationStatus::Error { message } => {
+                <i32>::sse_encode(8, serializer);
                 <String>::sse_encode(message, serializer);
             }
             _ => {
@@ -796,6 +7222,22 @@ impl SseEncode for crate::api::c2pa_reader::VerificationStatus {
     }
 }
 
+impl SseEncode for crate::api::xmp::XmpInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::digital_source_type::DigitalSourceType>>::sse_encode(
+            self.digital_source_type,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.credit, serializer);
+        <Option<String>>::sse_encode(self.creator_tool, serializer);
+        <Vec<String>>::sse_encode(self.creators, serializer);
+        <Option<String>>::sse_encode(self.provenance, serializer);
+        <String>::sse_encode(self.container, serializer);
+        <String>::sse_encode(self.packet, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -811,6 +7253,7 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::input::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -820,6 +7263,24 @@ mod io {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_rust_lib_origin_lens_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>::increment_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_rust_lib_origin_lens_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>::decrement_strong_count(ptr as _);
+        }
+    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
@@ -833,6 +7294,7 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::input::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -844,6 +7306,24 @@ mod web {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>::increment_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChunkedAsset(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChunkedAsset>>::decrement_strong_count(ptr as _);
+        }
+    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;